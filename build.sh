@@ -77,40 +77,42 @@ echo "Step 34. Testing malachite-nz with bin_build and 32_bit_limbs" &&
 cargo test --release --features bin_build --features 32_bit_limbs --features enable_serde &&
 echo "Step 35. Testing malachite-nz with bin_build" &&
 cargo test --release --features bin_build --features enable_serde &&
-echo "Step 36. Testing malachite-nz doctests without bin_build and with 32_bit_limbs" &&
+echo "Step 36. Testing malachite-nz multiplication, squaring, and division with parallel" &&
+cargo test --release --features bin_build --features parallel --test lib -- mul square div &&
+echo "Step 37. Testing malachite-nz doctests without bin_build and with 32_bit_limbs" &&
 cargo test --release --doc --features 32_bit_limbs &&
-echo "Step 37. Testing malachite-nz doctests without bin_build" &&
+echo "Step 38. Testing malachite-nz doctests without bin_build" &&
 cargo test --release --doc &&
-echo "Step 38. Running extra tests for malachite-nz" &&
+echo "Step 39. Running extra tests for malachite-nz" &&
 python3 extra-tests.py &&
-echo "Step 39. Running clippy on malachite-nz" &&
+echo "Step 40. Running clippy on malachite-nz" &&
 cargo clippy --tests --features bin_build --features enable_serde &&
-echo "Step 40. Documenting malachite-nz" &&
+echo "Step 41. Documenting malachite-nz" &&
 RUSTDOCFLAGS="--html-in-header katex-header.html" cargo doc --lib --no-deps --features doc-images &&
-echo "Step 41. Building malachite-nz lib for wasm with 32_bit_limbs" &&
+echo "Step 42. Building malachite-nz lib for wasm with 32_bit_limbs" &&
 cargo build --lib --release --features 32_bit_limbs --target wasm32-unknown-unknown &&
-echo "Step 42. Building malachite-nz lib for wasm" &&
+echo "Step 43. Building malachite-nz lib for wasm" &&
 cargo build --lib --release --target wasm32-unknown-unknown &&
 cd ../malachite-q &&
-echo "Step 43. Updating malachite-q" &&
+echo "Step 44. Updating malachite-q" &&
 cargo update &&
-echo "Step 44. Formatting malachite-q" &&
+echo "Step 45. Formatting malachite-q" &&
 cargo +nightly fmt --all &&
-echo "Step 45. Running clippy on malachite-q" &&
+echo "Step 46. Running clippy on malachite-q" &&
 cargo clippy --tests --features bin_build --features enable_serde &&
-echo "Step 46. Testing malachite-q with bin_build" &&
+echo "Step 47. Testing malachite-q with bin_build" &&
 cargo test --release --features bin_build --features enable_serde &&
-echo "Step 47. Testing malachite-q doctests without bin_build" &&
+echo "Step 48. Testing malachite-q doctests without bin_build" &&
 cargo test --release --doc &&
-echo "Step 48. Documenting malachite-q" &&
+echo "Step 49. Documenting malachite-q" &&
 RUSTDOCFLAGS="--html-in-header katex-header.html" cargo doc --lib --no-deps &&
-echo "Step 49. Building malachite-q lib for wasm" &&
+echo "Step 50. Building malachite-q lib for wasm" &&
 cargo build --lib --release --target wasm32-unknown-unknown &&
 cd ../malachite-criterion-bench &&
-echo "Step 50. Updating malachite-criterion-bench" &&
+echo "Step 51. Updating malachite-criterion-bench" &&
 cargo update &&
-echo "Step 51. Formatting malachite-criterion-bench" &&
+echo "Step 52. Formatting malachite-criterion-bench" &&
 cargo +nightly fmt --all &&
 cd .. &&
-echo "Step 52. Running additional-lints" &&
+echo "Step 53. Running additional-lints" &&
 python3 additional-lints.py
@@ -1,14 +1,17 @@
+use itertools::Itertools;
 use malachite_base::num::arithmetic::traits::UnsignedAbs;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::test_util::bench::bucketers::{
-    abs_usize_convertible_pair_max_bucketer, usize_convertible_pair_max_bucketer,
+    abs_usize_convertible_pair_max_bucketer, pair_1_vec_len_bucketer, triple_2_bucketer,
+    usize_convertible_pair_max_bucketer, vec_len_bucketer,
 };
 use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
 use malachite_base::test_util::generators::common::{GenConfig, GenMode};
 use malachite_base::test_util::generators::{
     signed_pair_gen_var_11, signed_pair_gen_var_12, unsigned_pair_gen_var_28,
-    unsigned_pair_gen_var_44,
+    unsigned_pair_gen_var_44, unsigned_triple_gen_var_13, unsigned_vec_gen,
+    unsigned_vec_unsigned_pair_gen,
 };
 use malachite_base::test_util::runner::Runner;
 
@@ -17,11 +20,17 @@ pub(crate) fn register(runner: &mut Runner) {
     register_signed_demos!(runner, demo_binomial_coefficient_signed);
     register_unsigned_demos!(runner, demo_checked_binomial_coefficient_unsigned);
     register_signed_demos!(runner, demo_checked_binomial_coefficient_signed);
+    register_unsigned_demos!(runner, demo_binomial_coefficient_mod);
+    register_unsigned_demos!(runner, demo_checked_multinomial_coefficient);
+    register_unsigned_demos!(runner, demo_multinomial_coefficient_mod);
 
     register_unsigned_benches!(runner, benchmark_binomial_coefficient_unsigned);
     register_signed_benches!(runner, benchmark_binomial_coefficient_signed);
     register_unsigned_benches!(runner, benchmark_checked_binomial_coefficient_unsigned);
     register_signed_benches!(runner, benchmark_checked_binomial_coefficient_signed);
+    register_unsigned_benches!(runner, benchmark_binomial_coefficient_mod);
+    register_unsigned_benches!(runner, benchmark_checked_multinomial_coefficient);
+    register_unsigned_benches!(runner, benchmark_multinomial_coefficient_mod);
 }
 
 fn demo_binomial_coefficient_unsigned<T: PrimitiveUnsigned>(
@@ -74,6 +83,67 @@ fn demo_checked_binomial_coefficient_signed<T: PrimitiveSigned>(
     }
 }
 
+fn demo_binomial_coefficient_mod<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for (m, n, k) in unsigned_triple_gen_var_13::<T, T>()
+        .get(gm, &config)
+        .filter(|&(m, _, _)| m != T::ZERO)
+        .take(limit)
+    {
+        println!(
+            "C({}, {}) ≡ {} mod {}",
+            n,
+            k,
+            T::binomial_coefficient_mod(n, k, m),
+            m
+        );
+    }
+}
+
+fn demo_checked_multinomial_coefficient<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for ks in unsigned_vec_gen::<u8>().get(gm, &config).take(limit) {
+        let ks = ks.into_iter().map(T::from).collect_vec();
+        println!(
+            "multinomial{:?} = {:?}",
+            ks,
+            T::checked_multinomial_coefficient(&ks)
+        );
+    }
+}
+
+fn demo_multinomial_coefficient_mod<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for (ks, m) in unsigned_vec_unsigned_pair_gen::<u8, T>()
+        .get(gm, &config)
+        .filter(|(ks, m)| {
+            *m != T::ZERO
+                && ks
+                    .iter()
+                    .try_fold(T::ZERO, |s, &k| s.checked_add(T::from(k)))
+                    .is_some()
+        })
+        .take(limit)
+    {
+        let ks = ks.into_iter().map(T::from).collect_vec();
+        println!(
+            "multinomial{:?} ≡ {} mod {}",
+            ks,
+            T::multinomial_coefficient_mod(&ks, m),
+            m
+        );
+    }
+}
+
 fn benchmark_binomial_coefficient_unsigned<T: PrimitiveUnsigned>(
     gm: GenMode,
     config: GenConfig,
@@ -183,3 +253,98 @@ fn benchmark_checked_binomial_coefficient_signed<T: PrimitiveSigned>(
         })],
     );
 }
+
+fn benchmark_binomial_coefficient_mod<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) where
+    usize: TryFrom<T>,
+{
+    run_benchmark(
+        &format!(
+            "{}::binomial_coefficient_mod({}, {}, {})",
+            T::NAME,
+            T::NAME,
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_triple_gen_var_13::<T, T>()
+            .get(gm, &config)
+            .filter(|&(m, _, _)| m != T::ZERO),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_2_bucketer("n"),
+        &mut [("Malachite", &mut |(m, n, k)| {
+            no_out!(T::binomial_coefficient_mod(n, k, m))
+        })],
+    );
+}
+
+fn benchmark_checked_multinomial_coefficient<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}::checked_multinomial_coefficient(&[{}])",
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_vec_gen::<u8>()
+            .get(gm, &config)
+            .map(|ks| ks.into_iter().map(T::from).collect_vec()),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |ks| {
+            no_out!(T::checked_multinomial_coefficient(&ks))
+        })],
+    );
+}
+
+fn benchmark_multinomial_coefficient_mod<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}::multinomial_coefficient_mod(&[{}], {})",
+            T::NAME,
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_vec_unsigned_pair_gen::<u8, T>()
+            .get(gm, &config)
+            .filter_map(|(ks, m)| {
+                let ks = ks.into_iter().map(T::from).collect_vec();
+                if m != T::ZERO
+                    && ks
+                        .iter()
+                        .try_fold(T::ZERO, |s, &k| s.checked_add(k))
+                        .is_some()
+                {
+                    Some((ks, m))
+                } else {
+                    None
+                }
+            }),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_vec_len_bucketer("ks"),
+        &mut [("Malachite", &mut |(ks, m)| {
+            no_out!(T::multinomial_coefficient_mod(&ks, m))
+        })],
+    );
+}
@@ -0,0 +1,85 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::carmichael_lambda::carmichael_lambda_table;
+use malachite_base::num::arithmetic::traits::CarmichaelLambda;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_5};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_carmichael_lambda);
+    register_unsigned_demos!(runner, demo_carmichael_lambda_table);
+
+    register_unsigned_benches!(runner, benchmark_carmichael_lambda_algorithms);
+    register_bench!(runner, benchmark_carmichael_lambda_table_algorithms);
+}
+
+fn demo_carmichael_lambda<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("{}.carmichael_lambda() = {}", n, n.carmichael_lambda());
+    }
+}
+
+fn demo_carmichael_lambda_table<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen_var_5::<T>().get(gm, &config).take(limit) {
+        println!(
+            "carmichael_lambda_table({}) = {:?}",
+            n,
+            carmichael_lambda_table(n)
+        );
+    }
+}
+
+fn benchmark_carmichael_lambda_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.carmichael_lambda()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.carmichael_lambda())),
+            ("using carmichael_lambda_from_factors", &mut |n| {
+                no_out!(T::carmichael_lambda_from_factors(&n.factor()))
+            }),
+        ],
+    );
+}
+
+fn benchmark_carmichael_lambda_table_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "carmichael_lambda_table(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(carmichael_lambda_table(n))),
+            ("using carmichael_lambda", &mut |n| {
+                no_out!((1..=n).map(|k| k.carmichael_lambda()).collect_vec())
+            }),
+        ],
+    );
+}
@@ -0,0 +1,151 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{triple_max_bit_bucketer, vec_len_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{
+    signed_triple_gen, unsigned_triple_gen_var_19, unsigned_vec_gen,
+};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_crt_unsigned);
+    register_signed_demos!(runner, demo_crt_signed);
+    register_unsigned_demos!(runner, demo_crt_precomputed);
+
+    register_unsigned_benches!(runner, benchmark_crt_unsigned);
+    register_signed_benches!(runner, benchmark_crt_signed);
+    register_unsigned_benches!(runner, benchmark_crt_precomputed_algorithms);
+}
+
+fn demo_crt_unsigned<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, a, b) in unsigned_triple_gen_var_19::<T>()
+        .get(gm, &config)
+        .filter(|&(_, a, b)| a != T::ZERO && b != T::ZERO)
+        .take(limit)
+    {
+        let congruences = [(x, a), (x + T::ONE, b)];
+        println!("crt({:?}) = {:?}", congruences, T::crt(&congruences));
+    }
+}
+
+fn demo_crt_signed<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, a, b) in signed_triple_gen::<T>()
+        .get(gm, &config)
+        .filter(|&(_, a, b)| a > T::ZERO && b > T::ZERO)
+        .take(limit)
+    {
+        let congruences = [(x, a), (x, b)];
+        println!("crt({:?}) = {:?}", congruences, T::crt(&congruences));
+    }
+}
+
+fn demo_crt_precomputed<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in unsigned_vec_gen::<T>()
+        .get(gm, &config)
+        .filter(|xs| !xs.contains(&T::ZERO))
+        .take(limit)
+    {
+        if let Some(basis) = T::precompute_crt_data(&xs) {
+            println!(
+                "crt_precomputed({:?}, {:?}) = {}",
+                xs,
+                xs,
+                T::crt_precomputed(&xs, &basis)
+            );
+        } else {
+            println!(
+                "{:?} are not pairwise coprime or their product is too large",
+                xs
+            );
+        }
+    }
+}
+
+fn benchmark_crt_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}::crt(&[({}, {}), ({}, {})])",
+            T::NAME,
+            T::NAME,
+            T::NAME,
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_triple_gen_var_19::<T>()
+            .get(gm, &config)
+            .filter(|&(_, a, b)| a != T::ZERO && b != T::ZERO),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_max_bit_bucketer("x", "a", "b"),
+        &mut [("Malachite", &mut |(x, a, b)| {
+            no_out!(T::crt(&[(x, a), (x, b)]))
+        })],
+    );
+}
+
+fn benchmark_crt_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}::crt(&[({}, {}), ({}, {})])",
+            T::NAME,
+            T::NAME,
+            T::NAME,
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        signed_triple_gen::<T>()
+            .get(gm, &config)
+            .filter(|&(_, a, b)| a > T::ZERO && b > T::ZERO),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_max_bit_bucketer("x", "a", "b"),
+        &mut [("Malachite", &mut |(x, a, b)| {
+            no_out!(T::crt(&[(x, a), (x, b)]))
+        })],
+    );
+}
+
+fn benchmark_crt_precomputed_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::crt_precomputed(&[{}], &CrtBasis)", T::NAME, T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_vec_gen::<T>()
+            .get(gm, &config)
+            .filter(|xs| !xs.contains(&T::ZERO)),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [
+            ("crt", &mut |xs| {
+                let congruences = xs.iter().map(|&x| (x, x)).collect::<Vec<_>>();
+                no_out!(T::crt(&congruences))
+            }),
+            ("precompute_crt_data and crt_precomputed", &mut |xs| {
+                if let Some(basis) = T::precompute_crt_data(&xs) {
+                    no_out!(T::crt_precomputed(&xs, &basis))
+                }
+            }),
+        ],
+    );
+}
@@ -0,0 +1,122 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::divisor_sigma::{divisor_count_table, divisor_sigma_table};
+use malachite_base::num::arithmetic::traits::DivisorCount;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    pair_1_bit_bucketer, unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_5, unsigned_pair_gen_var_3,
+};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_divisor_count);
+    register_unsigned_demos!(runner, demo_divisor_count_table);
+    register_unsigned_demos!(runner, demo_checked_divisor_sigma);
+    register_demo!(runner, demo_divisor_sigma_table);
+
+    register_unsigned_benches!(runner, benchmark_divisor_count);
+    register_bench!(runner, benchmark_divisor_count_table_algorithms);
+    register_unsigned_benches!(runner, benchmark_checked_divisor_sigma);
+}
+
+fn demo_divisor_count<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("{}.divisor_count() = {}", n, n.divisor_count());
+    }
+}
+
+fn demo_divisor_count_table<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5::<T>().get(gm, &config).take(limit) {
+        println!("divisor_count_table({}) = {:?}", n, divisor_count_table(n));
+    }
+}
+
+fn demo_checked_divisor_sigma<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, k) in unsigned_pair_gen_var_3::<T>().get(gm, &config).take(limit) {
+        if n != T::ZERO {
+            println!(
+                "{}.checked_divisor_sigma({}) = {:?}",
+                n,
+                k,
+                n.checked_divisor_sigma(k)
+            );
+        }
+    }
+}
+
+fn demo_divisor_sigma_table(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5::<u64>().get(gm, &config).take(limit) {
+        println!(
+            "divisor_sigma_table({}, 1) = {:?}",
+            n,
+            divisor_sigma_table(n, 1)
+        );
+    }
+}
+
+fn benchmark_divisor_count<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.divisor_count()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_count()))],
+    );
+}
+
+fn benchmark_divisor_count_table_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "divisor_count_table(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(divisor_count_table(n))),
+            ("using divisor_count", &mut |n| {
+                no_out!((1..=n).map(|k| k.divisor_count()).collect_vec())
+            }),
+        ],
+    );
+}
+
+fn benchmark_checked_divisor_sigma<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.checked_divisor_sigma(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_3::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(n, k)| {
+            if n != T::ZERO {
+                no_out!(n.checked_divisor_sigma(k))
+            }
+        })],
+    );
+}
@@ -0,0 +1,36 @@
+use itertools::Itertools;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_divisors);
+    register_unsigned_benches!(runner, benchmark_divisors);
+}
+
+fn demo_divisors<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("{}.divisors() = {:?}", n, n.divisors().collect_vec());
+    }
+}
+
+fn benchmark_divisors<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.divisors()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.divisors().count()))],
+    );
+}
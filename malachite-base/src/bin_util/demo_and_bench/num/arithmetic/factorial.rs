@@ -1,7 +1,7 @@
 use malachite_base::num::arithmetic::factorial::checked_multifactorial_naive;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::test_util::bench::bucketers::{
-    unsigned_direct_bucketer, usize_convertible_pair_max_bucketer,
+    pair_1_bucketer, unsigned_direct_bucketer, usize_convertible_pair_max_bucketer,
 };
 use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
 use malachite_base::test_util::generators::common::{GenConfig, GenMode};
@@ -23,9 +23,11 @@ pub(crate) fn register(runner: &mut Runner) {
     register_unsigned_demos!(runner, demo_checked_multifactorial);
     register_unsigned_demos!(runner, demo_subfactorial);
     register_unsigned_demos!(runner, demo_checked_subfactorial);
+    register_unsigned_demos!(runner, demo_factorial_mod);
 
     register_unsigned_benches!(runner, benchmark_factorial_algorithms);
     register_unsigned_benches!(runner, benchmark_checked_factorial);
+    register_unsigned_benches!(runner, benchmark_factorial_mod);
     register_unsigned_benches!(runner, benchmark_double_factorial_algorithms);
     register_unsigned_benches!(runner, benchmark_checked_double_factorial);
     register_unsigned_benches!(runner, benchmark_multifactorial_algorithms);
@@ -272,3 +274,32 @@ fn benchmark_checked_subfactorial<T: PrimitiveUnsigned>(
         &mut [("Malachite", &mut |n| no_out!(T::checked_subfactorial(n)))],
     );
 }
+
+fn demo_factorial_mod<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, m) in unsigned_pair_gen_var_12::<u8, T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("{}! ≡ {} mod {}", n, T::factorial_mod(u64::from(n), m), m);
+    }
+}
+
+fn benchmark_factorial_mod<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::factorial_mod(u64, {})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_12::<u8, T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("n"),
+        &mut [("Malachite", &mut |(n, m)| {
+            no_out!(T::factorial_mod(u64::from(n), m))
+        })],
+    );
+}
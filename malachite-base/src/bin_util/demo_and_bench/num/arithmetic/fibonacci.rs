@@ -0,0 +1,105 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    pair_max_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_pair_gen_var_12};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_fibonacci);
+    register_unsigned_demos!(runner, demo_checked_fibonacci_pair);
+    register_unsigned_demos!(runner, demo_fibonacci_mod);
+
+    register_unsigned_benches!(runner, benchmark_checked_fibonacci);
+    register_unsigned_benches!(runner, benchmark_checked_fibonacci_pair);
+    register_unsigned_benches!(runner, benchmark_fibonacci_mod_algorithms);
+}
+
+fn demo_checked_fibonacci<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen().get(gm, &config).take(limit) {
+        println!("F({}) = {:?}", n, T::checked_fibonacci(n));
+    }
+}
+
+fn demo_checked_fibonacci_pair<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen().get(gm, &config).take(limit) {
+        println!(
+            "(F({}), F({} + 1)) = {:?}",
+            n,
+            n,
+            T::checked_fibonacci_pair(n)
+        );
+    }
+}
+
+fn demo_fibonacci_mod<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, m) in unsigned_pair_gen_var_12::<u64, T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("F({}) ≡ {} mod {}", n, T::fibonacci_mod(n, m), m);
+    }
+}
+
+fn benchmark_checked_fibonacci<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_fibonacci(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_fibonacci(n)))],
+    );
+}
+
+fn benchmark_checked_fibonacci_pair<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_fibonacci_pair(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_fibonacci_pair(n)))],
+    );
+}
+
+fn benchmark_fibonacci_mod_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::fibonacci_mod(u64, {})", T::NAME, T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_pair_gen_var_12::<u64, T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_max_bit_bucketer("n", "m"),
+        &mut [
+            ("default", &mut |(n, m)| no_out!(T::fibonacci_mod(n, m))),
+            ("using lucas_sequence_mod", &mut |(n, m)| {
+                if T::convertible_from(n) {
+                    no_out!(T::exact_from(n).lucas_sequence_mod(T::ONE, m - T::ONE, m))
+                }
+            }),
+        ],
+    );
+}
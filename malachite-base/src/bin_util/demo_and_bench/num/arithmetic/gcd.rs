@@ -1,17 +1,19 @@
 use malachite_base::num::arithmetic::gcd::{gcd_binary, gcd_euclidean, gcd_fast_a, gcd_fast_b};
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
-use malachite_base::test_util::bench::bucketers::pair_max_bit_bucketer;
+use malachite_base::test_util::bench::bucketers::{pair_max_bit_bucketer, vec_len_bucketer};
 use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
 use malachite_base::test_util::generators::common::{GenConfig, GenMode};
-use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_base::test_util::generators::{unsigned_pair_gen_var_27, unsigned_vec_gen};
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
     register_unsigned_demos!(runner, demo_gcd);
     register_unsigned_demos!(runner, demo_gcd_assign);
+    register_unsigned_demos!(runner, demo_gcd_of);
 
     register_unsigned_benches!(runner, benchmark_gcd_algorithms);
     register_unsigned_benches!(runner, benchmark_gcd_assign);
+    register_unsigned_benches!(runner, benchmark_gcd_of_algorithms);
 }
 
 fn demo_gcd<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
@@ -69,3 +71,32 @@ fn benchmark_gcd_assign<T: PrimitiveUnsigned>(
         &mut [("Malachite", &mut |(mut x, y)| x.gcd_assign(y))],
     );
 }
+
+fn demo_gcd_of<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in unsigned_vec_gen::<T>().get(gm, &config).take(limit) {
+        println!("gcd_of({:?}) = {}", xs, T::gcd_of(xs.iter().copied()));
+    }
+}
+
+fn benchmark_gcd_of_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::gcd_of(Iterator<Item={}>)", T::NAME, T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_vec_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [
+            ("default", &mut |xs| no_out!(T::gcd_of(xs.into_iter()))),
+            ("left fold", &mut |xs| {
+                no_out!(xs.into_iter().fold(T::ZERO, |acc, x| acc.gcd(x)))
+            }),
+        ],
+    );
+}
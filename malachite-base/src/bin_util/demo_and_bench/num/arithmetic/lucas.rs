@@ -0,0 +1,143 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    pair_max_bit_bucketer, quadruple_max_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_pair_gen_var_12, unsigned_quadruple_gen_var_13,
+};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_lucas);
+    register_unsigned_demos!(runner, demo_checked_lucas_pair);
+    register_unsigned_demos!(runner, demo_lucas_mod);
+    register_unsigned_demos!(runner, demo_lucas_sequence_mod);
+
+    register_unsigned_benches!(runner, benchmark_checked_lucas);
+    register_unsigned_benches!(runner, benchmark_checked_lucas_pair);
+    register_unsigned_benches!(runner, benchmark_lucas_mod_algorithms);
+    register_unsigned_benches!(runner, benchmark_lucas_sequence_mod);
+}
+
+fn demo_checked_lucas<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen().get(gm, &config).take(limit) {
+        println!("L({}) = {:?}", n, T::checked_lucas(n));
+    }
+}
+
+fn demo_checked_lucas_pair<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen().get(gm, &config).take(limit) {
+        println!("(L({}), L({} + 1)) = {:?}", n, n, T::checked_lucas_pair(n));
+    }
+}
+
+fn demo_lucas_mod<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, m) in unsigned_pair_gen_var_12::<u64, T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("L({}) ≡ {} mod {}", n, T::lucas_mod(n, m), m);
+    }
+}
+
+fn demo_lucas_sequence_mod<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (k, p, q, m) in unsigned_quadruple_gen_var_13::<T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let (u, v) = k.lucas_sequence_mod(p, q, m);
+        println!(
+            "(U_{}({}, {}), V_{}({}, {})) ≡ ({}, {}) mod {}",
+            k, p, q, k, p, q, u, v, m
+        );
+    }
+}
+
+fn benchmark_checked_lucas<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_lucas(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_lucas(n)))],
+    );
+}
+
+fn benchmark_checked_lucas_pair<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_lucas_pair(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_lucas_pair(n)))],
+    );
+}
+
+fn benchmark_lucas_mod_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::lucas_mod(u64, {})", T::NAME, T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_pair_gen_var_12::<u64, T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_max_bit_bucketer("n", "m"),
+        &mut [
+            ("default", &mut |(n, m)| no_out!(T::lucas_mod(n, m))),
+            ("using lucas_sequence_mod", &mut |(n, m)| {
+                if T::convertible_from(n) {
+                    no_out!(T::exact_from(n).lucas_sequence_mod(T::ONE, m - T::ONE, m))
+                }
+            }),
+        ],
+    );
+}
+
+fn benchmark_lucas_sequence_mod<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!(
+            "{}.lucas_sequence_mod({}, {}, {})",
+            T::NAME,
+            T::NAME,
+            T::NAME,
+            T::NAME
+        ),
+        BenchmarkType::Single,
+        unsigned_quadruple_gen_var_13::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_max_bit_bucketer("k", "p", "q", "m"),
+        &mut [("Malachite", &mut |(k, p, q, m)| {
+            no_out!(k.lucas_sequence_mod(p, q, m))
+        })],
+    );
+}
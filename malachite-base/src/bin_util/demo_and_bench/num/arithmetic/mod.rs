@@ -6,20 +6,25 @@ pub(crate) fn register(runner: &mut Runner) {
     arithmetic_checked_shl::register(runner);
     arithmetic_checked_shr::register(runner);
     binomial_coefficient::register(runner);
+    carmichael_lambda::register(runner);
     ceiling::register(runner);
     checked_add_mul::register(runner);
     checked_square::register(runner);
     checked_sub_mul::register(runner);
     coprime_with::register(runner);
+    crt::register(runner);
     div_exact::register(runner);
     div_mod::register(runner);
     div_round::register(runner);
     divisible_by::register(runner);
     divisible_by_power_of_2::register(runner);
+    divisor_sigma::register(runner);
+    divisors::register(runner);
     eq_mod::register(runner);
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     factorial::register(runner);
+    fibonacci::register(runner);
     floor::register(runner);
     gcd::register(runner);
     is_power_of_2::register(runner);
@@ -28,9 +33,12 @@ pub(crate) fn register(runner: &mut Runner) {
     log_base::register(runner);
     log_base_2::register(runner);
     log_base_power_of_2::register(runner);
+    lucas::register(runner);
     mod_inverse::register(runner);
     mod_is_reduced::register(runner);
+    mod_log::register(runner);
     mod_add::register(runner);
+    mod_centered::register(runner);
     mod_mul::register(runner);
     mod_neg::register(runner);
     mod_op::register(runner);
@@ -48,8 +56,11 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_power_of_2_sub::register(runner);
     mod_shl::register(runner);
     mod_shr::register(runner);
+    mod_sqrt::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
+    moebius_mu::register(runner);
+    multiplicative_order::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
     overflowing_abs::register(runner);
@@ -63,8 +74,10 @@ pub(crate) fn register(runner: &mut Runner) {
     overflowing_sub::register(runner);
     overflowing_sub_mul::register(runner);
     parity::register(runner);
+    perfect_power::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
+    primitive_root::register(runner);
     primorial::register(runner);
     root::register(runner);
     rotate::register(runner);
@@ -85,6 +98,8 @@ pub(crate) fn register(runner: &mut Runner) {
     sqrt::register(runner);
     square::register(runner);
     sub_mul::register(runner);
+    totient::register(runner);
+    valuation::register(runner);
     wrapping_abs::register(runner);
     wrapping_add::register(runner);
     wrapping_add_mul::register(runner);
@@ -109,20 +124,25 @@ mod add_mul;
 mod arithmetic_checked_shl;
 mod arithmetic_checked_shr;
 mod binomial_coefficient;
+mod carmichael_lambda;
 mod ceiling;
 mod checked_add_mul;
 mod checked_square;
 mod checked_sub_mul;
 mod coprime_with;
+mod crt;
 mod div_exact;
 mod div_mod;
 mod div_round;
 mod divisible_by;
 mod divisible_by_power_of_2;
+mod divisor_sigma;
+mod divisors;
 mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod factorial;
+mod fibonacci;
 mod floor;
 mod gcd;
 mod is_power_of_2;
@@ -131,9 +151,12 @@ mod lcm;
 mod log_base;
 mod log_base_2;
 mod log_base_power_of_2;
+mod lucas;
 mod mod_add;
+mod mod_centered;
 mod mod_inverse;
 mod mod_is_reduced;
+mod mod_log;
 mod mod_mul;
 mod mod_neg;
 mod mod_op;
@@ -151,8 +174,11 @@ mod mod_power_of_2_square;
 mod mod_power_of_2_sub;
 mod mod_shl;
 mod mod_shr;
+mod mod_sqrt;
 mod mod_square;
 mod mod_sub;
+mod moebius_mu;
+mod multiplicative_order;
 mod neg;
 mod next_power_of_2;
 mod overflowing_abs;
@@ -166,8 +192,10 @@ mod overflowing_square;
 mod overflowing_sub;
 mod overflowing_sub_mul;
 mod parity;
+mod perfect_power;
 mod pow;
 mod power_of_2;
+mod primitive_root;
 mod primorial;
 mod root;
 mod rotate;
@@ -188,6 +216,8 @@ mod sign;
 mod sqrt;
 mod square;
 mod sub_mul;
+mod totient;
+mod valuation;
 mod wrapping_abs;
 mod wrapping_add;
 mod wrapping_add_mul;
@@ -0,0 +1,63 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::test_util::bench::bucketers::pair_max_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::signed_pair_gen_var_13;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_signed_demos!(runner, demo_mod_centered);
+    register_signed_demos!(runner, demo_mod_centered_assign);
+    register_signed_benches!(runner, benchmark_mod_centered);
+    register_signed_benches!(runner, benchmark_mod_centered_assign);
+}
+
+fn demo_mod_centered<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, m) in signed_pair_gen_var_13::<T>().get(gm, &config).take(limit) {
+        println!("{}.mod_centered({}) = {}", n, m, n.mod_centered(m));
+    }
+}
+
+fn demo_mod_centered_assign<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut n, m) in signed_pair_gen_var_13::<T>().get(gm, &config).take(limit) {
+        let old_n = n;
+        n.mod_centered_assign(m);
+        println!("n := {}; n.mod_centered_assign({}); n = {}", old_n, m, n);
+    }
+}
+
+fn benchmark_mod_centered<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.mod_centered({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        signed_pair_gen_var_13::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_max_bit_bucketer("n", "m"),
+        &mut [("Malachite", &mut |(n, m)| no_out!(n.mod_centered(m)))],
+    );
+}
+
+fn benchmark_mod_centered_assign<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.mod_centered_assign({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        signed_pair_gen_var_13::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_max_bit_bucketer("n", "m"),
+        &mut [("Malachite", &mut |(mut n, m)| n.mod_centered_assign(m))],
+    );
+}
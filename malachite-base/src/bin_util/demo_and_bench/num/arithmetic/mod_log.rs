@@ -0,0 +1,38 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::triple_3_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_mod_log);
+    register_unsigned_benches!(runner, benchmark_mod_log);
+}
+
+fn demo_mod_log<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, base, m) in unsigned_triple_gen_var_12::<T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("{}.mod_log({}, {}) = {:?}", x, base, m, x.mod_log(base, m));
+    }
+}
+
+fn benchmark_mod_log<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.mod_log({}, {})", T::NAME, T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_triple_gen_var_12::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(x, base, m)| no_out!(x.mod_log(base, m)))],
+    );
+}
@@ -0,0 +1,77 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::moebius_mu::moebius_mu_table;
+use malachite_base::num::arithmetic::traits::MoebiusMu;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_5};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_moebius_mu);
+    register_unsigned_demos!(runner, demo_moebius_mu_table);
+
+    register_unsigned_benches!(runner, benchmark_moebius_mu_algorithms);
+    register_bench!(runner, benchmark_moebius_mu_table_algorithms);
+}
+
+fn demo_moebius_mu<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("{}.moebius_mu() = {}", n, n.moebius_mu());
+    }
+}
+
+fn demo_moebius_mu_table<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5::<T>().get(gm, &config).take(limit) {
+        println!("moebius_mu_table({}) = {:?}", n, moebius_mu_table(n));
+    }
+}
+
+fn benchmark_moebius_mu_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.moebius_mu()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.moebius_mu())),
+            ("using moebius_mu_from_factors", &mut |n| {
+                no_out!(T::moebius_mu_from_factors(&n.factor()))
+            }),
+        ],
+    );
+}
+
+fn benchmark_moebius_mu_table_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "moebius_mu_table(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(moebius_mu_table(n))),
+            ("using moebius_mu", &mut |n| {
+                no_out!((1..=n).map(|k| k.moebius_mu()).collect_vec())
+            }),
+        ],
+    );
+}
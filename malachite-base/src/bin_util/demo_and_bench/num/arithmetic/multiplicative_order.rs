@@ -0,0 +1,43 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::pair_2_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_multiplicative_order);
+    register_unsigned_benches!(runner, benchmark_multiplicative_order);
+}
+
+fn demo_multiplicative_order<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in unsigned_pair_gen_var_16::<T>().get(gm, &config).take(limit) {
+        println!(
+            "{}.multiplicative_order({}) = {:?}",
+            x,
+            m,
+            x.multiplicative_order(m)
+        );
+    }
+}
+
+fn benchmark_multiplicative_order<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.multiplicative_order({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_16::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_bit_bucketer("m"),
+        &mut [(
+            "Malachite",
+            &mut |(x, m)| no_out!(x.multiplicative_order(m)),
+        )],
+    );
+}
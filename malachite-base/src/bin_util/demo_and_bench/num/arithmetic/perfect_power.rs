@@ -0,0 +1,143 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{signed_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_is_perfect_power_unsigned);
+    register_signed_demos!(runner, demo_is_perfect_power_signed);
+    register_unsigned_demos!(runner, demo_perfect_power_decompose_unsigned);
+    register_signed_demos!(runner, demo_perfect_power_decompose_signed);
+
+    register_unsigned_benches!(runner, benchmark_is_perfect_power_unsigned);
+    register_signed_benches!(runner, benchmark_is_perfect_power_signed);
+    register_unsigned_benches!(runner, benchmark_perfect_power_decompose_unsigned);
+    register_signed_benches!(runner, benchmark_perfect_power_decompose_signed);
+}
+
+fn demo_is_perfect_power_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen::<T>().get(gm, &config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{} is a perfect power", n);
+        } else {
+            println!("{} is not a perfect power", n);
+        }
+    }
+}
+
+fn demo_is_perfect_power_signed<T: PrimitiveSigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in signed_gen::<T>().get(gm, &config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{} is a perfect power", n);
+        } else {
+            println!("{} is not a perfect power", n);
+        }
+    }
+}
+
+fn demo_perfect_power_decompose_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen::<T>().get(gm, &config).take(limit) {
+        println!(
+            "{}.perfect_power_decompose() = {:?}",
+            n,
+            n.perfect_power_decompose()
+        );
+    }
+}
+
+fn demo_perfect_power_decompose_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for n in signed_gen::<T>().get(gm, &config).take(limit) {
+        println!(
+            "({}).perfect_power_decompose() = {:?}",
+            n,
+            n.perfect_power_decompose()
+        );
+    }
+}
+
+fn benchmark_is_perfect_power_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_perfect_power()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.is_perfect_power()))],
+    );
+}
+
+fn benchmark_is_perfect_power_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_perfect_power()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.is_perfect_power()))],
+    );
+}
+
+fn benchmark_perfect_power_decompose_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.perfect_power_decompose()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.perfect_power_decompose()))],
+    );
+}
+
+fn benchmark_perfect_power_decompose_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.perfect_power_decompose()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.perfect_power_decompose()))],
+    );
+}
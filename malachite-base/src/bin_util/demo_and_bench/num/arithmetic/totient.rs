@@ -0,0 +1,77 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::totient::totient_table;
+use malachite_base::num::arithmetic::traits::Totient;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_5};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_totient);
+    register_unsigned_demos!(runner, demo_totient_table);
+
+    register_unsigned_benches!(runner, benchmark_totient_algorithms);
+    register_bench!(runner, benchmark_totient_table_algorithms);
+}
+
+fn demo_totient<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_1::<T>().get(gm, &config).take(limit) {
+        println!("{}.totient() = {}", n, n.totient());
+    }
+}
+
+fn demo_totient_table<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5::<T>().get(gm, &config).take(limit) {
+        println!("totient_table({}) = {:?}", n, totient_table(n));
+    }
+}
+
+fn benchmark_totient_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.totient()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_1::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.totient())),
+            ("using totient_from_factors", &mut |n| {
+                no_out!(T::totient_from_factors(&n.factor()))
+            }),
+        ],
+    );
+}
+
+fn benchmark_totient_table_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "totient_table(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(totient_table(n))),
+            ("using totient", &mut |n| {
+                no_out!((1..=n).map(|k| k.totient()).collect_vec())
+            }),
+        ],
+    );
+}
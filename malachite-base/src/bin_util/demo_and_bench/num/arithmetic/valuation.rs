@@ -0,0 +1,151 @@
+use malachite_base::num::arithmetic::traits::{RemoveFactor, Valuation};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::pair_1_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{signed_unsigned_pair_gen, unsigned_pair_gen_var_24};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_valuation_unsigned);
+    register_signed_unsigned_match_demos!(runner, demo_valuation_signed);
+    register_unsigned_demos!(runner, demo_remove_factor_unsigned);
+    register_signed_unsigned_match_demos!(runner, demo_remove_factor_signed);
+
+    register_unsigned_benches!(runner, benchmark_valuation_unsigned);
+    register_signed_unsigned_match_benches!(runner, benchmark_valuation_signed);
+    register_unsigned_benches!(runner, benchmark_remove_factor_unsigned);
+    register_signed_unsigned_match_benches!(runner, benchmark_remove_factor_signed);
+}
+
+fn demo_valuation_unsigned<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in unsigned_pair_gen_var_24::<T, T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("{}.valuation({}) = {}", n, p, n.valuation(p));
+    }
+}
+
+fn demo_valuation_signed<S: PrimitiveSigned + Valuation<U, Output = u64>, U: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for (n, p) in signed_unsigned_pair_gen::<S, U>()
+        .get(gm, &config)
+        .filter(|&(n, p)| n != S::ZERO && p > U::ONE)
+        .take(limit)
+    {
+        println!("({}).valuation({}) = {}", n, p, n.valuation(p));
+    }
+}
+
+fn demo_remove_factor_unsigned<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in unsigned_pair_gen_var_24::<T, T>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("{}.remove_factor({}) = {:?}", n, p, n.remove_factor(p));
+    }
+}
+
+fn demo_remove_factor_signed<
+    S: PrimitiveSigned + RemoveFactor<U, CofactorOutput = S, ValuationOutput = u64>,
+    U: PrimitiveUnsigned,
+>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for (n, p) in signed_unsigned_pair_gen::<S, U>()
+        .get(gm, &config)
+        .filter(|&(n, p)| n != S::ZERO && p > U::ONE)
+        .take(limit)
+    {
+        println!("({}).remove_factor({}) = {:?}", n, p, n.remove_factor(p));
+    }
+}
+
+fn benchmark_valuation_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.valuation({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_24::<T, T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(n, p)| no_out!(n.valuation(p)))],
+    );
+}
+
+fn benchmark_valuation_signed<
+    S: PrimitiveSigned + Valuation<U, Output = u64>,
+    U: PrimitiveUnsigned,
+>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.valuation({})", S::NAME, U::NAME),
+        BenchmarkType::Single,
+        signed_unsigned_pair_gen::<S, U>()
+            .get(gm, &config)
+            .filter(|&(n, p)| n != S::ZERO && p > U::ONE),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(n, p)| no_out!(n.valuation(p)))],
+    );
+}
+
+fn benchmark_remove_factor_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.remove_factor({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_24::<T, T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(n, p)| no_out!(n.remove_factor(p)))],
+    );
+}
+
+fn benchmark_remove_factor_signed<
+    S: PrimitiveSigned + RemoveFactor<U, CofactorOutput = S, ValuationOutput = u64>,
+    U: PrimitiveUnsigned,
+>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.remove_factor({})", S::NAME, U::NAME),
+        BenchmarkType::Single,
+        signed_unsigned_pair_gen::<S, U>()
+            .get(gm, &config)
+            .filter(|&(n, p)| n != S::ZERO && p > U::ONE),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("n"),
+        &mut [("Malachite", &mut |(n, p)| no_out!(n.remove_factor(p)))],
+    );
+}
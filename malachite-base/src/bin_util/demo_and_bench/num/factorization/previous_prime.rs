@@ -0,0 +1,35 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_previous_prime);
+    register_unsigned_benches!(runner, benchmark_previous_prime);
+}
+
+fn demo_previous_prime<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen::<T>().get(gm, &config).take(limit) {
+        println!("{}.previous_prime() = {:?}", n, n.previous_prime());
+    }
+}
+
+fn benchmark_previous_prime<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.previous_prime()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.previous_prime()))],
+    );
+}
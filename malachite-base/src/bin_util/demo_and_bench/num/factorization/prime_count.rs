@@ -0,0 +1,64 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{NthPrime, PrimeCount, Primes};
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_bit_bucketer, unsigned_direct_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_11};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_prime_count);
+    register_demo!(runner, demo_nth_prime);
+    register_bench!(runner, benchmark_prime_count_algorithms);
+    register_bench!(runner, benchmark_nth_prime_algorithms);
+}
+
+fn demo_prime_count(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen::<u32>().get(gm, &config).take(limit) {
+        println!("{}.prime_count() = {}", n, n.prime_count());
+    }
+}
+
+fn demo_nth_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_11::<u64>().get(gm, &config).take(limit) {
+        println!("nth_prime({}) = {}", n, u64::nth_prime(n));
+    }
+}
+
+fn benchmark_prime_count_algorithms(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u32.prime_count()",
+        BenchmarkType::Algorithms,
+        unsigned_gen::<u32>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.prime_count())),
+            ("using primes_less_than_or_equal_to", &mut |n| {
+                no_out!(u32::primes_less_than_or_equal_to(&n).count())
+            }),
+        ],
+    );
+}
+
+fn benchmark_nth_prime_algorithms(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "u64::nth_prime(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_11::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(u64::nth_prime(n))),
+            ("using primes", &mut |n| {
+                no_out!(u64::primes().nth(usize::exact_from(n - 1)).unwrap())
+            }),
+        ],
+    );
+}
@@ -0,0 +1,72 @@
+use itertools::Itertools;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::primitive_int_increasing_range;
+use malachite_base::test_util::bench::bucketers::pair_1_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_gen;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_primes_in_range);
+    register_unsigned_demos!(runner, demo_primes_in_inclusive_range);
+
+    register_unsigned_benches!(runner, benchmark_primes_in_range_algorithms);
+}
+
+fn demo_primes_in_range<T: PrimitiveUnsigned>(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, d) in unsigned_pair_gen::<T, u8>().get(gm, &config).take(limit) {
+        let b = a.saturating_add(T::saturating_from(d));
+        println!(
+            "primes_in_range({}, {}) = {:?}",
+            a,
+            b,
+            T::primes_in_range(&a, &b).collect_vec()
+        );
+    }
+}
+
+fn demo_primes_in_inclusive_range<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+) {
+    for (a, d) in unsigned_pair_gen::<T, u8>().get(gm, &config).take(limit) {
+        let b = a.saturating_add(T::saturating_from(d));
+        println!(
+            "primes_in_inclusive_range({}, {}) = {:?}",
+            a,
+            b,
+            T::primes_in_inclusive_range(&a, &b).collect_vec()
+        );
+    }
+}
+
+fn benchmark_primes_in_range_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::primes_in_range({}, {})", T::NAME, T::NAME, T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_pair_gen::<T, u8>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bit_bucketer("a"),
+        &mut [
+            ("default", &mut |(a, d)| {
+                let b = a.saturating_add(T::saturating_from(d));
+                no_out!(T::primes_in_range(&a, &b).count())
+            }),
+            ("using is_prime", &mut |(a, d)| {
+                let b = a.saturating_add(T::saturating_from(d));
+                no_out!(primitive_int_increasing_range(a, b)
+                    .filter(|n| n.is_prime())
+                    .count())
+            }),
+        ],
+    );
+}
@@ -1,5 +1,6 @@
 use crate::num::arithmetic::traits::{
-    BinomialCoefficient, CheckedBinomialCoefficient, UnsignedAbs,
+    BinomialCoefficient, BinomialCoefficientMod, CheckedBinomialCoefficient,
+    CheckedMultinomialCoefficient, MultinomialCoefficient, MultinomialCoefficientMod, UnsignedAbs,
 };
 use crate::num::basic::signeds::PrimitiveSigned;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
@@ -175,3 +176,306 @@ macro_rules! impl_binomial_coefficient_primitive_int {
     };
 }
 apply_to_primitive_ints!(impl_binomial_coefficient_primitive_int);
+
+// Returns $\binom{n}{k} \bmod p$, where $p$ is prime and $k \leq n < p$.
+fn binomial_coefficient_mod_prime_small<T: PrimitiveUnsigned>(n: T, k: T, p: T) -> T {
+    let k = min(k, n - k);
+    if k == T::ZERO {
+        return T::ONE;
+    }
+    let offset = n - k;
+    let mut numerator = T::ONE;
+    let mut denominator = T::ONE;
+    for i in primitive_int_increasing_inclusive_range(T::ONE, k) {
+        numerator.mod_mul_assign(offset + i, p);
+        denominator.mod_mul_assign(i, p);
+    }
+    numerator.mod_mul(denominator.mod_inverse(p).unwrap(), p)
+}
+
+// Returns $\binom{n}{k} \bmod p$, where $p$ is prime, using Lucas's theorem: if $n_i$ and $k_i$
+// are the base-$p$ digits of $n$ and $k$, then $\binom{n}{k} \equiv \prod_i \binom{n_i}{k_i}
+// \pmod p$. Assumes $k \leq n$.
+fn binomial_coefficient_mod_prime<T: PrimitiveUnsigned>(mut n: T, mut k: T, p: T) -> T {
+    let mut result = T::ONE;
+    while k != T::ZERO {
+        let n_digit = n % p;
+        let k_digit = k % p;
+        if k_digit > n_digit {
+            return T::ZERO;
+        }
+        result.mod_mul_assign(binomial_coefficient_mod_prime_small(n_digit, k_digit, p), p);
+        n /= p;
+        k /= p;
+    }
+    result
+}
+
+// Returns the exponent of the prime $p$ in $n!$, using Legendre's formula.
+#[doc(hidden)]
+pub fn factorial_p_adic_valuation<T: PrimitiveUnsigned>(mut n: T, p: T) -> T {
+    let mut v = T::ZERO;
+    while n != T::ZERO {
+        n /= p;
+        v += n;
+    }
+    v
+}
+
+// Returns $(n!/p^{v_p(n!)}) \bmod q$, where $q = p^e$ and $e > 1$.
+//
+// The product of the integers in $[1, q]$ that are coprime to $p$ is $-1 \bmod q$, unless $p = 2$
+// and $e \geq 3$, in which case it is 1 (Gauss's generalization of Wilson's theorem). So the
+// product of the integers in $[1, n]$ that are coprime to $p$ is $(\pm 1)^{\lfloor n/q \rfloor}$
+// times the product of the integers in $[1, n \bmod q]$ that are coprime to $p$. The remaining
+// factors of $n!$ are the multiples of $p$, whose quotients by $p$ make up $\lfloor n/p \rfloor!$.
+fn factorial_p_free_mod<T: PrimitiveUnsigned>(mut n: T, p: T, q: T, negate_periods: bool) -> T {
+    let mut result = T::ONE;
+    while n != T::ZERO {
+        if negate_periods && (n / q).odd() {
+            result.mod_neg_assign(q);
+        }
+        let r = n % q;
+        let mut i = T::TWO;
+        while i <= r {
+            if !i.divisible_by(p) {
+                result.mod_mul_assign(i, q);
+            }
+            i += T::ONE;
+        }
+        n /= p;
+    }
+    result
+}
+
+// Returns $(\binom{n}{k}/p^{v_p(\binom{n}{k})}) \bmod q$, where $q = p^e$ and $e > 1$, by
+// multiplying $\min(k, n - k)$ terms with their factors of $p$ removed. Assumes $k \leq n$.
+fn binomial_coefficient_p_free_mod_small_k<T: PrimitiveUnsigned>(n: T, k: T, p: T, q: T) -> T {
+    let k = min(k, n - k);
+    let offset = n - k;
+    let mut numerator = T::ONE;
+    let mut denominator = T::ONE;
+    for i in primitive_int_increasing_inclusive_range(T::ONE, k) {
+        numerator.mod_mul_assign((offset + i).remove_factor(p).0 % q, q);
+        denominator.mod_mul_assign(i.remove_factor(p).0 % q, q);
+    }
+    numerator.mod_mul(denominator.mod_inverse(q).unwrap(), q)
+}
+
+// Returns $\binom{n}{k} \bmod q$, where $q = p^e$ and $p$ is prime. Assumes $k \leq n$.
+fn binomial_coefficient_mod_prime_power<T: PrimitiveUnsigned>(n: T, k: T, p: T, e: u64, q: T) -> T {
+    if e == 1 {
+        return binomial_coefficient_mod_prime(n, k, p);
+    }
+    let small_k = min(k, n - k);
+    if small_k == T::ZERO {
+        return T::ONE;
+    }
+    // By Kummer's theorem, this is also the number of carries when adding $k$ and $n - k$ in base
+    // $p$.
+    let v = factorial_p_adic_valuation(n, p)
+        - factorial_p_adic_valuation(k, p)
+        - factorial_p_adic_valuation(n - k, p);
+    if v >= T::wrapping_from(e) {
+        return T::ZERO;
+    }
+    // Each of the three p-free factorials takes about $q$ steps for each base-$p$ digit of $n$.
+    let digits = n.floor_log_base(p) + 1;
+    let unit = if q.saturating_mul(T::wrapping_from(3 * digits)) < small_k {
+        let negate_periods = p != T::TWO || e < 3;
+        let denominator = factorial_p_free_mod(k, p, q, negate_periods)
+            .mod_mul(factorial_p_free_mod(n - k, p, q, negate_periods), q);
+        factorial_p_free_mod(n, p, q, negate_periods)
+            .mod_mul(denominator.mod_inverse(q).unwrap(), q)
+    } else {
+        binomial_coefficient_p_free_mod_small_k(n, k, p, q)
+    };
+    p.pow(v.wrapping_into()).mod_mul(unit, q)
+}
+
+// Returns $\binom{n}{k} \bmod m$, given the prime factorization of $m > 1$. The binomial
+// coefficient is computed modulo each prime power dividing $m$, and the results are combined using
+// the Chinese remainder theorem.
+fn binomial_coefficient_mod_with_factors<T: PrimitiveUnsigned>(
+    n: T,
+    k: T,
+    m: T,
+    factors: &[(T, u64)],
+) -> T {
+    if k > n {
+        return T::ZERO;
+    }
+    if let &[(p, e)] = factors {
+        return binomial_coefficient_mod_prime_power(n, k, p, e, m);
+    }
+    let congruences = factors
+        .iter()
+        .map(|&(p, e)| {
+            let q = p.pow(e);
+            (binomial_coefficient_mod_prime_power(n, k, p, e, q), q)
+        })
+        .collect::<Vec<_>>();
+    T::crt(&congruences).unwrap().0
+}
+
+fn binomial_coefficient_mod<T: PrimitiveUnsigned>(n: T, k: T, m: T) -> T {
+    assert_ne!(m, T::ZERO, "Cannot reduce modulo 0");
+    if m == T::ONE || k > n {
+        T::ZERO
+    } else {
+        binomial_coefficient_mod_with_factors(n, k, m, &m.factor())
+    }
+}
+
+// The multinomial coefficient is computed as the product of the binomial coefficients
+// $\binom{k_1 + \cdots + k_i}{k_i}$. If the sum of the $k_i$ overflows, then at least two of the
+// $k_i$ are nonzero, so the multinomial coefficient is at least the sum and overflows too.
+fn checked_multinomial_coefficient<T: PrimitiveUnsigned>(ks: &[T]) -> Option<T> {
+    let mut n = T::ZERO;
+    let mut product = T::ONE;
+    for &k in ks {
+        n = n.checked_add(k)?;
+        product = product.checked_mul(T::checked_binomial_coefficient(n, k)?)?;
+    }
+    Some(product)
+}
+
+fn multinomial_coefficient_mod<T: PrimitiveUnsigned>(ks: &[T], m: T) -> T {
+    assert_ne!(m, T::ZERO, "Cannot reduce modulo 0");
+    if m == T::ONE {
+        return T::ZERO;
+    }
+    let factors = m.factor();
+    let mut n = T::ZERO;
+    let mut product = T::ONE;
+    for &k in ks {
+        n = n
+            .checked_add(k)
+            .expect("The sum of the inputs is too large");
+        product.mod_mul_assign(binomial_coefficient_mod_with_factors(n, k, m, &factors), m);
+        if product == T::ZERO {
+            break;
+        }
+    }
+    product
+}
+
+macro_rules! impl_binomial_coefficient_mod {
+    ($t:ident) => {
+        impl BinomialCoefficientMod for $t {
+            /// Computes the binomial coefficient of two numbers modulo a third number $m$.
+            ///
+            /// Unlike [`binomial_coefficient`](BinomialCoefficient::binomial_coefficient), this
+            /// function never overflows. If $m$ is prime, Lucas's theorem is used. If $m$ is a
+            /// prime power, Granville's generalization of Lucas's theorem is used. Otherwise, the
+            /// results for the prime powers dividing $m$ are combined using the Chinese remainder
+            /// theorem.
+            ///
+            /// $$
+            /// f(n, k, m) = \binom{n}{k} \bmod m.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k, m) = O(\min(k, n - k) + \sqrt{m})$
+            ///
+            /// $M(m) = O(\log m)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `n`, $k$ is `k`, and $m$ is
+            /// `m`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::binomial_coefficient#binomial_coefficient_mod).
+            #[inline]
+            fn binomial_coefficient_mod(n: $t, k: $t, m: $t) -> $t {
+                binomial_coefficient_mod(n, k, m)
+            }
+        }
+
+        impl CheckedMultinomialCoefficient for $t {
+            /// Computes the multinomial coefficient of a slice of numbers. If the result is too
+            /// large, the function returns `None`.
+            ///
+            /// $$
+            /// f((k_i)_{i=1}^r) = \\begin{cases}
+            ///     \operatorname{Some}(\binom{k_1 + \cdots + k_r}{k_1, \ldots, k_r}) & \text{if}
+            ///         \\quad \binom{k_1 + \cdots + k_r}{k_1, \ldots, k_r} < 2^W, \\\\
+            ///     \operatorname{None} & \\quad \\text{otherwise},
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH` and
+            /// $\binom{k_1 + \cdots + k_r}{k_1, \ldots, k_r} = (k_1 + \cdots + k_r)!/(k_1! \cdots
+            /// k_r!)$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `ks`.
+            ///
+            /// # Examples
+            /// See [here](super::binomial_coefficient#checked_multinomial_coefficient).
+            #[inline]
+            fn checked_multinomial_coefficient(ks: &[$t]) -> Option<$t> {
+                checked_multinomial_coefficient(ks)
+            }
+        }
+
+        impl MultinomialCoefficient for $t {
+            /// Computes the multinomial coefficient of a slice of numbers. If the result is too
+            /// large, the function panics.
+            ///
+            /// $$
+            /// f((k_i)_{i=1}^r) = \binom{k_1 + \cdots + k_r}{k_1, \ldots, k_r} =
+            /// \frac{(k_1 + \cdots + k_r)!}{k_1! \cdots k_r!}.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of `ks`.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable by this type.
+            ///
+            /// # Examples
+            /// See [here](super::binomial_coefficient#multinomial_coefficient).
+            #[inline]
+            fn multinomial_coefficient(ks: &[$t]) -> $t {
+                $t::checked_multinomial_coefficient(ks).unwrap()
+            }
+        }
+
+        impl MultinomialCoefficientMod for $t {
+            /// Computes the multinomial coefficient of a slice of numbers modulo a number $m$.
+            ///
+            /// $$
+            /// f((k_i)_{i=1}^r, m) = \binom{k_1 + \cdots + k_r}{k_1, \ldots, k_r} \bmod m.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n, r, m) = O(n + r\log m + \sqrt{m})$
+            ///
+            /// $M(m) = O(\log m)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is the sum of `ks`, $r$ is
+            /// `ks.len()`, and $m$ is `m`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0 or if the sum of `ks` is not representable by this type.
+            ///
+            /// # Examples
+            /// See [here](super::binomial_coefficient#multinomial_coefficient_mod).
+            #[inline]
+            fn multinomial_coefficient_mod(ks: &[$t], m: $t) -> $t {
+                multinomial_coefficient_mod(ks, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_binomial_coefficient_mod);
@@ -0,0 +1,139 @@
+use crate::num::arithmetic::traits::CarmichaelLambda;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::prime_sieve::prime_power_sieve;
+use crate::num::factorization::traits::Factor;
+
+// Computes $\lambda(p^e)$, where $p$ is prime and $e > 0$.
+fn carmichael_lambda_of_prime_power<T: PrimitiveUnsigned>(p: T, e: u64) -> T {
+    if p == T::TWO && e > 2 {
+        T::power_of_2(e - 2)
+    } else {
+        p.pow(e - 1) * (p - T::ONE)
+    }
+}
+
+fn carmichael_lambda_from_factors<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    factors.iter().fold(T::ONE, |lambda, &(p, e)| {
+        lambda.lcm(carmichael_lambda_of_prime_power(p, e))
+    })
+}
+
+// Returns the prime factorization of $\lambda(n)$, in no particular order, given the prime
+// factorization of $n$. Only $p - 1$ is factored for each prime factor $p$ of $n$, which is cheaper
+// than factoring $\lambda(n)$ itself.
+pub(crate) fn carmichael_lambda_factors<T: PrimitiveUnsigned>(
+    factors: &[(T, u64)],
+) -> Vec<(T, u64)> {
+    let mut lambda_factors: Vec<(T, u64)> = Vec::new();
+    let mut add_factor = |q: T, f: u64| {
+        if let Some(existing) = lambda_factors.iter_mut().find(|(r, _)| *r == q) {
+            existing.1 = existing.1.max(f);
+        } else {
+            lambda_factors.push((q, f));
+        }
+    };
+    for &(p, e) in factors {
+        if p == T::TWO {
+            if e > 1 {
+                add_factor(T::TWO, if e == 2 { 1 } else { e - 2 });
+            }
+        } else {
+            if e > 1 {
+                add_factor(p, e - 1);
+            }
+            for (q, f) in (p - T::ONE).factor() {
+                add_factor(q, f);
+            }
+        }
+    }
+    lambda_factors
+}
+
+/// Computes the Carmichael function $\lambda$ of every number from 0 to $n$, inclusive.
+///
+/// The $k$th entry of the result is $\lambda(k)$, except that the 0th entry is 0. The values are
+/// computed together using a prime sieve, which is much faster than computing
+/// [`carmichael_lambda`](CarmichaelLambda::carmichael_lambda) of each number separately.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n\log n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+///
+/// # Panics
+/// Panics if $n + 1$ does not fit in a [`usize`].
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::carmichael_lambda::carmichael_lambda_table;
+///
+/// assert_eq!(carmichael_lambda_table(0u8), &[0]);
+/// assert_eq!(
+///     carmichael_lambda_table(16u32),
+///     &[0, 1, 1, 2, 2, 4, 2, 6, 2, 6, 4, 10, 2, 12, 6, 4, 4]
+/// );
+/// ```
+pub fn carmichael_lambda_table<T: PrimitiveUnsigned>(n: T) -> Vec<T> {
+    prime_power_sieve(n, T::ZERO, T::ONE, |lambda, p, e| {
+        lambda.lcm_assign(carmichael_lambda_of_prime_power(p, e));
+    })
+}
+
+macro_rules! impl_carmichael_lambda {
+    ($t:ident) => {
+        impl CarmichaelLambda for $t {
+            /// Computes the Carmichael function $\lambda$ of a number: the smallest positive $m$
+            /// such that $a^m \equiv 1 \mod n$ for every $a$ coprime to $n$.
+            ///
+            /// The number is factored first. If its factorization is already known, use
+            /// [`carmichael_lambda_from_factors`](CarmichaelLambda::carmichael_lambda_from_factors)
+            /// instead.
+            ///
+            /// $$
+            /// f(n) = \lambda(n) = \operatorname{lcm}_{p^e \\| n} \lambda(p^e),
+            /// $$
+            /// where $\lambda(2^e) = 2^{e-2}$ if $e \geq 3$, and $\lambda(p^e) = p^{e-1}(p-1)$
+            /// otherwise.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor).
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::carmichael_lambda#carmichael_lambda).
+            #[inline]
+            fn carmichael_lambda(&self) -> $t {
+                carmichael_lambda_from_factors(&self.factor())
+            }
+
+            /// Computes the Carmichael function $\lambda$ of a number, given its prime
+            /// factorization.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// $$
+            /// f(\\{(p_i, e_i)\\}) = \operatorname{lcm}_i \lambda(p_i^{e_i}).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::carmichael_lambda#carmichael_lambda_from_factors).
+            #[inline]
+            fn carmichael_lambda_from_factors(factors: &[($t, u64)]) -> $t {
+                carmichael_lambda_from_factors(factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_carmichael_lambda);
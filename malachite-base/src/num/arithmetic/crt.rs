@@ -0,0 +1,253 @@
+use crate::num::arithmetic::traits::{Crt, CrtPrecomputed};
+use crate::num::basic::signeds::PrimitiveSigned;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+
+// Combines the congruences $x \equiv a \mod m$ and $x \equiv b \mod n$, where $a < m$ and $b < n$,
+// into a single congruence $x \equiv c \mod \operatorname{lcm}(m, n)$. Returns `None` if the
+// congruences are inconsistent or if the lcm is not representable.
+fn crt_combine<T: PrimitiveUnsigned>(a: T, m: T, b: T, n: T) -> Option<(T, T)> {
+    let g = m.gcd(n);
+    if a % g != b % g {
+        return None;
+    }
+    let n_g = n / g;
+    let lcm = m.checked_mul(n_g)?;
+    if n_g == T::ONE {
+        return Some((a, lcm));
+    }
+    // Solve a + m * t = b (mod n), or (m / g) * t = (b - a) / g (mod n / g).
+    let d = b.mod_sub(a % n, n) / g;
+    let inverse = (m / g % n_g).mod_inverse(n_g).unwrap();
+    Some((a + m * d.mod_mul(inverse, n_g), lcm))
+}
+
+fn crt_unsigned<T: PrimitiveUnsigned>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut x = T::ZERO;
+    let mut lcm = T::ONE;
+    for &(r, m) in congruences {
+        assert_ne!(m, T::ZERO);
+        (x, lcm) = crt_combine(x, lcm, r % m, m)?;
+    }
+    Some((x, lcm))
+}
+
+fn crt_signed<U: PrimitiveUnsigned + WrappingFrom<S>, S: PrimitiveSigned + WrappingFrom<U>>(
+    congruences: &[(S, S)],
+) -> Option<(S, S)> {
+    let mut x = U::ZERO;
+    let mut lcm = U::ONE;
+    for &(r, m) in congruences {
+        assert!(m > S::ZERO);
+        (x, lcm) = crt_combine(x, lcm, U::wrapping_from(r.mod_op(m)), U::wrapping_from(m))?;
+    }
+    if lcm > U::wrapping_from(S::MAX) {
+        None
+    } else {
+        Some((S::wrapping_from(x), S::wrapping_from(lcm)))
+    }
+}
+
+/// A precomputed basis for reconstructing numbers from their residues modulo a fixed set of
+/// pairwise coprime moduli.
+///
+/// This is the [`Data`](CrtPrecomputed::Data) of the [`CrtPrecomputed`] implementations for
+/// primitive unsigned integers, and is created by
+/// [`precompute_crt_data`](CrtPrecomputed::precompute_crt_data).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CrtBasis<T> {
+    moduli: Vec<T>,
+    // The $i$th coefficient is congruent to 1 modulo the $i$th modulus and to 0 modulo all the
+    // others.
+    coefficients: Vec<T>,
+    modulus: T,
+}
+
+impl<T: Copy> CrtBasis<T> {
+    /// Returns the moduli that the basis was created from.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+    ///
+    /// let basis = u32::precompute_crt_data(&[3, 5, 7]).unwrap();
+    /// assert_eq!(basis.moduli(), &[3, 5, 7]);
+    /// ```
+    pub fn moduli(&self) -> &[T] {
+        &self.moduli
+    }
+
+    /// Returns the product of the moduli that the basis was created from. Reconstructed numbers
+    /// are reduced modulo this product.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+    ///
+    /// let basis = u32::precompute_crt_data(&[3, 5, 7]).unwrap();
+    /// assert_eq!(basis.modulus(), 105);
+    /// ```
+    pub const fn modulus(&self) -> T {
+        self.modulus
+    }
+}
+
+fn precompute_crt_data_unsigned<T: PrimitiveUnsigned>(moduli: &[T]) -> Option<CrtBasis<T>> {
+    let mut modulus = T::ONE;
+    for &m in moduli {
+        assert_ne!(m, T::ZERO);
+        modulus = modulus.checked_mul(m)?;
+    }
+    let mut coefficients = Vec::with_capacity(moduli.len());
+    for &m in moduli {
+        let cofactor = modulus / m;
+        coefficients.push(if m == T::ONE {
+            T::ZERO
+        } else {
+            let reduced = cofactor % m;
+            if reduced == T::ZERO {
+                return None;
+            }
+            // The moduli are pairwise coprime iff each one is coprime to the product of the
+            // others.
+            cofactor * reduced.mod_inverse(m)?
+        });
+    }
+    Some(CrtBasis {
+        moduli: moduli.to_vec(),
+        coefficients,
+        modulus,
+    })
+}
+
+fn crt_precomputed_unsigned<T: PrimitiveUnsigned>(residues: &[T], data: &CrtBasis<T>) -> T {
+    assert_eq!(residues.len(), data.moduli.len());
+    let mut x = T::ZERO;
+    for ((&r, &m), &c) in residues
+        .iter()
+        .zip(data.moduli.iter())
+        .zip(data.coefficients.iter())
+    {
+        x.mod_add_assign((r % m).mod_mul(c, data.modulus), data.modulus);
+    }
+    x
+}
+
+macro_rules! impl_crt {
+    ($u:ident, $s:ident) => {
+        impl Crt for $u {
+            /// Solves a system of simultaneous congruences $x \equiv r_i \mod m_i$. The moduli
+            /// need not be pairwise coprime, and the residues need not be reduced.
+            ///
+            /// Returns the least nonnegative solution $x$ and the combined modulus
+            /// $L = \operatorname{lcm}(m_1, \ldots, m_k)$. Every solution is congruent to $x$
+            /// modulo $L$. Returns `None` if the congruences are inconsistent or if $L$ is not
+            /// representable. An empty system has the solution $(0, 1)$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(kn^2)$
+            ///
+            /// $M(n, k) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `Self::WIDTH`, and $k$ is
+            /// `congruences.len()`.
+            ///
+            /// # Panics
+            /// Panics if any modulus is 0.
+            ///
+            /// # Examples
+            /// See [here](super::crt#crt).
+            #[inline]
+            fn crt(congruences: &[($u, $u)]) -> Option<($u, $u)> {
+                crt_unsigned(congruences)
+            }
+        }
+
+        impl Crt for $s {
+            /// Solves a system of simultaneous congruences $x \equiv r_i \mod m_i$. The moduli
+            /// need not be pairwise coprime, and the residues need not be reduced or nonnegative.
+            ///
+            /// Returns the least nonnegative solution $x$ and the combined modulus
+            /// $L = \operatorname{lcm}(m_1, \ldots, m_k)$. Every solution is congruent to $x$
+            /// modulo $L$. Returns `None` if the congruences are inconsistent or if $L$ is not
+            /// representable. An empty system has the solution $(0, 1)$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(kn^2)$
+            ///
+            /// $M(n, k) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `Self::WIDTH`, and $k$ is
+            /// `congruences.len()`.
+            ///
+            /// # Panics
+            /// Panics if any modulus is not positive.
+            ///
+            /// # Examples
+            /// See [here](super::crt#crt).
+            #[inline]
+            fn crt(congruences: &[($s, $s)]) -> Option<($s, $s)> {
+                crt_signed::<$u, $s>(congruences)
+            }
+        }
+
+        impl CrtPrecomputed for $u {
+            type Data = CrtBasis<$u>;
+
+            /// Precomputes a basis for reconstructing numbers from their residues modulo the given
+            /// moduli.
+            ///
+            /// Returns `None` if the moduli are not pairwise coprime or if their product is not
+            /// representable.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(kn^2)$
+            ///
+            /// $M(n, k) = O(k)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `Self::WIDTH`, and $k$ is
+            /// `moduli.len()`.
+            ///
+            /// # Panics
+            /// Panics if any modulus is 0.
+            ///
+            /// # Examples
+            /// See [here](super::crt#precompute_crt_data).
+            #[inline]
+            fn precompute_crt_data(moduli: &[$u]) -> Option<CrtBasis<$u>> {
+                precompute_crt_data_unsigned(moduli)
+            }
+
+            /// Reconstructs a number from its residues modulo the moduli of a precomputed basis.
+            /// The residues need not be reduced.
+            ///
+            /// $f((r_1, \ldots, r_k), (m_1, \ldots, m_k)) = x$, where $0 \leq x < \prod_i m_i$ and
+            /// $x \equiv r_i \mod m_i$ for all $i$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(kn)$
+            ///
+            /// $M(n, k) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `Self::WIDTH`, and $k$ is
+            /// `residues.len()`.
+            ///
+            /// # Panics
+            /// Panics if the number of residues is not equal to the number of moduli in the basis.
+            ///
+            /// # Examples
+            /// See [here](super::crt#crt_precomputed).
+            #[inline]
+            fn crt_precomputed(residues: &[$u], data: &CrtBasis<$u>) -> $u {
+                crt_precomputed_unsigned(residues, data)
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_crt);
@@ -0,0 +1,255 @@
+use crate::num::arithmetic::traits::{CheckedDivisorSigma, DivisorCount, DivisorSigma};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::prime_sieve::prime_power_sieve;
+use crate::num::factorization::traits::Factor;
+
+fn divisor_count_from_factors<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    factors
+        .iter()
+        .fold(T::ONE, |tau, &(_, e)| tau * T::exact_from(e + 1))
+}
+
+// Computes $\sigma_k(p^e) = \sum_{i=0}^e p^{ik}$, where $p$ is prime, returning `None` if the
+// result is not representable.
+fn checked_divisor_sigma_of_prime_power<T: PrimitiveUnsigned>(p: T, e: u64, k: u64) -> Option<T> {
+    let q = p.checked_pow(k)?;
+    let mut sum = T::ONE;
+    let mut power = T::ONE;
+    for _ in 0..e {
+        power = power.checked_mul(q)?;
+        sum = sum.checked_add(power)?;
+    }
+    Some(sum)
+}
+
+fn checked_divisor_sigma_from_factors<T: PrimitiveUnsigned>(
+    factors: &[(T, u64)],
+    k: u64,
+) -> Option<T> {
+    let mut sigma = T::ONE;
+    for &(p, e) in factors {
+        sigma = sigma.checked_mul(checked_divisor_sigma_of_prime_power(p, e, k)?)?;
+    }
+    Some(sigma)
+}
+
+/// Computes the number of divisors $\tau$ of every number from 0 to $n$, inclusive.
+///
+/// The $k$th entry of the result is $\tau(k)$, except that the 0th entry is 0. The values are
+/// computed together using a prime sieve, which is much faster than computing
+/// [`divisor_count`](DivisorCount::divisor_count) of each number separately.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n\log\log n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+///
+/// # Panics
+/// Panics if $n + 1$ does not fit in a [`usize`].
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::divisor_sigma::divisor_count_table;
+///
+/// assert_eq!(divisor_count_table(0u8), &[0]);
+/// assert_eq!(divisor_count_table(12u32), &[0, 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6]);
+/// ```
+pub fn divisor_count_table<T: PrimitiveUnsigned>(n: T) -> Vec<T> {
+    prime_power_sieve(n, T::ZERO, T::ONE, |tau, _, e| {
+        *tau *= T::exact_from(e + 1);
+    })
+}
+
+/// Computes the sum of the $k$th powers of the divisors, $\sigma_k$, of every number from 0 to $n$,
+/// inclusive.
+///
+/// The $m$th entry of the result is $\sigma_k(m)$, except that the 0th entry is 0. The values are
+/// computed together using a prime sieve, which is much faster than computing
+/// [`divisor_sigma`](DivisorSigma::divisor_sigma) of each number separately.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n\log n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+///
+/// # Panics
+/// Panics if $n + 1$ does not fit in a [`usize`], or if $\sigma_k(m)$ is not representable for
+/// some $m \leq n$.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::divisor_sigma::divisor_sigma_table;
+///
+/// assert_eq!(divisor_sigma_table(0u8, 1), &[0]);
+/// assert_eq!(divisor_sigma_table(12u32, 1), &[0, 1, 3, 4, 7, 6, 12, 8, 15, 13, 18, 12, 28]);
+/// assert_eq!(divisor_sigma_table(6u32, 2), &[0, 1, 5, 10, 21, 26, 50]);
+/// ```
+pub fn divisor_sigma_table<T: PrimitiveUnsigned>(n: T, k: u64) -> Vec<T> {
+    prime_power_sieve(n, T::ZERO, T::ONE, |sigma, p, e| {
+        *sigma = sigma
+            .checked_mul(checked_divisor_sigma_of_prime_power(p, e, k).unwrap())
+            .unwrap();
+    })
+}
+
+macro_rules! impl_divisor_sigma {
+    ($t:ident) => {
+        impl DivisorCount for $t {
+            /// Counts the positive divisors of a number.
+            ///
+            /// The number is factored first. If its factorization is already known, use
+            /// [`divisor_count_from_factors`](DivisorCount::divisor_count_from_factors) instead.
+            ///
+            /// $$
+            /// f(n) = \tau(n) = \sum_{d | n} 1.
+            /// $$
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor).
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#divisor_count).
+            #[inline]
+            fn divisor_count(&self) -> $t {
+                divisor_count_from_factors(&self.factor())
+            }
+
+            /// Counts the positive divisors of a number, given its prime factorization.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// $$
+            /// f(\\{(p_i, e_i)\\}) = \prod_i (e_i + 1).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#divisor_count_from_factors).
+            #[inline]
+            fn divisor_count_from_factors(factors: &[($t, u64)]) -> $t {
+                divisor_count_from_factors(factors)
+            }
+        }
+
+        impl CheckedDivisorSigma for $t {
+            /// Computes the sum of the $k$th powers of the positive divisors of a number,
+            /// returning `None` if the result is not representable.
+            ///
+            /// The number is factored first. If its factorization is already known, use
+            /// [`CheckedDivisorSigma::checked_divisor_sigma_from_factors`] instead.
+            ///
+            /// $$
+            /// f(n, k) = \\begin{cases}
+            ///     \operatorname{Some}(\sigma_k(n)) & \text{if} \\quad \sigma_k(n) < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad \sigma_k(n) \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $\sigma_k(n) = \sum_{d | n} d^k$ and $W$ is `Self::WIDTH`.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor).
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#checked_divisor_sigma).
+            #[inline]
+            fn checked_divisor_sigma(&self, k: u64) -> Option<$t> {
+                checked_divisor_sigma_from_factors(&self.factor(), k)
+            }
+
+            /// Computes the sum of the $k$th powers of the positive divisors of a number, given
+            /// its prime factorization, returning `None` if the result is not representable.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the exponents in
+            /// `factors`.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#checked_divisor_sigma_from_factors).
+            #[inline]
+            fn checked_divisor_sigma_from_factors(factors: &[($t, u64)], k: u64) -> Option<$t> {
+                checked_divisor_sigma_from_factors(factors, k)
+            }
+        }
+
+        impl DivisorSigma for $t {
+            /// Computes the sum of the $k$th powers of the positive divisors of a number.
+            ///
+            /// The number is factored first. If its factorization is already known, use
+            /// [`divisor_sigma_from_factors`](DivisorSigma::divisor_sigma_from_factors) instead.
+            ///
+            /// If the result is too large, the function panics. For a function that returns
+            /// `None` instead, try
+            /// [`checked_divisor_sigma`](CheckedDivisorSigma::checked_divisor_sigma).
+            ///
+            /// $$
+            /// f(n, k) = \sigma_k(n) = \sum_{d | n} d^k.
+            /// $$
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor).
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#divisor_sigma).
+            #[inline]
+            fn divisor_sigma(&self, k: u64) -> $t {
+                self.checked_divisor_sigma(k).unwrap()
+            }
+
+            /// Computes the sum of the $k$th powers of the positive divisors of a number, given
+            /// its prime factorization.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// $$
+            /// f(\\{(p_i, e_i)\\}, k) = \prod_i \sum_{j=0}^{e_i} p_i^{jk}.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the exponents in
+            /// `factors`.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sigma#divisor_sigma_from_factors).
+            #[inline]
+            fn divisor_sigma_from_factors(factors: &[($t, u64)], k: u64) -> $t {
+                checked_divisor_sigma_from_factors(factors, k).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_divisor_sigma);
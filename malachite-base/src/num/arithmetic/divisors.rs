@@ -0,0 +1,76 @@
+use crate::num::arithmetic::traits::Divisors;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::ExactFrom;
+use crate::num::factorization::traits::Factor;
+use std::vec::IntoIter;
+
+fn divisors_from_factors<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> Vec<T> {
+    let count = factors
+        .iter()
+        .fold(1, |count, &(_, e)| count * usize::exact_from(e + 1));
+    let mut divisors = Vec::with_capacity(count);
+    divisors.push(T::ONE);
+    for &(p, e) in factors {
+        let len = divisors.len();
+        let mut power = T::ONE;
+        for _ in 0..e {
+            power *= p;
+            for i in 0..len {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+macro_rules! impl_divisors {
+    ($t:ident) => {
+        impl Divisors for $t {
+            type I = IntoIter<$t>;
+
+            /// Generates the positive divisors of a number, in ascending order.
+            ///
+            /// The number is factored first. If its factorization is already known, use
+            /// [`divisors_from_factors`](Divisors::divisors_from_factors) instead.
+            ///
+            /// The output length is $\tau(n)$, the number of divisors of $n$.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor).
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors).
+            #[inline]
+            fn divisors(&self) -> IntoIter<$t> {
+                divisors_from_factors(&self.factor()).into_iter()
+            }
+
+            /// Generates the positive divisors of a number, in ascending order, given its prime
+            /// factorization.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// The output length is $\prod_i (e_i + 1)$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the number of divisors.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors_from_factors).
+            #[inline]
+            fn divisors_from_factors(factors: &[($t, u64)]) -> IntoIter<$t> {
+                divisors_from_factors(factors).into_iter()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_divisors);
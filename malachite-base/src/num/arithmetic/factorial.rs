@@ -1,6 +1,7 @@
+use crate::num::arithmetic::binomial_coefficient::factorial_p_adic_valuation;
 use crate::num::arithmetic::traits::{
     CheckedDoubleFactorial, CheckedFactorial, CheckedMultifactorial, CheckedSubfactorial,
-    DoubleFactorial, Factorial, Multifactorial, Parity, Subfactorial,
+    DoubleFactorial, Factorial, FactorialMod, Multifactorial, Parity, Subfactorial,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
@@ -567,3 +568,71 @@ macro_rules! impl_factorials_b {
     };
 }
 apply_to_unsigneds!(impl_factorials_b);
+
+// When computing $n! \bmod m$ with $n$ at least this large, $m$ is factored to check whether it
+// divides $n!$ before any multiplications are done.
+#[doc(hidden)]
+pub const FACTORIAL_MOD_DIVISIBILITY_THRESHOLD: u64 = 1 << 16;
+
+// Returns whether $m$ divides $n!$: whether, for every prime power $p^e$ exactly dividing $m$, the
+// exponent of $p$ in $n!$ (given by Legendre's formula) is at least $e$. In particular, $p$ may not
+// exceed $n$.
+fn divides_factorial<T: PrimitiveUnsigned>(m: T, n: T) -> bool {
+    m.factor()
+        .into_iter()
+        .all(|(p, e)| p <= n && factorial_p_adic_valuation(n, p) >= T::wrapping_from(e))
+}
+
+fn factorial_mod<T: PrimitiveUnsigned>(n: u64, m: T) -> T {
+    assert_ne!(m, T::ZERO, "Cannot reduce modulo 0");
+    // If $n \geq m$, then $m$ is one of the factors of $n!$.
+    if !T::convertible_from(n)
+        || T::wrapping_from(n) >= m
+        || (n >= FACTORIAL_MOD_DIVISIBILITY_THRESHOLD && divides_factorial(m, T::wrapping_from(n)))
+    {
+        return T::ZERO;
+    }
+    let data = T::precompute_mod_mul_data(&m);
+    let mut f = T::ONE % m;
+    for i in 2..=n {
+        f.mod_mul_precomputed_assign(T::wrapping_from(i), m, &data);
+        // Once $m$ divides $i!$, it divides $n!$ as well.
+        if f == T::ZERO {
+            break;
+        }
+    }
+    f
+}
+
+macro_rules! impl_factorial_mod {
+    ($t:ident) => {
+        impl FactorialMod for $t {
+            /// Computes the factorial of a number modulo another number $m$.
+            ///
+            /// Unlike [`factorial`](Factorial::factorial), this function never overflows.
+            ///
+            /// $$
+            /// f(n, m) = n! \bmod m.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n, m) = O(\min(n, m))$
+            ///
+            /// $M(n, m) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is `m`. When $m$
+            /// divides $n!$, the result is found in much less time.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::factorial#factorial_mod).
+            #[inline]
+            fn factorial_mod(n: u64, m: $t) -> $t {
+                factorial_mod(n, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_factorial_mod);
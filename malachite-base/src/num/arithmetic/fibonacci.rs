@@ -0,0 +1,175 @@
+use crate::num::arithmetic::traits::{CheckedFibonacci, Fibonacci, FibonacciMod};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::logic::traits::{BitAccess, SignificantBits};
+
+// Computes $F_n$ and $F_{n+1}$ by repeated addition. The loop stops as soon as a value overflows,
+// which happens after $O(W)$ iterations, so this takes time $O(W)$ rather than $O(n)$.
+pub(crate) fn checked_fibonacci_like_pair<T: PrimitiveUnsigned>(
+    n: u64,
+    mut a: T,
+    mut b: T,
+) -> Option<(T, T)> {
+    for _ in 0..n {
+        let c = a.checked_add(b)?;
+        a = b;
+        b = c;
+    }
+    Some((a, b))
+}
+
+fn checked_fibonacci<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    if n == 0 {
+        Some(T::ZERO)
+    } else {
+        // (F_{n-1}, F_n)
+        checked_fibonacci_like_pair(n - 1, T::ZERO, T::ONE).map(|p| p.1)
+    }
+}
+
+// Computes $(F_n \bmod m, F_{n+1} \bmod m)$ using the fast doubling identities
+// $F_{2k} = F_k(2F_{k+1} - F_k)$ and $F_{2k+1} = F_k^2 + F_{k+1}^2$.
+pub(crate) fn fibonacci_pair_mod<T: PrimitiveUnsigned>(n: u64, m: T) -> (T, T) {
+    assert_ne!(m, T::ZERO, "Cannot reduce modulo 0");
+    let mut f = T::ZERO;
+    let mut g = T::ONE % m;
+    for i in (0..n.significant_bits()).rev() {
+        let f_2 = f.mod_mul(g.mod_add(g, m).mod_sub(f, m), m);
+        let g_2 = f.mod_square(m).mod_add(g.mod_square(m), m);
+        if n.get_bit(i) {
+            f = g_2;
+            g = f_2.mod_add(g_2, m);
+        } else {
+            f = f_2;
+            g = g_2;
+        }
+    }
+    (f, g)
+}
+
+macro_rules! impl_fibonacci {
+    ($t:ident) => {
+        impl CheckedFibonacci for $t {
+            /// Computes the $n$th Fibonacci number.
+            ///
+            /// If the input is too large, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(F_n) & \text{if} \\quad F_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad F_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`, $F_0 = 0$, $F_1 = 1$, and $F_{n+2} = F_{n+1} + F_n$.
+            ///
+            /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_fibonacci).
+            #[inline]
+            fn checked_fibonacci(n: u64) -> Option<$t> {
+                checked_fibonacci(n)
+            }
+
+            /// Computes the $n$th and $(n+1)$th Fibonacci numbers.
+            ///
+            /// If either output is too large, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}((F_n, F_{n+1})) & \text{if} \\quad F_{n+1} < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad F_{n+1} \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_fibonacci_pair).
+            #[inline]
+            fn checked_fibonacci_pair(n: u64) -> Option<($t, $t)> {
+                checked_fibonacci_like_pair(n, 0, 1)
+            }
+        }
+
+        impl Fibonacci for $t {
+            /// Computes the $n$th Fibonacci number.
+            ///
+            /// If the input is too large, the function panics. For a function that returns `None`
+            /// instead, try [`checked_fibonacci`](CheckedFibonacci::checked_fibonacci).
+            ///
+            /// $$
+            /// f(n) = F_n,
+            /// $$
+            /// where $F_0 = 0$, $F_1 = 1$, and $F_{n+2} = F_{n+1} + F_n$.
+            ///
+            /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#fibonacci).
+            #[inline]
+            fn fibonacci(n: u64) -> $t {
+                $t::checked_fibonacci(n).unwrap()
+            }
+
+            /// Computes the $n$th and $(n+1)$th Fibonacci numbers.
+            ///
+            /// If either output is too large, the function panics. For a function that returns
+            /// `None` instead, try
+            /// [`checked_fibonacci_pair`](CheckedFibonacci::checked_fibonacci_pair).
+            ///
+            /// $$
+            /// f(n) = (F_n, F_{n+1}).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if $F_{n+1}$ is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#fibonacci_pair).
+            #[inline]
+            fn fibonacci_pair(n: u64) -> ($t, $t) {
+                $t::checked_fibonacci_pair(n).unwrap()
+            }
+        }
+
+        impl FibonacciMod for $t {
+            /// Computes the $n$th Fibonacci number modulo $m$.
+            ///
+            /// $$
+            /// f(n, m) = F_n \bmod m.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(\log n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#fibonacci_mod).
+            #[inline]
+            fn fibonacci_mod(n: u64, m: $t) -> $t {
+                fibonacci_pair_mod(n, m).0
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_fibonacci);
@@ -1,4 +1,4 @@
-use crate::num::arithmetic::traits::{Gcd, GcdAssign};
+use crate::num::arithmetic::traits::{Gcd, GcdAssign, GcdOf};
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use std::cmp::min;
 
@@ -110,6 +110,17 @@ pub fn gcd_fast_b<T: PrimitiveUnsigned>(mut x: T, y: T) -> T {
     x
 }
 
+fn gcd_of<T: PrimitiveUnsigned, I: Iterator<Item = T>>(xs: I) -> T {
+    let mut gcd = T::ZERO;
+    for x in xs {
+        gcd = gcd_fast_a(gcd, x);
+        if gcd == T::ONE {
+            break;
+        }
+    }
+    gcd
+}
+
 macro_rules! impl_gcd {
     ($t:ident) => {
         impl Gcd<$t> for $t {
@@ -167,6 +178,32 @@ macro_rules! impl_gcd {
                 *self = gcd_fast_a(*self, other);
             }
         }
+
+        impl GcdOf<$t> for $t {
+            /// Computes the GCD (greatest common divisor) of all the numbers generated by an
+            /// iterator.
+            ///
+            /// The GCD of an empty iterator is 0. The iterator is only consumed until the GCD
+            /// reaches 1.
+            ///
+            /// $$
+            /// f((x_i)_ {i=0}^{k-1}) = \gcd(x_0, x_1, \ldots, x_{k-1}).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(n^2 + k)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is the maximum number of
+            /// significant bits of the numbers, and $k$ is the number of numbers.
+            ///
+            /// # Examples
+            /// See [here](super::gcd#gcd_of).
+            fn gcd_of<I: Iterator<Item = $t>>(xs: I) -> $t {
+                gcd_of(xs)
+            }
+        }
     };
 }
 apply_to_unsigneds!(impl_gcd);
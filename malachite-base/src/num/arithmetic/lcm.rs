@@ -1,4 +1,4 @@
-use crate::num::arithmetic::traits::{CheckedLcm, Lcm, LcmAssign};
+use crate::num::arithmetic::traits::{CheckedLcm, Lcm, LcmAssign, LcmOf};
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 
 #[inline]
@@ -14,6 +14,18 @@ fn checked_lcm<T: PrimitiveUnsigned>(x: T, y: T) -> Option<T> {
     }
 }
 
+fn lcm_of<T: PrimitiveUnsigned, I: Iterator<Item = T>>(xs: I) -> T {
+    // Overflow is only reported once we know that none of the remaining numbers is 0
+    let mut lcm = Some(T::ONE);
+    for x in xs {
+        if x == T::ZERO {
+            return T::ZERO;
+        }
+        lcm = lcm.and_then(|lcm| checked_lcm(lcm, x));
+    }
+    lcm.unwrap()
+}
+
 macro_rules! impl_lcm {
     ($t:ident) => {
         impl Lcm<$t> for $t {
@@ -100,6 +112,35 @@ macro_rules! impl_lcm {
                 checked_lcm(self, other)
             }
         }
+
+        impl LcmOf<$t> for $t {
+            /// Computes the LCM (least common multiple) of all the numbers generated by an
+            /// iterator.
+            ///
+            /// The LCM of an empty iterator is 1. If any of the numbers is 0, the LCM is 0, and
+            /// the iterator is not consumed any further.
+            ///
+            /// $$
+            /// f((x_i)_ {i=0}^{k-1}) = \operatorname{lcm}(x_0, x_1, \ldots, x_{k-1}).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(kn^2)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is the maximum number of
+            /// significant bits of the numbers, and $k$ is the number of numbers.
+            ///
+            /// # Panics
+            /// Panics if the result is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::lcm#lcm_of).
+            fn lcm_of<I: Iterator<Item = $t>>(xs: I) -> $t {
+                lcm_of(xs)
+            }
+        }
     };
 }
 apply_to_unsigneds!(impl_lcm);
@@ -0,0 +1,220 @@
+use crate::num::arithmetic::fibonacci::{checked_fibonacci_like_pair, fibonacci_pair_mod};
+use crate::num::arithmetic::traits::{CheckedLucas, Lucas, LucasMod, LucasSequenceMod};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+fn checked_lucas<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    if n == 0 {
+        Some(T::TWO)
+    } else {
+        // (L_{n-1}, L_n)
+        checked_fibonacci_like_pair(n - 1, T::TWO, T::ONE).map(|p| p.1)
+    }
+}
+
+// $L_n = 2F_{n+1} - F_n$.
+fn lucas_mod<T: PrimitiveUnsigned>(n: u64, m: T) -> T {
+    let (f, g) = fibonacci_pair_mod(n, m);
+    g.mod_add(g, m).mod_sub(f, m)
+}
+
+// Computes $U_k$, $V_k$, and $Q^k$ modulo $m$, using the ladder from Joye and Quisquater,
+// "Efficient computation of full Lucas sequences", 1996. No divisions are needed, so $m$ may be
+// even. Assumes that $p$ and $q$ are reduced modulo $m$.
+fn lucas_sequence_mod_helper<T: PrimitiveUnsigned>(k: T, p: T, q: T, m: T) -> (T, T, T) {
+    let one = T::ONE % m;
+    let two = T::TWO % m;
+    if k == T::ZERO {
+        return (T::ZERO, two, one);
+    }
+    let s = k.trailing_zeros();
+    let mut u_h = one;
+    let mut v_l = two;
+    let mut v_h = p;
+    let mut q_l = one;
+    let mut q_h = one;
+    for j in (s + 1..k.significant_bits()).rev() {
+        q_l.mod_mul_assign(q_h, m);
+        if k.get_bit(j) {
+            q_h = q_l.mod_mul(q, m);
+            u_h.mod_mul_assign(v_h, m);
+            v_l = v_h.mod_mul(v_l, m).mod_sub(p.mod_mul(q_l, m), m);
+            v_h = v_h.mod_square(m).mod_sub(q_h.mod_add(q_h, m), m);
+        } else {
+            q_h = q_l;
+            u_h = u_h.mod_mul(v_l, m).mod_sub(q_l, m);
+            v_h = v_h.mod_mul(v_l, m).mod_sub(p.mod_mul(q_l, m), m);
+            v_l = v_l.mod_square(m).mod_sub(q_l.mod_add(q_l, m), m);
+        }
+    }
+    q_l.mod_mul_assign(q_h, m);
+    q_h = q_l.mod_mul(q, m);
+    u_h = u_h.mod_mul(v_l, m).mod_sub(q_l, m);
+    v_l = v_h.mod_mul(v_l, m).mod_sub(p.mod_mul(q_l, m), m);
+    q_l.mod_mul_assign(q_h, m);
+    for _ in 0..s {
+        u_h.mod_mul_assign(v_l, m);
+        v_l = v_l.mod_square(m).mod_sub(q_l.mod_add(q_l, m), m);
+        q_l.mod_square_assign(m);
+    }
+    (u_h, v_l, q_l)
+}
+
+macro_rules! impl_lucas {
+    ($t:ident) => {
+        impl CheckedLucas for $t {
+            /// Computes the $n$th Lucas number.
+            ///
+            /// If the input is too large, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(L_n) & \text{if} \\quad L_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad L_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`, $L_0 = 2$, $L_1 = 1$, and $L_{n+2} = L_{n+1} + L_n$.
+            ///
+            /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::lucas#checked_lucas).
+            #[inline]
+            fn checked_lucas(n: u64) -> Option<$t> {
+                checked_lucas(n)
+            }
+
+            /// Computes the $n$th and $(n+1)$th Lucas numbers.
+            ///
+            /// If either output is too large, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}((L_n, L_{n+1})) & \text{if} \\quad L_n, L_{n+1} < 2^W, \\\\
+            ///     \operatorname{None} & \text{otherwise},
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::lucas#checked_lucas_pair).
+            #[inline]
+            fn checked_lucas_pair(n: u64) -> Option<($t, $t)> {
+                checked_fibonacci_like_pair(n, 2, 1)
+            }
+        }
+
+        impl Lucas for $t {
+            /// Computes the $n$th Lucas number.
+            ///
+            /// If the input is too large, the function panics. For a function that returns `None`
+            /// instead, try [`checked_lucas`](CheckedLucas::checked_lucas).
+            ///
+            /// $$
+            /// f(n) = L_n,
+            /// $$
+            /// where $L_0 = 2$, $L_1 = 1$, and $L_{n+2} = L_{n+1} + L_n$.
+            ///
+            /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::lucas#lucas).
+            #[inline]
+            fn lucas(n: u64) -> $t {
+                $t::checked_lucas(n).unwrap()
+            }
+
+            /// Computes the $n$th and $(n+1)$th Lucas numbers.
+            ///
+            /// If either output is too large, the function panics. For a function that returns
+            /// `None` instead, try [`checked_lucas_pair`](CheckedLucas::checked_lucas_pair).
+            ///
+            /// $$
+            /// f(n) = (L_n, L_{n+1}).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if either output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::lucas#lucas_pair).
+            #[inline]
+            fn lucas_pair(n: u64) -> ($t, $t) {
+                $t::checked_lucas_pair(n).unwrap()
+            }
+        }
+
+        impl LucasMod for $t {
+            /// Computes the $n$th Lucas number modulo $m$.
+            ///
+            /// $$
+            /// f(n, m) = L_n \bmod m.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(\log n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::lucas#lucas_mod).
+            #[inline]
+            fn lucas_mod(n: u64, m: $t) -> $t {
+                lucas_mod(n, m)
+            }
+        }
+
+        impl LucasSequenceMod for $t {
+            type Output = $t;
+
+            /// Computes $U_k(P, Q)$ and $V_k(P, Q)$ modulo $m$, where $k$ is `self`.
+            ///
+            /// The Lucas sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and
+            /// $X_{k+2} = PX_{k+1} - QX_k$ for $X \in \\{U, V\\}$. Since the inputs are unsigned, a
+            /// negative $Q$ should be passed as its residue modulo $m$. $P$ and $Q$ need not be
+            /// reduced. $U_k(1, -1)$ and $V_k(1, -1)$ are the Fibonacci and Lucas numbers.
+            ///
+            /// $$
+            /// f(k, P, Q, m) = (U_k(P, Q) \bmod m, V_k(P, Q) \bmod m).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `m` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::lucas#lucas_sequence_mod).
+            fn lucas_sequence_mod(self, p: $t, q: $t, m: $t) -> ($t, $t) {
+                assert_ne!(m, 0, "Cannot reduce modulo 0");
+                let (u, v, _) = lucas_sequence_mod_helper(self, p % m, q % m, m);
+                (u, v)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_lucas);
@@ -136,7 +136,12 @@ pub mod arithmetic_checked_shr;
 /// implementations panic if the result cannot be represented, and a checked trait whose
 /// implementations return `None` in that case:
 /// [`BinomialCoefficient`](traits::BinomialCoefficient) and
-/// [`CheckedBinomialCoefficient`](traits::CheckedBinomialCoefficient).
+/// [`CheckedBinomialCoefficient`](traits::CheckedBinomialCoefficient). There is also a trait for
+/// computing binomial coefficients modulo a number,
+/// [`BinomialCoefficientMod`](traits::BinomialCoefficientMod), and analogous traits for
+/// multinomial coefficients: [`MultinomialCoefficient`](traits::MultinomialCoefficient),
+/// [`CheckedMultinomialCoefficient`](traits::CheckedMultinomialCoefficient), and
+/// [`MultinomialCoefficientMod`](traits::MultinomialCoefficientMod).
 ///
 /// # binomial_coefficient
 /// ```
@@ -172,7 +177,81 @@ pub mod arithmetic_checked_shr;
 /// assert_eq!(i8::checked_binomial_coefficient(-3, -3), None);
 /// assert_eq!(i8::checked_binomial_coefficient(11, 5), None);
 /// ```
+///
+/// # binomial_coefficient_mod
+/// ```
+/// use malachite_base::num::arithmetic::traits::BinomialCoefficientMod;
+///
+/// assert_eq!(u8::binomial_coefficient_mod(10, 5, 7), 0);
+/// assert_eq!(u8::binomial_coefficient_mod(10, 5, 100), 52);
+/// assert_eq!(u8::binomial_coefficient_mod(3, 5, 100), 0);
+/// assert_eq!(u16::binomial_coefficient_mod(1000, 500, 1000), 320);
+/// assert_eq!(u16::binomial_coefficient_mod(50, 25, 1024), 376);
+/// assert_eq!(u32::binomial_coefficient_mod(100, 50, 1000000007), 538992043);
+/// ```
+///
+/// # multinomial_coefficient
+/// ```
+/// use malachite_base::num::arithmetic::traits::MultinomialCoefficient;
+///
+/// assert_eq!(u8::multinomial_coefficient(&[]), 1);
+/// assert_eq!(u8::multinomial_coefficient(&[5]), 1);
+/// assert_eq!(u8::multinomial_coefficient(&[2, 3]), 10);
+/// assert_eq!(u8::multinomial_coefficient(&[1, 2, 3]), 60);
+/// assert_eq!(u16::multinomial_coefficient(&[2, 2, 2, 2]), 2520);
+/// assert_eq!(u32::multinomial_coefficient(&[3, 4, 5]), 27720);
+/// ```
+///
+/// # checked_multinomial_coefficient
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedMultinomialCoefficient;
+///
+/// assert_eq!(u8::checked_multinomial_coefficient(&[]), Some(1));
+/// assert_eq!(u8::checked_multinomial_coefficient(&[1, 2, 3]), Some(60));
+/// assert_eq!(u8::checked_multinomial_coefficient(&[2, 2, 2, 2]), None);
+/// assert_eq!(u8::checked_multinomial_coefficient(&[200, 100]), None);
+/// assert_eq!(u16::checked_multinomial_coefficient(&[2, 2, 2, 2]), Some(2520));
+/// ```
+///
+/// # multinomial_coefficient_mod
+/// ```
+/// use malachite_base::num::arithmetic::traits::MultinomialCoefficientMod;
+///
+/// assert_eq!(u8::multinomial_coefficient_mod(&[1, 2, 3], 7), 4);
+/// assert_eq!(u32::multinomial_coefficient_mod(&[5, 5, 5, 5], 1000000007), 732744947);
+/// assert_eq!(
+///     u32::multinomial_coefficient_mod(&[100, 200, 300], 1000000007),
+///     756111920
+/// );
+/// ```
 pub mod binomial_coefficient;
+/// [`CarmichaelLambda`](traits::CarmichaelLambda), a trait for computing the Carmichael function
+/// $\lambda$ of a number, and
+/// [`carmichael_lambda_table`](carmichael_lambda::carmichael_lambda_table), a function for
+/// computing $\lambda$ of every number up to some bound.
+///
+/// # carmichael_lambda
+/// ```
+/// use malachite_base::num::arithmetic::traits::CarmichaelLambda;
+///
+/// assert_eq!(1u8.carmichael_lambda(), 1);
+/// assert_eq!(8u8.carmichael_lambda(), 2);
+/// assert_eq!(15u8.carmichael_lambda(), 4);
+/// assert_eq!(561u16.carmichael_lambda(), 80);
+/// assert_eq!(65537u32.carmichael_lambda(), 65536);
+/// assert_eq!(u32::MAX.carmichael_lambda(), 65536);
+/// assert_eq!((1u64 << 63).carmichael_lambda(), 1 << 61);
+/// ```
+///
+/// # carmichael_lambda_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::CarmichaelLambda;
+///
+/// assert_eq!(u8::carmichael_lambda_from_factors(&[]), 1);
+/// assert_eq!(u16::carmichael_lambda_from_factors(&[(3, 1), (11, 1), (17, 1)]), 80);
+/// assert_eq!(u64::carmichael_lambda_from_factors(&[(2, 63)]), 1 << 61);
+/// ```
+pub mod carmichael_lambda;
 /// [`Ceiling`](traits::Ceiling) and [`CeilingAssign`](traits::CeilingAssign), traits for computing
 /// the ceiling of a number.
 ///
@@ -267,6 +346,41 @@ pub mod checked_sub_mul;
 /// assert_eq!(6u8.coprime_with(35), true);
 /// ```
 pub mod coprime_with;
+/// [`Crt`](traits::Crt) and [`CrtPrecomputed`](traits::CrtPrecomputed), traits for solving
+/// systems of simultaneous congruences using the Chinese Remainder Theorem.
+///
+/// # crt
+/// ```
+/// use malachite_base::num::arithmetic::traits::Crt;
+///
+/// assert_eq!(u32::crt(&[]), Some((0, 1)));
+/// assert_eq!(u32::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(u32::crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+/// assert_eq!(u32::crt(&[(3, 4), (4, 6)]), None);
+/// assert_eq!(u8::crt(&[(1, 16), (2, 17)]), None);
+/// assert_eq!(i32::crt(&[(-1, 3), (-1, 5)]), Some((14, 15)));
+/// ```
+///
+/// # precompute_crt_data
+/// ```
+/// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+///
+/// let basis = u32::precompute_crt_data(&[3, 5, 7]).unwrap();
+/// assert_eq!(basis.modulus(), 105);
+/// assert!(u32::precompute_crt_data(&[4, 6]).is_none());
+/// assert!(u8::precompute_crt_data(&[16, 17]).is_none());
+/// ```
+///
+/// # crt_precomputed
+/// ```
+/// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+///
+/// let basis = u32::precompute_crt_data(&[3, 5, 7]).unwrap();
+/// assert_eq!(u32::crt_precomputed(&[2, 3, 2], &basis), 23);
+/// assert_eq!(u32::crt_precomputed(&[0, 0, 0], &basis), 0);
+/// assert_eq!(u32::crt_precomputed(&[5, 8, 9], &basis), 23);
+/// ```
+pub mod crt;
 /// [`DivExact`](traits::DivExact) and [`DivExactAssign`](traits::DivExactAssign), traits for
 /// dividing two numbers when it's known that the division is exact.
 ///
@@ -655,6 +769,104 @@ pub mod divisible_by;
 /// assert_eq!(96i32.divisible_by_power_of_2(6), false);
 /// ```
 pub mod divisible_by_power_of_2;
+/// [`DivisorCount`](traits::DivisorCount), [`DivisorSigma`](traits::DivisorSigma), and
+/// [`CheckedDivisorSigma`](traits::CheckedDivisorSigma), traits for counting the divisors of a
+/// number and for summing powers of its divisors; and
+/// [`divisor_count_table`](divisor_sigma::divisor_count_table) and
+/// [`divisor_sigma_table`](divisor_sigma::divisor_sigma_table), functions for computing these of
+/// every number up to some bound.
+///
+/// # divisor_count
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivisorCount;
+///
+/// assert_eq!(1u8.divisor_count(), 1);
+/// assert_eq!(12u8.divisor_count(), 6);
+/// assert_eq!(97u8.divisor_count(), 2);
+/// assert_eq!(360u16.divisor_count(), 24);
+/// assert_eq!(720720u32.divisor_count(), 240);
+/// assert_eq!(u64::MAX.divisor_count(), 128);
+/// ```
+///
+/// # divisor_count_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivisorCount;
+///
+/// assert_eq!(u8::divisor_count_from_factors(&[]), 1);
+/// assert_eq!(u16::divisor_count_from_factors(&[(2, 3), (3, 2), (5, 1)]), 24);
+/// ```
+///
+/// # divisor_sigma
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivisorSigma;
+///
+/// assert_eq!(1u8.divisor_sigma(1), 1);
+/// assert_eq!(12u8.divisor_sigma(0), 6);
+/// assert_eq!(12u8.divisor_sigma(1), 28);
+/// assert_eq!(12u8.divisor_sigma(2), 210);
+/// assert_eq!(360u16.divisor_sigma(1), 1170);
+/// assert_eq!((1u64 << 63).divisor_sigma(1), u64::MAX);
+/// ```
+///
+/// # divisor_sigma_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivisorSigma;
+///
+/// assert_eq!(u8::divisor_sigma_from_factors(&[], 1), 1);
+/// assert_eq!(u16::divisor_sigma_from_factors(&[(2, 3), (3, 2), (5, 1)], 1), 1170);
+/// assert_eq!(u32::divisor_sigma_from_factors(&[(2, 3), (3, 2), (5, 1)], 2), 201110);
+/// ```
+///
+/// # checked_divisor_sigma
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedDivisorSigma;
+///
+/// assert_eq!(12u8.checked_divisor_sigma(1), Some(28));
+/// assert_eq!(12u8.checked_divisor_sigma(2), Some(210));
+/// assert_eq!(12u8.checked_divisor_sigma(3), None);
+/// assert_eq!(u8::MAX.checked_divisor_sigma(1), None);
+/// assert_eq!((1u64 << 63).checked_divisor_sigma(1), Some(u64::MAX));
+/// assert_eq!(u64::MAX.checked_divisor_sigma(1), None);
+/// ```
+///
+/// # checked_divisor_sigma_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedDivisorSigma;
+///
+/// assert_eq!(u8::checked_divisor_sigma_from_factors(&[(2, 2), (3, 1)], 1), Some(28));
+/// assert_eq!(u8::checked_divisor_sigma_from_factors(&[(2, 2), (3, 1)], 3), None);
+/// ```
+pub mod divisor_sigma;
+/// [`Divisors`](traits::Divisors), a trait for generating the divisors of a number in ascending
+/// order.
+///
+/// # divisors
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::Divisors;
+///
+/// assert_eq!(1u8.divisors().collect_vec(), &[1]);
+/// assert_eq!(12u8.divisors().collect_vec(), &[1, 2, 3, 4, 6, 12]);
+/// assert_eq!(97u8.divisors().collect_vec(), &[1, 97]);
+/// assert_eq!(
+///     360u16.divisors().collect_vec(),
+///     &[1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 18, 20, 24, 30, 36, 40, 45, 60, 72, 90, 120, 180, 360]
+/// );
+/// assert_eq!(
+///     u32::MAX.divisors().rev().take(4).collect_vec(),
+///     &[4294967295, 1431655765, 858993459, 286331153]
+/// );
+/// ```
+///
+/// # divisors_from_factors
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::Divisors;
+///
+/// assert_eq!(u8::divisors_from_factors(&[]).collect_vec(), &[1]);
+/// assert_eq!(u8::divisors_from_factors(&[(3, 1), (2, 2)]).collect_vec(), &[1, 2, 3, 4, 6, 12]);
+/// ```
+pub mod divisors;
 /// [`EqMod`](traits::EqMod), a trait for determining whether one number is equal by another
 /// modulo a third.
 ///
@@ -699,7 +911,8 @@ pub mod extended_gcd;
 /// [`CheckedFactorial`](traits::CheckedFactorial),
 /// [`CheckedDoubleFactorial`](traits::CheckedDoubleFactorial),
 /// [`CheckedMultifactorial`](traits::CheckedMultifactorial), and
-/// [`CheckedSubfactorial`](traits::CheckedSubfactorial).
+/// [`CheckedSubfactorial`](traits::CheckedSubfactorial). There is also
+/// [`FactorialMod`](traits::FactorialMod), which computes the factorial modulo a number.
 ///
 /// # factorial
 /// ```
@@ -868,7 +1081,75 @@ pub mod extended_gcd;
 /// assert_eq!(u32::checked_subfactorial(10), Some(1334961));
 /// assert_eq!(u32::checked_subfactorial(100), None);
 /// ```
+///
+/// # factorial_mod
+/// ```
+/// use malachite_base::num::arithmetic::traits::FactorialMod;
+///
+/// assert_eq!(u8::factorial_mod(0, 10), 1);
+/// assert_eq!(u8::factorial_mod(4, 10), 4);
+/// assert_eq!(u8::factorial_mod(5, 10), 0);
+/// assert_eq!(u16::factorial_mod(10, 1000), 800);
+/// assert_eq!(u32::factorial_mod(20, 1000000007), 146326063);
+/// assert_eq!(u64::factorial_mod(1000, 1000000007), 641419708);
+/// ```
 pub mod factorial;
+/// [`Fibonacci`](traits::Fibonacci), [`CheckedFibonacci`](traits::CheckedFibonacci), and
+/// [`FibonacciMod`](traits::FibonacciMod), traits for computing Fibonacci numbers.
+///
+/// # checked_fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci(0), Some(0));
+/// assert_eq!(u8::checked_fibonacci(1), Some(1));
+/// assert_eq!(u8::checked_fibonacci(10), Some(55));
+/// assert_eq!(u8::checked_fibonacci(13), Some(233));
+/// assert_eq!(u8::checked_fibonacci(14), None);
+/// assert_eq!(u64::checked_fibonacci(93), Some(12200160415121876738));
+/// assert_eq!(u64::checked_fibonacci(94), None);
+/// ```
+///
+/// # checked_fibonacci_pair
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci_pair(0), Some((0, 1)));
+/// assert_eq!(u8::checked_fibonacci_pair(10), Some((55, 89)));
+/// assert_eq!(u8::checked_fibonacci_pair(12), Some((144, 233)));
+/// assert_eq!(u8::checked_fibonacci_pair(13), None);
+/// ```
+///
+/// # fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci(0), 0);
+/// assert_eq!(u8::fibonacci(1), 1);
+/// assert_eq!(u8::fibonacci(2), 1);
+/// assert_eq!(u8::fibonacci(10), 55);
+/// assert_eq!(u32::fibonacci(40), 102334155);
+/// ```
+///
+/// # fibonacci_pair
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci_pair(0), (0, 1));
+/// assert_eq!(u8::fibonacci_pair(10), (55, 89));
+/// assert_eq!(u32::fibonacci_pair(40), (102334155, 165580141));
+/// ```
+///
+/// # fibonacci_mod
+/// ```
+/// use malachite_base::num::arithmetic::traits::FibonacciMod;
+///
+/// assert_eq!(u8::fibonacci_mod(0, 10), 0);
+/// assert_eq!(u8::fibonacci_mod(10, 10), 5);
+/// assert_eq!(u32::fibonacci_mod(100, 1000000007), 687995182);
+/// assert_eq!(u64::fibonacci_mod(1000000000000, 1000000007), 730695249);
+/// ```
+pub mod fibonacci;
 /// [`Floor`](traits::Floor) and [`FloorAssign`](traits::FloorAssign), traits for computing the
 /// floor of a number.
 ///
@@ -885,8 +1166,8 @@ pub mod factorial;
 /// assert_eq!(x, -2.0);
 /// ```
 pub mod floor;
-/// [`Gcd`](traits::Gcd) and [`GcdAssign`](traits::GcdAssign), traits for computing the GCD
-/// (greatest common divisor) of two numbers.
+/// [`Gcd`](traits::Gcd), [`GcdAssign`](traits::GcdAssign), and [`GcdOf`](traits::GcdOf), traits
+/// for computing the GCD (greatest common divisor) of two or more numbers.
 ///
 /// # gcd
 /// ```
@@ -908,6 +1189,15 @@ pub mod floor;
 /// x.gcd_assign(90);
 /// assert_eq!(x, 6);
 /// ```
+///
+/// # gcd_of
+/// ```
+/// use malachite_base::num::arithmetic::traits::GcdOf;
+///
+/// assert_eq!(u8::gcd_of([].into_iter()), 0);
+/// assert_eq!(u16::gcd_of([12, 90, 42].into_iter()), 6);
+/// assert_eq!(u32::gcd_of([6, 10, 15].into_iter()), 1);
+/// ```
 pub mod gcd;
 /// [`IsPowerOf2`](traits::IsPowerOf2), a trait for determining whether a number is an integer
 /// power of 2.
@@ -969,8 +1259,9 @@ pub mod is_power_of_2;
 /// assert_eq!((-11i8).kronecker_symbol(-8), 1);
 /// ```
 pub mod kronecker_symbol;
-/// [`Lcm`](traits::Lcm), [`LcmAssign`](traits::LcmAssign), and [`CheckedLcm`](traits::CheckedLcm),
-/// traits for computing the LCM (least common multiple) of two numbers.
+/// [`Lcm`](traits::Lcm), [`LcmAssign`](traits::LcmAssign), [`CheckedLcm`](traits::CheckedLcm),
+/// and [`LcmOf`](traits::LcmOf), traits for computing the LCM (least common multiple) of two or
+/// more numbers.
 ///
 /// # lcm
 /// ```
@@ -1001,6 +1292,15 @@ pub mod kronecker_symbol;
 /// assert_eq!(12u16.checked_lcm(90), Some(180));
 /// assert_eq!(120u8.checked_lcm(90), None);
 /// ```
+///
+/// # lcm_of
+/// ```
+/// use malachite_base::num::arithmetic::traits::LcmOf;
+///
+/// assert_eq!(u8::lcm_of([].into_iter()), 1);
+/// assert_eq!(u16::lcm_of([12, 90, 42].into_iter()), 1260);
+/// assert_eq!(u32::lcm_of([6, 0, 15].into_iter()), 0);
+/// ```
 pub mod lcm;
 /// Traits for taking the base-$b$ logarithm of a number.
 ///
@@ -1122,6 +1422,70 @@ pub mod log_base_2;
 /// assert_eq!(0.0625f32.checked_log_base_power_of_2(2), Some(-2));
 /// ```
 pub mod log_base_power_of_2;
+/// [`Lucas`](traits::Lucas), [`CheckedLucas`](traits::CheckedLucas),
+/// [`LucasMod`](traits::LucasMod), and [`LucasSequenceMod`](traits::LucasSequenceMod), traits for
+/// computing Lucas numbers and Lucas sequences.
+///
+/// # checked_lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedLucas;
+///
+/// assert_eq!(u8::checked_lucas(0), Some(2));
+/// assert_eq!(u8::checked_lucas(1), Some(1));
+/// assert_eq!(u8::checked_lucas(10), Some(123));
+/// assert_eq!(u8::checked_lucas(11), Some(199));
+/// assert_eq!(u8::checked_lucas(12), None);
+/// ```
+///
+/// # checked_lucas_pair
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedLucas;
+///
+/// assert_eq!(u8::checked_lucas_pair(0), Some((2, 1)));
+/// assert_eq!(u8::checked_lucas_pair(10), Some((123, 199)));
+/// assert_eq!(u8::checked_lucas_pair(11), None);
+/// ```
+///
+/// # lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::Lucas;
+///
+/// assert_eq!(u8::lucas(0), 2);
+/// assert_eq!(u8::lucas(1), 1);
+/// assert_eq!(u8::lucas(2), 3);
+/// assert_eq!(u8::lucas(10), 123);
+/// assert_eq!(u32::lucas(40), 228826127);
+/// ```
+///
+/// # lucas_pair
+/// ```
+/// use malachite_base::num::arithmetic::traits::Lucas;
+///
+/// assert_eq!(u8::lucas_pair(0), (2, 1));
+/// assert_eq!(u8::lucas_pair(10), (123, 199));
+/// ```
+///
+/// # lucas_mod
+/// ```
+/// use malachite_base::num::arithmetic::traits::LucasMod;
+///
+/// assert_eq!(u8::lucas_mod(0, 10), 2);
+/// assert_eq!(u8::lucas_mod(0, 1), 0);
+/// assert_eq!(u8::lucas_mod(10, 10), 3);
+/// assert_eq!(u32::lucas_mod(100, 1000000007), 876413006);
+/// ```
+///
+/// # lucas_sequence_mod
+/// ```
+/// use malachite_base::num::arithmetic::traits::LucasSequenceMod;
+///
+/// // With P = 1 and Q = -1, U_k and V_k are the Fibonacci and Lucas numbers.
+/// assert_eq!(10u16.lucas_sequence_mod(1, 255, 256), (55, 123));
+/// // With P = 3 and Q = 2, U_k = 2^k - 1 and V_k = 2^k + 1.
+/// assert_eq!(10u32.lucas_sequence_mod(3, 2, 1000000), (1023, 1025));
+/// assert_eq!(100u64.lucas_sequence_mod(3, 2, 1000000007), (976371284, 976371286));
+/// ```
+pub mod lucas;
 /// [`ModAdd`](traits::ModAdd) and [`ModAddAssign`](traits::ModAddAssign), traits for adding two
 /// numbers modulo another number.
 ///
@@ -1146,6 +1510,34 @@ pub mod log_base_power_of_2;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_add;
+/// [`ModCentered`](traits::ModCentered) and [`ModCenteredAssign`](traits::ModCenteredAssign),
+/// traits for finding the residue of a number modulo another number $m$ that lies in
+/// $(-m/2, m/2]$.
+///
+/// # mod_centered
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModCentered;
+///
+/// assert_eq!(7i8.mod_centered(10), -3);
+/// assert_eq!(5i16.mod_centered(10), 5);
+/// assert_eq!((-5i32).mod_centered(10), 5);
+/// assert_eq!((-6i64).mod_centered(10), 4);
+/// assert_eq!(13i8.mod_centered(10), 3);
+/// ```
+///
+/// # mod_centered_assign
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModCenteredAssign;
+///
+/// let mut n = 7i8;
+/// n.mod_centered_assign(10);
+/// assert_eq!(n, -3);
+///
+/// let mut n = -6i64;
+/// n.mod_centered_assign(10);
+/// assert_eq!(n, 4);
+/// ```
+pub mod mod_centered;
 /// [`ModInverse`](traits::ModInverse), a trait for finding the multiplicative inverse of a number
 /// modulo another number.
 ///
@@ -1170,6 +1562,25 @@ pub mod mod_inverse;
 /// assert_eq!(100u16.mod_is_reduced(&101), true);
 /// ```
 pub mod mod_is_reduced;
+/// [`ModLog`](traits::ModLog), a trait for finding the discrete logarithm of a number to a given
+/// base, modulo another number.
+///
+/// # mod_log
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModLog;
+///
+/// assert_eq!(3u8.mod_log(2, 7), None);
+/// assert_eq!(2u8.mod_log(3, 7), Some(2));
+/// assert_eq!(10u16.mod_log(2, 13), Some(10));
+/// assert_eq!(4u8.mod_log(2, 12), Some(2));
+/// assert_eq!(3u8.mod_log(2, 8), None);
+/// assert_eq!(12345u32.mod_log(5, 1000000007), Some(775972150));
+/// assert_eq!(
+///     123456789u64.mod_log(2, 18446744073709551557),
+///     Some(13588326508526149906)
+/// );
+/// ```
+pub mod mod_log;
 /// Traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](traits::ModMul), [`ModMulAssign`](traits::ModMulAssign),
@@ -1992,6 +2403,64 @@ pub mod mod_shl;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_shr;
+/// [`ModSqrt`](traits::ModSqrt), a trait for finding square roots of a number modulo another
+/// number.
+///
+/// # mod_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(0u8.mod_sqrt(7), Some(0));
+/// assert_eq!(2u8.mod_sqrt(7), Some(3));
+/// assert_eq!(3u8.mod_sqrt(7), None);
+/// assert_eq!(3u32.mod_sqrt(1000000007), Some(82062379));
+/// assert_eq!(2u64.mod_sqrt(18446744073709551557), None);
+/// assert_eq!(
+///     2u128.mod_sqrt(170141183460469231731687303715884105727),
+///     Some(18446744073709551616)
+/// );
+/// ```
+///
+/// # mod_sqrts
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(3u8.mod_sqrts(7), &[]);
+/// assert_eq!(10u8.mod_sqrts(13), &[6, 7]);
+/// assert_eq!(4u8.mod_sqrts(8), &[2, 6]);
+/// assert_eq!(0u8.mod_sqrts(16), &[0, 4, 8, 12]);
+/// assert_eq!(4u16.mod_sqrts(15), &[2, 7, 8, 13]);
+/// assert_eq!(17u32.mod_sqrts(1 << 20), &[206569, 317719, 730857, 842007]);
+/// ```
+///
+/// # mod_sqrt_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(0u8.mod_sqrt_from_factors(&[]), Some(0));
+/// assert_eq!(2u8.mod_sqrt_from_factors(&[(7, 1)]), Some(3));
+/// assert_eq!(3u8.mod_sqrt_from_factors(&[(7, 1)]), None);
+/// assert_eq!(3u32.mod_sqrt_from_factors(&[(3, 1), (5, 1)]), None);
+///
+/// let x = 1u32.mod_sqrt_from_factors(&[(3, 1), (5, 1)]).unwrap();
+/// assert_eq!(x * x % 15, 1);
+/// ```
+///
+/// # mod_sqrts_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(0u8.mod_sqrts_from_factors(&[]), &[0]);
+/// assert_eq!(1u8.mod_sqrts_from_factors(&[(3, 1), (5, 1)]), &[1, 4, 11, 14]);
+/// assert_eq!(
+///     36u16.mod_sqrts_from_factors(&[(2, 3), (3, 4), (5, 1)]),
+///     &[
+///         6, 114, 426, 534, 546, 654, 966, 1074, 1086, 1194, 1506, 1614, 1626, 1734, 2046, 2154,
+///         2166, 2274, 2586, 2694, 2706, 2814, 3126, 3234
+///     ]
+/// );
+/// ```
+pub mod mod_sqrt;
 /// Traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](traits::ModSquare), [`ModSquareAssign`](traits::ModSquareAssign),
@@ -2073,6 +2542,63 @@ pub mod mod_square;
 /// assert_eq!(n, 8);
 /// ```
 pub mod mod_sub;
+/// [`MoebiusMu`](traits::MoebiusMu), a trait for computing the Möbius function $\mu$ of a number,
+/// and [`moebius_mu_table`](moebius_mu::moebius_mu_table), a function for computing $\mu$ of every
+/// number up to some bound.
+///
+/// # moebius_mu
+/// ```
+/// use malachite_base::num::arithmetic::traits::MoebiusMu;
+///
+/// assert_eq!(1u8.moebius_mu(), 1);
+/// assert_eq!(2u8.moebius_mu(), -1);
+/// assert_eq!(4u8.moebius_mu(), 0);
+/// assert_eq!(6u8.moebius_mu(), 1);
+/// assert_eq!(30u8.moebius_mu(), -1);
+/// assert_eq!(65535u16.moebius_mu(), 1);
+/// assert_eq!(u32::MAX.moebius_mu(), -1);
+/// ```
+///
+/// # moebius_mu_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::MoebiusMu;
+///
+/// assert_eq!(u8::moebius_mu_from_factors(&[]), 1);
+/// assert_eq!(u8::moebius_mu_from_factors(&[(2, 1), (3, 1)]), 1);
+/// assert_eq!(u8::moebius_mu_from_factors(&[(2, 1), (3, 2)]), 0);
+/// assert_eq!(u64::moebius_mu_from_factors(&[(3, 1), (5, 1), (17, 1)]), -1);
+/// ```
+pub mod moebius_mu;
+/// [`MultiplicativeOrder`](traits::MultiplicativeOrder), a trait for computing the multiplicative
+/// order of a number modulo another number.
+///
+/// # multiplicative_order
+/// ```
+/// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+///
+/// assert_eq!(0u8.multiplicative_order(1), Some(1));
+/// assert_eq!(1u8.multiplicative_order(7), Some(1));
+/// assert_eq!(2u8.multiplicative_order(7), Some(3));
+/// assert_eq!(3u8.multiplicative_order(7), Some(6));
+/// assert_eq!(6u8.multiplicative_order(9), None);
+/// assert_eq!(10u16.multiplicative_order(21), Some(6));
+/// assert_eq!(5u32.multiplicative_order(4294967291), Some(2147483645));
+/// assert_eq!(2u32.multiplicative_order(1000000007), Some(500000003));
+/// assert_eq!(
+///     2u64.multiplicative_order(18446744073709551557),
+///     Some(18446744073709551556)
+/// );
+/// ```
+///
+/// # multiplicative_order_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+///
+/// assert_eq!(0u8.multiplicative_order_from_factors(&[]), Some(1));
+/// assert_eq!(10u16.multiplicative_order_from_factors(&[(3, 1), (7, 1)]), Some(6));
+/// assert_eq!(3u32.multiplicative_order_from_factors(&[(998244353, 1)]), Some(998244352));
+/// ```
+pub mod multiplicative_order;
 /// [`NegAssign`](traits::NegAssign), a trait for negating a number in place.
 ///
 /// # neg_assign
@@ -2398,6 +2924,46 @@ pub mod overflowing_sub_mul;
 /// assert_eq!(4u32.odd(), false);
 /// ```
 pub mod parity;
+/// [`IsPerfectPower`](traits::IsPerfectPower) and
+/// [`PerfectPowerDecompose`](traits::PerfectPowerDecompose), traits for determining whether a
+/// number is a perfect power and for writing it as a perfect power with the largest possible
+/// exponent.
+///
+/// # is_perfect_power
+/// ```
+/// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+///
+/// assert_eq!(0u8.is_perfect_power(), true);
+/// assert_eq!(1u8.is_perfect_power(), true);
+/// assert_eq!(2u8.is_perfect_power(), false);
+/// assert_eq!(8u8.is_perfect_power(), true);
+/// assert_eq!(12u16.is_perfect_power(), false);
+/// assert_eq!(1000000u32.is_perfect_power(), true);
+/// assert_eq!(u64::MAX.is_perfect_power(), false);
+///
+/// assert_eq!((-1i8).is_perfect_power(), true);
+/// assert_eq!((-4i8).is_perfect_power(), false);
+/// assert_eq!((-8i8).is_perfect_power(), true);
+/// assert_eq!(i64::MIN.is_perfect_power(), true);
+/// ```
+///
+/// # perfect_power_decompose
+/// ```
+/// use malachite_base::num::arithmetic::traits::PerfectPowerDecompose;
+///
+/// assert_eq!(0u8.perfect_power_decompose(), (0, 1));
+/// assert_eq!(1u8.perfect_power_decompose(), (1, 1));
+/// assert_eq!(12u8.perfect_power_decompose(), (12, 1));
+/// assert_eq!(64u8.perfect_power_decompose(), (2, 6));
+/// assert_eq!(1000000u32.perfect_power_decompose(), (10, 6));
+/// assert_eq!(3486784401u64.perfect_power_decompose(), (3, 20));
+///
+/// assert_eq!((-1i8).perfect_power_decompose(), (-1, 1));
+/// assert_eq!((-64i8).perfect_power_decompose(), (-4, 3));
+/// assert_eq!((-16i16).perfect_power_decompose(), (-16, 1));
+/// assert_eq!(i64::MIN.perfect_power_decompose(), (-2, 63));
+/// ```
+pub mod perfect_power;
 /// [`Pow`](traits::Pow) and [`PowAssign`](traits::PowAssign), traits for raising a number to a
 /// power.
 ///
@@ -2441,6 +3007,36 @@ pub mod pow;
 /// assert_eq!(f32::power_of_2(-3), 0.125);
 /// ```
 pub mod power_of_2;
+/// [`PrimitiveRoot`](traits::PrimitiveRoot), a trait for finding the smallest primitive root
+/// modulo a number.
+///
+/// # primitive_root
+/// ```
+/// use malachite_base::num::arithmetic::traits::PrimitiveRoot;
+///
+/// assert_eq!(1u8.primitive_root(), Some(0));
+/// assert_eq!(2u8.primitive_root(), Some(1));
+/// assert_eq!(4u8.primitive_root(), Some(3));
+/// assert_eq!(7u8.primitive_root(), Some(3));
+/// assert_eq!(8u8.primitive_root(), None);
+/// assert_eq!(15u8.primitive_root(), None);
+/// assert_eq!(18u8.primitive_root(), Some(5));
+/// assert_eq!(41u8.primitive_root(), Some(6));
+/// assert_eq!(65537u32.primitive_root(), Some(3));
+/// assert_eq!(998244353u32.primitive_root(), Some(3));
+/// assert_eq!(2305843009213693951u64.primitive_root(), Some(37));
+/// ```
+///
+/// # primitive_root_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::PrimitiveRoot;
+///
+/// assert_eq!(u8::primitive_root_from_factors(&[]), Some(0));
+/// assert_eq!(u8::primitive_root_from_factors(&[(2, 1), (3, 2)]), Some(5));
+/// assert_eq!(u8::primitive_root_from_factors(&[(3, 1), (5, 1)]), None);
+/// assert_eq!(u32::primitive_root_from_factors(&[(1000000007, 1)]), Some(5));
+/// ```
+pub mod primitive_root;
 /// Traits for computing the primorial and the product of the first $n$ primes. There is a trait
 /// whose implementations panic if the result cannot be represented, and a checked trait whose
 /// implementations return `None` in that case: [`Primorial`](traits::Primorial) and
@@ -3561,8 +4157,68 @@ pub mod square;
 /// assert_eq!(x, -5.0);
 /// ```
 pub mod sub_mul;
+/// [`Totient`](traits::Totient), a trait for computing Euler's totient function $\varphi$ of a
+/// number, and [`totient_table`](totient::totient_table), a function for computing $\varphi$ of
+/// every number up to some bound.
+///
+/// # totient
+/// ```
+/// use malachite_base::num::arithmetic::traits::Totient;
+///
+/// assert_eq!(1u8.totient(), 1);
+/// assert_eq!(2u8.totient(), 1);
+/// assert_eq!(12u8.totient(), 4);
+/// assert_eq!(97u8.totient(), 96);
+/// assert_eq!(65535u16.totient(), 32768);
+/// assert_eq!(u32::MAX.totient(), 2147483648);
+/// assert_eq!(u64::MAX.totient(), 9208981628670443520);
+/// ```
+///
+/// # totient_from_factors
+/// ```
+/// use malachite_base::num::arithmetic::traits::Totient;
+///
+/// assert_eq!(u8::totient_from_factors(&[]), 1);
+/// assert_eq!(u8::totient_from_factors(&[(2, 3), (3, 2)]), 24);
+/// assert_eq!(u64::totient_from_factors(&[(3, 1), (1000000007, 1)]), 2000000012);
+/// ```
+pub mod totient;
 /// Various traits for performing arithmetic operations on numbers.
 pub mod traits;
+/// [`Valuation`](traits::Valuation) and [`RemoveFactor`](traits::RemoveFactor), traits for
+/// finding the $p$-adic valuation of a number and for removing all factors of $p$ from it.
+///
+/// # valuation
+/// ```
+/// use malachite_base::num::arithmetic::traits::Valuation;
+///
+/// assert_eq!(1u8.valuation(2), 0);
+/// assert_eq!(12u8.valuation(2), 2);
+/// assert_eq!(12u8.valuation(5), 0);
+/// assert_eq!(243u8.valuation(3), 5);
+/// assert_eq!(1000000u32.valuation(10), 6);
+/// assert_eq!(1000000u32.valuation(100), 3);
+///
+/// assert_eq!((-12i8).valuation(2), 2);
+/// assert_eq!(i8::MIN.valuation(2), 7);
+/// assert_eq!((-1000000i32).valuation(5), 6);
+/// ```
+///
+/// # remove_factor
+/// ```
+/// use malachite_base::num::arithmetic::traits::RemoveFactor;
+///
+/// assert_eq!(1u8.remove_factor(2), (1, 0));
+/// assert_eq!(12u8.remove_factor(2), (3, 2));
+/// assert_eq!(12u8.remove_factor(5), (12, 0));
+/// assert_eq!(1000u32.remove_factor(10), (1, 3));
+/// assert_eq!(1200u32.remove_factor(20), (3, 2));
+///
+/// assert_eq!((-12i8).remove_factor(2), (-3, 2));
+/// assert_eq!(i8::MIN.remove_factor(2), (-1, 7));
+/// assert_eq!(i8::MIN.remove_factor(3), (i8::MIN, 0));
+/// ```
+pub mod valuation;
 /// [`WrappingAbs`](traits::WrappingAbs) and [`WrappingAbsAssign`](traits::WrappingAbsAssign),
 /// traits for computing the absolute value of a number and wrapping at the boundary of the type.
 ///
@@ -0,0 +1,59 @@
+use crate::num::arithmetic::traits::{ModCentered, ModCenteredAssign};
+use crate::num::basic::signeds::PrimitiveSigned;
+
+fn mod_centered<T: PrimitiveSigned>(x: T, m: T) -> T {
+    assert!(m > T::ZERO, "modulus must be positive");
+    let r = x.mod_op(m);
+    if r > m >> 1 {
+        r - m
+    } else {
+        r
+    }
+}
+
+macro_rules! impl_mod_centered {
+    ($t:ident) => {
+        impl ModCentered for $t {
+            type Output = $t;
+
+            /// Finds the centered residue of a number modulo another number $m$. The input need
+            /// not be reduced.
+            ///
+            /// $f(x, m) = y$, where $-m/2 < y \leq m/2$ and $x \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `m` is not positive.
+            ///
+            /// # Examples
+            /// See [here](super::mod_centered#mod_centered).
+            #[inline]
+            fn mod_centered(self, m: $t) -> $t {
+                mod_centered(self, m)
+            }
+        }
+
+        impl ModCenteredAssign for $t {
+            /// Replaces a number with its centered residue modulo another number $m$. The input
+            /// need not be reduced.
+            ///
+            /// $x \gets y$, where $-m/2 < y \leq m/2$ and $x \equiv y \mod m$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `m` is not positive.
+            ///
+            /// # Examples
+            /// See [here](super::mod_centered#mod_centered_assign).
+            #[inline]
+            fn mod_centered_assign(&mut self, m: $t) {
+                *self = mod_centered(*self, m);
+            }
+        }
+    };
+}
+apply_to_signeds!(impl_mod_centered);
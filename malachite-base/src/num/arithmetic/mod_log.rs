@@ -0,0 +1,249 @@
+use crate::num::arithmetic::mod_pow::mod_pow_wide;
+use crate::num::arithmetic::traits::ModLog;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use std::collections::HashMap;
+
+// Discrete logarithms in subgroups whose prime order has at most this many bits are found using
+// baby-step giant-step, which needs a table of about $2^{b/2}$ entries. Larger subgroups use
+// Pollard's rho algorithm, which needs constant memory.
+pub(crate) const MOD_LOG_BSGS_MAX_BITS: u64 = 32;
+
+// Returns the least $x$ such that $\gamma^x \equiv \beta \mod m$, or `None` if there is none,
+// using the baby-step giant-step algorithm. $\gamma$ must be coprime to $m$ and have order $n$.
+pub_test! {mod_log_baby_step_giant_step<T: PrimitiveUnsigned>(
+    gamma: T,
+    beta: T,
+    n: T,
+    m: T,
+) -> Option<T> {
+    let s = n.ceiling_sqrt();
+    let mut table = HashMap::new();
+    let mut x = T::ONE;
+    let mut j = T::ZERO;
+    while j < s {
+        table.entry(x).or_insert(j);
+        x.mod_mul_assign(gamma, m);
+        j += T::ONE;
+    }
+    // x is now gamma^s.
+    let giant_step = x.mod_inverse(m).unwrap();
+    let mut y = beta;
+    let mut i = T::ZERO;
+    while i < s {
+        if let Some(&j) = table.get(&y) {
+            return Some(i * s + j);
+        }
+        y.mod_mul_assign(giant_step, m);
+        i += T::ONE;
+    }
+    None
+}}
+
+// Returns $x$ such that $\gamma^x \equiv \beta \mod m$, using Pollard's rho algorithm. $\gamma$
+// must have prime order $q$ modulo $m$, and $\beta$ must be a power of $\gamma$; otherwise, this
+// function may not terminate.
+pub_test! {mod_log_pollard_rho<T: PrimitiveUnsigned>(gamma: T, beta: T, q: T, m: T) -> T {
+    // Each walk element is x = gamma^a * beta^b. The walk squares x, or multiplies it by gamma or
+    // beta, depending on x mod 3.
+    let three = T::exact_from(3);
+    let step = |x: &mut T, a: &mut T, b: &mut T| {
+        let r = *x % three;
+        if r == T::ZERO {
+            x.mod_square_assign(m);
+            *a = a.mod_add(*a, q);
+            *b = b.mod_add(*b, q);
+        } else if r == T::ONE {
+            x.mod_mul_assign(gamma, m);
+            a.mod_add_assign(T::ONE, q);
+        } else {
+            x.mod_mul_assign(beta, m);
+            b.mod_add_assign(T::ONE, q);
+        }
+    };
+    // If a walk fails, restart it from gamma^(start + 1) * beta.
+    let mut start = T::ONE;
+    let mut start_x = gamma.mod_mul(beta, m);
+    loop {
+        let mut x = start_x;
+        let mut a = start % q;
+        let mut b = T::ONE;
+        let mut y = x;
+        let mut c = a;
+        let mut d = b;
+        // Floyd's cycle-finding algorithm
+        loop {
+            step(&mut x, &mut a, &mut b);
+            step(&mut y, &mut c, &mut d);
+            step(&mut y, &mut c, &mut d);
+            if x == y {
+                break;
+            }
+        }
+        // gamma^a * beta^b = gamma^c * beta^d, so beta^(b - d) = gamma^(c - a).
+        if b != d {
+            let log = c.mod_sub(a, q).mod_mul(b.mod_sub(d, q).mod_inverse(q).unwrap(), q);
+            if mod_pow_wide(gamma, log, m) == beta {
+                return log;
+            }
+        }
+        start += T::ONE;
+        start_x.mod_mul_assign(gamma, m);
+    }
+}}
+
+// Returns the least $x$ such that $\gamma^x \equiv \beta \mod m$, or `None` if there is none.
+// $\gamma$ must have prime order $q$ modulo $m$, and the multiplicative group modulo $m$ must be
+// cyclic unless $q$ is small.
+fn mod_log_prime_order<T: PrimitiveUnsigned>(gamma: T, beta: T, q: T, m: T) -> Option<T> {
+    if beta == T::ONE {
+        Some(T::ZERO)
+    } else if q.significant_bits() <= MOD_LOG_BSGS_MAX_BITS {
+        mod_log_baby_step_giant_step(gamma, beta, q, m)
+    } else if mod_pow_wide(beta, q, m) != T::ONE {
+        // In a cyclic group, the elements whose order divides q are exactly the powers of gamma.
+        None
+    } else {
+        Some(mod_log_pollard_rho(gamma, beta, q, m))
+    }
+}
+
+// Returns the least $x$ such that $g^x \equiv h \mod p^k$, together with the order of $g$, or
+// `None` if there is no such $x$. $p$ must be prime, $g$ must be coprime to $p$, and `pk` must be
+// $p^k$.
+//
+// The order of $g$ is found from the factorization of $\varphi(p^k) = p^{k-1}(p-1)$, and then the
+// Pohlig-Hellman algorithm reduces the problem to one discrete logarithm in a subgroup of prime
+// order $q$ for each factor $q$ of the order.
+fn mod_log_prime_power<T: PrimitiveUnsigned>(g: T, h: T, p: T, k: u64, pk: T) -> Option<(T, T)> {
+    if h % p == T::ZERO {
+        return None;
+    }
+    let mut factors = if p == T::TWO {
+        Vec::new()
+    } else {
+        (p - T::ONE).factor()
+    };
+    if k > 1 {
+        factors.push((p, k - 1));
+    }
+    let mut n = pk / p * (p - T::ONE);
+    let mut order_factors = Vec::with_capacity(factors.len());
+    for (q, e) in factors {
+        let mut e = e;
+        while e != 0 && mod_pow_wide(g, n / q, pk) == T::ONE {
+            n /= q;
+            e -= 1;
+        }
+        if e != 0 {
+            order_factors.push((q, e));
+        }
+    }
+    let mut congruences = Vec::with_capacity(order_factors.len());
+    for (q, e) in order_factors {
+        let q_e = q.pow(e);
+        let cofactor = n / q_e;
+        // g_q has order q^e, and gamma has order q.
+        let g_q = mod_pow_wide(g, cofactor, pk);
+        let h_q = mod_pow_wide(h, cofactor, pk);
+        let gamma = mod_pow_wide(g_q, q_e / q, pk);
+        let g_q_inverse = g_q.mod_inverse(pk).unwrap();
+        // Find the base-q digits of the logarithm of h_q one at a time.
+        let mut x = T::ZERO;
+        let mut q_j = T::ONE;
+        for _ in 0..e {
+            let beta = mod_pow_wide(
+                mod_pow_wide(g_q_inverse, x, pk).mod_mul(h_q, pk),
+                q_e / q / q_j,
+                pk,
+            );
+            x += mod_log_prime_order(gamma, beta, q, pk)? * q_j;
+            q_j *= q;
+        }
+        congruences.push((x, q_e));
+    }
+    let x = T::crt(&congruences).unwrap().0;
+    if mod_pow_wide(g, x, pk) == h {
+        Some((x, n))
+    } else {
+        None
+    }
+}
+
+fn mod_log<T: PrimitiveUnsigned>(h: T, g: T, m: T) -> Option<T> {
+    assert!(h < m);
+    assert!(g < m);
+    // While g is not coprime to m, divide g^x = h mod m by gcd(g, m). This leaves an equation
+    // c * g^(x - k) = h' mod m', where g is coprime to m'. Smaller values of x are checked along
+    // the way, so the solution found is the least one.
+    let mut m = m;
+    let mut h = h;
+    let mut c = T::ONE % m;
+    let mut k = T::ZERO;
+    loop {
+        if h == c {
+            return Some(k);
+        }
+        let d = g.gcd(m);
+        if d == T::ONE {
+            break;
+        }
+        if h % d != T::ZERO {
+            return None;
+        }
+        m /= d;
+        h /= d;
+        k += T::ONE;
+        c = (c % m).mod_mul(g / d % m, m);
+    }
+    // Now c is coprime to m, and m > 1.
+    let g = g % m;
+    let h = h.mod_mul(c.mod_inverse(m).unwrap(), m);
+    let mut congruences = Vec::new();
+    for (p, e) in m.factor() {
+        let pe = p.pow(e);
+        congruences.push(mod_log_prime_power(g % pe, h % pe, p, e, pe)?);
+    }
+    Some(T::crt(&congruences)?.0 + k)
+}
+
+macro_rules! impl_mod_log {
+    ($t:ident) => {
+        impl ModLog<$t, $t> for $t {
+            type Output = $t;
+
+            /// Finds the discrete logarithm of a number to a given base, modulo another number
+            /// $m$. The inputs must be already reduced modulo $m$.
+            ///
+            /// Returns the least $x \geq 0$ such that $b^x \equiv y \mod m$, or `None` if there is
+            /// no such $x$. The base need not be coprime to $m$, and $m$ need not be prime.
+            ///
+            /// $f(y, b, m) = \min \\{x \in \N : b^x \equiv y \mod m\\}$.
+            ///
+            /// The modulus is factored, and the problem is split into problems modulo each prime
+            /// power, which are combined using the Chinese Remainder Theorem. Modulo each prime
+            /// power, the Pohlig-Hellman algorithm reduces the problem to subgroups of prime
+            /// order, where the logarithm is found using baby-step giant-step if the order is
+            /// small or Pollard's rho algorithm otherwise.
+            ///
+            /// # Expected complexity
+            /// $T(n, q) = O(n^2\sqrt{q})$
+            ///
+            /// $M(n, q) = O(\min(\sqrt{q}, 2^{16}))$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `Self::WIDTH`, and $q$ is the
+            /// largest prime factor of the order of the base. This excludes the time needed to
+            /// factor $m$ and $p - 1$ for each prime factor $p$ of $m$.
+            ///
+            /// # Panics
+            /// Panics if `self` or `base` are greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_log#mod_log).
+            #[inline]
+            fn mod_log(self, base: $t, m: $t) -> Option<$t> {
+                mod_log(self, base, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_log);
@@ -23,6 +23,23 @@ pub_test! {simple_binary_mod_pow<T: PrimitiveUnsigned>(x: T, exp: u64, m: T) ->
     out
 }}
 
+// Raises `x` to the power `exp` modulo `m`, where `exp` may not fit in a `u64`. `m` must be greater
+// than 1.
+pub(crate) fn mod_pow_wide<T: PrimitiveUnsigned>(x: T, exp: T, m: T) -> T {
+    let bits = exp.significant_bits();
+    if bits <= u64::WIDTH {
+        return x.mod_pow(exp.wrapping_into(), m);
+    }
+    let mut out = T::ONE;
+    for i in (0..bits).rev() {
+        out.mod_square_assign(m);
+        if exp.get_bit(i) {
+            out.mod_mul_assign(x, m);
+        }
+    }
+    out
+}
+
 // m.get_highest_bit(), x < m, y < m
 //
 // This is equivalent to `n_mulmod_preinv` from `ulong_extras/mulmod_preinv.c`, FLINT 2.7.1.
@@ -0,0 +1,361 @@
+use crate::num::arithmetic::mod_pow::mod_pow_wide;
+use crate::num::arithmetic::traits::{ModSqrt, Parity};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Factor;
+
+// Returns a square root of `a` modulo the odd prime `p`, using the Tonelli-Shanks algorithm. `a`
+// must be a nonzero quadratic residue modulo `p`.
+pub_test! {mod_sqrt_tonelli_shanks<T: PrimitiveUnsigned>(a: T, p: T) -> T {
+    let p_minus_1 = p - T::ONE;
+    let s = p_minus_1.trailing_zeros();
+    let q = p_minus_1 >> s;
+    // a^((q + 1) / 2)
+    let mut r = mod_pow_wide(a, (q >> 1) + T::ONE, p);
+    if s == 1 {
+        return r;
+    }
+    let mut z = T::TWO;
+    while z.legendre_symbol(p) != -1 {
+        z += T::ONE;
+    }
+    let mut c = mod_pow_wide(z, q, p);
+    let mut t = mod_pow_wide(a, q, p);
+    let mut m = s;
+    // Invariants: r^2 = a * t, c has order 2^m, and t has order dividing 2^(m - 1).
+    while t != T::ONE {
+        let mut i = 0;
+        let mut t_pow = t;
+        while t_pow != T::ONE {
+            t_pow.mod_square_assign(p);
+            i += 1;
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b.mod_square_assign(p);
+        }
+        r.mod_mul_assign(b, p);
+        c = b.mod_square(p);
+        t.mod_mul_assign(c, p);
+        m = i;
+    }
+    r
+}}
+
+// Returns a square root of `a` modulo the odd prime `p`, using Cipolla's algorithm. `a` must be a
+// nonzero quadratic residue modulo `p`.
+pub_test! {mod_sqrt_cipolla<T: PrimitiveUnsigned>(a: T, p: T) -> T {
+    // Find t such that t^2 - a is a nonresidue, and work in the field F_p(w), where w^2 = t^2 - a.
+    let mut t = T::ONE;
+    let d = loop {
+        let d = t.mod_square(p).mod_sub(a, p);
+        if d.legendre_symbol(p) == -1 {
+            break d;
+        }
+        t += T::ONE;
+    };
+    // (t + w)^((p + 1) / 2) is a square root of a, and lies in F_p.
+    let exp: T = (p >> 1) + T::ONE;
+    let mut x = T::ONE;
+    let mut y = T::ZERO;
+    for i in (0..exp.significant_bits()).rev() {
+        let xy = x.mod_mul(y, p);
+        x = x.mod_square(p).mod_add(y.mod_square(p).mod_mul(d, p), p);
+        y = xy.mod_add(xy, p);
+        if exp.get_bit(i) {
+            (x, y) = (
+                x.mod_mul(t, p).mod_add(y.mod_mul(d, p), p),
+                x.mod_add(y.mod_mul(t, p), p),
+            );
+        }
+    }
+    x
+}}
+
+// Returns a square root of `a` modulo the odd prime `p`, or `None` if `a` is not a quadratic
+// residue. `a` must be nonzero and less than `p`.
+//
+// Tonelli-Shanks takes $O(s^2)$ multiplications in addition to an exponentiation, where $2^s$ is
+// the largest power of 2 dividing $p - 1$, so Cipolla's algorithm is used when $s$ is large.
+fn mod_sqrt_odd_prime<T: PrimitiveUnsigned>(a: T, p: T) -> Option<T> {
+    if a.legendre_symbol(p) != 1 {
+        return None;
+    }
+    let s = (p - T::ONE).trailing_zeros();
+    Some(if s * s > p.significant_bits() << 2 {
+        mod_sqrt_cipolla(a, p)
+    } else {
+        mod_sqrt_tonelli_shanks(a, p)
+    })
+}
+
+// Returns the square roots of `a` modulo `p^k`, in increasing order, where `p` is prime, `a` is
+// not divisible by `p`, and `pk` is `p^k`.
+fn mod_sqrts_unit_prime_power<T: PrimitiveUnsigned>(a: T, p: T, k: u64, pk: T) -> Vec<T> {
+    if p == T::TWO {
+        return match k {
+            1 => vec![T::ONE],
+            2 => {
+                if a.get_bit(1) {
+                    Vec::new()
+                } else {
+                    vec![T::ONE, T::exact_from(3)]
+                }
+            }
+            _ => {
+                if a & T::exact_from(7) != T::ONE {
+                    return Vec::new();
+                }
+                // Lift a root one bit at a time. If r^2 = a mod 2^j, then either r or
+                // r + 2^(j - 1) is a root modulo 2^(j + 1).
+                let mut r = T::ONE;
+                for j in 3..k {
+                    if r.wrapping_square().wrapping_sub(a).get_bit(j) {
+                        r.set_bit(j - 1);
+                    }
+                }
+                let half = T::power_of_2(k - 1);
+                let mut roots = vec![r, pk - r, r.mod_add(half, pk), (pk - r).mod_add(half, pk)];
+                roots.sort_unstable();
+                roots
+            }
+        };
+    }
+    let mut r = if let Some(r) = mod_sqrt_odd_prime(a % p, p) {
+        r
+    } else {
+        return Vec::new();
+    };
+    // Hensel lifting: if r^2 = a mod p^j, then r - (r^2 - a) / (2r) is a root modulo p^(2j).
+    let mut j = 1;
+    while j < k {
+        j = (j << 1).min(k);
+        let pj = p.pow(j);
+        let f = r.mod_square(pj).mod_sub(a % pj, pj);
+        let inverse = r.mod_add(r, pj).mod_inverse(pj).unwrap();
+        r = r.mod_sub(f.mod_mul(inverse, pj), pj);
+    }
+    let s = pk - r;
+    if r < s {
+        vec![r, s]
+    } else {
+        vec![s, r]
+    }
+}
+
+// Returns the square roots of `a` modulo `p^e`, in no particular order, where `p` is prime, `a` is
+// less than `p^e`, and `pe` is `p^e`.
+//
+// If $a = 0$, the roots are the multiples of $p^{\lceil e/2 \rceil}$. Otherwise, if
+// $a = p^{2w}b$ with $p \nmid b$, the roots are $p^w y$, where $y^2 \equiv b \mod p^{e - 2w}$ and
+// $y$ is taken modulo $p^{e - w}$.
+fn mod_sqrts_prime_power<T: PrimitiveUnsigned>(a: T, p: T, e: u64, pe: T) -> Vec<T> {
+    if a == T::ZERO {
+        let step = p.pow((e + 1) >> 1);
+        let mut roots = Vec::new();
+        let mut x = T::ZERO;
+        while x < pe {
+            roots.push(x);
+            x += step;
+        }
+        return roots;
+    }
+    let (b, v) = a.remove_factor(p);
+    if v.odd() {
+        return Vec::new();
+    }
+    let w = v >> 1;
+    let k = e - v;
+    let pk = p.pow(k);
+    let pw = p.pow(w);
+    let step = pw * pk;
+    let mut roots = Vec::new();
+    for y in mod_sqrts_unit_prime_power(b, p, k, pk) {
+        let mut x = pw * y;
+        loop {
+            roots.push(x);
+            if pe - x <= step {
+                break;
+            }
+            x += step;
+        }
+    }
+    roots
+}
+
+// Returns one square root of `a` modulo `p^e`, where `p` is prime and `a` is less than `p^e`. If
+// $e = 1$, the lesser root is returned.
+fn mod_sqrt_prime_power<T: PrimitiveUnsigned>(a: T, p: T, e: u64) -> Option<T> {
+    if a == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let (b, v) = a.remove_factor(p);
+    if v.odd() {
+        return None;
+    }
+    let k = e - v;
+    let unit_roots = mod_sqrts_unit_prime_power(b, p, k, p.pow(k));
+    unit_roots.first().map(|&y| p.pow(v >> 1) * y)
+}
+
+fn mod_sqrt_from_factors<T: PrimitiveUnsigned>(a: T, factors: &[(T, u64)]) -> Option<T> {
+    assert!(a < factors.iter().fold(T::ONE, |m, &(p, e)| m * p.pow(e)));
+    let mut congruences = Vec::with_capacity(factors.len());
+    for &(p, e) in factors {
+        let pe = p.pow(e);
+        congruences.push((mod_sqrt_prime_power(a % pe, p, e)?, pe));
+    }
+    Some(T::crt(&congruences).unwrap().0)
+}
+
+fn mod_sqrts_from_factors<T: PrimitiveUnsigned>(a: T, factors: &[(T, u64)]) -> Vec<T> {
+    let moduli = factors.iter().map(|&(p, e)| p.pow(e)).collect::<Vec<_>>();
+    let basis = T::precompute_crt_data(&moduli).unwrap();
+    assert!(a < basis.modulus());
+    let mut root_sets = Vec::with_capacity(factors.len());
+    for (&(p, e), &pe) in factors.iter().zip(moduli.iter()) {
+        let roots = mod_sqrts_prime_power(a % pe, p, e, pe);
+        if roots.is_empty() {
+            return Vec::new();
+        }
+        root_sets.push(roots);
+    }
+    // Combine every choice of root modulo each prime power.
+    let mut indices = vec![0; root_sets.len()];
+    let mut residues = root_sets.iter().map(|roots| roots[0]).collect::<Vec<_>>();
+    let mut roots = Vec::new();
+    'outer: loop {
+        roots.push(T::crt_precomputed(&residues, &basis));
+        for (i, roots_i) in root_sets.iter().enumerate() {
+            indices[i] += 1;
+            if indices[i] == roots_i.len() {
+                indices[i] = 0;
+                residues[i] = roots_i[0];
+            } else {
+                residues[i] = roots_i[indices[i]];
+                continue 'outer;
+            }
+        }
+        break;
+    }
+    roots.sort_unstable();
+    roots
+}
+
+macro_rules! impl_mod_sqrt {
+    ($t:ident) => {
+        impl ModSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Finds a square root of a number modulo another number $m$. The input must be
+            /// already reduced modulo $m$.
+            ///
+            /// Returns `None` if the number is not a square modulo $m$. If $m$ is prime, the
+            /// lesser of the square roots is returned; otherwise, which square root is returned is
+            /// unspecified.
+            ///
+            /// The modulus is factored first. If its factorization is already known, use
+            /// [`mod_sqrt_from_factors`](ModSqrt::mod_sqrt_from_factors) instead.
+            ///
+            /// $f(x, m) = y$, where $x, y < m$ and $y^2 \equiv x \mod m$.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor).
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt).
+            #[inline]
+            fn mod_sqrt(self, m: $t) -> Option<$t> {
+                assert!(self < m);
+                mod_sqrt_from_factors(self, &m.factor())
+            }
+
+            /// Finds all square roots of a number modulo another number $m$, in increasing order.
+            /// The input must be already reduced modulo $m$.
+            ///
+            /// The result is empty if the number is not a square modulo $m$. The number of roots
+            /// can be large when $m$ has large square factors; for example, 0 has
+            /// $2^{\lfloor k/2 \rfloor}$ square roots modulo $2^k$.
+            ///
+            /// The modulus is factored first. If its factorization is already known, use
+            /// [`mod_sqrts_from_factors`](ModSqrt::mod_sqrts_from_factors) instead.
+            ///
+            /// $f(x, m) = \\{y : y < m \land y^2 \equiv x \mod m\\}$.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor) and by the number of
+            /// roots.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrts).
+            #[inline]
+            fn mod_sqrts(self, m: $t) -> Vec<$t> {
+                assert!(self < m);
+                mod_sqrts_from_factors(self, &m.factor())
+            }
+
+            /// Finds a square root of a number modulo another number $m$, given the prime
+            /// factorization of $m$. The input must be already reduced modulo $m$.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. Square roots are found modulo each
+            /// odd prime using the Tonelli-Shanks algorithm or Cipolla's algorithm, lifted to
+            /// each prime power using Hensel's lemma, and combined using the Chinese Remainder
+            /// Theorem.
+            ///
+            /// Returns `None` if the number is not a square modulo $m$. If $m$ is prime, the
+            /// lesser of the square roots is returned; otherwise, which square root is returned is
+            /// unspecified.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^3)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `Self::WIDTH`.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to the product of the prime powers.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt_from_factors).
+            #[inline]
+            fn mod_sqrt_from_factors(self, factors: &[($t, u64)]) -> Option<$t> {
+                mod_sqrt_from_factors(self, factors)
+            }
+
+            /// Finds all square roots of a number modulo another number $m$, in increasing order,
+            /// given the prime factorization of $m$. The input must be already reduced modulo
+            /// $m$.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked.
+            ///
+            /// The result is empty if the number is not a square modulo $m$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(n^3 + kn)$
+            ///
+            /// $M(n, k) = O(k)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `Self::WIDTH`, and $k$ is the
+            /// number of roots.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to the product of the prime powers.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrts_from_factors).
+            #[inline]
+            fn mod_sqrts_from_factors(self, factors: &[($t, u64)]) -> Vec<$t> {
+                mod_sqrts_from_factors(self, factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_sqrt);
@@ -0,0 +1,93 @@
+use crate::num::arithmetic::traits::MoebiusMu;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::prime_sieve::prime_power_sieve;
+use crate::num::factorization::traits::Factor;
+
+fn moebius_mu_from_factors<T>(factors: &[(T, u64)]) -> i8 {
+    if factors.iter().any(|&(_, e)| e > 1) {
+        0
+    } else if factors.len() & 1 == 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+/// Computes the Möbius function $\mu$ of every number from 0 to $n$, inclusive.
+///
+/// The $k$th entry of the result is $\mu(k)$, except that the 0th entry is 0. The values are
+/// computed together using a prime sieve, which is much faster than computing
+/// [`moebius_mu`](MoebiusMu::moebius_mu) of each number separately.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n\log\log n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+///
+/// # Panics
+/// Panics if $n + 1$ does not fit in a [`usize`].
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::moebius_mu::moebius_mu_table;
+///
+/// assert_eq!(moebius_mu_table(0u8), &[0]);
+/// assert_eq!(moebius_mu_table(12u32), &[0, 1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]);
+/// ```
+pub fn moebius_mu_table<T: PrimitiveUnsigned>(n: T) -> Vec<i8> {
+    prime_power_sieve(n, 0, 1, |mu, _, e| *mu = if e == 1 { -*mu } else { 0 })
+}
+
+macro_rules! impl_moebius_mu {
+    ($t:ident) => {
+        impl MoebiusMu for $t {
+            /// Computes the Möbius function $\mu$ of a number.
+            ///
+            /// The number is factored first. If its factorization is already known, use
+            /// [`moebius_mu_from_factors`](MoebiusMu::moebius_mu_from_factors) instead.
+            ///
+            /// $$
+            /// f(n) = \mu(n) = \\begin{cases}
+            ///     (-1)^k & \text{if} \\quad n \text{ is a product of } k \text{ distinct primes},
+            ///         \\\\
+            ///     0 & \text{otherwise}.
+            /// \\end{cases}
+            /// $$
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor).
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::moebius_mu#moebius_mu).
+            #[inline]
+            fn moebius_mu(&self) -> i8 {
+                moebius_mu_from_factors(&self.factor())
+            }
+
+            /// Computes the Möbius function $\mu$ of a number, given its prime factorization.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::moebius_mu#moebius_mu_from_factors).
+            #[inline]
+            fn moebius_mu_from_factors(factors: &[($t, u64)]) -> i8 {
+                moebius_mu_from_factors(factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_moebius_mu);
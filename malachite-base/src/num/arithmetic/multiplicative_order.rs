@@ -0,0 +1,88 @@
+use crate::num::arithmetic::carmichael_lambda::carmichael_lambda_factors;
+use crate::num::arithmetic::mod_pow::mod_pow_wide;
+use crate::num::arithmetic::traits::MultiplicativeOrder;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Factor;
+
+// The order of every unit modulo $m$ divides $\lambda(m)$, so the order of `a` is found by removing
+// prime factors from $\lambda(m)$ for as long as `a` raised to the result is still 1.
+fn multiplicative_order_from_factors<T: PrimitiveUnsigned>(
+    a: T,
+    factors: &[(T, u64)],
+) -> Option<T> {
+    let m = factors.iter().fold(T::ONE, |m, &(p, e)| m * p.pow(e));
+    assert!(a < m);
+    if !a.coprime_with(m) {
+        return None;
+    }
+    let mut order = T::carmichael_lambda_from_factors(factors);
+    for (q, f) in carmichael_lambda_factors(factors) {
+        for _ in 0..f {
+            let reduced = order / q;
+            if mod_pow_wide(a, reduced, m) != T::ONE {
+                break;
+            }
+            order = reduced;
+        }
+    }
+    Some(order)
+}
+
+macro_rules! impl_multiplicative_order {
+    ($t:ident) => {
+        impl MultiplicativeOrder<$t> for $t {
+            type Output = $t;
+
+            /// Computes the multiplicative order of a number modulo another number $m$: the
+            /// smallest positive $k$ such that $a^k \equiv 1 \mod m$. The input must be already
+            /// reduced modulo $m$.
+            ///
+            /// Returns `None` if the number is not coprime to $m$, since then no such $k$ exists.
+            ///
+            /// The modulus is factored first. If its factorization is already known, use
+            /// [`MultiplicativeOrder::multiplicative_order_from_factors`] instead.
+            ///
+            /// $f(a, m) = \min \\{k \in \N^+ : a^k \equiv 1 \mod m\\}$.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of factoring $m$ and $p - 1$ for each prime factor $p$
+            /// of $m$.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::multiplicative_order#multiplicative_order).
+            #[inline]
+            fn multiplicative_order(self, m: $t) -> Option<$t> {
+                assert!(self < m);
+                multiplicative_order_from_factors(self, &m.factor())
+            }
+
+            /// Computes the multiplicative order of a number modulo another number $m$, given the
+            /// prime factorization of $m$. The input must be already reduced modulo $m$.
+            ///
+            /// Returns `None` if the number is not coprime to $m$, since then no such $k$ exists.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// $f(a, \\{(p_i, e_i)\\}) = \min \\{k \in \N^+ : a^k \equiv 1 \mod \prod_i
+            /// p_i^{e_i}\\}$.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of factoring $p_i - 1$ for each $i$.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to the product of the prime powers.
+            ///
+            /// # Examples
+            /// See [here](super::multiplicative_order#multiplicative_order_from_factors).
+            #[inline]
+            fn multiplicative_order_from_factors(self, factors: &[($t, u64)]) -> Option<$t> {
+                multiplicative_order_from_factors(self, factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_multiplicative_order);
@@ -0,0 +1,192 @@
+use crate::num::arithmetic::traits::{IsPerfectPower, ModPow, PerfectPowerDecompose, UnsignedAbs};
+use crate::num::basic::signeds::PrimitiveSigned;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::{IsPrime, PrimesInRange};
+use crate::num::logic::traits::TrailingZeros;
+
+// The number of primes $q \equiv 1 \pmod p$ modulo which a number is checked to be a $p$th power
+// residue before its $p$th root is computed.
+#[doc(hidden)]
+pub const POWER_RESIDUE_FILTER_COUNT: usize = 4;
+
+// Returns `false` if `n` is certainly not a perfect `p`th power. If $q \equiv 1 \pmod p$ is a prime
+// not dividing $n$, and $n$ is a $p$th power, then $n^{(q - 1)/p} \equiv 1 \pmod q$; only about
+// $1/p$ of all residues modulo $q$ pass this test.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn passes_power_residue_filter<T: PrimitiveUnsigned>(n: T, p: u64) -> bool {
+    let n: u128 = n.exact_into();
+    let step = p << 1;
+    let mut q = step + 1;
+    let mut checked = 0;
+    while checked < POWER_RESIDUE_FILTER_COUNT {
+        if q.is_prime() {
+            let r = u64::wrapping_from(n % u128::from(q));
+            if r != 0 && r.mod_pow((q - 1) / p, q) != 1 {
+                return false;
+            }
+            checked += 1;
+        }
+        q += step;
+    }
+    true
+}
+
+// Returns the $p$th root of `n` and $p$, where $p$ is the smallest prime at least `p_min` for which
+// `n` is a perfect $p$th power, or `None` if there is no such prime. `n` must be at least 2.
+fn smallest_prime_root<T: PrimitiveUnsigned>(n: T, p_min: u64) -> Option<(T, u64)> {
+    // If $n = b^p$ with $b \geq 2$, then $n$ has more than $p$ significant bits, its number of
+    // trailing zeros is divisible by $p$, and it is a $p$th power residue modulo every prime.
+    let bits = n.significant_bits();
+    if p_min >= bits {
+        return None;
+    }
+    let twos = TrailingZeros::trailing_zeros(n);
+    u64::primes_in_range(&p_min, &bits)
+        .filter(|&p| (twos == 0 || twos % p == 0) && passes_power_residue_filter(n, p))
+        .find_map(|p| n.checked_root(p).map(|root| (root, p)))
+}
+
+fn is_perfect_power_unsigned<T: PrimitiveUnsigned>(n: T, p_min: u64) -> bool {
+    n <= T::ONE || smallest_prime_root(n, p_min).is_some()
+}
+
+fn perfect_power_decompose_unsigned<T: PrimitiveUnsigned>(mut n: T) -> (T, u64) {
+    let mut exp = 1;
+    if n <= T::ONE {
+        return (n, exp);
+    }
+    // If $n = b^p$ and $p$ is the smallest prime for which $n$ is a $p$th power, then $b$ is not a
+    // $q$th power for any prime $q < p$, so the search for the next prime can resume at $p$.
+    let mut p_min = 2;
+    while let Some((root, p)) = smallest_prime_root(n, p_min) {
+        n = root;
+        exp *= p;
+        p_min = p;
+    }
+    (n, exp)
+}
+
+fn is_perfect_power_signed<U: PrimitiveUnsigned, S: PrimitiveSigned + UnsignedAbs<Output = U>>(
+    n: S,
+) -> bool {
+    // A negative number can only be an odd power.
+    is_perfect_power_unsigned(n.unsigned_abs(), if n >= S::ZERO { 2 } else { 3 })
+}
+
+fn perfect_power_decompose_signed<
+    U: PrimitiveUnsigned,
+    S: PrimitiveSigned + UnsignedAbs<Output = U> + WrappingFrom<U>,
+>(
+    n: S,
+) -> (S, u64) {
+    let (root, exp) = perfect_power_decompose_unsigned(n.unsigned_abs());
+    if n >= S::ZERO {
+        (S::wrapping_from(root), exp)
+    } else {
+        // A negative number can only be an odd power, so the factors of 2 in the exponent of $|n|$
+        // are moved into the base.
+        let twos = TrailingZeros::trailing_zeros(exp);
+        (
+            S::wrapping_from(root.pow(1 << twos)).wrapping_neg(),
+            exp >> twos,
+        )
+    }
+}
+
+macro_rules! impl_perfect_power_unsigned {
+    ($t:ident) => {
+        impl IsPerfectPower for $t {
+            /// Determines whether a number is a perfect power: whether it is equal to $b^k$ for
+            /// some integers $b$ and $k \geq 2$.
+            ///
+            /// 0 and 1 are perfect powers.
+            ///
+            /// $f(n) = (\exists b \in \Z, k \in \N : k \geq 2 \wedge b^k = n)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::perfect_power#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                is_perfect_power_unsigned(*self, 2)
+            }
+        }
+
+        impl PerfectPowerDecompose for $t {
+            type Output = $t;
+
+            /// Writes a number as a perfect power $b^k$ with the largest possible $k$, returning
+            /// $b$ and $k$.
+            ///
+            /// If the number is not a perfect power, the result is the number itself and 1. The
+            /// result for 0 and 1 is also the number itself and 1, since they are $k$th powers for
+            /// every $k$.
+            ///
+            /// $f(n) = (b, k)$, where $b^k = n$ and $k$ is as large as possible.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::perfect_power#perfect_power_decompose).
+            #[inline]
+            fn perfect_power_decompose(self) -> ($t, u64) {
+                perfect_power_decompose_unsigned(self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_perfect_power_unsigned);
+
+macro_rules! impl_perfect_power_signed {
+    ($u:ident, $s:ident) => {
+        impl IsPerfectPower for $s {
+            /// Determines whether a number is a perfect power: whether it is equal to $b^k$ for
+            /// some integers $b$ and $k \geq 2$.
+            ///
+            /// 0, 1, and $-1$ are perfect powers. A negative number is a perfect power if and only
+            /// if it is a perfect $k$th power for some odd $k \geq 3$.
+            ///
+            /// $f(n) = (\exists b \in \Z, k \in \N : k \geq 2 \wedge b^k = n)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::perfect_power#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                is_perfect_power_signed::<$u, $s>(*self)
+            }
+        }
+
+        impl PerfectPowerDecompose for $s {
+            type Output = $s;
+
+            /// Writes a number as a perfect power $b^k$ with the largest possible $k$, returning
+            /// $b$ and $k$.
+            ///
+            /// If the number is not a perfect power, the result is the number itself and 1. The
+            /// result for 0, 1, and $-1$ is also the number itself and 1, since they are $k$th
+            /// powers for every (odd) $k$. If the number is negative, $k$ is odd.
+            ///
+            /// $f(n) = (b, k)$, where $b^k = n$ and $k$ is as large as possible.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::perfect_power#perfect_power_decompose).
+            #[inline]
+            fn perfect_power_decompose(self) -> ($s, u64) {
+                perfect_power_decompose_signed::<$u, $s>(self)
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_perfect_power_signed);
@@ -0,0 +1,101 @@
+use crate::num::arithmetic::carmichael_lambda::carmichael_lambda_factors;
+use crate::num::arithmetic::mod_pow::mod_pow_wide;
+use crate::num::arithmetic::traits::PrimitiveRoot;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Factor;
+
+// A primitive root modulo $n$ exists if and only if $n$ is 1, 2, 4, $p^k$, or $2p^k$, where $p$ is
+// an odd prime.
+pub(crate) fn has_primitive_root<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> bool {
+    let mut odd_factor_count = 0;
+    let mut two_exponent = 0;
+    for &(p, e) in factors {
+        if p == T::TWO {
+            two_exponent = e;
+        } else {
+            odd_factor_count += 1;
+        }
+    }
+    match odd_factor_count {
+        0 => two_exponent <= 2,
+        1 => two_exponent <= 1,
+        _ => false,
+    }
+}
+
+// Candidates are tried in increasing order. A unit $g$ is a primitive root if and only if
+// $g^{\lambda(n)/q} \not\equiv 1 \mod n$ for each prime factor $q$ of $\lambda(n)$.
+fn primitive_root_from_factors<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> Option<T> {
+    if !has_primitive_root(factors) {
+        return None;
+    }
+    let n = factors.iter().fold(T::ONE, |n, &(p, e)| n * p.pow(e));
+    if n == T::ONE {
+        return Some(T::ZERO);
+    }
+    let lambda = T::carmichael_lambda_from_factors(factors);
+    let exps = carmichael_lambda_factors(factors)
+        .into_iter()
+        .map(|(q, _)| lambda / q)
+        .collect::<Vec<_>>();
+    let mut g = T::ONE;
+    loop {
+        if g.coprime_with(n) && exps.iter().all(|&exp| mod_pow_wide(g, exp, n) != T::ONE) {
+            return Some(g);
+        }
+        g += T::ONE;
+    }
+}
+
+macro_rules! impl_primitive_root {
+    ($t:ident) => {
+        impl PrimitiveRoot for $t {
+            /// Finds the smallest primitive root modulo a number $n$: the smallest generator of
+            /// the multiplicative group of integers modulo $n$.
+            ///
+            /// Returns `None` if there is no primitive root, which is the case unless $n$ is 1, 2,
+            /// 4, $p^k$, or $2p^k$ for an odd prime $p$. Modulo 1, the smallest primitive root is
+            /// 0.
+            ///
+            /// The number is factored first. If its factorization is already known, use
+            /// [`primitive_root_from_factors`](PrimitiveRoot::primitive_root_from_factors)
+            /// instead.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of factoring $n$ and $p - 1$ for each prime factor $p$
+            /// of $n$.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_root#primitive_root).
+            #[inline]
+            fn primitive_root(&self) -> Option<$t> {
+                primitive_root_from_factors(&self.factor())
+            }
+
+            /// Finds the smallest primitive root modulo a number $n$, given the prime
+            /// factorization of $n$.
+            ///
+            /// Returns `None` if there is no primitive root, which is the case unless $n$ is 1, 2,
+            /// 4, $p^k$, or $2p^k$ for an odd prime $p$. Modulo 1, the smallest primitive root is
+            /// 0.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of factoring $p - 1$ for the odd prime factor $p$ of
+            /// $n$.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_root#primitive_root_from_factors).
+            #[inline]
+            fn primitive_root_from_factors(factors: &[($t, u64)]) -> Option<$t> {
+                primitive_root_from_factors(factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_primitive_root);
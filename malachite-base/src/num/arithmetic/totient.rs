@@ -0,0 +1,93 @@
+use crate::num::arithmetic::traits::Totient;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::prime_sieve::prime_power_sieve;
+use crate::num::factorization::traits::Factor;
+
+fn totient_from_factors<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    factors
+        .iter()
+        .fold(T::ONE, |phi, &(p, e)| phi * p.pow(e - 1) * (p - T::ONE))
+}
+
+/// Computes Euler's totient function $\varphi$ of every number from 0 to $n$, inclusive.
+///
+/// The $k$th entry of the result is $\varphi(k)$, except that the 0th entry is 0. The values are
+/// computed together using a prime sieve, which is much faster than computing
+/// [`totient`](Totient::totient) of each number separately.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n\log\log n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+///
+/// # Panics
+/// Panics if $n + 1$ does not fit in a [`usize`].
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::totient::totient_table;
+///
+/// assert_eq!(totient_table(0u8), &[0]);
+/// assert_eq!(totient_table(12u32), &[0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4]);
+/// ```
+pub fn totient_table<T: PrimitiveUnsigned>(n: T) -> Vec<T> {
+    prime_power_sieve(n, T::ZERO, T::ONE, |phi, p, e| {
+        *phi *= p.pow(e - 1) * (p - T::ONE);
+    })
+}
+
+macro_rules! impl_totient {
+    ($t:ident) => {
+        impl Totient for $t {
+            /// Computes Euler's totient function $\varphi$ of a number: the number of positive
+            /// integers less than or equal to it that are coprime to it.
+            ///
+            /// The number is factored first. If its factorization is already known, use
+            /// [`totient_from_factors`](Totient::totient_from_factors) instead.
+            ///
+            /// $$
+            /// f(n) = \varphi(n) = n\prod_{p | n} \left ( 1 - \frac{1}{p} \right ).
+            /// $$
+            ///
+            /// # Expected complexity
+            /// Dominated by the complexity of [`factor`](Factor::factor).
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient).
+            #[inline]
+            fn totient(&self) -> $t {
+                totient_from_factors(&self.factor())
+            }
+
+            /// Computes Euler's totient function $\varphi$ of a number, given its prime
+            /// factorization.
+            ///
+            /// `factors` must contain distinct primes and their positive exponents, as returned by
+            /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+            ///
+            /// $$
+            /// f(\\{(p_i, e_i)\\}) = \prod_i p_i^{e_i - 1}(p_i - 1).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient_from_factors).
+            #[inline]
+            fn totient_from_factors(factors: &[($t, u64)]) -> $t {
+                totient_from_factors(factors)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_totient);
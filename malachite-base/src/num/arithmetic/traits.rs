@@ -51,6 +51,16 @@ pub trait ArithmeticCheckedShr<RHS> {
     fn arithmetic_checked_shr(self, other: RHS) -> Option<Self::Output>;
 }
 
+/// Computes the $n$th Bell number: the number of partitions of a set of size $n$.
+pub trait Bell {
+    fn bell(n: u64) -> Self;
+}
+
+/// Computes the $n$th Bernoulli number, using the convention $B_1 = -1/2$.
+pub trait Bernoulli {
+    fn bernoulli(n: u64) -> Self;
+}
+
 pub trait BinomialCoefficient<T = Self> {
     fn binomial_coefficient(n: T, k: T) -> Self;
 }
@@ -59,6 +69,41 @@ pub trait CheckedBinomialCoefficient<T = Self>: Sized {
     fn checked_binomial_coefficient(n: T, k: T) -> Option<Self>;
 }
 
+/// Computes a binomial coefficient modulo a number $m$.
+pub trait BinomialCoefficientMod<T = Self, M = Self> {
+    fn binomial_coefficient_mod(n: T, k: T, m: M) -> Self;
+}
+
+/// Computes the multinomial coefficient $(k_1 + \cdots + k_r)!/(k_1! \cdots k_r!)$.
+pub trait MultinomialCoefficient<T = Self> {
+    fn multinomial_coefficient(ks: &[T]) -> Self;
+}
+
+/// Computes the multinomial coefficient $(k_1 + \cdots + k_r)!/(k_1! \cdots k_r!)$, returning
+/// `None` if the result is not representable.
+pub trait CheckedMultinomialCoefficient<T = Self>: Sized {
+    fn checked_multinomial_coefficient(ks: &[T]) -> Option<Self>;
+}
+
+/// Computes the multinomial coefficient $(k_1 + \cdots + k_r)!/(k_1! \cdots k_r!)$ modulo a number
+/// $m$.
+pub trait MultinomialCoefficientMod<T = Self, M = Self> {
+    fn multinomial_coefficient_mod(ks: &[T], m: M) -> Self;
+}
+
+/// Computes the Carmichael function $\lambda$ of a number: the smallest positive $m$ such that
+/// $a^m \equiv 1 \mod n$ for every $a$ coprime to $n$.
+pub trait CarmichaelLambda: Sized {
+    fn carmichael_lambda(&self) -> Self;
+
+    fn carmichael_lambda_from_factors(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Computes the $n$th Catalan number, $C_n = \binom{2n}{n}/(n+1)$.
+pub trait Catalan {
+    fn catalan(n: u64) -> Self;
+}
+
 /// Takes the ceiling of a number.
 pub trait Ceiling {
     type Output;
@@ -156,6 +201,32 @@ pub trait CoprimeWith<RHS = Self> {
     fn coprime_with(self, other: RHS) -> bool;
 }
 
+/// Solves a system of simultaneous congruences $x \equiv r_i \mod m_i$, using the Chinese
+/// Remainder Theorem. The moduli need not be pairwise coprime.
+///
+/// The input is a slice of `(residue, modulus)` pairs. The output is the least nonnegative
+/// solution $x$ together with the combined modulus $\operatorname{lcm}(m_1, \ldots, m_k)$, or
+/// `None` if the congruences are inconsistent.
+pub trait Crt: Sized {
+    fn crt(congruences: &[(Self, Self)]) -> Option<(Self, Self)>;
+}
+
+/// Reconstructs numbers from their residues modulo a fixed set of pairwise coprime moduli, using
+/// the Chinese Remainder Theorem.
+///
+/// If many numbers need to be reconstructed from the same moduli, it is quicker to precompute a
+/// basis once and reuse it. For precomputing the basis, use
+/// [`precompute_crt_data`](CrtPrecomputed::precompute_crt_data).
+pub trait CrtPrecomputed: Sized {
+    type Data;
+
+    /// Precomputes a basis for reconstructing numbers from their residues modulo the given
+    /// moduli.
+    fn precompute_crt_data(moduli: &[Self]) -> Option<Self::Data>;
+
+    fn crt_precomputed(residues: &[Self], data: &Self::Data) -> Self;
+}
+
 /// Divides two numbers, assuming the first exactly divides the second.
 ///
 /// If it doesn't, the `div_exact` function may panic or return a meaningless result.
@@ -280,6 +351,38 @@ pub trait DivisibleBy<RHS = Self> {
     fn divisible_by(self, other: RHS) -> bool;
 }
 
+/// Counts the positive divisors of a number.
+pub trait DivisorCount: Sized {
+    fn divisor_count(&self) -> Self;
+
+    fn divisor_count_from_factors(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Computes the sum of the $k$th powers of the positive divisors of a number. Assumes the result
+/// is representable.
+pub trait DivisorSigma: Sized {
+    fn divisor_sigma(&self, k: u64) -> Self;
+
+    fn divisor_sigma_from_factors(factors: &[(Self, u64)], k: u64) -> Self;
+}
+
+/// Computes the sum of the $k$th powers of the positive divisors of a number, returning `None` if
+/// the result is not representable.
+pub trait CheckedDivisorSigma: Sized {
+    fn checked_divisor_sigma(&self, k: u64) -> Option<Self>;
+
+    fn checked_divisor_sigma_from_factors(factors: &[(Self, u64)], k: u64) -> Option<Self>;
+}
+
+/// Generates the positive divisors of a number, in ascending order.
+pub trait Divisors: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn divisors(&self) -> Self::I;
+
+    fn divisors_from_factors(factors: &[(Self, u64)]) -> Self::I;
+}
+
 /// Determines whether a number is equivalent to another number modulo $2^k$.
 pub trait EqModPowerOf2<RHS = Self> {
     fn eq_mod_power_of_2(self, other: RHS, pow: u64) -> bool;
@@ -321,6 +424,11 @@ pub trait CheckedFactorial: Sized {
     fn checked_factorial(n: u64) -> Option<Self>;
 }
 
+/// Computes the factorial of a number modulo another number $m$.
+pub trait FactorialMod<M = Self> {
+    fn factorial_mod(n: u64, m: M) -> Self;
+}
+
 pub trait DoubleFactorial {
     fn double_factorial(n: u64) -> Self;
 }
@@ -345,6 +453,27 @@ pub trait CheckedSubfactorial: Sized {
     fn checked_subfactorial(n: u64) -> Option<Self>;
 }
 
+/// Computes Fibonacci numbers.
+pub trait Fibonacci: Sized {
+    fn fibonacci(n: u64) -> Self;
+
+    /// Returns $(F_n, F_{n+1})$.
+    fn fibonacci_pair(n: u64) -> (Self, Self);
+}
+
+/// Computes Fibonacci numbers, returning `None` if the result is not representable.
+pub trait CheckedFibonacci: Sized {
+    fn checked_fibonacci(n: u64) -> Option<Self>;
+
+    /// Returns $(F_n, F_{n+1})$, or `None` if either is not representable.
+    fn checked_fibonacci_pair(n: u64) -> Option<(Self, Self)>;
+}
+
+/// Computes a Fibonacci number modulo another number.
+pub trait FibonacciMod<M = Self>: Sized {
+    fn fibonacci_mod(n: u64, m: M) -> Self;
+}
+
 /// Takes the floor of a number.
 pub trait Floor {
     type Output;
@@ -369,6 +498,19 @@ pub trait GcdAssign<RHS = Self> {
     fn gcd_assign(&mut self, other: RHS);
 }
 
+/// Calculates the GCD (greatest common divisor) of all the numbers generated by an iterator.
+///
+/// The GCD of an empty iterator is 0.
+pub trait GcdOf<A = Self>: Sized {
+    fn gcd_of<I: Iterator<Item = A>>(xs: I) -> Self;
+}
+
+/// Determines whether a number is a perfect power: whether it is equal to $b^k$ for some $b$ and
+/// some integer $k \geq 2$.
+pub trait IsPerfectPower {
+    fn is_perfect_power(&self) -> bool;
+}
+
 /// Determines whether a number is an integer power of 2.
 pub trait IsPowerOf2 {
     fn is_power_of_2(&self) -> bool;
@@ -386,6 +528,13 @@ pub trait LcmAssign<RHS = Self> {
     fn lcm_assign(&mut self, other: RHS);
 }
 
+/// Calculates the LCM (least common multiple) of all the numbers generated by an iterator.
+///
+/// The LCM of an empty iterator is 1.
+pub trait LcmOf<A = Self>: Sized {
+    fn lcm_of<I: Iterator<Item = A>>(xs: I) -> Self;
+}
+
 /// Calculates the LCM (least common multiple) of two numbers, returning `None` if the result is
 /// not representable.
 pub trait CheckedLcm<RHS = Self> {
@@ -476,6 +625,35 @@ pub trait CeilingLogBasePowerOf2<POW> {
     fn ceiling_log_base_power_of_2(self, pow: POW) -> Self::Output;
 }
 
+/// Computes Lucas numbers.
+pub trait Lucas: Sized {
+    fn lucas(n: u64) -> Self;
+
+    /// Returns $(L_n, L_{n+1})$.
+    fn lucas_pair(n: u64) -> (Self, Self);
+}
+
+/// Computes Lucas numbers, returning `None` if the result is not representable.
+pub trait CheckedLucas: Sized {
+    fn checked_lucas(n: u64) -> Option<Self>;
+
+    /// Returns $(L_n, L_{n+1})$, or `None` if either is not representable.
+    fn checked_lucas_pair(n: u64) -> Option<(Self, Self)>;
+}
+
+/// Computes a Lucas number modulo another number.
+pub trait LucasMod<M = Self>: Sized {
+    fn lucas_mod(n: u64, m: M) -> Self;
+}
+
+/// Computes the terms $U_k(P, Q)$ and $V_k(P, Q)$ of the Lucas sequences with parameters $P$ and
+/// $Q$, modulo another number. The index $k$ is `self`.
+pub trait LucasSequenceMod<M = Self> {
+    type Output;
+
+    fn lucas_sequence_mod(self, p: M, q: M, m: M) -> (Self::Output, Self::Output);
+}
+
 /// Adds two numbers modulo a third number $m$. Assumes the inputs are already reduced modulo $m$.
 pub trait ModAdd<RHS = Self, M = Self> {
     type Output;
@@ -489,6 +667,20 @@ pub trait ModAddAssign<RHS = Self, M = Self> {
     fn mod_add_assign(&mut self, other: RHS, m: M);
 }
 
+/// Finds the centered residue of a number modulo another number $m$: the residue in
+/// $(-m/2, m/2]$. The input need not be reduced.
+pub trait ModCentered<M = Self> {
+    type Output;
+
+    fn mod_centered(self, m: M) -> Self::Output;
+}
+
+/// Replaces a number with its centered residue modulo another number $m$: the residue in
+/// $(-m/2, m/2]$. The input need not be reduced.
+pub trait ModCenteredAssign<M = Self> {
+    fn mod_centered_assign(&mut self, m: M);
+}
+
 /// Finds the multiplicative inverse of a number modulo another number $m$. Assumes the input is
 /// already reduced modulo $m$.
 pub trait ModInverse<M = Self> {
@@ -502,6 +694,14 @@ pub trait ModIsReduced<M = Self> {
     fn mod_is_reduced(&self, m: &M) -> bool;
 }
 
+/// Finds the discrete logarithm of a number to a given base, modulo another number $m$. Assumes
+/// the inputs are already reduced modulo $m$.
+pub trait ModLog<B = Self, M = Self> {
+    type Output;
+
+    fn mod_log(self, base: B, m: M) -> Option<Self::Output>;
+}
+
 /// Multiplies two numbers modulo a third number $m$. Assumes the inputs are already reduced mod
 /// $m$.
 pub trait ModMul<RHS = Self, M = Self> {
@@ -881,6 +1081,20 @@ pub trait ModShrAssign<RHS, M = Self> {
     fn mod_shr_assign(&mut self, other: RHS, m: M);
 }
 
+/// Finds the square roots of a number modulo another number $m$. Assumes the input is already
+/// reduced modulo $m$.
+pub trait ModSqrt<M = Self> {
+    type Output;
+
+    fn mod_sqrt(self, m: M) -> Option<Self::Output>;
+
+    fn mod_sqrts(self, m: M) -> Vec<Self::Output>;
+
+    fn mod_sqrt_from_factors(self, factors: &[(Self::Output, u64)]) -> Option<Self::Output>;
+
+    fn mod_sqrts_from_factors(self, factors: &[(Self::Output, u64)]) -> Vec<Self::Output>;
+}
+
 /// Squares a number modulo another number $m$. Assumes the input is already reduced modulo $m$.
 pub trait ModSquare<M = Self> {
     type Output;
@@ -931,6 +1145,26 @@ pub trait ModSubAssign<RHS = Self, M = Self> {
     fn mod_sub_assign(&mut self, other: RHS, m: M);
 }
 
+/// Computes the Möbius function $\mu$ of a number.
+pub trait MoebiusMu: Sized {
+    fn moebius_mu(&self) -> i8;
+
+    fn moebius_mu_from_factors(factors: &[(Self, u64)]) -> i8;
+}
+
+/// Computes the multiplicative order of a number modulo another number $m$: the smallest positive
+/// $k$ such that $a^k \equiv 1 \mod m$. Assumes the input is already reduced modulo $m$.
+pub trait MultiplicativeOrder<M = Self> {
+    type Output;
+
+    fn multiplicative_order(self, m: M) -> Option<Self::Output>;
+
+    fn multiplicative_order_from_factors(
+        self,
+        factors: &[(Self::Output, u64)],
+    ) -> Option<Self::Output>;
+}
+
 /// Replaces a number with its negative. Assumes the result is representable.
 pub trait NegAssign {
     fn neg_assign(&mut self);
@@ -1139,6 +1373,20 @@ pub trait Parity {
     fn odd(self) -> bool;
 }
 
+/// Computes the number of partitions $p(n)$ of $n$: the number of ways of writing $n$ as a sum of
+/// positive integers, ignoring order.
+pub trait PartitionCount {
+    fn partition_count(n: u64) -> Self;
+}
+
+/// Writes a number as a perfect power $b^k$, with $k$ as large as possible, and returns $b$ and
+/// $k$.
+pub trait PerfectPowerDecompose {
+    type Output;
+
+    fn perfect_power_decompose(self) -> (Self::Output, u64);
+}
+
 /// Raises a number to a power. Assumes the result is representable.
 pub trait Pow<RHS> {
     type Output;
@@ -1156,6 +1404,14 @@ pub trait PowerOf2<POW> {
     fn power_of_2(pow: POW) -> Self;
 }
 
+/// Finds the smallest primitive root modulo a number $n$: the smallest generator of the
+/// multiplicative group of integers modulo $n$.
+pub trait PrimitiveRoot: Sized {
+    fn primitive_root(&self) -> Option<Self>;
+
+    fn primitive_root_from_factors(factors: &[(Self, u64)]) -> Option<Self>;
+}
+
 pub trait Primorial {
     fn primorial(n: u64) -> Self;
 
@@ -1180,6 +1436,15 @@ pub trait ReciprocalAssign {
     fn reciprocal_assign(&mut self);
 }
 
+/// Removes all factors of $p$ from a number, returning the remaining cofactor and the number of
+/// factors removed (the $p$-adic valuation).
+pub trait RemoveFactor<P> {
+    type CofactorOutput;
+    type ValuationOutput;
+
+    fn remove_factor(self, p: P) -> (Self::CofactorOutput, Self::ValuationOutput);
+}
+
 /// Finds the floor of the $n$th root of a number.
 pub trait FloorRoot<POW> {
     type Output;
@@ -1503,6 +1768,18 @@ pub trait SquareAssign {
     fn square_assign(&mut self);
 }
 
+/// Computes the unsigned Stirling number of the first kind, $\left[{n \atop k}\right]$: the number
+/// of permutations of $n$ elements with exactly $k$ cycles.
+pub trait StirlingFirst {
+    fn stirling_first(n: u64, k: u64) -> Self;
+}
+
+/// Computes the Stirling number of the second kind, $\left\{{n \atop k}\right\}$: the number of
+/// partitions of a set of size $n$ into exactly $k$ nonempty subsets.
+pub trait StirlingSecond {
+    fn stirling_second(n: u64, k: u64) -> Self;
+}
+
 /// Subtracts a number by the product of two other numbers.
 pub trait SubMul<Y = Self, Z = Self> {
     type Output;
@@ -1515,6 +1792,21 @@ pub trait SubMulAssign<Y = Self, Z = Self> {
     fn sub_mul_assign(&mut self, y: Y, z: Z);
 }
 
+/// Computes Euler's totient function $\varphi$ of a number: the number of positive integers less
+/// than or equal to it that are coprime to it.
+pub trait Totient: Sized {
+    fn totient(&self) -> Self;
+
+    fn totient_from_factors(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Finds the $p$-adic valuation of a number: the largest $k$ such that $p^k$ divides it.
+pub trait Valuation<P> {
+    type Output;
+
+    fn valuation(self, p: P) -> Self::Output;
+}
+
 /// Takes the absolute value of a number, wrapping around at the boundary of the type.
 pub trait WrappingAbs {
     type Output;
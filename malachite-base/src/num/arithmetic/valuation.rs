@@ -0,0 +1,136 @@
+use crate::num::arithmetic::traits::{RemoveFactor, UnsignedAbs, Valuation};
+use crate::num::basic::signeds::PrimitiveSigned;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+
+fn remove_factor_unsigned<T: PrimitiveUnsigned>(mut n: T, p: T) -> (T, u64) {
+    assert_ne!(n, T::ZERO, "Cannot remove factors from zero");
+    assert!(p > T::ONE, "p must be at least 2");
+    let mut v = 0;
+    while n % p == T::ZERO {
+        n /= p;
+        v += 1;
+    }
+    (n, v)
+}
+
+fn remove_factor_signed<
+    U: PrimitiveUnsigned,
+    S: PrimitiveSigned + UnsignedAbs<Output = U> + WrappingFrom<U>,
+>(
+    n: S,
+    p: U,
+) -> (S, u64) {
+    let (cofactor, v) = remove_factor_unsigned(n.unsigned_abs(), p);
+    // If `n` is `S::MIN` and `p` is odd, the cofactor's absolute value is not representable, but
+    // negating it wraps back to `S::MIN`.
+    let cofactor = S::wrapping_from(cofactor);
+    (
+        if n >= S::ZERO {
+            cofactor
+        } else {
+            cofactor.wrapping_neg()
+        },
+        v,
+    )
+}
+
+macro_rules! impl_valuation_unsigned {
+    ($t:ident) => {
+        impl Valuation<$t> for $t {
+            type Output = u64;
+
+            /// Finds the $p$-adic valuation of a number: the largest $k$ such that $p^k$ divides
+            /// it.
+            ///
+            /// $f(n, p) = \max\\{k \in \N : p^k \mid n\\}$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or `p` is less than 2.
+            ///
+            /// # Examples
+            /// See [here](super::valuation#valuation).
+            #[inline]
+            fn valuation(self, p: $t) -> u64 {
+                remove_factor_unsigned(self, p).1
+            }
+        }
+
+        impl RemoveFactor<$t> for $t {
+            type CofactorOutput = $t;
+            type ValuationOutput = u64;
+
+            /// Removes all factors of $p$ from a number, returning the cofactor and the $p$-adic
+            /// valuation of the number.
+            ///
+            /// $f(n, p) = (n/p^k, k)$, where $k = \max\\{k \in \N : p^k \mid n\\}$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or `p` is less than 2.
+            ///
+            /// # Examples
+            /// See [here](super::valuation#remove_factor).
+            #[inline]
+            fn remove_factor(self, p: $t) -> ($t, u64) {
+                remove_factor_unsigned(self, p)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_valuation_unsigned);
+
+macro_rules! impl_valuation_signed {
+    ($u:ident, $s:ident) => {
+        impl Valuation<$u> for $s {
+            type Output = u64;
+
+            /// Finds the $p$-adic valuation of a number: the largest $k$ such that $p^k$ divides
+            /// it.
+            ///
+            /// $f(n, p) = \max\\{k \in \N : p^k \mid n\\}$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or `p` is less than 2.
+            ///
+            /// # Examples
+            /// See [here](super::valuation#valuation).
+            #[inline]
+            fn valuation(self, p: $u) -> u64 {
+                remove_factor_unsigned(self.unsigned_abs(), p).1
+            }
+        }
+
+        impl RemoveFactor<$u> for $s {
+            type CofactorOutput = $s;
+            type ValuationOutput = u64;
+
+            /// Removes all factors of $p$ from a number, returning the cofactor and the $p$-adic
+            /// valuation of the number. The cofactor has the same sign as the number.
+            ///
+            /// $f(n, p) = (n/p^k, k)$, where $k = \max\\{k \in \N : p^k \mid n\\}$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or `p` is less than 2.
+            ///
+            /// # Examples
+            /// See [here](super::valuation#remove_factor).
+            #[inline]
+            fn remove_factor(self, p: $u) -> ($s, u64) {
+                remove_factor_signed(self, p)
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_valuation_signed);
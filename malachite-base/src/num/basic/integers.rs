@@ -7,22 +7,23 @@ use crate::num::arithmetic::traits::{
     CheckedSqrt, CheckedSquare, CheckedSub, CheckedSubMul, DivAssignMod, DivAssignRem, DivExact,
     DivExactAssign, DivMod, DivRem, DivRound, DivRoundAssign, DivisibleBy, DivisibleByPowerOf2,
     EqMod, EqModPowerOf2, ExtendedGcd, FloorRoot, FloorRootAssign, FloorSqrt, FloorSqrtAssign,
-    JacobiSymbol, KroneckerSymbol, LegendreSymbol, Mod, ModAssign, ModPowerOf2, ModPowerOf2Assign,
-    OverflowingAdd, OverflowingAddAssign, OverflowingAddMul, OverflowingAddMulAssign,
-    OverflowingDiv, OverflowingDivAssign, OverflowingMul, OverflowingMulAssign, OverflowingNeg,
-    OverflowingNegAssign, OverflowingPow, OverflowingPowAssign, OverflowingSquare,
-    OverflowingSquareAssign, OverflowingSub, OverflowingSubAssign, OverflowingSubMul,
-    OverflowingSubMulAssign, Parity, Pow, PowAssign, PowerOf2, RemPowerOf2, RemPowerOf2Assign,
-    RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign, RoundToMultiple,
-    RoundToMultipleAssign, RoundToMultipleOfPowerOf2, RoundToMultipleOfPowerOf2Assign,
-    SaturatingAdd, SaturatingAddAssign, SaturatingAddMul, SaturatingAddMulAssign, SaturatingMul,
-    SaturatingMulAssign, SaturatingPow, SaturatingPowAssign, SaturatingSquare,
-    SaturatingSquareAssign, SaturatingSub, SaturatingSubAssign, SaturatingSubMul,
-    SaturatingSubMulAssign, ShlRound, ShlRoundAssign, ShrRound, ShrRoundAssign, Sign, Square,
-    SquareAssign, SubMul, SubMulAssign, WrappingAdd, WrappingAddAssign, WrappingAddMul,
-    WrappingAddMulAssign, WrappingDiv, WrappingDivAssign, WrappingMul, WrappingMulAssign,
-    WrappingNeg, WrappingNegAssign, WrappingPow, WrappingPowAssign, WrappingSquare,
-    WrappingSquareAssign, WrappingSub, WrappingSubAssign, WrappingSubMul, WrappingSubMulAssign,
+    IsPerfectPower, JacobiSymbol, KroneckerSymbol, LegendreSymbol, Mod, ModAssign, ModPowerOf2,
+    ModPowerOf2Assign, OverflowingAdd, OverflowingAddAssign, OverflowingAddMul,
+    OverflowingAddMulAssign, OverflowingDiv, OverflowingDivAssign, OverflowingMul,
+    OverflowingMulAssign, OverflowingNeg, OverflowingNegAssign, OverflowingPow,
+    OverflowingPowAssign, OverflowingSquare, OverflowingSquareAssign, OverflowingSub,
+    OverflowingSubAssign, OverflowingSubMul, OverflowingSubMulAssign, Parity,
+    PerfectPowerDecompose, Pow, PowAssign, PowerOf2, RemPowerOf2, RemPowerOf2Assign, RotateLeft,
+    RotateLeftAssign, RotateRight, RotateRightAssign, RoundToMultiple, RoundToMultipleAssign,
+    RoundToMultipleOfPowerOf2, RoundToMultipleOfPowerOf2Assign, SaturatingAdd, SaturatingAddAssign,
+    SaturatingAddMul, SaturatingAddMulAssign, SaturatingMul, SaturatingMulAssign, SaturatingPow,
+    SaturatingPowAssign, SaturatingSquare, SaturatingSquareAssign, SaturatingSub,
+    SaturatingSubAssign, SaturatingSubMul, SaturatingSubMulAssign, ShlRound, ShlRoundAssign,
+    ShrRound, ShrRoundAssign, Sign, Square, SquareAssign, SubMul, SubMulAssign, WrappingAdd,
+    WrappingAddAssign, WrappingAddMul, WrappingAddMulAssign, WrappingDiv, WrappingDivAssign,
+    WrappingMul, WrappingMulAssign, WrappingNeg, WrappingNegAssign, WrappingPow, WrappingPowAssign,
+    WrappingSquare, WrappingSquareAssign, WrappingSub, WrappingSubAssign, WrappingSubMul,
+    WrappingSubMulAssign,
 };
 use crate::num::basic::traits::{Iverson, One, Two, Zero};
 use crate::num::comparison::traits::{EqAbs, OrdAbs, PartialOrdAbs};
@@ -180,6 +181,7 @@ pub trait PrimitiveInt:
     + HasRandomPrimitiveInts
     + Hash
     + IsInteger
+    + IsPerfectPower
     + Iverson
     + JacobiSymbol<Self>
     + KroneckerSymbol<Self>
@@ -248,6 +250,7 @@ pub trait PrimitiveInt:
     + PartialEq<Self>
     + PartialOrd<Self>
     + PartialOrdAbs<Self>
+    + PerfectPowerDecompose<Output = Self>
     + Pow<u64, Output = Self>
     + PowAssign<u64>
     + PowerOf2<u64>
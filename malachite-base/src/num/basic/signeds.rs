@@ -1,8 +1,9 @@
 use crate::num::arithmetic::traits::{
     Abs, AbsAssign, CeilingDivAssignMod, CeilingDivMod, CeilingMod, CeilingModAssign,
-    CeilingModPowerOf2, CeilingModPowerOf2Assign, CheckedAbs, ExtendedGcd, NegAssign,
-    OverflowingAbs, OverflowingAbsAssign, SaturatingAbs, SaturatingAbsAssign, SaturatingNeg,
-    SaturatingNegAssign, UnsignedAbs, WrappingAbs, WrappingAbsAssign,
+    CeilingModPowerOf2, CeilingModPowerOf2Assign, CheckedAbs, Crt, ExtendedGcd, ModCentered,
+    ModCenteredAssign, NegAssign, OverflowingAbs, OverflowingAbsAssign, SaturatingAbs,
+    SaturatingAbsAssign, SaturatingNeg, SaturatingNegAssign, UnsignedAbs, WrappingAbs,
+    WrappingAbsAssign,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::traits::NegativeOne;
@@ -22,9 +23,12 @@ pub trait PrimitiveSigned:
     + CeilingModPowerOf2Assign
     + CheckedAbs<Output = Self>
     + CheckedHammingDistance
+    + Crt
     + ExtendedGcd<Self, Cofactor = Self>
     + From<i8>
     + HasRandomSignedRange
+    + ModCentered<Self, Output = Self>
+    + ModCenteredAssign<Self>
     + Neg<Output = Self>
     + NegAssign
     + NegativeOne
@@ -1,3 +1,8 @@
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
 /// Provides the constant 0.
 #[allow(clippy::declare_interior_mutable_const)]
 pub trait Zero {
@@ -1,20 +1,27 @@
+use crate::num::arithmetic::crt::CrtBasis;
 use crate::num::arithmetic::traits::{
-    CeilingDivAssignNegMod, CeilingDivNegMod, CeilingLogBase, CeilingLogBase2,
-    CeilingLogBasePowerOf2, CheckedDoubleFactorial, CheckedFactorial, CheckedLcm, CheckedLogBase,
-    CheckedLogBase2, CheckedLogBasePowerOf2, CheckedMultifactorial, CheckedNextPowerOf2,
-    CheckedPrimorial, CheckedSubfactorial, CoprimeWith, DoubleFactorial, ExtendedGcd, Factorial,
-    FloorLogBase, FloorLogBase2, FloorLogBasePowerOf2, Gcd, GcdAssign, IsPowerOf2, Lcm, LcmAssign,
-    ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModMul, ModMulAssign, ModMulPrecomputed,
-    ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModPow, ModPowAssign, ModPowPrecomputed,
-    ModPowPrecomputedAssign, ModPowerOf2, ModPowerOf2Add, ModPowerOf2AddAssign, ModPowerOf2Inverse,
-    ModPowerOf2IsReduced, ModPowerOf2Mul, ModPowerOf2MulAssign, ModPowerOf2Neg,
-    ModPowerOf2NegAssign, ModPowerOf2Pow, ModPowerOf2PowAssign, ModPowerOf2Shl,
-    ModPowerOf2ShlAssign, ModPowerOf2Shr, ModPowerOf2ShrAssign, ModPowerOf2Square,
-    ModPowerOf2SquareAssign, ModPowerOf2Sub, ModPowerOf2SubAssign, ModSquare, ModSquareAssign,
-    ModSquarePrecomputed, ModSquarePrecomputedAssign, ModSub, ModSubAssign, Multifactorial, NegMod,
-    NegModAssign, NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign,
-    Primorial, RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial, XMulYToZZ,
-    XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
+    BinomialCoefficientMod, CarmichaelLambda, CeilingDivAssignNegMod, CeilingDivNegMod,
+    CeilingLogBase, CeilingLogBase2, CeilingLogBasePowerOf2, CheckedDivisorSigma,
+    CheckedDoubleFactorial, CheckedFactorial, CheckedFibonacci, CheckedLcm, CheckedLogBase,
+    CheckedLogBase2, CheckedLogBasePowerOf2, CheckedLucas, CheckedMultifactorial,
+    CheckedMultinomialCoefficient, CheckedNextPowerOf2, CheckedPrimorial, CheckedSubfactorial,
+    CoprimeWith, Crt, CrtPrecomputed, DivisorCount, DivisorSigma, Divisors, DoubleFactorial,
+    ExtendedGcd, Factorial, FactorialMod, Fibonacci, FibonacciMod, FloorLogBase, FloorLogBase2,
+    FloorLogBasePowerOf2, Gcd, GcdAssign, GcdOf, IsPowerOf2, Lcm, LcmAssign, LcmOf, Lucas,
+    LucasMod, LucasSequenceMod, ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModLog, ModMul,
+    ModMulAssign, ModMulPrecomputed, ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModPow,
+    ModPowAssign, ModPowPrecomputed, ModPowPrecomputedAssign, ModPowerOf2, ModPowerOf2Add,
+    ModPowerOf2AddAssign, ModPowerOf2Inverse, ModPowerOf2IsReduced, ModPowerOf2Mul,
+    ModPowerOf2MulAssign, ModPowerOf2Neg, ModPowerOf2NegAssign, ModPowerOf2Pow,
+    ModPowerOf2PowAssign, ModPowerOf2Shl, ModPowerOf2ShlAssign, ModPowerOf2Shr,
+    ModPowerOf2ShrAssign, ModPowerOf2Square, ModPowerOf2SquareAssign, ModPowerOf2Sub,
+    ModPowerOf2SubAssign, ModSqrt, ModSquare, ModSquareAssign, ModSquarePrecomputed,
+    ModSquarePrecomputedAssign, ModSub, ModSubAssign, MoebiusMu, Multifactorial,
+    MultinomialCoefficient, MultinomialCoefficientMod, MultiplicativeOrder, NegMod, NegModAssign,
+    NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign, PrimitiveRoot,
+    Primorial, RemoveFactor, RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial, Totient,
+    Valuation, XMulYToZZ, XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ,
+    XXXXAddYYYYToZZZZ,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::conversion::traits::{
@@ -22,20 +29,30 @@ use crate::num::conversion::traits::{
     SciMantissaAndExponent, VecFromOtherType, VecFromOtherTypeSlice,
 };
 use crate::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use crate::num::factorization::traits::Primes;
+use crate::num::factorization::primes_in_range::PrimesInRangeIterator;
+use crate::num::factorization::traits::{
+    CheckedNextPrime, Factor, IsPrime, NextPrime, NthPrime, PreviousPrime, PrimeCount, Primes,
+    PrimesInRange,
+};
 use crate::num::logic::traits::{BitBlockAccess, HammingDistance};
 
 /// Defines functions on primitive unsigned integer types: uxx and usize.
 pub trait PrimitiveUnsigned:
-    BitBlockAccess<Bits = Self>
+    BinomialCoefficientMod<Self, Self>
+    + BitBlockAccess<Bits = Self>
+    + CarmichaelLambda
     + CeilingLogBase<Output = u64>
     + CeilingLogBase2<Output = u64>
     + CeilingLogBasePowerOf2<u64, Output = u64>
     + CeilingDivAssignNegMod<Self, ModOutput = Self>
     + CeilingDivNegMod<Self, DivOutput = Self, ModOutput = Self>
+    + CheckedDivisorSigma
     + CheckedDoubleFactorial
     + CheckedFactorial
+    + CheckedFibonacci
+    + CheckedLucas
     + CheckedMultifactorial
+    + CheckedMultinomialCoefficient
     + CheckedPrimorial
     + CheckedSubfactorial
     + CheckedLcm<Self, Output = Self>
@@ -43,7 +60,13 @@ pub trait PrimitiveUnsigned:
     + CheckedLogBase2<Output = u64>
     + CheckedLogBasePowerOf2<u64, Output = u64>
     + CheckedNextPowerOf2<Output = Self>
+    + CheckedNextPrime<Output = Self>
     + CoprimeWith<Self>
+    + Crt
+    + CrtPrecomputed<Data = CrtBasis<Self>>
+    + DivisorCount
+    + DivisorSigma
+    + Divisors
     + DoubleFactorial
     + Digits<u8>
     + Digits<u16>
@@ -52,7 +75,11 @@ pub trait PrimitiveUnsigned:
     + Digits<u128>
     + Digits<usize>
     + ExtendedGcd<Self, Gcd = Self>
+    + Factor<FACTORS = Vec<(Self, u64)>>
     + Factorial
+    + FactorialMod<Self>
+    + Fibonacci
+    + FibonacciMod<Self>
     + FloorLogBase<Output = u64>
     + FloorLogBase2<Output = u64>
     + FloorLogBasePowerOf2<u64, Output = u64>
@@ -65,12 +92,19 @@ pub trait PrimitiveUnsigned:
     + FromOtherTypeSlice<usize>
     + Gcd<Self, Output = Self>
     + GcdAssign<Self>
+    + GcdOf<Self>
     + HammingDistance
     + IntegerMantissaAndExponent<Self, u64>
     + IsPowerOf2
+    + IsPrime
     + Lcm<Self, Output = Self>
     + LcmAssign<Self>
+    + LcmOf<Self>
+    + Lucas
+    + LucasMod<Self>
+    + LucasSequenceMod<Self, Output = Self>
     + ModIsReduced<Self>
+    + ModLog<Self, Self, Output = Self>
     + ModAdd<Self, Self, Output = Self>
     + ModAddAssign<Self, Self>
     + ModInverse<Self, Output = Self>
@@ -132,19 +166,26 @@ pub trait PrimitiveUnsigned:
     + ModPowerOf2SubAssign<Self>
     + ModPowPrecomputed<u64, Self, Output = Self>
     + ModPowPrecomputedAssign<u64, Self>
+    + ModSqrt<Self, Output = Self>
     + ModSquare<Self, Output = Self>
     + ModSquareAssign<Self>
     + ModSquarePrecomputed<u64, Self, Output = Self>
     + ModSquarePrecomputedAssign<u64, Self>
     + ModSub<Self, Self, Output = Self>
     + ModSubAssign<Self, Self>
+    + MoebiusMu
     + Multifactorial
+    + MultinomialCoefficient
+    + MultinomialCoefficientMod<Self, Self>
+    + MultiplicativeOrder<Self, Output = Self>
     + NegMod<Self, Output = Self>
     + NegModAssign<Self>
     + NegModPowerOf2<Output = Self>
     + NegModPowerOf2Assign
     + NextPowerOf2<Output = Self>
     + NextPowerOf2Assign
+    + NextPrime<Output = Self>
+    + NthPrime
     + PowerOf2Digits<u8>
     + PowerOf2Digits<u16>
     + PowerOf2Digits<u32>
@@ -157,9 +198,14 @@ pub trait PrimitiveUnsigned:
     + PowerOf2DigitIterable<u64>
     + PowerOf2DigitIterable<u128>
     + PowerOf2DigitIterable<usize>
+    + PreviousPrime<Output = Self>
+    + PrimeCount
     + Primes<I = PrimesIterator<Self>, LI = PrimesLessThanIterator<Self>>
+    + PrimesInRange<RI = PrimesInRangeIterator<Self>>
     + PrimitiveInt
+    + PrimitiveRoot
     + Primorial
+    + RemoveFactor<Self, CofactorOutput = Self, ValuationOutput = u64>
     + RootRem<u64, RootOutput = Self, RemOutput = Self>
     + RootAssignRem<u64, RemOutput = Self>
     + SciMantissaAndExponent<f32, u64>
@@ -167,6 +213,8 @@ pub trait PrimitiveUnsigned:
     + SqrtRem<SqrtOutput = Self, RemOutput = Self>
     + SqrtAssignRem<RemOutput = Self>
     + Subfactorial
+    + Totient
+    + Valuation<Self, Output = u64>
     + VecFromOtherType<u8>
     + VecFromOtherType<u16>
     + VecFromOtherType<u32>
@@ -0,0 +1,499 @@
+use crate::num::arithmetic::traits::{Gcd, ModMulPrecomputed};
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::{Factor, Primes};
+use crate::num::logic::traits::{BitAccess, SignificantBits};
+use std::cmp::min;
+
+// Primes less than this bound are removed by trial division before any other method is tried.
+#[doc(hidden)]
+pub const TRIAL_DIVISION_BOUND: u64 = 1 << 10;
+
+// The largest exponent $k$ for which a number with `bits` significant bits, none of whose prime
+// factors are less than `TRIAL_DIVISION_BOUND`, can be a perfect $k$th power.
+#[doc(hidden)]
+pub const fn max_perfect_power_exponent(bits: u64) -> u64 {
+    bits / 10
+}
+
+// The number of iterations between successive gcds in the Pollard-Brent rho algorithm.
+#[doc(hidden)]
+pub const RHO_BATCH_SIZE: u64 = 1 << 7;
+
+// The number of Pollard-Brent rho iterations tried before falling back to ECM on a number too large
+// for rho to be reliably fast. This is enough to find most factors of up to about 28 bits; larger
+// factors are found faster by ECM, for primitive integers and `Natural`s alike.
+#[doc(hidden)]
+pub const RHO_ITERATIONS_BEFORE_ECM: u64 = 1 << 14;
+
+// Pairs of the stage 1 bound $B_1$ and the number of curves to try with that bound, in order of
+// increasing factor size. Each pair is roughly optimal for finding factors of about 15, 20, 25,
+// ..., 50 decimal digits. The stage 2 bound is always $100B_1$. These values are taken from the
+// GMP-ECM documentation.
+#[doc(hidden)]
+pub const ECM_PARAMETERS: [(u64, u64); 8] = [
+    (2000, 25),
+    (11000, 90),
+    (50000, 300),
+    (250000, 700),
+    (1000000, 1800),
+    (3000000, 5100),
+    (11000000, 10600),
+    (43000000, 19300),
+];
+
+// The giant-step size used in stage 2 of ECM.
+#[doc(hidden)]
+pub const ECM_STAGE_2_D: u64 = 210;
+
+// Sorts a list of primes and exponents by prime, combining the exponents of equal primes.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+#[doc(hidden)]
+pub fn merge_factors<T: Ord>(mut factors: Vec<(T, u64)>) -> Vec<(T, u64)> {
+    factors.sort_unstable_by(|(p, _), (q, _)| p.cmp(q));
+    let mut merged: Vec<(T, u64)> = Vec::with_capacity(factors.len());
+    for (p, e) in factors {
+        match merged.last_mut() {
+            Some((q, f)) if *q == p => *f += e,
+            _ => merged.push((p, e)),
+        }
+    }
+    merged
+}
+
+// Removes every prime factor less than `TRIAL_DIVISION_BOUND` from `n`, pushing the factors and
+// their exponents onto `factors`. If this determines the rest of the factorization (because `n`
+// has become 1 or prime), `n` is also pushed onto `factors` if necessary, and `n` is set to 1.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn factor_trial_division<T: PrimitiveUnsigned>(n: &mut T, factors: &mut Vec<(T, u64)>) {
+    let zeros = n.trailing_zeros();
+    if zeros != 0 {
+        factors.push((T::TWO, zeros));
+        *n >>= zeros;
+    }
+    for p in u64::primes_less_than(&TRIAL_DIVISION_BOUND).skip(1) {
+        if !T::convertible_from(p) {
+            break;
+        }
+        let p = T::wrapping_from(p);
+        if p.checked_square().map_or(true, |p_squared| p_squared > *n) {
+            break;
+        }
+        let mut exponent = 0;
+        while n.divisible_by(p) {
+            *n /= p;
+            exponent += 1;
+        }
+        if exponent != 0 {
+            factors.push((p, exponent));
+        }
+    }
+    if *n == T::ONE {
+        return;
+    }
+    let n_u64: u64 = n.wrapping_into();
+    if n.significant_bits() <= 20 && n_u64 < TRIAL_DIVISION_BOUND * TRIAL_DIVISION_BOUND {
+        factors.push((*n, 1));
+        *n = T::ONE;
+    }
+}
+
+// Computes `|x - y|`.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+#[inline]
+fn abs_diff<T: PrimitiveUnsigned>(x: T, y: T) -> T {
+    if x >= y {
+        x - y
+    } else {
+        y - x
+    }
+}
+
+// Attempts to find a nontrivial factor of `n` using Brent's variant of Pollard's rho algorithm,
+// iterating $x \mapsto x^2 + c$ starting from 2. Gives up and returns `None` if the algorithm fails
+// with this $c$ or no factor has been found after roughly `max_iterations` iterations. Assumes that
+// `n` is odd and composite, and that `0 < c < n - 2`.
+//
+// # Expected complexity
+// $T(n) = O(n^{1/4})$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+pub_test! {pollard_brent_rho<T: PrimitiveUnsigned>(n: T, c: T, max_iterations: u64) -> Option<T> {
+    let data = T::precompute_mod_mul_data(&n);
+    let f = |x: T| x.mod_mul_precomputed(x, n, &data).mod_add(c, n);
+    let mut y = T::TWO;
+    let mut x = y;
+    let mut ys = y;
+    let mut q = T::ONE;
+    let mut g = T::ONE;
+    let mut r = 1;
+    let mut iterations = 0;
+    while g == T::ONE {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+        let mut k = 0;
+        while k < r && g == T::ONE {
+            ys = y;
+            for _ in 0..min(RHO_BATCH_SIZE, r - k) {
+                y = f(y);
+                q.mod_mul_precomputed_assign(abs_diff(x, y), n, &data);
+            }
+            g = q.gcd(n);
+            k += RHO_BATCH_SIZE;
+        }
+        iterations += r;
+        if g == T::ONE && iterations >= max_iterations {
+            return None;
+        }
+        r <<= 1;
+    }
+    if g == n {
+        // The batched product became divisible by `n`; step through the last batch one iteration
+        // at a time.
+        loop {
+            ys = f(ys);
+            g = abs_diff(x, ys).gcd(n);
+            if g != T::ONE {
+                break;
+            }
+        }
+    }
+    if g == n {
+        None
+    } else {
+        Some(g)
+    }
+}}
+
+// A point on a Montgomery curve $By^2 = x^3 + Ax^2 + x$ modulo `n`, in projective $(X : Z)$
+// coordinates with the $y$-coordinate dropped.
+type EcmPoint<T> = (T, T);
+
+// Arithmetic on the Montgomery curve with $(A + 2) / 4 = $`a_24`, modulo `n`.
+struct EcmCurve<T: PrimitiveUnsigned> {
+    n: T,
+    data: <T as ModMulPrecomputed<T, T>>::Data,
+    a_24: T,
+}
+
+impl<T: PrimitiveUnsigned> EcmCurve<T> {
+    #[inline]
+    fn mul(&self, x: T, y: T) -> T {
+        x.mod_mul_precomputed(y, self.n, &self.data)
+    }
+
+    // Computes $2P$.
+    fn double(&self, (x, z): EcmPoint<T>) -> EcmPoint<T> {
+        let n = self.n;
+        let s = x.mod_add(z, n);
+        let s = self.mul(s, s);
+        let d = x.mod_sub(z, n);
+        let d = self.mul(d, d);
+        let t = s.mod_sub(d, n);
+        (
+            self.mul(s, d),
+            self.mul(t, d.mod_add(self.mul(self.a_24, t), n)),
+        )
+    }
+
+    // Computes $P + Q$, given $P - Q$.
+    fn add(
+        &self,
+        (xp, zp): EcmPoint<T>,
+        (xq, zq): EcmPoint<T>,
+        (xd, zd): EcmPoint<T>,
+    ) -> EcmPoint<T> {
+        let n = self.n;
+        let u = self.mul(xp.mod_sub(zp, n), xq.mod_add(zq, n));
+        let v = self.mul(xp.mod_add(zp, n), xq.mod_sub(zq, n));
+        let s = u.mod_add(v, n);
+        let d = u.mod_sub(v, n);
+        (self.mul(zd, self.mul(s, s)), self.mul(xd, self.mul(d, d)))
+    }
+
+    // Computes $kP$ using the Montgomery ladder. Assumes that $k > 0$.
+    fn scale(&self, p: EcmPoint<T>, k: u64) -> EcmPoint<T> {
+        let mut r0 = p;
+        let mut r1 = self.double(p);
+        for i in (0..k.significant_bits() - 1).rev() {
+            if k.get_bit(i) {
+                r0 = self.add(r1, r0, p);
+                r1 = self.double(r1);
+            } else {
+                r1 = self.add(r1, r0, p);
+                r0 = self.double(r0);
+            }
+        }
+        r0
+    }
+}
+
+// Returns a nontrivial factor of `n` if `g = gcd(x, n)` is one, and `None` otherwise.
+#[inline]
+fn nontrivial_gcd<T: PrimitiveUnsigned>(x: T, n: T) -> Option<T> {
+    let g = x.gcd(n);
+    if g == T::ONE || g == n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// Attempts to find a nontrivial factor of `n` using one curve of Lenstra's elliptic curve method,
+// with stage 1 bound `b_1` and stage 2 bound `b_2`. The curve is chosen using Suyama's
+// parametrization with parameter `sigma`. Assumes that `n` is odd, composite, has no prime factors
+// less than `TRIAL_DIVISION_BOUND`, and is greater than `sigma`; that `sigma > 5`; and that
+// `b_1 >= 2 * ECM_STAGE_2_D`.
+//
+// # Worst-case complexity
+// $T(n) = O(B_2)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $B_2$ is `b_2`.
+pub_test! {ecm_one_curve<T: PrimitiveUnsigned>(n: T, sigma: u64, b_1: u64, b_2: u64) -> Option<T> {
+    let mut curve = EcmCurve {
+        n,
+        data: T::precompute_mod_mul_data(&n),
+        a_24: T::ZERO,
+    };
+    // u = sigma^2 - 5, v = 4 * sigma, x_0 = u^3, z_0 = v^3
+    let sigma = T::wrapping_from(sigma);
+    let u = curve.mul(sigma, sigma).mod_sub(T::from(5u8), n);
+    let v = curve.mul(sigma, T::from(4u8));
+    let u_cubed = curve.mul(curve.mul(u, u), u);
+    let v_cubed = curve.mul(curve.mul(v, v), v);
+    // (A + 2) / 4 = (v - u)^3 * (3u + v) / (16 * u^3 * v)
+    let v_minus_u = v.mod_sub(u, n);
+    let numerator = curve.mul(
+        curve.mul(curve.mul(v_minus_u, v_minus_u), v_minus_u),
+        curve.mul(u, T::from(3u8)).mod_add(v, n),
+    );
+    let denominator = curve.mul(curve.mul(u_cubed, v), T::from(16u8));
+    let g = denominator.gcd(n);
+    if g != T::ONE {
+        return if g == n { None } else { Some(g) };
+    }
+    curve.a_24 = curve.mul(numerator, denominator.mod_inverse(n).unwrap());
+    // Stage 1: multiply by every prime power no greater than b_1
+    let mut q = (u_cubed, v_cubed);
+    for p in u64::primes_less_than_or_equal_to(&b_1) {
+        let mut power = p;
+        while let Some(next_power) = power.checked_mul(p) {
+            if next_power > b_1 {
+                break;
+            }
+            power = next_power;
+        }
+        q = curve.scale(q, power);
+    }
+    let g = q.1.gcd(n);
+    if g == n {
+        return None;
+    } else if g != T::ONE {
+        return Some(g);
+    }
+    // Stage 2: every prime in (b_1, b_2] is m * D +/- j for some j coprime to D, 0 < j < D / 2. If
+    // the order of q modulo a prime factor of n is such a prime, then m * D * q = +/- j * q modulo
+    // that factor, so the cross product of their coordinates is divisible by it.
+    let half_d = ECM_STAGE_2_D >> 1;
+    let q_2 = curve.double(q);
+    let mut baby_steps: Vec<EcmPoint<T>> = Vec::with_capacity(usize::wrapping_from(half_d));
+    let mut previous = q;
+    let mut current = q;
+    for j in (1..half_d).step_by(2) {
+        if j > 1 {
+            // (j + 2) * q = j * q + 2 * q, and j * q - 2 * q = (j - 2) * q
+            let next = curve.add(current, q_2, previous);
+            previous = current;
+            current = next;
+        }
+        if j.gcd(ECM_STAGE_2_D) == 1 {
+            baby_steps.push(current);
+        }
+    }
+    let giant_step = curve.scale(q, ECM_STAGE_2_D);
+    let mut m = b_1 / ECM_STAGE_2_D;
+    let mut previous = curve.scale(q, (m - 1) * ECM_STAGE_2_D);
+    let mut current = curve.scale(q, m * ECM_STAGE_2_D);
+    let mut product = T::ONE;
+    while m * ECM_STAGE_2_D <= b_2 + half_d {
+        for &(x_j, z_j) in &baby_steps {
+            product = curve.mul(
+                product,
+                curve.mul(current.0, z_j).mod_sub(curve.mul(x_j, current.1), n),
+            );
+        }
+        let next = curve.add(current, giant_step, previous);
+        previous = current;
+        current = next;
+        m += 1;
+    }
+    nontrivial_gcd(product, n)
+}}
+
+// Finds a nontrivial factor of `n` using ECM, trying curves with increasing bounds until one
+// succeeds. Assumes that `n` is odd, composite, not a perfect power, and has no prime factors less
+// than `TRIAL_DIVISION_BOUND`.
+//
+// # Expected complexity
+// $T(n) = O(e^{\sqrt{2 \log p \log\log p}})$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $p$ is the smallest prime factor of `n`.
+fn ecm<T: PrimitiveUnsigned>(n: T) -> T {
+    let mut sigma = 6;
+    for (i, &(b_1, curves)) in ECM_PARAMETERS.iter().enumerate() {
+        let last = i == ECM_PARAMETERS.len() - 1;
+        let mut curve = 0;
+        while last || curve < curves {
+            if let Some(g) = ecm_one_curve(n, sigma, b_1, 100 * b_1) {
+                return g;
+            }
+            sigma += 1;
+            curve += 1;
+        }
+    }
+    unreachable!()
+}
+
+// Finds a nontrivial factor of `n`. Assumes that `n` is odd, composite, not a perfect power, and
+// has no prime factors less than `TRIAL_DIVISION_BOUND`.
+//
+// Pollard-Brent rho finds every factor of a number with at most 64 bits quickly. For larger
+// numbers, rho is given a limited number of iterations to find small factors, and ECM is used
+// afterwards.
+fn find_factor<T: PrimitiveUnsigned>(n: T) -> T {
+    if n.significant_bits() <= u64::WIDTH {
+        let mut c = T::ONE;
+        loop {
+            if let Some(g) = pollard_brent_rho(n, c, u64::MAX) {
+                return g;
+            }
+            c += T::ONE;
+        }
+    } else {
+        pollard_brent_rho(n, T::ONE, RHO_ITERATIONS_BEFORE_ECM).unwrap_or_else(|| ecm(n))
+    }
+}
+
+// Returns the prime factorization of `n`, sorted by prime.
+fn factor_unsigned<T: PrimitiveUnsigned>(n: T) -> Vec<(T, u64)> {
+    assert_ne!(n, T::ZERO, "Cannot factor 0");
+    let mut n = n;
+    let mut factors = Vec::new();
+    factor_trial_division(&mut n, &mut factors);
+    if n == T::ONE {
+        return factors;
+    }
+    let mut composites = vec![(n, 1)];
+    'outer: while let Some((m, multiplicity)) = composites.pop() {
+        if m.is_prime() {
+            factors.push((m, multiplicity));
+            continue;
+        }
+        for k in
+            u64::primes_less_than_or_equal_to(&max_perfect_power_exponent(m.significant_bits()))
+        {
+            if let Some(root) = m.checked_root(k) {
+                composites.push((root, multiplicity * k));
+                continue 'outer;
+            }
+        }
+        let g = find_factor(m);
+        composites.push((g, multiplicity));
+        composites.push((m / g, multiplicity));
+    }
+    merge_factors(factors)
+}
+
+macro_rules! impl_factor {
+    ($t:ident) => {
+        impl Factor for $t {
+            type FACTORS = Vec<($t, u64)>;
+
+            /// Returns the prime factorization of a number, as a [`Vec`] of primes and their
+            /// exponents, sorted in increasing order of the primes.
+            ///
+            /// Primes less than $2^{10}$ are removed by trial division, and the remaining factors
+            /// are found using Brent's variant of Pollard's rho algorithm. The result is always
+            /// exact.
+            ///
+            /// The factorization of 1 is empty.
+            ///
+            /// # Expected complexity
+            /// $T(n) = O(n^{1/4})$
+            ///
+            /// $M(n) = O(\log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::factor#factor).
+            #[inline]
+            fn factor(&self) -> Vec<($t, u64)> {
+                factor_unsigned(*self)
+            }
+        }
+    };
+}
+impl_factor!(u8);
+impl_factor!(u16);
+impl_factor!(u32);
+impl_factor!(u64);
+impl_factor!(usize);
+
+impl Factor for u128 {
+    type FACTORS = Vec<(u128, u64)>;
+
+    /// Returns the prime factorization of a `u128`, as a [`Vec`] of primes and their exponents,
+    /// sorted in increasing order of the primes.
+    ///
+    /// If the number fits in a `u64`, the `u64` factorization is used. Otherwise, primes less than
+    /// $2^{10}$ are removed by trial division, medium-sized factors are found using Brent's
+    /// variant of Pollard's rho algorithm, and large factors are found using Lenstra's elliptic
+    /// curve method. The result is always exact.
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(e^{\sqrt{2 \log p \log\log p}})$
+    ///
+    /// $M(n) = O(\log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self`, and $p$ is the second-largest
+    /// prime factor of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(u128, u64)> {
+        if let Ok(n) = u64::try_from(*self) {
+            n.factor()
+                .into_iter()
+                .map(|(p, e)| (u128::from(p), e))
+                .collect()
+        } else {
+            factor_unsigned(*self)
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use crate::num::arithmetic::traits::ModMulPrecomputed;
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::IsPrime;
+use crate::num::logic::traits::SignificantBits;
+
+// The primes less than 53. A number less than 53^2 = 2809 with no prime factors in this list is
+// prime.
+pub(crate) const SMALL_PRIMES: [u8; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+pub(crate) const SMALL_PRIMES_SQUARE_BOUND: u64 = 53 * 53;
+
+// Returns `Some(true)` or `Some(false)` if trial division by `SMALL_PRIMES` settles whether `n` is
+// prime, and `None` otherwise.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn is_prime_by_trial_division<T: PrimitiveUnsigned>(n: T) -> Option<bool> {
+    if n < T::TWO {
+        return Some(false);
+    }
+    for &p in &SMALL_PRIMES {
+        let p = T::from(p);
+        if n == p {
+            return Some(true);
+        } else if n.divisible_by(p) {
+            return Some(false);
+        }
+    }
+    let n_u64: u64 = n.wrapping_into();
+    if n.significant_bits() <= 12 && n_u64 < SMALL_PRIMES_SQUARE_BOUND {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+// Computes `x ^ exp` mod `m` by binary exponentiation. Unlike `ModPow`, the exponent may be as
+// wide as `T`.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `exp.significant_bits()`.
+fn mod_pow_wide<T: PrimitiveUnsigned>(
+    x: T,
+    exp: T,
+    m: T,
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+) -> T {
+    let mut out = T::ONE;
+    for i in (0..exp.significant_bits()).rev() {
+        out.mod_mul_precomputed_assign(out, m, data);
+        if exp.get_bit(i) {
+            out.mod_mul_precomputed_assign(x, m, data);
+        }
+    }
+    out
+}
+
+// Determines whether `n` is a strong probable prime to base `a`, where `n - 1 = d * 2^s` and `d`
+// is odd. Assumes that `n` is odd and greater than 2, and that `0 < a < n`.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn is_strong_probable_prime<T: PrimitiveUnsigned>(
+    n: T,
+    a: T,
+    d: T,
+    s: u64,
+    data: &<T as ModMulPrecomputed<T, T>>::Data,
+) -> bool {
+    let n_minus_1 = n - T::ONE;
+    let mut x = mod_pow_wide(a, d, n, data);
+    if x == T::ONE || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_mul_precomputed_assign(x, n, data);
+        if x == n_minus_1 {
+            return true;
+        } else if x == T::ONE {
+            return false;
+        }
+    }
+    false
+}
+
+// Runs the Miller-Rabin test on `n` with the given bases. Assumes that `n` is odd, greater than 2,
+// and fits in a `u64`. If the bases are chosen appropriately for the range of `n`, the result is
+// exact.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn is_prime_miller_rabin<T: PrimitiveUnsigned>(n: T, bases: &[u64]) -> bool {
+    let n_minus_1 = n - T::ONE;
+    let s = n_minus_1.trailing_zeros();
+    let d = n_minus_1 >> s;
+    let data = T::precompute_mod_mul_data(&n);
+    let n_u64: u64 = n.wrapping_into();
+    for &a in bases {
+        let a = a % n_u64;
+        if a != 0 && !is_strong_probable_prime(n, T::wrapping_from(a), d, s, &data) {
+            return false;
+        }
+    }
+    true
+}
+
+// Computes `x / 2` mod `m`. Assumes that `x < m` and that `m` is odd.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn half_mod<T: PrimitiveUnsigned>(x: T, m: T) -> T {
+    if x.even() {
+        x >> 1
+    } else {
+        // (x + m) / 2, written so as not to overflow
+        (x >> 1) + (m >> 1) + T::ONE
+    }
+}
+
+// Runs the strong Lucas probable-prime test on `n`, with parameters $P$, $Q$, and $D$ chosen using
+// Selfridge's Method A. Assumes that `n` is odd, is not a perfect square, has no prime factors less
+// than 53, and is less than `T::MAX`.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn is_strong_lucas_probable_prime<T: PrimitiveUnsigned>(n: T) -> bool {
+    // Find the first D in 5, -7, 9, -11, ... with (D/n) = -1. D and Q are stored reduced mod n.
+    let mut d_abs = 5u64;
+    let mut negative = false;
+    let (d, q) = loop {
+        let d_abs_t = T::wrapping_from(d_abs);
+        let d = if negative { n - d_abs_t } else { d_abs_t };
+        match d.jacobi_symbol(n) {
+            -1 => {
+                // Q = (1 - D) / 4
+                let q = if negative {
+                    T::wrapping_from((d_abs + 1) >> 2)
+                } else {
+                    n - T::wrapping_from((d_abs - 1) >> 2)
+                };
+                break (d, q);
+            }
+            // n has no small factors, so this means that n and D share a large factor
+            0 => return false,
+            _ => {}
+        }
+        d_abs += 2;
+        negative = !negative;
+    };
+    let data = T::precompute_mod_mul_data(&n);
+    let n_plus_1 = n + T::ONE;
+    let s = n_plus_1.trailing_zeros();
+    let k = n_plus_1 >> s;
+    // U_1 = 1, V_1 = P = 1, Q^1 = Q
+    let mut u = T::ONE;
+    let mut v = T::ONE;
+    let mut q_k = q;
+    for i in (0..k.significant_bits() - 1).rev() {
+        // U_{2k} = U_k V_k, V_{2k} = V_k^2 - 2Q^k
+        u.mod_mul_precomputed_assign(v, n, &data);
+        v = v
+            .mod_mul_precomputed(v, n, &data)
+            .mod_sub(q_k.mod_add(q_k, n), n);
+        q_k.mod_mul_precomputed_assign(q_k, n, &data);
+        if k.get_bit(i) {
+            // U_{k+1} = (P U_k + V_k) / 2, V_{k+1} = (D U_k + P V_k) / 2
+            let du = d.mod_mul_precomputed(u, n, &data);
+            u = half_mod(u.mod_add(v, n), n);
+            v = half_mod(du.mod_add(v, n), n);
+            q_k.mod_mul_precomputed_assign(q, n, &data);
+        }
+    }
+    if u == T::ZERO || v == T::ZERO {
+        return true;
+    }
+    for _ in 1..s {
+        v = v
+            .mod_mul_precomputed(v, n, &data)
+            .mod_sub(q_k.mod_add(q_k, n), n);
+        if v == T::ZERO {
+            return true;
+        }
+        q_k.mod_mul_precomputed_assign(q_k, n, &data);
+    }
+    false
+}
+
+// Runs the Baillie-PSW test on `n`: a strong probable-prime test to base 2 followed by a strong
+// Lucas probable-prime test. Assumes that `n` is odd, has no prime factors less than 53, and is
+// less than `T::MAX`. No composite number is known to pass this test, and it is known that none
+// less than $2^{64}$ does.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+pub_test! {is_prime_baillie_psw<T: PrimitiveUnsigned>(n: T) -> bool {
+    let n_minus_1 = n - T::ONE;
+    let s = n_minus_1.trailing_zeros();
+    let d = n_minus_1 >> s;
+    let data = T::precompute_mod_mul_data(&n);
+    is_strong_probable_prime(n, T::TWO, d, s, &data)
+        && n.checked_sqrt().is_none()
+        && is_strong_lucas_probable_prime(n)
+}}
+
+// Bases for which the Miller-Rabin test is deterministic for all n < 1373653, due to Pomerance,
+// Selfridge, and Wagstaff.
+const MILLER_RABIN_BASES_16: [u64; 2] = [2, 3];
+
+// Bases for which the Miller-Rabin test is deterministic for all n < 4759123141, due to Jaeschke.
+const MILLER_RABIN_BASES_32: [u64; 3] = [2, 7, 61];
+
+// Bases for which the Miller-Rabin test is deterministic for all n < 2^64, due to Sinclair.
+const MILLER_RABIN_BASES_64: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+macro_rules! impl_is_prime_miller_rabin {
+    ($t:ident, $bases:ident) => {
+        impl IsPrime for $t {
+            /// Determines whether a number is prime.
+            ///
+            /// Small factors are removed by trial division, and the Miller-Rabin test is then run
+            /// with a set of bases known to give the correct answer for every number of this
+            /// width, so the result is always exact.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#is_prime).
+            #[inline]
+            fn is_prime(&self) -> bool {
+                is_prime_by_trial_division(*self)
+                    .unwrap_or_else(|| is_prime_miller_rabin(*self, &$bases))
+            }
+        }
+    };
+}
+impl_is_prime_miller_rabin!(u16, MILLER_RABIN_BASES_16);
+impl_is_prime_miller_rabin!(u32, MILLER_RABIN_BASES_32);
+impl_is_prime_miller_rabin!(u64, MILLER_RABIN_BASES_64);
+
+impl IsPrime for u8 {
+    /// Determines whether a `u8` is prime.
+    ///
+    /// Every composite `u8` has a prime factor less than 16, so trial division suffices.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        is_prime_by_trial_division(*self).unwrap()
+    }
+}
+
+impl IsPrime for u128 {
+    /// Determines whether a `u128` is prime.
+    ///
+    /// If the number fits in a `u64`, the exact `u64` test is used. Otherwise, small factors are
+    /// removed by trial division and the Baillie-PSW test is run. No composite number is known to
+    /// pass the Baillie-PSW test, but it has not been proven that none exists in this range.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    fn is_prime(&self) -> bool {
+        let n = *self;
+        if n.significant_bits() <= u64::WIDTH {
+            u64::wrapping_from(n).is_prime()
+        } else {
+            is_prime_by_trial_division(n).unwrap_or_else(|| is_prime_baillie_psw(n))
+        }
+    }
+}
+
+impl IsPrime for usize {
+    /// Determines whether a `usize` is prime.
+    ///
+    /// The result is always exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        if usize::WIDTH == u32::WIDTH {
+            u32::wrapping_from(*self).is_prime()
+        } else {
+            u64::wrapping_from(*self).is_prime()
+        }
+    }
+}
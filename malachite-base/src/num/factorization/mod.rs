@@ -1,3 +1,110 @@
+/// [`Factor`](traits::Factor), a trait for finding the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::factorization::traits::Factor;
+///
+/// assert_eq!(1u8.factor(), &[]);
+/// assert_eq!(12u8.factor(), &[(2, 2), (3, 1)]);
+/// assert_eq!(65521u16.factor(), &[(65521, 1)]);
+/// assert_eq!(4294967295u32.factor(), &[(3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)]);
+/// assert_eq!(
+///     18446744073709551615u64.factor(),
+///     &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]
+/// );
+/// assert_eq!(
+///     70421805103159615449676617095090001433u128.factor(),
+///     &[(59649589127497217, 1), (1180591620717411303449, 1)]
+/// );
+/// ```
+pub mod factor;
+/// [`IsPrime`](traits::IsPrime), a trait for testing whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsPrime;
+///
+/// assert_eq!(0u8.is_prime(), false);
+/// assert_eq!(1u8.is_prime(), false);
+/// assert_eq!(2u8.is_prime(), true);
+/// assert_eq!(91u8.is_prime(), false);
+/// assert_eq!(97u8.is_prime(), true);
+/// assert_eq!(65521u16.is_prime(), true);
+/// assert_eq!(4294967291u32.is_prime(), true);
+/// assert_eq!(3825123056546413051u64.is_prime(), false);
+/// assert_eq!(18446744073709551557u64.is_prime(), true);
+/// assert_eq!(340282366920938463463374607431768211297u128.is_prime(), true);
+/// ```
+pub mod is_prime;
+/// [`NextPrime`](traits::NextPrime) and [`CheckedNextPrime`](traits::CheckedNextPrime), traits
+/// for finding the smallest prime greater than a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NextPrime;
+///
+/// assert_eq!(0u8.next_prime(), 2);
+/// assert_eq!(2u8.next_prime(), 3);
+/// assert_eq!(10u16.next_prime(), 11);
+/// assert_eq!(11u16.next_prime(), 13);
+/// assert_eq!(4294967290u32.next_prime(), 4294967291);
+/// assert_eq!(1000000000000000000u64.next_prime(), 1000000000000000003);
+/// assert_eq!(18446744073709551557u128.next_prime(), 18446744073709551629);
+/// ```
+///
+/// # checked_next_prime
+/// ```
+/// use malachite_base::num::factorization::traits::CheckedNextPrime;
+///
+/// assert_eq!(10u8.checked_next_prime(), Some(11));
+/// assert_eq!(251u8.checked_next_prime(), None);
+/// assert_eq!(65520u16.checked_next_prime(), Some(65521));
+/// assert_eq!(65521u16.checked_next_prime(), None);
+/// assert_eq!(18446744073709551557u64.checked_next_prime(), None);
+/// ```
+pub mod next_prime;
+/// [`PreviousPrime`](traits::PreviousPrime), a trait for finding the largest prime less than a
+/// number.
+///
+/// # previous_prime
+/// ```
+/// use malachite_base::num::factorization::traits::PreviousPrime;
+///
+/// assert_eq!(0u8.previous_prime(), None);
+/// assert_eq!(2u8.previous_prime(), None);
+/// assert_eq!(3u8.previous_prime(), Some(2));
+/// assert_eq!(u8::MAX.previous_prime(), Some(251));
+/// assert_eq!(11u16.previous_prime(), Some(7));
+/// assert_eq!(u32::MAX.previous_prime(), Some(4294967291));
+/// assert_eq!(1000000000000000000u64.previous_prime(), Some(999999999999999989));
+/// assert_eq!(18446744073709551629u128.previous_prime(), Some(18446744073709551557));
+/// ```
+pub mod previous_prime;
+/// [`PrimeCount`](traits::PrimeCount) and [`NthPrime`](traits::NthPrime), traits for counting the
+/// primes less than or equal to a number and for finding the $n$th prime.
+///
+/// # prime_count
+/// ```
+/// use malachite_base::num::factorization::traits::PrimeCount;
+///
+/// assert_eq!(0u8.prime_count(), 0);
+/// assert_eq!(10u8.prime_count(), 4);
+/// assert_eq!(100u16.prime_count(), 25);
+/// assert_eq!(1000000u32.prime_count(), 78498);
+/// assert_eq!(10000000000u64.prime_count(), 455052511);
+/// ```
+///
+/// # nth_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NthPrime;
+///
+/// assert_eq!(u8::nth_prime(1), 2);
+/// assert_eq!(u8::nth_prime(10), 29);
+/// assert_eq!(u16::nth_prime(100), 541);
+/// assert_eq!(u32::nth_prime(1000000), 15485863);
+/// assert_eq!(u64::nth_prime(1000000000), 22801763489);
+/// ```
+pub mod prime_count;
 /// An efficient prime sieve.
 pub mod prime_sieve;
 /// [`Primes`](traits::Primes), a trait for generating prime numbers.
@@ -49,5 +156,37 @@ pub mod prime_sieve;
 /// );
 /// ```
 pub mod primes;
+/// [`PrimesInRange`](traits::PrimesInRange), a trait for generating the primes in a range.
+///
+/// # primes_in_range
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::PrimesInRange;
+///
+/// assert_eq!(u8::primes_in_range(&10, &10).collect_vec(), &[]);
+/// assert_eq!(u8::primes_in_range(&0, &10).collect_vec(), &[2, 3, 5, 7]);
+/// assert_eq!(u16::primes_in_range(&100, &150).collect_vec(), &[
+///     101, 103, 107, 109, 113, 127, 131, 137, 139, 149
+/// ]);
+/// assert_eq!(
+///     u64::primes_in_range(&1000000000000, &1000000000100).collect_vec(),
+///     &[1000000000039, 1000000000061, 1000000000063, 1000000000091]
+/// );
+/// ```
+///
+/// # primes_in_inclusive_range
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::PrimesInRange;
+///
+/// assert_eq!(u8::primes_in_inclusive_range(&10, &10).collect_vec(), &[]);
+/// assert_eq!(u8::primes_in_inclusive_range(&11, &11).collect_vec(), &[11]);
+/// assert_eq!(u8::primes_in_inclusive_range(&240, &255).collect_vec(), &[241, 251]);
+/// assert_eq!(
+///     u64::primes_in_inclusive_range(&18446744073709551500, &u64::MAX).collect_vec(),
+///     &[18446744073709551521, 18446744073709551533, 18446744073709551557]
+/// );
+/// ```
+pub mod primes_in_range;
 /// Various traits for generating primes, primality testing, and factorization.
 pub mod traits;
@@ -0,0 +1,154 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{CheckedNextPrime, NextPrime};
+
+// The number of odd candidates sieved at once by `next_prime` and `previous_prime`.
+pub(crate) const PRIME_SEARCH_WINDOW_SIZE: usize = 1 << 6;
+
+// Windows are sieved by the odd primes less than this bound. A primitive's gaps between primes
+// are short, so a small window and bound suffice; the primes fit in a `u8`, which keeps the
+// remainders cheap to compute.
+pub(crate) const PRIME_SEARCH_SIEVE_BOUND: u64 = 1 << 8;
+
+// Below this, trial division and Miller-Rabin are cheap enough that computing the sieve's
+// remainders costs more than it saves, so candidates are tested one at a time.
+pub(crate) const PRIME_SEARCH_SIEVE_THRESHOLD: u64 = 1 << 48;
+
+const SIEVING_PRIME_COUNT: usize = 53;
+
+const fn odd_primes_less_than_sieve_bound() -> [u8; SIEVING_PRIME_COUNT] {
+    let mut primes = [0; SIEVING_PRIME_COUNT];
+    let mut count = 0;
+    let mut n = 3;
+    while n < PRIME_SEARCH_SIEVE_BOUND {
+        let mut d = 3;
+        while d * d <= n && n % d != 0 {
+            d += 2;
+        }
+        if d * d > n {
+            primes[count] = n as u8;
+            count += 1;
+        }
+        n += 2;
+    }
+    primes
+}
+
+const SIEVING_PRIMES: [u8; SIEVING_PRIME_COUNT] = odd_primes_less_than_sieve_bound();
+
+// Marks the entries of `sieve` whose numbers have an odd prime factor less than
+// `PRIME_SEARCH_SIEVE_BOUND`. Entry $i$ stands for `start` + 2$i$ when `ascending`, and for `start`
+// - 2$i$ otherwise; numbers past `T::MAX` are marked as if the arithmetic did not wrap. `start`
+// must be odd and every number in the window must exceed the bound, so that no sieving prime
+// marks itself.
+pub(crate) fn sieve_odd_window<T: PrimitiveUnsigned>(
+    start: T,
+    ascending: bool,
+    sieve: &mut [bool; PRIME_SEARCH_WINDOW_SIZE],
+) {
+    sieve.fill(false);
+    for &p in &SIEVING_PRIMES {
+        let mut r: usize = (start % T::from(p)).wrapping_into();
+        let p = usize::from(p);
+        if ascending && r != 0 {
+            r = p - r;
+        }
+        // start ± 2i is divisible by p exactly when i ≡ ±r / 2 (mod p); the sign is folded into r
+        // above, and (p + 1) / 2 is the inverse of 2.
+        let mut i = r * ((p + 1) >> 1) % p;
+        while i < PRIME_SEARCH_WINDOW_SIZE {
+            sieve[i] = true;
+            i += p;
+        }
+    }
+}
+
+fn checked_next_prime_unsigned<T: PrimitiveUnsigned>(n: T) -> Option<T> {
+    if n < T::TWO {
+        return Some(T::TWO);
+    }
+    // The smallest odd number greater than n
+    let mut candidate = n.checked_add(T::ONE)? | T::ONE;
+    // Candidates below the sieve threshold are tested one at a time.
+    if T::convertible_from(PRIME_SEARCH_SIEVE_THRESHOLD) {
+        let threshold = T::wrapping_from(PRIME_SEARCH_SIEVE_THRESHOLD);
+        while candidate < threshold {
+            if candidate.is_prime() {
+                return Some(candidate);
+            }
+            candidate += T::TWO;
+        }
+    } else {
+        loop {
+            if candidate.is_prime() {
+                return Some(candidate);
+            }
+            candidate = candidate.checked_add(T::TWO)?;
+        }
+    }
+    let mut sieve = [false; PRIME_SEARCH_WINDOW_SIZE];
+    loop {
+        sieve_odd_window(candidate, true, &mut sieve);
+        for (i, &composite) in sieve.iter().enumerate() {
+            if !composite {
+                let c = candidate.checked_add(T::wrapping_from(i << 1))?;
+                if c.is_prime() {
+                    return Some(c);
+                }
+            }
+        }
+        candidate = candidate.checked_add(T::wrapping_from(PRIME_SEARCH_WINDOW_SIZE << 1))?;
+    }
+}
+
+macro_rules! impl_next_prime {
+    ($t:ident) => {
+        impl CheckedNextPrime for $t {
+            type Output = $t;
+
+            /// Finds the smallest prime greater than a number, returning `None` if the result is
+            /// not representable.
+            ///
+            /// Odd candidates are tested in order using [`IsPrime`](super::traits::IsPrime). From
+            /// $2^{48}$ on, they are first sieved in windows by the odd primes less than $2^8$, and
+            /// only those that remain are tested.
+            ///
+            /// # Expected complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#checked_next_prime).
+            #[inline]
+            fn checked_next_prime(self) -> Option<$t> {
+                checked_next_prime_unsigned(self)
+            }
+        }
+
+        impl NextPrime for $t {
+            type Output = $t;
+
+            /// Finds the smallest prime greater than a number.
+            ///
+            /// # Expected complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if the next prime is greater than the type's maximum value.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#next_prime).
+            #[inline]
+            fn next_prime(self) -> $t {
+                checked_next_prime_unsigned(self).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_next_prime);
@@ -0,0 +1,72 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::next_prime::{
+    sieve_odd_window, PRIME_SEARCH_SIEVE_THRESHOLD, PRIME_SEARCH_WINDOW_SIZE,
+};
+use crate::num::factorization::traits::PreviousPrime;
+
+fn previous_prime_unsigned<T: PrimitiveUnsigned>(n: T) -> Option<T> {
+    if n <= T::TWO {
+        return None;
+    } else if n == T::from(3u8) {
+        return Some(T::TWO);
+    }
+    // The largest odd number less than n. Since n > 3, this is at least 3, and 3 is prime, so
+    // the loop terminates.
+    let mut candidate = (n - T::ONE) | T::ONE;
+    if candidate == n {
+        candidate -= T::TWO;
+    }
+    // Windows are sieved only while all of their numbers are at least the sieve threshold; the
+    // remaining candidates are tested one at a time.
+    let lowest = PRIME_SEARCH_SIEVE_THRESHOLD + ((PRIME_SEARCH_WINDOW_SIZE as u64 - 1) << 1);
+    if T::convertible_from(lowest) {
+        let lowest = T::wrapping_from(lowest);
+        let mut sieve = [false; PRIME_SEARCH_WINDOW_SIZE];
+        while candidate >= lowest {
+            sieve_odd_window(candidate, false, &mut sieve);
+            for (i, &composite) in sieve.iter().enumerate() {
+                if !composite {
+                    let c = candidate - T::wrapping_from(i << 1);
+                    if c.is_prime() {
+                        return Some(c);
+                    }
+                }
+            }
+            candidate -= T::wrapping_from(PRIME_SEARCH_WINDOW_SIZE << 1);
+        }
+    }
+    while !candidate.is_prime() {
+        candidate -= T::TWO;
+    }
+    Some(candidate)
+}
+
+macro_rules! impl_previous_prime {
+    ($t:ident) => {
+        impl PreviousPrime for $t {
+            type Output = $t;
+
+            /// Finds the largest prime less than a number, returning `None` if there is no such
+            /// prime; that is, if the number is less than or equal to 2.
+            ///
+            /// Odd candidates are tested in order using [`IsPrime`](super::traits::IsPrime). From
+            /// $2^{48}$ on, they are first sieved in windows by the odd primes less than $2^8$, and
+            /// only those that remain are tested.
+            ///
+            /// # Expected complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::previous_prime#previous_prime).
+            #[inline]
+            fn previous_prime(self) -> Option<$t> {
+                previous_prime_unsigned(self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_previous_prime);
@@ -0,0 +1,491 @@
+use crate::named::Named;
+use crate::num::arithmetic::traits::{FloorRoot, FloorSqrt};
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::conversion::traits::{ExactFrom, RoundingFrom, WrappingFrom};
+use crate::num::factorization::traits::{NthPrime, PrimeCount, Primes, PrimesInRange};
+use crate::num::logic::traits::SignificantBits;
+use crate::rounding_modes::RoundingMode;
+use std::cmp::{max, min};
+
+// Below this bound, primes are counted by sieving.
+const PRIME_COUNT_SIEVE_THRESHOLD: u64 = 1 << 16;
+
+// The number of primes less than 2^16. The first this many primes are found by sieving.
+const NTH_PRIME_SIEVE_THRESHOLD: u64 = 6542;
+
+// The number of primes less than 2^64.
+const U64_MAX_PRIME_COUNT: u64 = 425656284035217743;
+
+// Returns the number of primes less than 2^width, for a width of at most 64.
+const fn max_prime_count(width: u64) -> u64 {
+    match width {
+        8 => 54,
+        16 => 6542,
+        32 => 203280221,
+        _ => U64_MAX_PRIME_COUNT,
+    }
+}
+
+// The maximum length of the windows in which primes are generated when computing P_2 or walking
+// from an estimate of the $n$th prime to the $n$th prime.
+const PRIME_COUNT_WINDOW_LENGTH: u64 = 1 << 20;
+
+// Returns the least prime factor and the Möbius function of every positive integer less than or
+// equal to `y`. The least prime factor of 1 is taken to be `u32::MAX`.
+fn least_prime_factors_and_mobius(y: usize) -> (Vec<u32>, Vec<i8>) {
+    let mut lpf = vec![0; y + 1];
+    let mut mu = vec![1; y + 1];
+    lpf[1] = u32::MAX;
+    for p in 2..=y {
+        if lpf[p] != 0 {
+            continue;
+        }
+        for k in (p..=y).step_by(p) {
+            if lpf[k] == 0 {
+                lpf[k] = u32::wrapping_from(p);
+            }
+            mu[k] = -mu[k];
+        }
+        if p <= y / p {
+            for k in (p * p..=y).step_by(p * p) {
+                mu[k] = 0;
+            }
+        }
+    }
+    (lpf, mu)
+}
+
+// The maximum number of small primes that are handled by `PhiTiny` rather than by sieving.
+const PHI_TINY_PRIME_COUNT: usize = 6;
+
+// Computes $\phi(x, c)$, the number of positive integers less than or equal to $x$ that are not
+// divisible by any of the first $c$ primes, for a small fixed $c$. If $m$ is the product of those
+// primes, $\phi(x, c) = \lfloor x/m \rfloor \phi(m, c) + \phi(x \bmod m, c)$.
+struct PhiTiny {
+    modulus: u64,
+    // Element i is true if i is coprime to `modulus`.
+    coprime: Vec<bool>,
+    // Element i is phi(i, c).
+    counts: Vec<u64>,
+    // phi(modulus, c).
+    period_count: u64,
+}
+
+impl PhiTiny {
+    fn new(primes: &[u64]) -> PhiTiny {
+        let modulus = primes.iter().product();
+        let mut coprime = vec![true; usize::exact_from(modulus)];
+        for &p in primes {
+            for i in (0..coprime.len()).step_by(usize::exact_from(p)) {
+                coprime[i] = false;
+            }
+        }
+        let mut counts = Vec::with_capacity(coprime.len());
+        let mut count = 0;
+        counts.push(0);
+        for &c in &coprime[1..] {
+            if c {
+                count += 1;
+            }
+            counts.push(count);
+        }
+        // Only when there are no primes is 0 coprime to the modulus, which is then 1.
+        let period_count = if coprime[0] { count + 1 } else { count };
+        PhiTiny {
+            modulus,
+            coprime,
+            counts,
+            period_count,
+        }
+    }
+
+    fn phi(&self, x: u64) -> u64 {
+        x / self.modulus * self.period_count + self.counts[usize::exact_from(x % self.modulus)]
+    }
+}
+
+// A segment of a sieve, together with a binary indexed tree that counts the unsieved elements in
+// any prefix of the segment.
+struct SieveSegmentCounter {
+    sieve: Vec<bool>,
+    tree: Vec<u32>,
+    count: u64,
+}
+
+impl SieveSegmentCounter {
+    const fn new() -> SieveSegmentCounter {
+        SieveSegmentCounter {
+            sieve: Vec::new(),
+            tree: Vec::new(),
+            count: 0,
+        }
+    }
+
+    // Resets the segment to represent the `len` integers starting at `low`, with the multiples of
+    // the primes handled by `phi_tiny` already sieved out.
+    fn reset(&mut self, phi_tiny: &PhiTiny, low: u64, len: usize) {
+        self.sieve.clear();
+        let mut i = usize::exact_from(low % phi_tiny.modulus);
+        while self.sieve.len() < len {
+            let chunk_len = min(phi_tiny.coprime.len() - i, len - self.sieve.len());
+            self.sieve
+                .extend_from_slice(&phi_tiny.coprime[i..i + chunk_len]);
+            i = 0;
+        }
+        self.tree.clear();
+        self.tree.extend(self.sieve.iter().map(|&b| u32::from(b)));
+        for i in 0..len {
+            let j = i | (i + 1);
+            if j < len {
+                self.tree[j] += self.tree[i];
+            }
+        }
+        self.count = u64::wrapping_from(self.sieve.iter().filter(|&&b| b).count());
+    }
+
+    fn remove(&mut self, mut i: usize) {
+        if !self.sieve[i] {
+            return;
+        }
+        self.sieve[i] = false;
+        self.count -= 1;
+        while i < self.tree.len() {
+            self.tree[i] -= 1;
+            i |= i + 1;
+        }
+    }
+
+    // Returns the number of unsieved elements with index less than or equal to `i`.
+    fn count_up_to(&self, i: usize) -> u64 {
+        let mut sum = 0;
+        let mut i = i + 1;
+        while i != 0 {
+            sum += u64::from(self.tree[i - 1]);
+            i &= i - 1;
+        }
+        sum
+    }
+}
+
+// Computes the contribution of the special leaves to $\phi(x, \pi(y))$, as described in
+// Lagarias, Miller, and Odlyzko, "Computing $\pi(x)$: the Meissel-Lehmer method". The values of
+// $\phi(x/n, b)$ are found by sieving the interval $[1, x/y]$ in segments, using a binary indexed
+// tree to count the unsieved numbers in each segment.
+//
+// `primes` contains a 0 followed by the primes less than or equal to `y`, and the first `c` primes
+// are handled by `phi_tiny`.
+fn special_leaves(
+    x: u64,
+    y: u64,
+    c: usize,
+    phi_tiny: &PhiTiny,
+    primes: &[u64],
+    lpf: &[u32],
+    mu: &[i8],
+) -> i128 {
+    let pi_y = primes.len() - 1;
+    // Every special leaf x / n has n > y, so it is at most z.
+    let z = x / y;
+    let len = min(max(z.floor_sqrt(), 1 << 16).next_power_of_two(), z);
+    let mut counter = SieveSegmentCounter::new();
+    // For each prime, the next multiple of that prime that has not yet been sieved out.
+    let mut next = primes.to_vec();
+    // For each index b, the number of integers less than `low` that are not divisible by any of
+    // the first b - 1 primes.
+    let mut phi = vec![0; pi_y];
+    let mut sum = 0i128;
+    let mut low = 1;
+    while low <= z {
+        let high = min(low + len, z + 1);
+        counter.reset(phi_tiny, low, usize::exact_from(high - low));
+        for b in c + 1..pi_y {
+            let p = primes[b];
+            let xp = x / p;
+            let min_m = max(xp / high, y / p);
+            let max_m = min(xp / low, y);
+            // Every m with a least prime factor greater than p is greater than p.
+            if p >= max_m {
+                break;
+            }
+            if p <= y / p {
+                for m in (min_m + 1..=max_m).rev() {
+                    let m_index = usize::exact_from(m);
+                    let mu_m = mu[m_index];
+                    if mu_m != 0 && p < u64::from(lpf[m_index]) {
+                        let count = counter.count_up_to(usize::exact_from(xp / m - low));
+                        sum -= i128::from(mu_m) * i128::from(phi[b] + count);
+                    }
+                }
+            } else {
+                // Since p^2 > y, every m <= y whose least prime factor is greater than p is
+                // prime, so mu(m) = -1.
+                let min_m = max(min_m, p);
+                let start = primes.partition_point(|&q| q <= min_m);
+                let end = primes.partition_point(|&q| q <= max_m);
+                for &q in primes[start..end].iter().rev() {
+                    let count = counter.count_up_to(usize::exact_from(xp / q - low));
+                    sum += i128::from(phi[b] + count);
+                }
+            }
+            phi[b] += counter.count;
+            let mut k = next[b];
+            while k < high {
+                counter.remove(usize::exact_from(k - low));
+                k += p;
+            }
+            next[b] = k;
+        }
+        low = high;
+    }
+    sum
+}
+
+// Computes $P_2(x, \pi(y))$, the number of integers less than or equal to `x` that are the
+// product of exactly two primes greater than `y`. Assumes that $y^3 \geq x$.
+fn p2(x: u64, y: u64) -> u64 {
+    let sqrt = x.floor_sqrt();
+    if y >= sqrt {
+        return 0;
+    }
+    // The primes p in (y, sqrt(x)] are generated in descending order, so that the values x / p,
+    // which lie in [sqrt(x), x / (y + 1)], are ascending and pi(x / p) can be computed by counting
+    // primes incrementally.
+    let mut pi_p = prime_count_u64(sqrt);
+    let mut pi_xp = pi_p;
+    let z = x / (y + 1);
+    let mut larger_primes = if sqrt < z {
+        u64::primes_in_inclusive_range(&(sqrt + 1), &z)
+    } else {
+        u64::primes_in_range(&0, &0)
+    }
+    .peekable();
+    let mut sum = 0;
+    let mut high = sqrt;
+    while high > y {
+        let low = max(y + 1, high.saturating_sub(PRIME_COUNT_WINDOW_LENGTH - 1));
+        let primes: Vec<u64> = u64::primes_in_inclusive_range(&low, &high).collect();
+        for p in primes.into_iter().rev() {
+            let xp = x / p;
+            while larger_primes.next_if(|&q| q <= xp).is_some() {
+                pi_xp += 1;
+            }
+            sum += pi_xp - pi_p + 1;
+            pi_p -= 1;
+        }
+        high = low - 1;
+    }
+    sum
+}
+
+// Returns the value of y, with $x^{1/3} \leq y \leq x^{1/2}$, that balances the cost of computing
+// the special leaves against the cost of sieving.
+fn lmo_y(x: u64) -> u64 {
+    let alpha = max(1, x.significant_bits() / 10);
+    min(x.floor_root(3) * alpha, x.floor_sqrt())
+}
+
+// Counts the primes less than or equal to `x` using the Lagarias-Miller-Odlyzko algorithm, using
+// $\pi(x) = \phi(x, a) + a - 1 - P_2(x, a)$, where $a = \pi(y)$ for some $y \geq x^{1/3}$.
+//
+// Assumes that `x` is positive.
+pub_test! {lmo_prime_count(x: u64) -> u64 {
+    let y = lmo_y(x);
+    let mut primes = vec![0];
+    primes.extend(u64::primes_less_than_or_equal_to(&y));
+    // The table used by `PhiTiny` should not be much larger than the interval that is sieved.
+    let z = x / y;
+    let mut c = 0;
+    let mut primorial = 1;
+    while c < min(PHI_TINY_PRIME_COUNT, primes.len() - 1) && primorial * primes[c + 1] <= z {
+        c += 1;
+        primorial *= primes[c];
+    }
+    let phi_tiny = PhiTiny::new(&primes[1..=c]);
+    let (lpf, mu) = least_prime_factors_and_mobius(usize::exact_from(y));
+    // The ordinary leaves: the squarefree n <= y whose prime factors all exceed the cth prime.
+    let mut phi = 0i128;
+    for (n, &mu_n) in mu.iter().enumerate().skip(1) {
+        if mu_n != 0 && primes[c] < u64::from(lpf[n]) {
+            phi += i128::from(mu_n) * i128::from(phi_tiny.phi(x / u64::wrapping_from(n)));
+        }
+    }
+    phi += special_leaves(x, y, c, &phi_tiny, &primes, &lpf, &mu);
+    let a = i128::from(u64::wrapping_from(primes.len() - 1));
+    u64::exact_from(phi + a - 1 - i128::from(p2(x, y)))
+}}
+
+#[doc(hidden)]
+pub fn prime_count_u64(x: u64) -> u64 {
+    if x < PRIME_COUNT_SIEVE_THRESHOLD {
+        u64::wrapping_from(u64::primes_less_than_or_equal_to(&x).count())
+    } else {
+        lmo_prime_count(x)
+    }
+}
+
+// Returns an approximation to the logarithmic integral $\operatorname{li}(x)$, using Ramanujan's
+// series.
+fn li(x: f64) -> f64 {
+    const EULER_GAMMA: f64 = 0.5772156649015329;
+    let ln_x = x.ln();
+    let mut sum = 0.0;
+    // (-ln(x))^n / (n! 2^n)
+    let mut term = 1.0;
+    let mut inner_sum = 0.0;
+    let mut n = 1u32;
+    loop {
+        let n_f = f64::from(n);
+        term *= -ln_x / (2.0 * n_f);
+        if n & 1 == 1 {
+            inner_sum += 1.0 / n_f;
+        }
+        let delta = -2.0 * term * inner_sum;
+        sum += delta;
+        if n_f > ln_x && delta.abs() <= f64::EPSILON * sum.abs() {
+            break;
+        }
+        n += 1;
+    }
+    EULER_GAMMA + ln_x.ln() + x.sqrt() * sum
+}
+
+// Returns an approximation to the $n$th prime, using Newton's method to invert
+// $\operatorname{li}$.
+fn nth_prime_estimate(n: u64) -> u64 {
+    const TWO_POW_64: f64 = 18446744073709551616.0;
+    let n = f64::rounding_from(n, RoundingMode::Nearest);
+    let mut x = n * n.ln();
+    for _ in 0..100 {
+        let next = x - (li(x) - n) * x.ln();
+        let done = (next - x).abs() < 0.5;
+        x = next;
+        if done {
+            break;
+        }
+    }
+    if x >= TWO_POW_64 {
+        u64::MAX
+    } else {
+        u64::rounding_from(x.max(2.0), RoundingMode::Floor)
+    }
+}
+
+// Returns the length of a window near `x` that is expected to contain somewhat more than
+// `remaining` primes, since the density of primes near x is about 1 / ln(x).
+fn nth_prime_window_length(x: u64, remaining: u64) -> u64 {
+    (remaining + 1)
+        .saturating_mul(x.significant_bits())
+        .clamp(1 << 10, PRIME_COUNT_WINDOW_LENGTH)
+}
+
+#[doc(hidden)]
+pub fn nth_prime_u64(n: u64) -> u64 {
+    assert_ne!(n, 0, "n must be positive");
+    assert!(
+        n <= U64_MAX_PRIME_COUNT,
+        "The {}th prime is not representable as a u64",
+        n
+    );
+    if n <= NTH_PRIME_SIEVE_THRESHOLD {
+        return u64::primes().nth(usize::exact_from(n - 1)).unwrap();
+    }
+    let estimate = nth_prime_estimate(n);
+    let count = prime_count_u64(estimate);
+    if count >= n {
+        // The nth prime is the largest prime less than or equal to the estimate, or is found by
+        // skipping this many primes going downward from it.
+        let mut remaining = count - n;
+        let mut high = estimate;
+        loop {
+            let low = high.saturating_sub(nth_prime_window_length(high, remaining) - 1);
+            let primes: Vec<u64> = u64::primes_in_inclusive_range(&low, &high).collect();
+            let len = u64::wrapping_from(primes.len());
+            if len > remaining {
+                return primes[usize::exact_from(len - 1 - remaining)];
+            }
+            remaining -= len;
+            high = low - 1;
+        }
+    } else {
+        // The nth prime is the (n - count)th prime greater than the estimate.
+        let mut remaining = n - count;
+        let mut low = estimate + 1;
+        loop {
+            let high = low.saturating_add(nth_prime_window_length(low, remaining) - 1);
+            let primes: Vec<u64> = u64::primes_in_inclusive_range(&low, &high).collect();
+            let len = u64::wrapping_from(primes.len());
+            if len >= remaining {
+                return primes[usize::exact_from(remaining - 1)];
+            }
+            remaining -= len;
+            low = high + 1;
+        }
+    }
+}
+
+macro_rules! impl_prime_count {
+    ($t:ident) => {
+        impl PrimeCount for $t {
+            /// Counts the primes less than or equal to a number; that is, computes $\pi(x)$.
+            ///
+            /// Small values are handled by sieving. Larger values are handled by the
+            /// Lagarias-Miller-Odlyzko variant of the Meissel-Lehmer method, which expresses
+            /// $\pi(x)$ in terms of the partial sieve function $\phi(x, a)$, the number of
+            /// positive integers up to $x$ with no prime factor among the first $a$ primes, and
+            /// evaluates the latter with a segmented sieve.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{2/3})$
+            ///
+            /// $M(n) = O(n^{1/3}\log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to $2^{64}$.
+            ///
+            /// # Examples
+            /// See [here](super::prime_count#prime_count).
+            #[inline]
+            fn prime_count(&self) -> $t {
+                $t::wrapping_from(prime_count_u64(u64::exact_from(*self)))
+            }
+        }
+
+        impl NthPrime for $t {
+            /// Returns the $n$th prime, counting from 1.
+            ///
+            /// An estimate is computed by inverting the logarithmic integral, the primes less than
+            /// or equal to it are counted using [`PrimeCount`], and the $n$th prime is then found
+            /// by sieving near the estimate.
+            ///
+            /// $f(n) = p_n$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O((n \log n)^{2/3})$
+            ///
+            /// $M(n) = O((n \log n)^{1/3})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+            ///
+            /// # Panics
+            /// Panics if `n` is zero or if the $n$th prime is not representable as a value of
+            /// this type.
+            ///
+            /// # Examples
+            /// See [here](super::prime_count#nth_prime).
+            fn nth_prime(n: u64) -> $t {
+                if $t::WIDTH < u64::WIDTH {
+                    assert!(
+                        n <= max_prime_count($t::WIDTH),
+                        "The {}th prime is not representable as a {}",
+                        n,
+                        $t::NAME
+                    );
+                }
+                $t::wrapping_from(nth_prime_u64(n))
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_prime_count);
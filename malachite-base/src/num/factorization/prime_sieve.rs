@@ -1,6 +1,6 @@
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
-use crate::num::conversion::traits::ExactFrom;
+use crate::num::conversion::traits::{ExactFrom, ExactInto};
 use crate::num::logic::traits::CountOnes;
 
 pub const SIEVE_SEED_U32: u32 = 0x69128480;
@@ -473,3 +473,41 @@ pub fn limbs_prime_sieve_u64(bit_array: &mut [u64], n: u64) -> u64 {
         SEED_LIMIT_U64,
     )
 }
+
+// Computes a table of the values of an arithmetic function $f$ at $0, 1, \ldots, n$, using a prime
+// sieve to find the factorization of every number in the range at once. The entry at 0 is `zero`
+// and every other entry starts as `one`, $f(1)$; then, for every $m$ and every prime power $p^e$
+// exactly dividing $m$, `combine(&mut f(m), p, e)` is called. The calls for a given $m$ are made in
+// increasing order of $p$.
+//
+// # Worst-case complexity
+// $T(n) = O(n\log\log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n`, assuming `combine` takes constant
+// time.
+#[doc(hidden)]
+pub fn prime_power_sieve<T: PrimitiveUnsigned, U: Clone, F: FnMut(&mut U, T, u64)>(
+    n: T,
+    zero: U,
+    one: U,
+    mut combine: F,
+) -> Vec<U> {
+    let len = ExactInto::<usize>::exact_into(n) + 1;
+    let mut values = vec![one; len];
+    values[0] = zero;
+    for p in T::primes_less_than_or_equal_to(&n) {
+        let step: usize = p.exact_into();
+        for m in (step..len).step_by(step) {
+            let mut q = T::exact_from(m) / p;
+            let mut e = 1;
+            while q.divisible_by(p) {
+                q /= p;
+                e += 1;
+            }
+            combine(&mut values[m], p, e);
+        }
+    }
+    values
+}
@@ -0,0 +1,283 @@
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::{ExactFrom, SaturatingInto, WrappingFrom};
+use crate::num::factorization::traits::{Primes, PrimesInRange};
+use std::cmp::{max, min};
+
+#[doc(hidden)]
+// Odd primes less than or equal to this bound are always used to sieve, unless they exceed the
+// square root of the largest number in the range.
+pub const SEGMENTED_SIEVE_MIN_PRIME_BOUND: u64 = 1 << 8;
+
+#[doc(hidden)]
+// No odd primes greater than this bound are used to sieve.
+pub const SEGMENTED_SIEVE_MAX_PRIME_BOUND: u64 = 1 << 20;
+
+#[doc(hidden)]
+// The maximum number of odd candidates in each segment.
+pub const SEGMENTED_SIEVE_SEGMENT_LENGTH: usize = 1 << 15;
+
+#[doc(hidden)]
+/// A sieve over consecutive segments of odd numbers, each of the same length.
+///
+/// After the $k$th call to `sieve_next_segment` (counting from 0), element $i$ of `segment`
+/// corresponds to $s + 2(kl + i)$, where $s$ is the odd starting value and $l$ is the segment
+/// length. It is `true` if that number is divisible by one of the sieving primes and is not equal
+/// to it.
+#[derive(Clone, Debug)]
+pub struct SegmentedPrimeSieve {
+    primes: Vec<u64>,
+    // For each prime, the index of the next element in the next segment that it marks.
+    offsets: Vec<u64>,
+    pub segment: Vec<bool>,
+}
+
+#[doc(hidden)]
+// Returns the largest prime used to sieve a range that contains `count` odd candidates and whose
+// largest element has the floor of its square root equal to `sqrt`. Sieving with primes that are
+// much larger than the range costs more than testing the candidates that survive, so the bound
+// grows with the range. If the result is less than `sqrt`, surviving candidates must be tested
+// for primality.
+pub fn segmented_sieve_prime_bound(sqrt: u64, count: u64) -> u64 {
+    min(
+        sqrt,
+        count.clamp(
+            SEGMENTED_SIEVE_MIN_PRIME_BOUND,
+            SEGMENTED_SIEVE_MAX_PRIME_BOUND,
+        ),
+    )
+}
+
+impl SegmentedPrimeSieve {
+    /// Creates a sieve whose first segment starts at an odd number $s \geq 3$, sieving with the
+    /// odd primes less than or equal to `bound`. `small_start` must be `Some(s)` if $s$ fits in a
+    /// `u64`, and `residue(p)` must return $s \bmod p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n\log\log n + nR)$
+    ///
+    /// $M(n) = O(n + l)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `bound`, $l$ is `len`, and $R$ is the
+    /// time taken by `residue`.
+    pub fn new<F: Fn(u64) -> u64>(
+        small_start: Option<u64>,
+        residue: F,
+        bound: u64,
+        len: usize,
+    ) -> SegmentedPrimeSieve {
+        let primes: Vec<u64> = u64::primes_less_than_or_equal_to(&bound).skip(1).collect();
+        let offsets = primes
+            .iter()
+            .map(|&p| {
+                let square = p * p;
+                match small_start {
+                    // Multiples of p less than p^2 are marked by smaller primes, and p itself must
+                    // not be marked.
+                    Some(start) if square > start => (square - start) >> 1,
+                    _ => {
+                        // s + 2i is divisible by p iff i = -s / 2 mod p, and (p + 1) / 2 is the
+                        // inverse of 2 mod p.
+                        let r = residue(p);
+                        let r = if r == 0 { 0 } else { p - r };
+                        r * ((p + 1) >> 1) % p
+                    }
+                }
+            })
+            .collect();
+        SegmentedPrimeSieve {
+            primes,
+            offsets,
+            segment: vec![false; len],
+        }
+    }
+
+    /// Sieves the next segment.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, l) = O(n / \log n + l \log\log n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the sieving bound, and $l$ is the
+    /// segment length.
+    pub fn sieve_next_segment(&mut self) {
+        self.segment.fill(false);
+        let len = u64::wrapping_from(self.segment.len());
+        for (&p, offset) in self.primes.iter().zip(self.offsets.iter_mut()) {
+            let mut i = *offset;
+            while i < len {
+                self.segment[usize::wrapping_from(i)] = true;
+                i += p;
+            }
+            *offset = i - len;
+        }
+    }
+}
+
+/// An iterator that generates all primes in a range.
+///
+/// This `struct` is created by [`PrimesInRange::primes_in_range`] and
+/// [`PrimesInRange::primes_in_inclusive_range`]; see their documentation for more.
+#[derive(Clone, Debug)]
+pub struct PrimesInRangeIterator<T: PrimitiveUnsigned> {
+    two: bool,
+    // `None` once every odd candidate has been considered.
+    sieve: Option<SegmentedPrimeSieve>,
+    // Whether every candidate that survives sieving is prime.
+    complete: bool,
+    i: usize,
+    candidate: T,
+    last: T,
+}
+
+impl<T: PrimitiveUnsigned> PrimesInRangeIterator<T> {
+    const fn empty() -> PrimesInRangeIterator<T> {
+        PrimesInRangeIterator {
+            two: false,
+            sieve: None,
+            complete: true,
+            i: 0,
+            candidate: T::ZERO,
+            last: T::ZERO,
+        }
+    }
+
+    // Generates the primes in [a, b]; assumes that a <= b.
+    fn new(a: T, b: T) -> PrimesInRangeIterator<T> {
+        let two = a <= T::TWO && T::TWO <= b;
+        let start = max(a, T::from(3u8)) | T::ONE;
+        if start > b {
+            return PrimesInRangeIterator {
+                two,
+                ..PrimesInRangeIterator::empty()
+            };
+        }
+        let half_width: T = (b - start) >> 1;
+        let count = SaturatingInto::<u64>::saturating_into(half_width).saturating_add(1);
+        let len = usize::exact_from(min(count, SEGMENTED_SIEVE_SEGMENT_LENGTH as u64));
+        let sqrt: u64 = b.floor_sqrt().saturating_into();
+        let small_start = if start.significant_bits() <= u64::WIDTH {
+            Some(start.wrapping_into())
+        } else {
+            None
+        };
+        // Every sieving prime is at most the square root of b, so it is representable as a T.
+        let bound = segmented_sieve_prime_bound(sqrt, count);
+        let mut sieve = SegmentedPrimeSieve::new(
+            small_start,
+            |p| (start % T::wrapping_from(p)).wrapping_into(),
+            bound,
+            len,
+        );
+        sieve.sieve_next_segment();
+        PrimesInRangeIterator {
+            two,
+            sieve: Some(sieve),
+            complete: sqrt <= bound,
+            i: 0,
+            candidate: start,
+            last: b,
+        }
+    }
+}
+
+impl<T: PrimitiveUnsigned> Iterator for PrimesInRangeIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.two {
+            self.two = false;
+            return Some(T::TWO);
+        }
+        loop {
+            let sieve = self.sieve.as_mut()?;
+            if self.i == sieve.segment.len() {
+                sieve.sieve_next_segment();
+                self.i = 0;
+            }
+            let composite = sieve.segment[self.i];
+            self.i += 1;
+            let candidate = self.candidate;
+            let done = match candidate.checked_add(T::TWO) {
+                Some(next) if next <= self.last => {
+                    self.candidate = next;
+                    false
+                }
+                _ => true,
+            };
+            let prime = !composite && (self.complete || candidate.is_prime());
+            if done {
+                self.sieve = None;
+            }
+            if prime {
+                return Some(candidate);
+            } else if done {
+                return None;
+            }
+        }
+    }
+}
+
+macro_rules! impl_primes_in_range {
+    ($t:ident) => {
+        impl PrimesInRange for $t {
+            type RI = PrimesInRangeIterator<$t>;
+
+            /// Returns an iterator that generates all primes in the range $[a, b)$.
+            ///
+            /// A segmented sieve is used. The odd primes up to a bound are found with a prime
+            /// sieve and used to sieve consecutive segments of odd candidates. The bound is
+            /// $\sqrt{b}$ if possible, but it is at most $2^{20}$, and it is smaller for short
+            /// ranges. If it is less than $\sqrt{b}$, the candidates that survive are then
+            /// tested using [`IsPrime`](super::traits::IsPrime).
+            ///
+            /// # Worst-case complexity (amortized)
+            /// $T(i) = O(\log \log i)$
+            ///
+            /// $M(i) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $i$ is the iteration index.
+            ///
+            /// # Panics
+            /// Panics if $a > b$.
+            ///
+            /// # Examples
+            /// See [here](super::primes_in_range#primes_in_range).
+            fn primes_in_range(&a: &$t, &b: &$t) -> PrimesInRangeIterator<$t> {
+                if a > b {
+                    panic!("a must be less than or equal to b. a: {}, b: {}", a, b);
+                }
+                if a == b {
+                    PrimesInRangeIterator::empty()
+                } else {
+                    PrimesInRangeIterator::new(a, b - 1)
+                }
+            }
+
+            /// Returns an iterator that generates all primes in the range $[a, b]$.
+            ///
+            /// See [`PrimesInRange::primes_in_range`] for details.
+            ///
+            /// # Worst-case complexity (amortized)
+            /// $T(i) = O(\log \log i)$
+            ///
+            /// $M(i) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $i$ is the iteration index.
+            ///
+            /// # Panics
+            /// Panics if $a > b$.
+            ///
+            /// # Examples
+            /// See [here](super::primes_in_range#primes_in_inclusive_range).
+            fn primes_in_inclusive_range(&a: &$t, &b: &$t) -> PrimesInRangeIterator<$t> {
+                if a > b {
+                    panic!("a must be less than or equal to b. a: {}, b: {}", a, b);
+                }
+                PrimesInRangeIterator::new(a, b)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_primes_in_range);
@@ -8,3 +8,56 @@ pub trait Primes {
 
     fn primes() -> Self::I;
 }
+
+/// Determines whether a number is prime.
+pub trait IsPrime {
+    fn is_prime(&self) -> bool;
+}
+
+/// Finds the prime factorization of a number.
+pub trait Factor {
+    type FACTORS;
+
+    fn factor(&self) -> Self::FACTORS;
+}
+
+/// Finds the smallest prime greater than a number. Assumes the result is representable.
+pub trait NextPrime {
+    type Output;
+
+    fn next_prime(self) -> Self::Output;
+}
+
+/// Finds the smallest prime greater than a number, returning `None` if the result is not
+/// representable.
+pub trait CheckedNextPrime {
+    type Output;
+
+    fn checked_next_prime(self) -> Option<Self::Output>;
+}
+
+/// Finds the largest prime less than a number, returning `None` if there is no such prime.
+pub trait PreviousPrime {
+    type Output;
+
+    fn previous_prime(self) -> Option<Self::Output>;
+}
+
+/// Generates the primes in a range.
+pub trait PrimesInRange: Sized {
+    type RI: Iterator<Item = Self>;
+
+    fn primes_in_range(a: &Self, b: &Self) -> Self::RI;
+
+    fn primes_in_inclusive_range(a: &Self, b: &Self) -> Self::RI;
+}
+
+/// Counts the primes less than or equal to a number.
+pub trait PrimeCount {
+    fn prime_count(&self) -> Self;
+}
+
+/// Finds the $n$th prime.
+pub trait NthPrime {
+    fn nth_prime(n: u64) -> Self;
+}
@@ -917,6 +917,13 @@ pub fn exhaustive_signed_pair_gen_var_13<T: PrimitiveSigned>() -> It<(T, T)> {
     )
 }
 
+pub fn exhaustive_signed_pair_gen_var_14<T: PrimitiveSigned>() -> It<(T, T)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_signeds(),
+        exhaustive_positive_primitive_ints(),
+    ))
+}
+
 // -- (PrimitiveSigned, PrimitiveSigned, PrimitiveSigned) --
 
 pub fn exhaustive_signed_triple_gen<T: PrimitiveSigned>() -> It<(T, T, T)> {
@@ -2993,6 +3000,13 @@ pub fn exhaustive_unsigned_quadruple_gen_var_12<T: PrimitiveUnsigned>() -> It<(T
     ))
 }
 
+pub fn exhaustive_unsigned_quadruple_gen_var_13<T: PrimitiveUnsigned>() -> It<(T, T, T, T)> {
+    Box::new(exhaustive_quadruples_xxxy(
+        exhaustive_unsigneds(),
+        exhaustive_positive_primitive_ints(),
+    ))
+}
+
 // -- (PrimitiveUnsigned * 6) --
 
 pub fn exhaustive_unsigned_sextuple_gen_var_1<T: PrimitiveUnsigned>() -> It<(T, T, T, T, T, T)> {
@@ -802,6 +802,15 @@ pub fn signed_pair_gen_var_12<T: PrimitiveSigned>() -> Generator<(T, T)> {
     )
 }
 
+// All pairs of signeds where the second element is positive.
+pub fn signed_pair_gen_var_13<T: PrimitiveSigned>() -> Generator<(T, T)> {
+    Generator::new(
+        &exhaustive_signed_pair_gen_var_14,
+        &random_signed_pair_gen_var_12,
+        &special_random_signed_pair_gen_var_11,
+    )
+}
+
 // -- (PrimitiveSigned, PrimitiveSigned, PrimitiveSigned) --
 
 pub fn signed_triple_gen<T: PrimitiveSigned>() -> Generator<(T, T, T)> {
@@ -2559,6 +2568,15 @@ pub fn unsigned_quadruple_gen_var_12<T: PrimitiveUnsigned>() -> Generator<(T, T,
     )
 }
 
+// All quadruples of unsigneds where the fourth element is positive.
+pub fn unsigned_quadruple_gen_var_13<T: PrimitiveUnsigned>() -> Generator<(T, T, T, T)> {
+    Generator::new(
+        &exhaustive_unsigned_quadruple_gen_var_13,
+        &random_unsigned_quadruple_gen_var_4,
+        &special_random_unsigned_quadruple_gen_var_13,
+    )
+}
+
 // -- (PrimitiveUnsigned * 6) --
 
 // All sextuples of unsigneds of the same type.
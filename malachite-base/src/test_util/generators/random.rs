@@ -2109,6 +2109,14 @@ pub fn random_signed_pair_gen_var_11<T: PrimitiveSigned>(config: &GenConfig) ->
     )
 }
 
+pub fn random_signed_pair_gen_var_12<T: PrimitiveSigned>(_config: &GenConfig) -> It<(T, T)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &random_primitive_ints,
+        &random_positive_signeds,
+    ))
+}
+
 // -- (PrimitiveSigned, PrimitiveSigned, PrimitiveSigned) --
 
 fn halve_bits<T: PrimitiveSigned>(x: T) -> T {
@@ -4032,6 +4040,16 @@ pub fn random_unsigned_quadruple_gen_var_3<T: PrimitiveUnsigned>(
     ))
 }
 
+pub fn random_unsigned_quadruple_gen_var_4<T: PrimitiveUnsigned>(
+    _config: &GenConfig,
+) -> It<(T, T, T, T)> {
+    Box::new(random_quadruples_xxxy(
+        EXAMPLE_SEED,
+        &random_primitive_ints,
+        &random_positive_unsigneds,
+    ))
+}
+
 // -- (PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode) --
 
 struct UnsignedUnsignedRoundingModeTripleGenerator<T: PrimitiveUnsigned> {
@@ -0,0 +1,19 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use std::cmp::min;
+
+// Computes row $n$ of Pascal's triangle modulo $m$, up to column $k$.
+pub fn binomial_coefficient_mod_naive<T: PrimitiveUnsigned>(n: T, k: T, m: T) -> T {
+    if k > n {
+        return T::ZERO;
+    }
+    let n: usize = n.exact_into();
+    let k: usize = k.exact_into();
+    let mut row = vec![T::ZERO; k + 1];
+    row[0] = T::ONE % m;
+    for i in 1..=n {
+        for j in (1..=min(i, k)).rev() {
+            row[j] = row[j].mod_add(row[j - 1], m);
+        }
+    }
+    row[k]
+}
@@ -1,3 +1,4 @@
+pub mod binomial_coefficient;
 pub mod extended_gcd;
 pub mod factorial;
 pub mod kronecker_symbol;
@@ -1,27 +1,11 @@
-use crate::num::arithmetic::traits::{CheckedSquare, DivisibleBy, FloorSqrt, Parity};
+use crate::num::arithmetic::traits::{CheckedSquare, DivisibleBy, Parity};
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::{ExactFrom, WrappingFrom};
 use crate::num::factorization::prime_sieve::{id_to_n, limbs_count_ones, n_to_bit};
+use crate::num::factorization::traits::IsPrime;
 use crate::num::logic::traits::{NotAssign, TrailingZeros};
 use crate::slices::slice_leading_zeros;
 
-// Replace with good primality test once we have one
-fn is_prime_simple(n: u64) -> bool {
-    match n {
-        0 | 1 => false,
-        2 => true,
-        n if n.even() => false,
-        n => {
-            for f in 3..=n.floor_sqrt() {
-                if n.divisible_by(f) {
-                    return false;
-                }
-            }
-            true
-        }
-    }
-}
-
 pub fn limbs_prime_sieve_naive_1<T: PrimitiveUnsigned>(bit_array: &mut [T], n: u64) -> u64 {
     assert!(n > 4);
     let mut f = 5;
@@ -29,7 +13,7 @@ pub fn limbs_prime_sieve_naive_1<T: PrimitiveUnsigned>(bit_array: &mut [T], n: u
     'outer: for x in bit_array.iter_mut() {
         *x = T::MAX;
         for i in 0..T::WIDTH {
-            if is_prime_simple(f) {
+            if f.is_prime() {
                 x.clear_bit(i);
             }
             f += if b { 4 } else { 2 };
@@ -1,10 +1,15 @@
-use malachite_base::num::arithmetic::traits::BinomialCoefficient;
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, BinomialCoefficientMod, MultinomialCoefficient,
+};
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::test_util::generators::{
     signed_gen, signed_gen_var_2, signed_pair_gen_var_11, signed_pair_gen_var_12, unsigned_gen,
     unsigned_gen_var_1, unsigned_pair_gen_var_28, unsigned_pair_gen_var_44,
+    unsigned_triple_gen_var_13, unsigned_triple_gen_var_19, unsigned_vec_gen,
+    unsigned_vec_unsigned_pair_gen,
 };
+use malachite_base::test_util::num::arithmetic::binomial_coefficient::binomial_coefficient_mod_naive;
 use std::panic::catch_unwind;
 
 #[test]
@@ -267,3 +272,228 @@ fn checked_binomial_coefficient_properties() {
     apply_fn_to_unsigneds!(checked_binomial_coefficient_helper_unsigned);
     apply_fn_to_signeds!(checked_binomial_coefficient_helper_signed);
 }
+
+#[test]
+fn test_binomial_coefficient_mod() {
+    fn test<T: PrimitiveUnsigned>(n: T, k: T, m: T, out: T) {
+        assert_eq!(T::binomial_coefficient_mod(n, k, m), out);
+    }
+    test::<u8>(0, 0, 1, 0);
+    test::<u8>(0, 0, 10, 1);
+    test::<u8>(4, 2, 10, 6);
+    test::<u8>(1, 2, 10, 0);
+    test::<u8>(10, 5, 7, 0);
+    test::<u8>(10, 5, 100, 52);
+    test::<u8>(255, 128, 255, 0);
+    test::<u16>(1000, 500, 1000, 320);
+    test::<u16>(50, 25, 1024, 376);
+    test::<u32>(100, 50, 1000000007, 538992043);
+    test::<u32>(123456, 7890, 1000000007, 456787575);
+    test::<u32>(1000000, 500000, 1024, 128);
+    test::<u32>(1000000, 500000, 25, 9);
+    test::<u32>(1048575, 300199, 4, 3);
+    test::<u32>(1048575, 300199, 8, 3);
+    test::<u32>(1048575, 300199, 108, 27);
+    test::<u32>(1048575, 300199, 1024, 139);
+    test::<u32>(1048575, 300199, 12192768, 10204299);
+    test::<u64>(1000000000000000000, 1000000000, 1000003, 0);
+    test::<u64>(100000, 50000, 1048576, 387648);
+    test::<u64>(100000, 50000, 531441, 373977);
+}
+
+fn binomial_coefficient_mod_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::binomial_coefficient_mod(T::TWO, T::ONE, T::ZERO));
+}
+
+#[test]
+pub fn binomial_coefficient_mod_fail() {
+    apply_fn_to_unsigneds!(binomial_coefficient_mod_fail_helper);
+}
+
+fn binomial_coefficient_mod_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_triple_gen_var_13::<T, T>().test_properties(|(m, n, k)| {
+        if m == T::ZERO {
+            return;
+        }
+        let b = T::binomial_coefficient_mod(n, k, m);
+        assert!(b < m);
+        assert_eq!(binomial_coefficient_mod_naive(n, k, m), b);
+        if let Some(c) = T::checked_binomial_coefficient(n, k) {
+            assert_eq!(c % m, b);
+        }
+        if n >= k {
+            assert_eq!(T::binomial_coefficient_mod(n, n - k, m), b);
+        }
+    });
+}
+
+#[test]
+fn binomial_coefficient_mod_properties() {
+    // Random u128 moduli may have two large prime factors, which are too slow to find here, and
+    // usize is covered by one of the other types.
+    binomial_coefficient_mod_properties_helper::<u8>();
+    binomial_coefficient_mod_properties_helper::<u16>();
+    binomial_coefficient_mod_properties_helper::<u32>();
+    binomial_coefficient_mod_properties_helper::<u64>();
+
+    unsigned_triple_gen_var_19::<u8>().test_properties(|(n, k, m)| {
+        if m == 0 {
+            return;
+        }
+        let (n, k, m) = (u32::from(n), u32::from(k), u32::from(m));
+        assert_eq!(
+            u32::binomial_coefficient_mod(n, k, m),
+            binomial_coefficient_mod_naive(n, k, m)
+        );
+    });
+}
+
+#[test]
+fn test_multinomial_coefficient() {
+    fn test<T: PrimitiveUnsigned>(ks: &[T], out: T) {
+        assert_eq!(T::multinomial_coefficient(ks), out);
+    }
+    test::<u8>(&[], 1);
+    test::<u8>(&[0], 1);
+    test::<u8>(&[5], 1);
+    test::<u8>(&[0, 0], 1);
+    test::<u8>(&[2, 3], 10);
+    test::<u8>(&[1, 2, 3], 60);
+    test::<u16>(&[2, 2, 2, 2], 2520);
+    test::<u32>(&[3, 4, 5], 27720);
+    test::<u64>(&[10, 10, 10], 5550996791340);
+    test::<u128>(&[20, 20, 20], 577831214478475823831865900);
+}
+
+#[test]
+pub fn multinomial_coefficient_fail() {
+    assert_panic!(u8::multinomial_coefficient(&[2, 2, 2, 2]));
+    assert_panic!(u8::multinomial_coefficient(&[200, 100]));
+    assert_panic!(u128::multinomial_coefficient(&[30, 30, 30]));
+}
+
+#[test]
+fn test_checked_multinomial_coefficient() {
+    fn test<T: PrimitiveUnsigned>(ks: &[T], out: Option<T>) {
+        assert_eq!(T::checked_multinomial_coefficient(ks), out);
+    }
+    test::<u8>(&[], Some(1));
+    test::<u8>(&[0], Some(1));
+    test::<u8>(&[255], Some(1));
+    test::<u8>(&[255, 0, 0], Some(1));
+    test::<u8>(&[2, 3], Some(10));
+    test::<u8>(&[1, 2, 3], Some(60));
+    test::<u8>(&[2, 2, 2, 2], None);
+    test::<u8>(&[200, 100], None);
+    test::<u16>(&[2, 2, 2, 2], Some(2520));
+    test::<u64>(&[10, 10, 10], Some(5550996791340));
+    test::<u128>(&[20, 20, 20], Some(577831214478475823831865900));
+    test::<u128>(&[30, 30, 30], None);
+}
+
+fn checked_multinomial_coefficient_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_vec_gen::<u8>().test_properties(|ks| {
+        let ks = ks.into_iter().map(T::from).collect::<Vec<T>>();
+        let o = T::checked_multinomial_coefficient(&ks);
+        if let Some(x) = o {
+            assert_ne!(x, T::ZERO);
+            assert_eq!(T::multinomial_coefficient(&ks), x);
+        }
+        let mut ks_alt = ks.clone();
+        ks_alt.reverse();
+        assert_eq!(T::checked_multinomial_coefficient(&ks_alt), o);
+        let ks_alt = ks
+            .iter()
+            .copied()
+            .filter(|&k| k != T::ZERO)
+            .collect::<Vec<T>>();
+        assert_eq!(T::checked_multinomial_coefficient(&ks_alt), o);
+    });
+
+    unsigned_pair_gen_var_28::<T, T>().test_properties(|(a, b)| {
+        assert_eq!(
+            T::checked_multinomial_coefficient(&[a, b]),
+            a.checked_add(b)
+                .and_then(|n| T::checked_binomial_coefficient(n, a))
+        );
+    });
+
+    unsigned_gen::<T>().test_properties(|n| {
+        assert_eq!(T::checked_multinomial_coefficient(&[n]), Some(T::ONE));
+    });
+}
+
+#[test]
+fn checked_multinomial_coefficient_properties() {
+    apply_fn_to_unsigneds!(checked_multinomial_coefficient_properties_helper);
+}
+
+#[test]
+fn test_multinomial_coefficient_mod() {
+    fn test<T: PrimitiveUnsigned>(ks: &[T], m: T, out: T) {
+        assert_eq!(T::multinomial_coefficient_mod(ks, m), out);
+    }
+    test::<u8>(&[], 1, 0);
+    test::<u8>(&[], 10, 1);
+    test::<u8>(&[1, 2, 3], 7, 4);
+    test::<u8>(&[6, 6], 12, 0);
+    test::<u16>(&[1, 3, 5, 7, 11], 1000, 200);
+    test::<u32>(&[5, 5, 5, 5], 1000000007, 732744947);
+    test::<u32>(&[100, 200, 300], 1000000007, 756111920);
+    test::<u32>(&[1000, 2000, 3001], 12192768, 4064256);
+}
+
+fn multinomial_coefficient_mod_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::multinomial_coefficient_mod(&[T::ONE, T::TWO], T::ZERO));
+    assert_panic!(T::multinomial_coefficient_mod(&[T::MAX, T::ONE], T::TWO));
+}
+
+#[test]
+pub fn multinomial_coefficient_mod_fail() {
+    apply_fn_to_unsigneds!(multinomial_coefficient_mod_fail_helper);
+}
+
+fn multinomial_coefficient_mod_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_vec_unsigned_pair_gen::<u8, T>().test_properties(|(ks, m)| {
+        if m == T::ZERO {
+            return;
+        }
+        let ks = ks.into_iter().map(T::from).collect::<Vec<T>>();
+        if ks
+            .iter()
+            .try_fold(T::ZERO, |s, &k| s.checked_add(k))
+            .is_none()
+        {
+            return;
+        }
+        let x = T::multinomial_coefficient_mod(&ks, m);
+        assert!(x < m);
+        if let Some(y) = T::checked_multinomial_coefficient(&ks) {
+            assert_eq!(y % m, x);
+        }
+        let mut ks_alt = ks.clone();
+        ks_alt.reverse();
+        assert_eq!(T::multinomial_coefficient_mod(&ks_alt, m), x);
+    });
+
+    unsigned_triple_gen_var_13::<T, T>().test_properties(|(m, a, b)| {
+        if m == T::ZERO {
+            return;
+        }
+        if let Some(n) = a.checked_add(b) {
+            assert_eq!(
+                T::multinomial_coefficient_mod(&[a, b], m),
+                T::binomial_coefficient_mod(n, a, m)
+            );
+        }
+    });
+}
+
+#[test]
+fn multinomial_coefficient_mod_properties() {
+    // u128 and usize are skipped for the same reasons as in binomial_coefficient_mod_properties.
+    multinomial_coefficient_mod_properties_helper::<u8>();
+    multinomial_coefficient_mod_properties_helper::<u16>();
+    multinomial_coefficient_mod_properties_helper::<u32>();
+    multinomial_coefficient_mod_properties_helper::<u64>();
+}
@@ -0,0 +1,95 @@
+use malachite_base::num::arithmetic::carmichael_lambda::carmichael_lambda_table;
+use malachite_base::num::arithmetic::traits::{CarmichaelLambda, CoprimeWith, ModPow};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+
+#[test]
+fn test_carmichael_lambda() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.carmichael_lambda(), out);
+        assert_eq!(T::carmichael_lambda_from_factors(&n.factor()), out);
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(4, 2);
+    test::<u8>(8, 2);
+    test::<u8>(15, 4);
+    test::<u8>(16, 4);
+    test::<u8>(24, 2);
+    test::<u8>(97, 96);
+    test::<u16>(561, 80);
+    test::<u16>(65535, 256);
+    test::<u32>(65537, 65536);
+    test::<u32>(u32::MAX, 65536);
+    test::<u64>(1 << 63, 1 << 61);
+    test::<u64>(u64::MAX, 17153064960);
+    test::<u128>(u128::MAX, 321876063366081731297280);
+    test::<usize>(720720, 60);
+}
+
+#[test]
+fn test_carmichael_lambda_from_factors() {
+    fn test<T: PrimitiveUnsigned>(factors: &[(T, u64)], out: T) {
+        assert_eq!(T::carmichael_lambda_from_factors(factors), out);
+    }
+    test::<u8>(&[], 1);
+    test::<u8>(&[(2, 3), (3, 1)], 2);
+    test::<u16>(&[(3, 1), (11, 1), (17, 1)], 80);
+    test::<u16>(&[(17, 1), (11, 1), (3, 1)], 80);
+    test::<u64>(&[(2, 63)], 1 << 61);
+}
+
+#[test]
+#[should_panic]
+fn carmichael_lambda_fail() {
+    0u32.carmichael_lambda();
+}
+
+#[test]
+fn test_carmichael_lambda_table() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: &[T]) {
+        assert_eq!(carmichael_lambda_table(n), out);
+    }
+    test::<u8>(0, &[0]);
+    test::<u8>(1, &[0, 1]);
+    test::<u16>(16, &[0, 1, 1, 2, 2, 4, 2, 6, 2, 6, 4, 10, 2, 12, 6, 4, 4]);
+}
+
+fn carmichael_lambda_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let lambda = n.carmichael_lambda();
+        assert_eq!(T::carmichael_lambda_from_factors(&n.factor()), lambda);
+        assert_ne!(lambda, T::ZERO);
+        assert!(n.totient().divisible_by(lambda));
+        if n > T::ONE {
+            // Every unit raised to the power lambda is 1.
+            let lambda: u64 = lambda.exact_into();
+            for a in [T::ONE, n - T::ONE] {
+                assert_eq!(a.mod_pow(lambda, n), T::ONE);
+            }
+        }
+    });
+}
+
+#[test]
+fn carmichael_lambda_properties() {
+    carmichael_lambda_properties_helper::<u8>();
+    carmichael_lambda_properties_helper::<u16>();
+    carmichael_lambda_properties_helper::<u32>();
+    carmichael_lambda_properties_helper::<u64>();
+    carmichael_lambda_properties_helper::<usize>();
+
+    // Check minimality against the definition.
+    for n in 2..=u8::MAX {
+        let units = (1..n).filter(|&a| a.coprime_with(n)).collect::<Vec<u8>>();
+        let lambda = (1..)
+            .find(|&m| units.iter().all(|&a| a.mod_pow(m, n) == 1))
+            .unwrap();
+        assert_eq!(u64::from(n.carmichael_lambda()), lambda);
+    }
+    for (n, &lambda) in carmichael_lambda_table(10000u32).iter().enumerate().skip(1) {
+        assert_eq!(u32::exact_from(n).carmichael_lambda(), lambda);
+    }
+}
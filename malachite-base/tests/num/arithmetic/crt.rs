@@ -0,0 +1,221 @@
+use malachite_base::num::arithmetic::crt::CrtBasis;
+use malachite_base::num::arithmetic::traits::{Crt, Lcm};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{
+    signed_triple_gen, unsigned_triple_gen_var_19, unsigned_vec_gen,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_crt() {
+    fn test_u<T: PrimitiveUnsigned>(congruences: &[(T, T)], out: Option<(T, T)>) {
+        assert_eq!(T::crt(congruences), out);
+    }
+    test_u::<u8>(&[], Some((0, 1)));
+    test_u::<u8>(&[(0, 1)], Some((0, 1)));
+    test_u::<u8>(&[(5, 1)], Some((0, 1)));
+    test_u::<u8>(&[(5, 7)], Some((5, 7)));
+    test_u::<u8>(&[(12, 7)], Some((5, 7)));
+    test_u::<u16>(&[(2, 3), (3, 5), (2, 7)], Some((23, 105)));
+    test_u::<u32>(&[(3, 4), (5, 6)], Some((11, 12)));
+    test_u::<u32>(&[(3, 4), (4, 6)], None);
+    test_u::<u32>(&[(1, 6), (1, 10), (1, 15)], Some((1, 30)));
+    test_u::<u64>(&[(2, 12), (5, 9), (14, 18)], Some((14, 36)));
+    test_u::<u64>(&[(0, 4), (2, 8)], None);
+    test_u::<u64>(&[(6, 8), (2, 4)], Some((6, 8)));
+    test_u::<u8>(&[(1, 16), (2, 17)], None);
+    test_u::<u8>(&[(1, 15), (2, 17)], Some((121, 255)));
+    test_u::<u64>(
+        &[(1, 4294967291), (2, 4294967279)],
+        Some((1537228665292936541, 18446743979220271189)),
+    );
+    test_u::<u128>(
+        &[(1, 18446744073709551557), (2, 18446744073709551533)],
+        Some((
+            269390207145742948168885365600372308430,
+            340282366920938460843936948965011886881,
+        )),
+    );
+
+    fn test_s<T: PrimitiveSigned>(congruences: &[(T, T)], out: Option<(T, T)>) {
+        assert_eq!(T::crt(congruences), out);
+    }
+    test_s::<i8>(&[], Some((0, 1)));
+    test_s::<i8>(&[(-1, 7)], Some((6, 7)));
+    test_s::<i16>(&[(-1, 3), (-1, 5)], Some((14, 15)));
+    test_s::<i32>(&[(-7, 4), (-1, 6)], Some((5, 12)));
+    test_s::<i32>(&[(-1, 4), (0, 6)], None);
+    test_s::<i8>(&[(1, 8), (2, 15)], Some((17, 120)));
+    test_s::<i8>(&[(1, 8), (2, 17)], None);
+    test_s::<i64>(&[(i64::MIN, 3), (i64::MAX, 5)], Some((7, 15)));
+}
+
+fn crt_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::crt(&[(T::ONE, T::ZERO)]));
+    assert_panic!(T::crt(&[(T::ONE, T::TWO), (T::ONE, T::ZERO)]));
+}
+
+fn crt_fail_helper_signed<T: PrimitiveSigned>() {
+    assert_panic!(T::crt(&[(T::ONE, T::ZERO)]));
+    assert_panic!(T::crt(&[(T::ONE, T::NEGATIVE_ONE)]));
+}
+
+#[test]
+fn crt_fail() {
+    apply_fn_to_unsigneds!(crt_fail_helper);
+    apply_fn_to_signeds!(crt_fail_helper_signed);
+}
+
+#[test]
+fn test_crt_precomputed() {
+    fn test<T: PrimitiveUnsigned>(moduli: &[T], residues: &[T], modulus: T, out: T) {
+        let basis: CrtBasis<T> = T::precompute_crt_data(moduli).unwrap();
+        assert_eq!(basis.moduli(), moduli);
+        assert_eq!(basis.modulus(), modulus);
+        assert_eq!(T::crt_precomputed(residues, &basis), out);
+    }
+    test::<u8>(&[], &[], 1, 0);
+    test::<u8>(&[1], &[5], 1, 0);
+    test::<u8>(&[7], &[12], 7, 5);
+    test::<u8>(&[1, 7, 1], &[3, 12, 5], 7, 5);
+    test::<u16>(&[3, 5, 7], &[2, 3, 2], 105, 23);
+    test::<u32>(&[3, 5, 7], &[5, 8, 9], 105, 23);
+    test::<u32>(&[4, 9, 25], &[3, 8, 24], 900, 899);
+    test::<u8>(&[15, 17], &[1, 2], 255, 121);
+    test::<u64>(
+        &[4294967291, 4294967279],
+        &[1, 2],
+        18446743979220271189,
+        1537228665292936541,
+    );
+
+    fn test_none<T: PrimitiveUnsigned>(moduli: &[T]) {
+        assert!(T::precompute_crt_data(moduli).is_none());
+    }
+    test_none::<u8>(&[2, 2]);
+    test_none::<u8>(&[4, 6]);
+    test_none::<u32>(&[3, 5, 21]);
+    test_none::<u8>(&[16, 17]);
+}
+
+fn precompute_crt_data_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::precompute_crt_data(&[T::ZERO]));
+    assert_panic!(T::precompute_crt_data(&[T::TWO, T::ZERO]));
+}
+
+fn crt_precomputed_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!({
+        let basis = T::precompute_crt_data(&[T::TWO, T::exact_from(3)]).unwrap();
+        T::crt_precomputed(&[T::ONE], &basis)
+    });
+    assert_panic!({
+        let basis = T::precompute_crt_data(&[T::TWO, T::exact_from(3)]).unwrap();
+        T::crt_precomputed(&[T::ONE, T::ONE, T::ONE], &basis)
+    });
+}
+
+#[test]
+fn crt_precomputed_fail() {
+    apply_fn_to_unsigneds!(precompute_crt_data_fail_helper);
+    apply_fn_to_unsigneds!(crt_precomputed_fail_helper);
+}
+
+fn crt_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_triple_gen_var_19::<T>().test_properties(|(x, a, b)| {
+        if a == T::ZERO || b == T::ZERO {
+            return;
+        }
+        let congruences = [(x % a, a), (x % b, b)];
+        let result = T::crt(&congruences);
+        match a.checked_lcm(b) {
+            None => assert!(result.is_none()),
+            Some(lcm) => {
+                assert_eq!(result, Some((x % lcm, lcm)));
+                assert_eq!(T::crt(&[(x, a), (x, b)]), result);
+                assert_eq!(T::crt(&[congruences[1], congruences[0]]), result);
+            }
+        }
+        let g = a.gcd(b);
+        if g != T::ONE {
+            let y = if x % a % g == T::ZERO {
+                x % a + T::ONE
+            } else {
+                x % a - T::ONE
+            };
+            assert!(T::crt(&[(y, a), (x, b)]).is_none());
+        }
+    });
+
+    unsigned_vec_gen::<T>().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.is_empty() || xs[1..].contains(&T::ZERO) {
+            return;
+        }
+        let x = xs[0];
+        let moduli = &xs[1..];
+        let congruences = moduli.iter().map(|&m| (x % m, m)).collect::<Vec<_>>();
+        let lcm = moduli.iter().try_fold(T::ONE, |l, &m| l.checked_lcm(m));
+        match T::crt(&congruences) {
+            None => assert!(lcm.is_none()),
+            Some((y, l)) => {
+                assert_eq!(Some(l), lcm);
+                assert!(y < l);
+                assert_eq!(y, x % l);
+            }
+        }
+        if let Some(basis) = T::precompute_crt_data(moduli) {
+            let residues = moduli.iter().map(|&m| x % m).collect::<Vec<_>>();
+            let y = T::crt_precomputed(&residues, &basis);
+            assert_eq!(y, x % basis.modulus());
+            assert_eq!(Some(basis.modulus()), lcm);
+            assert_eq!(T::crt(&congruences), Some((y, basis.modulus())));
+            assert_eq!(T::crt_precomputed(&xs[..moduli.len()], &basis), {
+                let congruences = xs[..moduli.len()]
+                    .iter()
+                    .zip(moduli.iter())
+                    .map(|(&r, &m)| (r, m))
+                    .collect::<Vec<_>>();
+                T::crt(&congruences).unwrap().0
+            });
+        }
+    });
+}
+
+fn crt_properties_helper_signed<
+    U: PrimitiveUnsigned + WrappingFrom<S>,
+    S: PrimitiveSigned + WrappingFrom<U>,
+>() {
+    signed_triple_gen::<S>().test_properties(|(x, a, b)| {
+        if a <= S::ZERO || b <= S::ZERO {
+            return;
+        }
+        let result = S::crt(&[(x, a), (x, b)]);
+        match U::wrapping_from(a).checked_lcm(U::wrapping_from(b)) {
+            Some(lcm) if lcm <= U::wrapping_from(S::MAX) => {
+                let lcm = S::wrapping_from(lcm);
+                assert_eq!(result, Some((x.mod_op(lcm), lcm)));
+            }
+            _ => assert!(result.is_none()),
+        }
+    });
+}
+
+#[test]
+fn crt_properties() {
+    apply_fn_to_unsigneds!(crt_properties_helper_unsigned);
+    apply_fn_to_unsigned_signed_pairs!(crt_properties_helper_signed);
+
+    // Every pair of residues for moduli below 20, coprime or not
+    for a in 1..20u16 {
+        for b in 1..20u16 {
+            for r in 0..a {
+                for s in 0..b {
+                    let expected = (0..a.lcm(b)).find(|x| x % a == r && x % b == s);
+                    assert_eq!(u16::crt(&[(r, a), (s, b)]), expected.map(|x| (x, a.lcm(b))));
+                }
+            }
+        }
+    }
+}
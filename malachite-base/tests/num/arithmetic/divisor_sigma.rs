@@ -0,0 +1,197 @@
+use malachite_base::num::arithmetic::divisor_sigma::{divisor_count_table, divisor_sigma_table};
+use malachite_base::num::arithmetic::traits::{CheckedDivisorSigma, DivisorCount, DivisorSigma};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_27, unsigned_pair_gen_var_3,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_divisor_count() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.divisor_count(), out);
+        assert_eq!(T::divisor_count_from_factors(&n.factor()), out);
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 2);
+    test::<u8>(12, 6);
+    test::<u8>(97, 2);
+    test::<u8>(128, 8);
+    test::<u16>(360, 24);
+    test::<u16>(65535, 16);
+    test::<u32>(720720, 240);
+    test::<u64>(u64::MAX, 128);
+    test::<u128>(u128::MAX, 512);
+    test::<usize>(1001, 8);
+}
+
+#[test]
+fn test_divisor_count_from_factors() {
+    fn test<T: PrimitiveUnsigned>(factors: &[(T, u64)], out: T) {
+        assert_eq!(T::divisor_count_from_factors(factors), out);
+    }
+    test::<u8>(&[], 1);
+    test::<u16>(&[(2, 3), (3, 2), (5, 1)], 24);
+    test::<u64>(&[(2, 63)], 64);
+}
+
+#[test]
+#[should_panic]
+fn divisor_count_fail() {
+    0u32.divisor_count();
+}
+
+#[test]
+fn test_divisor_sigma() {
+    fn test<T: PrimitiveUnsigned>(n: T, k: u64, out: T) {
+        assert_eq!(n.divisor_sigma(k), out);
+        assert_eq!(T::divisor_sigma_from_factors(&n.factor(), k), out);
+    }
+    test::<u8>(1, 0, 1);
+    test::<u8>(1, 100, 1);
+    test::<u8>(12, 0, 6);
+    test::<u8>(12, 1, 28);
+    test::<u8>(12, 2, 210);
+    test::<u8>(127, 1, 128);
+    test::<u16>(360, 1, 1170);
+    test::<u32>(720720, 1, 3249792);
+    test::<u32>(360, 2, 201110);
+    test::<u64>(1 << 63, 1, u64::MAX);
+    test::<u128>(1 << 127, 1, u128::MAX);
+    test::<usize>(1001, 1, 1344);
+}
+
+#[test]
+fn divisor_sigma_fail() {
+    assert_panic!(0u32.divisor_sigma(1));
+    assert_panic!(u8::MAX.divisor_sigma(1));
+    assert_panic!(12u8.divisor_sigma(3));
+    assert_panic!(u64::MAX.divisor_sigma(1));
+    assert_panic!(u8::divisor_sigma_from_factors(&[(2, 2), (3, 1)], 3));
+}
+
+#[test]
+fn test_checked_divisor_sigma() {
+    fn test<T: PrimitiveUnsigned>(n: T, k: u64, out: Option<T>) {
+        assert_eq!(n.checked_divisor_sigma(k), out);
+        assert_eq!(T::checked_divisor_sigma_from_factors(&n.factor(), k), out);
+    }
+    test::<u8>(1, 1, Some(1));
+    test::<u8>(12, 1, Some(28));
+    test::<u8>(12, 2, Some(210));
+    test::<u8>(12, 3, None);
+    test::<u8>(100, 1, Some(217));
+    test::<u8>(u8::MAX, 1, None);
+    test::<u8>(2, 8, None);
+    test::<u32>(u32::MAX, 1, None);
+    test::<u64>(1 << 63, 1, Some(u64::MAX));
+    test::<u64>(u64::MAX, 1, None);
+    test::<u128>(u128::MAX, 1, None);
+}
+
+#[test]
+#[should_panic]
+fn checked_divisor_sigma_fail() {
+    0u32.checked_divisor_sigma(1);
+}
+
+#[test]
+fn test_divisor_count_table() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: &[T]) {
+        assert_eq!(divisor_count_table(n), out);
+    }
+    test::<u8>(0, &[0]);
+    test::<u8>(1, &[0, 1]);
+    test::<u16>(12, &[0, 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6]);
+}
+
+#[test]
+fn test_divisor_sigma_table() {
+    fn test<T: PrimitiveUnsigned>(n: T, k: u64, out: &[T]) {
+        assert_eq!(divisor_sigma_table(n, k), out);
+    }
+    test::<u8>(0, 1, &[0]);
+    test::<u8>(1, 1, &[0, 1]);
+    test::<u8>(12, 0, &[0, 1, 2, 2, 3, 2, 4, 2, 4, 3, 4, 2, 6]);
+    test::<u16>(12, 1, &[0, 1, 3, 4, 7, 6, 12, 8, 15, 13, 18, 12, 28]);
+    test::<u32>(6, 2, &[0, 1, 5, 10, 21, 26, 50]);
+}
+
+#[test]
+#[should_panic]
+fn divisor_sigma_table_fail() {
+    divisor_sigma_table(u8::MAX, 1);
+}
+
+fn divisor_sigma_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let factors = n.factor();
+        let tau = n.divisor_count();
+        assert_eq!(T::divisor_count_from_factors(&factors), tau);
+        assert!(tau <= n);
+        assert_eq!(n.checked_divisor_sigma(0), Some(tau));
+        if n > T::ONE {
+            assert_eq!(tau == T::TWO, n.is_prime());
+        }
+        let sigma = n.checked_divisor_sigma(1);
+        assert_eq!(T::checked_divisor_sigma_from_factors(&factors, 1), sigma);
+        if let Some(sigma) = sigma {
+            assert!(sigma >= n);
+            if n > T::ONE {
+                assert_eq!(sigma == n + T::ONE, n.is_prime());
+            }
+        }
+    });
+
+    unsigned_pair_gen_var_3::<T>().test_properties_with_limit(SMALL_LIMIT, |(n, k)| {
+        if n == T::ZERO {
+            return;
+        }
+        let sigma = n.checked_divisor_sigma(k);
+        assert_eq!(T::checked_divisor_sigma_from_factors(&n.factor(), k), sigma);
+        if let Some(sigma) = sigma {
+            assert_eq!(n.divisor_sigma(k), sigma);
+            assert!(sigma >= n.checked_pow(k).unwrap());
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties_with_limit(SMALL_LIMIT, |(a, b)| {
+        if a == T::ZERO || b == T::ZERO || !a.coprime_with(b) {
+            return;
+        }
+        if let Some(n) = a.checked_mul(b) {
+            assert_eq!(n.divisor_count(), a.divisor_count() * b.divisor_count());
+            if let (Some(x), Some(y)) = (a.checked_divisor_sigma(1), b.checked_divisor_sigma(1)) {
+                assert_eq!(n.checked_divisor_sigma(1), x.checked_mul(y));
+            }
+        }
+    });
+}
+
+#[test]
+fn divisor_sigma_properties() {
+    divisor_sigma_properties_helper::<u8>();
+    divisor_sigma_properties_helper::<u16>();
+    divisor_sigma_properties_helper::<u32>();
+    divisor_sigma_properties_helper::<u64>();
+    divisor_sigma_properties_helper::<usize>();
+
+    for n in 1..=u8::MAX {
+        let divisors = (1..=n).filter(|&d| n % d == 0).collect::<Vec<u8>>();
+        assert_eq!(n.divisor_count(), u8::exact_from(divisors.len()));
+        for k in 0..4 {
+            let sigma = divisors
+                .iter()
+                .try_fold(0u8, |acc, &d| acc.checked_add(d.checked_pow(k)?));
+            assert_eq!(n.checked_divisor_sigma(u64::from(k)), sigma);
+        }
+    }
+    let taus = divisor_count_table(10000u32);
+    let sigmas = divisor_sigma_table(10000u64, 2);
+    for n in 1..=10000 {
+        assert_eq!(n.divisor_count(), taus[usize::exact_from(n)]);
+        assert_eq!(u64::from(n).divisor_sigma(2), sigmas[usize::exact_from(n)]);
+    }
+}
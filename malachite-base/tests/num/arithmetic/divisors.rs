@@ -0,0 +1,93 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::Divisors;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+
+#[test]
+fn test_divisors() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: &[T]) {
+        assert_eq!(n.divisors().collect_vec(), out);
+        assert_eq!(T::divisors_from_factors(&n.factor()).collect_vec(), out);
+    }
+    test::<u8>(1, &[1]);
+    test::<u8>(2, &[1, 2]);
+    test::<u8>(12, &[1, 2, 3, 4, 6, 12]);
+    test::<u8>(97, &[1, 97]);
+    test::<u8>(128, &[1, 2, 4, 8, 16, 32, 64, 128]);
+    test::<u16>(
+        360,
+        &[
+            1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 18, 20, 24, 30, 36, 40, 45, 60, 72, 90, 120, 180,
+            360,
+        ],
+    );
+    test::<u32>(
+        u32::MAX,
+        &[
+            1, 3, 5, 15, 17, 51, 85, 255, 257, 771, 1285, 3855, 4369, 13107, 21845, 65535, 65537,
+            196611, 327685, 983055, 1114129, 3342387, 5570645, 16711935, 16843009, 50529027,
+            84215045, 252645135, 286331153, 858993459, 1431655765, 4294967295,
+        ],
+    );
+    test::<u64>(1000000007, &[1, 1000000007]);
+    test::<usize>(1001, &[1, 7, 11, 13, 77, 91, 143, 1001]);
+}
+
+#[test]
+fn test_divisors_from_factors() {
+    fn test<T: PrimitiveUnsigned>(factors: &[(T, u64)], out: &[T]) {
+        assert_eq!(T::divisors_from_factors(factors).collect_vec(), out);
+    }
+    test::<u8>(&[], &[1]);
+    test::<u8>(&[(2, 2), (3, 1)], &[1, 2, 3, 4, 6, 12]);
+    test::<u8>(&[(3, 1), (2, 2)], &[1, 2, 3, 4, 6, 12]);
+    test::<u128>(&[(2, 127)], &(0..128).map(|i| 1 << i).collect_vec());
+}
+
+#[test]
+#[should_panic]
+fn divisors_fail() {
+    0u32.divisors();
+}
+
+fn divisors_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let divisors = n.divisors().collect_vec();
+        assert_eq!(
+            T::divisors_from_factors(&n.factor()).collect_vec(),
+            divisors
+        );
+        assert_eq!(T::exact_from(divisors.len()), n.divisor_count());
+        assert!(divisors.iter().tuple_windows().all(|(d, e)| d < e));
+        assert_eq!(divisors.first(), Some(&T::ONE));
+        assert_eq!(divisors.last(), Some(&n));
+        assert!(divisors.iter().all(|&d| n.divisible_by(d)));
+        // Divisors come in pairs d, n / d.
+        assert!(divisors
+            .iter()
+            .zip(divisors.iter().rev())
+            .all(|(&d, &e)| d * e == n));
+        if let Some(sigma) = n.checked_divisor_sigma(1) {
+            assert_eq!(
+                divisors
+                    .iter()
+                    .try_fold(T::ZERO, |acc, &d| acc.checked_add(d)),
+                Some(sigma)
+            );
+        }
+    });
+}
+
+#[test]
+fn divisors_properties() {
+    divisors_properties_helper::<u8>();
+    divisors_properties_helper::<u16>();
+    divisors_properties_helper::<u32>();
+    divisors_properties_helper::<u64>();
+    divisors_properties_helper::<usize>();
+
+    for n in 1..=1000u16 {
+        assert!(n.divisors().eq((1..=n).filter(|&d| n % d == 0)));
+    }
+}
@@ -388,3 +388,59 @@ fn checked_subfactorial_properties_helper<T: PrimitiveUnsigned>() {
 fn checked_subfactorial_properties() {
     apply_fn_to_unsigneds!(checked_subfactorial_properties_helper);
 }
+
+#[test]
+fn test_factorial_mod() {
+    fn test<T: PrimitiveUnsigned>(n: u64, m: T, out: T) {
+        assert_eq!(T::factorial_mod(n, m), out);
+    }
+    test::<u8>(0, 1, 0);
+    test::<u8>(0, 10, 1);
+    test::<u8>(4, 10, 4);
+    test::<u8>(5, 10, 0);
+    test::<u8>(255, 255, 0);
+    test::<u8>(100, 101, 100);
+    test::<u16>(10, 1000, 800);
+    test::<u32>(12, 479001599, 1);
+    test::<u32>(20, 1000000007, 146326063);
+    test::<u64>(1000, 1000000007, 641419708);
+    test::<u64>(1000, 1000003, 864722);
+    test::<u64>(u64::MAX, 1000003, 0);
+    // The running product becomes 0 long before $i$ reaches $n$.
+    test::<u64>(60000, 1 << 62, 0);
+    test::<u64>(1 << 61, 1 << 62, 0);
+    // $m$ divides $n!$, although the running product would only become 0 at $i = 549755813881$.
+    test::<u64>(1 << 40, 1099511627762, 0);
+    test::<u128>(1 << 62, 1 << 126, 0);
+}
+
+fn factorial_mod_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::factorial_mod(10, T::ZERO));
+}
+
+#[test]
+fn factorial_mod_fail() {
+    apply_fn_to_unsigneds!(factorial_mod_fail_helper);
+}
+
+fn factorial_mod_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_12::<u8, T>().test_properties(|(n, m)| {
+        let n = u64::from(n);
+        let f = T::factorial_mod(n, m);
+        assert!(f < m);
+        if let Some(g) = T::checked_factorial(n) {
+            assert_eq!(g % m, f);
+        }
+        if T::exact_from(n) >= m {
+            assert_eq!(f, T::ZERO);
+        }
+        if n != 0 && T::exact_from(n) < m {
+            assert_eq!(T::factorial_mod(n - 1, m).mod_mul(T::exact_from(n), m), f);
+        }
+    });
+}
+
+#[test]
+fn factorial_mod_properties() {
+    apply_fn_to_unsigneds!(factorial_mod_properties_helper);
+}
@@ -0,0 +1,165 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_pair_gen_var_12};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::fibonacci(n), out);
+    }
+    test::<u8>(0, 0);
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(3, 2);
+    test::<u8>(4, 3);
+    test::<u8>(5, 5);
+    test::<u8>(13, 233);
+    test::<u32>(40, 102334155);
+    test::<u64>(93, 12200160415121876738);
+    test::<u128>(186, 332825110087067562321196029789634457848);
+}
+
+fn fibonacci_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci(200));
+}
+
+#[test]
+fn fibonacci_fail() {
+    apply_fn_to_unsigneds!(fibonacci_fail_helper);
+}
+
+#[test]
+fn test_checked_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_fibonacci(n), out);
+    }
+    test::<u8>(0, Some(0));
+    test::<u8>(1, Some(1));
+    test::<u8>(10, Some(55));
+    test::<u8>(13, Some(233));
+    test::<u8>(14, None);
+    test::<u64>(93, Some(12200160415121876738));
+    test::<u64>(94, None);
+    test::<u128>(186, Some(332825110087067562321196029789634457848));
+    test::<u128>(187, None);
+    test::<u32>(u64::MAX, None);
+}
+
+#[test]
+fn test_fibonacci_pair() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: (T, T)) {
+        assert_eq!(T::fibonacci_pair(n), out);
+    }
+    test::<u8>(0, (0, 1));
+    test::<u8>(1, (1, 1));
+    test::<u8>(2, (1, 2));
+    test::<u8>(12, (144, 233));
+    test::<u32>(40, (102334155, 165580141));
+}
+
+fn fibonacci_pair_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci_pair(200));
+}
+
+#[test]
+fn fibonacci_pair_fail() {
+    apply_fn_to_unsigneds!(fibonacci_pair_fail_helper);
+}
+
+#[test]
+fn test_checked_fibonacci_pair() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<(T, T)>) {
+        assert_eq!(T::checked_fibonacci_pair(n), out);
+    }
+    test::<u8>(0, Some((0, 1)));
+    test::<u8>(10, Some((55, 89)));
+    test::<u8>(12, Some((144, 233)));
+    test::<u8>(13, None);
+    test::<u64>(92, Some((7540113804746346429, 12200160415121876738)));
+    test::<u64>(93, None);
+}
+
+#[test]
+fn test_fibonacci_mod() {
+    fn test<T: PrimitiveUnsigned>(n: u64, m: T, out: T) {
+        assert_eq!(T::fibonacci_mod(n, m), out);
+    }
+    test::<u8>(0, 1, 0);
+    test::<u8>(1, 1, 0);
+    test::<u8>(0, 10, 0);
+    test::<u8>(1, 10, 1);
+    test::<u8>(10, 10, 5);
+    test::<u8>(13, 255, 233);
+    test::<u8>(14, 255, 122);
+    test::<u32>(100, 1000000007, 687995182);
+    test::<u64>(1000000000000, 1000000007, 730695249);
+    test::<u64>(u64::MAX, u64::MAX, 4093298358055684510);
+}
+
+fn fibonacci_mod_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci_mod(10, T::ZERO));
+}
+
+#[test]
+fn fibonacci_mod_fail() {
+    apply_fn_to_unsigneds!(fibonacci_mod_fail_helper);
+}
+
+fn checked_fibonacci_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let of = T::checked_fibonacci(n);
+        let op = T::checked_fibonacci_pair(n);
+        if let Some(f) = of {
+            assert_eq!(T::fibonacci(n), f);
+            if n > 1 {
+                let (f_1, f_2) = T::fibonacci_pair(n - 2);
+                assert_eq!(f_1 + f_2, f);
+            }
+        }
+        if let Some((f, g)) = op {
+            assert_eq!(T::fibonacci_pair(n), (f, g));
+            assert_eq!(of, Some(f));
+            assert_eq!(T::checked_fibonacci(n + 1), Some(g));
+        } else if n != u64::MAX {
+            assert!(T::checked_fibonacci(n + 1).is_none());
+        }
+        if n != u64::MAX && of.is_none() {
+            assert!(T::checked_fibonacci(n + 1).is_none());
+        }
+    });
+}
+
+#[test]
+fn checked_fibonacci_properties() {
+    apply_fn_to_unsigneds!(checked_fibonacci_properties_helper);
+}
+
+fn fibonacci_mod_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_12::<u64, T>().test_properties(|(n, m)| {
+        let f = T::fibonacci_mod(n, m);
+        assert!(f < m);
+        if let Some(g) = T::checked_fibonacci(n) {
+            assert_eq!(g % m, f);
+        }
+        if n < u64::MAX - 1 {
+            let f_1 = T::fibonacci_mod(n + 1, m);
+            let f_2 = T::fibonacci_mod(n + 2, m);
+            assert_eq!(f.mod_add(f_1, m), f_2);
+        }
+        if T::convertible_from(n) {
+            assert_eq!(
+                T::exact_from(n).lucas_sequence_mod(T::ONE, m - T::ONE, m).0,
+                f
+            );
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        assert_eq!(T::fibonacci_mod(n, T::ONE), T::ZERO);
+    });
+}
+
+#[test]
+fn fibonacci_mod_properties() {
+    apply_fn_to_unsigneds!(fibonacci_mod_properties_helper);
+}
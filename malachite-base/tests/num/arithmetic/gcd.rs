@@ -1,7 +1,7 @@
 use malachite_base::num::arithmetic::gcd::{gcd_binary, gcd_euclidean, gcd_fast_a, gcd_fast_b};
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::test_util::generators::{
-    unsigned_gen, unsigned_pair_gen_var_27, unsigned_triple_gen_var_19,
+    unsigned_gen, unsigned_pair_gen_var_27, unsigned_triple_gen_var_19, unsigned_vec_gen,
 };
 
 #[test]
@@ -63,3 +63,39 @@ fn gcd_properties_helper<T: PrimitiveUnsigned>() {
 fn gcd_properties() {
     apply_fn_to_unsigneds!(gcd_properties_helper);
 }
+
+#[test]
+fn test_gcd_of() {
+    fn test<T: PrimitiveUnsigned>(xs: &[T], out: T) {
+        assert_eq!(T::gcd_of(xs.iter().copied()), out);
+    }
+    test::<u8>(&[], 0);
+    test::<u16>(&[0], 0);
+    test::<u32>(&[0, 0, 0], 0);
+    test::<u64>(&[6], 6);
+    test::<u128>(&[0, 6, 0], 6);
+    test::<usize>(&[12, 90, 42], 6);
+    test::<u8>(&[6, 10, 15], 1);
+    test::<u16>(&[48, 18, 30, 0], 6);
+}
+
+fn gcd_of_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_vec_gen::<T>().test_properties(|xs| {
+        let gcd = T::gcd_of(xs.iter().copied());
+        assert_eq!(xs.iter().fold(T::ZERO, |acc, &x| acc.gcd(x)), gcd);
+        assert_eq!(T::gcd_of(xs.iter().rev().copied()), gcd);
+        for &x in &xs {
+            assert!(x.divisible_by(gcd));
+        }
+        assert_eq!(gcd == T::ZERO, xs.iter().all(|&x| x == T::ZERO));
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(x, y)| {
+        assert_eq!(T::gcd_of([x, y].into_iter()), x.gcd(y));
+    });
+}
+
+#[test]
+fn gcd_of_properties() {
+    apply_fn_to_unsigneds!(gcd_of_properties_helper);
+}
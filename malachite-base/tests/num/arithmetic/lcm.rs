@@ -1,6 +1,7 @@
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
 use malachite_base::test_util::generators::{
     unsigned_gen, unsigned_pair_gen_var_33, unsigned_pair_gen_var_34, unsigned_triple_gen_var_19,
+    unsigned_vec_gen,
 };
 use std::panic::catch_unwind;
 
@@ -124,3 +125,49 @@ fn checked_lcm_properties_helper<T: PrimitiveUnsigned>() {
 fn checked_lcm_properties() {
     apply_fn_to_unsigneds!(checked_lcm_properties_helper);
 }
+
+#[test]
+fn test_lcm_of() {
+    fn test<T: PrimitiveUnsigned>(xs: &[T], out: T) {
+        assert_eq!(T::lcm_of(xs.iter().copied()), out);
+    }
+    test::<u8>(&[], 1);
+    test::<u16>(&[0], 0);
+    test::<u32>(&[6], 6);
+    test::<u64>(&[6, 0, 15], 0);
+    test::<u128>(&[1, 1, 1], 1);
+    test::<usize>(&[12, 90, 42], 1260);
+    test::<u8>(&[6, 10, 15], 30);
+    test::<u16>(&[4, 6, 8, 9], 72);
+}
+
+fn lcm_of_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lcm_of([T::MAX, T::TWO].into_iter()));
+}
+
+#[test]
+fn lcm_of_fail() {
+    apply_fn_to_unsigneds!(lcm_of_fail_helper);
+}
+
+fn lcm_of_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_vec_gen::<T>().test_properties(|xs| {
+        if let Some(lcm) = xs.iter().try_fold(T::ONE, |acc, &x| acc.checked_lcm(x)) {
+            assert_eq!(T::lcm_of(xs.iter().copied()), lcm);
+            assert_eq!(T::lcm_of(xs.iter().rev().copied()), lcm);
+            for &x in &xs {
+                assert!(lcm.divisible_by(x));
+            }
+            assert_eq!(lcm == T::ZERO, xs.contains(&T::ZERO));
+        }
+    });
+
+    unsigned_pair_gen_var_34::<T>().test_properties(|(x, y)| {
+        assert_eq!(T::lcm_of([x, y].into_iter()), x.lcm(y));
+    });
+}
+
+#[test]
+fn lcm_of_properties() {
+    apply_fn_to_unsigneds!(lcm_of_properties_helper);
+}
@@ -0,0 +1,227 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_pair_gen_var_12, unsigned_quadruple_gen_var_13,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::lucas(n), out);
+    }
+    test::<u8>(0, 2);
+    test::<u8>(1, 1);
+    test::<u8>(2, 3);
+    test::<u8>(3, 4);
+    test::<u8>(4, 7);
+    test::<u8>(5, 11);
+    test::<u8>(11, 199);
+    test::<u32>(40, 228826127);
+    test::<u64>(92, 16860207025497407047);
+}
+
+fn lucas_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lucas(200));
+}
+
+#[test]
+fn lucas_fail() {
+    apply_fn_to_unsigneds!(lucas_fail_helper);
+}
+
+#[test]
+fn test_checked_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_lucas(n), out);
+    }
+    test::<u8>(0, Some(2));
+    test::<u8>(1, Some(1));
+    test::<u8>(10, Some(123));
+    test::<u8>(11, Some(199));
+    test::<u8>(12, None);
+    test::<u64>(92, Some(16860207025497407047));
+    test::<u64>(93, None);
+    test::<u32>(u64::MAX, None);
+}
+
+#[test]
+fn test_lucas_pair() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: (T, T)) {
+        assert_eq!(T::lucas_pair(n), out);
+    }
+    test::<u8>(0, (2, 1));
+    test::<u8>(1, (1, 3));
+    test::<u8>(10, (123, 199));
+    test::<u32>(40, (228826127, 370248451));
+}
+
+fn lucas_pair_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lucas_pair(200));
+}
+
+#[test]
+fn lucas_pair_fail() {
+    apply_fn_to_unsigneds!(lucas_pair_fail_helper);
+}
+
+#[test]
+fn test_checked_lucas_pair() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<(T, T)>) {
+        assert_eq!(T::checked_lucas_pair(n), out);
+    }
+    test::<u8>(0, Some((2, 1)));
+    test::<u8>(10, Some((123, 199)));
+    test::<u8>(11, None);
+}
+
+#[test]
+fn test_lucas_mod() {
+    fn test<T: PrimitiveUnsigned>(n: u64, m: T, out: T) {
+        assert_eq!(T::lucas_mod(n, m), out);
+    }
+    test::<u8>(0, 1, 0);
+    test::<u8>(0, 2, 0);
+    test::<u8>(0, 3, 2);
+    test::<u8>(1, 10, 1);
+    test::<u8>(10, 10, 3);
+    test::<u8>(12, 255, 67);
+    test::<u32>(100, 1000000007, 876413006);
+    test::<u64>(u64::MAX, u64::MAX, 9053541341258626079);
+}
+
+fn lucas_mod_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lucas_mod(10, T::ZERO));
+}
+
+#[test]
+fn lucas_mod_fail() {
+    apply_fn_to_unsigneds!(lucas_mod_fail_helper);
+}
+
+#[test]
+fn test_lucas_sequence_mod() {
+    fn test<T: PrimitiveUnsigned>(k: T, p: T, q: T, m: T, out: (T, T)) {
+        assert_eq!(k.lucas_sequence_mod(p, q, m), out);
+    }
+    test::<u8>(0, 1, 1, 1, (0, 0));
+    test::<u8>(0, 5, 7, 10, (0, 2));
+    test::<u8>(1, 5, 7, 10, (1, 5));
+    test::<u16>(10, 1, 255, 256, (55, 123));
+    test::<u8>(12, 1, 254, 255, (144, 67));
+    // Even moduli are allowed.
+    test::<u16>(16, 1, 99, 100, (87, 7));
+    // U_k(3, 2) = 2^k - 1 and V_k(3, 2) = 2^k + 1
+    test::<u32>(10, 3, 2, 1000000, (1023, 1025));
+    test::<u64>(100, 3, 2, 1000000007, (976371284, 976371286));
+    // U_k(2, 1) = k and V_k(2, 1) = 2
+    test::<u64>(123456789, 2, 1, 1000000007, (123456789, 2));
+    // P and Q are reduced first.
+    test::<u32>(10, 4, 3, 1, (0, 0));
+    test::<u32>(10, 1003, 1002, 1000, (23, 25));
+}
+
+fn lucas_sequence_mod_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::TWO.lucas_sequence_mod(T::ONE, T::ONE, T::ZERO));
+}
+
+#[test]
+fn lucas_sequence_mod_fail() {
+    apply_fn_to_unsigneds!(lucas_sequence_mod_fail_helper);
+}
+
+fn checked_lucas_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let ol = T::checked_lucas(n);
+        if let Some(l) = ol {
+            assert_eq!(T::lucas(n), l);
+            if n > 1 {
+                let (l_1, l_2) = T::lucas_pair(n - 2);
+                assert_eq!(l_1 + l_2, l);
+            }
+            // L_n = F_{n-1} + F_{n+1}
+            if n > 0 {
+                if let (Some(f_1), Some(f_2)) =
+                    (T::checked_fibonacci(n - 1), T::checked_fibonacci(n + 1))
+                {
+                    assert_eq!(f_1 + f_2, l);
+                }
+            }
+        }
+        if let Some((l, l_1)) = T::checked_lucas_pair(n) {
+            assert_eq!(T::lucas_pair(n), (l, l_1));
+            assert_eq!(ol, Some(l));
+            assert_eq!(T::checked_lucas(n + 1), Some(l_1));
+        }
+        if n != u64::MAX && ol.is_none() {
+            assert!(T::checked_lucas(n + 1).is_none());
+        }
+    });
+}
+
+#[test]
+fn checked_lucas_properties() {
+    apply_fn_to_unsigneds!(checked_lucas_properties_helper);
+}
+
+fn lucas_mod_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_12::<u64, T>().test_properties(|(n, m)| {
+        let l = T::lucas_mod(n, m);
+        assert!(l < m);
+        if let Some(l_alt) = T::checked_lucas(n) {
+            assert_eq!(l_alt % m, l);
+        }
+        if n < u64::MAX - 1 {
+            let l_1 = T::lucas_mod(n + 1, m);
+            let l_2 = T::lucas_mod(n + 2, m);
+            assert_eq!(l.mod_add(l_1, m), l_2);
+        }
+        if T::convertible_from(n) {
+            assert_eq!(
+                T::exact_from(n).lucas_sequence_mod(T::ONE, m - T::ONE, m).1,
+                l
+            );
+        }
+    });
+}
+
+#[test]
+fn lucas_mod_properties() {
+    apply_fn_to_unsigneds!(lucas_mod_properties_helper);
+}
+
+fn lucas_sequence_mod_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_quadruple_gen_var_13::<T>().test_properties(|(k, p, q, m)| {
+        let (u, v) = k.lucas_sequence_mod(p, q, m);
+        assert!(u < m);
+        assert!(v < m);
+        assert_eq!(k.lucas_sequence_mod(p % m, q % m, m), (u, v));
+        let p = p % m;
+        let q = q % m;
+        if k != T::MAX {
+            let u_1 = (k + T::ONE).lucas_sequence_mod(p, q, m).0;
+            // V_k = 2U_{k+1} - PU_k
+            assert_eq!(v, u_1.mod_add(u_1, m).mod_sub(p.mod_mul(u, m), m));
+            // U_{k+1} = PU_k - QU_{k-1}
+            if k != T::ZERO {
+                let u_0 = (k - T::ONE).lucas_sequence_mod(p, q, m).0;
+                assert_eq!(u_1, p.mod_mul(u, m).mod_sub(q.mod_mul(u_0, m), m));
+            }
+        }
+        // U_{2k} = U_k V_k
+        if let Some(k_2) = k.checked_mul(T::TWO) {
+            assert_eq!(k_2.lucas_sequence_mod(p, q, m).0, u.mod_mul(v, m));
+        }
+    });
+
+    unsigned_gen::<T>().test_properties(|k| {
+        assert_eq!(
+            k.lucas_sequence_mod(T::ONE, T::ONE, T::ONE),
+            (T::ZERO, T::ZERO)
+        );
+    });
+}
+
+#[test]
+fn lucas_sequence_mod_properties() {
+    apply_fn_to_unsigneds!(lucas_sequence_mod_properties_helper);
+}
@@ -0,0 +1,77 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::test_util::generators::{signed_gen, signed_pair_gen_var_13};
+use std::panic::catch_unwind;
+
+fn mod_centered_helper<T: PrimitiveSigned>() {
+    let test = |n: T, m: T, out: T| {
+        assert_eq!(n.mod_centered(m), out);
+
+        let mut n = n;
+        n.mod_centered_assign(m);
+        assert_eq!(n, out);
+    };
+    test(T::ZERO, T::ONE, T::ZERO);
+    test(T::exact_from(5), T::ONE, T::ZERO);
+    test(T::ONE, T::TWO, T::ONE);
+    test(T::NEGATIVE_ONE, T::TWO, T::ONE);
+    test(T::exact_from(7), T::exact_from(10), T::exact_from(-3));
+    test(T::exact_from(5), T::exact_from(10), T::exact_from(5));
+    test(T::exact_from(-5), T::exact_from(10), T::exact_from(5));
+    test(T::exact_from(-6), T::exact_from(10), T::exact_from(4));
+    test(T::exact_from(13), T::exact_from(10), T::exact_from(3));
+    test(T::exact_from(4), T::exact_from(9), T::exact_from(4));
+    test(T::exact_from(5), T::exact_from(9), T::exact_from(-4));
+    test(T::MIN, T::MAX, T::NEGATIVE_ONE);
+    test(T::MAX, T::MAX, T::ZERO);
+    test(T::MIN + T::ONE, T::MAX, T::ZERO);
+    test(T::exact_from(-50), T::MAX, T::exact_from(-50));
+}
+
+#[test]
+fn test_mod_centered() {
+    apply_fn_to_signeds!(mod_centered_helper);
+}
+
+fn mod_centered_fail_helper<T: PrimitiveSigned>() {
+    assert_panic!(T::ONE.mod_centered(T::ZERO));
+    assert_panic!(T::ONE.mod_centered(T::NEGATIVE_ONE));
+    assert_panic!({
+        let mut n = T::ONE;
+        n.mod_centered_assign(T::ZERO);
+    });
+}
+
+#[test]
+fn mod_centered_fail() {
+    apply_fn_to_signeds!(mod_centered_fail_helper);
+}
+
+fn mod_centered_properties_helper<T: PrimitiveSigned>() {
+    signed_pair_gen_var_13::<T>().test_properties(|(n, m)| {
+        let r = n.mod_centered(m);
+
+        let mut n_alt = n;
+        n_alt.mod_centered_assign(m);
+        assert_eq!(n_alt, r);
+
+        assert!(r <= m >> 1);
+        let half: T = m >> 1;
+        assert!(r > -half || m.odd() && r == -half);
+        assert!(r.checked_sub(n).map_or(true, |d| d.divisible_by(m)));
+        assert_eq!(r.mod_centered(m), r);
+        assert_eq!(n.mod_op(m), r.mod_op(m));
+    });
+
+    signed_gen::<T>().test_properties(|n| {
+        assert_eq!(n.mod_centered(T::ONE), T::ZERO);
+        assert_eq!(
+            n.mod_centered(T::TWO),
+            if n.even() { T::ZERO } else { T::ONE }
+        );
+    });
+}
+
+#[test]
+fn mod_centered_properties() {
+    apply_fn_to_signeds!(mod_centered_properties_helper);
+}
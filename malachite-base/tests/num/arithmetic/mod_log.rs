@@ -0,0 +1,137 @@
+use malachite_base::num::arithmetic::mod_log::{mod_log_baby_step_giant_step, mod_log_pollard_rho};
+use malachite_base::num::arithmetic::traits::{ModLog, ModPow};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_triple_gen_var_12};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mod_log() {
+    fn test<T: PrimitiveUnsigned>(x: T, base: T, m: T, out: Option<T>) {
+        assert_eq!(x.mod_log(base, m), out);
+    }
+    test::<u8>(0, 0, 1, Some(0));
+    test::<u8>(1, 0, 2, Some(0));
+    test::<u8>(0, 0, 2, Some(1));
+    test::<u8>(1, 1, 2, Some(0));
+    test::<u8>(0, 1, 2, None);
+    test::<u8>(1, 3, 7, Some(0));
+    test::<u8>(3, 3, 7, Some(1));
+    test::<u8>(2, 3, 7, Some(2));
+    test::<u8>(3, 2, 7, None);
+    test::<u8>(4, 2, 12, Some(2));
+    test::<u8>(8, 2, 12, Some(3));
+    test::<u8>(2, 2, 12, Some(1));
+    test::<u8>(3, 2, 12, None);
+    test::<u8>(3, 2, 8, None);
+    test::<u8>(0, 2, 8, Some(3));
+    test::<u8>(0, 6, 36, Some(2));
+    test::<u8>(5, 3, 8, None);
+    test::<u8>(3, 3, 8, Some(1));
+    test::<u16>(10, 2, 13, Some(10));
+    test::<u16>(11, 3, 256, Some(39));
+    test::<u16>(7, 3, 256, None);
+    test::<u32>(12345, 5, 1000000007, Some(775972150));
+    // The order of 2 has a prime factor with more than 32 bits, so Pollard's rho algorithm is used
+    test::<u64>(
+        123456789,
+        2,
+        18446744073709551557,
+        Some(13588326508526149906),
+    );
+    test::<u128>(
+        123456789,
+        2,
+        18446744073709551557,
+        Some(13588326508526149906),
+    );
+}
+
+fn mod_log_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_log(T::ZERO, T::ZERO));
+    assert_panic!(T::ONE.mod_log(T::ZERO, T::ONE));
+    assert_panic!(T::ZERO.mod_log(T::ONE, T::ONE));
+    assert_panic!(T::exact_from(7).mod_log(T::TWO, T::exact_from(7)));
+}
+
+#[test]
+fn mod_log_fail() {
+    apply_fn_to_unsigneds!(mod_log_fail_helper);
+}
+
+#[test]
+fn test_mod_log_baby_step_giant_step_and_pollard_rho() {
+    fn test<T: PrimitiveUnsigned>(gamma: T, beta: T, q: T, m: T, out: T) {
+        assert_eq!(mod_log_baby_step_giant_step(gamma, beta, q, m), Some(out));
+        assert_eq!(mod_log_pollard_rho(gamma, beta, q, m), out);
+    }
+    // 2 has order 11 modulo 23
+    test::<u8>(2, 4, 11, 23, 2);
+    test::<u8>(2, 13, 11, 23, 7);
+    // 4 has order 5 modulo 11
+    test::<u8>(4, 9, 5, 11, 3);
+    // 4 has order 500000003 modulo 1000000007
+    test::<u32>(4, 16, 500000003, 1000000007, 2);
+    test::<u64>(4, 554030327, 500000003, 1000000007, 123456789);
+
+    // 2 is not a power of 4 modulo 11
+    assert_eq!(mod_log_baby_step_giant_step::<u8>(4, 2, 5, 11), None);
+}
+
+fn mod_log_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_triple_gen_var_12::<T>().test_properties_with_limit(SMALL_LIMIT, |(base, x, m)| {
+        let power = base.mod_pow(x.wrapping_into(), m);
+        let log = power.mod_log(base, m).unwrap();
+        assert!(log <= x);
+        assert_eq!(base.mod_pow(log.wrapping_into(), m), power);
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        assert_eq!((T::ONE % m).mod_log(T::ZERO, m), Some(T::ZERO));
+        if m != T::ONE {
+            assert_eq!(T::ONE.mod_log(T::ONE, m), Some(T::ZERO));
+            assert_eq!(T::ZERO.mod_log(T::ONE, m), None);
+        }
+    });
+}
+
+#[test]
+fn mod_log_properties() {
+    // Moduli with more than 32 bits may have subgroups whose prime orders are too large to handle
+    // quickly in debug mode, so only moduli that fit in a u32 are tested.
+    mod_log_properties_helper::<u8>();
+    mod_log_properties_helper::<u16>();
+    mod_log_properties_helper::<u32>();
+
+    unsigned_triple_gen_var_12::<u32>().test_properties_with_limit(SMALL_LIMIT, |(base, x, m)| {
+        let power = base.mod_pow(u64::from(x), m);
+        let log = power.mod_log(base, m);
+        assert_eq!(
+            u64::from(power).mod_log(u64::from(base), u64::from(m)),
+            log.map(u64::from)
+        );
+        assert_eq!(
+            u128::from(power).mod_log(u128::from(base), u128::from(m)),
+            log.map(u128::from)
+        );
+    });
+
+    // Compare with the position of x in the cycle of powers of base
+    for m in 1..100u32 {
+        for base in 0..m {
+            let mut powers = Vec::new();
+            let mut p = 1 % m;
+            while !powers.contains(&p) {
+                powers.push(p);
+                p = p * base % m;
+            }
+            for x in 0..m {
+                assert_eq!(
+                    x.mod_log(base, m),
+                    powers.iter().position(|&y| y == x).map(u32::exact_from)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use malachite_base::num::arithmetic::mod_sqrt::{mod_sqrt_cipolla, mod_sqrt_tonelli_shanks};
+use malachite_base::num::arithmetic::traits::{ModSqrt, ModSquare};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_16};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mod_sqrt() {
+    fn test<T: PrimitiveUnsigned>(x: T, m: T, out: Option<T>, outs: &[T]) {
+        assert_eq!(x.mod_sqrt(m), out);
+        assert_eq!(x.mod_sqrts(m), outs);
+        let factors = m.factor();
+        assert_eq!(x.mod_sqrt_from_factors(&factors), out);
+        assert_eq!(x.mod_sqrts_from_factors(&factors), outs);
+    }
+    test::<u8>(0, 1, Some(0), &[0]);
+    test::<u8>(0, 2, Some(0), &[0]);
+    test::<u8>(1, 2, Some(1), &[1]);
+    test::<u8>(0, 7, Some(0), &[0]);
+    test::<u8>(2, 7, Some(3), &[3, 4]);
+    test::<u8>(3, 7, None, &[]);
+    test::<u8>(10, 13, Some(6), &[6, 7]);
+    test::<u8>(5, 11, Some(4), &[4, 7]);
+    test::<u8>(3, 4, None, &[]);
+    test::<u8>(1, 4, Some(1), &[1, 3]);
+    test::<u8>(4, 8, Some(2), &[2, 6]);
+    test::<u8>(5, 8, None, &[]);
+    test::<u8>(0, 16, Some(0), &[0, 4, 8, 12]);
+    test::<u8>(8, 16, None, &[]);
+    test::<u8>(9, 27, Some(3), &[3, 6, 12, 15, 21, 24]);
+    test::<u8>(1, 255, Some(1), &[1, 16, 86, 101, 154, 169, 239, 254]);
+    test::<u16>(4, 15, Some(7), &[2, 7, 8, 13]);
+    test::<u32>(17, 1 << 20, Some(206569), &[206569, 317719, 730857, 842007]);
+    test::<u32>(3, 1000000007, Some(82062379), &[82062379, 917937628]);
+    test::<u64>(2, 18446744073709551557, None, &[]);
+    // p - 1 = 2^32 * (2^32 - 1), so Cipolla's algorithm is used
+    test::<u64>(
+        3,
+        18446744069414584321,
+        Some(281474976579584),
+        &[281474976579584, 18446462594438004737],
+    );
+    test::<u64>(7, 18446744069414584321, None, &[]);
+    test::<u64>(
+        10,
+        2305843009213693951,
+        Some(156831321985921556),
+        &[156831321985921556, 2149011687227772395],
+    );
+    test::<u128>(
+        2,
+        170141183460469231731687303715884105727,
+        Some(18446744073709551616),
+        &[18446744073709551616, 170141183460469231713240559642174554111],
+    );
+}
+
+fn mod_sqrt_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_sqrt(T::ZERO));
+    assert_panic!(T::ONE.mod_sqrt(T::ONE));
+    assert_panic!(T::exact_from(7).mod_sqrt(T::exact_from(7)));
+    assert_panic!(T::ZERO.mod_sqrts(T::ZERO));
+    assert_panic!(T::exact_from(7).mod_sqrts(T::exact_from(7)));
+    assert_panic!(T::ONE.mod_sqrt_from_factors(&[]));
+    assert_panic!(T::exact_from(7).mod_sqrt_from_factors(&[(T::exact_from(7), 1)]));
+    assert_panic!(T::ONE.mod_sqrts_from_factors(&[]));
+    assert_panic!(T::exact_from(9).mod_sqrts_from_factors(&[(T::exact_from(3), 2)]));
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    apply_fn_to_unsigneds!(mod_sqrt_fail_helper);
+}
+
+#[test]
+fn test_mod_sqrt_tonelli_shanks_and_cipolla() {
+    fn test<T: PrimitiveUnsigned>(x: T, p: T, out: T) {
+        let root = mod_sqrt_tonelli_shanks(x, p);
+        assert!(root == out || root == p - out);
+        let root = mod_sqrt_cipolla(x, p);
+        assert!(root == out || root == p - out);
+    }
+    test::<u8>(1, 3, 1);
+    test::<u8>(2, 7, 3);
+    test::<u8>(4, 5, 2);
+    test::<u8>(10, 13, 6);
+    test::<u8>(2, 17, 6);
+    test::<u8>(3, 193, 14);
+    test::<u32>(3, 1000000007, 82062379);
+    test::<u64>(3, 18446744069414584321, 281474976579584);
+    test::<u128>(
+        2,
+        170141183460469231731687303715884105727,
+        18446744073709551616,
+    );
+}
+
+fn mod_sqrt_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_16::<T>().test_properties_with_limit(SMALL_LIMIT, |(x, m)| {
+        let factors = m.factor();
+        let square = x.mod_square(m);
+        let root = square.mod_sqrt(m).unwrap();
+        assert!(root < m);
+        assert_eq!(root.mod_square(m), square);
+        assert_eq!(square.mod_sqrt_from_factors(&factors), Some(root));
+
+        if let Some(root) = x.mod_sqrt(m) {
+            assert!(root < m);
+            assert_eq!(root.mod_square(m), x);
+        }
+        assert_eq!(x.mod_sqrt_from_factors(&factors), x.mod_sqrt(m));
+
+        // A squarefree modulus with k prime factors has at most 2^k roots, so listing them
+        // is cheap
+        if factors.iter().all(|&(_, e)| e == 1) {
+            let roots = square.mod_sqrts(m);
+            assert!(roots.contains(&x));
+            assert!(roots.contains(&root));
+            assert!(roots.windows(2).all(|w| w[0] < w[1]));
+            for &r in &roots {
+                assert_eq!(r.mod_square(m), square);
+                assert!(roots.contains(&r.mod_neg(m)));
+            }
+            assert_eq!(square.mod_sqrts_from_factors(&factors), roots);
+            if factors.len() == 1 && m != T::TWO {
+                assert_eq!(roots.len(), if square == T::ZERO { 1 } else { 2 });
+                assert_eq!(roots[0], root);
+            }
+            let roots = x.mod_sqrts(m);
+            assert_eq!(roots.is_empty(), x.mod_sqrt(m).is_none());
+        }
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        assert_eq!(T::ZERO.mod_sqrt(m), Some(T::ZERO));
+        if m != T::ONE {
+            assert_eq!(T::ONE.mod_sqrt(m), Some(T::ONE));
+        }
+    });
+}
+
+fn mod_sqrt_odd_prime_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_16::<T>().test_properties_with_limit(SMALL_LIMIT, |(x, m)| {
+        let p = if let Some(p) = m.checked_next_prime() {
+            p
+        } else {
+            return;
+        };
+        if p == T::TWO || x == T::ZERO {
+            return;
+        }
+        let square = x.mod_square(p);
+        let root = mod_sqrt_tonelli_shanks(square, p);
+        assert!(root == x || root == p - x);
+        let root = mod_sqrt_cipolla(square, p);
+        assert!(root == x || root == p - x);
+        assert_eq!(square.mod_sqrt_from_factors(&[(p, 1)]), Some(x.min(p - x)));
+    });
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    mod_sqrt_properties_helper::<u8>();
+    mod_sqrt_properties_helper::<u16>();
+    mod_sqrt_properties_helper::<u32>();
+    mod_sqrt_properties_helper::<u64>();
+    mod_sqrt_properties_helper::<usize>();
+    apply_fn_to_unsigneds!(mod_sqrt_odd_prime_properties_helper);
+
+    // Random u128s may have two large prime factors, which are too slow to find here, so moduli
+    // that fit in a u64 are tested instead.
+    unsigned_pair_gen_var_16::<u64>().test_properties(|(x, m)| {
+        let x = u128::from(x);
+        let m = u128::from(m);
+        let square = x.mod_square(m);
+        let root = square.mod_sqrt(m).unwrap();
+        assert_eq!(root.mod_square(m), square);
+        assert_eq!(
+            u128::from(
+                u64::exact_from(square)
+                    .mod_sqrt(u64::exact_from(m))
+                    .unwrap()
+            ),
+            root
+        );
+    });
+
+    // Compare with the roots found by squaring every residue
+    for m in 1..200u32 {
+        for x in 0..m {
+            let expected = (0..m).filter(|&r| r * r % m == x).collect::<Vec<_>>();
+            assert_eq!(x.mod_sqrts(m), expected);
+            match x.mod_sqrt(m) {
+                None => assert!(expected.is_empty()),
+                Some(r) => assert!(expected.contains(&r)),
+            }
+        }
+    }
+}
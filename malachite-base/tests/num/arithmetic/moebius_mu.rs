@@ -0,0 +1,98 @@
+use malachite_base::num::arithmetic::moebius_mu::moebius_mu_table;
+use malachite_base::num::arithmetic::traits::MoebiusMu;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+
+#[test]
+fn test_moebius_mu() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: i8) {
+        assert_eq!(n.moebius_mu(), out);
+        assert_eq!(T::moebius_mu_from_factors(&n.factor()), out);
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, -1);
+    test::<u8>(4, 0);
+    test::<u8>(6, 1);
+    test::<u8>(30, -1);
+    test::<u8>(u8::MAX, -1);
+    test::<u16>(65535, 1);
+    test::<u32>(u32::MAX, -1);
+    test::<u64>(1 << 63, 0);
+    test::<u64>(u64::MAX, -1);
+    test::<u128>(u128::MAX, -1);
+    test::<usize>(1001, -1);
+}
+
+#[test]
+fn test_moebius_mu_from_factors() {
+    fn test<T: PrimitiveUnsigned>(factors: &[(T, u64)], out: i8) {
+        assert_eq!(T::moebius_mu_from_factors(factors), out);
+    }
+    test::<u8>(&[], 1);
+    test::<u8>(&[(2, 1), (3, 1)], 1);
+    test::<u8>(&[(2, 1), (3, 2)], 0);
+    test::<u64>(&[(3, 1), (5, 1), (17, 1)], -1);
+}
+
+#[test]
+#[should_panic]
+fn moebius_mu_fail() {
+    0u32.moebius_mu();
+}
+
+#[test]
+fn test_moebius_mu_table() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: &[i8]) {
+        assert_eq!(moebius_mu_table(n), out);
+    }
+    test::<u8>(0, &[0]);
+    test::<u8>(1, &[0, 1]);
+    test::<u16>(12, &[0, 1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0]);
+}
+
+fn moebius_mu_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let mu = n.moebius_mu();
+        assert_eq!(T::moebius_mu_from_factors(&n.factor()), mu);
+        assert!(mu == -1 || mu == 0 || mu == 1);
+        if n.is_prime() {
+            assert_eq!(mu, -1);
+        }
+        if let Some(square) = n.checked_square() {
+            assert_eq!(square.moebius_mu(), i8::from(n == T::ONE));
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties_with_limit(SMALL_LIMIT, |(a, b)| {
+        if a == T::ZERO || b == T::ZERO || !a.coprime_with(b) {
+            return;
+        }
+        if let Some(n) = a.checked_mul(b) {
+            assert_eq!(n.moebius_mu(), a.moebius_mu() * b.moebius_mu());
+        }
+    });
+}
+
+#[test]
+fn moebius_mu_properties() {
+    moebius_mu_properties_helper::<u8>();
+    moebius_mu_properties_helper::<u16>();
+    moebius_mu_properties_helper::<u32>();
+    moebius_mu_properties_helper::<u64>();
+    moebius_mu_properties_helper::<usize>();
+
+    let mus = moebius_mu_table(10000u32);
+    for (n, &mu) in mus.iter().enumerate().skip(1) {
+        assert_eq!(u32::exact_from(n).moebius_mu(), mu);
+    }
+    // The sum of mu(d) over the divisors d of n is 0, unless n is 1.
+    for n in 1..=1000 {
+        let sum: i32 = (1..=n)
+            .filter(|d| n % d == 0)
+            .map(|d| i32::from(mus[d]))
+            .sum();
+        assert_eq!(sum, i32::from(n == 1));
+    }
+}
@@ -0,0 +1,119 @@
+use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_16};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_multiplicative_order() {
+    fn test<T: PrimitiveUnsigned>(a: T, m: T, out: Option<T>) {
+        assert_eq!(a.multiplicative_order(m), out);
+        assert_eq!(a.multiplicative_order_from_factors(&m.factor()), out);
+    }
+    test::<u8>(0, 1, Some(1));
+    test::<u8>(1, 2, Some(1));
+    test::<u8>(0, 2, None);
+    test::<u8>(1, 7, Some(1));
+    test::<u8>(2, 7, Some(3));
+    test::<u8>(3, 7, Some(6));
+    test::<u8>(6, 7, Some(2));
+    test::<u8>(6, 9, None);
+    test::<u8>(3, 8, Some(2));
+    test::<u8>(3, 16, Some(4));
+    test::<u8>(5, 128, Some(32));
+    test::<u16>(10, 21, Some(6));
+    test::<u16>(2, 65535, Some(16));
+    test::<u32>(5, 4294967291, Some(2147483645));
+    test::<u32>(2, 1000000007, Some(500000003));
+    test::<u32>(3, 998244353, Some(998244352));
+    test::<u64>(2, 18446744073709551557, Some(18446744073709551556));
+    test::<u64>(3, 1 << 63, Some(1 << 61));
+    test::<u128>(3, 1 << 127, Some(1 << 125));
+    test::<u128>(
+        3,
+        170141183460469231731687303715884105727,
+        Some(56713727820156410577229101238628035242),
+    );
+}
+
+#[test]
+fn test_multiplicative_order_from_factors() {
+    fn test<T: PrimitiveUnsigned>(a: T, factors: &[(T, u64)], out: Option<T>) {
+        assert_eq!(a.multiplicative_order_from_factors(factors), out);
+    }
+    test::<u8>(0, &[], Some(1));
+    test::<u16>(10, &[(3, 1), (7, 1)], Some(6));
+    test::<u16>(10, &[(7, 1), (3, 1)], Some(6));
+    test::<u16>(15, &[(3, 1), (7, 1)], None);
+    test::<u32>(3, &[(998244353, 1)], Some(998244352));
+}
+
+fn multiplicative_order_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.multiplicative_order(T::ZERO));
+    assert_panic!(T::ONE.multiplicative_order(T::ONE));
+    assert_panic!(T::exact_from(7).multiplicative_order(T::exact_from(7)));
+    assert_panic!(T::ONE.multiplicative_order_from_factors(&[]));
+    assert_panic!(T::exact_from(9).multiplicative_order_from_factors(&[(T::exact_from(3), 2)]));
+}
+
+#[test]
+fn multiplicative_order_fail() {
+    apply_fn_to_unsigneds!(multiplicative_order_fail_helper);
+}
+
+fn multiplicative_order_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_16::<T>().test_properties_with_limit(SMALL_LIMIT, |(a, m)| {
+        let factors = m.factor();
+        let order = a.multiplicative_order(m);
+        assert_eq!(a.multiplicative_order_from_factors(&factors), order);
+        assert_eq!(order.is_some(), a.coprime_with(m));
+        if let Some(order) = order {
+            assert_ne!(order, T::ZERO);
+            assert!(T::carmichael_lambda_from_factors(&factors).divisible_by(order));
+            assert_eq!(a.mod_pow(order.exact_into(), m), T::ONE % m);
+            for (q, _) in order.factor() {
+                assert_ne!(a.mod_pow((order / q).exact_into(), m), T::ONE % m);
+            }
+        }
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|m| {
+        assert_eq!((T::ONE % m).multiplicative_order(m), Some(T::ONE));
+        if m > T::TWO {
+            assert_eq!((m - T::ONE).multiplicative_order(m), Some(T::TWO));
+        }
+    });
+}
+
+#[test]
+fn multiplicative_order_properties() {
+    multiplicative_order_properties_helper::<u8>();
+    multiplicative_order_properties_helper::<u16>();
+    multiplicative_order_properties_helper::<u32>();
+    multiplicative_order_properties_helper::<u64>();
+
+    // Random u128s may have two large prime factors, which are too slow to find here, so moduli
+    // that fit in a u64 are tested instead.
+    unsigned_pair_gen_var_16::<u64>().test_properties_with_limit(SMALL_LIMIT, |(a, m)| {
+        assert_eq!(
+            u128::from(a).multiplicative_order(u128::from(m)),
+            a.multiplicative_order(m).map(u128::from)
+        );
+    });
+
+    // Step through the powers of a until 1 appears, giving up after m steps
+    for m in 1..200u32 {
+        for a in 0..m {
+            let mut expected = None;
+            let mut power = a % m;
+            for k in 1..=m {
+                if power == 1 % m {
+                    expected = Some(k);
+                    break;
+                }
+                power = power * a % m;
+            }
+            assert_eq!(a.multiplicative_order(m), expected);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use malachite_base::num::arithmetic::traits::{Parity, PerfectPowerDecompose};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    signed_gen, signed_unsigned_pair_gen_var_18, unsigned_gen, unsigned_pair_gen_var_32,
+};
+
+#[test]
+fn test_perfect_power_decompose_unsigned() {
+    fn test<T: PrimitiveUnsigned>(n: T, root: T, exp: u64) {
+        assert_eq!(n.perfect_power_decompose(), (root, exp));
+        assert_eq!(n.is_perfect_power(), exp > 1 || n <= T::ONE);
+    }
+    test::<u8>(0, 0, 1);
+    test::<u8>(1, 1, 1);
+    test::<u8>(2, 2, 1);
+    test::<u8>(4, 2, 2);
+    test::<u8>(8, 2, 3);
+    test::<u8>(12, 12, 1);
+    test::<u8>(36, 6, 2);
+    test::<u8>(64, 2, 6);
+    test::<u8>(128, 2, 7);
+    test::<u8>(243, 3, 5);
+    test::<u8>(u8::MAX, u8::MAX, 1);
+    test::<u16>(1024, 2, 10);
+    test::<u16>(15625, 5, 6);
+    test::<u32>(1000000, 10, 6);
+    test::<u32>(1 << 31, 2, 31);
+    test::<u32>(3 << 30, 3 << 30, 1);
+    test::<u64>(3486784401, 3, 20);
+    test::<u64>(4294967297, 4294967297, 1);
+    test::<u64>(18446744030759878681, 4294967291, 2);
+    test::<u64>(u64::MAX, u64::MAX, 1);
+    test::<u128>(1 << 127, 2, 127);
+    test::<u128>(324518553658426726783156020576256, 2, 108);
+    test::<u128>(3u128.pow(80), 3, 80);
+    test::<u128>(1853020188851841, 3, 32);
+    test::<u128>(1853020188851843, 1853020188851843, 1);
+    test::<usize>(1000, 10, 3);
+}
+
+#[test]
+fn test_perfect_power_decompose_signed() {
+    fn test<T: PrimitiveSigned>(n: T, root: T, exp: u64) {
+        assert_eq!(n.perfect_power_decompose(), (root, exp));
+        assert_eq!(
+            n.is_perfect_power(),
+            exp > 1 || n == T::ZERO || n == T::ONE || n == T::NEGATIVE_ONE
+        );
+    }
+    test::<i8>(0, 0, 1);
+    test::<i8>(1, 1, 1);
+    test::<i8>(-1, -1, 1);
+    test::<i8>(4, 2, 2);
+    test::<i8>(-4, -4, 1);
+    test::<i8>(-8, -2, 3);
+    test::<i8>(64, 2, 6);
+    test::<i8>(-64, -4, 3);
+    test::<i8>(-27, -3, 3);
+    test::<i8>(i8::MIN, -2, 7);
+    test::<i8>(i8::MAX, i8::MAX, 1);
+    test::<i16>(-16, -16, 1);
+    test::<i16>(-243, -3, 5);
+    test::<i16>(i16::MIN, -2, 15);
+    test::<i32>(-1000000, -100, 3);
+    test::<i32>(1000000, 10, 6);
+    test::<i64>(i64::MIN, -2, 63);
+    test::<i64>(-3486784401, -81, 5);
+    test::<i64>(-4294967296, -4294967296, 1);
+    test::<i64>(3486784401, 3, 20);
+    test::<i128>(i128::MIN, -2, 127);
+    test::<i128>(-(1 << 126), -4, 63);
+    test::<i128>(-(1 << 64), -(1 << 64), 1);
+    test::<isize>(-125, -5, 3);
+}
+
+fn perfect_power_decompose_naive(n: u64) -> (u64, u64) {
+    if n <= 1 {
+        return (n, 1);
+    }
+    for b in 2.. {
+        let mut power = b;
+        let mut exp = 1;
+        while power < n {
+            power *= b;
+            exp += 1;
+        }
+        if power == n {
+            return (b, exp);
+        }
+    }
+    unreachable!()
+}
+
+fn perfect_power_decompose_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let (root, exp) = n.perfect_power_decompose();
+        assert_ne!(exp, 0);
+        assert_eq!(root.pow(exp), n);
+        assert_eq!(n.is_perfect_power(), exp > 1 || n <= T::ONE);
+        if n > T::ONE {
+            assert_eq!(root.perfect_power_decompose(), (root, 1));
+            assert_eq!(root.is_perfect_power(), false);
+        }
+    });
+
+    unsigned_pair_gen_var_32::<T, u64>().test_properties(|(b, exp)| {
+        if let Some(n) = b.checked_pow(exp) {
+            if b > T::ONE {
+                assert_eq!(n.perfect_power_decompose().1 % exp, 0);
+                if exp > 1 {
+                    assert!(n.is_perfect_power());
+                }
+            }
+        }
+    });
+}
+
+fn perfect_power_decompose_properties_helper_signed<T: PrimitiveSigned>() {
+    signed_gen::<T>().test_properties(|n| {
+        let (root, exp) = n.perfect_power_decompose();
+        assert_ne!(exp, 0);
+        assert_eq!(root.pow(exp), n);
+        if n < T::ZERO {
+            assert!(exp.odd());
+        }
+        let trivial = n == T::ZERO || n == T::ONE || n == T::NEGATIVE_ONE;
+        assert_eq!(n.is_perfect_power(), exp > 1 || trivial);
+        if !trivial {
+            assert_eq!(root.is_perfect_power(), false);
+        }
+        assert_eq!(root.sign(), n.sign());
+    });
+
+    signed_unsigned_pair_gen_var_18::<T, u64>().test_properties(|(b, exp)| {
+        if let Some(n) = b.checked_pow(exp) {
+            if b > T::ONE || b < T::NEGATIVE_ONE {
+                assert_eq!(n.perfect_power_decompose().1 % exp, 0);
+                if exp > 1 {
+                    assert!(n.is_perfect_power());
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn perfect_power_decompose_properties() {
+    apply_fn_to_unsigneds!(perfect_power_decompose_properties_helper_unsigned);
+    apply_fn_to_signeds!(perfect_power_decompose_properties_helper_signed);
+
+    for n in 0..=u16::MAX {
+        assert_eq!(
+            u64::from(n).perfect_power_decompose(),
+            perfect_power_decompose_naive(u64::from(n))
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use malachite_base::num::arithmetic::traits::{MultiplicativeOrder, PrimitiveRoot, Totient};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+
+#[test]
+fn test_primitive_root() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: Option<T>) {
+        assert_eq!(n.primitive_root(), out);
+        assert_eq!(T::primitive_root_from_factors(&n.factor()), out);
+    }
+    test::<u8>(1, Some(0));
+    test::<u8>(2, Some(1));
+    test::<u8>(3, Some(2));
+    test::<u8>(4, Some(3));
+    test::<u8>(7, Some(3));
+    test::<u8>(8, None);
+    test::<u8>(9, Some(2));
+    test::<u8>(10, Some(3));
+    test::<u8>(12, None);
+    test::<u8>(15, None);
+    test::<u8>(18, Some(5));
+    test::<u8>(23, Some(5));
+    test::<u8>(41, Some(6));
+    test::<u16>(65535, None);
+    test::<u32>(65537, Some(3));
+    test::<u32>(998244353, Some(3));
+    test::<u32>(1000000007, Some(5));
+    test::<u64>(2305843009213693951, Some(37));
+    test::<u64>(18446744073709551557, Some(2));
+    test::<u64>(1 << 63, None);
+    test::<u128>(170141183460469231731687303715884105727, Some(43));
+}
+
+#[test]
+fn test_primitive_root_from_factors() {
+    fn test<T: PrimitiveUnsigned>(factors: &[(T, u64)], out: Option<T>) {
+        assert_eq!(T::primitive_root_from_factors(factors), out);
+    }
+    test::<u8>(&[], Some(0));
+    test::<u8>(&[(2, 1), (3, 2)], Some(5));
+    test::<u8>(&[(3, 2), (2, 1)], Some(5));
+    test::<u8>(&[(3, 1), (5, 1)], None);
+    test::<u8>(&[(2, 2), (3, 1)], None);
+    test::<u32>(&[(1000000007, 1)], Some(5));
+}
+
+#[test]
+#[should_panic]
+fn primitive_root_fail() {
+    0u32.primitive_root();
+}
+
+fn primitive_root_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let factors = n.factor();
+        let root = n.primitive_root();
+        assert_eq!(T::primitive_root_from_factors(&factors), root);
+        let odd_factor_count = factors.iter().filter(|&&(p, _)| p != T::TWO).count();
+        let two_exponent = factors
+            .iter()
+            .find(|&&(p, _)| p == T::TWO)
+            .map_or(0, |&(_, e)| e);
+        assert_eq!(
+            root.is_some(),
+            odd_factor_count == 0 && two_exponent <= 2
+                || odd_factor_count == 1 && two_exponent <= 1
+        );
+        if let Some(root) = root {
+            assert!(root < n);
+            let totient = n.totient();
+            assert_eq!(root.multiplicative_order(n), Some(totient));
+            let mut g = T::ONE;
+            while g < root {
+                if g.coprime_with(n) {
+                    assert_ne!(g.multiplicative_order(n), Some(totient));
+                }
+                g += T::ONE;
+            }
+        }
+    });
+}
+
+#[test]
+fn primitive_root_properties() {
+    primitive_root_properties_helper::<u8>();
+    primitive_root_properties_helper::<u16>();
+    primitive_root_properties_helper::<u32>();
+    primitive_root_properties_helper::<u64>();
+
+    // Random u128s may have two large prime factors, which are too slow to find here, so numbers
+    // that fit in a u64 are tested instead.
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        assert_eq!(
+            u128::from(n).primitive_root(),
+            n.primitive_root().map(u128::from)
+        );
+    });
+
+    // The smallest residue whose order is the totient, if there is one
+    for n in 1..200u32 {
+        let totient = n.totient();
+        let expected = (0..n).find(|&g| g.multiplicative_order(n) == Some(totient));
+        assert_eq!(n.primitive_root(), expected);
+    }
+}
@@ -0,0 +1,107 @@
+use malachite_base::num::arithmetic::totient::totient_table;
+use malachite_base::num::arithmetic::traits::{CoprimeWith, Totient};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_27};
+
+#[test]
+fn test_totient() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.totient(), out);
+        assert_eq!(T::totient_from_factors(&n.factor()), out);
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(3, 2);
+    test::<u8>(4, 2);
+    test::<u8>(12, 4);
+    test::<u8>(97, 96);
+    test::<u8>(128, 64);
+    test::<u8>(u8::MAX, 128);
+    test::<u16>(65535, 32768);
+    test::<u32>(1000000, 400000);
+    test::<u32>(u32::MAX, 2147483648);
+    test::<u64>(u64::MAX, 9208981628670443520);
+    test::<u64>(1 << 63, 1 << 62);
+    test::<u128>(u128::MAX, 169875107699410294159549716941399654400);
+    test::<usize>(1001, 720);
+}
+
+#[test]
+fn test_totient_from_factors() {
+    fn test<T: PrimitiveUnsigned>(factors: &[(T, u64)], out: T) {
+        assert_eq!(T::totient_from_factors(factors), out);
+    }
+    test::<u8>(&[], 1);
+    test::<u8>(&[(2, 3), (3, 2)], 24);
+    test::<u8>(&[(3, 2), (2, 3)], 24);
+    test::<u64>(&[(3, 1), (1000000007, 1)], 2000000012);
+    test::<u128>(
+        &[(18446744073709551557, 2)],
+        340282366920938461268212062660331572692,
+    );
+}
+
+#[test]
+#[should_panic]
+fn totient_fail() {
+    0u32.totient();
+}
+
+#[test]
+fn test_totient_table() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: &[T]) {
+        assert_eq!(totient_table(n), out);
+    }
+    test::<u8>(0, &[0]);
+    test::<u8>(1, &[0, 1]);
+    test::<u16>(12, &[0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4]);
+    test::<u32>(
+        20,
+        &[0, 1, 1, 2, 2, 4, 2, 6, 4, 6, 4, 10, 4, 12, 6, 8, 8, 16, 6, 18, 8],
+    );
+}
+
+fn totient_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let phi = n.totient();
+        assert_eq!(T::totient_from_factors(&n.factor()), phi);
+        assert_ne!(phi, T::ZERO);
+        assert!(phi <= n);
+        if n > T::TWO {
+            assert!(phi.even());
+        }
+        if n > T::ONE {
+            assert_eq!(phi == n - T::ONE, n.is_prime());
+        }
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties_with_limit(SMALL_LIMIT, |(a, b)| {
+        if a == T::ZERO || b == T::ZERO || !a.coprime_with(b) {
+            return;
+        }
+        if let Some(n) = a.checked_mul(b) {
+            assert_eq!(n.totient(), a.totient() * b.totient());
+        }
+    });
+}
+
+#[test]
+fn totient_properties() {
+    totient_properties_helper::<u8>();
+    totient_properties_helper::<u16>();
+    totient_properties_helper::<u32>();
+    totient_properties_helper::<u64>();
+    totient_properties_helper::<usize>();
+
+    for n in 1..=u8::MAX {
+        assert_eq!(
+            n.totient(),
+            u8::exact_from((1..=n).filter(|&k| k.coprime_with(n)).count())
+        );
+    }
+    for (n, &phi) in totient_table(10000u32).iter().enumerate().skip(1) {
+        assert_eq!(u32::exact_from(n).totient(), phi);
+    }
+}
@@ -0,0 +1,134 @@
+use malachite_base::num::arithmetic::traits::{RemoveFactor, UnsignedAbs, Valuation};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{signed_unsigned_pair_gen, unsigned_pair_gen_var_24};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_valuation_unsigned() {
+    fn test<T: PrimitiveUnsigned>(n: T, p: T, cofactor: T, v: u64) {
+        assert_eq!(n.valuation(p), v);
+        assert_eq!(n.remove_factor(p), (cofactor, v));
+    }
+    test::<u8>(1, 2, 1, 0);
+    test::<u8>(1, 3, 1, 0);
+    test::<u8>(12, 2, 3, 2);
+    test::<u8>(12, 3, 4, 1);
+    test::<u8>(12, 4, 3, 1);
+    test::<u8>(12, 5, 12, 0);
+    test::<u8>(128, 2, 1, 7);
+    test::<u8>(243, 3, 1, 5);
+    test::<u8>(u8::MAX, 5, 51, 1);
+    test::<u16>(1000, 10, 1, 3);
+    test::<u16>(1200, 20, 3, 2);
+    test::<u32>(1000000, 100, 1, 3);
+    test::<u32>(1000000, 1000000, 1, 1);
+    test::<u32>(1000000, 1000001, 1000000, 0);
+    test::<u64>(3486784401, 3, 1, 20);
+    test::<u64>(3486784401, 9, 1, 10);
+    test::<u64>(3486784401, 27, 9, 6);
+    test::<u64>(1 << 63, 2, 1, 63);
+    test::<u64>(u64::MAX, 3, 6148914691236517205, 1);
+    test::<u128>(3u128.pow(80), 3, 1, 80);
+    test::<u128>(3u128.pow(70) << 7, 6, 3u128.pow(63), 7);
+    test::<usize>(1000, 10, 1, 3);
+}
+
+#[test]
+fn test_valuation_signed() {
+    fn test<
+        U: PrimitiveUnsigned,
+        S: PrimitiveSigned
+            + RemoveFactor<U, CofactorOutput = S, ValuationOutput = u64>
+            + UnsignedAbs<Output = U>
+            + Valuation<U, Output = u64>,
+    >(
+        n: S,
+        p: U,
+        cofactor: S,
+        v: u64,
+    ) {
+        assert_eq!(n.valuation(p), v);
+        assert_eq!(n.remove_factor(p), (cofactor, v));
+    }
+    test::<u8, i8>(1, 2, 1, 0);
+    test::<u8, i8>(-1, 2, -1, 0);
+    test::<u8, i8>(12, 2, 3, 2);
+    test::<u8, i8>(-12, 2, -3, 2);
+    test::<u8, i8>(-12, 5, -12, 0);
+    test::<u8, i8>(i8::MIN, 2, -1, 7);
+    test::<u8, i8>(i8::MIN, 4, -2, 3);
+    test::<u8, i8>(i8::MIN, 3, i8::MIN, 0);
+    test::<u8, i8>(i8::MAX, 127, 1, 1);
+    test::<u16, i16>(-1000, 10, -1, 3);
+    test::<u32, i32>(-1000000, 5, -64, 6);
+    test::<u64, i64>(i64::MIN, 2, -1, 63);
+    test::<u64, i64>(-3486784401, 3, -1, 20);
+    test::<u128, i128>(i128::MIN, 8, -2, 42);
+    test::<usize, isize>(-125, 5, -1, 3);
+}
+
+fn valuation_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.valuation(T::TWO));
+    assert_panic!(T::ONE.valuation(T::ZERO));
+    assert_panic!(T::ONE.valuation(T::ONE));
+    assert_panic!(T::ZERO.remove_factor(T::TWO));
+    assert_panic!(T::ONE.remove_factor(T::ZERO));
+    assert_panic!(T::ONE.remove_factor(T::ONE));
+}
+
+#[test]
+fn valuation_fail() {
+    apply_fn_to_unsigneds!(valuation_fail_helper);
+    assert_panic!(0i8.valuation(2u8));
+    assert_panic!((-1i16).valuation(1u16));
+    assert_panic!(0i32.remove_factor(2u32));
+    assert_panic!((-1i64).remove_factor(0u64));
+}
+
+fn valuation_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_24::<T, T>().test_properties(|(n, p)| {
+        let (cofactor, v) = n.remove_factor(p);
+        assert_eq!(n.valuation(p), v);
+        assert_ne!(cofactor, T::ZERO);
+        assert!(!cofactor.divisible_by(p));
+        assert_eq!(
+            p.checked_pow(v).and_then(|q| q.checked_mul(cofactor)),
+            Some(n)
+        );
+        if let Some(np) = n.checked_mul(p) {
+            assert_eq!(np.valuation(p), v + 1);
+        }
+        if p.is_power_of_2() {
+            assert_eq!(v, n.trailing_zeros() / p.trailing_zeros());
+        }
+        assert_eq!(cofactor.remove_factor(p), (cofactor, 0));
+    });
+}
+
+fn valuation_properties_helper_signed<
+    U: PrimitiveUnsigned,
+    S: PrimitiveSigned
+        + RemoveFactor<U, CofactorOutput = S, ValuationOutput = u64>
+        + UnsignedAbs<Output = U>
+        + Valuation<U, Output = u64>,
+>() {
+    signed_unsigned_pair_gen::<S, U>().test_properties(|(n, p)| {
+        if n == S::ZERO || p < U::TWO {
+            return;
+        }
+        let (cofactor, v) = n.remove_factor(p);
+        assert_eq!(n.valuation(p), v);
+        assert_eq!(n.unsigned_abs().remove_factor(p).1, v);
+        if n != S::MIN {
+            assert_eq!(cofactor.sign(), n.sign());
+            assert_eq!((-n).remove_factor(p), (-cofactor, v));
+        }
+    });
+}
+
+#[test]
+fn valuation_properties() {
+    apply_fn_to_unsigneds!(valuation_properties_helper_unsigned);
+    apply_fn_to_unsigned_signed_pairs!(valuation_properties_helper_signed);
+}
@@ -0,0 +1,158 @@
+use itertools::Itertools;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::factor::{ecm_one_curve, pollard_brent_rho};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_27, unsigned_triple_gen_var_19,
+};
+
+fn product<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    factors.iter().fold(T::ONE, |acc, &(p, e)| acc * p.pow(e))
+}
+
+fn merge<T: PrimitiveUnsigned>(xs: &[(T, u64)], ys: &[(T, u64)]) -> Vec<(T, u64)> {
+    let mut merged: Vec<(T, u64)> = Vec::new();
+    for &(p, e) in xs.iter().chain(ys.iter()).sorted() {
+        match merged.last_mut() {
+            Some((q, f)) if *q == p => *f += e,
+            _ => merged.push((p, e)),
+        }
+    }
+    merged
+}
+
+#[test]
+fn test_factor() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: &[(T, u64)]) {
+        assert_eq!(n.factor(), out);
+    }
+    test::<u8>(1, &[]);
+    test::<u8>(2, &[(2, 1)]);
+    test::<u8>(12, &[(2, 2), (3, 1)]);
+    test::<u8>(128, &[(2, 7)]);
+    test::<u8>(251, &[(251, 1)]);
+    test::<u8>(u8::MAX, &[(3, 1), (5, 1), (17, 1)]);
+
+    test::<u16>(1024, &[(2, 10)]);
+    test::<u16>(65521, &[(65521, 1)]);
+    test::<u16>(u16::MAX, &[(3, 1), (5, 1), (17, 1), (257, 1)]);
+
+    test::<u32>(1018081, &[(1009, 2)]);
+    test::<u32>(1022117, &[(1009, 1), (1013, 1)]);
+    test::<u32>(1057058, &[(2, 1), (727, 2)]);
+    test::<u32>(3215031751, &[(151, 1), (751, 1), (28351, 1)]);
+    test::<u32>(4294967291, &[(4294967291, 1)]);
+    test::<u32>(u32::MAX, &[(3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)]);
+
+    test::<u64>(
+        3825123056546413051,
+        &[(149491, 1), (747451, 1), (34233211, 1)],
+    );
+    // 65537^3
+    test::<u64>(281487861809153, &[(65537, 3)]);
+    test::<u64>(4611686014132420609, &[(2147483647, 2)]);
+    test::<u64>(18446744030759878681, &[(4294967291, 2)]);
+    test::<u64>(18446743979220271189, &[(4294967279, 1), (4294967291, 1)]);
+    test::<u64>(
+        u64::MAX,
+        &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+    );
+
+    // A product of a 56-bit prime and a 71-bit prime
+    test::<u128>(
+        70421805103159615449676617095090001433,
+        &[(59649589127497217, 1), (1180591620717411303449, 1)],
+    );
+    test::<u128>(
+        u128::MAX,
+        &[
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (274177, 1),
+            (6700417, 1),
+            (67280421310721, 1),
+        ],
+    );
+    // A product of two 40-bit primes and a 48-bit prime
+    test::<u128>(
+        1099511627689 * 1099511627791 * 281474976710597,
+        &[(1099511627689, 1), (1099511627791, 1), (281474976710597, 1)],
+    );
+    test::<u128>(
+        18446744073709551557 * 18446744073709551629,
+        &[(18446744073709551557, 1), (18446744073709551629, 1)],
+    );
+
+    test::<usize>(1001, &[(7, 1), (11, 1), (13, 1)]);
+}
+
+#[test]
+#[should_panic]
+fn factor_fail() {
+    0u32.factor();
+}
+
+#[test]
+fn test_pollard_brent_rho() {
+    let g = pollard_brent_rho(1022117u32, 1, u64::MAX).unwrap();
+    assert!(g == 1009 || g == 1013);
+    let g = pollard_brent_rho(18446743979220271189u64, 1, u64::MAX).unwrap();
+    assert!(g == 4294967279 || g == 4294967291);
+    assert_eq!(pollard_brent_rho(18446743979220271189u64, 1, 1), None);
+}
+
+#[test]
+fn test_ecm_one_curve() {
+    // A product of a 56-bit prime and a 71-bit prime
+    let n = 70421805103159615449676617095090001433u128;
+    let g = (6..)
+        .find_map(|sigma| ecm_one_curve(n, sigma, 2000, 200000))
+        .unwrap();
+    assert!(g == 59649589127497217 || g == 1180591620717411303449);
+}
+
+fn factor_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        let factors = n.factor();
+        assert_eq!(product(&factors), n);
+        assert!(factors.iter().all(|&(p, e)| p.is_prime() && e != 0));
+        assert!(factors.iter().tuple_windows().all(|((p, _), (q, _))| p < q));
+        assert_eq!(n.is_prime(), factors == [(n, 1)]);
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(a, b)| {
+        if a == T::ZERO || b == T::ZERO {
+            return;
+        }
+        if let Some(n) = a.checked_mul(b) {
+            assert_eq!(n.factor(), merge(&a.factor(), &b.factor()));
+        }
+    });
+}
+
+#[test]
+fn factor_properties() {
+    factor_properties_helper::<u8>();
+    factor_properties_helper::<u16>();
+    factor_properties_helper::<u32>();
+    factor_properties_helper::<u64>();
+
+    // Random u128s may have two large prime factors, which are too slow to find here, so
+    // products of u32s are tested instead.
+    unsigned_triple_gen_var_19::<u32>().test_properties(|(a, b, c)| {
+        if a == 0 || b == 0 || c == 0 {
+            return;
+        }
+        let n = u128::from(a) * u128::from(b) * u128::from(c);
+        let factors = [a, b, c]
+            .iter()
+            .flat_map(Factor::factor)
+            .map(|(p, e)| (u128::from(p), e))
+            .collect_vec();
+        assert_eq!(n.factor(), merge(&factors, &[]));
+    });
+}
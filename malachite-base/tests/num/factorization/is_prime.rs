@@ -0,0 +1,102 @@
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Parity};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::is_prime::is_prime_baillie_psw;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_pair_gen_var_27};
+
+#[test]
+fn test_is_prime() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: bool) {
+        assert_eq!(n.is_prime(), out);
+    }
+    test::<u8>(0, false);
+    test::<u8>(1, false);
+    test::<u8>(2, true);
+    test::<u8>(3, true);
+    test::<u8>(4, false);
+    test::<u8>(5, true);
+    test::<u8>(9, false);
+    test::<u8>(91, false);
+    test::<u8>(97, true);
+    test::<u8>(251, true);
+    test::<u8>(u8::MAX, false);
+
+    test::<u16>(2809, false);
+    test::<u16>(2819, true);
+    test::<u16>(65521, true);
+    test::<u16>(u16::MAX, false);
+
+    // Carmichael numbers
+    test::<u32>(561, false);
+    test::<u32>(41041, false);
+    test::<u32>(825265, false);
+    // The smallest strong pseudoprime to bases 2, 3, 5, and 7
+    test::<u32>(3215031751, false);
+    test::<u32>(4294967291, true);
+    test::<u32>(u32::MAX, false);
+
+    // A strong pseudoprime to the first 9 prime bases
+    test::<u64>(3825123056546413051, false);
+    test::<u64>(1000000000000000003, true);
+    test::<u64>(18446744073709551557, true);
+    test::<u64>(u64::MAX, false);
+
+    test::<u128>(18446744073709551557, true);
+    test::<u128>(18446744073709551629, true);
+    test::<u128>(18446744073709551557 * 18446744073709551629, false);
+    // A strong pseudoprime to base 2, equal to 8589937621 * 17179875241
+    test::<u128>(147574056656752341661, false);
+    // The smallest strong pseudoprime to the first 12 prime bases
+    test::<u128>(318665857834031151167461, false);
+    test::<u128>(170141183460469231731687303715884105727, true);
+    test::<u128>(340282366920938463463374607431768211297, true);
+    test::<u128>(u128::MAX, false);
+
+    test::<usize>(97, true);
+    test::<usize>(4294967291, true);
+    test::<usize>(3215031751, false);
+}
+
+fn is_prime_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let p = n.is_prime();
+        if p && n != T::TWO {
+            assert!(n.odd());
+        }
+        let n_128: u128 = n.wrapping_into();
+        assert_eq!(n_128.is_prime(), p);
+    });
+
+    unsigned_pair_gen_var_27::<T>().test_properties(|(a, b)| {
+        if let Some(product) = a.checked_mul(b) {
+            if a > T::ONE && b > T::ONE {
+                assert!(!product.is_prime());
+            }
+        }
+    });
+}
+
+#[test]
+fn is_prime_properties() {
+    apply_fn_to_unsigneds!(is_prime_properties_helper);
+
+    // The Baillie-PSW test is known to be exact below 2^64
+    unsigned_gen::<u64>().test_properties(|n| {
+        if n > 2809 && n.odd() && !(3..53).any(|p| n.divisible_by(p)) {
+            assert_eq!(is_prime_baillie_psw(n), n.is_prime());
+        }
+    });
+
+    let mut primes = u16::primes().peekable();
+    for n in 0..=u16::MAX {
+        let p = primes.peek() == Some(&n);
+        if p {
+            primes.next();
+        }
+        assert_eq!(n.is_prime(), p);
+        assert_eq!(u32::from(n).is_prime(), p);
+        assert_eq!(u64::from(n).is_prime(), p);
+        assert_eq!(usize::wrapping_from(n).is_prime(), p);
+    }
+}
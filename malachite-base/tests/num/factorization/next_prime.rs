@@ -0,0 +1,79 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{NextPrime, Primes};
+use malachite_base::test_util::generators::unsigned_gen;
+
+#[test]
+fn test_checked_next_prime() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: Option<T>) {
+        assert_eq!(n.checked_next_prime(), out);
+        if let Some(out) = out {
+            assert_eq!(n.next_prime(), out);
+        }
+    }
+    test::<u8>(0, Some(2));
+    test::<u8>(1, Some(2));
+    test::<u8>(2, Some(3));
+    test::<u8>(3, Some(5));
+    test::<u8>(4, Some(5));
+    test::<u8>(10, Some(11));
+    test::<u8>(250, Some(251));
+    test::<u8>(251, None);
+    test::<u8>(u8::MAX, None);
+
+    test::<u16>(65520, Some(65521));
+    test::<u16>(65521, None);
+
+    test::<u32>(1000000000, Some(1000000007));
+    test::<u32>(4294967290, Some(4294967291));
+    test::<u32>(4294967291, None);
+
+    test::<u64>(1000000000000000000, Some(1000000000000000003));
+    test::<u64>(18446744073709551556, Some(18446744073709551557));
+    test::<u64>(18446744073709551557, None);
+    test::<u64>(u64::MAX, None);
+
+    test::<u128>(18446744073709551557, Some(18446744073709551629));
+    test::<u128>(u128::MAX, None);
+
+    test::<usize>(100, Some(101));
+}
+
+#[test]
+#[should_panic]
+fn next_prime_fail() {
+    251u8.next_prime();
+}
+
+fn checked_next_prime_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let p = n.checked_next_prime();
+        if let Some(p) = p {
+            assert!(p > n);
+            assert!(p.is_prime());
+            assert_eq!(n.next_prime(), p);
+            // p.previous_prime() is the largest prime less than or equal to n
+            let q = p.previous_prime();
+            assert_eq!(q.is_some(), n >= T::TWO);
+            if let Some(q) = q {
+                assert!(q <= n);
+                assert!(q.is_prime());
+                assert_eq!(q.next_prime(), p);
+            }
+        } else {
+            assert!(n >= T::MAX.previous_prime().unwrap());
+        }
+    });
+}
+
+#[test]
+fn checked_next_prime_properties() {
+    apply_fn_to_unsigneds!(checked_next_prime_properties_helper);
+
+    let mut n = 0u16;
+    for p in u16::primes() {
+        while n < p {
+            assert_eq!(n.next_prime(), p);
+            n += 1;
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{PreviousPrime, Primes};
+use malachite_base::test_util::generators::unsigned_gen;
+
+#[test]
+fn test_previous_prime() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: Option<T>) {
+        assert_eq!(n.previous_prime(), out);
+    }
+    test::<u8>(0, None);
+    test::<u8>(1, None);
+    test::<u8>(2, None);
+    test::<u8>(3, Some(2));
+    test::<u8>(4, Some(3));
+    test::<u8>(5, Some(3));
+    test::<u8>(10, Some(7));
+    test::<u8>(u8::MAX, Some(251));
+
+    test::<u16>(u16::MAX, Some(65521));
+
+    test::<u32>(1000000000, Some(999999937));
+    test::<u32>(u32::MAX, Some(4294967291));
+
+    test::<u64>(1000000000000000000, Some(999999999999999989));
+    test::<u64>(u64::MAX, Some(18446744073709551557));
+
+    test::<u128>(18446744073709551629, Some(18446744073709551557));
+    test::<u128>(u128::MAX, Some(340282366920938463463374607431768211297));
+
+    test::<usize>(100, Some(97));
+}
+
+fn previous_prime_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let p = n.previous_prime();
+        assert_eq!(p.is_some(), n > T::TWO);
+        if let Some(p) = p {
+            assert!(p < n);
+            assert!(p.is_prime());
+            // There are no primes strictly between p and n
+            assert!(p.checked_next_prime().map_or(true, |q| q >= n));
+        }
+    });
+}
+
+#[test]
+fn previous_prime_properties() {
+    apply_fn_to_unsigneds!(previous_prime_properties_helper);
+
+    let mut previous = None;
+    let mut n = 0u16;
+    for p in u16::primes() {
+        while n <= p {
+            assert_eq!(n.previous_prime(), previous);
+            n += 1;
+        }
+        previous = Some(p);
+    }
+}
@@ -0,0 +1,141 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::prime_count::lmo_prime_count;
+use malachite_base::num::factorization::traits::{
+    IsPrime, NthPrime, PrimeCount, Primes, PrimesInRange,
+};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen, unsigned_gen_var_1, unsigned_gen_var_11,
+};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_prime_count() {
+    fn test<T: PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.prime_count(), out);
+    }
+    test::<u8>(0, 0);
+    test::<u8>(1, 0);
+    test::<u8>(2, 1);
+    test::<u8>(3, 2);
+    test::<u8>(4, 2);
+    test::<u8>(10, 4);
+    test::<u8>(100, 25);
+    test::<u8>(u8::MAX, 54);
+
+    test::<u16>(1000, 168);
+    test::<u16>(10000, 1229);
+    test::<u16>(u16::MAX, 6542);
+
+    test::<u32>(65536, 6542);
+    test::<u32>(65537, 6543);
+    test::<u32>(100000, 9592);
+    test::<u32>(1000000, 78498);
+    test::<u32>(10000000, 664579);
+    test::<u32>(100000000, 5761455);
+    test::<u32>(1000000000, 50847534);
+    test::<u32>(u32::MAX, 203280221);
+
+    test::<u64>(10000000000, 455052511);
+    test::<u64>(1 << 34, 762939111);
+
+    test::<u128>(1000000, 78498);
+    test::<usize>(1000000, 78498);
+}
+
+#[test]
+fn prime_count_fail() {
+    assert_panic!(u128::MAX.prime_count());
+}
+
+#[test]
+fn test_nth_prime() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::nth_prime(n), out);
+    }
+    test::<u8>(1, 2);
+    test::<u8>(2, 3);
+    test::<u8>(3, 5);
+    test::<u8>(10, 29);
+    test::<u8>(54, 251);
+
+    test::<u16>(100, 541);
+    test::<u16>(6542, 65521);
+
+    test::<u32>(6543, 65537);
+    test::<u32>(6544, 65539);
+    test::<u32>(10000, 104729);
+    test::<u32>(100000, 1299709);
+    test::<u32>(1000000, 15485863);
+    test::<u32>(10000000, 179424673);
+    test::<u32>(203280221, 4294967291);
+
+    test::<u64>(203280222, 4294967311);
+    test::<u64>(1000000000, 22801763489);
+
+    test::<u128>(1000000, 15485863);
+    test::<usize>(1000000, 15485863);
+}
+
+fn nth_prime_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::nth_prime(0));
+}
+
+#[test]
+fn nth_prime_fail() {
+    apply_fn_to_unsigneds!(nth_prime_fail_helper);
+    assert_panic!(u8::nth_prime(55));
+    assert_panic!(u16::nth_prime(6543));
+    assert_panic!(u32::nth_prime(203280222));
+    assert_panic!(u64::nth_prime(425656284035217744));
+}
+
+#[test]
+fn prime_count_properties() {
+    unsigned_gen_var_1::<u16>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let count = n.prime_count();
+        assert_eq!(lmo_prime_count(u64::from(n)), u64::from(count));
+        assert_eq!(count - (n - 1).prime_count(), u16::from(n.is_prime()));
+        assert_eq!(
+            usize::wrapping_from(count),
+            u16::primes_less_than_or_equal_to(&n).count()
+        );
+    });
+
+    unsigned_gen::<u32>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        // Compare a window of prime counts with the primes in the window.
+        let a = n >> 12;
+        let b = a + 1000;
+        assert_eq!(
+            u64::wrapping_from(u32::primes_in_inclusive_range(&(a + 1), &b).count()),
+            u64::from(b.prime_count() - a.prime_count())
+        );
+    });
+
+    let mut n = 0u64;
+    for (count, p) in u64::primes_less_than(&10000).enumerate() {
+        let count = u64::wrapping_from(count);
+        while n < p {
+            assert_eq!(n.prime_count(), count);
+            assert_eq!(lmo_prime_count(n.max(1)), count);
+            n += 1;
+        }
+    }
+}
+
+#[test]
+fn nth_prime_properties() {
+    unsigned_gen_var_11::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        for n in [n, n + 6542] {
+            let p = u64::nth_prime(n);
+            assert!(p.is_prime());
+            assert_eq!(p.prime_count(), n);
+            assert_eq!((p - 1).prime_count(), n - 1);
+        }
+    });
+
+    for (i, p) in u32::primes().take(10000).enumerate() {
+        assert_eq!(u32::nth_prime(u64::wrapping_from(i) + 1), p);
+    }
+}
@@ -0,0 +1,126 @@
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{CheckedNextPrime, Primes, PrimesInRange};
+use malachite_base::test_util::generators::{unsigned_pair_gen, unsigned_pair_gen_var_7};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_primes_in_range() {
+    fn test<T: PrimitiveUnsigned>(a: T, b: T, out: &[T]) {
+        assert_eq!(T::primes_in_range(&a, &b).collect_vec(), out);
+    }
+    test::<u8>(0, 0, &[]);
+    test::<u8>(0, 2, &[]);
+    test::<u8>(0, 3, &[2]);
+    test::<u8>(2, 3, &[2]);
+    test::<u8>(3, 4, &[3]);
+    test::<u8>(0, 10, &[2, 3, 5, 7]);
+    test::<u8>(8, 11, &[]);
+    test::<u8>(200, u8::MAX, &[211, 223, 227, 229, 233, 239, 241, 251]);
+    test::<u16>(65500, u16::MAX, &[65519, 65521]);
+    test::<u32>(4294967200, u32::MAX, &[4294967231, 4294967279, 4294967291]);
+    test::<u64>(
+        1000000000000,
+        1000000000100,
+        &[1000000000039, 1000000000061, 1000000000063, 1000000000091],
+    );
+    test::<u128>(
+        18446744073709551557,
+        18446744073709551654,
+        &[18446744073709551557, 18446744073709551629, 18446744073709551653],
+    );
+
+    assert_eq!(u32::primes_in_range(&0, &1000000).count(), 78498);
+    assert_eq!(
+        u64::primes_in_range(&1000000000000, &1000000100000).count(),
+        3614
+    );
+    assert_eq!(
+        u64::primes_in_range(&(u64::MAX - 99999), &u64::MAX).count(),
+        2139
+    );
+    assert_eq!(
+        u64::primes_in_range(&((1 << 40) - 100000), &((1 << 40) + 100000)).count(),
+        7247
+    );
+}
+
+#[test]
+fn test_primes_in_inclusive_range() {
+    fn test<T: PrimitiveUnsigned>(a: T, b: T, out: &[T]) {
+        assert_eq!(T::primes_in_inclusive_range(&a, &b).collect_vec(), out);
+    }
+    test::<u8>(0, 0, &[]);
+    test::<u8>(0, 2, &[2]);
+    test::<u8>(3, 3, &[3]);
+    test::<u8>(0, 10, &[2, 3, 5, 7]);
+    test::<u8>(240, u8::MAX, &[241, 251]);
+    test::<u16>(65500, u16::MAX, &[65519, 65521]);
+    test::<u64>(
+        18446744073709551500,
+        u64::MAX,
+        &[18446744073709551521, 18446744073709551533, 18446744073709551557],
+    );
+    test::<u128>(
+        u128::MAX - 200,
+        u128::MAX,
+        &[340282366920938463463374607431768211283, 340282366920938463463374607431768211297],
+    );
+}
+
+fn primes_in_range_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::primes_in_range(&T::ONE, &T::ZERO));
+    assert_panic!(T::primes_in_inclusive_range(&T::ONE, &T::ZERO));
+}
+
+#[test]
+fn primes_in_range_fail() {
+    apply_fn_to_unsigneds!(primes_in_range_fail_helper);
+}
+
+fn primes_in_range_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen::<T, u8>().test_properties(|(a, d)| {
+        let b = a.saturating_add(T::saturating_from(d));
+        let ps = T::primes_in_range(&a, &b).collect_vec();
+        assert!(is_strictly_ascending(ps.iter()));
+        assert!(ps.iter().all(|&p| a <= p && p < b));
+        let first = if a <= T::TWO {
+            Some(T::TWO)
+        } else {
+            (a - T::ONE).checked_next_prime()
+        };
+        let expected =
+            itertools::iterate(first, |&p| p.and_then(CheckedNextPrime::checked_next_prime))
+                .map_while(|p| p)
+                .take_while(|&p| p < b)
+                .collect_vec();
+        assert_eq!(ps, expected);
+
+        let mut ps = ps;
+        if b.is_prime() {
+            ps.push(b);
+        }
+        assert_eq!(T::primes_in_inclusive_range(&a, &b).collect_vec(), ps);
+    });
+}
+
+#[test]
+fn primes_in_range_properties() {
+    // Testing the primality of large u128s is slow, so u128s are only tested in
+    // test_primes_in_range and test_primes_in_inclusive_range.
+    primes_in_range_properties_helper::<u8>();
+    primes_in_range_properties_helper::<u16>();
+    primes_in_range_properties_helper::<u32>();
+    primes_in_range_properties_helper::<u64>();
+
+    unsigned_pair_gen_var_7::<u8>().test_properties(|(a, b)| {
+        assert_eq!(
+            u8::primes_in_range(&a, &b).collect_vec(),
+            u8::primes()
+                .skip_while(|&p| p < a)
+                .take_while(|&p| p < b)
+                .collect_vec()
+        );
+    });
+}
@@ -7,6 +7,10 @@ extern crate rug;
 use criterion::*;
 use malachite_base::num::random::random_primitive_ints;
 use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::arithmetic::mul::fft::{
+    limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_scratch_len,
+};
+use malachite_nz::natural::arithmetic::mul::ntt::limbs_mul_greater_to_out_ntt;
 use malachite_nz::natural::random::get_random_natural_with_bits;
 use malachite_nz::natural::Natural;
 use num::BigUint;
@@ -46,9 +50,34 @@ fn bench_mul(c: &mut Criterion) {
     }
     group.finish();
 }
+
+fn bench_mul_fft_vs_ntt(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Natural * Natural, FFT vs. NTT");
+    let plot_config = PlotConfiguration::default().summary_scale(AxisScale::Logarithmic);
+    group.plot_config(plot_config);
+    let sizes = [64000u64, 192000, 1000000, 10000000, 100000000];
+    for &i in sizes.iter() {
+        let x = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("a")), i);
+        let y = get_random_natural_with_bits(&mut random_primitive_ints(EXAMPLE_SEED.fork("b")), i);
+        let xs = x.into_limbs_asc();
+        let ys = y.into_limbs_asc();
+        let out_len = xs.len() + ys.len();
+        group.bench_function(BenchmarkId::new("fft", i), |b| {
+            let mut out = vec![0; out_len];
+            let mut scratch = vec![0; limbs_mul_greater_to_out_fft_scratch_len(xs.len(), ys.len())];
+            b.iter(|| limbs_mul_greater_to_out_fft(&mut out, &xs, &ys, &mut scratch))
+        });
+        group.bench_function(BenchmarkId::new("ntt", i), |b| {
+            let mut out = vec![0; out_len];
+            b.iter(|| limbs_mul_greater_to_out_ntt(&mut out, &xs, &ys))
+        });
+    }
+    group.finish();
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().significance_level(0.1).sample_size(10);
-    targets = bench_mul
+    targets = bench_mul, bench_mul_fft_vs_ntt
 }
 criterion_main!(benches);
@@ -0,0 +1,90 @@
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_pair_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_coprime_with);
+    register_demo!(runner, demo_integer_coprime_with_val_ref);
+    register_demo!(runner, demo_integer_coprime_with_ref_val);
+    register_demo!(runner, demo_integer_coprime_with_ref_ref);
+
+    register_bench!(runner, benchmark_integer_coprime_with_evaluation_strategy);
+}
+
+fn demo_integer_coprime_with(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        if x.coprime_with(y) {
+            println!("{} is coprime with {}", x_old, y_old);
+        } else {
+            println!("{} is not coprime with {}", x_old, y_old);
+        }
+    }
+}
+
+fn demo_integer_coprime_with_val_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        if x.coprime_with(&y) {
+            println!("{} is coprime with &{}", x_old, y);
+        } else {
+            println!("{} is not coprime with &{}", x_old, y);
+        }
+    }
+}
+
+fn demo_integer_coprime_with_ref_val(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let y_old = y.clone();
+        if (&x).coprime_with(y) {
+            println!("&{} is coprime with {}", x, y_old);
+        } else {
+            println!("&{} is not coprime with {}", x, y_old);
+        }
+    }
+}
+
+fn demo_integer_coprime_with_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        if (&x).coprime_with(&y) {
+            println!("&{} is coprime with &{}", x, y);
+        } else {
+            println!("&{} is not coprime with &{}", x, y);
+        }
+    }
+}
+
+fn benchmark_integer_coprime_with_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.coprime_with(Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Integer.coprime_with(Integer)", &mut |(x, y)| {
+                no_out!(x.coprime_with(y))
+            }),
+            ("Integer.coprime_with(&Integer)", &mut |(x, y)| {
+                no_out!(x.coprime_with(&y))
+            }),
+            ("&Integer.coprime_with(Integer)", &mut |(x, y)| {
+                no_out!((&x).coprime_with(y))
+            }),
+            ("&Integer.coprime_with(&Integer)", &mut |(x, y)| {
+                no_out!((&x).coprime_with(&y))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,45 @@
+use malachite_base::num::arithmetic::traits::Crt;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::bench::bucketers::triple_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_triple_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_crt);
+
+    register_bench!(runner, benchmark_integer_crt);
+}
+
+fn demo_integer_crt(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, a, b) in integer_triple_gen()
+        .get(gm, &config)
+        .filter(|(_, a, b)| *a > 0u32 && *b > 0u32)
+        .take(limit)
+    {
+        let congruences = [(x.clone(), a), (x, b)];
+        println!(
+            "Integer::crt({:?}) = {:?}",
+            congruences,
+            Integer::crt(&congruences)
+        );
+    }
+}
+
+fn benchmark_integer_crt(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Integer::crt(&[(Integer, Integer), (Integer, Integer)])",
+        BenchmarkType::Single,
+        integer_triple_gen()
+            .get(gm, &config)
+            .filter(|(_, a, b)| *a > 0u32 && *b > 0u32),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_integer_max_bit_bucketer("x", "a", "b"),
+        &mut [("Malachite", &mut |(x, a, b)| {
+            no_out!(Integer::crt(&[(x.clone(), a), (x, b)]))
+        })],
+    );
+}
@@ -0,0 +1,192 @@
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign, GcdOf};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::{
+    pair_integer_max_bit_bucketer, triple_3_pair_integer_max_bit_bucketer,
+    vec_integer_sum_bits_bucketer,
+};
+use malachite_nz::test_util::generators::{
+    integer_pair_gen, integer_pair_gen_nrm, integer_vec_gen,
+};
+use num::Integer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_gcd);
+    register_demo!(runner, demo_integer_gcd_val_ref);
+    register_demo!(runner, demo_integer_gcd_ref_val);
+    register_demo!(runner, demo_integer_gcd_ref_ref);
+    register_demo!(runner, demo_integer_gcd_assign);
+    register_demo!(runner, demo_integer_gcd_assign_ref);
+    register_demo!(runner, demo_natural_gcd_of_integers);
+    register_demo!(runner, demo_natural_gcd_of_integers_ref);
+
+    register_bench!(runner, benchmark_integer_gcd_library_comparison);
+    register_bench!(runner, benchmark_integer_gcd_evaluation_strategy);
+    register_bench!(runner, benchmark_integer_gcd_assign_evaluation_strategy);
+    register_bench!(
+        runner,
+        benchmark_natural_gcd_of_integers_evaluation_strategy
+    );
+}
+
+fn demo_integer_gcd(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!("({}).gcd({}) = {}", x_old, y_old, x.gcd(y));
+    }
+}
+
+fn demo_integer_gcd_val_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).gcd(&{}) = {}", x_old, y, x.gcd(&y));
+    }
+}
+
+fn demo_integer_gcd_ref_val(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let y_old = y.clone();
+        println!("(&{}).gcd({}) = {}", x, y_old, (&x).gcd(y));
+    }
+}
+
+fn demo_integer_gcd_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("(&{}).gcd(&{}) = {}", x, y, (&x).gcd(&y));
+    }
+}
+
+fn demo_integer_gcd_assign(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.gcd_assign(y);
+        println!("x := {}; x.gcd_assign({}); x = {}", x_old, y_old, x);
+    }
+}
+
+fn demo_integer_gcd_assign_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        x.gcd_assign(&y);
+        println!("x := {}; x.gcd_assign(&{}); x = {}", x_old, y, x);
+    }
+}
+
+fn demo_natural_gcd_of_integers(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in integer_vec_gen().get(gm, &config).take(limit) {
+        println!(
+            "gcd_of({:?}) = {}",
+            xs.clone(),
+            Natural::gcd_of(xs.into_iter())
+        );
+    }
+}
+
+fn demo_natural_gcd_of_integers_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in integer_vec_gen().get(gm, &config).take(limit) {
+        println!("gcd_of({:?}) = {}", xs, Natural::gcd_of(xs.iter()));
+    }
+}
+
+#[allow(unused_must_use)]
+fn benchmark_integer_gcd_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.gcd(Integer)",
+        BenchmarkType::LibraryComparison,
+        integer_pair_gen_nrm().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Malachite", &mut |(_, _, (x, y))| no_out!(x.gcd(y))),
+            ("num", &mut |((x, y), _, _)| no_out!(x.gcd(&y))),
+            ("rug", &mut |(_, (x, y), _)| no_out!(x.gcd(&y))),
+        ],
+    );
+}
+
+fn benchmark_integer_gcd_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.gcd(Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Integer.gcd(Integer)", &mut |(x, y)| no_out!(x.gcd(y))),
+            ("Integer.gcd(&Integer)", &mut |(x, y)| no_out!(x.gcd(&y))),
+            ("&Integer.gcd(Integer)", &mut |(x, y)| no_out!((&x).gcd(y))),
+            (
+                "&Integer.gcd(&Integer)",
+                &mut |(x, y)| no_out!((&x).gcd(&y)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_integer_gcd_assign_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.gcd_assign(Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Integer.gcd_assign(Integer)", &mut |(mut x, y)| {
+                x.gcd_assign(y)
+            }),
+            ("Integer.gcd_assign(&Integer)", &mut |(mut x, y)| {
+                x.gcd_assign(&y)
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_gcd_of_integers_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::gcd_of(Iterator<Item=Integer>)",
+        BenchmarkType::EvaluationStrategy,
+        integer_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_integer_sum_bits_bucketer(),
+        &mut [
+            ("Natural::gcd_of(Iterator<Item=Integer>)", &mut |xs| {
+                no_out!(Natural::gcd_of(xs.into_iter()))
+            }),
+            ("Natural::gcd_of(Iterator<Item=&Integer>)", &mut |xs| {
+                no_out!(Natural::gcd_of(xs.iter()))
+            }),
+        ],
+    );
+}
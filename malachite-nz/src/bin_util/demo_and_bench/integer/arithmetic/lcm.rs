@@ -0,0 +1,192 @@
+use malachite_base::num::arithmetic::traits::{Lcm, LcmAssign, LcmOf};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::{
+    pair_integer_max_bit_bucketer, triple_3_pair_integer_max_bit_bucketer,
+    vec_integer_sum_bits_bucketer,
+};
+use malachite_nz::test_util::generators::{
+    integer_pair_gen, integer_pair_gen_nrm, integer_vec_gen,
+};
+use num::Integer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_lcm);
+    register_demo!(runner, demo_integer_lcm_val_ref);
+    register_demo!(runner, demo_integer_lcm_ref_val);
+    register_demo!(runner, demo_integer_lcm_ref_ref);
+    register_demo!(runner, demo_integer_lcm_assign);
+    register_demo!(runner, demo_integer_lcm_assign_ref);
+    register_demo!(runner, demo_natural_lcm_of_integers);
+    register_demo!(runner, demo_natural_lcm_of_integers_ref);
+
+    register_bench!(runner, benchmark_integer_lcm_library_comparison);
+    register_bench!(runner, benchmark_integer_lcm_evaluation_strategy);
+    register_bench!(runner, benchmark_integer_lcm_assign_evaluation_strategy);
+    register_bench!(
+        runner,
+        benchmark_natural_lcm_of_integers_evaluation_strategy
+    );
+}
+
+fn demo_integer_lcm(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        println!("({}).lcm({}) = {}", x_old, y_old, x.lcm(y));
+    }
+}
+
+fn demo_integer_lcm_val_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        println!("({}).lcm(&{}) = {}", x_old, y, x.lcm(&y));
+    }
+}
+
+fn demo_integer_lcm_ref_val(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let y_old = y.clone();
+        println!("(&{}).lcm({}) = {}", x, y_old, (&x).lcm(y));
+    }
+}
+
+fn demo_integer_lcm_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        println!("(&{}).lcm(&{}) = {}", x, y, (&x).lcm(&y));
+    }
+}
+
+fn demo_integer_lcm_assign(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        x.lcm_assign(y);
+        println!("x := {}; x.lcm_assign({}); x = {}", x_old, y_old, x);
+    }
+}
+
+fn demo_integer_lcm_assign_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y) in integer_pair_gen().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        x.lcm_assign(&y);
+        println!("x := {}; x.lcm_assign(&{}); x = {}", x_old, y, x);
+    }
+}
+
+fn demo_natural_lcm_of_integers(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in integer_vec_gen().get(gm, &config).take(limit) {
+        println!(
+            "lcm_of({:?}) = {}",
+            xs.clone(),
+            Natural::lcm_of(xs.into_iter())
+        );
+    }
+}
+
+fn demo_natural_lcm_of_integers_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in integer_vec_gen().get(gm, &config).take(limit) {
+        println!("lcm_of({:?}) = {}", xs, Natural::lcm_of(xs.iter()));
+    }
+}
+
+#[allow(unused_must_use)]
+fn benchmark_integer_lcm_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.lcm(Integer)",
+        BenchmarkType::LibraryComparison,
+        integer_pair_gen_nrm().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Malachite", &mut |(_, _, (x, y))| no_out!(x.lcm(y))),
+            ("num", &mut |((x, y), _, _)| no_out!(x.lcm(&y))),
+            ("rug", &mut |(_, (x, y), _)| no_out!(x.lcm(&y))),
+        ],
+    );
+}
+
+fn benchmark_integer_lcm_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.lcm(Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Integer.lcm(Integer)", &mut |(x, y)| no_out!(x.lcm(y))),
+            ("Integer.lcm(&Integer)", &mut |(x, y)| no_out!(x.lcm(&y))),
+            ("&Integer.lcm(Integer)", &mut |(x, y)| no_out!((&x).lcm(y))),
+            (
+                "&Integer.lcm(&Integer)",
+                &mut |(x, y)| no_out!((&x).lcm(&y)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_integer_lcm_assign_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.lcm_assign(Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_pair_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("x", "y"),
+        &mut [
+            ("Integer.lcm_assign(Integer)", &mut |(mut x, y)| {
+                x.lcm_assign(y)
+            }),
+            ("Integer.lcm_assign(&Integer)", &mut |(mut x, y)| {
+                x.lcm_assign(&y)
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_lcm_of_integers_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::lcm_of(Iterator<Item=Integer>)",
+        BenchmarkType::EvaluationStrategy,
+        integer_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_integer_sum_bits_bucketer(),
+        &mut [
+            ("Natural::lcm_of(Iterator<Item=Integer>)", &mut |xs| {
+                no_out!(Natural::lcm_of(xs.into_iter()))
+            }),
+            ("Natural::lcm_of(Iterator<Item=&Integer>)", &mut |xs| {
+                no_out!(Natural::lcm_of(xs.iter()))
+            }),
+        ],
+    );
+}
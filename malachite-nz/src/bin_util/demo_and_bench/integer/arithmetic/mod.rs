@@ -5,6 +5,8 @@ pub(crate) fn register(runner: &mut Runner) {
     add::register(runner);
     add_mul::register(runner);
     binomial_coefficient::register(runner);
+    coprime_with::register(runner);
+    crt::register(runner);
     div::register(runner);
     div_exact::register(runner);
     div_mod::register(runner);
@@ -14,12 +16,23 @@ pub(crate) fn register(runner: &mut Runner) {
     eq_mod::register(runner);
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
+    gcd::register(runner);
     kronecker_symbol::register(runner);
+    lcm::register(runner);
+    mod_add::register(runner);
+    mod_centered::register(runner);
+    mod_inverse::register(runner);
+    mod_mul::register(runner);
+    mod_neg::register(runner);
     mod_op::register(runner);
+    mod_pow::register(runner);
     mod_power_of_2::register(runner);
+    mod_square::register(runner);
+    mod_sub::register(runner);
     mul::register(runner);
     neg::register(runner);
     parity::register(runner);
+    perfect_power::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
     root::register(runner);
@@ -34,12 +47,15 @@ pub(crate) fn register(runner: &mut Runner) {
     square::register(runner);
     sub::register(runner);
     sub_mul::register(runner);
+    valuation::register(runner);
 }
 
 mod abs;
 mod add;
 mod add_mul;
 mod binomial_coefficient;
+mod coprime_with;
+mod crt;
 mod div;
 mod div_exact;
 mod div_mod;
@@ -49,12 +65,23 @@ mod divisible_by_power_of_2;
 mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
+mod gcd;
 mod kronecker_symbol;
+mod lcm;
+mod mod_add;
+mod mod_centered;
+mod mod_inverse;
+mod mod_mul;
+mod mod_neg;
 mod mod_op;
+mod mod_pow;
 mod mod_power_of_2;
+mod mod_square;
+mod mod_sub;
 mod mul;
 mod neg;
 mod parity;
+mod perfect_power;
 mod pow;
 mod power_of_2;
 mod root;
@@ -69,3 +96,4 @@ mod sqrt;
 mod square;
 mod sub;
 mod sub_mul;
+mod valuation;
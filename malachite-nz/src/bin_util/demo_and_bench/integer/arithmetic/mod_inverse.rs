@@ -0,0 +1,51 @@
+use malachite_base::num::arithmetic::traits::ModInverse;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_pair_gen_var_8;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_mod_inverse);
+
+    register_bench!(runner, benchmark_integer_mod_inverse_evaluation_strategy);
+}
+
+fn demo_integer_mod_inverse(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in integer_pair_gen_var_8().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        println!("{}.mod_inverse({}) = {:?}", x_old, m_old, x.mod_inverse(m));
+    }
+}
+
+fn benchmark_integer_mod_inverse_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_inverse(Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_pair_gen_var_8().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_integer_max_bit_bucketer("x", "m"),
+        &mut [
+            ("Integer.mod_inverse(Integer)", &mut |(x, m)| {
+                no_out!(x.mod_inverse(m))
+            }),
+            ("Integer.mod_inverse(&Integer)", &mut |(x, m)| {
+                no_out!(x.mod_inverse(&m))
+            }),
+            ("(&Integer).mod_inverse(Integer)", &mut |(x, m)| {
+                no_out!((&x).mod_inverse(m))
+            }),
+            ("(&Integer).mod_inverse(&Integer)", &mut |(x, m)| {
+                no_out!((&x).mod_inverse(&m))
+            }),
+        ],
+    );
+}
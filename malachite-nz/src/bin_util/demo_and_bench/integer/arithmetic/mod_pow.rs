@@ -0,0 +1,139 @@
+use malachite_base::num::arithmetic::traits::{ModPow, ModPowAssign};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::triple_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_triple_gen_var_4;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_mod_pow);
+    register_demo!(runner, demo_integer_mod_pow_assign);
+
+    register_bench!(runner, benchmark_integer_mod_pow_evaluation_strategy);
+    register_bench!(runner, benchmark_integer_mod_pow_assign_evaluation_strategy);
+}
+
+fn demo_integer_mod_pow(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, exp, m) in integer_triple_gen_var_4()
+        .get(gm, &config)
+        .filter(|(_, exp, _)| *exp >= 0u32)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let exp_old = exp.clone();
+        let m_old = m.clone();
+        println!(
+            "{}.mod_pow({}, {}) = {}",
+            x_old,
+            exp_old,
+            m_old,
+            x.mod_pow(exp, m)
+        );
+    }
+}
+
+fn demo_integer_mod_pow_assign(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, exp, m) in integer_triple_gen_var_4()
+        .get(gm, &config)
+        .filter(|(_, exp, _)| *exp >= 0u32)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let exp_old = exp.clone();
+        let m_old = m.clone();
+        x.mod_pow_assign(exp, m);
+        println!(
+            "x := {}; x.mod_pow_assign({}, {}); x = {}",
+            x_old, exp_old, m_old, x
+        );
+    }
+}
+
+fn benchmark_integer_mod_pow_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_pow(Integer, Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_triple_gen_var_4()
+            .get(gm, &config)
+            .filter(|(_, exp, _)| *exp >= 0u32),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_integer_max_bit_bucketer("x", "exp", "m"),
+        &mut [
+            ("Integer.mod_pow(Integer, Integer)", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(exp, m))
+            }),
+            ("Integer.mod_pow(Integer, &Integer)", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(exp, &m))
+            }),
+            ("Integer.mod_pow(&Integer, Integer)", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(&exp, m))
+            }),
+            ("Integer.mod_pow(&Integer, &Integer)", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(&exp, &m))
+            }),
+            ("(&Integer).mod_pow(Integer, Integer)", &mut |(
+                x,
+                exp,
+                m,
+            )| {
+                no_out!((&x).mod_pow(exp, m))
+            }),
+            (
+                "(&Integer).mod_pow(Integer, &Integer)",
+                &mut |(x, exp, m)| no_out!((&x).mod_pow(exp, &m)),
+            ),
+            (
+                "(&Integer).mod_pow(&Integer, Integer)",
+                &mut |(x, exp, m)| no_out!((&x).mod_pow(&exp, m)),
+            ),
+            (
+                "(&Integer).mod_pow(&Integer, &Integer)",
+                &mut |(x, exp, m)| no_out!((&x).mod_pow(&exp, &m)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_integer_mod_pow_assign_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_pow_assign(Integer, Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_triple_gen_var_4()
+            .get(gm, &config)
+            .filter(|(_, exp, _)| *exp >= 0u32),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_integer_max_bit_bucketer("x", "exp", "m"),
+        &mut [
+            (
+                "Integer.mod_pow_assign(Integer, Integer)",
+                &mut |(mut x, exp, m)| x.mod_pow_assign(exp, m),
+            ),
+            (
+                "Integer.mod_pow_assign(Integer, &Integer)",
+                &mut |(mut x, exp, m)| x.mod_pow_assign(exp, &m),
+            ),
+            (
+                "Integer.mod_pow_assign(&Integer, Integer)",
+                &mut |(mut x, exp, m)| x.mod_pow_assign(&exp, m),
+            ),
+            (
+                "Integer.mod_pow_assign(&Integer, &Integer)",
+                &mut |(mut x, exp, m)| x.mod_pow_assign(&exp, &m),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,121 @@
+use malachite_base::num::arithmetic::traits::{ModSub, ModSubAssign};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::triple_integer_max_bit_bucketer;
+use malachite_nz::test_util::generators::integer_triple_gen_var_4;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_mod_sub);
+    register_demo!(runner, demo_integer_mod_sub_assign);
+
+    register_bench!(runner, benchmark_integer_mod_sub_evaluation_strategy);
+    register_bench!(runner, benchmark_integer_mod_sub_assign_evaluation_strategy);
+}
+
+fn demo_integer_mod_sub(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in integer_triple_gen_var_4().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let m_old = m.clone();
+        println!(
+            "{}.mod_sub({}, {}) = {}",
+            x_old,
+            y_old,
+            m_old,
+            x.mod_sub(y, m)
+        );
+    }
+}
+
+fn demo_integer_mod_sub_assign(gm: GenMode, config: GenConfig, limit: usize) {
+    for (mut x, y, m) in integer_triple_gen_var_4().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let y_old = y.clone();
+        let m_old = m.clone();
+        x.mod_sub_assign(y, m);
+        println!(
+            "x := {}; x.mod_sub_assign({}, {}); x = {}",
+            x_old, y_old, m_old, x
+        );
+    }
+}
+
+fn benchmark_integer_mod_sub_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_sub(Integer, Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_triple_gen_var_4().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_integer_max_bit_bucketer("x", "y", "m"),
+        &mut [
+            ("Integer.mod_sub(Integer, Integer)", &mut |(x, y, m)| {
+                no_out!(x.mod_sub(y, m))
+            }),
+            ("Integer.mod_sub(Integer, &Integer)", &mut |(x, y, m)| {
+                no_out!(x.mod_sub(y, &m))
+            }),
+            ("Integer.mod_sub(&Integer, Integer)", &mut |(x, y, m)| {
+                no_out!(x.mod_sub(&y, m))
+            }),
+            ("Integer.mod_sub(&Integer, &Integer)", &mut |(x, y, m)| {
+                no_out!(x.mod_sub(&y, &m))
+            }),
+            ("(&Integer).mod_sub(Integer, Integer)", &mut |(x, y, m)| {
+                no_out!((&x).mod_sub(y, m))
+            }),
+            ("(&Integer).mod_sub(Integer, &Integer)", &mut |(x, y, m)| {
+                no_out!((&x).mod_sub(y, &m))
+            }),
+            ("(&Integer).mod_sub(&Integer, Integer)", &mut |(x, y, m)| {
+                no_out!((&x).mod_sub(&y, m))
+            }),
+            (
+                "(&Integer).mod_sub(&Integer, &Integer)",
+                &mut |(x, y, m)| no_out!((&x).mod_sub(&y, &m)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_integer_mod_sub_assign_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.mod_sub_assign(Integer, Integer)",
+        BenchmarkType::EvaluationStrategy,
+        integer_triple_gen_var_4().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_integer_max_bit_bucketer("x", "y", "m"),
+        &mut [
+            (
+                "Integer.mod_sub_assign(Integer, Integer)",
+                &mut |(mut x, y, m)| x.mod_sub_assign(y, m),
+            ),
+            (
+                "Integer.mod_sub_assign(Integer, &Integer)",
+                &mut |(mut x, y, m)| x.mod_sub_assign(y, &m),
+            ),
+            (
+                "Integer.mod_sub_assign(&Integer, Integer)",
+                &mut |(mut x, y, m)| x.mod_sub_assign(&y, m),
+            ),
+            (
+                "Integer.mod_sub_assign(&Integer, &Integer)",
+                &mut |(mut x, y, m)| x.mod_sub_assign(&y, &m),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,122 @@
+use malachite_base::num::arithmetic::traits::{RemoveFactor, Valuation};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_1_integer_bit_bucketer;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_valuation);
+    register_demo!(runner, demo_integer_valuation_ref);
+    register_demo!(runner, demo_integer_remove_factor);
+    register_demo!(runner, demo_integer_remove_factor_ref);
+
+    register_bench!(runner, benchmark_integer_valuation_evaluation_strategy);
+    register_bench!(runner, benchmark_integer_remove_factor_evaluation_strategy);
+}
+
+fn demo_integer_valuation(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in integer_natural_pair_gen()
+        .get(gm, &config)
+        .filter(|(n, p)| *n != 0u32 && *p > 1u32)
+        .take(limit)
+    {
+        let n_old = n.clone();
+        println!("({}).valuation(&{}) = {}", n_old, p, n.valuation(&p));
+    }
+}
+
+fn demo_integer_valuation_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in integer_natural_pair_gen()
+        .get(gm, &config)
+        .filter(|(n, p)| *n != 0u32 && *p > 1u32)
+        .take(limit)
+    {
+        println!("(&{}).valuation(&{}) = {}", n, p, (&n).valuation(&p));
+    }
+}
+
+fn demo_integer_remove_factor(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in integer_natural_pair_gen()
+        .get(gm, &config)
+        .filter(|(n, p)| *n != 0u32 && *p > 1u32)
+        .take(limit)
+    {
+        let n_old = n.clone();
+        println!(
+            "({}).remove_factor(&{}) = {:?}",
+            n_old,
+            p,
+            n.remove_factor(&p)
+        );
+    }
+}
+
+fn demo_integer_remove_factor_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in integer_natural_pair_gen()
+        .get(gm, &config)
+        .filter(|(n, p)| *n != 0u32 && *p > 1u32)
+        .take(limit)
+    {
+        println!(
+            "(&{}).remove_factor(&{}) = {:?}",
+            n,
+            p,
+            (&n).remove_factor(&p)
+        );
+    }
+}
+
+fn benchmark_integer_valuation_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.valuation(&Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_pair_gen()
+            .get(gm, &config)
+            .filter(|(n, p)| *n != 0u32 && *p > 1u32),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_integer_bit_bucketer("n"),
+        &mut [
+            ("Integer.valuation(&Natural)", &mut |(n, p)| {
+                no_out!(n.valuation(&p))
+            }),
+            ("(&Integer).valuation(&Natural)", &mut |(n, p)| {
+                no_out!((&n).valuation(&p))
+            }),
+        ],
+    );
+}
+
+fn benchmark_integer_remove_factor_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.remove_factor(&Natural)",
+        BenchmarkType::EvaluationStrategy,
+        integer_natural_pair_gen()
+            .get(gm, &config)
+            .filter(|(n, p)| *n != 0u32 && *p > 1u32),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_integer_bit_bucketer("n"),
+        &mut [
+            ("Integer.remove_factor(&Natural)", &mut |(n, p)| {
+                no_out!(n.remove_factor(&p))
+            }),
+            ("(&Integer).remove_factor(&Natural)", &mut |(n, p)| {
+                no_out!((&n).remove_factor(&p))
+            }),
+        ],
+    );
+}
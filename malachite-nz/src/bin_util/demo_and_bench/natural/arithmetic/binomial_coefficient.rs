@@ -1,8 +1,17 @@
-use malachite_base::num::arithmetic::traits::BinomialCoefficient;
-use malachite_base::test_util::bench::bucketers::usize_convertible_pair_max_bucketer;
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, BinomialCoefficientMod, MultinomialCoefficient, MultinomialCoefficientMod,
+};
+use malachite_base::test_util::bench::bucketers::{
+    pair_1_vec_len_bucketer, triple_2_bucketer, usize_convertible_pair_max_bucketer,
+    vec_len_bucketer,
+};
 use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
 use malachite_base::test_util::generators::common::{GenConfig, GenMode};
-use malachite_base::test_util::generators::unsigned_pair_gen_var_28;
+use malachite_base::test_util::generators::{
+    unsigned_pair_gen_var_28, unsigned_triple_gen_var_13, unsigned_vec_gen,
+    unsigned_vec_unsigned_pair_gen,
+};
 use malachite_base::test_util::runner::Runner;
 use malachite_nz::natural::arithmetic::binomial_coefficient::*;
 use malachite_nz::natural::Natural;
@@ -31,6 +40,11 @@ pub(crate) fn register(runner: &mut Runner) {
     register_demo!(runner, demo_binomial_coefficient_limb_limb);
     register_demo!(runner, demo_natural_binomial_coefficient);
     register_demo!(runner, demo_natural_binomial_coefficient_ref);
+    register_demo!(runner, demo_natural_binomial_coefficient_mod);
+    register_demo!(runner, demo_natural_binomial_coefficient_mod_ref);
+    register_demo!(runner, demo_natural_multinomial_coefficient);
+    register_demo!(runner, demo_natural_multinomial_coefficient_mod);
+    register_demo!(runner, demo_natural_multinomial_coefficient_mod_ref);
 
     register_bench!(runner, benchmark_binomial_coefficient_limb_limb_algorithms);
     register_bench!(
@@ -42,6 +56,19 @@ pub(crate) fn register(runner: &mut Runner) {
         runner,
         benchmark_natural_binomial_coefficient_library_comparison
     );
+    register_bench!(
+        runner,
+        benchmark_natural_binomial_coefficient_mod_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_natural_binomial_coefficient_mod_algorithms
+    );
+    register_bench!(runner, benchmark_natural_multinomial_coefficient);
+    register_bench!(
+        runner,
+        benchmark_natural_multinomial_coefficient_mod_algorithms
+    );
 }
 
 fn demo_limbs_binomial_coefficient_limb_limb_bdiv(gm: GenMode, config: GenConfig, limit: usize) {
@@ -252,3 +279,212 @@ fn benchmark_natural_binomial_coefficient_library_comparison(
         ],
     );
 }
+
+fn demo_natural_binomial_coefficient_mod(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n, k) in unsigned_triple_gen_var_13::<u64, u64>()
+        .get(gm, &config)
+        .filter(|&(m, _, _)| m != 0)
+        .take(limit)
+    {
+        println!(
+            "C({}, {}) ≡ {} mod {}",
+            n,
+            k,
+            Natural::binomial_coefficient_mod(Natural::from(n), Natural::from(k), Natural::from(m)),
+            m
+        );
+    }
+}
+
+fn demo_natural_binomial_coefficient_mod_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n, k) in unsigned_triple_gen_var_13::<u64, u64>()
+        .get(gm, &config)
+        .filter(|&(m, _, _)| m != 0)
+        .take(limit)
+    {
+        println!(
+            "C({}, {}) ≡ {} mod {}",
+            n,
+            k,
+            Natural::binomial_coefficient_mod(
+                &Natural::from(n),
+                &Natural::from(k),
+                &Natural::from(m)
+            ),
+            m
+        );
+    }
+}
+
+fn demo_natural_multinomial_coefficient(gm: GenMode, config: GenConfig, limit: usize) {
+    for ks in unsigned_vec_gen::<u8>().get(gm, &config).take(limit) {
+        let ks = ks.into_iter().map(Natural::from).collect_vec();
+        println!(
+            "multinomial{:?} = {}",
+            ks,
+            Natural::multinomial_coefficient(&ks)
+        );
+    }
+}
+
+fn demo_natural_multinomial_coefficient_mod(gm: GenMode, config: GenConfig, limit: usize) {
+    for (ks, m) in unsigned_vec_unsigned_pair_gen::<u8, u64>()
+        .get(gm, &config)
+        .filter(|&(_, m)| m != 0)
+        .take(limit)
+    {
+        let ks = ks.into_iter().map(Natural::from).collect_vec();
+        println!(
+            "multinomial{:?} ≡ {} mod {}",
+            ks,
+            Natural::multinomial_coefficient_mod(&ks, Natural::from(m)),
+            m
+        );
+    }
+}
+
+fn demo_natural_multinomial_coefficient_mod_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (ks, m) in unsigned_vec_unsigned_pair_gen::<u8, u64>()
+        .get(gm, &config)
+        .filter(|&(_, m)| m != 0)
+        .take(limit)
+    {
+        let ks = ks.into_iter().map(Natural::from).collect_vec();
+        println!(
+            "multinomial{:?} ≡ {} mod {}",
+            ks,
+            Natural::multinomial_coefficient_mod(&ks, &Natural::from(m)),
+            m
+        );
+    }
+}
+
+fn benchmark_natural_binomial_coefficient_mod_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::binomial_coefficient_mod(Natural, Natural, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        unsigned_triple_gen_var_13::<u64, u64>()
+            .get(gm, &config)
+            .filter(|&(m, _, _)| m != 0),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_2_bucketer("n"),
+        &mut [
+            (
+                "Natural::binomial_coefficient_mod(Natural, Natural, Natural)",
+                &mut |(m, n, k)| {
+                    no_out!(Natural::binomial_coefficient_mod(
+                        Natural::from(n),
+                        Natural::from(k),
+                        Natural::from(m)
+                    ))
+                },
+            ),
+            (
+                "Natural::binomial_coefficient_mod(&Natural, &Natural, &Natural)",
+                &mut |(m, n, k)| {
+                    no_out!(Natural::binomial_coefficient_mod(
+                        &Natural::from(n),
+                        &Natural::from(k),
+                        &Natural::from(m)
+                    ))
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_natural_binomial_coefficient_mod_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::binomial_coefficient_mod(Natural, Natural, Natural)",
+        BenchmarkType::Algorithms,
+        unsigned_triple_gen_var_13::<u64, u64>()
+            .get(gm, &config)
+            .filter(|&(m, _, _)| m != 0),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_2_bucketer("n"),
+        &mut [
+            ("default", &mut |(m, n, k)| {
+                no_out!(Natural::binomial_coefficient_mod(
+                    Natural::from(n),
+                    Natural::from(k),
+                    Natural::from(m)
+                ))
+            }),
+            ("using binomial_coefficient", &mut |(m, n, k)| {
+                no_out!(
+                    Natural::binomial_coefficient(Natural::from(n), Natural::from(k))
+                        % Natural::from(m)
+                )
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_multinomial_coefficient(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::multinomial_coefficient(&[Natural])",
+        BenchmarkType::Single,
+        unsigned_vec_gen::<u8>()
+            .get(gm, &config)
+            .map(|ks| ks.into_iter().map(Natural::from).collect_vec()),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_len_bucketer(),
+        &mut [("Malachite", &mut |ks| {
+            no_out!(Natural::multinomial_coefficient(&ks))
+        })],
+    );
+}
+
+fn benchmark_natural_multinomial_coefficient_mod_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::multinomial_coefficient_mod(&[Natural], Natural)",
+        BenchmarkType::Algorithms,
+        unsigned_vec_unsigned_pair_gen::<u8, u64>()
+            .get(gm, &config)
+            .filter(|&(_, m)| m != 0)
+            .map(|(ks, m)| {
+                (
+                    ks.into_iter().map(Natural::from).collect_vec(),
+                    Natural::from(m),
+                )
+            }),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_vec_len_bucketer("ks"),
+        &mut [
+            ("default", &mut |(ks, m)| {
+                no_out!(Natural::multinomial_coefficient_mod(&ks, m))
+            }),
+            ("using multinomial_coefficient", &mut |(ks, m)| {
+                no_out!(Natural::multinomial_coefficient(&ks) % m)
+            }),
+        ],
+    );
+}
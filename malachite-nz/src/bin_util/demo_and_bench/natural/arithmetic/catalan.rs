@@ -0,0 +1,35 @@
+use malachite_base::num::arithmetic::traits::Catalan;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::catalan::catalan_naive;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_catalan);
+    register_bench!(runner, benchmark_catalan_algorithms);
+}
+
+fn demo_catalan(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("catalan({}) = {}", n, Natural::catalan(n));
+    }
+}
+
+fn benchmark_catalan_algorithms(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::catalan(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::catalan(n))),
+            ("naive", &mut |n| no_out!(catalan_naive(n))),
+        ],
+    );
+}
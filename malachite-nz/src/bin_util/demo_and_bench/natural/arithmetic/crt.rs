@@ -0,0 +1,95 @@
+use malachite_base::num::arithmetic::traits::{Crt, CrtPrecomputed};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::{
+    triple_natural_max_bit_bucketer, vec_natural_sum_bits_bucketer,
+};
+use malachite_nz::test_util::generators::{natural_triple_gen_var_6, natural_vec_gen};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_crt);
+    register_demo!(runner, demo_natural_crt_precomputed);
+
+    register_bench!(runner, benchmark_natural_crt);
+    register_bench!(runner, benchmark_natural_crt_precomputed_algorithms);
+}
+
+fn demo_natural_crt(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, a, b) in natural_triple_gen_var_6().get(gm, &config).take(limit) {
+        let congruences = [(x.clone(), a), (x + Natural::from(1u32), b)];
+        println!(
+            "Natural::crt({:?}) = {:?}",
+            congruences,
+            Natural::crt(&congruences)
+        );
+    }
+}
+
+fn demo_natural_crt_precomputed(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in natural_vec_gen()
+        .get(gm, &config)
+        .filter(|xs| !xs.contains(&Natural::from(0u32)))
+        .take(limit)
+    {
+        if let Some(basis) = Natural::precompute_crt_data(&xs) {
+            println!(
+                "Natural::crt_precomputed({:?}, {:?}) = {}",
+                xs,
+                xs,
+                Natural::crt_precomputed(&xs, &basis)
+            );
+        } else {
+            println!("{:?} are not pairwise coprime", xs);
+        }
+    }
+}
+
+fn benchmark_natural_crt(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::crt(&[(Natural, Natural), (Natural, Natural)])",
+        BenchmarkType::Single,
+        natural_triple_gen_var_6().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_natural_max_bit_bucketer("x", "a", "b"),
+        &mut [("Malachite", &mut |(x, a, b)| {
+            no_out!(Natural::crt(&[(x.clone(), a), (x, b)]))
+        })],
+    );
+}
+
+fn benchmark_natural_crt_precomputed_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::crt_precomputed(&[Natural], &CrtBasis)",
+        BenchmarkType::Algorithms,
+        natural_vec_gen()
+            .get(gm, &config)
+            .filter(|xs| !xs.contains(&Natural::from(0u32))),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_natural_sum_bits_bucketer(),
+        &mut [
+            ("crt", &mut |xs| {
+                let congruences = xs
+                    .iter()
+                    .map(|x| (x.clone(), x.clone()))
+                    .collect::<Vec<_>>();
+                no_out!(Natural::crt(&congruences))
+            }),
+            ("precompute_crt_data and crt_precomputed", &mut |xs| {
+                if let Some(basis) = Natural::precompute_crt_data(&xs) {
+                    no_out!(Natural::crt_precomputed(&xs, &basis))
+                }
+            }),
+        ],
+    );
+}
@@ -0,0 +1,52 @@
+use malachite_base::num::arithmetic::traits::{DivisorCount, DivisorSigma};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen_var_2;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_divisor_count);
+    register_demo!(runner, demo_natural_divisor_sigma);
+
+    register_bench!(runner, benchmark_natural_divisor_count);
+    register_bench!(runner, benchmark_natural_divisor_sigma);
+}
+
+fn demo_natural_divisor_count(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, &config).take(limit) {
+        println!("{}.divisor_count() = {}", n, n.divisor_count());
+    }
+}
+
+fn demo_natural_divisor_sigma(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen_var_2().get(gm, &config).take(limit) {
+        println!("{}.divisor_sigma(1) = {}", n, n.divisor_sigma(1));
+    }
+}
+
+fn benchmark_natural_divisor_count(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.divisor_count()",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_count()))],
+    );
+}
+
+fn benchmark_natural_divisor_sigma(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.divisor_sigma(1)",
+        BenchmarkType::Single,
+        natural_gen_var_2().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.divisor_sigma(1)))],
+    );
+}
@@ -1,5 +1,5 @@
 use malachite_base::num::arithmetic::traits::{
-    DoubleFactorial, Factorial, Multifactorial, Subfactorial,
+    DoubleFactorial, Factorial, FactorialMod, Multifactorial, Subfactorial,
 };
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::test_util::bench::bucketers::{
@@ -11,7 +11,10 @@ use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_ge
 use malachite_base::test_util::runner::Runner;
 use malachite_nz::natural::arithmetic::factorial::limbs_odd_factorial;
 use malachite_nz::natural::Natural;
-use malachite_nz::test_util::generators::unsigned_bool_pair_gen_var_1;
+use malachite_nz::test_util::bench::bucketers::pair_1_natural_bit_bucketer;
+use malachite_nz::test_util::generators::{
+    natural_unsigned_pair_gen_var_13, unsigned_bool_pair_gen_var_1,
+};
 use malachite_nz::test_util::natural::arithmetic::factorial::{
     double_factorial_naive, factorial_naive, multifactorial_naive,
 };
@@ -23,6 +26,8 @@ pub(crate) fn register(runner: &mut Runner) {
     register_demo!(runner, demo_double_factorial);
     register_demo!(runner, demo_multifactorial);
     register_demo!(runner, demo_subfactorial);
+    register_demo!(runner, demo_factorial_mod);
+    register_demo!(runner, demo_factorial_mod_ref);
 
     register_bench!(runner, benchmark_limbs_odd_factorial);
     register_bench!(runner, benchmark_factorial_algorithms);
@@ -32,6 +37,8 @@ pub(crate) fn register(runner: &mut Runner) {
     register_bench!(runner, benchmark_multifactorial_algorithms);
     register_bench!(runner, benchmark_multifactorial_library_comparison);
     register_bench!(runner, benchmark_subfactorial);
+    register_bench!(runner, benchmark_factorial_mod_algorithms);
+    register_bench!(runner, benchmark_factorial_mod_evaluation_strategy);
 }
 
 fn demo_limbs_odd_factorial(gm: GenMode, config: GenConfig, limit: usize) {
@@ -237,3 +244,72 @@ fn benchmark_subfactorial(gm: GenMode, config: GenConfig, limit: usize, file_nam
         &mut [("Malachite", &mut |n| no_out!(Natural::subfactorial(n)))],
     );
 }
+
+fn demo_factorial_mod(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n) in natural_unsigned_pair_gen_var_13::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let m_old = m.clone();
+        println!("{}! ≡ {} mod {}", n, Natural::factorial_mod(n, m), m_old);
+    }
+}
+
+fn demo_factorial_mod_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n) in natural_unsigned_pair_gen_var_13::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("{}! ≡ {} mod {}", n, Natural::factorial_mod(n, &m), m);
+    }
+}
+
+fn benchmark_factorial_mod_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::factorial_mod(u64, Natural)",
+        BenchmarkType::Algorithms,
+        natural_unsigned_pair_gen_var_13::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("m"),
+        &mut [
+            ("default", &mut |(m, n)| {
+                no_out!(Natural::factorial_mod(n, m))
+            }),
+            ("using factorial", &mut |(m, n)| {
+                no_out!(Natural::factorial(n) % m)
+            }),
+        ],
+    );
+}
+
+fn benchmark_factorial_mod_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::factorial_mod(u64, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_unsigned_pair_gen_var_13::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural::factorial_mod(u64, Natural)", &mut |(m, n)| {
+                no_out!(Natural::factorial_mod(n, m))
+            }),
+            ("Natural::factorial_mod(u64, &Natural)", &mut |(m, n)| {
+                no_out!(Natural::factorial_mod(n, &m))
+            }),
+        ],
+    );
+}
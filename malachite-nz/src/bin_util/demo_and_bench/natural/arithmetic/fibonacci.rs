@@ -0,0 +1,175 @@
+use malachite_base::num::arithmetic::traits::{Fibonacci, FibonacciMod};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_1_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_unsigned_pair_gen_var_13;
+use malachite_nz::test_util::natural::arithmetic::fibonacci::fibonacci_pair_naive;
+use rug::Complete;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_fibonacci);
+    register_demo!(runner, demo_fibonacci_pair);
+    register_demo!(runner, demo_fibonacci_mod);
+    register_demo!(runner, demo_fibonacci_mod_ref);
+
+    register_bench!(runner, benchmark_fibonacci_library_comparison);
+    register_bench!(runner, benchmark_fibonacci_algorithms);
+    register_bench!(runner, benchmark_fibonacci_pair_library_comparison);
+    register_bench!(runner, benchmark_fibonacci_mod_algorithms);
+    register_bench!(runner, benchmark_fibonacci_mod_evaluation_strategy);
+}
+
+fn demo_fibonacci(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("F({}) = {}", n, Natural::fibonacci(n));
+    }
+}
+
+fn demo_fibonacci_pair(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!(
+            "(F({}), F({} + 1)) = {:?}",
+            n,
+            n,
+            Natural::fibonacci_pair(n)
+        );
+    }
+}
+
+fn demo_fibonacci_mod(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n) in natural_unsigned_pair_gen_var_13::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let m_old = m.clone();
+        println!("F({}) ≡ {} mod {}", n, Natural::fibonacci_mod(n, m), m_old);
+    }
+}
+
+fn demo_fibonacci_mod_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n) in natural_unsigned_pair_gen_var_13::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("F({}) ≡ {} mod {}", n, Natural::fibonacci_mod(n, &m), m);
+    }
+}
+
+fn benchmark_fibonacci_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::fibonacci(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::fibonacci(n))),
+            ("rug", &mut |n| {
+                no_out!(rug::Integer::fibonacci(u32::exact_from(n)).complete())
+            }),
+        ],
+    );
+}
+
+fn benchmark_fibonacci_algorithms(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::fibonacci(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::fibonacci(n))),
+            ("naive", &mut |n| no_out!(fibonacci_pair_naive(n))),
+        ],
+    );
+}
+
+fn benchmark_fibonacci_pair_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::fibonacci_pair(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::fibonacci_pair(n))),
+            ("rug", &mut |n| {
+                no_out!(<(rug::Integer, rug::Integer)>::from(
+                    rug::Integer::fibonacci_2(u32::exact_from(n))
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_fibonacci_mod_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::fibonacci_mod(u64, Natural)",
+        BenchmarkType::Algorithms,
+        natural_unsigned_pair_gen_var_13::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("m"),
+        &mut [
+            ("default", &mut |(m, n)| {
+                no_out!(Natural::fibonacci_mod(n, m))
+            }),
+            ("using fibonacci", &mut |(m, n)| {
+                no_out!(Natural::fibonacci(n) % m)
+            }),
+        ],
+    );
+}
+
+fn benchmark_fibonacci_mod_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::fibonacci_mod(u64, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_unsigned_pair_gen_var_13::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural::fibonacci_mod(u64, Natural)", &mut |(m, n)| {
+                no_out!(Natural::fibonacci_mod(n, m))
+            }),
+            ("Natural::fibonacci_mod(u64, &Natural)", &mut |(m, n)| {
+                no_out!(Natural::fibonacci_mod(n, &m))
+            }),
+        ],
+    );
+}
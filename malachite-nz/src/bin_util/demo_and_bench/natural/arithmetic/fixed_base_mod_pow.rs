@@ -0,0 +1,68 @@
+use malachite_base::num::arithmetic::traits::ModPow;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPowTable;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_triple_gen_var_5;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_fixed_base_mod_pow);
+
+    register_bench!(runner, benchmark_natural_fixed_base_mod_pow_algorithms);
+}
+
+fn demo_natural_fixed_base_mod_pow(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, &config).take(limit) {
+        let table = FixedBaseModPowTable::new(&x, &m, exp.significant_bits());
+        println!(
+            "{}^{} ≡ {} mod {} (computed with a fixed-base table)",
+            x,
+            exp,
+            table.pow(&exp),
+            m
+        );
+    }
+}
+
+// Each benchmarked function raises the same base to several exponents, so that the cost of
+// building the table is amortized, as it would be when many powers of a fixed generator are needed.
+fn benchmark_natural_fixed_base_mod_pow_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    const REPETITIONS: u32 = 100;
+    run_benchmark(
+        "FixedBaseModPowTable.pow(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            (
+                "repeated Natural.mod_pow(&Natural, &Natural)",
+                &mut |(x, exp, m)| {
+                    for i in 0..REPETITIONS {
+                        no_out!((&x).mod_pow(&exp + Natural::from(i), &m))
+                    }
+                },
+            ),
+            (
+                "repeated FixedBaseModPowTable.pow(&Natural)",
+                &mut |(x, exp, m)| {
+                    let max_exp_bits = (&exp + Natural::from(REPETITIONS)).significant_bits();
+                    let table = FixedBaseModPowTable::new(&x, &m, max_exp_bits);
+                    for i in 0..REPETITIONS {
+                        no_out!(table.pow(&(&exp + Natural::from(i))))
+                    }
+                },
+            ),
+        ],
+    );
+}
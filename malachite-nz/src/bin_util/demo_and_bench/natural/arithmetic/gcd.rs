@@ -1,4 +1,5 @@
-use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign};
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign, GcdOf};
+use malachite_base::num::basic::traits::Zero;
 use malachite_base::test_util::bench::bucketers::{
     pair_1_vec_len_bucketer, quadruple_3_vec_len_bucketer, quadruple_max_bit_bucketer,
     unsigned_direct_bucketer,
@@ -17,14 +18,16 @@ use malachite_nz::natural::arithmetic::gcd::matrix_2_2::{
     limbs_matrix_2_2_mul, limbs_matrix_2_2_mul_small, limbs_matrix_2_2_mul_strassen,
     limbs_matrix_mul_2_2_scratch_len,
 };
+use malachite_nz::natural::Natural;
 use malachite_nz::test_util::bench::bucketers::{
     limbs_matrix_2_2_mul_bucketer, pair_1_half_gcd_matrix_bucketer, pair_natural_max_bit_bucketer,
     triple_1_half_gcd_matrix_bucketer, triple_3_pair_natural_max_bit_bucketer,
+    vec_natural_sum_bits_bucketer,
 };
 use malachite_nz::test_util::generators::{
     large_type_gen_var_5, large_type_gen_var_6, large_type_gen_var_7, large_type_gen_var_8,
     natural_pair_gen, natural_pair_gen_nrm, natural_pair_gen_var_4, natural_pair_gen_var_4_nrm,
-    unsigned_vec_pair_gen_var_10,
+    natural_vec_gen, unsigned_vec_pair_gen_var_10,
 };
 use malachite_nz::test_util::natural::arithmetic::gcd::{
     gcd_binary_nz, gcd_euclidean_nz, limbs_gcd_div_alt, limbs_gcd_div_naive, OwnedHalfGcdMatrix,
@@ -47,6 +50,8 @@ pub(crate) fn register(runner: &mut Runner) {
     register_demo!(runner, demo_natural_gcd_assign);
     register_demo!(runner, demo_natural_gcd_assign_ref);
     register_demo!(runner, demo_natural_gcd_2);
+    register_demo!(runner, demo_natural_gcd_of);
+    register_demo!(runner, demo_natural_gcd_of_ref);
 
     register_bench!(runner, benchmark_limbs_gcd_limb);
     register_bench!(runner, benchmark_half_gcd_matrix_init);
@@ -63,6 +68,8 @@ pub(crate) fn register(runner: &mut Runner) {
     register_bench!(runner, benchmark_natural_gcd_algorithms_2);
     register_bench!(runner, benchmark_natural_gcd_library_comparison_2);
     register_bench!(runner, benchmark_natural_gcd_evaluation_strategy_2);
+    register_bench!(runner, benchmark_natural_gcd_of_algorithms);
+    register_bench!(runner, benchmark_natural_gcd_of_evaluation_strategy);
 }
 
 fn demo_limbs_gcd_limb(gm: GenMode, config: GenConfig, limit: usize) {
@@ -632,3 +639,69 @@ fn benchmark_natural_gcd_assign_evaluation_strategy(
         ],
     );
 }
+
+fn demo_natural_gcd_of(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in natural_vec_gen().get(gm, &config).take(limit) {
+        println!(
+            "gcd_of({:?}) = {}",
+            xs.clone(),
+            Natural::gcd_of(xs.into_iter())
+        );
+    }
+}
+
+fn demo_natural_gcd_of_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in natural_vec_gen().get(gm, &config).take(limit) {
+        println!("gcd_of({:?}) = {}", xs, Natural::gcd_of(xs.iter()));
+    }
+}
+
+fn benchmark_natural_gcd_of_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::gcd_of(Iterator<Item=Natural>)",
+        BenchmarkType::Algorithms,
+        natural_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_natural_sum_bits_bucketer(),
+        &mut [
+            ("default", &mut |xs| {
+                no_out!(Natural::gcd_of(xs.into_iter()))
+            }),
+            ("left fold", &mut |xs| {
+                no_out!(xs.into_iter().fold(Natural::ZERO, |acc, x| acc.gcd(x)))
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_gcd_of_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::gcd_of(Iterator<Item=Natural>)",
+        BenchmarkType::EvaluationStrategy,
+        natural_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_natural_sum_bits_bucketer(),
+        &mut [
+            ("Natural::gcd_of(Iterator<Item=Natural>)", &mut |xs| {
+                no_out!(Natural::gcd_of(xs.into_iter()))
+            }),
+            ("Natural::gcd_of(Iterator<Item=&Natural>)", &mut |xs| {
+                no_out!(Natural::gcd_of(xs.iter()))
+            }),
+        ],
+    );
+}
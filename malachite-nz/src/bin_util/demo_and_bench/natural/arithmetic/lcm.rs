@@ -1,11 +1,16 @@
-use malachite_base::num::arithmetic::traits::{Lcm, LcmAssign};
+use malachite_base::num::arithmetic::traits::{Lcm, LcmAssign, LcmOf};
+use malachite_base::num::basic::traits::One;
 use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
 use malachite_base::test_util::generators::common::{GenConfig, GenMode};
 use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
 use malachite_nz::test_util::bench::bucketers::{
     pair_natural_max_bit_bucketer, triple_3_pair_natural_max_bit_bucketer,
+    vec_natural_sum_bits_bucketer,
+};
+use malachite_nz::test_util::generators::{
+    natural_pair_gen, natural_pair_gen_nrm, natural_vec_gen,
 };
-use malachite_nz::test_util::generators::{natural_pair_gen, natural_pair_gen_nrm};
 use num::Integer;
 
 pub(crate) fn register(runner: &mut Runner) {
@@ -15,10 +20,14 @@ pub(crate) fn register(runner: &mut Runner) {
     register_demo!(runner, demo_natural_lcm_ref_ref);
     register_demo!(runner, demo_natural_lcm_assign);
     register_demo!(runner, demo_natural_lcm_assign_ref);
+    register_demo!(runner, demo_natural_lcm_of);
+    register_demo!(runner, demo_natural_lcm_of_ref);
 
     register_bench!(runner, benchmark_natural_lcm_library_comparison);
     register_bench!(runner, benchmark_natural_lcm_evaluation_strategy);
     register_bench!(runner, benchmark_natural_lcm_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_natural_lcm_of_algorithms);
+    register_bench!(runner, benchmark_natural_lcm_of_evaluation_strategy);
 }
 
 fn demo_natural_lcm(gm: GenMode, config: GenConfig, limit: usize) {
@@ -135,3 +144,69 @@ fn benchmark_natural_lcm_assign_evaluation_strategy(
         ],
     );
 }
+
+fn demo_natural_lcm_of(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in natural_vec_gen().get(gm, &config).take(limit) {
+        println!(
+            "lcm_of({:?}) = {}",
+            xs.clone(),
+            Natural::lcm_of(xs.into_iter())
+        );
+    }
+}
+
+fn demo_natural_lcm_of_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for xs in natural_vec_gen().get(gm, &config).take(limit) {
+        println!("lcm_of({:?}) = {}", xs, Natural::lcm_of(xs.iter()));
+    }
+}
+
+fn benchmark_natural_lcm_of_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::lcm_of(Iterator<Item=Natural>)",
+        BenchmarkType::Algorithms,
+        natural_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_natural_sum_bits_bucketer(),
+        &mut [
+            ("default", &mut |xs| {
+                no_out!(Natural::lcm_of(xs.into_iter()))
+            }),
+            ("left fold", &mut |xs| {
+                no_out!(xs.into_iter().fold(Natural::ONE, |acc, x| acc.lcm(x)))
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_lcm_of_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::lcm_of(Iterator<Item=Natural>)",
+        BenchmarkType::EvaluationStrategy,
+        natural_vec_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_natural_sum_bits_bucketer(),
+        &mut [
+            ("Natural::lcm_of(Iterator<Item=Natural>)", &mut |xs| {
+                no_out!(Natural::lcm_of(xs.into_iter()))
+            }),
+            ("Natural::lcm_of(Iterator<Item=&Natural>)", &mut |xs| {
+                no_out!(Natural::lcm_of(xs.iter()))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,193 @@
+use malachite_base::num::arithmetic::traits::{Lucas, LucasMod, LucasSequenceMod};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::{
+    pair_1_natural_bit_bucketer, quadruple_4_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{
+    natural_quadruple_gen_var_1, natural_unsigned_pair_gen_var_13,
+};
+use malachite_nz::test_util::natural::arithmetic::fibonacci::lucas_pair_naive;
+use rug::Complete;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_lucas);
+    register_demo!(runner, demo_lucas_pair);
+    register_demo!(runner, demo_lucas_mod);
+    register_demo!(runner, demo_lucas_mod_ref);
+    register_demo!(runner, demo_lucas_sequence_mod);
+    register_demo!(runner, demo_lucas_sequence_mod_ref);
+
+    register_bench!(runner, benchmark_lucas_library_comparison);
+    register_bench!(runner, benchmark_lucas_algorithms);
+    register_bench!(runner, benchmark_lucas_pair_library_comparison);
+    register_bench!(runner, benchmark_lucas_mod_algorithms);
+    register_bench!(runner, benchmark_lucas_sequence_mod_evaluation_strategy);
+}
+
+fn demo_lucas(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("L({}) = {}", n, Natural::lucas(n));
+    }
+}
+
+fn demo_lucas_pair(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("(L({}), L({} + 1)) = {:?}", n, n, Natural::lucas_pair(n));
+    }
+}
+
+fn demo_lucas_mod(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n) in natural_unsigned_pair_gen_var_13::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let m_old = m.clone();
+        println!("L({}) ≡ {} mod {}", n, Natural::lucas_mod(n, m), m_old);
+    }
+}
+
+fn demo_lucas_mod_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (m, n) in natural_unsigned_pair_gen_var_13::<u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!("L({}) ≡ {} mod {}", n, Natural::lucas_mod(n, &m), m);
+    }
+}
+
+fn demo_lucas_sequence_mod(gm: GenMode, config: GenConfig, limit: usize) {
+    for (k, p, q, m) in natural_quadruple_gen_var_1().get(gm, &config).take(limit) {
+        let k_old = k.clone();
+        let p_old = p.clone();
+        let q_old = q.clone();
+        let m_old = m.clone();
+        let (u, v) = k.lucas_sequence_mod(p, q, m);
+        println!(
+            "(U_{}({}, {}), V_{}({}, {})) ≡ ({}, {}) mod {}",
+            k_old, p_old, q_old, k_old, p_old, q_old, u, v, m_old
+        );
+    }
+}
+
+fn demo_lucas_sequence_mod_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (k, p, q, m) in natural_quadruple_gen_var_1().get(gm, &config).take(limit) {
+        let (u, v) = (&k).lucas_sequence_mod(&p, &q, &m);
+        println!(
+            "(U_{}({}, {}), V_{}({}, {})) ≡ ({}, {}) mod {}",
+            k, p, q, k, p, q, u, v, m
+        );
+    }
+}
+
+fn benchmark_lucas_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::lucas(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::lucas(n))),
+            ("rug", &mut |n| {
+                no_out!(rug::Integer::lucas(u32::exact_from(n)).complete())
+            }),
+        ],
+    );
+}
+
+fn benchmark_lucas_algorithms(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::lucas(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::lucas(n))),
+            ("naive", &mut |n| no_out!(lucas_pair_naive(n))),
+        ],
+    );
+}
+
+fn benchmark_lucas_pair_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::lucas_pair(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::lucas_pair(n))),
+            ("rug", &mut |n| {
+                no_out!(<(rug::Integer, rug::Integer)>::from(rug::Integer::lucas_2(
+                    u32::exact_from(n)
+                )))
+            }),
+        ],
+    );
+}
+
+fn benchmark_lucas_mod_algorithms(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::lucas_mod(u64, Natural)",
+        BenchmarkType::Algorithms,
+        natural_unsigned_pair_gen_var_13::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("m"),
+        &mut [
+            ("default", &mut |(m, n)| no_out!(Natural::lucas_mod(n, m))),
+            ("using lucas", &mut |(m, n)| no_out!(Natural::lucas(n) % m)),
+        ],
+    );
+}
+
+fn benchmark_lucas_sequence_mod_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.lucas_sequence_mod(Natural, Natural, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_quadruple_gen_var_1().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_4_natural_bit_bucketer("m"),
+        &mut [
+            (
+                "Natural.lucas_sequence_mod(Natural, Natural, Natural)",
+                &mut |(k, p, q, m)| no_out!(k.lucas_sequence_mod(p, q, m)),
+            ),
+            (
+                "(&Natural).lucas_sequence_mod(&Natural, &Natural, &Natural)",
+                &mut |(k, p, q, m)| no_out!((&k).lucas_sequence_mod(&p, &q, &m)),
+            ),
+        ],
+    );
+}
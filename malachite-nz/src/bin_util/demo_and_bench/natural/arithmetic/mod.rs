@@ -4,19 +4,26 @@ pub(crate) fn register(runner: &mut Runner) {
     add::register(runner);
     add_mul::register(runner);
     binomial_coefficient::register(runner);
+    carmichael_lambda::register(runner);
+    catalan::register(runner);
     checked_sub::register(runner);
     checked_sub_mul::register(runner);
     coprime_with::register(runner);
+    crt::register(runner);
     div::register(runner);
     div_exact::register(runner);
     div_mod::register(runner);
     div_round::register(runner);
     divisible_by::register(runner);
     divisible_by_power_of_2::register(runner);
+    divisor_sigma::register(runner);
+    divisors::register(runner);
     eq_mod::register(runner);
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     factorial::register(runner);
+    fibonacci::register(runner);
+    fixed_base_mod_pow::register(runner);
     gcd::register(runner);
     is_power_of_2::register(runner);
     kronecker_symbol::register(runner);
@@ -24,10 +31,13 @@ pub(crate) fn register(runner: &mut Runner) {
     log_base::register(runner);
     log_base_2::register(runner);
     log_base_power_of_2::register(runner);
+    lucas::register(runner);
     mod_add::register(runner);
     mod_inverse::register(runner);
     mod_is_reduced::register(runner);
+    mod_log::register(runner);
     mod_mul::register(runner);
+    mod_multi_pow::register(runner);
     mod_neg::register(runner);
     mod_op::register(runner);
     mod_pow::register(runner);
@@ -44,15 +54,24 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_power_of_2_sub::register(runner);
     mod_shl::register(runner);
     mod_shr::register(runner);
+    mod_sqrt::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
+    moebius_mu::register(runner);
+    montgomery::register(runner);
     mul::register(runner);
+    multiplicative_order::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
     parity::register(runner);
+    partition_count::register(runner);
+    perfect_power::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
+    precomputed_divisor::register(runner);
+    primitive_root::register(runner);
     primorial::register(runner);
+    rational_reconstruction::register(runner);
     root::register(runner);
     round_to_multiple::register(runner);
     round_to_multiple_of_power_of_2::register(runner);
@@ -65,26 +84,36 @@ pub(crate) fn register(runner: &mut Runner) {
     sign::register(runner);
     sqrt::register(runner);
     square::register(runner);
+    stirling::register(runner);
     sub::register(runner);
     sub_mul::register(runner);
+    totient::register(runner);
+    valuation::register(runner);
 }
 
 mod add;
 mod add_mul;
 mod binomial_coefficient;
+mod carmichael_lambda;
+mod catalan;
 mod checked_sub;
 mod checked_sub_mul;
 mod coprime_with;
+mod crt;
 mod div;
 mod div_exact;
 mod div_mod;
 mod div_round;
 mod divisible_by;
 mod divisible_by_power_of_2;
+mod divisor_sigma;
+mod divisors;
 mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod factorial;
+mod fibonacci;
+mod fixed_base_mod_pow;
 mod gcd;
 mod is_power_of_2;
 mod kronecker_symbol;
@@ -92,10 +121,13 @@ mod lcm;
 mod log_base;
 mod log_base_2;
 mod log_base_power_of_2;
+mod lucas;
 mod mod_add;
 mod mod_inverse;
 mod mod_is_reduced;
+mod mod_log;
 mod mod_mul;
+mod mod_multi_pow;
 mod mod_neg;
 mod mod_op;
 mod mod_pow;
@@ -112,15 +144,24 @@ mod mod_power_of_2_square;
 mod mod_power_of_2_sub;
 mod mod_shl;
 mod mod_shr;
+mod mod_sqrt;
 mod mod_square;
 mod mod_sub;
+mod moebius_mu;
+mod montgomery;
 mod mul;
+mod multiplicative_order;
 mod neg;
 mod next_power_of_2;
 mod parity;
+mod partition_count;
+mod perfect_power;
 mod pow;
 mod power_of_2;
+mod precomputed_divisor;
+mod primitive_root;
 mod primorial;
+mod rational_reconstruction;
 mod root;
 mod round_to_multiple;
 mod round_to_multiple_of_power_of_2;
@@ -133,5 +174,8 @@ mod shr_round;
 mod sign;
 mod sqrt;
 mod square;
+mod stirling;
 mod sub;
 mod sub_mul;
+mod totient;
+mod valuation;
@@ -0,0 +1,83 @@
+use malachite_base::num::arithmetic::traits::ModLog;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_log);
+    register_demo!(runner, demo_natural_mod_log_ref_ref_ref);
+
+    register_bench!(runner, benchmark_natural_mod_log_evaluation_strategy);
+}
+
+// Discrete logarithms modulo large random numbers are infeasible, so the inputs are taken from
+// triples of `u32`s.
+fn to_naturals((x, base, m): (u32, u32, u32)) -> (Natural, Natural, Natural) {
+    (Natural::from(x), Natural::from(base), Natural::from(m))
+}
+
+fn demo_natural_mod_log(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, base, m) in unsigned_triple_gen_var_12::<u32>()
+        .get(gm, &config)
+        .map(to_naturals)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let base_old = base.clone();
+        let m_old = m.clone();
+        println!(
+            "{}.mod_log({}, {}) = {:?}",
+            x_old,
+            base_old,
+            m_old,
+            x.mod_log(base, m)
+        );
+    }
+}
+
+fn demo_natural_mod_log_ref_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, base, m) in unsigned_triple_gen_var_12::<u32>()
+        .get(gm, &config)
+        .map(to_naturals)
+        .take(limit)
+    {
+        println!(
+            "(&{}).mod_log(&{}, &{}) = {:?}",
+            x,
+            base,
+            m,
+            (&x).mod_log(&base, &m)
+        );
+    }
+}
+
+fn benchmark_natural_mod_log_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_log(Natural, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        unsigned_triple_gen_var_12::<u32>()
+            .get(gm, &config)
+            .map(to_naturals),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_log(Natural, Natural)", &mut |(x, base, m)| {
+                no_out!(x.mod_log(base, m))
+            }),
+            (
+                "(&Natural).mod_log(&Natural, &Natural)",
+                &mut |(x, base, m)| no_out!((&x).mod_log(&base, &m)),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,68 @@
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::mod_multi_pow::{
+    mod_multi_pow_naive, mod_multi_pow_pippenger, mod_multi_pow_straus,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_1_vec_natural_sum_bits_bucketer;
+use malachite_nz::test_util::generators::natural_vec_natural_pair_gen_var_4;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_multi_pow);
+
+    register_bench!(runner, benchmark_natural_mod_multi_pow_algorithms);
+}
+
+// Pairs up consecutive elements of `xs` as bases and exponents.
+fn to_powers(xs: &[Natural]) -> Vec<(Natural, Natural)> {
+    xs.chunks_exact(2)
+        .map(|pair| (pair[0].clone(), pair[1].clone()))
+        .collect()
+}
+
+fn demo_natural_mod_multi_pow(gm: GenMode, config: GenConfig, limit: usize) {
+    for (xs, m) in natural_vec_natural_pair_gen_var_4()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let powers = to_powers(&xs);
+        println!(
+            "Natural::mod_multi_pow({:?}, {}) = {}",
+            powers,
+            m,
+            Natural::mod_multi_pow(&powers, &m)
+        );
+    }
+}
+
+fn benchmark_natural_mod_multi_pow_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::mod_multi_pow(&[(Natural, Natural)], &Natural)",
+        BenchmarkType::Algorithms,
+        natural_vec_natural_pair_gen_var_4().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_vec_natural_sum_bits_bucketer(),
+        &mut [
+            ("default", &mut |(xs, m)| {
+                no_out!(Natural::mod_multi_pow(&to_powers(&xs), &m))
+            }),
+            ("naive", &mut |(xs, m)| {
+                no_out!(mod_multi_pow_naive(&to_powers(&xs), &m))
+            }),
+            ("Straus", &mut |(xs, m)| {
+                no_out!(mod_multi_pow_straus(&to_powers(&xs), &m))
+            }),
+            ("Pippenger", &mut |(xs, m)| {
+                no_out!(mod_multi_pow_pippenger(&to_powers(&xs), &m))
+            }),
+        ],
+    );
+}
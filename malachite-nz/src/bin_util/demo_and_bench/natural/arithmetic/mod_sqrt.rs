@@ -0,0 +1,74 @@
+use malachite_base::num::arithmetic::traits::{ModSqrt, ModSquare};
+use malachite_base::num::factorization::traits::NextPrime;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::mod_sqrt::{mod_sqrt_cipolla, mod_sqrt_tonelli_shanks};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_sqrt);
+    register_demo!(runner, demo_natural_mod_sqrt_ref_ref);
+
+    register_bench!(runner, benchmark_natural_mod_sqrt_prime_algorithms);
+}
+
+// Replaces the modulus with the next prime, so that factoring it is cheap.
+fn to_prime_modulus((x, m): (Natural, Natural)) -> (Natural, Natural) {
+    let p = m.next_prime();
+    (x % &p, p)
+}
+
+fn demo_natural_mod_sqrt(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, p) in natural_pair_gen_var_8()
+        .get(gm, &config)
+        .map(to_prime_modulus)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let p_old = p.clone();
+        println!("{}.mod_sqrt({}) = {:?}", x_old, p_old, x.mod_sqrt(p));
+    }
+}
+
+fn demo_natural_mod_sqrt_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, p) in natural_pair_gen_var_8()
+        .get(gm, &config)
+        .map(to_prime_modulus)
+        .take(limit)
+    {
+        println!("(&{}).mod_sqrt(&{}) = {:?}", x, p, (&x).mod_sqrt(&p));
+    }
+}
+
+fn benchmark_natural_mod_sqrt_prime_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "(&Natural).mod_sqrt_from_factors(&[(Natural, 1)])",
+        BenchmarkType::Algorithms,
+        natural_pair_gen_var_8()
+            .get(gm, &config)
+            .map(to_prime_modulus)
+            .filter(|(x, p)| *x != 0u32 && *p != 2u32)
+            .map(|(x, p)| (x.mod_square(&p), p)),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("p"),
+        &mut [
+            ("default", &mut |(x, p)| {
+                no_out!((&x).mod_sqrt_from_factors(&[(p, 1)]))
+            }),
+            ("Tonelli-Shanks", &mut |(x, p)| {
+                no_out!(mod_sqrt_tonelli_shanks(&x, &p))
+            }),
+            ("Cipolla", &mut |(x, p)| no_out!(mod_sqrt_cipolla(&x, &p))),
+        ],
+    );
+}
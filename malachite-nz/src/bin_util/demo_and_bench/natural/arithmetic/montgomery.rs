@@ -0,0 +1,124 @@
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::{natural_triple_gen_var_3, natural_triple_gen_var_5};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_montgomery_mul);
+    register_demo!(runner, demo_natural_montgomery_pow);
+
+    register_bench!(runner, benchmark_natural_montgomery_mul_algorithms);
+    register_bench!(runner, benchmark_natural_montgomery_pow_algorithms);
+}
+
+fn demo_natural_montgomery_mul(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_3().get(gm, &config).take(limit) {
+        let m = m | Natural::ONE;
+        let context = MontgomeryContext::new(&m);
+        let product = context.mul(&context.to_montgomery(&x), &context.to_montgomery(&y));
+        println!(
+            "{} * {} ≡ {} mod {} (computed in Montgomery form)",
+            x,
+            y,
+            context.from_montgomery(&product),
+            m
+        );
+    }
+}
+
+fn demo_natural_montgomery_pow(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, &config).take(limit) {
+        let m = m | Natural::ONE;
+        let context = MontgomeryContext::new(&m);
+        let power = context.pow(&context.to_montgomery(&x), &exp);
+        println!(
+            "{}^{} ≡ {} mod {} (computed in Montgomery form)",
+            x,
+            exp,
+            context.from_montgomery(&power),
+            m
+        );
+    }
+}
+
+// Each benchmarked function multiplies by `y` repeatedly, so that the cost of creating the context
+// and converting to and from Montgomery form is amortized, as it would be in a long chain of
+// multiplications.
+fn benchmark_natural_montgomery_mul_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    const CHAIN_LENGTH: usize = 100;
+    run_benchmark(
+        "MontgomeryContext.mul(&Natural, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_3()
+            .get(gm, &config)
+            .map(|(x, y, m)| (x, y, m | Natural::ONE)),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            (
+                "repeated Natural.mod_mul(&Natural, &Natural)",
+                &mut |(mut x, y, m)| {
+                    for _ in 0..CHAIN_LENGTH {
+                        x = x.mod_mul(&y, &m);
+                    }
+                    no_out!(x)
+                },
+            ),
+            (
+                "repeated MontgomeryContext.mul(&Natural, &Natural)",
+                &mut |(x, y, m)| {
+                    let context = MontgomeryContext::new(&m);
+                    let mut x = context.to_montgomery(&x);
+                    let y = context.to_montgomery(&y);
+                    for _ in 0..CHAIN_LENGTH {
+                        x = context.mul(&x, &y);
+                    }
+                    no_out!(context.from_montgomery(&x))
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_natural_montgomery_pow_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "MontgomeryContext.pow(&Natural, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_5()
+            .get(gm, &config)
+            .map(|(x, exp, m)| (x, exp, m | Natural::ONE)),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_pow(&Natural, &Natural)", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(&exp, &m))
+            }),
+            (
+                "MontgomeryContext.pow(&Natural, &Natural)",
+                &mut |(x, exp, m)| {
+                    let context = MontgomeryContext::new(&m);
+                    no_out!(context.from_montgomery(&context.pow(&context.to_montgomery(&x), &exp)))
+                },
+            ),
+        ],
+    );
+}
@@ -0,0 +1,68 @@
+use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_multiplicative_order);
+    register_demo!(runner, demo_natural_multiplicative_order_ref_ref);
+
+    register_bench!(
+        runner,
+        benchmark_natural_multiplicative_order_evaluation_strategy
+    );
+}
+
+fn demo_natural_multiplicative_order(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_8().get(gm, &config).take(limit) {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        println!(
+            "{}.multiplicative_order({}) = {:?}",
+            x_old,
+            m_old,
+            x.multiplicative_order(m)
+        );
+    }
+}
+
+fn demo_natural_multiplicative_order_ref_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_8().get(gm, &config).take(limit) {
+        println!(
+            "(&{}).multiplicative_order(&{}) = {:?}",
+            x,
+            m,
+            (&x).multiplicative_order(&m)
+        );
+    }
+}
+
+fn benchmark_natural_multiplicative_order_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.multiplicative_order(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_pair_gen_var_8().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            (
+                "Natural.multiplicative_order(Natural)",
+                &mut |(x, m): (Natural, Natural)| no_out!(x.multiplicative_order(m)),
+            ),
+            (
+                "(&Natural).multiplicative_order(&Natural)",
+                &mut |(x, m): (Natural, Natural)| no_out!((&x).multiplicative_order(&m)),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,40 @@
+use malachite_base::num::arithmetic::traits::PartitionCount;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::partition_count::partition_count_naive;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_partition_count);
+    register_bench!(runner, benchmark_partition_count_algorithms);
+}
+
+fn demo_partition_count(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("partition_count({}) = {}", n, Natural::partition_count(n));
+    }
+}
+
+fn benchmark_partition_count_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::partition_count(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::partition_count(n))),
+            ("naive", &mut |n| no_out!(partition_count_naive(n))),
+        ],
+    );
+}
@@ -0,0 +1,99 @@
+use malachite_base::num::arithmetic::traits::{IsPerfectPower, PerfectPowerDecompose};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::{
+    natural_bit_bucketer, pair_2_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{natural_gen, natural_gen_rm};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_is_perfect_power);
+    register_demo!(runner, demo_natural_perfect_power_decompose);
+    register_demo!(runner, demo_natural_perfect_power_decompose_ref);
+
+    register_bench!(
+        runner,
+        benchmark_natural_is_perfect_power_library_comparison
+    );
+    register_bench!(
+        runner,
+        benchmark_natural_perfect_power_decompose_evaluation_strategy
+    );
+}
+
+fn demo_natural_is_perfect_power(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{} is a perfect power", n);
+        } else {
+            println!("{} is not a perfect power", n);
+        }
+    }
+}
+
+fn demo_natural_perfect_power_decompose(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        println!(
+            "{}.perfect_power_decompose() = {:?}",
+            n.clone(),
+            n.perfect_power_decompose()
+        );
+    }
+}
+
+fn demo_natural_perfect_power_decompose_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        println!(
+            "(&{}).perfect_power_decompose() = {:?}",
+            n,
+            (&n).perfect_power_decompose()
+        );
+    }
+}
+
+fn benchmark_natural_is_perfect_power_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.is_perfect_power()",
+        BenchmarkType::LibraryComparison,
+        natural_gen_rm().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("n"),
+        &mut [
+            ("Malachite", &mut |(_, n)| no_out!(n.is_perfect_power())),
+            ("rug", &mut |(n, _)| no_out!(n.is_perfect_power())),
+        ],
+    );
+}
+
+fn benchmark_natural_perfect_power_decompose_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.perfect_power_decompose()",
+        BenchmarkType::EvaluationStrategy,
+        natural_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [
+            ("Natural.perfect_power_decompose()", &mut |n| {
+                no_out!(n.perfect_power_decompose())
+            }),
+            ("(&Natural).perfect_power_decompose()", &mut |n| {
+                no_out!((&n).perfect_power_decompose())
+            }),
+        ],
+    );
+}
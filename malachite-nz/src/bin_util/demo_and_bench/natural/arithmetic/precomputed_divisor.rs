@@ -0,0 +1,163 @@
+use malachite_base::num::arithmetic::traits::{DivExact, DivMod, Mod};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_5, natural_pair_gen_var_6};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_precomputed_divisor_div_mod);
+    register_demo!(runner, demo_natural_precomputed_divisor_mod_op);
+    register_demo!(runner, demo_natural_precomputed_divisor_div_exact);
+    register_demo!(runner, demo_natural_precomputed_divisor_divisible_by);
+
+    register_bench!(
+        runner,
+        benchmark_natural_precomputed_divisor_div_mod_algorithms
+    );
+    register_bench!(
+        runner,
+        benchmark_natural_precomputed_divisor_mod_op_algorithms
+    );
+    register_bench!(
+        runner,
+        benchmark_natural_precomputed_divisor_div_exact_algorithms
+    );
+}
+
+fn demo_natural_precomputed_divisor_div_mod(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in natural_pair_gen_var_5().get(gm, &config).take(limit) {
+        let d = PrecomputedDivisor::new(&y);
+        println!("{}.div_mod({}) = {:?}", x, y, d.div_mod(&x));
+    }
+}
+
+fn demo_natural_precomputed_divisor_mod_op(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in natural_pair_gen_var_5().get(gm, &config).take(limit) {
+        let d = PrecomputedDivisor::new(&y);
+        println!("{}.mod_op({}) = {}", x, y, d.mod_op(&x));
+    }
+}
+
+fn demo_natural_precomputed_divisor_div_exact(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in natural_pair_gen_var_6().get(gm, &config).take(limit) {
+        let d = PrecomputedDivisor::new(&y);
+        println!("{}.div_exact({}) = {}", x, y, d.div_exact(&x));
+    }
+}
+
+fn demo_natural_precomputed_divisor_divisible_by(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, y) in natural_pair_gen_var_5().get(gm, &config).take(limit) {
+        let d = PrecomputedDivisor::new(&y);
+        if d.divisible_by(&x) {
+            println!("{} is divisible by {}", x, y);
+        } else {
+            println!("{} is not divisible by {}", x, y);
+        }
+    }
+}
+
+// Each benchmarked function divides several numbers by the same divisor, so that the cost of the
+// precomputation is amortized, as it would be when reducing many numbers by a fixed modulus.
+const REPETITIONS: u32 = 100;
+
+fn benchmark_natural_precomputed_divisor_div_mod_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "PrecomputedDivisor.div_mod(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_pair_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("y"),
+        &mut [
+            ("repeated (&Natural).div_mod(&Natural)", &mut |(x, y)| {
+                for i in 0..REPETITIONS {
+                    no_out!((&x + Natural::from(i)).div_mod(&y))
+                }
+            }),
+            (
+                "repeated PrecomputedDivisor.div_mod(&Natural)",
+                &mut |(x, y)| {
+                    let d = PrecomputedDivisor::new(&y);
+                    for i in 0..REPETITIONS {
+                        no_out!(d.div_mod(&(&x + Natural::from(i))))
+                    }
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_natural_precomputed_divisor_mod_op_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "PrecomputedDivisor.mod_op(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_pair_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("y"),
+        &mut [
+            ("repeated (&Natural).mod_op(&Natural)", &mut |(x, y)| {
+                for i in 0..REPETITIONS {
+                    no_out!((&x + Natural::from(i)).mod_op(&y))
+                }
+            }),
+            (
+                "repeated PrecomputedDivisor.mod_op(&Natural)",
+                &mut |(x, y)| {
+                    let d = PrecomputedDivisor::new(&y);
+                    for i in 0..REPETITIONS {
+                        no_out!(d.mod_op(&(&x + Natural::from(i))))
+                    }
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_natural_precomputed_divisor_div_exact_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "PrecomputedDivisor.div_exact(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_pair_gen_var_6().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("y"),
+        &mut [
+            ("repeated (&Natural).div_exact(&Natural)", &mut |(x, y)| {
+                for _ in 0..REPETITIONS {
+                    no_out!((&x).div_exact(&y))
+                }
+            }),
+            (
+                "repeated PrecomputedDivisor.div_exact(&Natural)",
+                &mut |(x, y)| {
+                    let d = PrecomputedDivisor::new(&y);
+                    for _ in 0..REPETITIONS {
+                        no_out!(d.div_exact(&x))
+                    }
+                },
+            ),
+        ],
+    );
+}
@@ -0,0 +1,57 @@
+use malachite_base::num::arithmetic::traits::FloorSqrt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+use malachite_nz::test_util::natural::arithmetic::rational_reconstruction::*;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_rational_reconstruction);
+
+    register_bench!(runner, benchmark_natural_rational_reconstruction_algorithms);
+}
+
+fn default_bound(m: &Natural) -> Natural {
+    ((m - Natural::ONE) >> 1u32).floor_sqrt()
+}
+
+fn demo_natural_rational_reconstruction(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, m) in natural_pair_gen_var_8().get(gm, &config).take(limit) {
+        let bound = default_bound(&m);
+        if let Some((p, q)) = a.rational_reconstruction(&m, &bound, &bound) {
+            println!("{} ≡ {}/{} mod {}", a, p, q, m);
+        } else {
+            println!("{} is not a small fraction mod {}", a, m);
+        }
+    }
+}
+
+fn benchmark_natural_rational_reconstruction_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.rational_reconstruction(&Natural, &Natural, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_pair_gen_var_8()
+            .get(gm, &config)
+            .map(|(a, m)| (a, default_bound(&m), m)),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("default", &mut |(a, bound, m)| {
+                no_out!(a.rational_reconstruction(&m, &bound, &bound))
+            }),
+            ("simple", &mut |(a, bound, m)| {
+                no_out!(rational_reconstruction_simple(&a, &m, &bound, &bound))
+            }),
+        ],
+    );
+}
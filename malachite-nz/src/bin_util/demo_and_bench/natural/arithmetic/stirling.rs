@@ -0,0 +1,118 @@
+use malachite_base::num::arithmetic::traits::{Bell, StirlingFirst, StirlingSecond};
+use malachite_base::test_util::bench::bucketers::{
+    unsigned_direct_bucketer, usize_convertible_pair_max_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_gen_var_28};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::stirling::{
+    bell_naive, stirling_first_naive, stirling_second_naive,
+};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_stirling_first);
+    register_demo!(runner, demo_stirling_second);
+    register_demo!(runner, demo_bell);
+
+    register_bench!(runner, benchmark_stirling_first_algorithms);
+    register_bench!(runner, benchmark_stirling_second_algorithms);
+    register_bench!(runner, benchmark_bell_algorithms);
+}
+
+fn demo_stirling_first(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, k) in unsigned_pair_gen_var_28::<u64, u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!(
+            "stirling_first({}, {}) = {}",
+            n,
+            k,
+            Natural::stirling_first(n, k)
+        );
+    }
+}
+
+fn demo_stirling_second(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, k) in unsigned_pair_gen_var_28::<u64, u64>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        println!(
+            "stirling_second({}, {}) = {}",
+            n,
+            k,
+            Natural::stirling_second(n, k)
+        );
+    }
+}
+
+fn demo_bell(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("bell({}) = {}", n, Natural::bell(n));
+    }
+}
+
+fn benchmark_stirling_first_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::stirling_first(u64, u64)",
+        BenchmarkType::Algorithms,
+        unsigned_pair_gen_var_28::<u64, u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &usize_convertible_pair_max_bucketer("n", "k"),
+        &mut [
+            ("default", &mut |(n, k)| {
+                no_out!(Natural::stirling_first(n, k))
+            }),
+            ("naive", &mut |(n, k)| no_out!(stirling_first_naive(n, k))),
+        ],
+    );
+}
+
+fn benchmark_stirling_second_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::stirling_second(u64, u64)",
+        BenchmarkType::Algorithms,
+        unsigned_pair_gen_var_28::<u64, u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &usize_convertible_pair_max_bucketer("n", "k"),
+        &mut [
+            ("default", &mut |(n, k)| {
+                no_out!(Natural::stirling_second(n, k))
+            }),
+            ("naive", &mut |(n, k)| no_out!(stirling_second_naive(n, k))),
+        ],
+    );
+}
+
+fn benchmark_bell_algorithms(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::bell(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::bell(n))),
+            ("naive", &mut |n| no_out!(bell_naive(n))),
+        ],
+    );
+}
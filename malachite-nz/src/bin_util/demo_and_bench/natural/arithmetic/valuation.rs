@@ -0,0 +1,125 @@
+use malachite_base::num::arithmetic::traits::{RemoveFactor, Valuation};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_1_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_3;
+use malachite_nz::test_util::natural::arithmetic::valuation::remove_factor_naive;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_valuation);
+    register_demo!(runner, demo_natural_valuation_ref);
+    register_demo!(runner, demo_natural_remove_factor);
+    register_demo!(runner, demo_natural_remove_factor_ref);
+
+    register_bench!(runner, benchmark_natural_valuation_evaluation_strategy);
+    register_bench!(runner, benchmark_natural_remove_factor_algorithms);
+    register_bench!(runner, benchmark_natural_remove_factor_evaluation_strategy);
+}
+
+fn demo_natural_valuation(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_3().get(gm, &config).take(limit) {
+        let n_old = n.clone();
+        println!("{}.valuation(&{}) = {}", n_old, p, n.valuation(&p));
+    }
+}
+
+fn demo_natural_valuation_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_3().get(gm, &config).take(limit) {
+        println!("(&{}).valuation(&{}) = {}", n, p, (&n).valuation(&p));
+    }
+}
+
+fn demo_natural_remove_factor(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_3().get(gm, &config).take(limit) {
+        let n_old = n.clone();
+        println!(
+            "{}.remove_factor(&{}) = {:?}",
+            n_old,
+            p,
+            n.remove_factor(&p)
+        );
+    }
+}
+
+fn demo_natural_remove_factor_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (n, p) in natural_pair_gen_var_3().get(gm, &config).take(limit) {
+        println!(
+            "(&{}).remove_factor(&{}) = {:?}",
+            n,
+            p,
+            (&n).remove_factor(&p)
+        );
+    }
+}
+
+fn benchmark_natural_valuation_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.valuation(&Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_pair_gen_var_3().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("n"),
+        &mut [
+            ("Natural.valuation(&Natural)", &mut |(n, p)| {
+                no_out!(n.valuation(&p))
+            }),
+            ("(&Natural).valuation(&Natural)", &mut |(n, p)| {
+                no_out!((&n).valuation(&p))
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_remove_factor_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.remove_factor(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_pair_gen_var_3().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("n"),
+        &mut [
+            ("default", &mut |(n, p)| no_out!(n.remove_factor(&p))),
+            ("naive", &mut |(n, p)| no_out!(remove_factor_naive(n, &p))),
+        ],
+    );
+}
+
+fn benchmark_natural_remove_factor_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.remove_factor(&Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_pair_gen_var_3().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("n"),
+        &mut [
+            ("Natural.remove_factor(&Natural)", &mut |(n, p)| {
+                no_out!(n.remove_factor(&p))
+            }),
+            ("(&Natural).remove_factor(&Natural)", &mut |(n, p)| {
+                no_out!((&n).remove_factor(&p))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,60 @@
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_is_prime);
+
+    register_bench!(runner, benchmark_natural_is_prime);
+    register_bench!(runner, benchmark_natural_is_prime_u64_algorithms);
+}
+
+fn demo_natural_is_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        if n.is_prime() {
+            println!("{} is prime", n);
+        } else {
+            println!("{} is not prime", n);
+        }
+    }
+}
+
+fn benchmark_natural_is_prime(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.is_prime()",
+        BenchmarkType::Single,
+        natural_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.is_prime()))],
+    );
+}
+
+fn benchmark_natural_is_prime_u64_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.is_prime()",
+        BenchmarkType::Algorithms,
+        unsigned_gen::<u64>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("Natural", &mut |n| no_out!(Natural::from(n).is_prime())),
+            ("u64", &mut |n| no_out!(n.is_prime())),
+        ],
+    );
+}
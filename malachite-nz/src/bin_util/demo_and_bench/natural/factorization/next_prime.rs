@@ -0,0 +1,74 @@
+use malachite_base::num::factorization::traits::NextPrime;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::{
+    natural_bit_bucketer, pair_2_natural_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{natural_gen, natural_gen_rm};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_next_prime);
+    register_demo!(runner, demo_natural_next_prime_ref);
+
+    register_bench!(runner, benchmark_natural_next_prime_library_comparison);
+    register_bench!(runner, benchmark_natural_next_prime_evaluation_strategy);
+}
+
+fn demo_natural_next_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        let n_old = n.clone();
+        println!("{}.next_prime() = {}", n_old, n.next_prime());
+    }
+}
+
+fn demo_natural_next_prime_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        println!("(&{}).next_prime() = {}", n, (&n).next_prime());
+    }
+}
+
+#[allow(unused_must_use)]
+fn benchmark_natural_next_prime_library_comparison(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.next_prime()",
+        BenchmarkType::LibraryComparison,
+        natural_gen_rm().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("n"),
+        &mut [
+            ("Malachite", &mut |(_, n)| no_out!(n.next_prime())),
+            ("rug", &mut |(n, _)| no_out!(n.next_prime())),
+        ],
+    );
+}
+
+fn benchmark_natural_next_prime_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.next_prime()",
+        BenchmarkType::EvaluationStrategy,
+        natural_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [
+            ("Natural.next_prime()", &mut |n| no_out!(n.next_prime())),
+            ("(&Natural).next_prime()", &mut |n| {
+                no_out!((&n).next_prime())
+            }),
+        ],
+    );
+}
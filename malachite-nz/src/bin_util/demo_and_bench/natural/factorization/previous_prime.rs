@@ -0,0 +1,51 @@
+use malachite_base::num::factorization::traits::PreviousPrime;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_previous_prime);
+    register_demo!(runner, demo_natural_previous_prime_ref);
+
+    register_bench!(runner, benchmark_natural_previous_prime_evaluation_strategy);
+}
+
+fn demo_natural_previous_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        let n_old = n.clone();
+        println!("{}.previous_prime() = {:?}", n_old, n.previous_prime());
+    }
+}
+
+fn demo_natural_previous_prime_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, &config).take(limit) {
+        println!("(&{}).previous_prime() = {:?}", n, (&n).previous_prime());
+    }
+}
+
+fn benchmark_natural_previous_prime_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.previous_prime()",
+        BenchmarkType::EvaluationStrategy,
+        natural_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [
+            ("Natural.previous_prime()", &mut |n| {
+                no_out!(n.previous_prime())
+            }),
+            ("(&Natural).previous_prime()", &mut |n| {
+                no_out!((&n).previous_prime())
+            }),
+        ],
+    );
+}
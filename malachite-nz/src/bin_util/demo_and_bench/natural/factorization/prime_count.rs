@@ -0,0 +1,23 @@
+use malachite_base::num::factorization::traits::{NthPrime, PrimeCount};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_11};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_prime_count);
+    register_demo!(runner, demo_natural_nth_prime);
+}
+
+fn demo_natural_prime_count(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen::<u32>().get(gm, &config).take(limit) {
+        let n = Natural::from(n);
+        println!("{}.prime_count() = {}", n, n.prime_count());
+    }
+}
+
+fn demo_natural_nth_prime(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_11::<u64>().get(gm, &config).take(limit) {
+        println!("Natural::nth_prime({}) = {}", n, Natural::nth_prime(n));
+    }
+}
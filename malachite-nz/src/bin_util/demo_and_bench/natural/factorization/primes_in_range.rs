@@ -0,0 +1,80 @@
+use itertools::Itertools;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{NextPrime, PrimesInRange};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::pair_1_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_unsigned_pair_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_primes_in_range);
+    register_demo!(runner, demo_natural_primes_in_inclusive_range);
+
+    register_bench!(runner, benchmark_natural_primes_in_range_algorithms);
+}
+
+fn demo_natural_primes_in_range(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, d) in natural_unsigned_pair_gen::<u8>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let b = &a + Natural::from(d);
+        println!(
+            "primes_in_range({}, {}) = {:?}",
+            a,
+            b,
+            Natural::primes_in_range(&a, &b).collect_vec()
+        );
+    }
+}
+
+fn demo_natural_primes_in_inclusive_range(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, d) in natural_unsigned_pair_gen::<u8>()
+        .get(gm, &config)
+        .take(limit)
+    {
+        let b = &a + Natural::from(d);
+        println!(
+            "primes_in_inclusive_range({}, {}) = {:?}",
+            a,
+            b,
+            Natural::primes_in_inclusive_range(&a, &b).collect_vec()
+        );
+    }
+}
+
+fn benchmark_natural_primes_in_range_algorithms(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::primes_in_range(&Natural, &Natural)",
+        BenchmarkType::Algorithms,
+        natural_unsigned_pair_gen::<u8>().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_natural_bit_bucketer("a"),
+        &mut [
+            ("default", &mut |(a, d)| {
+                let b = &a + Natural::from(d);
+                no_out!(Natural::primes_in_range(&a, &b).count())
+            }),
+            ("using next_prime", &mut |(a, d)| {
+                let b = &a + Natural::from(d);
+                let first = if a <= 2u32 {
+                    Natural::TWO
+                } else {
+                    (a - Natural::ONE).next_prime()
+                };
+                no_out!(itertools::iterate(first, |p| p.next_prime())
+                    .take_while(|p| *p < b)
+                    .count())
+            }),
+        ],
+    );
+}
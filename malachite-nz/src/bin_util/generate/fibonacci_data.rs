@@ -0,0 +1,54 @@
+use malachite_base::num::arithmetic::traits::CheckedLucas;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::platform::Limb;
+
+fn fibonacci_table() -> usize {
+    let mut xs: Vec<Limb> = vec![0, 1];
+    loop {
+        let n = xs.len();
+        if let Some(x) = xs[n - 1].checked_add(xs[n - 2]) {
+            xs.push(x);
+        } else {
+            break;
+        }
+    }
+    println!(
+        "// This is equivalent to `__gmp_fib_table` in `mpn/fib_table.c`, GMP 6.2.1, without the \
+        leading"
+    );
+    println!("// $F_{{-1}}$.");
+    print!("pub const FIBONACCI_TABLE: [Limb; {}] = [", xs.len());
+    let mut first = true;
+    for &x in &xs {
+        if first {
+            first = false;
+        } else {
+            print!(", ");
+        }
+        print!("{:#x}", x);
+    }
+    println!("];");
+    let limit = xs.len() - 1;
+    println!("// This is equivalent to `FIB_TABLE_LIMIT` in `fib_table.h`, GMP 6.2.1.");
+    println!("pub const FIBONACCI_TABLE_LIMIT: u64 = {};", limit);
+    limit
+}
+
+fn lucas_limit(fibonacci_limit: usize) {
+    let mut limit = 0;
+    for n in 0..=u64::exact_from(fibonacci_limit) {
+        if Limb::checked_lucas(n).is_none() {
+            break;
+        }
+        limit = n;
+    }
+    println!("// This is equivalent to `FIB_TABLE_LUCNUM_LIMIT` in `fib_table.h`, GMP 6.2.1.");
+    println!("pub const LUCAS_TABLE_LIMIT: u64 = {};", limit);
+}
+
+pub(crate) fn generate_fibonacci_data() {
+    println!("// This section is created by fibonacci_data.rs.");
+    println!();
+    let limit = fibonacci_table();
+    lucas_limit(limit);
+}
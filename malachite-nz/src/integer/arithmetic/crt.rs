@@ -0,0 +1,146 @@
+use crate::integer::arithmetic::mod_op::{positive_modulus, residue_ref};
+use crate::integer::Integer;
+use crate::natural::arithmetic::crt::{crt_combine, CrtBasis};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Crt, CrtPrecomputed};
+use malachite_base::num::basic::traits::{One, Zero};
+
+impl Crt for Integer {
+    /// Solves a system of simultaneous congruences $x \equiv r_i \mod m_i$. The moduli need not be
+    /// pairwise coprime, and the residues need not be reduced or nonnegative.
+    ///
+    /// Returns the least nonnegative solution $x$ and the combined modulus
+    /// $L = \operatorname{lcm}(m_1, \ldots, m_k)$. Every solution is congruent to $x$ modulo $L$.
+    /// Returns `None` if the congruences are inconsistent. An empty system has the solution
+    /// $(0, 1)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n, k) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits of the moduli
+    /// and residues, and $k$ is `congruences.len()`.
+    ///
+    /// # Panics
+    /// Panics if any modulus is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Crt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::crt(&[]).to_debug_string(), "Some((0, 1))");
+    /// assert_eq!(
+    ///     Integer::crt(&[
+    ///         (Integer::from(-1), Integer::from(3)),
+    ///         (Integer::from(-1), Integer::from(5))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((14, 15))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::crt(&[
+    ///         (Integer::from(-7), Integer::from(4)),
+    ///         (Integer::from(-1), Integer::from(6))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((5, 12))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::crt(&[
+    ///         (Integer::from(-1), Integer::from(4)),
+    ///         (Integer::from(0), Integer::from(6))
+    ///     ]),
+    ///     None
+    /// );
+    /// ```
+    fn crt(congruences: &[(Integer, Integer)]) -> Option<(Integer, Integer)> {
+        let mut x = Natural::ZERO;
+        let mut lcm = Natural::ONE;
+        for (r, m) in congruences {
+            let m = positive_modulus(m);
+            (x, lcm) = crt_combine(x, lcm, residue_ref(r, m), m)?;
+        }
+        Some((Integer::from(x), Integer::from(lcm)))
+    }
+}
+
+impl CrtPrecomputed for Integer {
+    type Data = CrtBasis;
+
+    /// Precomputes a basis for reconstructing [`Integer`]s from their residues modulo the given
+    /// moduli.
+    ///
+    /// Returns `None` if the moduli are not pairwise coprime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n, k) = O(kn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits of the moduli,
+    /// and $k$ is `moduli.len()`.
+    ///
+    /// # Panics
+    /// Panics if any modulus is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::precompute_crt_data(&[Integer::from(3), Integer::from(5)]).is_some());
+    /// assert!(Integer::precompute_crt_data(&[Integer::from(4), Integer::from(6)]).is_none());
+    /// ```
+    fn precompute_crt_data(moduli: &[Integer]) -> Option<CrtBasis> {
+        let moduli: Vec<Natural> = moduli.iter().map(|m| positive_modulus(m).clone()).collect();
+        Natural::precompute_crt_data(&moduli)
+    }
+
+    /// Reconstructs an [`Integer`] from its residues modulo the moduli of a precomputed basis. The
+    /// residues need not be reduced or nonnegative.
+    ///
+    /// $f((r_1, \ldots, r_k), (m_1, \ldots, m_k)) = x$, where $0 \leq x < \prod_i m_i$ and
+    /// $x \equiv r_i \mod m_i$ for all $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n, k) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits of the moduli
+    /// and residues, and $k$ is `residues.len()`.
+    ///
+    /// # Panics
+    /// Panics if the number of residues is not equal to the number of moduli in the basis.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let basis =
+    ///     Integer::precompute_crt_data(&[Integer::from(3), Integer::from(5), Integer::from(7)])
+    ///         .unwrap();
+    /// assert_eq!(
+    ///     Integer::crt_precomputed(
+    ///         &[Integer::from(-1), Integer::from(-1), Integer::from(-1)],
+    ///         &basis
+    ///     ),
+    ///     104
+    /// );
+    /// ```
+    fn crt_precomputed(residues: &[Integer], data: &CrtBasis) -> Integer {
+        assert_eq!(residues.len(), data.moduli().len());
+        Integer::from(
+            data.reconstruct(
+                residues
+                    .iter()
+                    .zip(data.moduli().iter())
+                    .map(|(r, m)| residue_ref(r, m)),
+            ),
+        )
+    }
+}
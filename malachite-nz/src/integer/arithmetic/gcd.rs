@@ -0,0 +1,316 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign, GcdOf};
+
+impl Gcd<Integer> for Integer {
+    type Output = Natural;
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s, taking both by value.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$, which makes
+    /// sense if we interpret "greatest" to mean "greatest by the divisibility order". The GCD is
+    /// always nonnegative.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).gcd(Integer::from(5)), 1);
+    /// assert_eq!(Integer::from(12).gcd(Integer::from(-90)), 6);
+    /// assert_eq!(Integer::from(-12).gcd(Integer::from(-90)), 6);
+    /// ```
+    #[inline]
+    fn gcd(self, other: Integer) -> Natural {
+        self.abs.gcd(other.abs)
+    }
+}
+
+impl<'a> Gcd<&'a Integer> for Integer {
+    type Output = Natural;
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s, taking the first by value
+    /// and the second by reference.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$, which makes
+    /// sense if we interpret "greatest" to mean "greatest by the divisibility order". The GCD is
+    /// always nonnegative.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).gcd(&Integer::from(5)), 1);
+    /// assert_eq!(Integer::from(12).gcd(&Integer::from(-90)), 6);
+    /// assert_eq!(Integer::from(-12).gcd(&Integer::from(-90)), 6);
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Integer) -> Natural {
+        self.abs.gcd(&other.abs)
+    }
+}
+
+impl<'a> Gcd<Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s, taking the first by
+    /// reference and the second by value.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$, which makes
+    /// sense if we interpret "greatest" to mean "greatest by the divisibility order". The GCD is
+    /// always nonnegative.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).gcd(Integer::from(5)), 1);
+    /// assert_eq!((&Integer::from(12)).gcd(Integer::from(-90)), 6);
+    /// assert_eq!((&Integer::from(-12)).gcd(Integer::from(-90)), 6);
+    /// ```
+    #[inline]
+    fn gcd(self, other: Integer) -> Natural {
+        (&self.abs).gcd(other.abs)
+    }
+}
+
+impl<'a, 'b> Gcd<&'a Integer> for &'b Integer {
+    type Output = Natural;
+
+    /// Computes the GCD (greatest common divisor) of two [`Integer`]s, taking both by reference.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$, which makes
+    /// sense if we interpret "greatest" to mean "greatest by the divisibility order". The GCD is
+    /// always nonnegative.
+    ///
+    /// $$
+    /// f(x, y) = \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Gcd;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).gcd(&Integer::from(5)), 1);
+    /// assert_eq!((&Integer::from(12)).gcd(&Integer::from(-90)), 6);
+    /// assert_eq!((&Integer::from(-12)).gcd(&Integer::from(-90)), 6);
+    /// ```
+    #[inline]
+    fn gcd(self, other: &'a Integer) -> Natural {
+        (&self.abs).gcd(&other.abs)
+    }
+}
+
+impl GcdAssign<Integer> for Integer {
+    /// Replaces an [`Integer`] by its GCD (greatest common divisor) with another [`Integer`],
+    /// taking the [`Integer`] on the right-hand side by value.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$, which makes
+    /// sense if we interpret "greatest" to mean "greatest by the divisibility order". The GCD is
+    /// always nonnegative.
+    ///
+    /// $$
+    /// x \gets \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.gcd_assign(Integer::from(5));
+    /// assert_eq!(x, 1);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.gcd_assign(Integer::from(-90));
+    /// assert_eq!(x, 6);
+    /// ```
+    #[inline]
+    fn gcd_assign(&mut self, other: Integer) {
+        self.abs.gcd_assign(other.abs);
+        self.sign = true;
+    }
+}
+
+impl<'a> GcdAssign<&'a Integer> for Integer {
+    /// Replaces an [`Integer`] by its GCD (greatest common divisor) with another [`Integer`],
+    /// taking the [`Integer`] on the right-hand side by reference.
+    ///
+    /// The GCD of 0 and $n$, for any $n$, is $|n|$. In particular, $\gcd(0, 0) = 0$, which makes
+    /// sense if we interpret "greatest" to mean "greatest by the divisibility order". The GCD is
+    /// always nonnegative.
+    ///
+    /// $$
+    /// x \gets \gcd(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.gcd_assign(&Integer::from(5));
+    /// assert_eq!(x, 1);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.gcd_assign(&Integer::from(-90));
+    /// assert_eq!(x, 6);
+    /// ```
+    #[inline]
+    fn gcd_assign(&mut self, other: &'a Integer) {
+        self.abs.gcd_assign(&other.abs);
+        self.sign = true;
+    }
+}
+
+impl GcdOf<Integer> for Natural {
+    /// Computes the GCD (greatest common divisor) of all the [`Integer`]s in an iterator.
+    ///
+    /// The GCD of an empty iterator is 0. The iterator is only consumed until the GCD reaches 1.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \gcd(x_0, x_1, \ldots, x_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Integer::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::gcd_of(vec_from_str::<Integer>("[]").unwrap().into_iter()),
+    ///     0
+    /// );
+    /// assert_eq!(
+    ///     Natural::gcd_of(vec_from_str::<Integer>("[-12, 90, -42]").unwrap().into_iter()),
+    ///     6
+    /// );
+    /// assert_eq!(
+    ///     Natural::gcd_of(vec_from_str::<Integer>("[6, -10, 15]").unwrap().into_iter()),
+    ///     1
+    /// );
+    /// ```
+    #[inline]
+    fn gcd_of<I: Iterator<Item = Integer>>(xs: I) -> Natural {
+        Natural::gcd_of(xs.map(|x| x.abs))
+    }
+}
+
+impl<'a> GcdOf<&'a Integer> for Natural {
+    /// Computes the GCD (greatest common divisor) of all the [`Integer`]s in an iterator of
+    /// [`Integer`] references.
+    ///
+    /// The GCD of an empty iterator is 0. The iterator is only consumed until the GCD reaches 1.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \gcd(x_0, x_1, \ldots, x_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Integer::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::gcd_of(vec_from_str::<Integer>("[]").unwrap().iter()),
+    ///     0
+    /// );
+    /// assert_eq!(
+    ///     Natural::gcd_of(vec_from_str::<Integer>("[-12, 90, -42]").unwrap().iter()),
+    ///     6
+    /// );
+    /// assert_eq!(
+    ///     Natural::gcd_of(vec_from_str::<Integer>("[6, -10, 15]").unwrap().iter()),
+    ///     1
+    /// );
+    /// ```
+    #[inline]
+    fn gcd_of<I: Iterator<Item = &'a Integer>>(xs: I) -> Natural {
+        Natural::gcd_of(xs.map(Integer::unsigned_abs_ref))
+    }
+}
@@ -0,0 +1,308 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Lcm, LcmAssign, LcmOf};
+
+impl Lcm<Integer> for Integer {
+    type Output = Natural;
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s, taking both by value.
+    ///
+    /// The LCM is always nonnegative.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).lcm(Integer::from(5)), 15);
+    /// assert_eq!(Integer::from(12).lcm(Integer::from(-90)), 180);
+    /// assert_eq!(Integer::from(-12).lcm(Integer::from(-90)), 180);
+    /// ```
+    #[inline]
+    fn lcm(self, other: Integer) -> Natural {
+        self.abs.lcm(other.abs)
+    }
+}
+
+impl<'a> Lcm<&'a Integer> for Integer {
+    type Output = Natural;
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s, taking the first by value and
+    /// the second by reference.
+    ///
+    /// The LCM is always nonnegative.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).lcm(&Integer::from(5)), 15);
+    /// assert_eq!(Integer::from(12).lcm(&Integer::from(-90)), 180);
+    /// assert_eq!(Integer::from(-12).lcm(&Integer::from(-90)), 180);
+    /// ```
+    #[inline]
+    fn lcm(self, other: &'a Integer) -> Natural {
+        self.abs.lcm(&other.abs)
+    }
+}
+
+impl<'a> Lcm<Integer> for &'a Integer {
+    type Output = Natural;
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s, taking the first by reference
+    /// and the second by value.
+    ///
+    /// The LCM is always nonnegative.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).lcm(Integer::from(5)), 15);
+    /// assert_eq!((&Integer::from(12)).lcm(Integer::from(-90)), 180);
+    /// assert_eq!((&Integer::from(-12)).lcm(Integer::from(-90)), 180);
+    /// ```
+    #[inline]
+    fn lcm(self, other: Integer) -> Natural {
+        (&self.abs).lcm(other.abs)
+    }
+}
+
+impl<'a, 'b> Lcm<&'a Integer> for &'b Integer {
+    type Output = Natural;
+
+    /// Computes the LCM (least common multiple) of two [`Integer`]s, taking both by reference.
+    ///
+    /// The LCM is always nonnegative.
+    ///
+    /// $$
+    /// f(x, y) = \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lcm;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).lcm(&Integer::from(5)), 15);
+    /// assert_eq!((&Integer::from(12)).lcm(&Integer::from(-90)), 180);
+    /// assert_eq!((&Integer::from(-12)).lcm(&Integer::from(-90)), 180);
+    /// ```
+    #[inline]
+    fn lcm(self, other: &'a Integer) -> Natural {
+        (&self.abs).lcm(&other.abs)
+    }
+}
+
+impl LcmAssign<Integer> for Integer {
+    /// Replaces an [`Integer`] by its LCM (least common multiple) with another [`Integer`], taking
+    /// the [`Integer`] on the right-hand side by value.
+    ///
+    /// The LCM is always nonnegative.
+    ///
+    /// $$
+    /// x \gets \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.lcm_assign(Integer::from(5));
+    /// assert_eq!(x, 15);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.lcm_assign(Integer::from(-90));
+    /// assert_eq!(x, 180);
+    /// ```
+    #[inline]
+    fn lcm_assign(&mut self, other: Integer) {
+        self.abs.lcm_assign(other.abs);
+        self.sign = true;
+    }
+}
+
+impl<'a> LcmAssign<&'a Integer> for Integer {
+    /// Replaces an [`Integer`] by its LCM (least common multiple) with another [`Integer`], taking
+    /// the [`Integer`] on the right-hand side by reference.
+    ///
+    /// The LCM is always nonnegative.
+    ///
+    /// $$
+    /// x \gets \operatorname{lcm}(x, y).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.lcm_assign(&Integer::from(5));
+    /// assert_eq!(x, 15);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.lcm_assign(&Integer::from(-90));
+    /// assert_eq!(x, 180);
+    /// ```
+    #[inline]
+    fn lcm_assign(&mut self, other: &'a Integer) {
+        self.abs.lcm_assign(&other.abs);
+        self.sign = true;
+    }
+}
+
+impl LcmOf<Integer> for Natural {
+    /// Computes the LCM (least common multiple) of all the [`Integer`]s in an iterator.
+    ///
+    /// The LCM of an empty iterator is 1. If any of the [`Integer`]s is 0, the LCM is 0, and the
+    /// iterator is not consumed any further. The LCM is computed by combining adjacent pairs in a
+    /// balanced tree.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \operatorname{lcm}(x_0, x_1, \ldots, x_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Integer::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Integer>("[]").unwrap().into_iter()),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Integer>("[-12, 90, -42]").unwrap().into_iter()),
+    ///     1260
+    /// );
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Integer>("[6, 0, -15]").unwrap().into_iter()),
+    ///     0
+    /// );
+    /// ```
+    #[inline]
+    fn lcm_of<I: Iterator<Item = Integer>>(xs: I) -> Natural {
+        Natural::lcm_of(xs.map(|x| x.abs))
+    }
+}
+
+impl<'a> LcmOf<&'a Integer> for Natural {
+    /// Computes the LCM (least common multiple) of all the [`Integer`]s in an iterator of
+    /// [`Integer`] references.
+    ///
+    /// The LCM of an empty iterator is 1. If any of the [`Integer`]s is 0, the LCM is 0, and the
+    /// iterator is not consumed any further. The LCM is computed by combining adjacent pairs in a
+    /// balanced tree.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \operatorname{lcm}(x_0, x_1, \ldots, x_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Integer::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Integer>("[]").unwrap().iter()),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Integer>("[-12, 90, -42]").unwrap().iter()),
+    ///     1260
+    /// );
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Integer>("[6, 0, -15]").unwrap().iter()),
+    ///     0
+    /// );
+    /// ```
+    #[inline]
+    fn lcm_of<I: Iterator<Item = &'a Integer>>(xs: I) -> Natural {
+        Natural::lcm_of(xs.map(Integer::unsigned_abs_ref))
+    }
+}
@@ -11,6 +11,13 @@ pub mod add_mul;
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
 /// for computing the binomial coefficient of two numbers.
 pub mod binomial_coefficient;
+/// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
+/// trait for determining whether two numbers are coprime.
+pub mod coprime_with;
+/// Implementations of [`Crt`](malachite_base::num::arithmetic::traits::Crt) and
+/// [`CrtPrecomputed`](malachite_base::num::arithmetic::traits::CrtPrecomputed), traits for solving
+/// systems of simultaneous congruences using the Chinese Remainder Theorem.
+pub mod crt;
 /// Division of [`Integer`](super::Integer)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -50,11 +57,40 @@ pub mod eq_mod_power_of_2;
 /// Implementations of [`ExtendedGcd`](malachite_base::num::arithmetic::traits::ExtendedGcd), a
 /// trait for computing the extended GCD of two numbers.
 pub mod extended_gcd;
+/// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd),
+/// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), and
+/// [`GcdOf`](malachite_base::num::arithmetic::traits::GcdOf), traits for computing the GCD
+/// (greatest common divisor) of two or more numbers.
+pub mod gcd;
 /// Implementations of [`LegendreSymbol`](malachite_base::num::arithmetic::traits::LegendreSymbol),
 /// [`JacobiSymbol`](malachite_base::num::arithmetic::traits::JacobiSymbol), and
 /// [`KroneckerSymbol`](malachite_base::num::arithmetic::traits::KroneckerSymbol), traits for
 /// computing the Legendre, Jacobi, and Kronecker symbols of two numbers.
 pub mod kronecker_symbol;
+/// Implementations of [`Lcm`](malachite_base::num::arithmetic::traits::Lcm),
+/// [`LcmAssign`](malachite_base::num::arithmetic::traits::LcmAssign), and
+/// [`LcmOf`](malachite_base::num::arithmetic::traits::LcmOf), traits for computing the LCM (least
+/// common multiple) of two or more numbers.
+pub mod lcm;
+/// Implementations of [`ModAdd`](malachite_base::num::arithmetic::traits::ModAdd) and
+/// [`ModAddAssign`](malachite_base::num::arithmetic::traits::ModAddAssign), traits for adding two
+/// numbers modulo another number.
+pub mod mod_add;
+/// Implementations of [`ModCentered`](malachite_base::num::arithmetic::traits::ModCentered) and
+/// [`ModCenteredAssign`](malachite_base::num::arithmetic::traits::ModCenteredAssign), traits for
+/// finding the residue of a number modulo another number $m$ that lies in $(-m/2, m/2]$.
+pub mod mod_centered;
+/// Implementations of [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse), a trait
+/// for finding the multiplicative inverse of a number modulo another number.
+pub mod mod_inverse;
+/// Implementations of [`ModMul`](malachite_base::num::arithmetic::traits::ModMul) and
+/// [`ModMulAssign`](malachite_base::num::arithmetic::traits::ModMulAssign), traits for multiplying
+/// two numbers modulo another number.
+pub mod mod_mul;
+/// Implementations of [`ModNeg`](malachite_base::num::arithmetic::traits::ModNeg) and
+/// [`ModNegAssign`](malachite_base::num::arithmetic::traits::ModNegAssign), traits for negating a
+/// number modulo another number.
+pub mod mod_neg;
 /// Implementations of traits for finding the remainder of two numbers, subject to various rounding
 /// rules.
 ///
@@ -67,6 +103,10 @@ pub mod kronecker_symbol;
 ///
 /// The [`Rem`](std::ops::Rem) trait in the standard library rounds towards 0.
 pub mod mod_op;
+/// Implementations of [`ModPow`](malachite_base::num::arithmetic::traits::ModPow) and
+/// [`ModPowAssign`](malachite_base::num::arithmetic::traits::ModPowAssign), traits for raising a
+/// number to a power modulo another number.
+pub mod mod_pow;
 /// Implementations of traits for finding the remainder of a number divided by $2^k$, subject to
 /// various rounding rules.
 ///
@@ -78,6 +118,14 @@ pub mod mod_op;
 /// | towards 0 | [`RemPowerOf2`](malachite_base::num::arithmetic::traits::RemPowerOf2) | [`RemPowerOf2Assign`](malachite_base::num::arithmetic::traits::RemPowerOf2Assign)       |
 /// | towards $\infty$  | [`CeilingModPowerOf2`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2) | [`CeilingModPowerOf2Assign`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2Assign) |
 pub mod mod_power_of_2;
+/// Implementations of [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare) and
+/// [`ModSquareAssign`](malachite_base::num::arithmetic::traits::ModSquareAssign), traits for
+/// squaring a number modulo another number.
+pub mod mod_square;
+/// Implementations of [`ModSub`](malachite_base::num::arithmetic::traits::ModSub) and
+/// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
+/// two numbers modulo another number.
+pub mod mod_sub;
 /// Multiplication of [`Integer`](super::Integer)s.
 pub mod mul;
 /// Negation of an [`Integer`](super::Integer).
@@ -85,6 +133,11 @@ pub mod neg;
 /// Implementations of [`Parity`](malachite_base::num::arithmetic::traits::Parity), a trait for
 /// determining whether a number is even or odd.
 pub mod parity;
+/// Implementations of [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower)
+/// and [`PerfectPowerDecompose`](malachite_base::num::arithmetic::traits::PerfectPowerDecompose),
+/// traits for determining whether a number is a perfect power and for writing it as a perfect power
+/// with the largest possible exponent.
+pub mod perfect_power;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
@@ -521,3 +574,7 @@ pub mod sub;
 /// [`SubMulAssign`](malachite_base::num::arithmetic::traits::SubMulAssign), traits for subtracting
 /// the product of two numbers from a number.
 pub mod sub_mul;
+/// Implementations of [`Valuation`](malachite_base::num::arithmetic::traits::Valuation) and
+/// [`RemoveFactor`](malachite_base::num::arithmetic::traits::RemoveFactor), traits for finding the
+/// $p$-adic valuation of a number and for removing all factors of $p$ from it.
+pub mod valuation;
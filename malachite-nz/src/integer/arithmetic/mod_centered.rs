@@ -0,0 +1,230 @@
+use crate::integer::arithmetic::mod_op::{positive_modulus, residue, residue_ref};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModCentered, ModCenteredAssign};
+use std::mem::take;
+
+// Moves a residue modulo `m` into $(-m/2, m/2]$.
+fn centered(r: Natural, m: &Natural) -> Integer {
+    if r > m >> 1 {
+        -Integer::from(m - r)
+    } else {
+        Integer::from(r)
+    }
+}
+
+impl ModCentered<Integer> for Integer {
+    type Output = Integer;
+
+    /// Finds the centered residue of an [`Integer`] modulo another [`Integer`] $m$. The input need
+    /// not be reduced. Both [`Integer`]s are taken by value.
+    ///
+    /// $f(x, m) = y$, where $-m/2 < y \leq m/2$ and $x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModCentered;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(7).mod_centered(Integer::from(10)), -3);
+    /// assert_eq!(Integer::from(5).mod_centered(Integer::from(10)), 5);
+    /// assert_eq!(Integer::from(-5).mod_centered(Integer::from(10)), 5);
+    /// assert_eq!(Integer::from(-6).mod_centered(Integer::from(10)), 4);
+    /// assert_eq!(Integer::from(13).mod_centered(Integer::from(10)), 3);
+    /// ```
+    fn mod_centered(self, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        centered(residue(self, m), m)
+    }
+}
+
+impl<'a> ModCentered<&'a Integer> for Integer {
+    type Output = Integer;
+
+    /// Finds the centered residue of an [`Integer`] modulo another [`Integer`] $m$. The input need
+    /// not be reduced. The first [`Integer`] is taken by value and the second by reference.
+    ///
+    /// $f(x, m) = y$, where $-m/2 < y \leq m/2$ and $x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModCentered;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(7).mod_centered(&Integer::from(10)), -3);
+    /// assert_eq!(Integer::from(5).mod_centered(&Integer::from(10)), 5);
+    /// assert_eq!(Integer::from(-5).mod_centered(&Integer::from(10)), 5);
+    /// assert_eq!(Integer::from(-6).mod_centered(&Integer::from(10)), 4);
+    /// assert_eq!(Integer::from(13).mod_centered(&Integer::from(10)), 3);
+    /// ```
+    fn mod_centered(self, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        centered(residue(self, m), m)
+    }
+}
+
+impl<'a> ModCentered<Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Finds the centered residue of an [`Integer`] modulo another [`Integer`] $m$. The input need
+    /// not be reduced. The first [`Integer`] is taken by reference and the second by value.
+    ///
+    /// $f(x, m) = y$, where $-m/2 < y \leq m/2$ and $x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModCentered;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(7)).mod_centered(Integer::from(10)), -3);
+    /// assert_eq!((&Integer::from(5)).mod_centered(Integer::from(10)), 5);
+    /// assert_eq!((&Integer::from(-5)).mod_centered(Integer::from(10)), 5);
+    /// assert_eq!((&Integer::from(-6)).mod_centered(Integer::from(10)), 4);
+    /// assert_eq!((&Integer::from(13)).mod_centered(Integer::from(10)), 3);
+    /// ```
+    fn mod_centered(self, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        centered(residue_ref(self, m), m)
+    }
+}
+
+impl<'a, 'b> ModCentered<&'a Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Finds the centered residue of an [`Integer`] modulo another [`Integer`] $m$. The input need
+    /// not be reduced. Both [`Integer`]s are taken by reference.
+    ///
+    /// $f(x, m) = y$, where $-m/2 < y \leq m/2$ and $x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModCentered;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(7)).mod_centered(&Integer::from(10)), -3);
+    /// assert_eq!((&Integer::from(5)).mod_centered(&Integer::from(10)), 5);
+    /// assert_eq!((&Integer::from(-5)).mod_centered(&Integer::from(10)), 5);
+    /// assert_eq!((&Integer::from(-6)).mod_centered(&Integer::from(10)), 4);
+    /// assert_eq!((&Integer::from(13)).mod_centered(&Integer::from(10)), 3);
+    /// ```
+    fn mod_centered(self, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        centered(residue_ref(self, m), m)
+    }
+}
+
+impl ModCenteredAssign<Integer> for Integer {
+    /// Replaces an [`Integer`] with its centered residue modulo another [`Integer`] $m$. The input
+    /// need not be reduced. The [`Integer`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets y$, where $-m/2 < y \leq m/2$ and $x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModCenteredAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(7);
+    /// x.mod_centered_assign(Integer::from(10));
+    /// assert_eq!(x, -3);
+    ///
+    /// let mut x = Integer::from(-6);
+    /// x.mod_centered_assign(Integer::from(10));
+    /// assert_eq!(x, 4);
+    /// ```
+    fn mod_centered_assign(&mut self, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = centered(residue(take(self), m), m);
+    }
+}
+
+impl<'a> ModCenteredAssign<&'a Integer> for Integer {
+    /// Replaces an [`Integer`] with its centered residue modulo another [`Integer`] $m$. The input
+    /// need not be reduced. The [`Integer`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets y$, where $-m/2 < y \leq m/2$ and $x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModCenteredAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(7);
+    /// x.mod_centered_assign(&Integer::from(10));
+    /// assert_eq!(x, -3);
+    ///
+    /// let mut x = Integer::from(-6);
+    /// x.mod_centered_assign(&Integer::from(10));
+    /// assert_eq!(x, 4);
+    /// ```
+    fn mod_centered_assign(&mut self, m: &'a Integer) {
+        let m = positive_modulus(m);
+        *self = centered(residue(take(self), m), m);
+    }
+}
@@ -0,0 +1,162 @@
+use crate::integer::arithmetic::mod_op::{positive_modulus, residue, residue_ref};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModInverse;
+
+// Inverts a residue modulo `m`. Unlike `Natural::mod_inverse`, this accepts a residue of zero and a
+// modulus of 1, modulo which every number is its own inverse.
+pub(crate) fn mod_inverse_residue(x: Natural, m: &Natural) -> Option<Natural> {
+    if *m == 1u32 {
+        Some(x)
+    } else if x == 0u32 {
+        None
+    } else {
+        x.mod_inverse(m)
+    }
+}
+
+impl ModInverse<Integer> for Integer {
+    type Output = Integer;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo another [`Integer`] $m$. The
+    /// input need not be reduced or nonnegative. Both [`Integer`]s are taken by value.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_inverse(Integer::from(10)), Some(Integer::from(3)));
+    /// assert_eq!(Integer::from(13).mod_inverse(Integer::from(10)), Some(Integer::from(7)));
+    /// assert_eq!(Integer::from(4).mod_inverse(Integer::from(10)), None);
+    /// ```
+    fn mod_inverse(self, m: Integer) -> Option<Integer> {
+        let m = positive_modulus(&m);
+        mod_inverse_residue(residue(self, m), m).map(Integer::from)
+    }
+}
+
+impl<'a> ModInverse<&'a Integer> for Integer {
+    type Output = Integer;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo another [`Integer`] $m$. The
+    /// input need not be reduced or nonnegative. The first [`Integer`] is taken by value and the
+    /// second by reference.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_inverse(&Integer::from(10)), Some(Integer::from(3)));
+    /// assert_eq!(Integer::from(13).mod_inverse(&Integer::from(10)), Some(Integer::from(7)));
+    /// assert_eq!(Integer::from(4).mod_inverse(&Integer::from(10)), None);
+    /// ```
+    fn mod_inverse(self, m: &'a Integer) -> Option<Integer> {
+        let m = positive_modulus(m);
+        mod_inverse_residue(residue(self, m), m).map(Integer::from)
+    }
+}
+
+impl<'a> ModInverse<Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo another [`Integer`] $m$. The
+    /// input need not be reduced or nonnegative. The first [`Integer`] is taken by reference and
+    /// the second by value.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_inverse(Integer::from(10)), Some(Integer::from(3)));
+    /// assert_eq!((&Integer::from(13)).mod_inverse(Integer::from(10)), Some(Integer::from(7)));
+    /// assert_eq!((&Integer::from(4)).mod_inverse(Integer::from(10)), None);
+    /// ```
+    fn mod_inverse(self, m: Integer) -> Option<Integer> {
+        let m = positive_modulus(&m);
+        mod_inverse_residue(residue_ref(self, m), m).map(Integer::from)
+    }
+}
+
+impl<'a, 'b> ModInverse<&'a Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo another [`Integer`] $m$. The
+    /// input need not be reduced or nonnegative. Both [`Integer`]s are taken by reference.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_inverse(&Integer::from(10)), Some(Integer::from(3)));
+    /// assert_eq!((&Integer::from(13)).mod_inverse(&Integer::from(10)), Some(Integer::from(7)));
+    /// assert_eq!((&Integer::from(4)).mod_inverse(&Integer::from(10)), None);
+    /// ```
+    fn mod_inverse(self, m: &'a Integer) -> Option<Integer> {
+        let m = positive_modulus(m);
+        mod_inverse_residue(residue_ref(self, m), m).map(Integer::from)
+    }
+}
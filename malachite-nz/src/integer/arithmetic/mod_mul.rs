@@ -0,0 +1,418 @@
+use crate::integer::arithmetic::mod_op::{positive_modulus, residue, residue_ref};
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{ModMul, ModMulAssign};
+use std::mem::take;
+
+impl ModMul<Integer, Integer> for Integer {
+    type Output = Integer;
+
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced
+    /// or nonnegative. All three [`Integer`]s are taken by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_mul(Integer::from(4), Integer::from(7)), 2);
+    /// assert_eq!(Integer::from(15).mod_mul(Integer::from(-6), Integer::from(7)), 1);
+    /// ```
+    fn mod_mul(self, other: Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue(self, m).mod_mul(residue(other, m), m))
+    }
+}
+
+impl<'a> ModMul<Integer, &'a Integer> for Integer {
+    type Output = Integer;
+
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced
+    /// or nonnegative. The first two [`Integer`]s are taken by value and the third by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_mul(Integer::from(4), &Integer::from(7)), 2);
+    /// assert_eq!(Integer::from(15).mod_mul(Integer::from(-6), &Integer::from(7)), 1);
+    /// ```
+    fn mod_mul(self, other: Integer, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue(self, m).mod_mul(residue(other, m), m))
+    }
+}
+
+impl<'a> ModMul<&'a Integer, Integer> for Integer {
+    type Output = Integer;
+
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced
+    /// or nonnegative. The first and third [`Integer`]s are taken by value and the second by
+    /// reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_mul(&Integer::from(4), Integer::from(7)), 2);
+    /// assert_eq!(Integer::from(15).mod_mul(&Integer::from(-6), Integer::from(7)), 1);
+    /// ```
+    fn mod_mul(self, other: &'a Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue(self, m).mod_mul(residue_ref(other, m), m))
+    }
+}
+
+impl<'a, 'b> ModMul<&'a Integer, &'b Integer> for Integer {
+    type Output = Integer;
+
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced
+    /// or nonnegative. The first [`Integer`] is taken by value and the second and third by
+    /// reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_mul(&Integer::from(4), &Integer::from(7)), 2);
+    /// assert_eq!(Integer::from(15).mod_mul(&Integer::from(-6), &Integer::from(7)), 1);
+    /// ```
+    fn mod_mul(self, other: &'a Integer, m: &'b Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue(self, m).mod_mul(residue_ref(other, m), m))
+    }
+}
+
+impl<'a> ModMul<Integer, Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced
+    /// or nonnegative. The first [`Integer`] is taken by reference and the second and third by
+    /// value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_mul(Integer::from(4), Integer::from(7)), 2);
+    /// assert_eq!((&Integer::from(15)).mod_mul(Integer::from(-6), Integer::from(7)), 1);
+    /// ```
+    fn mod_mul(self, other: Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue_ref(self, m).mod_mul(residue(other, m), m))
+    }
+}
+
+impl<'a, 'b> ModMul<Integer, &'a Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced
+    /// or nonnegative. The first and third [`Integer`]s are taken by reference and the second by
+    /// value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_mul(Integer::from(4), &Integer::from(7)), 2);
+    /// assert_eq!((&Integer::from(15)).mod_mul(Integer::from(-6), &Integer::from(7)), 1);
+    /// ```
+    fn mod_mul(self, other: Integer, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue_ref(self, m).mod_mul(residue(other, m), m))
+    }
+}
+
+impl<'a, 'b> ModMul<&'a Integer, Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced
+    /// or nonnegative. The first two [`Integer`]s are taken by reference and the third by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_mul(&Integer::from(4), Integer::from(7)), 2);
+    /// assert_eq!((&Integer::from(15)).mod_mul(&Integer::from(-6), Integer::from(7)), 1);
+    /// ```
+    fn mod_mul(self, other: &'a Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue_ref(self, m).mod_mul(residue_ref(other, m), m))
+    }
+}
+
+impl<'a, 'b, 'c> ModMul<&'a Integer, &'b Integer> for &'c Integer {
+    type Output = Integer;
+
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced
+    /// or nonnegative. All three [`Integer`]s are taken by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMul;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_mul(&Integer::from(4), &Integer::from(7)), 2);
+    /// assert_eq!((&Integer::from(15)).mod_mul(&Integer::from(-6), &Integer::from(7)), 1);
+    /// ```
+    fn mod_mul(self, other: &'a Integer, m: &'b Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue_ref(self, m).mod_mul(residue_ref(other, m), m))
+    }
+}
+
+impl ModMulAssign<Integer, Integer> for Integer {
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$, in place. The inputs need not be
+    /// reduced or nonnegative. Both [`Integer`]s on the right-hand side are taken by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_mul_assign(Integer::from(4), Integer::from(7));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(15);
+    /// x.mod_mul_assign(Integer::from(-6), Integer::from(7));
+    /// assert_eq!(x, 1);
+    /// ```
+    fn mod_mul_assign(&mut self, other: Integer, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = Integer::from(residue(take(self), m).mod_mul(residue(other, m), m));
+    }
+}
+
+impl<'a> ModMulAssign<Integer, &'a Integer> for Integer {
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$, in place. The inputs need not be
+    /// reduced or nonnegative. The first [`Integer`] on the right-hand side is taken by value and
+    /// the second by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_mul_assign(Integer::from(4), &Integer::from(7));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(15);
+    /// x.mod_mul_assign(Integer::from(-6), &Integer::from(7));
+    /// assert_eq!(x, 1);
+    /// ```
+    fn mod_mul_assign(&mut self, other: Integer, m: &'a Integer) {
+        let m = positive_modulus(m);
+        *self = Integer::from(residue(take(self), m).mod_mul(residue(other, m), m));
+    }
+}
+
+impl<'a> ModMulAssign<&'a Integer, Integer> for Integer {
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$, in place. The inputs need not be
+    /// reduced or nonnegative. The first [`Integer`] on the right-hand side is taken by reference
+    /// and the second by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_mul_assign(&Integer::from(4), Integer::from(7));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(15);
+    /// x.mod_mul_assign(&Integer::from(-6), Integer::from(7));
+    /// assert_eq!(x, 1);
+    /// ```
+    fn mod_mul_assign(&mut self, other: &'a Integer, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = Integer::from(residue(take(self), m).mod_mul(residue_ref(other, m), m));
+    }
+}
+
+impl<'a, 'b> ModMulAssign<&'a Integer, &'b Integer> for Integer {
+    /// Multiplies two [`Integer`]s modulo a third [`Integer`] $m$, in place. The inputs need not be
+    /// reduced or nonnegative. Both [`Integer`]s on the right-hand side are taken by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $xy \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModMulAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_mul_assign(&Integer::from(4), &Integer::from(7));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(15);
+    /// x.mod_mul_assign(&Integer::from(-6), &Integer::from(7));
+    /// assert_eq!(x, 1);
+    /// ```
+    fn mod_mul_assign(&mut self, other: &'a Integer, m: &'b Integer) {
+        let m = positive_modulus(m);
+        *self = Integer::from(residue(take(self), m).mod_mul(residue_ref(other, m), m));
+    }
+}
@@ -0,0 +1,212 @@
+use crate::integer::arithmetic::mod_op::{positive_modulus, residue, residue_ref};
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{ModNeg, ModNegAssign};
+use std::mem::take;
+
+impl ModNeg<Integer> for Integer {
+    type Output = Integer;
+
+    /// Negates an [`Integer`] modulo another [`Integer`] $m$. The input need not be reduced or
+    /// nonnegative. Both [`Integer`]s are taken by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(3).mod_neg(Integer::from(7)), 4);
+    /// assert_eq!(Integer::from(-3).mod_neg(Integer::from(7)), 3);
+    /// assert_eq!(Integer::from(14).mod_neg(Integer::from(7)), 0);
+    /// ```
+    fn mod_neg(self, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue(self, m).mod_neg(m))
+    }
+}
+
+impl<'a> ModNeg<&'a Integer> for Integer {
+    type Output = Integer;
+
+    /// Negates an [`Integer`] modulo another [`Integer`] $m$. The input need not be reduced or
+    /// nonnegative. The first [`Integer`] is taken by value and the second by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(3).mod_neg(&Integer::from(7)), 4);
+    /// assert_eq!(Integer::from(-3).mod_neg(&Integer::from(7)), 3);
+    /// assert_eq!(Integer::from(14).mod_neg(&Integer::from(7)), 0);
+    /// ```
+    fn mod_neg(self, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue(self, m).mod_neg(m))
+    }
+}
+
+impl<'a> ModNeg<Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Negates an [`Integer`] modulo another [`Integer`] $m$. The input need not be reduced or
+    /// nonnegative. The first [`Integer`] is taken by reference and the second by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(3)).mod_neg(Integer::from(7)), 4);
+    /// assert_eq!((&Integer::from(-3)).mod_neg(Integer::from(7)), 3);
+    /// assert_eq!((&Integer::from(14)).mod_neg(Integer::from(7)), 0);
+    /// ```
+    fn mod_neg(self, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue_ref(self, m).mod_neg(m))
+    }
+}
+
+impl<'a, 'b> ModNeg<&'a Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Negates an [`Integer`] modulo another [`Integer`] $m$. The input need not be reduced or
+    /// nonnegative. Both [`Integer`]s are taken by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNeg;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(3)).mod_neg(&Integer::from(7)), 4);
+    /// assert_eq!((&Integer::from(-3)).mod_neg(&Integer::from(7)), 3);
+    /// assert_eq!((&Integer::from(14)).mod_neg(&Integer::from(7)), 0);
+    /// ```
+    fn mod_neg(self, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue_ref(self, m).mod_neg(m))
+    }
+}
+
+impl ModNegAssign<Integer> for Integer {
+    /// Negates an [`Integer`] modulo another [`Integer`] $m$, in place. The input need not be
+    /// reduced or nonnegative. The [`Integer`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNegAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_neg_assign(Integer::from(7));
+    /// assert_eq!(x, 4);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_neg_assign(Integer::from(7));
+    /// assert_eq!(x, 3);
+    /// ```
+    fn mod_neg_assign(&mut self, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = Integer::from(residue(take(self), m).mod_neg(m));
+    }
+}
+
+impl<'a> ModNegAssign<&'a Integer> for Integer {
+    /// Negates an [`Integer`] modulo another [`Integer`] $m$, in place. The input need not be
+    /// reduced or nonnegative. The [`Integer`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $-x \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModNegAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(3);
+    /// x.mod_neg_assign(&Integer::from(7));
+    /// assert_eq!(x, 4);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_neg_assign(&Integer::from(7));
+    /// assert_eq!(x, 3);
+    /// ```
+    fn mod_neg_assign(&mut self, m: &'a Integer) {
+        let m = positive_modulus(m);
+        *self = Integer::from(residue(take(self), m).mod_neg(m));
+    }
+}
@@ -1,9 +1,34 @@
 use crate::integer::Integer;
+use crate::natural::Natural;
 use malachite_base::num::arithmetic::traits::{
     CeilingMod, CeilingModAssign, Mod, ModAssign, NegMod, NegModAssign,
 };
 use std::ops::{Rem, RemAssign};
 
+// Returns a positive modulus as a `Natural`, panicking if it is not positive.
+pub(crate) fn positive_modulus(m: &Integer) -> &Natural {
+    assert!(*m > 0u32);
+    &m.abs
+}
+
+// Returns the least nonnegative residue of `x` modulo `m`, taking `x` by value.
+pub(crate) fn residue(x: Integer, m: &Natural) -> Natural {
+    if x.sign {
+        x.abs % m
+    } else {
+        x.abs.neg_mod(m)
+    }
+}
+
+// Returns the least nonnegative residue of `x` modulo `m`, taking `x` by reference.
+pub(crate) fn residue_ref(x: &Integer, m: &Natural) -> Natural {
+    if x.sign {
+        &x.abs % m
+    } else {
+        (&x.abs).neg_mod(m)
+    }
+}
+
 impl Mod<Integer> for Integer {
     type Output = Integer;
 
@@ -0,0 +1,460 @@
+use crate::integer::arithmetic::mod_inverse::mod_inverse_residue;
+use crate::integer::arithmetic::mod_op::{positive_modulus, residue, residue_ref};
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPow, ModPowAssign};
+use std::mem::take;
+
+// Raises a residue modulo `m` to an `Integer` power, inverting it first if the exponent is
+// negative.
+fn mod_pow_residue(x: Natural, exp: &Integer, m: &Natural) -> Natural {
+    if exp.sign {
+        x.mod_pow(&exp.abs, m)
+    } else {
+        mod_inverse_residue(x, m)
+            .expect("base is not invertible modulo m")
+            .mod_pow(&exp.abs, m)
+    }
+}
+
+impl ModPow<Integer, Integer> for Integer {
+    type Output = Integer;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$. The base need
+    /// not be reduced or nonnegative. If the exponent is negative, the base is first inverted
+    /// modulo $m$. All three [`Integer`]s are taken by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-4).mod_pow(Integer::from(13), Integer::from(497)), 52);
+    /// assert_eq!(Integer::from(3).mod_pow(Integer::from(-1), Integer::from(10)), 7);
+    /// assert_eq!(Integer::from(-3).mod_pow(Integer::from(-2), Integer::from(10)), 9);
+    /// ```
+    fn mod_pow(self, exp: Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(mod_pow_residue(residue(self, m), &exp, m))
+    }
+}
+
+impl<'a> ModPow<Integer, &'a Integer> for Integer {
+    type Output = Integer;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$. The base need
+    /// not be reduced or nonnegative. If the exponent is negative, the base is first inverted
+    /// modulo $m$. The first two [`Integer`]s are taken by value and the third by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-4).mod_pow(Integer::from(13), &Integer::from(497)), 52);
+    /// assert_eq!(Integer::from(3).mod_pow(Integer::from(-1), &Integer::from(10)), 7);
+    /// assert_eq!(Integer::from(-3).mod_pow(Integer::from(-2), &Integer::from(10)), 9);
+    /// ```
+    fn mod_pow(self, exp: Integer, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(mod_pow_residue(residue(self, m), &exp, m))
+    }
+}
+
+impl<'a> ModPow<&'a Integer, Integer> for Integer {
+    type Output = Integer;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$. The base need
+    /// not be reduced or nonnegative. If the exponent is negative, the base is first inverted
+    /// modulo $m$. The first and third [`Integer`]s are taken by value and the second by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-4).mod_pow(&Integer::from(13), Integer::from(497)), 52);
+    /// assert_eq!(Integer::from(3).mod_pow(&Integer::from(-1), Integer::from(10)), 7);
+    /// assert_eq!(Integer::from(-3).mod_pow(&Integer::from(-2), Integer::from(10)), 9);
+    /// ```
+    fn mod_pow(self, exp: &'a Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(mod_pow_residue(residue(self, m), exp, m))
+    }
+}
+
+impl<'a, 'b> ModPow<&'a Integer, &'b Integer> for Integer {
+    type Output = Integer;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$. The base need
+    /// not be reduced or nonnegative. If the exponent is negative, the base is first inverted
+    /// modulo $m$. The first [`Integer`] is taken by value and the second and third by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-4).mod_pow(&Integer::from(13), &Integer::from(497)), 52);
+    /// assert_eq!(Integer::from(3).mod_pow(&Integer::from(-1), &Integer::from(10)), 7);
+    /// assert_eq!(Integer::from(-3).mod_pow(&Integer::from(-2), &Integer::from(10)), 9);
+    /// ```
+    fn mod_pow(self, exp: &'a Integer, m: &'b Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(mod_pow_residue(residue(self, m), exp, m))
+    }
+}
+
+impl<'a> ModPow<Integer, Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$. The base need
+    /// not be reduced or nonnegative. If the exponent is negative, the base is first inverted
+    /// modulo $m$. The first [`Integer`] is taken by reference and the second and third by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-4)).mod_pow(Integer::from(13), Integer::from(497)), 52);
+    /// assert_eq!((&Integer::from(3)).mod_pow(Integer::from(-1), Integer::from(10)), 7);
+    /// assert_eq!((&Integer::from(-3)).mod_pow(Integer::from(-2), Integer::from(10)), 9);
+    /// ```
+    fn mod_pow(self, exp: Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(mod_pow_residue(residue_ref(self, m), &exp, m))
+    }
+}
+
+impl<'a, 'b> ModPow<Integer, &'a Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$. The base need
+    /// not be reduced or nonnegative. If the exponent is negative, the base is first inverted
+    /// modulo $m$. The first and third [`Integer`]s are taken by reference and the second by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-4)).mod_pow(Integer::from(13), &Integer::from(497)), 52);
+    /// assert_eq!((&Integer::from(3)).mod_pow(Integer::from(-1), &Integer::from(10)), 7);
+    /// assert_eq!((&Integer::from(-3)).mod_pow(Integer::from(-2), &Integer::from(10)), 9);
+    /// ```
+    fn mod_pow(self, exp: Integer, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(mod_pow_residue(residue_ref(self, m), &exp, m))
+    }
+}
+
+impl<'a, 'b> ModPow<&'a Integer, Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$. The base need
+    /// not be reduced or nonnegative. If the exponent is negative, the base is first inverted
+    /// modulo $m$. The first two [`Integer`]s are taken by reference and the third by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-4)).mod_pow(&Integer::from(13), Integer::from(497)), 52);
+    /// assert_eq!((&Integer::from(3)).mod_pow(&Integer::from(-1), Integer::from(10)), 7);
+    /// assert_eq!((&Integer::from(-3)).mod_pow(&Integer::from(-2), Integer::from(10)), 9);
+    /// ```
+    fn mod_pow(self, exp: &'a Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(mod_pow_residue(residue_ref(self, m), exp, m))
+    }
+}
+
+impl<'a, 'b, 'c> ModPow<&'a Integer, &'b Integer> for &'c Integer {
+    type Output = Integer;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$. The base need
+    /// not be reduced or nonnegative. If the exponent is negative, the base is first inverted
+    /// modulo $m$. All three [`Integer`]s are taken by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-4)).mod_pow(&Integer::from(13), &Integer::from(497)), 52);
+    /// assert_eq!((&Integer::from(3)).mod_pow(&Integer::from(-1), &Integer::from(10)), 7);
+    /// assert_eq!((&Integer::from(-3)).mod_pow(&Integer::from(-2), &Integer::from(10)), 9);
+    /// ```
+    fn mod_pow(self, exp: &'a Integer, m: &'b Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(mod_pow_residue(residue_ref(self, m), exp, m))
+    }
+}
+
+impl ModPowAssign<Integer, Integer> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$, in place. The
+    /// base need not be reduced or nonnegative. If the exponent is negative, the base is first
+    /// inverted modulo $m$. Both [`Integer`]s on the right-hand side are taken by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_pow_assign(Integer::from(13), Integer::from(497));
+    /// assert_eq!(x, 52);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_pow_assign(Integer::from(-2), Integer::from(10));
+    /// assert_eq!(x, 9);
+    /// ```
+    fn mod_pow_assign(&mut self, exp: Integer, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = Integer::from(mod_pow_residue(residue(take(self), m), &exp, m));
+    }
+}
+
+impl<'a> ModPowAssign<Integer, &'a Integer> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$, in place. The
+    /// base need not be reduced or nonnegative. If the exponent is negative, the base is first
+    /// inverted modulo $m$. The first [`Integer`] on the right-hand side is taken by value and the
+    /// second by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_pow_assign(Integer::from(13), &Integer::from(497));
+    /// assert_eq!(x, 52);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_pow_assign(Integer::from(-2), &Integer::from(10));
+    /// assert_eq!(x, 9);
+    /// ```
+    fn mod_pow_assign(&mut self, exp: Integer, m: &'a Integer) {
+        let m = positive_modulus(m);
+        *self = Integer::from(mod_pow_residue(residue(take(self), m), &exp, m));
+    }
+}
+
+impl<'a> ModPowAssign<&'a Integer, Integer> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$, in place. The
+    /// base need not be reduced or nonnegative. If the exponent is negative, the base is first
+    /// inverted modulo $m$. The first [`Integer`] on the right-hand side is taken by reference and
+    /// the second by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_pow_assign(&Integer::from(13), Integer::from(497));
+    /// assert_eq!(x, 52);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_pow_assign(&Integer::from(-2), Integer::from(10));
+    /// assert_eq!(x, 9);
+    /// ```
+    fn mod_pow_assign(&mut self, exp: &'a Integer, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = Integer::from(mod_pow_residue(residue(take(self), m), exp, m));
+    }
+}
+
+impl<'a, 'b> ModPowAssign<&'a Integer, &'b Integer> for Integer {
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a third [`Integer`] $m$, in place. The
+    /// base need not be reduced or nonnegative. If the exponent is negative, the base is first
+    /// inverted modulo $m$. Both [`Integer`]s on the right-hand side are taken by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive, or if `exp` is negative and `self` is not invertible modulo
+    /// `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPowAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-4);
+    /// x.mod_pow_assign(&Integer::from(13), &Integer::from(497));
+    /// assert_eq!(x, 52);
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_pow_assign(&Integer::from(-2), &Integer::from(10));
+    /// assert_eq!(x, 9);
+    /// ```
+    fn mod_pow_assign(&mut self, exp: &'a Integer, m: &'b Integer) {
+        let m = positive_modulus(m);
+        *self = Integer::from(mod_pow_residue(residue(take(self), m), exp, m));
+    }
+}
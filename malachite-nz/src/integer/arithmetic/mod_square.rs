@@ -0,0 +1,208 @@
+use crate::integer::arithmetic::mod_op::{positive_modulus, residue, residue_ref};
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{ModSquare, ModSquareAssign};
+use std::mem::take;
+
+impl ModSquare<Integer> for Integer {
+    type Output = Integer;
+
+    /// Squares an [`Integer`] modulo another [`Integer`] $m$. The input need not be reduced or
+    /// nonnegative. Both [`Integer`]s are taken by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_square(Integer::from(7)), 2);
+    /// assert_eq!(Integer::from(12).mod_square(Integer::from(7)), 4);
+    /// ```
+    fn mod_square(self, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue(self, m).mod_square(m))
+    }
+}
+
+impl<'a> ModSquare<&'a Integer> for Integer {
+    type Output = Integer;
+
+    /// Squares an [`Integer`] modulo another [`Integer`] $m$. The input need not be reduced or
+    /// nonnegative. The first [`Integer`] is taken by value and the second by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_square(&Integer::from(7)), 2);
+    /// assert_eq!(Integer::from(12).mod_square(&Integer::from(7)), 4);
+    /// ```
+    fn mod_square(self, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue(self, m).mod_square(m))
+    }
+}
+
+impl<'a> ModSquare<Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Squares an [`Integer`] modulo another [`Integer`] $m$. The input need not be reduced or
+    /// nonnegative. The first [`Integer`] is taken by reference and the second by value.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_square(Integer::from(7)), 2);
+    /// assert_eq!((&Integer::from(12)).mod_square(Integer::from(7)), 4);
+    /// ```
+    fn mod_square(self, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue_ref(self, m).mod_square(m))
+    }
+}
+
+impl<'a, 'b> ModSquare<&'a Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Squares an [`Integer`] modulo another [`Integer`] $m$. The input need not be reduced or
+    /// nonnegative. Both [`Integer`]s are taken by reference.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquare;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_square(&Integer::from(7)), 2);
+    /// assert_eq!((&Integer::from(12)).mod_square(&Integer::from(7)), 4);
+    /// ```
+    fn mod_square(self, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue_ref(self, m).mod_square(m))
+    }
+}
+
+impl ModSquareAssign<Integer> for Integer {
+    /// Squares an [`Integer`] modulo another [`Integer`] $m$, in place. The input need not be
+    /// reduced or nonnegative. The [`Integer`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquareAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_square_assign(Integer::from(7));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.mod_square_assign(Integer::from(7));
+    /// assert_eq!(x, 4);
+    /// ```
+    fn mod_square_assign(&mut self, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = Integer::from(residue(take(self), m).mod_square(m));
+    }
+}
+
+impl<'a> ModSquareAssign<&'a Integer> for Integer {
+    /// Squares an [`Integer`] modulo another [`Integer`] $m$, in place. The input need not be
+    /// reduced or nonnegative. The [`Integer`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets y$, where $0 \leq y < m$ and $x^2 \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSquareAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_square_assign(&Integer::from(7));
+    /// assert_eq!(x, 2);
+    ///
+    /// let mut x = Integer::from(12);
+    /// x.mod_square_assign(&Integer::from(7));
+    /// assert_eq!(x, 4);
+    /// ```
+    fn mod_square_assign(&mut self, m: &'a Integer) {
+        let m = positive_modulus(m);
+        *self = Integer::from(residue(take(self), m).mod_square(m));
+    }
+}
@@ -0,0 +1,416 @@
+use crate::integer::arithmetic::mod_op::{positive_modulus, residue, residue_ref};
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{ModSub, ModSubAssign};
+use std::mem::take;
+
+impl ModSub<Integer, Integer> for Integer {
+    type Output = Integer;
+
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced or
+    /// nonnegative. All three [`Integer`]s are taken by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(Integer::from(4), Integer::from(7)), 0);
+    /// assert_eq!(Integer::from(10).mod_sub(Integer::from(-20), Integer::from(7)), 2);
+    /// ```
+    fn mod_sub(self, other: Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue(self, m).mod_sub(residue(other, m), m))
+    }
+}
+
+impl<'a> ModSub<Integer, &'a Integer> for Integer {
+    type Output = Integer;
+
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced or
+    /// nonnegative. The first two [`Integer`]s are taken by value and the third by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(Integer::from(4), &Integer::from(7)), 0);
+    /// assert_eq!(Integer::from(10).mod_sub(Integer::from(-20), &Integer::from(7)), 2);
+    /// ```
+    fn mod_sub(self, other: Integer, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue(self, m).mod_sub(residue(other, m), m))
+    }
+}
+
+impl<'a> ModSub<&'a Integer, Integer> for Integer {
+    type Output = Integer;
+
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced or
+    /// nonnegative. The first and third [`Integer`]s are taken by value and the second by
+    /// reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(&Integer::from(4), Integer::from(7)), 0);
+    /// assert_eq!(Integer::from(10).mod_sub(&Integer::from(-20), Integer::from(7)), 2);
+    /// ```
+    fn mod_sub(self, other: &'a Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue(self, m).mod_sub(residue_ref(other, m), m))
+    }
+}
+
+impl<'a, 'b> ModSub<&'a Integer, &'b Integer> for Integer {
+    type Output = Integer;
+
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced or
+    /// nonnegative. The first [`Integer`] is taken by value and the second and third by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-3).mod_sub(&Integer::from(4), &Integer::from(7)), 0);
+    /// assert_eq!(Integer::from(10).mod_sub(&Integer::from(-20), &Integer::from(7)), 2);
+    /// ```
+    fn mod_sub(self, other: &'a Integer, m: &'b Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue(self, m).mod_sub(residue_ref(other, m), m))
+    }
+}
+
+impl<'a> ModSub<Integer, Integer> for &'a Integer {
+    type Output = Integer;
+
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced or
+    /// nonnegative. The first [`Integer`] is taken by reference and the second and third by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(Integer::from(4), Integer::from(7)), 0);
+    /// assert_eq!((&Integer::from(10)).mod_sub(Integer::from(-20), Integer::from(7)), 2);
+    /// ```
+    fn mod_sub(self, other: Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue_ref(self, m).mod_sub(residue(other, m), m))
+    }
+}
+
+impl<'a, 'b> ModSub<Integer, &'a Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced or
+    /// nonnegative. The first and third [`Integer`]s are taken by reference and the second by
+    /// value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(Integer::from(4), &Integer::from(7)), 0);
+    /// assert_eq!((&Integer::from(10)).mod_sub(Integer::from(-20), &Integer::from(7)), 2);
+    /// ```
+    fn mod_sub(self, other: Integer, m: &'a Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue_ref(self, m).mod_sub(residue(other, m), m))
+    }
+}
+
+impl<'a, 'b> ModSub<&'a Integer, Integer> for &'b Integer {
+    type Output = Integer;
+
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced or
+    /// nonnegative. The first two [`Integer`]s are taken by reference and the third by value.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(&Integer::from(4), Integer::from(7)), 0);
+    /// assert_eq!((&Integer::from(10)).mod_sub(&Integer::from(-20), Integer::from(7)), 2);
+    /// ```
+    fn mod_sub(self, other: &'a Integer, m: Integer) -> Integer {
+        let m = positive_modulus(&m);
+        Integer::from(residue_ref(self, m).mod_sub(residue_ref(other, m), m))
+    }
+}
+
+impl<'a, 'b, 'c> ModSub<&'a Integer, &'b Integer> for &'c Integer {
+    type Output = Integer;
+
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$. The inputs need not be reduced or
+    /// nonnegative. All three [`Integer`]s are taken by reference.
+    ///
+    /// $f(x, y, m) = z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSub;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-3)).mod_sub(&Integer::from(4), &Integer::from(7)), 0);
+    /// assert_eq!((&Integer::from(10)).mod_sub(&Integer::from(-20), &Integer::from(7)), 2);
+    /// ```
+    fn mod_sub(self, other: &'a Integer, m: &'b Integer) -> Integer {
+        let m = positive_modulus(m);
+        Integer::from(residue_ref(self, m).mod_sub(residue_ref(other, m), m))
+    }
+}
+
+impl ModSubAssign<Integer, Integer> for Integer {
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$, in place. The inputs need not be
+    /// reduced or nonnegative. Both [`Integer`]s on the right-hand side are taken by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_sub_assign(Integer::from(4), Integer::from(7));
+    /// assert_eq!(x, 0);
+    ///
+    /// let mut x = Integer::from(10);
+    /// x.mod_sub_assign(Integer::from(-20), Integer::from(7));
+    /// assert_eq!(x, 2);
+    /// ```
+    fn mod_sub_assign(&mut self, other: Integer, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = Integer::from(residue(take(self), m).mod_sub(residue(other, m), m));
+    }
+}
+
+impl<'a> ModSubAssign<Integer, &'a Integer> for Integer {
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$, in place. The inputs need not be
+    /// reduced or nonnegative. The first [`Integer`] on the right-hand side is taken by value and
+    /// the second by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_sub_assign(Integer::from(4), &Integer::from(7));
+    /// assert_eq!(x, 0);
+    ///
+    /// let mut x = Integer::from(10);
+    /// x.mod_sub_assign(Integer::from(-20), &Integer::from(7));
+    /// assert_eq!(x, 2);
+    /// ```
+    fn mod_sub_assign(&mut self, other: Integer, m: &'a Integer) {
+        let m = positive_modulus(m);
+        *self = Integer::from(residue(take(self), m).mod_sub(residue(other, m), m));
+    }
+}
+
+impl<'a> ModSubAssign<&'a Integer, Integer> for Integer {
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$, in place. The inputs need not be
+    /// reduced or nonnegative. The first [`Integer`] on the right-hand side is taken by reference
+    /// and the second by value.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_sub_assign(&Integer::from(4), Integer::from(7));
+    /// assert_eq!(x, 0);
+    ///
+    /// let mut x = Integer::from(10);
+    /// x.mod_sub_assign(&Integer::from(-20), Integer::from(7));
+    /// assert_eq!(x, 2);
+    /// ```
+    fn mod_sub_assign(&mut self, other: &'a Integer, m: Integer) {
+        let m = positive_modulus(&m);
+        *self = Integer::from(residue(take(self), m).mod_sub(residue_ref(other, m), m));
+    }
+}
+
+impl<'a, 'b> ModSubAssign<&'a Integer, &'b Integer> for Integer {
+    /// Subtracts two [`Integer`]s modulo a third [`Integer`] $m$, in place. The inputs need not be
+    /// reduced or nonnegative. Both [`Integer`]s on the right-hand side are taken by reference.
+    ///
+    /// $x \gets z$, where $0 \leq z < m$ and $x - y \equiv z \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSubAssign;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut x = Integer::from(-3);
+    /// x.mod_sub_assign(&Integer::from(4), &Integer::from(7));
+    /// assert_eq!(x, 0);
+    ///
+    /// let mut x = Integer::from(10);
+    /// x.mod_sub_assign(&Integer::from(-20), &Integer::from(7));
+    /// assert_eq!(x, 2);
+    /// ```
+    fn mod_sub_assign(&mut self, other: &'a Integer, m: &'b Integer) {
+        let m = positive_modulus(m);
+        *self = Integer::from(residue(take(self), m).mod_sub(residue_ref(other, m), m));
+    }
+}
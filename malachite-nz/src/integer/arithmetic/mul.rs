@@ -1,5 +1,6 @@
 use crate::integer::Integer;
-use malachite_base::num::basic::traits::{One, Zero};
+use crate::natural::arithmetic::mul::product_tree;
+use crate::natural::Natural;
 use std::iter::Product;
 use std::ops::{Mul, MulAssign};
 
@@ -224,9 +225,46 @@ impl<'a> MulAssign<&'a Integer> for Integer {
     }
 }
 
+impl Integer {
+    /// Computes the product tree of a slice of [`Integer`]s.
+    ///
+    /// The tree is returned as a list of levels, starting with the leaves, which are copies of
+    /// `xs`. Each subsequent level is obtained by multiplying adjacent pairs of the previous level;
+    /// if the previous level has an odd length, its last element is carried up unchanged. The last
+    /// level contains a single element, the product of all the [`Integer`]s. If `xs` is empty, the
+    /// tree is empty.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Integer::sum(xs.map(Integer::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Integer::product_tree(&vec_from_str::<Integer>("[2, -3, 5, -7, 11]").unwrap())
+    ///         .to_debug_string(),
+    ///     "[[2, -3, 5, -7, 11], [-6, -35, 11], [210, 11], [2310]]"
+    /// );
+    /// ```
+    pub fn product_tree(xs: &[Integer]) -> Vec<Vec<Integer>> {
+        product_tree(xs)
+    }
+}
+
 impl Product for Integer {
     /// Multiplies together all the [`Integer`]s in an iterator.
     ///
+    /// The absolute values are multiplied together in a balanced binary tree, as in the [`Product`]
+    /// implementation for [`Natural`].
+    ///
     /// $$
     /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i.
     /// $$
@@ -254,28 +292,21 @@ impl Product for Integer {
     where
         I: Iterator<Item = Integer>,
     {
-        let mut stack = Vec::new();
-        for (i, x) in xs.enumerate().map(|(i, x)| (i + 1, x)) {
-            if x == 0 {
-                return Integer::ZERO;
-            }
-            let mut p = x;
-            for _ in 0..i.trailing_zeros() {
-                p *= stack.pop().unwrap();
-            }
-            stack.push(p);
-        }
-        let mut p = Integer::ONE;
-        for x in stack.into_iter().rev() {
-            p *= x;
-        }
-        p
+        let mut sign = true;
+        let abs = Natural::product(xs.map(|x| {
+            sign = sign == x.sign;
+            x.abs
+        }));
+        Integer::from_sign_and_abs(sign, abs)
     }
 }
 
 impl<'a> Product<&'a Integer> for Integer {
     /// Multiplies together all the [`Integer`]s in an iterator of [`Integer`] references.
     ///
+    /// The absolute values are multiplied together in a balanced binary tree, as in the [`Product`]
+    /// implementation for [`Natural`].
+    ///
     /// $$
     /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i.
     /// $$
@@ -303,21 +334,11 @@ impl<'a> Product<&'a Integer> for Integer {
     where
         I: Iterator<Item = &'a Integer>,
     {
-        let mut stack = Vec::new();
-        for (i, x) in xs.enumerate().map(|(i, x)| (i + 1, x)) {
-            if *x == 0 {
-                return Integer::ZERO;
-            }
-            let mut p = x.clone();
-            for _ in 0..i.trailing_zeros() {
-                p *= stack.pop().unwrap();
-            }
-            stack.push(p);
-        }
-        let mut p = Integer::ONE;
-        for x in stack.into_iter().rev() {
-            p *= x;
-        }
-        p
+        let mut sign = true;
+        let abs = Natural::product(xs.map(|x| {
+            sign = sign == x.sign;
+            &x.abs
+        }));
+        Integer::from_sign_and_abs(sign, abs)
     }
 }
@@ -0,0 +1,145 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{IsPerfectPower, PerfectPowerDecompose, Pow};
+
+// A negative number can only be an odd power, so the factors of 2 in the exponent of the absolute
+// value are moved into the base.
+fn perfect_power_decompose_helper(sign: bool, root: Natural, exp: u64) -> (Integer, u64) {
+    if sign {
+        (Integer::from(root), exp)
+    } else {
+        let twos = exp.trailing_zeros();
+        (-root.pow(1 << twos), exp >> twos)
+    }
+}
+
+impl IsPerfectPower for Integer {
+    /// Determines whether an [`Integer`] is a perfect power: whether it is equal to $b^k$ for some
+    /// [`Integer`] $b$ and some integer $k \geq 2$.
+    ///
+    /// 0, 1, and $-1$ are perfect powers. A negative [`Integer`] is a perfect power if and only if
+    /// it is a perfect $k$th power for some odd $k \geq 3$.
+    ///
+    /// $f(n) = (\exists b \in \Z, k \in \N : k \geq 2 \wedge b^k = n)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsPerfectPower, Pow};
+    /// use malachite_base::num::basic::traits::NegativeOne;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::NEGATIVE_ONE.is_perfect_power(), true);
+    /// assert_eq!(Integer::from(1000).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-1000).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-16).is_perfect_power(), false);
+    /// assert_eq!(Integer::from(-10).pow(101).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-2).pow(128).is_perfect_power(), true);
+    /// assert_eq!((-Integer::from(2).pow(128)).is_perfect_power(), false);
+    /// ```
+    fn is_perfect_power(&self) -> bool {
+        if self.sign {
+            self.abs.is_perfect_power()
+        } else {
+            let exp = (&self.abs).perfect_power_decompose().1;
+            self.abs == 1u32 || exp >> exp.trailing_zeros() > 1
+        }
+    }
+}
+
+impl PerfectPowerDecompose for Integer {
+    type Output = Integer;
+
+    /// Writes an [`Integer`] as a perfect power $b^k$ with the largest possible $k$, returning $b$
+    /// and $k$. The [`Integer`] is taken by value.
+    ///
+    /// If the [`Integer`] is not a perfect power, the result is the [`Integer`] itself and 1. The
+    /// result for 0, 1, and $-1$ is also the [`Integer`] itself and 1, since they are $k$th powers
+    /// for every (odd) $k$. If the [`Integer`] is negative, $k$ is odd.
+    ///
+    /// $f(n) = (b, k)$, where $b^k = n$ and $k$ is as large as possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecompose, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-12).perfect_power_decompose().to_debug_string(), "(-12, 1)");
+    /// assert_eq!(Integer::from(1000).perfect_power_decompose().to_debug_string(), "(10, 3)");
+    /// assert_eq!(Integer::from(-1000).perfect_power_decompose().to_debug_string(), "(-10, 3)");
+    /// assert_eq!(Integer::from(-64).perfect_power_decompose().to_debug_string(), "(-4, 3)");
+    /// assert_eq!(
+    ///     Integer::from(-10).pow(100).perfect_power_decompose().to_debug_string(),
+    ///     "(10, 100)"
+    /// );
+    /// assert_eq!(
+    ///     (-Integer::from(10).pow(100)).perfect_power_decompose().to_debug_string(),
+    ///     "(-10000, 25)"
+    /// );
+    /// ```
+    fn perfect_power_decompose(self) -> (Integer, u64) {
+        let (root, exp) = self.abs.perfect_power_decompose();
+        perfect_power_decompose_helper(self.sign, root, exp)
+    }
+}
+
+impl<'a> PerfectPowerDecompose for &'a Integer {
+    type Output = Integer;
+
+    /// Writes an [`Integer`] as a perfect power $b^k$ with the largest possible $k$, returning $b$
+    /// and $k$. The [`Integer`] is taken by reference.
+    ///
+    /// If the [`Integer`] is not a perfect power, the result is the [`Integer`] itself and 1. The
+    /// result for 0, 1, and $-1$ is also the [`Integer`] itself and 1, since they are $k$th powers
+    /// for every (odd) $k$. If the [`Integer`] is negative, $k$ is odd.
+    ///
+    /// $f(n) = (b, k)$, where $b^k = n$ and $k$ is as large as possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecompose, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!((&Integer::from(-12)).perfect_power_decompose().to_debug_string(), "(-12, 1)");
+    /// assert_eq!((&Integer::from(1000)).perfect_power_decompose().to_debug_string(), "(10, 3)");
+    /// assert_eq!(
+    ///     (&Integer::from(-1000)).perfect_power_decompose().to_debug_string(),
+    ///     "(-10, 3)"
+    /// );
+    /// assert_eq!((&Integer::from(-64)).perfect_power_decompose().to_debug_string(), "(-4, 3)");
+    /// assert_eq!(
+    ///     (&Integer::from(-10).pow(100)).perfect_power_decompose().to_debug_string(),
+    ///     "(10, 100)"
+    /// );
+    /// assert_eq!(
+    ///     (&-Integer::from(10).pow(100)).perfect_power_decompose().to_debug_string(),
+    ///     "(-10000, 25)"
+    /// );
+    /// ```
+    fn perfect_power_decompose(self) -> (Integer, u64) {
+        let (root, exp) = (&self.abs).perfect_power_decompose();
+        perfect_power_decompose_helper(self.sign, root, exp)
+    }
+}
@@ -0,0 +1,175 @@
+use crate::integer::Integer;
+use crate::natural::arithmetic::valuation::remove_factor_natural;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{RemoveFactor, Valuation};
+
+impl<'a, 'b> Valuation<&'b Natural> for &'a Integer {
+    type Output = u64;
+
+    /// Finds the $p$-adic valuation of an [`Integer`], taking both the [`Integer`] and $p$ by
+    /// reference: the largest $k$ such that $p^k$ divides it.
+    ///
+    /// $f(n, p) = \max\\{k \in \N : p^k \mid n\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Pow, Valuation};
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Integer::from(12)).valuation(&Natural::from(2u32)), 2);
+    /// assert_eq!((&Integer::from(-12)).valuation(&Natural::from(2u32)), 2);
+    /// assert_eq!((&Integer::from(-12)).valuation(&Natural::from(5u32)), 0);
+    /// assert_eq!(
+    ///     (&-Integer::from(10u32).pow(100)).valuation(&Natural::from(1000u32)),
+    ///     33
+    /// );
+    /// ```
+    #[inline]
+    fn valuation(self, p: &'b Natural) -> u64 {
+        remove_factor_natural(&self.abs, p).1
+    }
+}
+
+impl<'a> Valuation<&'a Natural> for Integer {
+    type Output = u64;
+
+    /// Finds the $p$-adic valuation of an [`Integer`], taking the [`Integer`] by value and $p$ by
+    /// reference: the largest $k$ such that $p^k$ divides it.
+    ///
+    /// $f(n, p) = \max\\{k \in \N : p^k \mid n\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Pow, Valuation};
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Integer::from(12).valuation(&Natural::from(2u32)), 2);
+    /// assert_eq!(Integer::from(-12).valuation(&Natural::from(2u32)), 2);
+    /// assert_eq!(Integer::from(-12).valuation(&Natural::from(5u32)), 0);
+    /// assert_eq!((-Integer::from(10u32).pow(100)).valuation(&Natural::from(1000u32)), 33);
+    /// ```
+    #[inline]
+    fn valuation(self, p: &'a Natural) -> u64 {
+        remove_factor_natural(&self.abs, p).1
+    }
+}
+
+impl<'a> RemoveFactor<&'a Natural> for Integer {
+    type CofactorOutput = Integer;
+    type ValuationOutput = u64;
+
+    /// Removes all factors of $p$ from an [`Integer`], taking the [`Integer`] by value and $p$ by
+    /// reference. Returns the cofactor, which has the same sign as the [`Integer`], and the
+    /// $p$-adic valuation of the [`Integer`].
+    ///
+    /// $f(n, p) = (n/p^k, k)$, where $k = \max\\{k \in \N : p^k \mid n\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(12).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(3, 2)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-12).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(-3, 2)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-12).remove_factor(&Natural::from(5u32)).to_debug_string(),
+    ///     "(-12, 0)"
+    /// );
+    /// ```
+    fn remove_factor(self, p: &'a Natural) -> (Integer, u64) {
+        let (cofactor, v) = remove_factor_natural(&self.abs, p);
+        match cofactor {
+            Some(cofactor) => (Integer::from_sign_and_abs(self.sign, cofactor), v),
+            None => (self, v),
+        }
+    }
+}
+
+impl<'a, 'b> RemoveFactor<&'b Natural> for &'a Integer {
+    type CofactorOutput = Integer;
+    type ValuationOutput = u64;
+
+    /// Removes all factors of $p$ from an [`Integer`], taking both the [`Integer`] and $p$ by
+    /// reference. Returns the cofactor, which has the same sign as the [`Integer`], and the
+    /// $p$-adic valuation of the [`Integer`].
+    ///
+    /// $f(n, p) = (n/p^k, k)$, where $k = \max\\{k \in \N : p^k \mid n\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(12)).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(3, 2)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-12)).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(-3, 2)"
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-12)).remove_factor(&Natural::from(5u32)).to_debug_string(),
+    ///     "(-12, 0)"
+    /// );
+    /// ```
+    fn remove_factor(self, p: &'b Natural) -> (Integer, u64) {
+        let (cofactor, v) = remove_factor_natural(&self.abs, p);
+        match cofactor {
+            Some(cofactor) => (Integer::from_sign_and_abs(self.sign, cofactor), v),
+            None => (self.clone(), v),
+        }
+    }
+}
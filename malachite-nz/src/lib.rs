@@ -96,6 +96,12 @@
 //! # Features
 //! - `32_bit_limbs`: Sets the type of [`Limb`](crate#limbs) to [`u32`] instead of the default,
 //!   [`u64`].
+//! - `parallel`: Uses [rayon](https://docs.rs/rayon) to compute the independent parts of large
+//!   multiplications and squarings on multiple threads: the sub-products of the Toom-Cook
+//!   algorithms, the forward transforms of the two operands of an FFT multiplication, the
+//!   pointwise products of an FFT, and the transforms modulo each of the three primes of an NTT
+//!   multiplication. Division, and everything else that is built on top of multiplication,
+//!   benefits as well. The results are identical to those computed without the feature.
 //! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
 //!   typical user, building this code would result in an unnecessarily long compilation time and
 //!   an unnecessarily large binary. Some of it is also used for testing `malachite-q`, so it can't
@@ -144,6 +150,8 @@
 extern crate itertools;
 #[macro_use]
 extern crate malachite_base;
+#[cfg(feature = "parallel")]
+extern crate rayon;
 #[cfg(feature = "serde")]
 #[macro_use]
 extern crate serde;
@@ -18,17 +18,20 @@ use crate::platform::{
     ONE_LIMB_ODD_FACTORIAL_TABLE, TABLE_2N_MINUS_POPC_2N,
 };
 use malachite_base::num::arithmetic::traits::{
-    AddMulAssign, BinomialCoefficient, DivAssignMod, DivExact, Parity, PowerOf2, Square,
+    AddMulAssign, BinomialCoefficient, BinomialCoefficientMod, Crt, DivAssignMod, DivExact, DivMod,
+    DivisibleBy, FloorLogBase, ModInverse, ModMul, ModMulAssign, ModNegAssign,
+    MultinomialCoefficient, MultinomialCoefficientMod, Parity, Pow, PowerOf2, RemoveFactor, Square,
     WrappingAddAssign,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
-use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::basic::traits::{One, Two, Zero};
 use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
 #[cfg(feature = "32_bit_limbs")]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u32;
 #[cfg(not(feature = "32_bit_limbs"))]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u64;
 use malachite_base::num::factorization::prime_sieve::{id_to_n, limbs_prime_sieve_size, n_to_bit};
+use malachite_base::num::factorization::traits::Factor;
 use malachite_base::num::logic::traits::{CountOnes, LeadingZeros, SignificantBits};
 use std::cmp::{max, min, Ordering};
 
@@ -676,3 +679,418 @@ impl<'a> BinomialCoefficient<&'a Natural> for Natural {
         binomial_coefficient_helper(n.clone(), k)
     }
 }
+
+// Returns $\binom{n}{k} \bmod p$, where $p$ is prime and $k \leq n < p$.
+fn binomial_coefficient_mod_prime_small(n: Natural, k: Natural, p: &Natural) -> Natural {
+    let k = min(&n - &k, k);
+    let offset = n - &k;
+    let mut numerator = Natural::ONE;
+    let mut denominator = Natural::ONE;
+    let mut i = Natural::ONE;
+    while i <= k {
+        numerator.mod_mul_assign(&offset + &i, p);
+        denominator.mod_mul_assign(&i, p);
+        i += Natural::ONE;
+    }
+    numerator.mod_mul(denominator.mod_inverse(p).unwrap(), p)
+}
+
+// Returns $\binom{n}{k} \bmod p$, where $p$ is prime, using Lucas's theorem. Assumes $k \leq n$.
+fn binomial_coefficient_mod_prime(mut n: Natural, mut k: Natural, p: &Natural) -> Natural {
+    let mut result = Natural::ONE;
+    while k != 0u32 {
+        let n_digit = n.div_assign_mod(p);
+        let k_digit = k.div_assign_mod(p);
+        if k_digit > n_digit {
+            return Natural::ZERO;
+        }
+        result.mod_mul_assign(binomial_coefficient_mod_prime_small(n_digit, k_digit, p), p);
+    }
+    result
+}
+
+// Returns the exponent of the prime $p$ in $n!$, using Legendre's formula.
+fn factorial_p_adic_valuation(n: &Natural, p: &Natural) -> Natural {
+    let mut n = n / p;
+    let mut v = Natural::ZERO;
+    while n != 0u32 {
+        v += &n;
+        n /= p;
+    }
+    v
+}
+
+// Returns $(n!/p^{v_p(n!)}) \bmod q$, where $q = p^e$ and $e > 1$. See the primitive version of
+// this function in `malachite-base` for details.
+fn factorial_p_free_mod(n: &Natural, p: &Natural, q: &Natural, negate_periods: bool) -> Natural {
+    let mut n = n.clone();
+    let mut result = Natural::ONE;
+    while n != 0u32 {
+        let (periods, r) = (&n).div_mod(q);
+        if negate_periods && periods.odd() {
+            result.mod_neg_assign(q);
+        }
+        let mut i = Natural::TWO;
+        while i <= r {
+            if !(&i).divisible_by(p) {
+                result.mod_mul_assign(&i, q);
+            }
+            i += Natural::ONE;
+        }
+        n /= p;
+    }
+    result
+}
+
+// Returns $(\binom{n}{k}/p^{v_p(\binom{n}{k})}) \bmod q$, where $q = p^e$ and $e > 1$, by
+// multiplying $k$ terms with their factors of $p$ removed. Assumes $2k \leq n$.
+fn binomial_coefficient_p_free_mod_small_k(
+    n: &Natural,
+    k: &Natural,
+    p: &Natural,
+    q: &Natural,
+) -> Natural {
+    let offset = n - k;
+    let mut numerator = Natural::ONE;
+    let mut denominator = Natural::ONE;
+    let mut i = Natural::ONE;
+    while i <= *k {
+        numerator.mod_mul_assign((&offset + &i).remove_factor(p).0 % q, q);
+        denominator.mod_mul_assign((&i).remove_factor(p).0 % q, q);
+        i += Natural::ONE;
+    }
+    numerator.mod_mul(denominator.mod_inverse(q).unwrap(), q)
+}
+
+// Returns $\binom{n}{k} \bmod q$, where $q = p^e$ and $p$ is prime. Assumes $k \leq n$.
+fn binomial_coefficient_mod_prime_power(
+    n: &Natural,
+    k: &Natural,
+    p: &Natural,
+    e: u64,
+    q: &Natural,
+) -> Natural {
+    if e == 1 {
+        return binomial_coefficient_mod_prime(n.clone(), k.clone(), p);
+    }
+    let n_minus_k = n - k;
+    let small_k = min(k, &n_minus_k);
+    if *small_k == 0u32 {
+        return Natural::ONE;
+    }
+    // By Kummer's theorem, this is also the number of carries when adding $k$ and $n - k$ in base
+    // $p$.
+    let v = factorial_p_adic_valuation(n, p)
+        - factorial_p_adic_valuation(k, p)
+        - factorial_p_adic_valuation(&n_minus_k, p);
+    if v >= e {
+        return Natural::ZERO;
+    }
+    // Each of the three p-free factorials takes about $q$ steps for each base-$p$ digit of $n$.
+    let digits = n.floor_log_base(p) + 1;
+    let unit = if q * Natural::from(3 * digits) < *small_k {
+        let negate_periods = *p != 2u32 || e < 3;
+        let denominator = factorial_p_free_mod(k, p, q, negate_periods)
+            .mod_mul(factorial_p_free_mod(&n_minus_k, p, q, negate_periods), q);
+        factorial_p_free_mod(n, p, q, negate_periods)
+            .mod_mul(denominator.mod_inverse(q).unwrap(), q)
+    } else {
+        binomial_coefficient_p_free_mod_small_k(n, small_k, p, q)
+    };
+    p.pow(u64::exact_from(&v)).mod_mul(unit, q)
+}
+
+// Returns $\binom{n}{k} \bmod m$, given the prime factorization of $m > 1$. The binomial
+// coefficient is computed modulo each prime power dividing $m$, and the results are combined using
+// the Chinese remainder theorem.
+fn binomial_coefficient_mod_with_factors(
+    n: &Natural,
+    k: &Natural,
+    m: &Natural,
+    factors: &[(Natural, u64)],
+) -> Natural {
+    if k > n {
+        return Natural::ZERO;
+    }
+    if let [(p, e)] = factors {
+        return binomial_coefficient_mod_prime_power(n, k, p, *e, m);
+    }
+    let congruences = factors
+        .iter()
+        .map(|(p, e)| {
+            let q = p.pow(*e);
+            (binomial_coefficient_mod_prime_power(n, k, p, *e, &q), q)
+        })
+        .collect::<Vec<_>>();
+    Natural::crt(&congruences).unwrap().0
+}
+
+fn binomial_coefficient_mod_helper(n: &Natural, k: &Natural, m: &Natural) -> Natural {
+    assert_ne!(*m, 0u32, "Cannot reduce modulo 0");
+    if *m == 1u32 || k > n {
+        return Natural::ZERO;
+    }
+    if let (Ok(n), Ok(k), Ok(m)) = (Limb::try_from(n), Limb::try_from(k), Limb::try_from(m)) {
+        return Natural::from(Limb::binomial_coefficient_mod(n, k, m));
+    }
+    binomial_coefficient_mod_with_factors(n, k, m, &m.factor())
+}
+
+impl BinomialCoefficientMod<Natural, Natural> for Natural {
+    /// Computes the binomial coefficient of two [`Natural`]s modulo a third [`Natural`] $m$,
+    /// taking all three by value.
+    ///
+    /// If $m$ is prime, Lucas's theorem is used. If $m$ is a prime power, Granville's
+    /// generalization of Lucas's theorem is used. Otherwise, the results for the prime powers
+    /// dividing $m$ are combined using the Chinese remainder theorem. The full binomial
+    /// coefficient is never computed.
+    ///
+    /// $$
+    /// f(n, k, m) = \binom{n}{k} \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// TODO
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::BinomialCoefficientMod;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         Natural::from(10u32),
+    ///         Natural::from(5u32),
+    ///         Natural::from(100u32)
+    ///     ),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         Natural::from(1000000u32),
+    ///         Natural::from(500000u32),
+    ///         Natural::from(1000000007u32)
+    ///     ),
+    ///     996692777
+    /// );
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         Natural::from_str("1000000000000000000000").unwrap(),
+    ///         Natural::from(1000u32),
+    ///         Natural::from(2305843009213693951u64)
+    ///     ),
+    ///     33507485050496592u64
+    /// );
+    /// ```
+    #[inline]
+    fn binomial_coefficient_mod(n: Natural, k: Natural, m: Natural) -> Natural {
+        binomial_coefficient_mod_helper(&n, &k, &m)
+    }
+}
+
+impl<'a, 'b> BinomialCoefficientMod<&'a Natural, &'b Natural> for Natural {
+    /// Computes the binomial coefficient of two [`Natural`]s modulo a third [`Natural`] $m$,
+    /// taking all three by reference.
+    ///
+    /// If $m$ is prime, Lucas's theorem is used. If $m$ is a prime power, Granville's
+    /// generalization of Lucas's theorem is used. Otherwise, the results for the prime powers
+    /// dividing $m$ are combined using the Chinese remainder theorem. The full binomial
+    /// coefficient is never computed.
+    ///
+    /// $$
+    /// f(n, k, m) = \binom{n}{k} \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// TODO
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::BinomialCoefficientMod;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         &Natural::from(10u32),
+    ///         &Natural::from(5u32),
+    ///         &Natural::from(100u32)
+    ///     ),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         &Natural::from(1000000u32),
+    ///         &Natural::from(500000u32),
+    ///         &Natural::from(1000000007u32)
+    ///     ),
+    ///     996692777
+    /// );
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         &Natural::from_str("1000000000000000000000").unwrap(),
+    ///         &Natural::from(1000u32),
+    ///         &Natural::from(2305843009213693951u64)
+    ///     ),
+    ///     33507485050496592u64
+    /// );
+    /// ```
+    #[inline]
+    fn binomial_coefficient_mod(n: &'a Natural, k: &'a Natural, m: &'b Natural) -> Natural {
+        binomial_coefficient_mod_helper(n, k, m)
+    }
+}
+
+impl MultinomialCoefficient for Natural {
+    /// Computes the multinomial coefficient of a slice of [`Natural`]s.
+    ///
+    /// The multinomial coefficient is computed as the product of the binomial coefficients
+    /// $\binom{k_1 + \cdots + k_i}{k_i}$.
+    ///
+    /// $$
+    /// f((k_i)_{i=1}^r) = \binom{k_1 + \cdots + k_r}{k_1, \ldots, k_r} =
+    /// \frac{(k_1 + \cdots + k_r)!}{k_1! \cdots k_r!}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// TODO
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultinomialCoefficient;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::multinomial_coefficient(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::multinomial_coefficient(&[
+    ///         Natural::from(1u32),
+    ///         Natural::from(2u32),
+    ///         Natural::from(3u32)
+    ///     ]),
+    ///     60
+    /// );
+    /// assert_eq!(
+    ///     Natural::multinomial_coefficient(&[
+    ///         Natural::from(20u32),
+    ///         Natural::from(20u32),
+    ///         Natural::from(20u32)
+    ///     ])
+    ///     .to_string(),
+    ///     "577831214478475823831865900"
+    /// );
+    /// ```
+    fn multinomial_coefficient(ks: &[Natural]) -> Natural {
+        let mut n = Natural::ZERO;
+        let mut product = Natural::ONE;
+        for k in ks {
+            n += k;
+            product *= Natural::binomial_coefficient(&n, k);
+        }
+        product
+    }
+}
+
+fn multinomial_coefficient_mod_helper(ks: &[Natural], m: &Natural) -> Natural {
+    assert_ne!(*m, 0u32, "Cannot reduce modulo 0");
+    if *m == 1u32 {
+        return Natural::ZERO;
+    }
+    let factors = m.factor();
+    let mut n = Natural::ZERO;
+    let mut product = Natural::ONE;
+    for k in ks {
+        n += k;
+        product.mod_mul_assign(binomial_coefficient_mod_with_factors(&n, k, m, &factors), m);
+        if product == 0u32 {
+            break;
+        }
+    }
+    product
+}
+
+impl MultinomialCoefficientMod<Natural, Natural> for Natural {
+    /// Computes the multinomial coefficient of a slice of [`Natural`]s modulo a [`Natural`] $m$.
+    /// The modulus is taken by value.
+    ///
+    /// $$
+    /// f((k_i)_{i=1}^r, m) = \binom{k_1 + \cdots + k_r}{k_1, \ldots, k_r} \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// TODO
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultinomialCoefficientMod;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::multinomial_coefficient_mod(
+    ///         &[Natural::from(1u32), Natural::from(2u32), Natural::from(3u32)],
+    ///         Natural::from(7u32)
+    ///     ),
+    ///     4
+    /// );
+    /// assert_eq!(
+    ///     Natural::multinomial_coefficient_mod(
+    ///         &[Natural::from(100u32), Natural::from(200u32), Natural::from(300u32)],
+    ///         Natural::from(1000000007u32)
+    ///     ),
+    ///     756111920
+    /// );
+    /// ```
+    #[inline]
+    fn multinomial_coefficient_mod(ks: &[Natural], m: Natural) -> Natural {
+        multinomial_coefficient_mod_helper(ks, &m)
+    }
+}
+
+impl<'a> MultinomialCoefficientMod<Natural, &'a Natural> for Natural {
+    /// Computes the multinomial coefficient of a slice of [`Natural`]s modulo a [`Natural`] $m$.
+    /// The modulus is taken by reference.
+    ///
+    /// $$
+    /// f((k_i)_{i=1}^r, m) = \binom{k_1 + \cdots + k_r}{k_1, \ldots, k_r} \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// TODO
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultinomialCoefficientMod;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::multinomial_coefficient_mod(
+    ///         &[Natural::from(1u32), Natural::from(2u32), Natural::from(3u32)],
+    ///         &Natural::from(7u32)
+    ///     ),
+    ///     4
+    /// );
+    /// assert_eq!(
+    ///     Natural::multinomial_coefficient_mod(
+    ///         &[Natural::from(100u32), Natural::from(200u32), Natural::from(300u32)],
+    ///         &Natural::from(1000000007u32)
+    ///     ),
+    ///     756111920
+    /// );
+    /// ```
+    #[inline]
+    fn multinomial_coefficient_mod(ks: &[Natural], m: &'a Natural) -> Natural {
+        multinomial_coefficient_mod_helper(ks, m)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{CarmichaelLambda, LcmAssign, Pow, PowerOf2};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::Factor;
+
+// Computes $\lambda(p^e)$, where $p$ is prime and $e > 0$.
+fn carmichael_lambda_of_prime_power(p: &Natural, e: u64) -> Natural {
+    if *p == Natural::TWO && e > 2 {
+        Natural::power_of_2(e - 2)
+    } else {
+        p.pow(e - 1) * (p - Natural::ONE)
+    }
+}
+
+// Returns the prime factorization of $\lambda(n)$, in no particular order, given the prime
+// factorization of $n$. Only $p - 1$ is factored for each prime factor $p$ of $n$, which is cheaper
+// than factoring $\lambda(n)$ itself.
+pub(crate) fn carmichael_lambda_factors(factors: &[(Natural, u64)]) -> Vec<(Natural, u64)> {
+    let mut lambda_factors: Vec<(Natural, u64)> = Vec::new();
+    let mut add_factor = |q: Natural, f: u64| {
+        if let Some(existing) = lambda_factors.iter_mut().find(|(r, _)| *r == q) {
+            existing.1 = existing.1.max(f);
+        } else {
+            lambda_factors.push((q, f));
+        }
+    };
+    for (p, e) in factors {
+        let e = *e;
+        if *p == Natural::TWO {
+            if e > 1 {
+                add_factor(Natural::TWO, if e == 2 { 1 } else { e - 2 });
+            }
+        } else {
+            if e > 1 {
+                add_factor(p.clone(), e - 1);
+            }
+            for (q, f) in (p - Natural::ONE).factor() {
+                add_factor(q, f);
+            }
+        }
+    }
+    lambda_factors
+}
+
+impl CarmichaelLambda for Natural {
+    /// Computes the Carmichael function $\lambda$ of a [`Natural`]: the smallest positive $m$
+    /// such that $a^m \equiv 1 \mod n$ for every $a$ coprime to $n$.
+    ///
+    /// The [`Natural`] is factored first. If its factorization is already known, use
+    /// [`carmichael_lambda_from_factors`](CarmichaelLambda::carmichael_lambda_from_factors)
+    /// instead.
+    ///
+    /// $$
+    /// f(n) = \lambda(n) = \operatorname{lcm}_{p^e \\| n} \lambda(p^e),
+    /// $$
+    /// where $\lambda(2^e) = 2^{e-2}$ if $e \geq 3$, and $\lambda(p^e) = p^{e-1}(p-1)$ otherwise.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{CarmichaelLambda, Pow};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ONE.carmichael_lambda(), 1);
+    /// assert_eq!(Natural::from(15u32).carmichael_lambda(), 4);
+    /// assert_eq!(Natural::from(561u32).carmichael_lambda(), 80);
+    /// assert_eq!(Natural::from(10u32).pow(20).carmichael_lambda(), 5000000000000000000u64);
+    /// ```
+    #[inline]
+    fn carmichael_lambda(&self) -> Natural {
+        Natural::carmichael_lambda_from_factors(&self.factor())
+    }
+
+    /// Computes the Carmichael function $\lambda$ of a [`Natural`], given its prime
+    /// factorization.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// $$
+    /// f(\\{(p_i, e_i)\\}) = \operatorname{lcm}_i \lambda(p_i^{e_i}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of the number
+    /// whose factorization is `factors`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CarmichaelLambda;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::carmichael_lambda_from_factors(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::carmichael_lambda_from_factors(&[
+    ///         (Natural::from(3u32), 1),
+    ///         (Natural::from(11u32), 1),
+    ///         (Natural::from(17u32), 1)
+    ///     ]),
+    ///     80
+    /// );
+    /// ```
+    fn carmichael_lambda_from_factors(factors: &[(Natural, u64)]) -> Natural {
+        let mut lambda = Natural::ONE;
+        for (p, e) in factors {
+            lambda.lcm_assign(carmichael_lambda_of_prime_power(p, *e));
+        }
+        lambda
+    }
+}
@@ -0,0 +1,43 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, Catalan, DivExact};
+use malachite_base::num::basic::traits::One;
+
+impl Catalan for Natural {
+    /// Computes a Catalan number.
+    ///
+    /// The Catalan number $C_n$ counts, among many other things, the binary trees with $n$
+    /// internal nodes and the balanced strings of $n$ pairs of parentheses.
+    ///
+    /// $$
+    /// f(n) = C_n = \frac{1}{n+1}\binom{2n}{n}.
+    /// $$
+    ///
+    /// $C_n = O(4^n n^{-3/2})$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Catalan;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::catalan(0), 1);
+    /// assert_eq!(Natural::catalan(1), 1);
+    /// assert_eq!(Natural::catalan(2), 2);
+    /// assert_eq!(Natural::catalan(3), 5);
+    /// assert_eq!(Natural::catalan(10), 16796);
+    /// assert_eq!(
+    ///     Natural::catalan(100).to_string(),
+    ///     "896519947090131496687170070074100632420837521538745909320"
+    /// );
+    /// ```
+    fn catalan(n: u64) -> Natural {
+        let n = Natural::from(n);
+        Natural::binomial_coefficient(&n << 1, n.clone()).div_exact(n + Natural::ONE)
+    }
+}
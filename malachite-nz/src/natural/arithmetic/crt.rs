@@ -0,0 +1,291 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    Crt, CrtPrecomputed, DivExact, Gcd, ModInverse, ModMul, ModSub,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Combines the congruences $x \equiv a \mod m$ and $x \equiv b \mod n$, where $a < m$ and $b < n$,
+// into a single congruence $x \equiv c \mod \operatorname{lcm}(m, n)$. Returns `None` if the
+// congruences are inconsistent.
+pub(crate) fn crt_combine(
+    a: Natural,
+    m: Natural,
+    b: Natural,
+    n: &Natural,
+) -> Option<(Natural, Natural)> {
+    let g = (&m).gcd(n);
+    if &a % &g != &b % &g {
+        return None;
+    }
+    let n_g = n.div_exact(&g);
+    if n_g == 1u32 {
+        return Some((a, m));
+    }
+    // Solve a + m * t = b (mod n), or (m / g) * t = (b - a) / g (mod n / g).
+    let d = b.mod_sub(&a % n, n).div_exact(&g);
+    let inverse = ((&m).div_exact(&g) % &n_g).mod_inverse(&n_g).unwrap();
+    let x = a + &m * d.mod_mul(inverse, &n_g);
+    Some((x, m * n_g))
+}
+
+impl Crt for Natural {
+    /// Solves a system of simultaneous congruences $x \equiv r_i \mod m_i$. The moduli need not be
+    /// pairwise coprime, and the residues need not be reduced.
+    ///
+    /// Returns the least nonnegative solution $x$ and the combined modulus
+    /// $L = \operatorname{lcm}(m_1, \ldots, m_k)$. Every solution is congruent to $x$ modulo $L$.
+    /// Returns `None` if the congruences are inconsistent. An empty system has the solution
+    /// $(0, 1)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n, k) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits of the moduli,
+    /// and $k$ is `congruences.len()`.
+    ///
+    /// # Panics
+    /// Panics if any modulus is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Crt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::crt(&[]).to_debug_string(), "Some((0, 1))");
+    /// assert_eq!(
+    ///     Natural::crt(&[
+    ///         (Natural::from(2u32), Natural::from(3u32)),
+    ///         (Natural::from(3u32), Natural::from(5u32)),
+    ///         (Natural::from(2u32), Natural::from(7u32))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((23, 105))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::crt(&[
+    ///         (Natural::from(3u32), Natural::from(4u32)),
+    ///         (Natural::from(5u32), Natural::from(6u32))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((11, 12))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::crt(&[
+    ///         (Natural::from(3u32), Natural::from(4u32)),
+    ///         (Natural::from(4u32), Natural::from(6u32))
+    ///     ]),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     Natural::crt(&[
+    ///         (Natural::from(1u32), Natural::from(18446744073709551557u64)),
+    ///         (Natural::from(2u32), Natural::from(18446744073709551533u64))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((269390207145742948168885365600372308430, \
+    ///     340282366920938460843936948965011886881))"
+    /// );
+    /// ```
+    fn crt(congruences: &[(Natural, Natural)]) -> Option<(Natural, Natural)> {
+        let mut x = Natural::ZERO;
+        let mut lcm = Natural::ONE;
+        for (r, m) in congruences {
+            assert_ne!(*m, 0u32);
+            (x, lcm) = crt_combine(x, lcm, r % m, m)?;
+        }
+        Some((x, lcm))
+    }
+}
+
+/// A precomputed basis for reconstructing [`Natural`]s from their residues modulo a fixed set of
+/// pairwise coprime moduli.
+///
+/// This is the [`Data`](CrtPrecomputed::Data) of the [`CrtPrecomputed`] implementations for
+/// [`Natural`] and [`Integer`](crate::integer::Integer), and is created by
+/// [`precompute_crt_data`](CrtPrecomputed::precompute_crt_data).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CrtBasis {
+    moduli: Vec<Natural>,
+    // The $i$th cofactor is the product of all the moduli except the $i$th.
+    cofactors: Vec<Natural>,
+    // The $i$th inverse is the inverse of the $i$th cofactor modulo the $i$th modulus.
+    inverses: Vec<Natural>,
+    modulus: Natural,
+}
+
+impl CrtBasis {
+    /// Returns the moduli that the basis was created from.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let basis = Natural::precompute_crt_data(&[
+    ///     Natural::from(3u32),
+    ///     Natural::from(5u32),
+    ///     Natural::from(7u32),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(basis.moduli().to_debug_string(), "[3, 5, 7]");
+    /// ```
+    pub fn moduli(&self) -> &[Natural] {
+        &self.moduli
+    }
+
+    /// Returns the product of the moduli that the basis was created from. Reconstructed numbers
+    /// are reduced modulo this product.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let basis = Natural::precompute_crt_data(&[
+    ///     Natural::from(3u32),
+    ///     Natural::from(5u32),
+    ///     Natural::from(7u32),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(*basis.modulus(), 105);
+    /// ```
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    // Reconstructs a number from its residues, which must already be reduced. The caller must
+    // check that there is one residue per modulus.
+    pub(crate) fn reconstruct(&self, residues: impl Iterator<Item = Natural>) -> Natural {
+        let mut x = Natural::ZERO;
+        for (((r, m), cofactor), inverse) in residues
+            .zip(self.moduli.iter())
+            .zip(self.cofactors.iter())
+            .zip(self.inverses.iter())
+        {
+            if *m != 1u32 {
+                x += cofactor * r.mod_mul(inverse, m);
+            }
+        }
+        x % &self.modulus
+    }
+}
+
+impl CrtPrecomputed for Natural {
+    type Data = CrtBasis;
+
+    /// Precomputes a basis for reconstructing [`Natural`]s from their residues modulo the given
+    /// moduli.
+    ///
+    /// Returns `None` if the moduli are not pairwise coprime.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n, k) = O(kn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits of the moduli,
+    /// and $k$ is `moduli.len()`.
+    ///
+    /// # Panics
+    /// Panics if any modulus is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(
+    ///     Natural::precompute_crt_data(&[Natural::from(3u32), Natural::from(5u32)]).is_some()
+    /// );
+    /// assert!(
+    ///     Natural::precompute_crt_data(&[Natural::from(4u32), Natural::from(6u32)]).is_none()
+    /// );
+    /// ```
+    fn precompute_crt_data(moduli: &[Natural]) -> Option<CrtBasis> {
+        for m in moduli {
+            assert_ne!(*m, 0u32);
+        }
+        let modulus: Natural = moduli.iter().product();
+        let mut cofactors = Vec::with_capacity(moduli.len());
+        let mut inverses = Vec::with_capacity(moduli.len());
+        for m in moduli {
+            let cofactor = (&modulus).div_exact(m);
+            inverses.push(if *m == 1u32 {
+                Natural::ZERO
+            } else {
+                let reduced = &cofactor % m;
+                if reduced == 0u32 {
+                    return None;
+                }
+                // The moduli are pairwise coprime iff each one is coprime to the product of the
+                // others.
+                reduced.mod_inverse(m)?
+            });
+            cofactors.push(cofactor);
+        }
+        Some(CrtBasis {
+            moduli: moduli.to_vec(),
+            cofactors,
+            inverses,
+            modulus,
+        })
+    }
+
+    /// Reconstructs a [`Natural`] from its residues modulo the moduli of a precomputed basis. The
+    /// residues need not be reduced.
+    ///
+    /// $f((r_1, \ldots, r_k), (m_1, \ldots, m_k)) = x$, where $0 \leq x < \prod_i m_i$ and
+    /// $x \equiv r_i \mod m_i$ for all $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n, k) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits of the moduli,
+    /// and $k$ is `residues.len()`.
+    ///
+    /// # Panics
+    /// Panics if the number of residues is not equal to the number of moduli in the basis.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CrtPrecomputed;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let basis = Natural::precompute_crt_data(&[
+    ///     Natural::from(3u32),
+    ///     Natural::from(5u32),
+    ///     Natural::from(7u32),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(
+    ///     Natural::crt_precomputed(
+    ///         &[Natural::from(2u32), Natural::from(3u32), Natural::from(2u32)],
+    ///         &basis
+    ///     ),
+    ///     23
+    /// );
+    /// assert_eq!(
+    ///     Natural::crt_precomputed(
+    ///         &[Natural::from(5u32), Natural::from(8u32), Natural::from(9u32)],
+    ///         &basis
+    ///     ),
+    ///     23
+    /// );
+    /// ```
+    fn crt_precomputed(residues: &[Natural], data: &CrtBasis) -> Natural {
+        assert_eq!(residues.len(), data.moduli.len());
+        data.reconstruct(residues.iter().zip(data.moduli.iter()).map(|(r, m)| r % m))
+    }
+}
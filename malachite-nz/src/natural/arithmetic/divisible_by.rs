@@ -436,7 +436,7 @@ limbs_divisible_by_ref_ref(ns: &[Limb], ds: &[Limb]) -> bool {
 }}
 
 impl Natural {
-    fn divisible_by_limb(&self, other: Limb) -> bool {
+    pub(crate) fn divisible_by_limb(&self, other: Limb) -> bool {
         match (self, other) {
             (&natural_zero!(), _) => true,
             (_, 0) => false,
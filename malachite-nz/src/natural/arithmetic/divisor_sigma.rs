@@ -0,0 +1,157 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{DivExact, DivisorCount, DivisorSigma, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::Factor;
+
+// Computes $\sigma_k(p^e) = \sum_{i=0}^e p^{ik}$, where $p$ is prime.
+fn divisor_sigma_of_prime_power(p: &Natural, e: u64, k: u64) -> Natural {
+    if k == 0 {
+        Natural::from(e + 1)
+    } else {
+        let q = p.pow(k);
+        ((&q).pow(e + 1) - Natural::ONE).div_exact(q - Natural::ONE)
+    }
+}
+
+impl DivisorCount for Natural {
+    /// Counts the positive divisors of a [`Natural`].
+    ///
+    /// The [`Natural`] is factored first. If its factorization is already known, use
+    /// [`divisor_count_from_factors`](DivisorCount::divisor_count_from_factors) instead.
+    ///
+    /// $$
+    /// f(n) = \tau(n) = \sum_{d | n} 1.
+    /// $$
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{DivisorCount, Pow};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ONE.divisor_count(), 1);
+    /// assert_eq!(Natural::from(12u32).divisor_count(), 6);
+    /// assert_eq!(Natural::from(10u32).pow(20).divisor_count(), 441);
+    /// ```
+    #[inline]
+    fn divisor_count(&self) -> Natural {
+        Natural::divisor_count_from_factors(&self.factor())
+    }
+
+    /// Counts the positive divisors of a [`Natural`], given its prime factorization.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// $$
+    /// f(\\{(p_i, e_i)\\}) = \prod_i (e_i + 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivisorCount;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::divisor_count_from_factors(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::divisor_count_from_factors(&[
+    ///         (Natural::from(2u32), 20),
+    ///         (Natural::from(5u32), 20)
+    ///     ]),
+    ///     441
+    /// );
+    /// ```
+    fn divisor_count_from_factors(factors: &[(Natural, u64)]) -> Natural {
+        factors
+            .iter()
+            .fold(Natural::ONE, |tau, (_, e)| tau * Natural::from(e + 1))
+    }
+}
+
+impl DivisorSigma for Natural {
+    /// Computes the sum of the $k$th powers of the positive divisors of a [`Natural`].
+    ///
+    /// The [`Natural`] is factored first. If its factorization is already known, use
+    /// [`divisor_sigma_from_factors`](DivisorSigma::divisor_sigma_from_factors) instead.
+    ///
+    /// $$
+    /// f(n, k) = \sigma_k(n) = \sum_{d | n} d^k.
+    /// $$
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor), unless $k$ is large.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{DivisorSigma, Pow};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ONE.divisor_sigma(1), 1);
+    /// assert_eq!(Natural::from(12u32).divisor_sigma(0), 6);
+    /// assert_eq!(Natural::from(12u32).divisor_sigma(1), 28);
+    /// assert_eq!(Natural::from(12u32).divisor_sigma(2), 210);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(20).divisor_sigma(1),
+    ///     249999880790709924931u128
+    /// );
+    /// ```
+    #[inline]
+    fn divisor_sigma(&self, k: u64) -> Natural {
+        Natural::divisor_sigma_from_factors(&self.factor(), k)
+    }
+
+    /// Computes the sum of the $k$th powers of the positive divisors of a [`Natural`], given its
+    /// prime factorization.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// $$
+    /// f(\\{(p_i, e_i)\\}, k) = \prod_i \frac{p_i^{k(e_i+1)} - 1}{p_i^k - 1}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is $k$ times the number of bits of the
+    /// number whose factorization is `factors`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivisorSigma;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::divisor_sigma_from_factors(&[], 1), 1);
+    /// assert_eq!(
+    ///     Natural::divisor_sigma_from_factors(
+    ///         &[(Natural::from(2u32), 2), (Natural::from(3u32), 1)],
+    ///         2
+    ///     ),
+    ///     210
+    /// );
+    /// ```
+    fn divisor_sigma_from_factors(factors: &[(Natural, u64)], k: u64) -> Natural {
+        factors.iter().fold(Natural::ONE, |sigma, (p, e)| {
+            sigma * divisor_sigma_of_prime_power(p, *e, k)
+        })
+    }
+}
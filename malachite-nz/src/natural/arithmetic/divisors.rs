@@ -0,0 +1,99 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Divisors;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::Factor;
+use std::vec::IntoIter;
+
+impl Divisors for Natural {
+    type I = IntoIter<Natural>;
+
+    /// Generates the positive divisors of a [`Natural`], in ascending order.
+    ///
+    /// The [`Natural`] is factored first. If its factorization is already known, use
+    /// [`divisors_from_factors`](Divisors::divisors_from_factors) instead.
+    ///
+    /// The output length is $\tau(n)$, the number of divisors of $n$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor), unless `self` has very many
+    /// divisors.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Divisors, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(12u32).divisors().collect::<Vec<_>>().to_debug_string(),
+    ///     "[1, 2, 3, 4, 6, 12]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32)
+    ///         .pow(20)
+    ///         .divisors()
+    ///         .rev()
+    ///         .take(3)
+    ///         .collect::<Vec<_>>()
+    ///         .to_debug_string(),
+    ///     "[100000000000000000000, 50000000000000000000, 25000000000000000000]"
+    /// );
+    /// ```
+    #[inline]
+    fn divisors(&self) -> IntoIter<Natural> {
+        Natural::divisors_from_factors(&self.factor())
+    }
+
+    /// Generates the positive divisors of a [`Natural`], in ascending order, given its prime
+    /// factorization.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// The output length is $\prod_i (e_i + 1)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm^2 + n \log n)$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of divisors, and $m$ is the
+    /// number of bits of the number whose factorization is `factors`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Divisors;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::divisors_from_factors(&[]).collect::<Vec<_>>().to_debug_string(),
+    ///     "[1]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::divisors_from_factors(&[(Natural::from(3u32), 1), (Natural::from(2u32), 2)])
+    ///         .collect::<Vec<_>>()
+    ///         .to_debug_string(),
+    ///     "[1, 2, 3, 4, 6, 12]"
+    /// );
+    /// ```
+    fn divisors_from_factors(factors: &[(Natural, u64)]) -> IntoIter<Natural> {
+        let mut divisors = vec![Natural::ONE];
+        for (p, e) in factors {
+            let len = divisors.len();
+            let mut power = Natural::ONE;
+            for _ in 0..*e {
+                power *= p;
+                for i in 0..len {
+                    let divisor = &divisors[i] * &power;
+                    divisors.push(divisor);
+                }
+            }
+        }
+        divisors.sort_unstable();
+        divisors.into_iter()
+    }
+}
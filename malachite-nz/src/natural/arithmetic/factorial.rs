@@ -10,19 +10,23 @@ use crate::platform::{
     TABLE_2N_MINUS_POPC_2N, TABLE_LIMIT_2N_MINUS_POPC_2N,
 };
 use malachite_base::fail_on_untested_path;
+use malachite_base::num::arithmetic::binomial_coefficient::factorial_p_adic_valuation;
+use malachite_base::num::arithmetic::factorial::FACTORIAL_MOD_DIVISIBILITY_THRESHOLD;
 use malachite_base::num::arithmetic::traits::{
-    DoubleFactorial, Factorial, Gcd, Multifactorial, Parity, Pow, PowerOf2, Square, Subfactorial,
-    XMulYToZZ,
+    DoubleFactorial, Factorial, FactorialMod, Gcd, ModMulPrecomputed, ModMulPrecomputedAssign,
+    Multifactorial, Parity, Pow, PowerOf2, RemoveFactor, Square, Subfactorial, XMulYToZZ,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
-use malachite_base::num::basic::traits::One;
+use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, WrappingFrom};
 #[cfg(feature = "32_bit_limbs")]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u32;
 #[cfg(not(feature = "32_bit_limbs"))]
 use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u64;
 use malachite_base::num::factorization::prime_sieve::{id_to_n, limbs_prime_sieve_size, n_to_bit};
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
 use malachite_base::num::logic::traits::{BitAccess, CountOnes, NotAssign, SignificantBits};
+use std::cmp::min;
 
 pub_test! {subfactorial_naive(n: u64) -> Natural {
     let mut f = Natural::ONE;
@@ -676,3 +680,129 @@ impl Subfactorial for Natural {
         subfactorial_naive(n)
     }
 }
+
+// The largest prime that `known_to_divide_factorial` tries to divide by.
+const FACTORIAL_MOD_TRIAL_DIVISION_BOUND: u64 = 1 << 20;
+
+// Returns `true` if $m$ is known to divide $n!$, and `false` if it doesn't divide $n!$ or if this
+// could not be determined. The prime factors of $m$ up to $\min(n, B)$, where $B$ is
+// `FACTORIAL_MOD_TRIAL_DIVISION_BOUND`, are found by trial division, and their exponents are
+// compared with their exponents in $n!$, given by Legendre's formula. For $m$ to be known to divide
+// $n!$, the remaining cofactor must be 1, or a prime no greater than $n$.
+fn known_to_divide_factorial(m: &Natural, n: u64) -> bool {
+    let mut m = m.clone();
+    let bound = Limb::wrapping_from(min(n, FACTORIAL_MOD_TRIAL_DIVISION_BOUND));
+    for p in Limb::primes_less_than_or_equal_to(&bound) {
+        if !m.divisible_by_limb(p) {
+            continue;
+        }
+        let (cofactor, e) = m.remove_factor(&Natural::from(p));
+        if factorial_p_adic_valuation(n, u64::from(p)) < e {
+            return false;
+        }
+        m = cofactor;
+        if m == 1u32 {
+            return true;
+        }
+    }
+    m == 1u32 || (m <= n && m.is_prime())
+}
+
+fn factorial_mod_helper(n: u64, m: &Natural) -> Natural {
+    assert_ne!(*m, 0u32, "Cannot reduce modulo 0");
+    if let Ok(m) = Limb::try_from(m) {
+        return Natural::from(Limb::factorial_mod(n, m));
+    }
+    // If $n \geq m$, then $m$ is one of the factors of $n!$.
+    if *m <= n || (n >= FACTORIAL_MOD_DIVISIBILITY_THRESHOLD && known_to_divide_factorial(m, n)) {
+        return Natural::ZERO;
+    }
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(m);
+    let mut f = Natural::ONE;
+    for i in 2..=n {
+        f.mod_mul_precomputed_assign(Natural::from(i), m, &data);
+        // Once $m$ divides $i!$, it divides $n!$ as well.
+        if f == 0u32 {
+            break;
+        }
+    }
+    f
+}
+
+impl FactorialMod<Natural> for Natural {
+    /// Computes the factorial of a number modulo a [`Natural`] $m$. The [`Natural`] is taken by
+    /// value.
+    ///
+    /// $$
+    /// f(n, m) = n! \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log m \log\log m)$
+    ///
+    /// $M(m) = O(m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is
+    /// `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::FactorialMod;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::factorial_mod(5, Natural::from(7u32)), 1);
+    /// assert_eq!(Natural::factorial_mod(10, Natural::from(10u32)), 0);
+    /// assert_eq!(
+    ///     Natural::factorial_mod(100, Natural::from_str("1000000000000000000000007").unwrap())
+    ///         .to_string(),
+    ///     "803123216511661322057624"
+    /// );
+    /// ```
+    #[inline]
+    fn factorial_mod(n: u64, m: Natural) -> Natural {
+        factorial_mod_helper(n, &m)
+    }
+}
+
+impl<'a> FactorialMod<&'a Natural> for Natural {
+    /// Computes the factorial of a number modulo a [`Natural`] $m$. The [`Natural`] is taken by
+    /// reference.
+    ///
+    /// $$
+    /// f(n, m) = n! \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm \log m \log\log m)$
+    ///
+    /// $M(m) = O(m \log m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is
+    /// `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::FactorialMod;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::factorial_mod(5, &Natural::from(7u32)), 1);
+    /// assert_eq!(Natural::factorial_mod(10, &Natural::from(10u32)), 0);
+    /// assert_eq!(
+    ///     Natural::factorial_mod(100, &Natural::from_str("1000000000000000000000007").unwrap())
+    ///         .to_string(),
+    ///     "803123216511661322057624"
+    /// );
+    /// ```
+    #[inline]
+    fn factorial_mod(n: u64, m: &'a Natural) -> Natural {
+        factorial_mod_helper(n, m)
+    }
+}
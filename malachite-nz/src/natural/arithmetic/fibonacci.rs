@@ -0,0 +1,264 @@
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use crate::platform::{Limb, FIBONACCI_TABLE, FIBONACCI_TABLE_LIMIT};
+use malachite_base::num::arithmetic::traits::{
+    Fibonacci, FibonacciMod, ModAdd, ModMul, ModSub, Parity, Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Returns $(F_n, F_{n-1})$. Assumes $n > 0$.
+//
+// The top bits of $n$ are looked up in `FIBONACCI_TABLE`, and then each remaining bit is handled
+// with one doubling step. Each doubling step uses two squarings and no general multiplications:
+// $$
+// F_{2k+1} = 4F_k^2 - F_{k-1}^2 + 2(-1)^k,
+// $$
+// $$
+// F_{2k-1} = F_k^2 + F_{k-1}^2,
+// $$
+// $$
+// F_{2k} = F_{2k+1} - F_{2k-1}.
+// $$
+//
+// This is equivalent to `mpn_fib2_ui` from `mpn/generic/fib2_ui.c`, GMP 6.2.1.
+pub(crate) fn fibonacci_and_previous(n: u64) -> (Natural, Natural) {
+    assert_ne!(n, 0);
+    if n <= FIBONACCI_TABLE_LIMIT {
+        let i = usize::wrapping_from(n);
+        return (
+            Natural::from(FIBONACCI_TABLE[i]),
+            Natural::from(FIBONACCI_TABLE[i - 1]),
+        );
+    }
+    // Choose the shift so that the starting index is at least 1 and within the table.
+    let shift = n.significant_bits() - (FIBONACCI_TABLE_LIMIT.significant_bits() - 1);
+    let mut k = n >> shift;
+    let i = usize::wrapping_from(k);
+    let mut f = Natural::from(FIBONACCI_TABLE[i]);
+    let mut f_prev = Natural::from(FIBONACCI_TABLE[i - 1]);
+    for j in (0..shift).rev() {
+        let f_sq = f.square();
+        let f_prev_sq = f_prev.square();
+        // F_{2k+1}
+        let mut f_next = &f_sq << 2u32;
+        f_next -= &f_prev_sq;
+        if k.even() {
+            f_next += Natural::TWO;
+        } else {
+            f_next -= Natural::TWO;
+        }
+        // F_{2k-1}
+        let f_lo = f_sq + f_prev_sq;
+        // F_{2k}
+        let f_mid = &f_next - &f_lo;
+        if n.get_bit(j) {
+            f = f_next;
+            f_prev = f_mid;
+            k = (k << 1) | 1;
+        } else {
+            f = f_mid;
+            f_prev = f_lo;
+            k <<= 1;
+        }
+    }
+    (f, f_prev)
+}
+
+// Returns $(F_n \bmod m, F_{n+1} \bmod m)$, using the fast doubling identities
+// $F_{2k} = F_k(2F_{k+1} - F_k)$ and $F_{2k+1} = F_k^2 + F_{k+1}^2$. Assumes $m > 1$.
+pub(crate) fn fibonacci_pair_mod(n: u64, m: &Natural) -> (Natural, Natural) {
+    let mut f = Natural::ZERO;
+    let mut g = Natural::ONE;
+    for i in (0..n.significant_bits()).rev() {
+        let f_2 = (&f).mod_mul((&g).mod_add(&g, m).mod_sub(&f, m), m);
+        let g_2 = ((&f).square() + (&g).square()) % m;
+        if n.get_bit(i) {
+            g = (&f_2).mod_add(&g_2, m);
+            f = g_2;
+        } else {
+            f = f_2;
+            g = g_2;
+        }
+    }
+    (f, g)
+}
+
+impl Fibonacci for Natural {
+    /// Computes the $n$th Fibonacci number.
+    ///
+    /// $$
+    /// f(n) = F_n,
+    /// $$
+    /// where $F_0 = 0$, $F_1 = 1$, and $F_{n+2} = F_{n+1} + F_n$.
+    ///
+    /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci(0), 0);
+    /// assert_eq!(Natural::fibonacci(1), 1);
+    /// assert_eq!(Natural::fibonacci(2), 1);
+    /// assert_eq!(Natural::fibonacci(10), 55);
+    /// assert_eq!(
+    ///     Natural::fibonacci(100).to_string(),
+    ///     "354224848179261915075"
+    /// );
+    /// assert_eq!(
+    ///     Natural::fibonacci(200).to_string(),
+    ///     "280571172992510140037611932413038677189525"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_fib_ui` from `mpz/fib_ui.c`, GMP 6.2.1.
+    fn fibonacci(n: u64) -> Natural {
+        if n <= FIBONACCI_TABLE_LIMIT {
+            Natural::from(FIBONACCI_TABLE[usize::wrapping_from(n)])
+        } else {
+            fibonacci_and_previous(n).0
+        }
+    }
+
+    /// Computes the $n$th and $(n+1)$th Fibonacci numbers.
+    ///
+    /// $$
+    /// f(n) = (F_n, F_{n+1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci_pair(0).to_debug_string(), "(0, 1)");
+    /// assert_eq!(Natural::fibonacci_pair(10).to_debug_string(), "(55, 89)");
+    /// assert_eq!(
+    ///     Natural::fibonacci_pair(100).to_debug_string(),
+    ///     "(354224848179261915075, 573147844013817084101)"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_fib2_ui` from `mpz/fib2_ui.c`, GMP 6.2.1.
+    fn fibonacci_pair(n: u64) -> (Natural, Natural) {
+        if n < FIBONACCI_TABLE_LIMIT {
+            let i = usize::wrapping_from(n);
+            (
+                Natural::from(FIBONACCI_TABLE[i]),
+                Natural::from(FIBONACCI_TABLE[i + 1]),
+            )
+        } else {
+            let (f, f_prev) = fibonacci_and_previous(n);
+            let f_next = &f + f_prev;
+            (f, f_next)
+        }
+    }
+}
+
+fn fibonacci_mod_helper(n: u64, m: &Natural) -> Natural {
+    match m {
+        natural_zero!() => panic!("Cannot reduce modulo 0"),
+        Natural(Small(m)) => Natural::from(Limb::fibonacci_mod(n, *m)),
+        m => fibonacci_pair_mod(n, m).0,
+    }
+}
+
+impl FibonacciMod<Natural> for Natural {
+    /// Computes the $n$th Fibonacci number modulo a [`Natural`] $m$. The [`Natural`] is taken by
+    /// value.
+    ///
+    /// $$
+    /// f(n, m) = F_n \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::FibonacciMod;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::fibonacci_mod(10, Natural::from(10u32)), 5);
+    /// assert_eq!(
+    ///     Natural::fibonacci_mod(
+    ///         1000000000000000000,
+    ///         Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///     )
+    ///     .to_string(),
+    ///     "579082029062782147055949019488"
+    /// );
+    /// ```
+    #[inline]
+    fn fibonacci_mod(n: u64, m: Natural) -> Natural {
+        fibonacci_mod_helper(n, &m)
+    }
+}
+
+impl<'a> FibonacciMod<&'a Natural> for Natural {
+    /// Computes the $n$th Fibonacci number modulo a [`Natural`] $m$. The [`Natural`] is taken by
+    /// reference.
+    ///
+    /// $$
+    /// f(n, m) = F_n \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::FibonacciMod;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::fibonacci_mod(10, &Natural::from(10u32)), 5);
+    /// assert_eq!(
+    ///     Natural::fibonacci_mod(
+    ///         1000000000000000000,
+    ///         &Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///     )
+    ///     .to_string(),
+    ///     "579082029062782147055949019488"
+    /// );
+    /// ```
+    #[inline]
+    fn fibonacci_mod(n: u64, m: &'a Natural) -> Natural {
+        fibonacci_mod_helper(n, m)
+    }
+}
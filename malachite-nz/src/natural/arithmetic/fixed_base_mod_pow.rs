@@ -0,0 +1,215 @@
+use crate::natural::arithmetic::mod_multi_pow::{
+    finish, ModMulArithmetic, MontgomeryData, PlainArithmetic,
+};
+use crate::natural::arithmetic::mod_pow::{get_bits, get_window_size};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{DivRound, Parity, PowerOf2};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::min;
+
+// The entries of a `FixedBaseModPowTable`, in the representation used by the arithmetic: limbs in
+// Montgomery form if the modulus is odd, and ordinary `Natural`s otherwise.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Entries {
+    Montgomery(MontgomeryData, Vec<Vec<Limb>>),
+    Plain(Vec<Natural>),
+}
+
+fn build_table<A: ModMulArithmetic>(
+    arithmetic: &mut A,
+    x: &Natural,
+    rows: usize,
+    row_len: usize,
+) -> Vec<A::Element> {
+    let mut table = Vec::with_capacity(rows * row_len);
+    let mut base = arithmetic.convert_in(x);
+    for _ in 0..rows {
+        // Fill the row with base, base^2, ..., base^(2^w - 1), then move on to base^(2^w).
+        table.push(base.clone());
+        for _ in 1..row_len {
+            let mut next = table.last().unwrap().clone();
+            arithmetic.mul_assign(&mut next, &base);
+            table.push(next);
+        }
+        arithmetic.mul_assign(&mut base, table.last().unwrap());
+    }
+    table
+}
+
+fn table_pow<A: ModMulArithmetic>(
+    arithmetic: &mut A,
+    table: &[A::Element],
+    window_size: u64,
+    exp: &Natural,
+    m: &Natural,
+) -> Natural {
+    let width = exp.significant_bits();
+    let es = exp.to_limbs_asc();
+    let row_len = usize::power_of_2(window_size) - 1;
+    let mut acc = None;
+    let mut start = 0;
+    let mut row = 0;
+    while start < width {
+        let end = min(start + window_size, width);
+        let digit = get_bits(&es, end, end - start);
+        if digit != 0 {
+            arithmetic.mul_acc(&mut acc, &table[row * row_len + digit - 1]);
+        }
+        start = end;
+        row += 1;
+    }
+    finish(arithmetic, acc, m)
+}
+
+/// A precomputed table for raising a fixed base to many different powers modulo a fixed modulus
+/// $m$.
+///
+/// The exponent is split into windows of $w$ bits, and for each window position $i$ the table
+/// holds $x^{j2^{wi}} \mod m$ for every nonzero $w$-bit digit $j$. A power can then be computed
+/// by multiplying together one table entry per window, with no squarings at all, so that raising
+/// the base to an $n$-bit exponent takes about $n/w$ modular multiplications. The table takes up
+/// about $2^wn/w$ residues, where $n$ is the maximum number of bits of an exponent. The window
+/// size is chosen in the same way as for
+/// [`ModPow`](malachite_base::num::arithmetic::traits::ModPow).
+///
+/// If $m$ is odd, the arithmetic is done in Montgomery form.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FixedBaseModPowTable {
+    modulus: Natural,
+    // The number of exponent bits covered by each row of the table.
+    window_size: u64,
+    // The maximum number of bits of an exponent.
+    max_exp_bits: u64,
+    // Row i holds x^(j * 2^(wi)) for j in 1..2^w, where w is `window_size`.
+    entries: Entries,
+}
+
+impl FixedBaseModPowTable {
+    /// Precomputes a table for raising `x` to powers with at most `max_exp_bits` bits modulo `m`.
+    /// The base does not need to be reduced.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(2^wmn \log n \log\log n / w)$
+    ///
+    /// $M(n, m) = O(2^wmn / w)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, $m$ is
+    /// `max_exp_bits`, and $w$ is the window size, which grows slowly with $m$.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = FixedBaseModPowTable::new(&Natural::from(4u32), &Natural::from(497u32), 100);
+    /// assert_eq!(*table.modulus(), 497);
+    /// assert_eq!(table.max_exp_bits(), 100);
+    /// ```
+    pub fn new(x: &Natural, m: &Natural, max_exp_bits: u64) -> FixedBaseModPowTable {
+        assert_ne!(*m, 0u32, "modulus must be nonzero");
+        let window_size = get_window_size(max_exp_bits);
+        let row_len = usize::power_of_2(window_size) - 1;
+        let rows = usize::exact_from(max_exp_bits.div_round(window_size, RoundingMode::Ceiling));
+        let entries = if m.odd() {
+            let data = MontgomeryData::new(m);
+            let table = build_table(&mut data.arithmetic(), x, rows, row_len);
+            Entries::Montgomery(data, table)
+        } else {
+            Entries::Plain(build_table(&mut PlainArithmetic(m), x, rows, row_len))
+        };
+        FixedBaseModPowTable {
+            modulus: m.clone(),
+            window_size,
+            max_exp_bits,
+            entries,
+        }
+    }
+
+    /// Returns the modulus that the table was precomputed for.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = FixedBaseModPowTable::new(&Natural::from(4u32), &Natural::from(497u32), 100);
+    /// assert_eq!(*table.modulus(), 497);
+    /// ```
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Returns the maximum number of bits of an exponent that the table supports.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = FixedBaseModPowTable::new(&Natural::from(4u32), &Natural::from(497u32), 100);
+    /// assert_eq!(table.max_exp_bits(), 100);
+    /// ```
+    pub const fn max_exp_bits(&self) -> u64 {
+        self.max_exp_bits
+    }
+
+    /// Raises the table's base to a [`Natural`] power modulo the table's modulus $m$.
+    ///
+    /// $f(x, k, m) = y$, where $y < m$ and $x^k \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n / w)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`,
+    /// $m$ is `exp.significant_bits()`, and $w$ is the window size.
+    ///
+    /// # Panics
+    /// Panics if `exp` has more than `self.max_exp_bits()` bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = FixedBaseModPowTable::new(&Natural::from(4u32), &Natural::from(497u32), 100);
+    /// assert_eq!(table.pow(&Natural::from(13u32)), 445);
+    /// assert_eq!(table.pow(&Natural::from(0u32)), 1);
+    /// ```
+    pub fn pow(&self, exp: &Natural) -> Natural {
+        assert!(
+            exp.significant_bits() <= self.max_exp_bits,
+            "exponent has more than {} bits",
+            self.max_exp_bits
+        );
+        match &self.entries {
+            Entries::Montgomery(data, table) => table_pow(
+                &mut data.arithmetic(),
+                table,
+                self.window_size,
+                exp,
+                &self.modulus,
+            ),
+            Entries::Plain(table) => table_pow(
+                &mut PlainArithmetic(&self.modulus),
+                table,
+                self.window_size,
+                exp,
+                &self.modulus,
+            ),
+        }
+    }
+}
@@ -6,8 +6,9 @@ use crate::natural::comparison::cmp::limbs_cmp;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{Limb, BMOD_1_TO_MOD_1_THRESHOLD};
-use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign};
+use malachite_base::num::arithmetic::traits::{Gcd, GcdAssign, GcdOf};
 use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::num::logic::traits::TrailingZeros;
 use malachite_base::slices::slice_leading_zeros;
@@ -373,6 +374,95 @@ impl<'a> GcdAssign<&'a Natural> for Natural {
     }
 }
 
+impl GcdOf for Natural {
+    /// Computes the GCD (greatest common divisor) of all the [`Natural`]s in an iterator.
+    ///
+    /// The GCD of an empty iterator is 0. The running GCD only shrinks, so each step reduces a
+    /// possibly large [`Natural`] modulo a small one; the iterator is only consumed until the GCD
+    /// reaches 1.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \gcd(x_0, x_1, \ldots, x_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Natural::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::gcd_of(Vec::<Natural>::new().into_iter()), 0);
+    /// assert_eq!(
+    ///     Natural::gcd_of(vec_from_str::<Natural>("[12, 90, 42]").unwrap().into_iter()),
+    ///     6
+    /// );
+    /// assert_eq!(
+    ///     Natural::gcd_of(vec_from_str::<Natural>("[6, 10, 15]").unwrap().into_iter()),
+    ///     1
+    /// );
+    /// ```
+    fn gcd_of<I: Iterator<Item = Natural>>(xs: I) -> Natural {
+        let mut gcd = Natural::ZERO;
+        for x in xs {
+            gcd.gcd_assign(x);
+            if gcd == 1u32 {
+                return Natural::ONE;
+            }
+        }
+        gcd
+    }
+}
+
+impl<'a> GcdOf<&'a Natural> for Natural {
+    /// Computes the GCD (greatest common divisor) of all the [`Natural`]s in an iterator of
+    /// [`Natural`] references.
+    ///
+    /// The GCD of an empty iterator is 0. The running GCD only shrinks, so each step reduces a
+    /// possibly large [`Natural`] modulo a small one; the iterator is only consumed until the GCD
+    /// reaches 1.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \gcd(x_0, x_1, \ldots, x_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Natural::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::gcd_of(Vec::<Natural>::new().iter()), 0);
+    /// assert_eq!(Natural::gcd_of(vec_from_str::<Natural>("[12, 90, 42]").unwrap().iter()), 6);
+    /// assert_eq!(Natural::gcd_of(vec_from_str::<Natural>("[6, 10, 15]").unwrap().iter()), 1);
+    /// ```
+    fn gcd_of<I: Iterator<Item = &'a Natural>>(xs: I) -> Natural {
+        let mut gcd = Natural::ZERO;
+        for x in xs {
+            gcd.gcd_assign(x);
+            if gcd == 1u32 {
+                return Natural::ONE;
+            }
+        }
+        gcd
+    }
+}
+
 /// Implementations of [`ExtendedGcd`](malachite_base::num::arithmetic::traits::ExtendedGcd), a
 /// trait for computing the extended GCD of two numbers.
 pub mod extended_gcd;
@@ -1,6 +1,8 @@
 use crate::natural::Natural;
-use malachite_base::num::arithmetic::traits::{DivExact, DivExactAssign, Gcd, Lcm, LcmAssign};
-use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivExactAssign, Gcd, Lcm, LcmAssign, LcmOf,
+};
+use malachite_base::num::basic::traits::{One, Zero};
 
 impl Lcm<Natural> for Natural {
     type Output = Natural;
@@ -224,3 +226,124 @@ impl<'a> LcmAssign<&'a Natural> for Natural {
         *self *= other;
     }
 }
+
+// Combines adjacent pairs until a single LCM remains, so that the operands of each LCM have
+// roughly the same size. None of the `Natural`s may be 0.
+fn lcm_of_balanced(mut xs: Vec<Natural>) -> Natural {
+    while xs.len() > 1 {
+        let mut ys = Vec::with_capacity((xs.len() + 1) >> 1);
+        let mut xs_iter = xs.into_iter();
+        while let Some(x) = xs_iter.next() {
+            ys.push(match xs_iter.next() {
+                Some(y) => x.lcm(y),
+                None => x,
+            });
+        }
+        xs = ys;
+    }
+    xs.pop().unwrap_or(Natural::ONE)
+}
+
+impl LcmOf for Natural {
+    /// Computes the LCM (least common multiple) of all the [`Natural`]s in an iterator.
+    ///
+    /// The LCM of an empty iterator is 1. If any of the [`Natural`]s is 0, the LCM is 0, and the
+    /// iterator is not consumed any further. Rather than folding from the left, the LCM is
+    /// computed by combining adjacent pairs in a balanced tree, which keeps the operands of each
+    /// step of similar size.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \operatorname{lcm}(x_0, x_1, \ldots, x_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Natural::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lcm_of(Vec::<Natural>::new().into_iter()), 1);
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Natural>("[12, 90, 42]").unwrap().into_iter()),
+    ///     1260
+    /// );
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Natural>("[6, 0, 15]").unwrap().into_iter()),
+    ///     0
+    /// );
+    /// ```
+    fn lcm_of<I: Iterator<Item = Natural>>(xs: I) -> Natural {
+        let mut ys = Vec::new();
+        for x in xs {
+            if x == 0u32 {
+                return Natural::ZERO;
+            }
+            ys.push(x);
+        }
+        lcm_of_balanced(ys)
+    }
+}
+
+impl<'a> LcmOf<&'a Natural> for Natural {
+    /// Computes the LCM (least common multiple) of all the [`Natural`]s in an iterator of
+    /// [`Natural`] references.
+    ///
+    /// The LCM of an empty iterator is 1. If any of the [`Natural`]s is 0, the LCM is 0, and the
+    /// iterator is not consumed any further. Rather than folding from the left, the LCM is
+    /// computed by combining adjacent pairs in a balanced tree, which keeps the operands of each
+    /// step of similar size.
+    ///
+    /// $$
+    /// f((x_i)_ {i=0}^{n-1}) = \operatorname{lcm}(x_0, x_1, \ldots, x_{n-1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Natural::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LcmOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lcm_of(Vec::<Natural>::new().iter()), 1);
+    /// assert_eq!(
+    ///     Natural::lcm_of(vec_from_str::<Natural>("[12, 90, 42]").unwrap().iter()),
+    ///     1260
+    /// );
+    /// assert_eq!(Natural::lcm_of(vec_from_str::<Natural>("[6, 0, 15]").unwrap().iter()), 0);
+    /// ```
+    fn lcm_of<I: Iterator<Item = &'a Natural>>(xs: I) -> Natural {
+        // The first level of the tree is computed from the references directly, so that the
+        // inputs are never cloned (except for an unpaired last element)
+        let mut ys = Vec::new();
+        let mut pending: Option<&Natural> = None;
+        for x in xs {
+            if *x == 0u32 {
+                return Natural::ZERO;
+            }
+            if let Some(p) = pending.take() {
+                ys.push(p.lcm(x));
+            } else {
+                pending = Some(x);
+            }
+        }
+        if let Some(p) = pending {
+            ys.push(p.clone());
+        }
+        lcm_of_balanced(ys)
+    }
+}
@@ -0,0 +1,353 @@
+use crate::natural::arithmetic::fibonacci::{fibonacci_and_previous, fibonacci_pair_mod};
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use crate::platform::{Limb, FIBONACCI_TABLE, LUCAS_TABLE_LIMIT};
+use malachite_base::num::arithmetic::traits::{
+    Lucas, LucasMod, LucasSequenceMod, ModAdd, ModMul, ModSub, Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+impl Lucas for Natural {
+    /// Computes the $n$th Lucas number.
+    ///
+    /// $$
+    /// f(n) = L_n,
+    /// $$
+    /// where $L_0 = 2$, $L_1 = 1$, and $L_{n+2} = L_{n+1} + L_n$.
+    ///
+    /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lucas;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lucas(0), 2);
+    /// assert_eq!(Natural::lucas(1), 1);
+    /// assert_eq!(Natural::lucas(2), 3);
+    /// assert_eq!(Natural::lucas(10), 123);
+    /// assert_eq!(Natural::lucas(100).to_string(), "792070839848372253127");
+    /// assert_eq!(
+    ///     Natural::lucas(200).to_string(),
+    ///     "627376215338105766356982006981782561278127"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_lucnum_ui` from `mpz/lucnum_ui.c`, GMP 6.2.1.
+    fn lucas(n: u64) -> Natural {
+        if n == 0 {
+            Natural::TWO
+        } else if n <= LUCAS_TABLE_LIMIT {
+            // L_n = F_n + 2F_{n-1}
+            let i = usize::wrapping_from(n);
+            Natural::from(FIBONACCI_TABLE[i] + (FIBONACCI_TABLE[i - 1] << 1))
+        } else {
+            let (f, f_prev) = fibonacci_and_previous(n);
+            f + (f_prev << 1)
+        }
+    }
+
+    /// Computes the $n$th and $(n+1)$th Lucas numbers.
+    ///
+    /// $$
+    /// f(n) = (L_n, L_{n+1}).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lucas;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lucas_pair(0).to_debug_string(), "(2, 1)");
+    /// assert_eq!(Natural::lucas_pair(10).to_debug_string(), "(123, 199)");
+    /// assert_eq!(
+    ///     Natural::lucas_pair(100).to_debug_string(),
+    ///     "(792070839848372253127, 1281597540372340914251)"
+    /// );
+    /// ```
+    ///
+    /// This is equivalent to `mpz_lucnum2_ui` from `mpz/lucnum2_ui.c`, GMP 6.2.1.
+    fn lucas_pair(n: u64) -> (Natural, Natural) {
+        if n == 0 {
+            (Natural::TWO, Natural::ONE)
+        } else {
+            // L_n = F_n + 2F_{n-1} and L_{n+1} = 3F_n + F_{n-1}
+            let (f, f_prev) = fibonacci_and_previous(n);
+            let l = &f + (&f_prev << 1);
+            let l_next = (&f << 1) + f + f_prev;
+            (l, l_next)
+        }
+    }
+}
+
+fn lucas_mod_helper(n: u64, m: &Natural) -> Natural {
+    match m {
+        natural_zero!() => panic!("Cannot reduce modulo 0"),
+        Natural(Small(m)) => Natural::from(Limb::lucas_mod(n, *m)),
+        m => {
+            // L_n = 2F_{n+1} - F_n
+            let (f, f_next) = fibonacci_pair_mod(n, m);
+            (&f_next).mod_add(&f_next, m).mod_sub(f, m)
+        }
+    }
+}
+
+impl LucasMod<Natural> for Natural {
+    /// Computes the $n$th Lucas number modulo a [`Natural`] $m$. The [`Natural`] is taken by
+    /// value.
+    ///
+    /// $$
+    /// f(n, m) = L_n \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LucasMod;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::lucas_mod(10, Natural::from(10u32)), 3);
+    /// assert_eq!(
+    ///     Natural::lucas_mod(
+    ///         1000000000000000000,
+    ///         Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///     )
+    ///     .to_string(),
+    ///     "273674495706502311781176843414"
+    /// );
+    /// ```
+    #[inline]
+    fn lucas_mod(n: u64, m: Natural) -> Natural {
+        lucas_mod_helper(n, &m)
+    }
+}
+
+impl<'a> LucasMod<&'a Natural> for Natural {
+    /// Computes the $n$th Lucas number modulo a [`Natural`] $m$. The [`Natural`] is taken by
+    /// reference.
+    ///
+    /// $$
+    /// f(n, m) = L_n \bmod m.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `n.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LucasMod;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Natural::lucas_mod(10, &Natural::from(10u32)), 3);
+    /// assert_eq!(
+    ///     Natural::lucas_mod(
+    ///         1000000000000000000,
+    ///         &Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///     )
+    ///     .to_string(),
+    ///     "273674495706502311781176843414"
+    /// );
+    /// ```
+    #[inline]
+    fn lucas_mod(n: u64, m: &'a Natural) -> Natural {
+        lucas_mod_helper(n, m)
+    }
+}
+
+// Computes $U_k$ and $V_k$ modulo $m$ with the ladder from Joye and Quisquater, "Efficient
+// computation of full Lucas sequences", 1996. No divisions are needed, so $m$ may be even. Assumes
+// that $k > 0$, $m > 1$, and that $p$ and $q$ are reduced modulo $m$.
+fn lucas_sequence_mod_ladder(
+    k: &Natural,
+    p: &Natural,
+    q: &Natural,
+    m: &Natural,
+) -> (Natural, Natural) {
+    let s = k.trailing_zeros().unwrap();
+    let mut u_h = Natural::ONE;
+    let mut v_l = Natural::TWO % m;
+    let mut v_h = p.clone();
+    let mut q_l = Natural::ONE;
+    let mut q_h = Natural::ONE;
+    for j in (s + 1..k.significant_bits()).rev() {
+        q_l = q_l.mod_mul(&q_h, m);
+        if k.get_bit(j) {
+            q_h = (&q_l).mod_mul(q, m);
+            u_h = u_h.mod_mul(&v_h, m);
+            v_l = (&v_h).mod_mul(v_l, m).mod_sub(p.mod_mul(&q_l, m), m);
+            v_h = ((&v_h).square() % m).mod_sub((&q_h).mod_add(&q_h, m), m);
+        } else {
+            q_h = q_l.clone();
+            u_h = u_h.mod_mul(&v_l, m).mod_sub(&q_l, m);
+            v_h = v_h.mod_mul(&v_l, m).mod_sub(p.mod_mul(&q_l, m), m);
+            v_l = ((&v_l).square() % m).mod_sub((&q_l).mod_add(&q_l, m), m);
+        }
+    }
+    q_l = q_l.mod_mul(q_h, m);
+    q_h = (&q_l).mod_mul(q, m);
+    u_h = u_h.mod_mul(&v_l, m).mod_sub(&q_l, m);
+    v_l = v_h.mod_mul(v_l, m).mod_sub(p.mod_mul(&q_l, m), m);
+    q_l = q_l.mod_mul(q_h, m);
+    for _ in 0..s {
+        u_h = u_h.mod_mul(&v_l, m);
+        v_l = ((&v_l).square() % m).mod_sub((&q_l).mod_add(&q_l, m), m);
+        q_l = (&q_l).square() % m;
+    }
+    (u_h, v_l)
+}
+
+fn lucas_sequence_mod_helper(
+    k: &Natural,
+    p: &Natural,
+    q: &Natural,
+    m: &Natural,
+) -> (Natural, Natural) {
+    match (k, p, q, m) {
+        (_, _, _, natural_zero!()) => panic!("Cannot reduce modulo 0"),
+        (_, _, _, natural_one!()) => (Natural::ZERO, Natural::ZERO),
+        (natural_zero!(), _, _, _) => (Natural::ZERO, Natural::TWO % m),
+        (Natural(Small(k)), Natural(Small(p)), Natural(Small(q)), Natural(Small(m))) => {
+            let (u, v) = k.lucas_sequence_mod(*p, *q, *m);
+            (Natural::from(u), Natural::from(v))
+        }
+        _ => lucas_sequence_mod_ladder(k, &(p % m), &(q % m), m),
+    }
+}
+
+impl LucasSequenceMod<Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes $U_k(P, Q)$ and $V_k(P, Q)$ modulo a [`Natural`] $m$, where $k$ is `self`. All
+    /// four [`Natural`]s are taken by value.
+    ///
+    /// The Lucas sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and
+    /// $X_{k+2} = PX_{k+1} - QX_k$ for $X \in \\{U, V\\}$. A negative $Q$ should be passed as its
+    /// residue modulo $m$. $P$ and $Q$ need not be reduced.
+    ///
+    /// $$
+    /// f(k, P, Q, m) = (U_k(P, Q) \bmod m, V_k(P, Q) \bmod m).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LucasSequenceMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // U_k(3, 2) = 2^k - 1 and V_k(3, 2) = 2^k + 1
+    /// assert_eq!(
+    ///     Natural::from(1000u32)
+    ///         .lucas_sequence_mod(
+    ///             Natural::from(3u32),
+    ///             Natural::from(2u32),
+    ///             Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(141502251827270929530186206575, 141502251827270929530186206577)"
+    /// );
+    /// ```
+    #[inline]
+    fn lucas_sequence_mod(self, p: Natural, q: Natural, m: Natural) -> (Natural, Natural) {
+        lucas_sequence_mod_helper(&self, &p, &q, &m)
+    }
+}
+
+impl<'a> LucasSequenceMod<&'a Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Computes $U_k(P, Q)$ and $V_k(P, Q)$ modulo a [`Natural`] $m$, where $k$ is `self`. All
+    /// four [`Natural`]s are taken by reference.
+    ///
+    /// The Lucas sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and
+    /// $X_{k+2} = PX_{k+1} - QX_k$ for $X \in \\{U, V\\}$. A negative $Q$ should be passed as its
+    /// residue modulo $m$. $P$ and $Q$ need not be reduced.
+    ///
+    /// $$
+    /// f(k, P, Q, m) = (U_k(P, Q) \bmod m, V_k(P, Q) \bmod m).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LucasSequenceMod;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let m = Natural::from_str("1000000000000000000000000000057").unwrap();
+    /// assert_eq!(
+    ///     (&Natural::from(1000u32))
+    ///         .lucas_sequence_mod(&Natural::from(5u32), &(&m - Natural::from(3u32)), &m)
+    ///         .to_debug_string(),
+    ///     "(227971715182048756672734975445, 39670250579260806896050641219)"
+    /// );
+    /// ```
+    #[inline]
+    fn lucas_sequence_mod(
+        self,
+        p: &'a Natural,
+        q: &'a Natural,
+        m: &'a Natural,
+    ) -> (Natural, Natural) {
+        lucas_sequence_mod_helper(self, p, q, m)
+    }
+}
@@ -6,8 +6,20 @@ pub mod add;
 pub mod add_mul;
 /// Implementations of
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
-/// for computing the binomial coefficient of two numbers.
+/// for computing the binomial coefficient of two numbers;
+/// [`BinomialCoefficientMod`](malachite_base::num::arithmetic::traits::BinomialCoefficientMod),
+/// which computes it modulo a third number; and
+/// [`MultinomialCoefficient`](malachite_base::num::arithmetic::traits::MultinomialCoefficient) and
+/// [`MultinomialCoefficientMod`](malachite_base::num::arithmetic::traits::MultinomialCoefficientMod),
+/// their multinomial analogs.
 pub mod binomial_coefficient;
+/// An implementation of
+/// [`CarmichaelLambda`](malachite_base::num::arithmetic::traits::CarmichaelLambda), a trait for
+/// computing the Carmichael function of a number.
+pub mod carmichael_lambda;
+/// An implementation of [`Catalan`](malachite_base::num::arithmetic::traits::Catalan), a trait for
+/// computing Catalan numbers.
+pub mod catalan;
 /// Implementations of [`CheckedSub`](malachite_base::num::arithmetic::traits::CheckedSub), a trait
 /// for subtracting two numbers and checking whether the result is representable.
 pub mod checked_sub;
@@ -18,6 +30,10 @@ pub mod checked_sub_mul;
 /// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
 /// trait for determining whether two numbers are coprime.
 pub mod coprime_with;
+/// Implementations of [`Crt`](malachite_base::num::arithmetic::traits::Crt) and
+/// [`CrtPrecomputed`](malachite_base::num::arithmetic::traits::CrtPrecomputed), traits for solving
+/// systems of simultaneous congruences using the Chinese Remainder Theorem.
+pub mod crt;
 /// Division of [`Natural`](super::Natural)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -52,6 +68,13 @@ pub mod divisible_by;
 /// for determining whether a number
 /// is divisible by $2^k$.
 pub mod divisible_by_power_of_2;
+/// Implementations of [`DivisorCount`](malachite_base::num::arithmetic::traits::DivisorCount) and
+/// [`DivisorSigma`](malachite_base::num::arithmetic::traits::DivisorSigma), traits for counting
+/// the divisors of a number and for summing powers of them.
+pub mod divisor_sigma;
+/// An implementation of [`Divisors`](malachite_base::num::arithmetic::traits::Divisors), a trait
+/// for generating the divisors of a number.
+pub mod divisors;
 /// Implementations of [`EqMod`](malachite_base::num::arithmetic::traits::EqMod), a trait for
 /// determining whether one number is equal by another modulo a third.
 pub mod eq_mod;
@@ -60,12 +83,21 @@ pub mod eq_mod;
 pub mod eq_mod_power_of_2;
 /// Implementations of [`Factorial`](malachite_base::num::arithmetic::traits::Factorial),
 /// [`DoubleFactorial`](malachite_base::num::arithmetic::traits::DoubleFactorial),
-/// [`Multifactorial`](malachite_base::num::arithmetic::traits::Multifactorial), and
-/// [`Subfactorial`](malachite_base::num::arithmetic::traits::Subfactorial).
+/// [`Multifactorial`](malachite_base::num::arithmetic::traits::Multifactorial),
+/// [`Subfactorial`](malachite_base::num::arithmetic::traits::Subfactorial), and
+/// [`FactorialMod`](malachite_base::num::arithmetic::traits::FactorialMod).
 pub mod factorial;
-/// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd) and
-/// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
-/// (greatest common divisor) of two numbers.
+/// Implementations of [`Fibonacci`](malachite_base::num::arithmetic::traits::Fibonacci) and
+/// [`FibonacciMod`](malachite_base::num::arithmetic::traits::FibonacciMod), traits for computing
+/// Fibonacci numbers.
+pub mod fibonacci;
+/// [`FixedBaseModPowTable`](fixed_base_mod_pow::FixedBaseModPowTable), a precomputed table for
+/// raising a fixed base to many powers modulo a fixed modulus.
+pub mod fixed_base_mod_pow;
+/// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd),
+/// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), and
+/// [`GcdOf`](malachite_base::num::arithmetic::traits::GcdOf), traits for computing the GCD
+/// (greatest common divisor) of two or more numbers.
 pub mod gcd;
 /// Implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a trait
 /// for determining whether a number is an integer power of 2.
@@ -76,9 +108,10 @@ pub mod is_power_of_2;
 /// computing the Legendre, Jacobi, and Kronecker symbols of two numbers.
 pub mod kronecker_symbol;
 /// Implementations of [`Lcm`](malachite_base::num::arithmetic::traits::Lcm),
-/// [`LcmAssign`](malachite_base::num::arithmetic::traits::LcmAssign), and
-/// [`CheckedLcm`](malachite_base::num::arithmetic::traits::CheckedLcm), traits for computing the
-/// LCM (least common multiple) of two numbers.
+/// [`LcmAssign`](malachite_base::num::arithmetic::traits::LcmAssign),
+/// [`CheckedLcm`](malachite_base::num::arithmetic::traits::CheckedLcm), and
+/// [`LcmOf`](malachite_base::num::arithmetic::traits::LcmOf), traits for computing the LCM (least
+/// common multiple) of two or more numbers.
 pub mod lcm;
 /// Implementations of traits for taking the base-$b$ logarithm of a number.
 ///
@@ -100,6 +133,11 @@ pub mod log_base_2;
 /// and
 /// [`CheckedLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CheckedLogBasePowerOf2).
 pub mod log_base_power_of_2;
+/// Implementations of [`Lucas`](malachite_base::num::arithmetic::traits::Lucas),
+/// [`LucasMod`](malachite_base::num::arithmetic::traits::LucasMod), and
+/// [`LucasSequenceMod`](malachite_base::num::arithmetic::traits::LucasSequenceMod), traits for
+/// computing Lucas numbers and Lucas sequences.
+pub mod lucas;
 /// Implementations of [`ModAdd`](malachite_base::num::arithmetic::traits::ModAdd) and
 /// [`ModAddAssign`](malachite_base::num::arithmetic::traits::ModAddAssign), traits for adding two
 /// numbers modulo another number.
@@ -111,6 +149,9 @@ pub mod mod_inverse;
 /// Implementations of [`ModIsReduced`](malachite_base::num::arithmetic::traits::ModIsReduced), a
 /// trait for checking whether a number is reduced modulo another number.
 pub mod mod_is_reduced;
+/// Implementations of [`ModLog`](malachite_base::num::arithmetic::traits::ModLog), a trait for
+/// finding the discrete logarithm of a number to a given base, modulo another number.
+pub mod mod_log;
 /// Implementations of traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](malachite_base::num::arithmetic::traits::ModMul),
@@ -121,6 +162,9 @@ pub mod mod_is_reduced;
 /// [`ModMulPrecomputedAssign`](malachite_base::num::arithmetic::traits::ModMulPrecomputedAssign)
 /// are useful when having to make several multiplications modulo the same modulus.
 pub mod mod_mul;
+/// Multi-exponentiation: computing a product of several powers modulo a [`Natural`](super::Natural)
+/// faster than computing each power separately.
+pub mod mod_multi_pow;
 /// Implementations of [`ModNeg`](malachite_base::num::arithmetic::traits::ModNeg) and
 /// [`ModNegAssign`](malachite_base::num::arithmetic::traits::ModNegAssign), traits for negating a
 /// number modulo another number.
@@ -478,6 +522,9 @@ pub mod mod_shl;
 /// assert_eq!(x, 7436663564915145u64);
 /// ```
 pub mod mod_shr;
+/// An implementation of [`ModSqrt`](malachite_base::num::arithmetic::traits::ModSqrt), a trait for
+/// finding square roots of a number modulo another number.
+pub mod mod_sqrt;
 /// Implementations of traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare),
@@ -490,8 +537,18 @@ pub mod mod_square;
 /// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
 /// two numbers modulo another number.
 pub mod mod_sub;
+/// An implementation of [`MoebiusMu`](malachite_base::num::arithmetic::traits::MoebiusMu), a trait
+/// for computing the Möbius function of a number.
+pub mod moebius_mu;
+/// [`MontgomeryContext`](montgomery::MontgomeryContext), a precomputed context for multiplying
+/// [`Natural`](super::Natural)s modulo a fixed odd modulus using Montgomery reduction.
+pub mod montgomery;
 /// Multiplication of [`Natural`](super::Natural)s.
 pub mod mul;
+/// Implementations of
+/// [`MultiplicativeOrder`](malachite_base::num::arithmetic::traits::MultiplicativeOrder), a trait
+/// for computing the multiplicative order of a number modulo another number.
+pub mod multiplicative_order;
 /// Negation of a [`Natural`](super::Natural), returning an [`Integer`](crate::integer::Integer).
 pub mod neg;
 /// Implementations of [`NextPowerOf2`](malachite_base::num::arithmetic::traits::NextPowerOf2) and
@@ -501,6 +558,15 @@ pub mod next_power_of_2;
 /// Implementations of [`Parity`](malachite_base::num::arithmetic::traits::Parity), a trait for
 /// determining whether a number is even or odd.
 pub mod parity;
+/// An implementation of
+/// [`PartitionCount`](malachite_base::num::arithmetic::traits::PartitionCount), a trait for
+/// computing the number of partitions of a number.
+pub mod partition_count;
+/// Implementations of [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower)
+/// and [`PerfectPowerDecompose`](malachite_base::num::arithmetic::traits::PerfectPowerDecompose),
+/// traits for determining whether a number is a perfect power and for writing it as a perfect power
+/// with the largest possible exponent.
+pub mod perfect_power;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
@@ -508,9 +574,18 @@ pub mod pow;
 /// Implementations of [`PowerOf2`](malachite_base::num::arithmetic::traits::PowerOf2), a trait for
 /// computing a power of 2.
 pub mod power_of_2;
+/// [`PrecomputedDivisor`](precomputed_divisor::PrecomputedDivisor), a precomputed divisor for
+/// dividing many [`Natural`](super::Natural)s by the same [`Natural`](super::Natural).
+pub mod precomputed_divisor;
+/// An implementation of [`PrimitiveRoot`](malachite_base::num::arithmetic::traits::PrimitiveRoot),
+/// a trait for finding the smallest primitive root modulo a number.
+pub mod primitive_root;
 /// An implementation of [`Primorial`](malachite_base::num::arithmetic::traits::Primorial), a trait
 /// for computing the primorial of a number.
 pub mod primorial;
+/// Rational reconstruction: recovering a fraction with bounded numerator and denominator from its
+/// residue modulo a [`Natural`](super::Natural).
+pub mod rational_reconstruction;
 /// Implementations of traits for taking the $n$th root of a number.
 ///
 /// The traits are [`FloorRoot`](malachite_base::num::arithmetic::traits::FloorRoot),
@@ -910,9 +985,21 @@ pub mod sqrt;
 /// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
 /// number.
 pub mod square;
+/// Implementations of [`StirlingFirst`](malachite_base::num::arithmetic::traits::StirlingFirst),
+/// [`StirlingSecond`](malachite_base::num::arithmetic::traits::StirlingSecond), and
+/// [`Bell`](malachite_base::num::arithmetic::traits::Bell), traits for computing Stirling numbers
+/// of both kinds and Bell numbers.
+pub mod stirling;
 /// Subtraction of [`Natural`](super::Natural)s.
 pub mod sub;
 /// Implementations of [`SubMul`](malachite_base::num::arithmetic::traits::SubMul) and
 /// [`SubMulAssign`](malachite_base::num::arithmetic::traits::SubMulAssign), traits for subtracting
 /// the product of two numbers from a number.
 pub mod sub_mul;
+/// An implementation of [`Totient`](malachite_base::num::arithmetic::traits::Totient), a trait for
+/// computing Euler's totient function of a number.
+pub mod totient;
+/// Implementations of [`Valuation`](malachite_base::num::arithmetic::traits::Valuation) and
+/// [`RemoveFactor`](malachite_base::num::arithmetic::traits::RemoveFactor), traits for finding the
+/// $p$-adic valuation of a number and for removing all factors of $p$ from it.
+pub mod valuation;
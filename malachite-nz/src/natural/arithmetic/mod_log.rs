@@ -0,0 +1,363 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, Crt, DivExact, DivisibleBy, Gcd, ModAdd, ModInverse, ModLog, ModMul, ModMulAssign,
+    ModPow, ModSquareAssign, ModSub, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::num::logic::traits::SignificantBits;
+use std::collections::HashMap;
+
+// Discrete logarithms in subgroups whose prime order has at most this many bits are found using
+// baby-step giant-step, which needs a table of about $2^{b/2}$ entries. Larger subgroups use
+// Pollard's rho algorithm, which needs constant memory.
+const MOD_LOG_BSGS_MAX_BITS: u64 = 32;
+
+// Returns the least $x$ such that $\gamma^x \equiv \beta \mod m$, or `None` if there is none,
+// using the baby-step giant-step algorithm. $\gamma$ must be coprime to $m$ and have order $n$.
+pub_test! {mod_log_baby_step_giant_step(
+    gamma: &Natural,
+    beta: &Natural,
+    n: &Natural,
+    m: &Natural,
+) -> Option<Natural> {
+    let s = n.ceiling_sqrt();
+    let mut table = HashMap::new();
+    let mut x = Natural::ONE;
+    let mut j = Natural::ZERO;
+    while j < s {
+        table.entry(x.clone()).or_insert_with(|| j.clone());
+        x.mod_mul_assign(gamma, m);
+        j += Natural::ONE;
+    }
+    // x is now gamma^s.
+    let giant_step = x.mod_inverse(m).unwrap();
+    let mut y = beta.clone();
+    let mut i = Natural::ZERO;
+    while i < s {
+        if let Some(j) = table.get(&y) {
+            return Some(i * &s + j);
+        }
+        y.mod_mul_assign(&giant_step, m);
+        i += Natural::ONE;
+    }
+    None
+}}
+
+// One step of the walk used by Pollard's rho algorithm. Each walk element is
+// x = gamma^a * beta^b, and the walk squares x, or multiplies it by gamma or beta, depending on
+// x mod 3.
+fn mod_log_pollard_rho_step(
+    x: &mut Natural,
+    a: &mut Natural,
+    b: &mut Natural,
+    gamma: &Natural,
+    beta: &Natural,
+    q: &Natural,
+    m: &Natural,
+) {
+    match u32::wrapping_from(&(&*x % Natural::from(3u32))) {
+        0 => {
+            x.mod_square_assign(m);
+            *a = (&*a).mod_add(&*a, q);
+            *b = (&*b).mod_add(&*b, q);
+        }
+        1 => {
+            x.mod_mul_assign(gamma, m);
+            *a = (&*a).mod_add(Natural::ONE, q);
+        }
+        _ => {
+            x.mod_mul_assign(beta, m);
+            *b = (&*b).mod_add(Natural::ONE, q);
+        }
+    }
+}
+
+// Returns $x$ such that $\gamma^x \equiv \beta \mod m$, using Pollard's rho algorithm. $\gamma$
+// must have prime order $q$ modulo $m$, and $\beta$ must be a power of $\gamma$; otherwise, this
+// function may not terminate.
+pub_test! {mod_log_pollard_rho(
+    gamma: &Natural,
+    beta: &Natural,
+    q: &Natural,
+    m: &Natural,
+) -> Natural {
+    // If a walk fails, restart it from gamma^(start + 1) * beta.
+    let mut start = Natural::ONE;
+    let mut start_x = gamma.mod_mul(beta, m);
+    loop {
+        let mut x = start_x.clone();
+        let mut a = &start % q;
+        let mut b = Natural::ONE;
+        let mut y = x.clone();
+        let mut c = a.clone();
+        let mut d = b.clone();
+        // Floyd's cycle-finding algorithm
+        loop {
+            mod_log_pollard_rho_step(&mut x, &mut a, &mut b, gamma, beta, q, m);
+            mod_log_pollard_rho_step(&mut y, &mut c, &mut d, gamma, beta, q, m);
+            mod_log_pollard_rho_step(&mut y, &mut c, &mut d, gamma, beta, q, m);
+            if x == y {
+                break;
+            }
+        }
+        // gamma^a * beta^b = gamma^c * beta^d, so beta^(b - d) = gamma^(c - a).
+        if b != d {
+            let log = c
+                .mod_sub(a, q)
+                .mod_mul(b.mod_sub(d, q).mod_inverse(q).unwrap(), q);
+            if gamma.mod_pow(&log, m) == *beta {
+                return log;
+            }
+        }
+        start += Natural::ONE;
+        start_x.mod_mul_assign(gamma, m);
+    }
+}}
+
+// Returns the least $x$ such that $\gamma^x \equiv \beta \mod m$, or `None` if there is none.
+// $\gamma$ must have prime order $q$ modulo $m$, and the multiplicative group modulo $m$ must be
+// cyclic unless $q$ is small.
+fn mod_log_prime_order(
+    gamma: &Natural,
+    beta: &Natural,
+    q: &Natural,
+    m: &Natural,
+) -> Option<Natural> {
+    if *beta == 1u32 {
+        Some(Natural::ZERO)
+    } else if q.significant_bits() <= MOD_LOG_BSGS_MAX_BITS {
+        mod_log_baby_step_giant_step(gamma, beta, q, m)
+    } else if beta.mod_pow(q, m) != 1u32 {
+        // In a cyclic group, the elements whose order divides q are exactly the powers of gamma.
+        None
+    } else {
+        Some(mod_log_pollard_rho(gamma, beta, q, m))
+    }
+}
+
+// Returns the least $x$ such that $g^x \equiv h \mod p^k$, together with the order of $g$, or
+// `None` if there is no such $x$. $p$ must be prime, $g$ must be coprime to $p$, and `pk` must be
+// $p^k$.
+//
+// The order of $g$ is found from the factorization of $\varphi(p^k) = p^{k-1}(p-1)$, and then the
+// Pohlig-Hellman algorithm reduces the problem to one discrete logarithm in a subgroup of prime
+// order $q$ for each factor $q$ of the order.
+fn mod_log_prime_power(
+    g: &Natural,
+    h: &Natural,
+    p: &Natural,
+    k: u64,
+    pk: &Natural,
+) -> Option<(Natural, Natural)> {
+    if h.divisible_by(p) {
+        return None;
+    }
+    let p_minus_1 = p - Natural::ONE;
+    let mut factors = if *p == 2u32 {
+        Vec::new()
+    } else {
+        p_minus_1.factor()
+    };
+    if k > 1 {
+        factors.push((p.clone(), k - 1));
+    }
+    let mut n = pk.div_exact(p) * p_minus_1;
+    let mut order_factors = Vec::with_capacity(factors.len());
+    for (q, mut e) in factors {
+        while e != 0 && g.mod_pow((&n).div_exact(&q), pk) == 1u32 {
+            n = n.div_exact(&q);
+            e -= 1;
+        }
+        if e != 0 {
+            order_factors.push((q, e));
+        }
+    }
+    let mut congruences = Vec::with_capacity(order_factors.len());
+    for (q, e) in order_factors {
+        let q_e = (&q).pow(e);
+        let cofactor = (&n).div_exact(&q_e);
+        // g_q has order q^e, and gamma has order q.
+        let g_q = g.mod_pow(&cofactor, pk);
+        let h_q = h.mod_pow(&cofactor, pk);
+        let mut q_pow = (&q_e).div_exact(&q);
+        let gamma = (&g_q).mod_pow(&q_pow, pk);
+        let g_q_inverse = g_q.mod_inverse(pk).unwrap();
+        // Find the base-q digits of the logarithm of h_q one at a time.
+        let mut x = Natural::ZERO;
+        let mut q_j = Natural::ONE;
+        for _ in 0..e {
+            let beta = (&g_q_inverse)
+                .mod_pow(&x, pk)
+                .mod_mul(&h_q, pk)
+                .mod_pow(&q_pow, pk);
+            x += mod_log_prime_order(&gamma, &beta, &q, pk)? * &q_j;
+            q_j *= &q;
+            if q_pow != 1u32 {
+                q_pow = q_pow.div_exact(&q);
+            }
+        }
+        congruences.push((x, q_e));
+    }
+    let x = Natural::crt(&congruences).unwrap().0;
+    if g.mod_pow(&x, pk) == *h {
+        Some((x, n))
+    } else {
+        None
+    }
+}
+
+fn mod_log(h: &Natural, g: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(h < m);
+    assert!(g < m);
+    // While g is not coprime to m, divide g^x = h mod m by gcd(g, m). This leaves an equation
+    // c * g^(x - k) = h' mod m', where g is coprime to m'. Smaller values of x are checked along
+    // the way, so the solution found is the least one.
+    let mut m = m.clone();
+    let mut h = h.clone();
+    let mut c = Natural::ONE % &m;
+    let mut k = Natural::ZERO;
+    loop {
+        if h == c {
+            return Some(k);
+        }
+        let d = g.gcd(&m);
+        if d == 1u32 {
+            break;
+        }
+        if !(&h).divisible_by(&d) {
+            return None;
+        }
+        m = m.div_exact(&d);
+        h = h.div_exact(&d);
+        k += Natural::ONE;
+        c = (c % &m).mod_mul(g.div_exact(&d) % &m, &m);
+    }
+    // Now c is coprime to m, and m > 1.
+    let g = g % &m;
+    let h = h.mod_mul(c.mod_inverse(&m).unwrap(), &m);
+    let mut congruences = Vec::new();
+    for (p, e) in m.factor() {
+        let pe = (&p).pow(e);
+        congruences.push(mod_log_prime_power(&(&g % &pe), &(&h % &pe), &p, e, &pe)?);
+    }
+    Some(Natural::crt(&congruences)?.0 + k)
+}
+
+impl ModLog<Natural, Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds the discrete logarithm of a [`Natural`] to a given base, modulo another [`Natural`]
+    /// $m$. The inputs must be already reduced modulo $m$. All three [`Natural`]s are taken by
+    /// value.
+    ///
+    /// Returns the least $x \geq 0$ such that $b^x \equiv y \mod m$, or `None` if there is no such
+    /// $x$. The base need not be coprime to $m$, and $m$ need not be prime.
+    ///
+    /// $f(y, b, m) = \min \\{x \in \N : b^x \equiv y \mod m\\}$.
+    ///
+    /// The modulus is factored, and the problem is split into problems modulo each prime power,
+    /// which are combined using the Chinese Remainder Theorem. Modulo each prime power, the
+    /// Pohlig-Hellman algorithm reduces the problem to subgroups of prime order, where the
+    /// logarithm is found using baby-step giant-step if the order is small or Pollard's rho
+    /// algorithm otherwise.
+    ///
+    /// # Expected complexity
+    /// $T(n, q) = O(n^2\sqrt{q})$
+    ///
+    /// $M(n, q) = O(n\min(\sqrt{q}, 2^{16}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $q$ is the
+    /// largest prime factor of the order of the base. This excludes the time needed to factor $m$
+    /// and $p - 1$ for each prime factor $p$ of $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLog;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_log(Natural::from(3u32), Natural::from(7u32)),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_log(Natural::from(2u32), Natural::from(7u32)),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(4u32).mod_log(Natural::from(2u32), Natural::from(12u32)),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(12345u32).mod_log(Natural::from(5u32), Natural::from(1000000007u32)),
+    ///     Some(Natural::from(775972150u32))
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: Natural, m: Natural) -> Option<Natural> {
+        mod_log(&self, &base, &m)
+    }
+}
+
+impl<'a, 'b, 'c> ModLog<&'b Natural, &'c Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds the discrete logarithm of a [`Natural`] to a given base, modulo another [`Natural`]
+    /// $m$. The inputs must be already reduced modulo $m$. All three [`Natural`]s are taken by
+    /// reference.
+    ///
+    /// Returns the least $x \geq 0$ such that $b^x \equiv y \mod m$, or `None` if there is no such
+    /// $x$. The base need not be coprime to $m$, and $m$ need not be prime.
+    ///
+    /// $f(y, b, m) = \min \\{x \in \N : b^x \equiv y \mod m\\}$.
+    ///
+    /// The modulus is factored, and the problem is split into problems modulo each prime power,
+    /// which are combined using the Chinese Remainder Theorem. Modulo each prime power, the
+    /// Pohlig-Hellman algorithm reduces the problem to subgroups of prime order, where the
+    /// logarithm is found using baby-step giant-step if the order is small or Pollard's rho
+    /// algorithm otherwise.
+    ///
+    /// # Expected complexity
+    /// $T(n, q) = O(n^2\sqrt{q})$
+    ///
+    /// $M(n, q) = O(n\min(\sqrt{q}, 2^{16}))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $q$ is the
+    /// largest prime factor of the order of the base. This excludes the time needed to factor $m$
+    /// and $p - 1$ for each prime factor $p$ of $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLog;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_log(&Natural::from(3u32), &Natural::from(7u32)),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_log(&Natural::from(2u32), &Natural::from(7u32)),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(4u32)).mod_log(&Natural::from(2u32), &Natural::from(12u32)),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(12345u32))
+    ///         .mod_log(&Natural::from(5u32), &Natural::from(1000000007u32)),
+    ///     Some(Natural::from(775972150u32))
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: &'b Natural, m: &'c Natural) -> Option<Natural> {
+        mod_log(self, base, m)
+    }
+}
@@ -0,0 +1,429 @@
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_pow::{
+    get_bits, get_window_size, select_fns, to_redc, REDC_1_TO_REDC_N_THRESHOLD,
+};
+use crate::natural::arithmetic::sub::limbs_sub_same_length_in_place_left;
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::logic::bit_access::limbs_get_bit;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    DivRound, ModMulAssign, ModPow, ModSquareAssign, Parity, PowerOf2,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{SignificantBits, TrailingZeros};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::slices::slice_set_zero;
+use std::cmp::{min, Ordering};
+
+// Multiplication modulo a fixed modulus $m$, in some representation of the residues. The
+// multi-exponentiation algorithms are generic over this, so that they can work with limbs in
+// Montgomery form when $m$ is odd, and with ordinary [`Natural`]s otherwise.
+pub(crate) trait ModMulArithmetic {
+    type Element: Clone;
+
+    fn convert_in(&mut self, x: &Natural) -> Self::Element;
+
+    fn convert_out(&mut self, x: &Self::Element) -> Natural;
+
+    fn mul_assign(&mut self, x: &mut Self::Element, y: &Self::Element);
+
+    fn square_assign(&mut self, x: &mut Self::Element);
+
+    // Multiplies an accumulator by `y`, where `None` stands for 1. This avoids multiplications by
+    // 1 at the start of an exponentiation.
+    fn mul_acc(&mut self, acc: &mut Option<Self::Element>, y: &Self::Element) {
+        if let Some(x) = acc.as_mut() {
+            self.mul_assign(x, y);
+        } else {
+            *acc = Some(y.clone());
+        }
+    }
+}
+
+// The data needed for Montgomery multiplication modulo an odd $m$: the limbs of $m$, and an
+// inverse of $m$ in the form expected by the REDC function that `select_fns` chooses for $m$'s
+// length.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct MontgomeryData {
+    ms: Vec<Limb>,
+    is: Vec<Limb>,
+}
+
+impl MontgomeryData {
+    // # Worst-case complexity
+    // $T(n) = O(n \log n \log\log n)$
+    //
+    // $M(n) = O(n \log n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    pub(crate) fn new(m: &Natural) -> MontgomeryData {
+        assert!(m.odd());
+        let ms = m.to_limbs_asc();
+        let ms_len = ms.len();
+        let is = if ms_len < REDC_1_TO_REDC_N_THRESHOLD {
+            vec![limbs_modular_invert_limb(ms[0]).wrapping_neg()]
+        } else {
+            let mut is = vec![0; ms_len];
+            let mut scratch = vec![0; limbs_modular_invert_scratch_len(ms_len)];
+            limbs_modular_invert(&mut is, &ms, &mut scratch);
+            is
+        };
+        MontgomeryData { ms, is }
+    }
+
+    // # Worst-case complexity
+    // $T(n) = O(n)$
+    //
+    // $M(n) = O(n)$
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    pub(crate) fn arithmetic(&self) -> MontgomeryArithmetic<'_> {
+        MontgomeryArithmetic {
+            data: self,
+            scratch: vec![0; self.ms.len() << 1],
+        }
+    }
+}
+
+// Montgomery multiplication modulo an odd $m$ with $n$ limbs, using the same multiplication,
+// squaring, and REDC functions as [`ModPow`]. Residues are represented by $n$ limbs in Montgomery
+// form, and are not necessarily fully reduced until they are converted out.
+pub(crate) struct MontgomeryArithmetic<'a> {
+    data: &'a MontgomeryData,
+    // Space for a double-length product.
+    scratch: Vec<Limb>,
+}
+
+impl<'a> ModMulArithmetic for MontgomeryArithmetic<'a> {
+    type Element = Vec<Limb>;
+
+    fn convert_in(&mut self, x: &Natural) -> Vec<Limb> {
+        let mut out = vec![0; self.data.ms.len()];
+        if *x != 0u32 {
+            to_redc(&mut out, &x.to_limbs_asc(), &self.data.ms);
+        }
+        out
+    }
+
+    fn convert_out(&mut self, x: &Vec<Limb>) -> Natural {
+        let ms = &self.data.ms;
+        let (_, _, reduce_fn) = select_fns(ms.len());
+        let (scratch_lo, scratch_hi) = self.scratch.split_at_mut(ms.len());
+        scratch_lo.copy_from_slice(x);
+        slice_set_zero(scratch_hi);
+        let mut out = vec![0; ms.len()];
+        reduce_fn(&mut out, &mut self.scratch, ms, &self.data.is);
+        if limbs_cmp_same_length(&out, ms) != Ordering::Less {
+            limbs_sub_same_length_in_place_left(&mut out, ms);
+        }
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    fn mul_assign(&mut self, x: &mut Vec<Limb>, y: &Vec<Limb>) {
+        let (mul_fn, _, reduce_fn) = select_fns(self.data.ms.len());
+        mul_fn(&mut self.scratch, x, y);
+        reduce_fn(x, &mut self.scratch, &self.data.ms, &self.data.is);
+    }
+
+    fn square_assign(&mut self, x: &mut Vec<Limb>) {
+        let (_, square_fn, reduce_fn) = select_fns(self.data.ms.len());
+        square_fn(&mut self.scratch, x);
+        reduce_fn(x, &mut self.scratch, &self.data.ms, &self.data.is);
+    }
+}
+
+// Ordinary multiplication of reduced [`Natural`]s modulo $m$. This is used when $m$ is even.
+pub(crate) struct PlainArithmetic<'a>(pub(crate) &'a Natural);
+
+impl<'a> ModMulArithmetic for PlainArithmetic<'a> {
+    type Element = Natural;
+
+    fn convert_in(&mut self, x: &Natural) -> Natural {
+        x % self.0
+    }
+
+    fn convert_out(&mut self, x: &Natural) -> Natural {
+        x.clone()
+    }
+
+    fn mul_assign(&mut self, x: &mut Natural, y: &Natural) {
+        x.mod_mul_assign(y, self.0);
+    }
+
+    fn square_assign(&mut self, x: &mut Natural) {
+        x.mod_square_assign(self.0);
+    }
+}
+
+// Converts an accumulator, where `None` stands for 1, out of the arithmetic's representation.
+pub(crate) fn finish<A: ModMulArithmetic>(
+    arithmetic: &mut A,
+    acc: Option<A::Element>,
+    m: &Natural,
+) -> Natural {
+    match acc {
+        None => Natural::ONE % m,
+        Some(x) => arithmetic.convert_out(&x),
+    }
+}
+
+// The nonzero exponents in a multi-exponentiation, along with their bases converted into the
+// arithmetic's representation.
+fn prepare_powers<A: ModMulArithmetic>(
+    arithmetic: &mut A,
+    powers: &[(Natural, Natural)],
+) -> Vec<(A::Element, Vec<Limb>, u64)> {
+    powers
+        .iter()
+        .filter(|(_, e)| *e != 0u32)
+        .map(|(x, e)| {
+            (
+                arithmetic.convert_in(x),
+                e.to_limbs_asc(),
+                e.significant_bits(),
+            )
+        })
+        .collect()
+}
+
+// Computes $\prod_i x_i^{e_i} \mod m$ naively, by computing each power separately.
+//
+// # Worst-case complexity
+// $T(n, m, k) = O(kmn \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, $m$ is the maximum
+// number of bits of any exponent, and $k$ is `powers.len()`.
+pub_test! {mod_multi_pow_naive(powers: &[(Natural, Natural)], m: &Natural) -> Natural {
+    let mut product = Natural::ONE % m;
+    for (x, e) in powers {
+        product.mod_mul_assign((x % m).mod_pow(e, m), m);
+    }
+    product
+}}
+
+fn mod_multi_pow_straus_helper<A: ModMulArithmetic>(
+    arithmetic: &mut A,
+    powers: &[(Natural, Natural)],
+    m: &Natural,
+) -> Natural {
+    let mut width = 0;
+    // For each base: its precomputed odd powers, and the positions at which it is multiplied into
+    // the accumulator, along with the index of the odd power to multiply by, from the highest
+    // position down.
+    let mut tables = Vec::new();
+    for (x, es, e_width) in prepare_powers(arithmetic, powers) {
+        width = width.max(e_width);
+        let window_size = get_window_size(e_width);
+        let mut x_squared = x.clone();
+        arithmetic.square_assign(&mut x_squared);
+        let mut odd_powers = vec![x];
+        for _ in 1..usize::power_of_2(window_size - 1) {
+            let mut next = odd_powers.last().unwrap().clone();
+            arithmetic.mul_assign(&mut next, &x_squared);
+            odd_powers.push(next);
+        }
+        let mut schedule = Vec::new();
+        let mut bit_index = e_width;
+        while bit_index != 0 {
+            if !limbs_get_bit(&es, bit_index - 1) {
+                bit_index -= 1;
+                continue;
+            }
+            // Extract the largest block of bits <= window_size whose most significant bit is 1,
+            // then drop its trailing zeros so that it is odd.
+            let this_window_size = min(window_size, bit_index);
+            let exp_bits = get_bits(&es, bit_index, this_window_size);
+            let trailing_zeros = TrailingZeros::trailing_zeros(Limb::exact_from(exp_bits));
+            bit_index -= this_window_size;
+            schedule.push((bit_index + trailing_zeros, exp_bits >> trailing_zeros >> 1));
+        }
+        tables.push((odd_powers, schedule, 0));
+    }
+    let mut acc = None;
+    for position in (0..width).rev() {
+        if let Some(x) = acc.as_mut() {
+            arithmetic.square_assign(x);
+        }
+        for (odd_powers, schedule, next) in &mut tables {
+            if let Some(&(p, i)) = schedule.get(*next) {
+                if p == position {
+                    arithmetic.mul_acc(&mut acc, &odd_powers[i]);
+                    *next += 1;
+                }
+            }
+        }
+    }
+    finish(arithmetic, acc, m)
+}
+
+// Computes $\prod_i x_i^{e_i} \mod m$ using Straus's algorithm, also known as Shamir's trick. Each
+// exponent is split into sliding windows, as in [`ModPow`], and the odd powers of each base up to
+// the window size are precomputed; but all the bases share a single sequence of squarings.
+//
+// `m` must be greater than 1.
+//
+// # Worst-case complexity
+// $T(n, m, k) = O((m + k2^w + km/w)n \log n \log\log n)$
+//
+// $M(n, k) = O(k2^wn)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, $m$ is the maximum
+// number of bits of any exponent, $k$ is `powers.len()`, and $w$ is the window size, which grows
+// slowly with $m$.
+pub_test! {mod_multi_pow_straus(powers: &[(Natural, Natural)], m: &Natural) -> Natural {
+    if m.odd() {
+        mod_multi_pow_straus_helper(&mut MontgomeryData::new(m).arithmetic(), powers, m)
+    } else {
+        mod_multi_pow_straus_helper(&mut PlainArithmetic(m), powers, m)
+    }
+}}
+
+// Chooses the window size for Pippenger's algorithm by minimizing the number of multiplications,
+// $\lceil m/c \rceil (k + 2^{c+1})$.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+fn pippenger_window_size(k: usize, width: u64) -> u64 {
+    let k = u64::exact_from(k);
+    (1..=16)
+        .min_by_key(|&c| width.div_round(c, RoundingMode::Ceiling) * (k + (1 << (c + 1))))
+        .unwrap()
+}
+
+fn mod_multi_pow_pippenger_helper<A: ModMulArithmetic>(
+    arithmetic: &mut A,
+    powers: &[(Natural, Natural)],
+    m: &Natural,
+) -> Natural {
+    let powers = prepare_powers(arithmetic, powers);
+    let width = powers
+        .iter()
+        .map(|(_, _, e_width)| *e_width)
+        .max()
+        .unwrap_or(0);
+    if width == 0 {
+        return finish(arithmetic, None, m);
+    }
+    let window_size = pippenger_window_size(powers.len(), width);
+    let mut acc = None;
+    for window in (0..width.div_round(window_size, RoundingMode::Ceiling)).rev() {
+        if let Some(x) = acc.as_mut() {
+            for _ in 0..window_size {
+                arithmetic.square_assign(x);
+            }
+        }
+        let start = window * window_size;
+        let mut buckets = vec![None; usize::power_of_2(window_size) - 1];
+        for (x, es, e_width) in &powers {
+            if start < *e_width {
+                let end = min(start + window_size, *e_width);
+                let digit = get_bits(es, end, end - start);
+                if digit != 0 {
+                    arithmetic.mul_acc(&mut buckets[digit - 1], x);
+                }
+            }
+        }
+        // Compute the product of B_j^j by keeping a running product of the buckets from the
+        // highest down, and multiplying the running product into the total at each step.
+        let mut running = None;
+        let mut total = None;
+        for bucket in buckets.iter().rev() {
+            if let Some(bucket) = bucket {
+                arithmetic.mul_acc(&mut running, bucket);
+            }
+            if let Some(running) = &running {
+                arithmetic.mul_acc(&mut total, running);
+            }
+        }
+        if let Some(total) = total {
+            arithmetic.mul_acc(&mut acc, &total);
+        }
+    }
+    finish(arithmetic, acc, m)
+}
+
+// Computes $\prod_i x_i^{e_i} \mod m$ using Pippenger's bucket algorithm. The exponents are split
+// into fixed windows of $c$ bits. For each window, each base is multiplied into the bucket
+// corresponding to its exponent's digit, and the buckets are combined into
+// $\prod_{j=1}^{2^c-1} B_j^j$ using about $2^{c+1}$ multiplications. This needs fewer
+// multiplications than Straus's algorithm when there are many bases.
+//
+// `m` must be greater than 1.
+//
+// # Worst-case complexity
+// $T(n, m, k) = O((m + (k + 2^c)m/c)n \log n \log\log n)$
+//
+// $M(n, k) = O((k + 2^c)n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, $m$ is the maximum
+// number of bits of any exponent, $k$ is `powers.len()`, and $c$ is the window size, which is
+// about $\log_2 k$.
+pub_test! {mod_multi_pow_pippenger(powers: &[(Natural, Natural)], m: &Natural) -> Natural {
+    if m.odd() {
+        mod_multi_pow_pippenger_helper(&mut MontgomeryData::new(m).arithmetic(), powers, m)
+    } else {
+        mod_multi_pow_pippenger_helper(&mut PlainArithmetic(m), powers, m)
+    }
+}}
+
+//TODO tune
+const MOD_MULTI_POW_PIPPENGER_THRESHOLD: usize = 128;
+
+impl Natural {
+    /// Computes the product of several powers modulo a [`Natural`] $m$. The bases do not need to
+    /// be reduced.
+    ///
+    /// $f((x_i, e_i)_{i=1}^k, m) = y$, where $y < m$ and $\prod_{i=1}^k x_i^{e_i} \equiv y \mod m$.
+    ///
+    /// This is faster than computing each power separately, since all the powers share a single
+    /// sequence of squarings. Straus's algorithm (also known as Shamir's trick) is used when there
+    /// are a few bases, and Pippenger's algorithm when there are many. If $m$ is odd, the
+    /// arithmetic is done in Montgomery form.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, k) = O((m + km/\log k)n \log n \log\log n)$
+    ///
+    /// $M(n, k) = O(kn \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, $m$ is the
+    /// maximum number of bits of any exponent, and $k$ is `powers.len()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // 4^13 * 5^7 ≡ 445 * 96 ≡ 475 (mod 497)
+    /// assert_eq!(
+    ///     Natural::mod_multi_pow(
+    ///         &[
+    ///             (Natural::from(4u32), Natural::from(13u32)),
+    ///             (Natural::from(5u32), Natural::from(7u32))
+    ///         ],
+    ///         &Natural::from(497u32)
+    ///     ),
+    ///     475
+    /// );
+    /// assert_eq!(Natural::mod_multi_pow(&[], &Natural::from(10u32)), 1);
+    /// ```
+    pub fn mod_multi_pow(powers: &[(Natural, Natural)], m: &Natural) -> Natural {
+        assert_ne!(*m, 0u32, "modulus must be nonzero");
+        match powers {
+            _ if *m == 1u32 => Natural::ZERO,
+            [] => Natural::ONE,
+            [(x, e)] => (x % m).mod_pow(e, m),
+            _ if powers.len() < MOD_MULTI_POW_PIPPENGER_THRESHOLD => {
+                mod_multi_pow_straus(powers, m)
+            }
+            _ => mod_multi_pow_pippenger(powers, m),
+        }
+    }
+}
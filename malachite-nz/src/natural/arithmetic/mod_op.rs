@@ -479,7 +479,7 @@ pub_test! {limbs_mod_divide_and_conquer(
 //
 // This is equivalent to `mpn_preinv_mu_div_qr` from `mpn/generic/mu_div_qr.c`, GMP 6.2.1, where
 // only the remainder is calculated.
-fn limbs_mod_barrett_preinverted(
+pub(crate) fn limbs_mod_barrett_preinverted(
     qs: &mut [Limb],
     rs: &mut [Limb],
     ns: &[Limb],
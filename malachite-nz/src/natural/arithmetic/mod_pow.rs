@@ -101,7 +101,7 @@ fn limbs_redc_limb_raw(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Li
 //
 // This is equivalent to `MPN_REDC_1` from `mpn/generic/powm.c`, GMP 6.2.1. Investigate changes
 // from 6.1.2?
-fn limbs_redc_limb(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Limb) {
+pub(crate) fn limbs_redc_limb(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Limb) {
     if limbs_redc_limb_raw(out, xs, ms, m_inv) {
         limbs_sub_same_length_in_place_left(&mut out[..ms.len()], ms);
     }
@@ -132,7 +132,7 @@ pub(crate) fn get_window_size(width: u64) -> u64 {
 // where $T$ is time, $M$ is additional memory, and $n$ is `ms.len()`.
 //
 // This is equivalent to `mpn_redc_n` from `mpn/generic/redc_n.c`, GMP 6.2.1.
-fn limbs_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb], is: &[Limb]) {
+pub(crate) fn limbs_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb], is: &[Limb]) {
     let ms_len = ms.len();
     assert!(ms_len > 8);
     let n = limbs_mul_mod_base_pow_n_minus_1_next_size(ms_len);
@@ -166,7 +166,7 @@ fn limbs_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb], is: &[Limb]) {
 //
 // This is equivalent to `redcify` from `mpn/generic/powm.c`, 6.2.1. Investigate changes from
 // 6.1.2?
-fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
+pub(crate) fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
     let xs_len = xs.len();
     let ms_len = ms.len();
     if ms_len == 1 {
@@ -182,7 +182,7 @@ fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
 }
 
 //TODO tune
-const REDC_1_TO_REDC_N_THRESHOLD: usize = 100;
+pub(crate) const REDC_1_TO_REDC_N_THRESHOLD: usize = 100;
 
 // # Worst-case complexity
 // Constant time and additional memory.
@@ -223,7 +223,7 @@ fn limbs_redc_helper(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], is: &[Limb]
 // # Worst-case complexity
 // Constant time and additional memory.
 #[allow(clippy::absurd_extreme_comparisons, clippy::type_complexity)]
-fn select_fns(
+pub(crate) fn select_fns(
     ms_len: usize,
 ) -> (
     &'static dyn Fn(&mut [Limb], &[Limb], &[Limb]),
@@ -0,0 +1,584 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    Crt, CrtPrecomputed, LegendreSymbol, ModAdd, ModInverse, ModMul, ModMulAssign, ModPow,
+    ModPowerOf2, ModSqrt, ModSquare, ModSquareAssign, ModSub, Parity, Pow, PowerOf2, RemoveFactor,
+    Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Returns a square root of `a` modulo the odd prime `p`, using the Tonelli-Shanks algorithm. `a`
+// must be a nonzero quadratic residue modulo `p`.
+pub_test! {mod_sqrt_tonelli_shanks(a: &Natural, p: &Natural) -> Natural {
+    let p_minus_1 = p - Natural::ONE;
+    let s = p_minus_1.trailing_zeros().unwrap();
+    let q = p_minus_1 >> s;
+    // a^((q + 1) / 2)
+    let mut r = a.mod_pow((&q >> 1u32) + Natural::ONE, p);
+    if s == 1 {
+        return r;
+    }
+    let mut z = Natural::TWO;
+    while (&z).legendre_symbol(p) != -1 {
+        z += Natural::ONE;
+    }
+    let mut c = z.mod_pow(&q, p);
+    let mut t = a.mod_pow(&q, p);
+    let mut m = s;
+    // Invariants: r^2 = a * t, c has order 2^m, and t has order dividing 2^(m - 1).
+    while t != 1u32 {
+        let mut i = 0;
+        let mut t_pow = t.clone();
+        while t_pow != 1u32 {
+            t_pow.mod_square_assign(p);
+            i += 1;
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b.mod_square_assign(p);
+        }
+        r.mod_mul_assign(&b, p);
+        c = b.mod_square(p);
+        t.mod_mul_assign(&c, p);
+        m = i;
+    }
+    r
+}}
+
+// Returns a square root of `a` modulo the odd prime `p`, using Cipolla's algorithm. `a` must be a
+// nonzero quadratic residue modulo `p`.
+pub_test! {mod_sqrt_cipolla(a: &Natural, p: &Natural) -> Natural {
+    // Find t such that t^2 - a is a nonresidue, and work in the field F_p(w), where w^2 = t^2 - a.
+    let mut t = Natural::ONE;
+    let d = loop {
+        let d = (&t).mod_square(p).mod_sub(a, p);
+        if (&d).legendre_symbol(p) == -1 {
+            break d;
+        }
+        t += Natural::ONE;
+    };
+    // (t + w)^((p + 1) / 2) is a square root of a, and lies in F_p.
+    let exp = (p >> 1u32) + Natural::ONE;
+    let mut x = Natural::ONE;
+    let mut y = Natural::ZERO;
+    for i in (0..exp.significant_bits()).rev() {
+        let xy = (&x).mod_mul(&y, p);
+        x = (&x).mod_square(p).mod_add(y.mod_square(p).mod_mul(&d, p), p);
+        y = (&xy).mod_add(&xy, p);
+        if exp.get_bit(i) {
+            (x, y) = (
+                (&x).mod_mul(&t, p).mod_add((&y).mod_mul(&d, p), p),
+                x.mod_add(y.mod_mul(&t, p), p),
+            );
+        }
+    }
+    x
+}}
+
+// Returns a square root of `a` modulo the odd prime `p`, or `None` if `a` is not a quadratic
+// residue. `a` must be nonzero and less than `p`.
+//
+// Tonelli-Shanks takes $O(s^2)$ multiplications in addition to an exponentiation, where $2^s$ is
+// the largest power of 2 dividing $p - 1$, so Cipolla's algorithm is used when $s$ is large.
+fn mod_sqrt_odd_prime(a: &Natural, p: &Natural) -> Option<Natural> {
+    if a.legendre_symbol(p) != 1 {
+        return None;
+    }
+    let s = (p - Natural::ONE).trailing_zeros().unwrap();
+    Some(if s * s > p.significant_bits() << 2 {
+        mod_sqrt_cipolla(a, p)
+    } else {
+        mod_sqrt_tonelli_shanks(a, p)
+    })
+}
+
+// Returns the square roots of `a` modulo `p^k`, in increasing order, where `p` is prime, `a` is
+// not divisible by `p`, and `pk` is `p^k`.
+fn mod_sqrts_unit_prime_power(a: &Natural, p: &Natural, k: u64, pk: &Natural) -> Vec<Natural> {
+    if *p == 2u32 {
+        return match k {
+            1 => vec![Natural::ONE],
+            2 => {
+                if a.get_bit(1) {
+                    Vec::new()
+                } else {
+                    vec![Natural::ONE, Natural::from(3u32)]
+                }
+            }
+            _ => {
+                if a.mod_power_of_2(3) != 1u32 {
+                    return Vec::new();
+                }
+                // Lift a root one bit at a time. If r^2 = a mod 2^j, then either r or
+                // r + 2^(j - 1) is a root modulo 2^(j + 1).
+                let mut r = Natural::ONE;
+                for j in 3..k {
+                    if (&r).square().get_bit(j) != a.get_bit(j) {
+                        r.set_bit(j - 1);
+                    }
+                }
+                let half = Natural::power_of_2(k - 1);
+                let s = pk - &r;
+                let mut roots = vec![(&r).mod_add(&half, pk), (&s).mod_add(&half, pk), r, s];
+                roots.sort_unstable();
+                roots
+            }
+        };
+    }
+    let mut r = if let Some(r) = mod_sqrt_odd_prime(&(a % p), p) {
+        r
+    } else {
+        return Vec::new();
+    };
+    // Hensel lifting: if r^2 = a mod p^j, then r - (r^2 - a) / (2r) is a root modulo p^(2j).
+    let mut j = 1;
+    while j < k {
+        j = (j << 1).min(k);
+        let pj = p.pow(j);
+        let f = (&r).mod_square(&pj).mod_sub(a % &pj, &pj);
+        let inverse = (&r).mod_add(&r, &pj).mod_inverse(&pj).unwrap();
+        r = r.mod_sub(f.mod_mul(inverse, &pj), &pj);
+    }
+    let s = pk - &r;
+    if r < s {
+        vec![r, s]
+    } else {
+        vec![s, r]
+    }
+}
+
+// Returns the square roots of `a` modulo `p^e`, in no particular order, where `p` is prime, `a` is
+// less than `p^e`, and `pe` is `p^e`.
+//
+// If $a = 0$, the roots are the multiples of $p^{\lceil e/2 \rceil}$. Otherwise, if
+// $a = p^{2w}b$ with $p \nmid b$, the roots are $p^w y$, where $y^2 \equiv b \mod p^{e - 2w}$ and
+// $y$ is taken modulo $p^{e - w}$.
+fn mod_sqrts_prime_power(a: &Natural, p: &Natural, e: u64, pe: &Natural) -> Vec<Natural> {
+    let mut roots = Vec::new();
+    if *a == 0u32 {
+        let step = p.pow((e + 1) >> 1);
+        let mut x = Natural::ZERO;
+        while x < *pe {
+            roots.push(x.clone());
+            x += &step;
+        }
+        return roots;
+    }
+    let (b, v) = a.remove_factor(p);
+    if v.odd() {
+        return roots;
+    }
+    let k = e - v;
+    let pk = p.pow(k);
+    let pw = p.pow(v >> 1);
+    let step = &pw * &pk;
+    for y in mod_sqrts_unit_prime_power(&b, p, k, &pk) {
+        let mut x = &pw * y;
+        while x < *pe {
+            roots.push(x.clone());
+            x += &step;
+        }
+    }
+    roots
+}
+
+// Returns one square root of `a` modulo `p^e`, where `p` is prime and `a` is less than `p^e`. If
+// $e = 1$, the lesser root is returned.
+fn mod_sqrt_prime_power(a: &Natural, p: &Natural, e: u64) -> Option<Natural> {
+    if *a == 0u32 {
+        return Some(Natural::ZERO);
+    }
+    let (b, v) = a.remove_factor(p);
+    if v.odd() {
+        return None;
+    }
+    let k = e - v;
+    let unit_roots = mod_sqrts_unit_prime_power(&b, p, k, &p.pow(k));
+    unit_roots.into_iter().next().map(|y| p.pow(v >> 1) * y)
+}
+
+fn mod_sqrt_from_factors(a: &Natural, factors: &[(Natural, u64)]) -> Option<Natural> {
+    let moduli = factors.iter().map(|(p, e)| p.pow(*e)).collect::<Vec<_>>();
+    assert!(*a < moduli.iter().product::<Natural>());
+    let mut congruences = Vec::with_capacity(factors.len());
+    for ((p, e), pe) in factors.iter().zip(moduli) {
+        congruences.push((mod_sqrt_prime_power(&(a % &pe), p, *e)?, pe));
+    }
+    Some(Natural::crt(&congruences).unwrap().0)
+}
+
+fn mod_sqrts_from_factors(a: &Natural, factors: &[(Natural, u64)]) -> Vec<Natural> {
+    let moduli = factors.iter().map(|(p, e)| p.pow(*e)).collect::<Vec<_>>();
+    let basis = Natural::precompute_crt_data(&moduli).unwrap();
+    assert!(a < basis.modulus());
+    let mut root_sets = Vec::with_capacity(factors.len());
+    for ((p, e), pe) in factors.iter().zip(moduli.iter()) {
+        let roots = mod_sqrts_prime_power(&(a % pe), p, *e, pe);
+        if roots.is_empty() {
+            return Vec::new();
+        }
+        root_sets.push(roots);
+    }
+    // Combine every choice of root modulo each prime power.
+    let mut indices = vec![0; root_sets.len()];
+    let mut residues = root_sets
+        .iter()
+        .map(|roots| roots[0].clone())
+        .collect::<Vec<_>>();
+    let mut roots = Vec::new();
+    'outer: loop {
+        roots.push(Natural::crt_precomputed(&residues, &basis));
+        for (i, roots_i) in root_sets.iter().enumerate() {
+            indices[i] += 1;
+            if indices[i] == roots_i.len() {
+                indices[i] = 0;
+            }
+            residues[i].clone_from(&roots_i[indices[i]]);
+            if indices[i] != 0 {
+                continue 'outer;
+            }
+        }
+        break;
+    }
+    roots.sort_unstable();
+    roots
+}
+
+impl ModSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$. The input must be
+    /// already reduced modulo $m$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if the [`Natural`] is not a square modulo $m$. If $m$ is prime, the lesser
+    /// of the square roots is returned; otherwise, which square root is returned is unspecified.
+    ///
+    /// The modulus is factored first. If its factorization is already known, use
+    /// [`mod_sqrt_from_factors`](ModSqrt::mod_sqrt_from_factors) instead.
+    ///
+    /// $f(x, m) = y$, where $x, y < m$ and $y^2 \equiv x \mod m$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(2u32).mod_sqrt(Natural::from(7u32)), Some(Natural::from(3u32)));
+    /// assert_eq!(Natural::from(3u32).mod_sqrt(Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     Natural::from(2u32)
+    ///         .mod_sqrt(Natural::from(170141183460469231731687303715884105727u128)),
+    ///     Some(Natural::from(18446744073709551616u128))
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, m: Natural) -> Option<Natural> {
+        (&self).mod_sqrt(&m)
+    }
+
+    /// Finds all square roots of a [`Natural`] modulo another [`Natural`] $m$, in increasing
+    /// order. The input must be already reduced modulo $m$. Both [`Natural`]s are taken by value.
+    ///
+    /// The result is empty if the [`Natural`] is not a square modulo $m$. The number of roots can
+    /// be large when $m$ has large square factors; for example, 0 has $2^{\lfloor k/2 \rfloor}$
+    /// square roots modulo $2^k$.
+    ///
+    /// The modulus is factored first. If its factorization is already known, use
+    /// [`mod_sqrts_from_factors`](ModSqrt::mod_sqrts_from_factors) instead.
+    ///
+    /// $f(x, m) = \\{y : y < m \land y^2 \equiv x \mod m\\}$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor) and by the number of roots.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(10u32).mod_sqrts(Natural::from(13u32)).to_debug_string(),
+    ///     "[6, 7]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(4u32).mod_sqrts(Natural::from(15u32)).to_debug_string(),
+    ///     "[2, 7, 8, 13]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_sqrts(Natural::from(7u32)).to_debug_string(),
+    ///     "[]"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrts(self, m: Natural) -> Vec<Natural> {
+        (&self).mod_sqrts(&m)
+    }
+
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$, given the prime
+    /// factorization of $m$. The input must be already reduced modulo $m$. The first [`Natural`]
+    /// is taken by value.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. Square roots are found modulo each odd
+    /// prime using the Tonelli-Shanks algorithm or Cipolla's algorithm, lifted to each prime power
+    /// using Hensel's lemma, and combined using the Chinese Remainder Theorem.
+    ///
+    /// Returns `None` if the [`Natural`] is not a square modulo $m$. If $m$ is prime, the lesser
+    /// of the square roots is returned; otherwise, which square root is returned is unspecified.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to the product of the prime powers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_sqrt_from_factors(&[(Natural::from(7u32), 1)]),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .mod_sqrt_from_factors(&[(Natural::from(3u32), 1), (Natural::from(5u32), 1)]),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt_from_factors(self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        mod_sqrt_from_factors(&self, factors)
+    }
+
+    /// Finds all square roots of a [`Natural`] modulo another [`Natural`] $m$, in increasing
+    /// order, given the prime factorization of $m$. The input must be already reduced modulo $m$.
+    /// The first [`Natural`] is taken by value.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked.
+    ///
+    /// The result is empty if the [`Natural`] is not a square modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 + kn^2)$
+    ///
+    /// $M(n, k) = O(kn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of bits of $m$, and $k$ is
+    /// the number of roots.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to the product of the prime powers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(1u32)
+    ///         .mod_sqrts_from_factors(&[(Natural::from(3u32), 1), (Natural::from(5u32), 1)])
+    ///         .to_debug_string(),
+    ///     "[1, 4, 11, 14]"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(0u32)
+    ///         .mod_sqrts_from_factors(&[(Natural::from(2u32), 4)])
+    ///         .to_debug_string(),
+    ///     "[0, 4, 8, 12]"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrts_from_factors(self, factors: &[(Natural, u64)]) -> Vec<Natural> {
+        mod_sqrts_from_factors(&self, factors)
+    }
+}
+
+impl<'a, 'b> ModSqrt<&'b Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$. The input must be
+    /// already reduced modulo $m$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns `None` if the [`Natural`] is not a square modulo $m$. If $m$ is prime, the lesser
+    /// of the square roots is returned; otherwise, which square root is returned is unspecified.
+    ///
+    /// The modulus is factored first. If its factorization is already known, use
+    /// [`mod_sqrt_from_factors`](ModSqrt::mod_sqrt_from_factors) instead.
+    ///
+    /// $f(x, m) = y$, where $x, y < m$ and $y^2 \equiv x \mod m$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_sqrt(&Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Natural::from(3u32)).mod_sqrt(&Natural::from(7u32)), None);
+    /// assert_eq!(
+    ///     (&Natural::from(2u32))
+    ///         .mod_sqrt(&Natural::from(170141183460469231731687303715884105727u128)),
+    ///     Some(Natural::from(18446744073709551616u128))
+    /// );
+    /// ```
+    fn mod_sqrt(self, m: &'b Natural) -> Option<Natural> {
+        assert!(self < m);
+        mod_sqrt_from_factors(self, &m.factor())
+    }
+
+    /// Finds all square roots of a [`Natural`] modulo another [`Natural`] $m$, in increasing
+    /// order. The input must be already reduced modulo $m$. Both [`Natural`]s are taken by
+    /// reference.
+    ///
+    /// The result is empty if the [`Natural`] is not a square modulo $m$. The number of roots can
+    /// be large when $m$ has large square factors; for example, 0 has $2^{\lfloor k/2 \rfloor}$
+    /// square roots modulo $2^k$.
+    ///
+    /// The modulus is factored first. If its factorization is already known, use
+    /// [`mod_sqrts_from_factors`](ModSqrt::mod_sqrts_from_factors) instead.
+    ///
+    /// $f(x, m) = \\{y : y < m \land y^2 \equiv x \mod m\\}$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor) and by the number of roots.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(10u32)).mod_sqrts(&Natural::from(13u32)).to_debug_string(),
+    ///     "[6, 7]"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(4u32)).mod_sqrts(&Natural::from(15u32)).to_debug_string(),
+    ///     "[2, 7, 8, 13]"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_sqrts(&Natural::from(7u32)).to_debug_string(),
+    ///     "[]"
+    /// );
+    /// ```
+    fn mod_sqrts(self, m: &'b Natural) -> Vec<Natural> {
+        assert!(self < m);
+        mod_sqrts_from_factors(self, &m.factor())
+    }
+
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$, given the prime
+    /// factorization of $m$. The input must be already reduced modulo $m$. The first [`Natural`]
+    /// is taken by reference.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. Square roots are found modulo each odd
+    /// prime using the Tonelli-Shanks algorithm or Cipolla's algorithm, lifted to each prime power
+    /// using Hensel's lemma, and combined using the Chinese Remainder Theorem.
+    ///
+    /// Returns `None` if the [`Natural`] is not a square modulo $m$. If $m$ is prime, the lesser
+    /// of the square roots is returned; otherwise, which square root is returned is unspecified.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to the product of the prime powers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_sqrt_from_factors(&[(Natural::from(7u32), 1)]),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .mod_sqrt_from_factors(&[(Natural::from(3u32), 1), (Natural::from(5u32), 1)]),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt_from_factors(self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        mod_sqrt_from_factors(self, factors)
+    }
+
+    /// Finds all square roots of a [`Natural`] modulo another [`Natural`] $m$, in increasing
+    /// order, given the prime factorization of $m$. The input must be already reduced modulo $m$.
+    /// The first [`Natural`] is taken by reference.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked.
+    ///
+    /// The result is empty if the [`Natural`] is not a square modulo $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(n^3 + kn^2)$
+    ///
+    /// $M(n, k) = O(kn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of bits of $m$, and $k$ is
+    /// the number of roots.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to the product of the prime powers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(1u32))
+    ///         .mod_sqrts_from_factors(&[(Natural::from(3u32), 1), (Natural::from(5u32), 1)])
+    ///         .to_debug_string(),
+    ///     "[1, 4, 11, 14]"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(0u32))
+    ///         .mod_sqrts_from_factors(&[(Natural::from(2u32), 4)])
+    ///         .to_debug_string(),
+    ///     "[0, 4, 8, 12]"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrts_from_factors(self, factors: &[(Natural, u64)]) -> Vec<Natural> {
+        mod_sqrts_from_factors(self, factors)
+    }
+}
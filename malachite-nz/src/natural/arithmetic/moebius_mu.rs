@@ -0,0 +1,76 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::MoebiusMu;
+use malachite_base::num::factorization::traits::Factor;
+
+impl MoebiusMu for Natural {
+    /// Computes the Möbius function $\mu$ of a [`Natural`].
+    ///
+    /// The [`Natural`] is factored first. If its factorization is already known, use
+    /// [`moebius_mu_from_factors`](MoebiusMu::moebius_mu_from_factors) instead.
+    ///
+    /// $$
+    /// f(n) = \mu(n) = \\begin{cases}
+    ///     (-1)^k & \text{if} \\quad n \text{ is a product of } k \text{ distinct primes}, \\\\
+    ///     0 & \text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{MoebiusMu, Pow};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ONE.moebius_mu(), 1);
+    /// assert_eq!(Natural::from(30u32).moebius_mu(), -1);
+    /// assert_eq!(Natural::from(10u32).pow(20).moebius_mu(), 0);
+    /// assert_eq!(Natural::from(u64::MAX).moebius_mu(), -1);
+    /// ```
+    #[inline]
+    fn moebius_mu(&self) -> i8 {
+        Natural::moebius_mu_from_factors(&self.factor())
+    }
+
+    /// Computes the Möbius function $\mu$ of a [`Natural`], given its prime factorization.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MoebiusMu;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::moebius_mu_from_factors(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::moebius_mu_from_factors(&[(Natural::from(2u32), 1), (Natural::from(3u32), 1)]),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Natural::moebius_mu_from_factors(&[(Natural::from(2u32), 1), (Natural::from(3u32), 2)]),
+    ///     0
+    /// );
+    /// ```
+    fn moebius_mu_from_factors(factors: &[(Natural, u64)]) -> i8 {
+        if factors.iter().any(|&(_, e)| e > 1) {
+            0
+        } else if factors.len() & 1 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_pow::{
+    limbs_redc, limbs_redc_limb, to_redc, REDC_1_TO_REDC_N_THRESHOLD,
+};
+use crate::natural::arithmetic::sub::limbs_sub_same_length_in_place_left;
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{Parity, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use std::cmp::Ordering;
+
+/// A precomputed context for multiplying [`Natural`]s modulo a fixed odd modulus $m$ using
+/// Montgomery reduction.
+///
+/// Numbers are represented in Montgomery form: a residue $x$ is stored as $xR \mod m$, where $R$
+/// is $2^{Wn}$, $W$ is the width of a [`Limb`], and $n$ is the number of limbs of $m$. In this
+/// form, a modular multiplication requires a multiplication and a Montgomery reduction, but no
+/// division, so long chains of multiplications modulo the same $m$ are cheaper than repeated calls
+/// to [`ModMul`](malachite_base::num::arithmetic::traits::ModMul).
+///
+/// Convert numbers into Montgomery form with [`to_montgomery`](Self::to_montgomery), operate on
+/// them with [`mul`](Self::mul), [`square`](Self::square), and [`pow`](Self::pow), and convert the
+/// results back with [`from_montgomery`](Self::from_montgomery). The Montgomery-form values that
+/// these functions take and return are reduced modulo $m$, but their exact values depend on the
+/// width of [`Limb`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MontgomeryContext {
+    modulus: Natural,
+    // The limbs of the modulus, in ascending order.
+    ms: Vec<Limb>,
+    // If the modulus has fewer than `REDC_1_TO_REDC_N_THRESHOLD` limbs, this contains the single
+    // limb $-m^{-1} \mod 2^W$. Otherwise, it contains the limbs of $m^{-1} \mod R$.
+    is: Vec<Limb>,
+    // $R \mod m$, which is 1 in Montgomery form.
+    one: Natural,
+}
+
+impl MontgomeryContext {
+    /// Creates a [`MontgomeryContext`] for a given odd modulus.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is even (including if it is 0).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(&Natural::from(101u32));
+    /// assert_eq!(*context.modulus(), 101);
+    /// ```
+    pub fn new(m: &Natural) -> MontgomeryContext {
+        assert!(m.odd(), "Montgomery reduction requires an odd modulus");
+        let ms = m.to_limbs_asc();
+        let ms_len = ms.len();
+        let is = if ms_len < REDC_1_TO_REDC_N_THRESHOLD {
+            vec![limbs_modular_invert_limb(ms[0]).wrapping_neg()]
+        } else {
+            let mut is = vec![0; ms_len];
+            let mut scratch = vec![0; limbs_modular_invert_scratch_len(ms_len)];
+            limbs_modular_invert(&mut is, &ms, &mut scratch);
+            is
+        };
+        let mut context = MontgomeryContext {
+            modulus: m.clone(),
+            ms,
+            is,
+            one: Natural::ZERO,
+        };
+        context.one = context.to_montgomery(&Natural::ONE);
+        context
+    }
+
+    /// Returns the modulus that the context was created from.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(&Natural::from(101u32));
+    /// assert_eq!(*context.modulus(), 101);
+    /// ```
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Returns 1 in Montgomery form; that is, $R \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(&Natural::from(101u32));
+    /// assert_eq!(context.from_montgomery(context.one()), 1);
+    /// ```
+    pub const fn one(&self) -> &Natural {
+        &self.one
+    }
+
+    /// Converts a [`Natural`] into Montgomery form. The input does not need to be reduced.
+    ///
+    /// $f(x) = xR \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), self.modulus().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(&Natural::from(101u32));
+    /// let x = context.to_montgomery(&Natural::from(123u32));
+    /// assert!(x < 101);
+    /// assert_eq!(context.from_montgomery(&x), 22);
+    /// ```
+    pub fn to_montgomery(&self, x: &Natural) -> Natural {
+        if *x == 0u32 {
+            return Natural::ZERO;
+        }
+        let mut out = vec![0; self.ms.len()];
+        to_redc(&mut out, &x.to_limbs_asc(), &self.ms);
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    /// Converts a [`Natural`] out of Montgomery form. The input must be less than the modulus.
+    ///
+    /// $f(x) = xR^{-1} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(&Natural::from(101u32));
+    /// let x = context.to_montgomery(&Natural::from(5u32));
+    /// assert_eq!(context.from_montgomery(&x), 5);
+    /// ```
+    pub fn from_montgomery(&self, x: &Natural) -> Natural {
+        self.redc(x.to_limbs_asc())
+    }
+
+    /// Multiplies two [`Natural`]s in Montgomery form. Both inputs must be less than the
+    /// modulus.
+    ///
+    /// $f(x, y) = xyR^{-1} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(&Natural::from(101u32));
+    /// let x = context.to_montgomery(&Natural::from(20u32));
+    /// let y = context.to_montgomery(&Natural::from(30u32));
+    /// assert_eq!(context.from_montgomery(&context.mul(&x, &y)), 95);
+    /// ```
+    pub fn mul(&self, x: &Natural, y: &Natural) -> Natural {
+        self.redc((x * y).into_limbs_asc())
+    }
+
+    /// Squares a [`Natural`] in Montgomery form. The input must be less than the modulus.
+    ///
+    /// $f(x) = x^2R^{-1} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(&Natural::from(101u32));
+    /// let x = context.to_montgomery(&Natural::from(20u32));
+    /// assert_eq!(context.from_montgomery(&context.square(&x)), 97);
+    /// ```
+    pub fn square(&self, x: &Natural) -> Natural {
+        self.redc(x.square().into_limbs_asc())
+    }
+
+    /// Raises a [`Natural`] in Montgomery form to a [`Natural`] power. The base must be less than
+    /// the modulus. The result is also in Montgomery form.
+    ///
+    /// $f(x, k) = x^kR^{1-k} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`,
+    /// and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let context = MontgomeryContext::new(&Natural::from(101u32));
+    /// let x = context.to_montgomery(&Natural::from(3u32));
+    /// assert_eq!(context.from_montgomery(&context.pow(&x, &Natural::from(100u32))), 1);
+    /// assert_eq!(context.from_montgomery(&context.pow(&x, &Natural::from(5u32))), 41);
+    /// assert_eq!(context.pow(&x, &Natural::from(0u32)), *context.one());
+    /// ```
+    pub fn pow(&self, x: &Natural, exp: &Natural) -> Natural {
+        if *exp == 0u32 {
+            return self.one.clone();
+        }
+        let mut out = x.clone();
+        for i in (0..exp.significant_bits() - 1).rev() {
+            out = self.square(&out);
+            if exp.get_bit(i) {
+                out = self.mul(&out, x);
+            }
+        }
+        out
+    }
+
+    // Given the limbs of some $x < mR$, returns $xR^{-1} \mod m$.
+    fn redc(&self, mut xs: Vec<Limb>) -> Natural {
+        let ms_len = self.ms.len();
+        xs.resize(ms_len << 1, 0);
+        let mut out = vec![0; ms_len];
+        if ms_len < REDC_1_TO_REDC_N_THRESHOLD {
+            limbs_redc_limb(&mut out, &mut xs, &self.ms, self.is[0]);
+        } else {
+            limbs_redc(&mut out, &xs, &self.ms, &self.is);
+        }
+        if limbs_cmp_same_length(&out, &self.ms) != Ordering::Less {
+            limbs_sub_same_length_in_place_left(&mut out, &self.ms);
+        }
+        Natural::from_owned_limbs_asc(out)
+    }
+}
@@ -3,7 +3,7 @@ use crate::natural::arithmetic::add::{
     limbs_slice_add_limb_in_place, limbs_slice_add_same_length_in_place_left,
 };
 use crate::natural::arithmetic::mul::{
-    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len,
+    join, limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len,
 };
 use crate::natural::arithmetic::neg::{limbs_neg, limbs_neg_in_place};
 use crate::natural::arithmetic::shl::{limbs_shl_to_out, limbs_slice_shl_in_place};
@@ -13,7 +13,7 @@ use crate::natural::arithmetic::sub::{
     limbs_sub_limb_in_place, limbs_sub_same_length_in_place_left,
     limbs_sub_same_length_in_place_right, limbs_sub_same_length_to_out,
 };
-use crate::platform::{Limb, SignedLimb, FFT_TAB, MULMOD_TAB};
+use crate::platform::{Limb, SignedLimb, FFT_TAB, MULMOD_TAB, MUL_FFT_PARALLEL_THRESHOLD};
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
     CeilingLogBase2, Parity, PowerOf2, WrappingAddAssign, WrappingSubAssign, XXAddYYToZZ,
@@ -2065,6 +2065,208 @@ fn limbs_ifft_mfa_truncate_sqrt_outer<'a>(
     }
 }
 
+// Returns the temporaries needed to transform the second operand of an FFT multiplication in
+// parallel with the first, or an empty `Vec` if the transforms should be run one after the other;
+// that is, if the `parallel` feature is disabled or the product has fewer than
+// `MUL_FFT_PARALLEL_THRESHOLD` limbs. `size` is the length of each coefficient.
+fn limbs_fft_parallel_temps(product_len: usize, size: usize) -> Vec<Limb> {
+    if cfg!(feature = "parallel") && product_len >= MUL_FFT_PARALLEL_THRESHOLD {
+        vec![0; 3 * size]
+    } else {
+        Vec::new()
+    }
+}
+
+// Applies `limbs_fft_truncate_sqrt` to both `xss` and `yss`. If `yss_temps` is empty, both
+// transforms use `ts`, `us`, and `scratch`, one after the other. Otherwise `yss_temps` provides
+// the temporaries for the transform of `yss` and the two transforms are run in parallel.
+#[allow(clippy::mut_mut, clippy::too_many_arguments)]
+fn limbs_fft_truncate_sqrt_pair<'a>(
+    xss: &mut [&'a mut [Limb]],
+    yss: &mut [&'a mut [Limb]],
+    w: usize,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    scratch: &mut [Limb],
+    yss_temps: &'a mut [Limb],
+    trunc: usize,
+) {
+    if yss_temps.is_empty() {
+        limbs_fft_truncate_sqrt(xss, w, ts, us, scratch, trunc);
+        limbs_fft_truncate_sqrt(yss, w, ts, us, scratch, trunc);
+    } else {
+        let size = scratch.len();
+        split_into_chunks_mut!(yss_temps, size, [ts_2, us_2], scratch_2);
+        let mut ts_2 = ts_2;
+        let mut us_2 = us_2;
+        join(
+            || limbs_fft_truncate_sqrt(xss, w, ts, us, scratch, trunc),
+            || limbs_fft_truncate_sqrt(yss, w, &mut ts_2, &mut us_2, scratch_2, trunc),
+        );
+    }
+}
+
+// Applies `limbs_fft_mfa_truncate_sqrt_outer` to both `xss` and `yss`. If `yss_temps` is empty,
+// both transforms use `ts`, `us`, and `ss`, one after the other. Otherwise `yss_temps` provides the
+// temporaries for the transform of `yss` and the two transforms are run in parallel.
+#[allow(clippy::mut_mut, clippy::too_many_arguments)]
+fn limbs_fft_mfa_truncate_sqrt_outer_pair<'a>(
+    xss: &mut [&'a mut [Limb]],
+    yss: &mut [&'a mut [Limb]],
+    w: usize,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    ss: &mut &'a mut [Limb],
+    yss_temps: &'a mut [Limb],
+    xs_len: usize,
+    trunc: usize,
+) {
+    if yss_temps.is_empty() {
+        limbs_fft_mfa_truncate_sqrt_outer(xss, w, ts, us, ss, xs_len, trunc);
+        limbs_fft_mfa_truncate_sqrt_outer(yss, w, ts, us, ss, xs_len, trunc);
+    } else {
+        let size = ss.len();
+        split_into_chunks_mut!(yss_temps, size, [ts_2, us_2], ss_2);
+        let mut ts_2 = ts_2;
+        let mut us_2 = us_2;
+        let mut ss_2 = ss_2;
+        join(
+            || limbs_fft_mfa_truncate_sqrt_outer(xss, w, ts, us, ss, xs_len, trunc),
+            || {
+                limbs_fft_mfa_truncate_sqrt_outer(
+                    yss, w, &mut ts_2, &mut us_2, &mut ss_2, xs_len, trunc,
+                )
+            },
+        );
+    }
+}
+
+// Applies `f` to each element of `xss`, together with a scratch slice; this is how the pointwise
+// products of an FFT are computed. `size` is the length of each coefficient.
+//
+// If the `parallel` feature is enabled and the coefficients have at least
+// `MUL_FFT_PARALLEL_THRESHOLD` limbs in total, `xss` is split into two halves which are processed
+// in parallel, the second with a fresh copy of the scratch space. Otherwise the elements are
+// processed one after the other and share `scratch`. The results are the same either way.
+fn limbs_fft_pointwise<T: Send, F: Fn(&mut T, &mut [Limb]) + Sync>(
+    xss: &mut [T],
+    size: usize,
+    scratch: &mut [Limb],
+    f: &F,
+) {
+    let len = xss.len();
+    if cfg!(feature = "parallel") && len > 1 && len * size >= MUL_FFT_PARALLEL_THRESHOLD {
+        let (xss_lo, xss_hi) = xss.split_at_mut(len >> 1);
+        let mut scratch_2 = vec![0; scratch.len()];
+        join(
+            || limbs_fft_pointwise(xss_lo, size, scratch, f),
+            || limbs_fft_pointwise(xss_hi, size, &mut scratch_2, f),
+        );
+    } else {
+        for xs in xss.iter_mut() {
+            f(xs, scratch);
+        }
+    }
+}
+
+// Computes `limbs_fft_mulmod_2expp1` on `xs` and `ys`, carving the coefficient slices and
+// temporaries that it needs out of `scratch`.
+fn limbs_fft_mulmod_2expp1_with_scratch(
+    xs: &mut [Limb],
+    ys: &[Limb],
+    n: usize,
+    w: usize,
+    scratch: &mut [Limb],
+) {
+    let nw = n * w;
+    let depth2 = nw.ceiling_log_base_2();
+    let off = if depth2 < 12 {
+        MULMOD_TAB[0]
+    } else {
+        MULMOD_TAB[min(usize::exact_from(depth2), MULMOD_TAB.len() + 11) - 12]
+    };
+    let depth2 = (depth2 >> 1) - u64::from(off);
+    let w2 = nw >> (depth2 << 1);
+    let n3 = usize::power_of_2(depth2);
+    let size = ((n3 * w2) >> Limb::LOG_WIDTH) + 1;
+    let two_n3 = n3 << 1;
+    let yss_scratch_len = (n3 + n3 * size) << 1;
+    let (scratch, combine_scratch) = scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
+    let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
+    let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+    for _ in 0..two_n3 {
+        let (lo, hi) = xss_scratch.split_at_mut(size);
+        xss2.push(lo);
+        xss_scratch = hi;
+    }
+    let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
+    let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
+    let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
+    let mut yss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+    for _ in 0..two_n3 {
+        let (lo, hi) = yss_scratch.split_at_mut(size);
+        yss2.push(lo);
+        yss_scratch = hi;
+    }
+    limbs_fft_mulmod_2expp1(
+        xs,
+        ys,
+        n,
+        w,
+        &mut xss2,
+        xss0,
+        &mut yss2,
+        yss_scratch,
+        &mut ts2,
+        &mut us2,
+        &mut ss2,
+        combine_scratch,
+    );
+}
+
+// Computes `limbs_fft_mulmod_2expp1_same` on `xs`, carving the coefficient slices and temporaries
+// that it needs out of `scratch`.
+fn limbs_fft_mulmod_2expp1_same_with_scratch(
+    xs: &mut [Limb],
+    n: usize,
+    w: usize,
+    scratch: &mut [Limb],
+) {
+    let nw = n * w;
+    let depth2 = nw.ceiling_log_base_2();
+    let off = if depth2 < 12 {
+        MULMOD_TAB[0]
+    } else {
+        MULMOD_TAB[min(usize::exact_from(depth2), MULMOD_TAB.len() + 11) - 12]
+    };
+    let depth2 = (depth2 >> 1) - u64::from(off);
+    let w3 = nw >> (depth2 << 1);
+    let n3 = usize::power_of_2(depth2);
+    let size = ((n3 * w3) >> Limb::LOG_WIDTH) + 1;
+    let two_n3 = n3 << 1;
+    let (mut xss_scratch, combine_scratch) = scratch.split_at_mut(((n * size) << 2) + 3 * size);
+    let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+    for _ in 0..two_n3 {
+        let (lo, hi) = xss_scratch.split_at_mut(size);
+        xss2.push(lo);
+        xss_scratch = hi;
+    }
+    let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
+    let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
+    let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
+    limbs_fft_mulmod_2expp1_same(
+        xs,
+        n,
+        w,
+        &mut xss2,
+        xss0,
+        &mut ts2,
+        &mut us2,
+        &mut ss2,
+        combine_scratch,
+    );
+}
+
 pub_const_test! {
     #[cfg(feature = "32_bit_limbs")]
     limbs_mul_greater_to_out_fft_is_valid(xs_len: usize, ys_len: usize) -> bool {
@@ -2254,6 +2456,7 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         let four_n = n << 2;
         let (scratch, combine_scratch) = scratch.split_at_mut(((n * size) << 3) + 3 * size);
         let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut((n * size) << 2);
+        let mut yss_temps = limbs_fft_parallel_temps(xs_len + ys_len, size);
         let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(four_n);
         for _ in 0..four_n {
             let (lo, hi) = xss_scratch.split_at_mut(size);
@@ -2280,16 +2483,25 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         for xs in &mut xss[j1..] {
             slice_set_zero(xs);
         }
-        limbs_fft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, ss, trunc);
         j2 = limbs_fft_split_bits(&mut yss, ys, bits);
         for xs in &mut yss[j2..] {
             slice_set_zero(xs);
         }
-        limbs_fft_truncate_sqrt(&mut yss, w, &mut ts, &mut us, ss, trunc);
+        limbs_fft_truncate_sqrt_pair(
+            &mut xss,
+            &mut yss,
+            w,
+            &mut ts,
+            &mut us,
+            ss,
+            &mut yss_temps,
+            trunc,
+        );
         let n_2 = (b + U_WIDTH - 1) >> Limb::LOG_WIDTH;
         assert_eq!(n_2, len);
         let k = (n_2 << Limb::LOG_WIDTH) - b;
-        for (xs, ys) in xss.iter_mut().zip(yss.iter_mut()).take(trunc) {
+        let mut pairs: Vec<_> = xss.iter_mut().zip(yss.iter_mut()).take(trunc).collect();
+        limbs_fft_pointwise(&mut pairs, size, combine_scratch, &|(xs, ys), scratch| {
             limbs_fft_normmod_2expp1(xs);
             limbs_fft_normmod_2expp1(ys);
             let (xs_last, xs_init) = xs.split_last_mut().unwrap();
@@ -2302,9 +2514,9 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
                 ys_init,
                 c == 1,
                 k,
-                combine_scratch,
+                scratch,
             ));
-        }
+        });
         limbs_ifft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, &mut ss, trunc);
         let depth = depth + 2;
         for xs in &mut xss[..trunc] {
@@ -2328,6 +2540,7 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         let mut j2 = ((ys_len << Limb::LOG_WIDTH) - 1) / bits + 1;
         let (scratch, misc_scratch) = scratch.split_at_mut(((n * size) << 3) + 3 * size);
         let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut((n * size) << 2);
+        let mut yss_temps = limbs_fft_parallel_temps(xs_len + ys_len, size);
         let four_n = n << 2;
         let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(four_n);
         for _ in 0..four_n {
@@ -2352,141 +2565,64 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         for ps in &mut xss[j1..] {
             slice_set_zero(ps);
         }
-        limbs_fft_mfa_truncate_sqrt_outer(&mut xss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
         j2 = limbs_fft_split_bits(&mut yss, ys, bits);
         for qs in &mut yss[j2..] {
             slice_set_zero(qs);
         }
-        limbs_fft_mfa_truncate_sqrt_outer(&mut yss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
+        limbs_fft_mfa_truncate_sqrt_outer_pair(
+            &mut xss,
+            &mut yss,
+            w,
+            &mut ts,
+            &mut us,
+            &mut ss,
+            &mut yss_temps,
+            sqrt,
+            trunc,
+        );
         let two_n = four_n >> 1;
         let len = two_n / sqrt;
         let depth = len.ceiling_log_base_2();
-        // convolutions on relevant rows
-        let xss_hi = &mut xss[two_n..];
-        let yss_hi = &mut yss[two_n..];
         let wy = w * len;
-        if bits >> Limb::LOG_WIDTH <= cutoff {
-            let n_2 = (nw + U_WIDTH - 1) >> Limb::LOG_WIDTH;
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                let yss_hi = &mut yss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_hi.iter_mut().zip(yss_hi.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    let k = (n_2 << Limb::LOG_WIDTH) - nw;
-                    xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
-                        &mut xs[..n_2],
-                        &ys[..n_2],
-                        false,
-                        k,
-                        misc_scratch,
-                    ));
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for (xss_chunk, yss_chunk) in xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_chunk.iter_mut().zip(yss_chunk.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    let k = (n_2 << Limb::LOG_WIDTH) - nw;
-                    xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
-                        &mut xs[..n_2],
-                        &ys[..n_2],
-                        false,
-                        k,
-                        misc_scratch,
-                    ));
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
-            }
-        } else {
-            let depth2 = nw.ceiling_log_base_2();
-            let off = if depth2 < 12 {
-                MULMOD_TAB[0]
+        let n_2 = (nw + U_WIDTH - 1) >> Limb::LOG_WIDTH;
+        let k = (n_2 << Limb::LOG_WIDTH) - nw;
+        let basecase = bits >> Limb::LOG_WIDTH <= cutoff;
+        #[allow(clippy::mut_mut)]
+        let f = |(xs, ys): &mut (&mut &mut [Limb], &mut &mut [Limb]), scratch: &mut [Limb]| {
+            limbs_fft_normmod_2expp1(xs);
+            limbs_fft_normmod_2expp1(ys);
+            if basecase {
+                xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
+                    &mut xs[..n_2],
+                    &ys[..n_2],
+                    false,
+                    k,
+                    scratch,
+                ));
             } else {
-                MULMOD_TAB[min(usize::exact_from(depth2), MULMOD_TAB.len() + 11) - 12]
-            };
-            let depth2 = (depth2 >> 1) - u64::from(off);
-            let w2 = nw >> (depth2 << 1);
-            let n3 = usize::power_of_2(depth2);
-            let size = ((n3 * w2) >> Limb::LOG_WIDTH) + 1;
-            let two_n3 = n3 << 1;
-            let yss_scratch_len = (n3 + n3 * size) << 1;
-            let (scratch, combine_scratch) =
-                misc_scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
-            let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
-            let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = xss_scratch.split_at_mut(size);
-                xss2.push(lo);
-                xss_scratch = hi;
-            }
-            let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
-            let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
-            let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
-            let mut yss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = yss_scratch.split_at_mut(size);
-                yss2.push(lo);
-                yss_scratch = hi;
-            }
-            let yss0 = yss_scratch;
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                let yss_hi = &mut yss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_hi.iter_mut().zip(yss_hi.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    limbs_fft_mulmod_2expp1(
-                        xs,
-                        ys,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut yss2,
-                        yss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for (xss_chunk, yss_chunk) in xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_chunk.iter_mut().zip(yss_chunk.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    limbs_fft_mulmod_2expp1(
-                        xs,
-                        ys,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut yss2,
-                        yss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
+                limbs_fft_mulmod_2expp1_with_scratch(xs, ys, n, w, scratch);
             }
+        };
+        // convolutions on relevant rows
+        let xss_hi = &mut xss[two_n..];
+        let yss_hi = &mut yss[two_n..];
+        for s in 0..(trunc - two_n) / sqrt {
+            let start = sqrt * n_revbin(s, depth);
+            let xss_hi = &mut xss_hi[start..][..sqrt];
+            let yss_hi = &mut yss_hi[start..][..sqrt];
+            limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
+            limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
+            let mut pairs: Vec<_> = xss_hi.iter_mut().zip(yss_hi.iter_mut()).collect();
+            limbs_fft_pointwise(&mut pairs, size, misc_scratch, &f);
+            limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
+        }
+        // convolutions on rows
+        for (xss_chunk, yss_chunk) in xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len) {
+            limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
+            limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
+            let mut pairs: Vec<_> = xss_chunk.iter_mut().zip(yss_chunk.iter_mut()).collect();
+            limbs_fft_pointwise(&mut pairs, size, misc_scratch, &f);
+            limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
         }
         limbs_ifft_mfa_truncate_sqrt_outer(&mut xss, n, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
         let out = &mut out[..xs_len + ys_len];
@@ -2668,16 +2804,12 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
             slice_set_zero(xs);
         }
         limbs_fft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, ss, trunc);
-        for xs in &mut xss[..trunc] {
+        limbs_fft_pointwise(&mut xss[..trunc], size, combine_scratch, &|xs, scratch| {
             limbs_fft_normmod_2expp1(xs);
             let (xs_last, xs_init) = xs.split_last_mut().unwrap();
             assert_eq!(*xs_last, 0);
-            *xs_last = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(
-                xs_init,
-                b,
-                combine_scratch,
-            ));
-        }
+            *xs_last = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs_init, b, scratch));
+        });
         limbs_ifft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, &mut ss, trunc);
         let depth = depth + 2;
         for xs in &mut xss[0..trunc] {
@@ -2721,93 +2853,31 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
         let two_n = four_n >> 1;
         let len = two_n / sqrt;
         let depth = len.ceiling_log_base_2();
-        // convolutions on relevant rows
-        let xss_hi = &mut xss[two_n..];
         let wy = w * len;
-        if nw >> Limb::LOG_WIDTH <= cutoff {
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                for xs in xss_hi.iter_mut() {
-                    limbs_fft_normmod_2expp1(xs);
-                    xs[limbs] =
-                        Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, misc_scratch));
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for xss_chunk in xss.chunks_mut(sqrt).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                for xs in xss_chunk.iter_mut() {
-                    limbs_fft_normmod_2expp1(xs);
-                    xs[limbs] =
-                        Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, misc_scratch));
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
-            }
-        } else {
-            let depth2 = nw.ceiling_log_base_2();
-            let off = if depth2 < 12 {
-                MULMOD_TAB[0]
+        let basecase = nw >> Limb::LOG_WIDTH <= cutoff;
+        #[allow(clippy::mut_mut)]
+        let f = |xs: &mut &mut [Limb], scratch: &mut [Limb]| {
+            limbs_fft_normmod_2expp1(xs);
+            if basecase {
+                xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, scratch));
             } else {
-                MULMOD_TAB[min(usize::exact_from(depth2), MULMOD_TAB.len() + 11) - 12]
-            };
-            let depth2 = (depth2 >> 1) - u64::from(off);
-            let w3 = nw >> (depth2 << 1);
-            let n3 = usize::power_of_2(depth2);
-            let size = ((n3 * w3) >> Limb::LOG_WIDTH) + 1;
-            let two_n3 = n3 << 1;
-            let (mut xss_scratch, combine_scratch) =
-                misc_scratch.split_at_mut(((n * size) << 2) + 3 * size);
-            let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = xss_scratch.split_at_mut(size);
-                xss2.push(lo);
-                xss_scratch = hi;
-            }
-            let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
-            let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
-            let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                for xs in xss_hi.iter_mut() {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_mulmod_2expp1_same(
-                        xs,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for xss_chunk in xss.chunks_mut(sqrt).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                for xs in xss_chunk.iter_mut() {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_mulmod_2expp1_same(
-                        xs,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
+                limbs_fft_mulmod_2expp1_same_with_scratch(xs, n, w, scratch);
             }
+        };
+        // convolutions on relevant rows
+        let xss_hi = &mut xss[two_n..];
+        for s in 0..(trunc - two_n) / sqrt {
+            let start = sqrt * n_revbin(s, depth);
+            let xss_hi = &mut xss_hi[start..][..sqrt];
+            limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
+            limbs_fft_pointwise(xss_hi, size, misc_scratch, &f);
+            limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
+        }
+        // convolutions on rows
+        for xss_chunk in xss.chunks_mut(sqrt).take(len) {
+            limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
+            limbs_fft_pointwise(xss_chunk, size, misc_scratch, &f);
+            limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
         }
         limbs_ifft_mfa_truncate_sqrt_outer(&mut xss, n, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
         let out = &mut out[..xs_len << 1];
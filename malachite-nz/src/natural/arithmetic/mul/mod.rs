@@ -4,6 +4,7 @@ use crate::natural::arithmetic::mul::fft::{
     limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_scratch_len,
 };
 use crate::natural::arithmetic::mul::limb::limbs_mul_limb_to_out;
+use crate::natural::arithmetic::mul::ntt::limbs_mul_greater_to_out_ntt;
 use crate::natural::arithmetic::mul::toom::MUL_TOOM33_THRESHOLD_LIMIT;
 use crate::natural::arithmetic::mul::toom::{
     limbs_mul_greater_to_out_toom_22, limbs_mul_greater_to_out_toom_22_scratch_len,
@@ -20,10 +21,10 @@ use crate::natural::arithmetic::mul::toom::{
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
-    Limb, MUL_FFT_THRESHOLD, MUL_TOOM22_THRESHOLD, MUL_TOOM32_TO_TOOM43_THRESHOLD,
-    MUL_TOOM32_TO_TOOM53_THRESHOLD, MUL_TOOM33_THRESHOLD, MUL_TOOM42_TO_TOOM53_THRESHOLD,
-    MUL_TOOM42_TO_TOOM63_THRESHOLD, MUL_TOOM44_THRESHOLD, MUL_TOOM6H_THRESHOLD,
-    MUL_TOOM8H_THRESHOLD,
+    Limb, MUL_FFT_THRESHOLD, MUL_NTT_THRESHOLD, MUL_TOOM22_THRESHOLD,
+    MUL_TOOM32_TO_TOOM43_THRESHOLD, MUL_TOOM32_TO_TOOM53_THRESHOLD, MUL_TOOM33_THRESHOLD,
+    MUL_TOOM42_TO_TOOM53_THRESHOLD, MUL_TOOM42_TO_TOOM63_THRESHOLD, MUL_TOOM44_THRESHOLD,
+    MUL_TOOM6H_THRESHOLD, MUL_TOOM8H_THRESHOLD, MUL_TOOM_PARALLEL_THRESHOLD,
 };
 use malachite_base::num::basic::traits::One;
 use malachite_base::num::basic::traits::Zero;
@@ -98,10 +99,13 @@ pub_crate_test! { limbs_mul_same_length_to_out_scratch_len(len: usize) -> usize
         limbs_mul_greater_to_out_toom_44_scratch_len(len, len)
     } else if len < MUL_TOOM8H_THRESHOLD {
         limbs_mul_greater_to_out_toom_6h_scratch_len(len, len)
-    } else if len < MUL_FFT_THRESHOLD {
+    } else if len < MUL_FFT_THRESHOLD && len < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_toom_8h_scratch_len(len, len)
-    } else {
+    } else if len < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_fft_scratch_len(len, len)
+    } else {
+        // The NTT allocates its own memory.
+        0
     }
 }}
 
@@ -141,10 +145,12 @@ pub_crate_test! {limbs_mul_same_length_to_out(
         limbs_mul_greater_to_out_toom_44(out, xs, ys, scratch);
     } else if len < MUL_TOOM8H_THRESHOLD {
         limbs_mul_greater_to_out_toom_6h(out, xs, ys, scratch);
-    } else if len < MUL_FFT_THRESHOLD {
+    } else if len < MUL_FFT_THRESHOLD && len < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_toom_8h(out, xs, ys, scratch);
-    } else {
+    } else if len < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_fft(out, xs, ys, scratch);
+    } else {
+        limbs_mul_greater_to_out_ntt(out, xs, ys);
     }
 }}
 
@@ -236,8 +242,11 @@ pub_crate_test! { limbs_mul_greater_to_out_scratch_len(xs_len: usize, ys_len: us
         } else {
             limbs_mul_greater_to_out_toom_8h_scratch_len(xs_len, ys_len)
         }
-    } else {
+    } else if (xs_len + ys_len) >> 1 < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_fft_scratch_len(xs_len, ys_len)
+    } else {
+        // The NTT allocates its own memory.
+        0
     }
 }}
 
@@ -386,8 +395,10 @@ pub_crate_test! {limbs_mul_greater_to_out(
         } else {
             limbs_mul_greater_to_out_toom_8h(out, xs, ys, scratch);
         }
-    } else {
+    } else if (xs_len + ys_len) >> 1 < MUL_NTT_THRESHOLD {
         limbs_mul_greater_to_out_fft(out, xs, ys, scratch);
+    } else {
+        limbs_mul_greater_to_out_ntt(out, xs, ys);
     }
     out[xs_len + ys_len - 1]
 }}
@@ -468,6 +479,53 @@ pub_crate_test! {limbs_mul_greater_to_out_basecase(out: &mut [Limb], xs: &[Limb]
     }
 }}
 
+// Runs `f` and `g`, possibly in parallel, and returns both results. In parallel only if the
+// `parallel` feature is enabled; otherwise `f` is run, then `g`.
+#[cfg(feature = "parallel")]
+#[inline]
+pub(crate) fn join<F: FnOnce() -> A + Send, G: FnOnce() -> B + Send, A: Send, B: Send>(
+    f: F,
+    g: G,
+) -> (A, B) {
+    rayon::join(f, g)
+}
+
+// Runs `f` and `g`, possibly in parallel, and returns both results. In parallel only if the
+// `parallel` feature is enabled; otherwise `f` is run, then `g`.
+#[cfg(not(feature = "parallel"))]
+#[inline]
+pub(crate) fn join<F: FnOnce() -> A + Send, G: FnOnce() -> B + Send, A: Send, B: Send>(
+    f: F,
+    g: G,
+) -> (A, B) {
+    (f(), g())
+}
+
+// Computes two independent sub-products of a Toom-Cook multiplication or squaring. Each of `f`
+// and `g` writes one sub-product to its own output slice, using the scratch slice that it is
+// passed. `n` is the length of the factors of each sub-product.
+//
+// If the `parallel` feature is enabled and `n` is at least `MUL_TOOM_PARALLEL_THRESHOLD`, `g` is
+// given a fresh copy of the scratch space and the two are computed in parallel. Otherwise `f` and
+// `g` share `scratch` and are computed one after the other. The results are the same either way.
+pub(crate) fn limbs_toom_sub_products<
+    F: FnOnce(&mut [Limb]) + Send,
+    G: FnOnce(&mut [Limb]) + Send,
+>(
+    n: usize,
+    scratch: &mut [Limb],
+    f: F,
+    g: G,
+) {
+    if cfg!(feature = "parallel") && n >= MUL_TOOM_PARALLEL_THRESHOLD {
+        let mut scratch_2 = vec![0; scratch.len()];
+        join(|| f(scratch), || g(&mut scratch_2));
+    } else {
+        f(scratch);
+        g(scratch);
+    }
+}
+
 impl Mul<Natural> for Natural {
     type Output = Natural;
 
@@ -713,9 +771,164 @@ impl<'a> MulAssign<&'a Natural> for Natural {
     }
 }
 
+// Accumulates the factors of a product so that they are multiplied together in a balanced binary
+// tree, as in `limbs_product`. Factors that fit in a `Limb` are first multiplied together until the
+// product no longer fits in a `Limb`, so that the leaves of the tree are not too small.
+//
+// The $k$th leaf pushed is multiplied by the top $t$ partial products on the stack, where $t$ is
+// the number of trailing zeros of $k$; this keeps the stack's partial products sorted by the
+// number of leaves that they contain, each being a power of 2.
+struct ProductTreeAccumulator {
+    stack: Vec<Natural>,
+    leaf_count: usize,
+    limb: Limb,
+}
+
+impl ProductTreeAccumulator {
+    const fn new() -> ProductTreeAccumulator {
+        ProductTreeAccumulator {
+            stack: Vec::new(),
+            leaf_count: 0,
+            limb: 1,
+        }
+    }
+
+    fn push_leaf(&mut self, mut p: Natural) {
+        self.leaf_count += 1;
+        for _ in 0..self.leaf_count.trailing_zeros() {
+            p *= self.stack.pop().unwrap();
+        }
+        self.stack.push(p);
+    }
+
+    // `x` must be nonzero.
+    fn push_limb(&mut self, x: Limb) {
+        if let Some(p) = self.limb.checked_mul(x) {
+            self.limb = p;
+        } else {
+            let p = self.limb;
+            self.limb = x;
+            self.push_leaf(Natural::from(p));
+        }
+    }
+
+    // Returns `false` iff `x` is zero. In that case `x` is not pushed, and the caller may stop
+    // early, since the product is zero.
+    fn push(&mut self, x: Natural) -> bool {
+        match x {
+            Natural(Small(0)) => false,
+            Natural(Small(x)) => {
+                self.push_limb(x);
+                true
+            }
+            x => {
+                self.push_leaf(x);
+                true
+            }
+        }
+    }
+
+    // Returns `false` iff `x` is zero. In that case `x` is not pushed, and the caller may stop
+    // early, since the product is zero.
+    fn push_ref(&mut self, x: &Natural) -> bool {
+        match *x {
+            Natural(Small(0)) => false,
+            Natural(Small(x)) => {
+                self.push_limb(x);
+                true
+            }
+            ref x => {
+                self.push_leaf(x.clone());
+                true
+            }
+        }
+    }
+
+    fn finish(mut self) -> Natural {
+        if self.limb != 1 {
+            let p = self.limb;
+            self.push_leaf(Natural::from(p));
+        }
+        let mut p = Natural::ONE;
+        for x in self.stack.into_iter().rev() {
+            p *= x;
+        }
+        p
+    }
+}
+
+// Given a slice of values, returns the levels of their product tree, starting with the leaves.
+// Each level is obtained from the previous one by multiplying adjacent pairs; if the previous level
+// has odd length, its last element is carried up unchanged.
+pub(crate) fn product_tree<T: Clone>(xs: &[T]) -> Vec<Vec<T>>
+where
+    for<'a, 'b> &'a T: Mul<&'b T, Output = T>,
+{
+    if xs.is_empty() {
+        return Vec::new();
+    }
+    let mut levels = vec![xs.to_vec()];
+    while levels.last().unwrap().len() > 1 {
+        let level = levels.last().unwrap();
+        let next = level
+            .chunks(2)
+            .map(|pair| {
+                if pair.len() == 2 {
+                    &pair[0] * &pair[1]
+                } else {
+                    pair[0].clone()
+                }
+            })
+            .collect();
+        levels.push(next);
+    }
+    levels
+}
+
+impl Natural {
+    /// Computes the product tree of a slice of [`Natural`]s.
+    ///
+    /// The tree is returned as a list of levels, starting with the leaves, which are copies of
+    /// `xs`. Each subsequent level is obtained by multiplying adjacent pairs of the previous level;
+    /// if the previous level has an odd length, its last element is carried up unchanged. The last
+    /// level contains a single element, the product of all the [`Natural`]s. If `xs` is empty, the
+    /// tree is empty.
+    ///
+    /// Product trees are the first step of many algorithms that operate on many moduli at once,
+    /// such as simultaneous reduction and Chinese remaindering.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Natural::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::product_tree(&[]).to_debug_string(), "[]");
+    /// assert_eq!(
+    ///     Natural::product_tree(&vec_from_str::<Natural>("[2, 3, 5, 7, 11]").unwrap())
+    ///         .to_debug_string(),
+    ///     "[[2, 3, 5, 7, 11], [6, 35, 11], [210, 11], [2310]]"
+    /// );
+    /// ```
+    pub fn product_tree(xs: &[Natural]) -> Vec<Vec<Natural>> {
+        product_tree(xs)
+    }
+}
+
 impl Product for Natural {
     /// Multiplies together all the [`Natural`]s in an iterator.
     ///
+    /// The factors are multiplied together in a balanced binary tree, after first combining
+    /// consecutive factors that fit in a single limb.
+    ///
     /// $$
     /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i.
     /// $$
@@ -743,28 +956,22 @@ impl Product for Natural {
     where
         I: Iterator<Item = Natural>,
     {
-        let mut stack = Vec::new();
-        for (i, x) in xs.enumerate().map(|(i, x)| (i + 1, x)) {
-            if x == 0 {
+        let mut acc = ProductTreeAccumulator::new();
+        for x in xs {
+            if !acc.push(x) {
                 return Natural::ZERO;
             }
-            let mut p = x;
-            for _ in 0..i.trailing_zeros() {
-                p *= stack.pop().unwrap();
-            }
-            stack.push(p);
-        }
-        let mut p = Natural::ONE;
-        for x in stack.into_iter().rev() {
-            p *= x;
         }
-        p
+        acc.finish()
     }
 }
 
 impl<'a> Product<&'a Natural> for Natural {
     /// Multiplies together all the [`Natural`]s in an iterator of [`Natural`] references.
     ///
+    /// The factors are multiplied together in a balanced binary tree, after first combining
+    /// consecutive factors that fit in a single limb.
+    ///
     /// $$
     /// f((x_i)_ {i=0}^{n-1}) = \prod_ {i=0}^{n-1} x_i.
     /// $$
@@ -789,22 +996,13 @@ impl<'a> Product<&'a Natural> for Natural {
     where
         I: Iterator<Item = &'a Natural>,
     {
-        let mut stack = Vec::new();
-        for (i, x) in xs.enumerate().map(|(i, x)| (i + 1, x)) {
-            if *x == 0 {
+        let mut acc = ProductTreeAccumulator::new();
+        for x in xs {
+            if !acc.push_ref(x) {
                 return Natural::ZERO;
             }
-            let mut p = x.clone();
-            for _ in 0..i.trailing_zeros() {
-                p *= stack.pop().unwrap();
-            }
-            stack.push(p);
         }
-        let mut p = Natural::ONE;
-        for x in stack.into_iter().rev() {
-            p *= x;
-        }
-        p
+        acc.finish()
     }
 }
 
@@ -817,6 +1015,9 @@ pub mod mul_low;
 /// Code for multiplying two [`Natural`]s modulo one less than a large power of 2; used by the
 /// Schönhage-Strassen algorithm.
 pub mod mul_mod;
+/// Code for multiplying very large [`Natural`]s using a number-theoretic transform modulo three
+/// primes.
+pub mod ntt;
 /// Code for evaluating polynomials at various points; used in Toom-Cook multiplication.
 pub mod poly_eval;
 /// Code for reconstructing polynomials from their values at various points; used in Toom-Cook
@@ -0,0 +1,456 @@
+use crate::natural::arithmetic::mul::join;
+use crate::platform::{Limb, MUL_FFT_PARALLEL_THRESHOLD};
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::conversion::traits::WrappingFrom;
+#[cfg(feature = "32_bit_limbs")]
+use malachite_base::num::conversion::traits::{JoinHalves, SplitInHalf};
+use malachite_base::slices::slice_set_zero;
+use std::cmp::max;
+
+// A prime $p < 2^{62}$ for which $2^{42}$ divides $p - 1$, together with the constants needed to
+// do Montgomery arithmetic modulo $p$ with $R = 2^{64}$. Values are kept in ordinary
+// (non-Montgomery) form, and during a transform they are only reduced to $[0, 2p)$; multiplying
+// such a value by a constant in Montgomery form gives an ordinary product, so only constants and
+// twiddle factors are stored in Montgomery form.
+struct NttPrime {
+    p: u64,
+    // $-p^{-1} \mod 2^{64}$
+    p_inv_neg: u64,
+    // $R \mod p$, or 1 in Montgomery form
+    one: u64,
+    // $R^2 \mod p$
+    r2: u64,
+    // A primitive $2^{42}$th root of unity, in Montgomery form
+    root: u64,
+}
+
+const NTT_MAX_LOG_LEN: u64 = 42;
+
+// Transforms of at most this many 64-bit words are computed level by level; larger ones are split
+// recursively.
+const NTT_BLOCK_LEN: usize = 1 << 10;
+
+const fn const_mul_mod(x: u64, y: u64, p: u64) -> u64 {
+    ((x as u128 * y as u128) % p as u128) as u64
+}
+
+const fn const_pow_mod(mut x: u64, mut exp: u64, p: u64) -> u64 {
+    let mut out = 1;
+    while exp != 0 {
+        if exp & 1 != 0 {
+            out = const_mul_mod(out, x, p);
+        }
+        x = const_mul_mod(x, x, p);
+        exp >>= 1;
+    }
+    out
+}
+
+// Returns $x R \mod p$.
+const fn const_to_montgomery(x: u64, p: u64) -> u64 {
+    (((x as u128) << 64) % p as u128) as u64
+}
+
+impl NttPrime {
+    // `g` must be a primitive root modulo `p`.
+    const fn new(p: u64, g: u64) -> NttPrime {
+        // Newton's iteration doubles the number of correct low bits each time; p is its own
+        // inverse modulo 8.
+        let mut inv = p;
+        let mut i = 0;
+        while i < 5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(p.wrapping_mul(inv)));
+            i += 1;
+        }
+        NttPrime {
+            p,
+            p_inv_neg: inv.wrapping_neg(),
+            one: const_to_montgomery(1, p),
+            r2: const_mul_mod(const_to_montgomery(1, p), const_to_montgomery(1, p), p),
+            root: const_to_montgomery(const_pow_mod(g, (p - 1) >> NTT_MAX_LOG_LEN, p), p),
+        }
+    }
+
+    // Returns $xyR^{-1} \mod p$, possibly plus $p$. Either `x` and `y` must both be less than
+    // $2p$, or `x` must be less than $2^{64}$ and `y` less than $p$.
+    #[inline]
+    fn mul_lazy(&self, x: u64, y: u64) -> u64 {
+        let product = u128::from(x) * u128::from(y);
+        let m = u64::wrapping_from(product).wrapping_mul(self.p_inv_neg);
+        u64::wrapping_from((product + u128::from(m) * u128::from(self.p)) >> 64)
+    }
+
+    // Returns $xyR^{-1} \mod p$. `x` and `y` must be less than $2p$.
+    #[inline]
+    fn mul(&self, x: u64, y: u64) -> u64 {
+        let r = self.mul_lazy(x, y);
+        if r >= self.p {
+            r - self.p
+        } else {
+            r
+        }
+    }
+
+    #[inline]
+    const fn add(&self, x: u64, y: u64) -> u64 {
+        let sum = x + y;
+        if sum >= self.p {
+            sum - self.p
+        } else {
+            sum
+        }
+    }
+
+    #[inline]
+    const fn sub(&self, x: u64, y: u64) -> u64 {
+        if x >= y {
+            x - y
+        } else {
+            x + self.p - y
+        }
+    }
+
+    // Returns $x^e$, where `x` and the result are in Montgomery form.
+    fn pow(&self, mut x: u64, mut exp: u64) -> u64 {
+        let mut out = self.one;
+        while exp != 0 {
+            if exp & 1 != 0 {
+                out = self.mul(out, x);
+            }
+            x = self.mul(x, x);
+            exp >>= 1;
+        }
+        out
+    }
+
+    // Returns a table that contains, for each power of 2 $m$ with $2 \leq m \leq 2^\ell$, where
+    // $\ell$ is `log_len`, the first $m / 2$ powers of a primitive $m$th root of unity (or of its
+    // inverse, if `inverse` is `true`), in Montgomery form, at the indices $[m / 2, m)$. Since the
+    // roots are all powers of the same $2^\ell$th root, the smaller tables are obtained by taking
+    // every other entry of the next larger one. `log_len` must be positive.
+    fn twiddles(&self, log_len: u64, inverse: bool) -> Vec<u64> {
+        let len = usize::power_of_2(log_len);
+        let mut w = self.pow(self.root, u64::power_of_2(NTT_MAX_LOG_LEN - log_len));
+        if inverse {
+            w = self.pow(w, u64::wrapping_from(len - 1));
+        }
+        let mut ws = vec![0; len];
+        let mut x = self.one;
+        for t in &mut ws[len >> 1..] {
+            *t = x;
+            x = self.mul(x, w);
+        }
+        let mut m = len >> 1;
+        while m > 1 {
+            let (ws_lo, ws_hi) = ws.split_at_mut(m);
+            for (t, &u) in ws_lo[m >> 1..].iter_mut().zip(ws_hi.iter().step_by(2)) {
+                *t = u;
+            }
+            m >>= 1;
+        }
+        ws
+    }
+
+    // Applies one level of decimation-in-frequency butterflies to `xs`, whose length is twice the
+    // length of `ws`. The entries of `xs` must be, and remain, less than $2p$.
+    #[inline]
+    fn forward_butterflies(&self, xs: &mut [u64], ws: &[u64]) {
+        let two_p = self.p << 1;
+        let (xs_lo, xs_hi) = xs.split_at_mut(ws.len());
+        for ((x, y), &w) in xs_lo.iter_mut().zip(xs_hi.iter_mut()).zip(ws.iter()) {
+            let (a, b) = (*x, *y);
+            let sum = a + b;
+            *x = if sum >= two_p { sum - two_p } else { sum };
+            *y = self.mul_lazy(a + two_p - b, w);
+        }
+    }
+
+    // Applies one level of decimation-in-time butterflies to `xs`, whose length is twice the
+    // length of `ws`. The entries of `xs` must be, and remain, less than $2p$.
+    #[inline]
+    fn inverse_butterflies(&self, xs: &mut [u64], ws: &[u64]) {
+        let two_p = self.p << 1;
+        let (xs_lo, xs_hi) = xs.split_at_mut(ws.len());
+        for ((x, y), &w) in xs_lo.iter_mut().zip(xs_hi.iter_mut()).zip(ws.iter()) {
+            let a = *x;
+            let b = self.mul_lazy(*y, w);
+            let sum = a + b;
+            *x = if sum >= two_p { sum - two_p } else { sum };
+            let diff = a + two_p - b;
+            *y = if diff >= two_p { diff - two_p } else { diff };
+        }
+    }
+
+    // Replaces `xs` with its transform, in bit-reversed order, using the decimation-in-frequency
+    // (Gentleman-Sande) algorithm. The entries of `xs` must be, and remain, less than $2p$. `ws`
+    // must be a non-inverse output of `twiddles` for a length at least `xs.len()`.
+    //
+    // Transforms that don't fit in the cache are split depth-first, so that each half is finished
+    // while it is still cached.
+    fn forward(&self, xs: &mut [u64], ws: &[u64]) {
+        let len = xs.len();
+        if len > NTT_BLOCK_LEN {
+            self.forward_butterflies(xs, &ws[len >> 1..len]);
+            let (xs_lo, xs_hi) = xs.split_at_mut(len >> 1);
+            self.forward(xs_lo, ws);
+            self.forward(xs_hi, ws);
+        } else {
+            let mut m = len;
+            while m > 1 {
+                for block in xs.chunks_exact_mut(m) {
+                    self.forward_butterflies(block, &ws[m >> 1..m]);
+                }
+                m >>= 1;
+            }
+        }
+    }
+
+    // Replaces `xs`, a transform in bit-reversed order, with `xs.len()` times its inverse
+    // transform, in natural order, using the decimation-in-time (Cooley-Tukey) algorithm. The
+    // entries of `xs` must be, and remain, less than $2p$. `ws_inv` must be an inverse output of
+    // `twiddles` for a length at least `xs.len()`.
+    fn inverse(&self, xs: &mut [u64], ws_inv: &[u64]) {
+        let len = xs.len();
+        if len > NTT_BLOCK_LEN {
+            let (xs_lo, xs_hi) = xs.split_at_mut(len >> 1);
+            self.inverse(xs_lo, ws_inv);
+            self.inverse(xs_hi, ws_inv);
+            self.inverse_butterflies(xs, &ws_inv[len >> 1..len]);
+        } else {
+            let mut m = 2;
+            while m <= len {
+                for block in xs.chunks_exact_mut(m) {
+                    self.inverse_butterflies(block, &ws_inv[m >> 1..m]);
+                }
+                m <<= 1;
+            }
+        }
+    }
+}
+
+const NTT_PRIME_1: NttPrime = NttPrime::new(0x3fff540000000001, 5);
+const NTT_PRIME_2: NttPrime = NttPrime::new(0x3fff840000000001, 19);
+const NTT_PRIME_3: NttPrime = NttPrime::new(0x3fffc00000000001, 11);
+
+// $p_1^{-1} \mod p_2$, in Montgomery form
+const NTT_P1_INV_MOD_P2: u64 = const_to_montgomery(
+    const_pow_mod(
+        NTT_PRIME_1.p % NTT_PRIME_2.p,
+        NTT_PRIME_2.p - 2,
+        NTT_PRIME_2.p,
+    ),
+    NTT_PRIME_2.p,
+);
+// $p_1 \mod p_3$, in Montgomery form
+const NTT_P1_MOD_P3: u64 = const_to_montgomery(NTT_PRIME_1.p % NTT_PRIME_3.p, NTT_PRIME_3.p);
+// $(p_1p_2)^{-1} \mod p_3$, in Montgomery form
+const NTT_P1_P2_INV_MOD_P3: u64 = const_to_montgomery(
+    const_pow_mod(
+        const_mul_mod(NTT_PRIME_1.p, NTT_PRIME_2.p, NTT_PRIME_3.p),
+        NTT_PRIME_3.p - 2,
+        NTT_PRIME_3.p,
+    ),
+    NTT_PRIME_3.p,
+);
+
+// Interprets a slice of `Limb`s as the digits of a number in base $2^{64}$, which are the
+// coefficients that the NTT operates on.
+#[cfg(feature = "32_bit_limbs")]
+fn limbs_to_ntt_words(xs: &[Limb]) -> Vec<u64> {
+    xs.chunks(2)
+        .map(|chunk| u64::join_halves(chunk.get(1).copied().unwrap_or(0), chunk[0]))
+        .collect()
+}
+
+// Computes, modulo `prime`, the cyclic convolution of `xs` and `ys` of length `out.len()` (or of
+// `xs` with itself, if `ys` is `None`), writing it to `out`. `ys_scratch` must have the same length
+// as `out`, unless `ys` is `None`.
+fn limbs_ntt_convolution_mod_prime(
+    out: &mut [u64],
+    xs: &[u64],
+    ys: Option<&[u64]>,
+    ys_scratch: &mut [u64],
+    prime: &NttPrime,
+) {
+    let len = out.len();
+    let log_len = u64::wrapping_from(len.trailing_zeros());
+    let ws = prime.twiddles(log_len, false);
+    // Multiplying by 1 in Montgomery form reduces the coefficients to $[0, 2p)$ without a division.
+    let (out_lo, out_hi) = out.split_at_mut(xs.len());
+    for (o, &x) in out_lo.iter_mut().zip(xs.iter()) {
+        *o = prime.mul_lazy(x, prime.one);
+    }
+    slice_set_zero(out_hi);
+    prime.forward(out, &ws);
+    if let Some(ys) = ys {
+        let (ys_scratch_lo, ys_scratch_hi) = ys_scratch.split_at_mut(ys.len());
+        for (o, &y) in ys_scratch_lo.iter_mut().zip(ys.iter()) {
+            *o = prime.mul_lazy(y, prime.one);
+        }
+        slice_set_zero(ys_scratch_hi);
+        prime.forward(ys_scratch, &ws);
+        for (o, &y) in out.iter_mut().zip(ys_scratch.iter()) {
+            *o = prime.mul_lazy(*o, y);
+        }
+    } else {
+        for o in out.iter_mut() {
+            *o = prime.mul_lazy(*o, *o);
+        }
+    }
+    drop(ws);
+    prime.inverse(out, &prime.twiddles(log_len, true));
+    // Each pointwise product introduced a factor of $R^{-1}$ and the inverse transform a factor
+    // of `len`, so multiply by $R^2 / \text{len}$, in Montgomery form. Since
+    // $\text{len} \cdot (p - 1) / \text{len} = p - 1 \equiv -1$, the inverse of `len` is
+    // $p - (p - 1) / \text{len}$.
+    let len_inv = prime.p - (prime.p - 1) / u64::wrapping_from(len);
+    let scale = prime.mul(prime.mul(len_inv, prime.r2), prime.r2);
+    for o in out.iter_mut() {
+        *o = prime.mul(*o, scale);
+    }
+}
+
+// Recombines the residues of the coefficients of a convolution modulo the three primes, using
+// Garner's algorithm, and adds the coefficients, shifted by multiples of 64 bits, to obtain the
+// product. Writes the `out.len()` lowest 64-bit words of the product to `out`; the product must
+// fit.
+fn limbs_ntt_recombine(out: &mut [u64], rs_1: &[u64], rs_2: &[u64], rs_3: &[u64]) {
+    let p_1 = NTT_PRIME_1.p;
+    let p_1_p_2 = u128::from(p_1) * u128::from(NTT_PRIME_2.p);
+    let p_1_p_2_lo = u128::from(u64::wrapping_from(p_1_p_2));
+    let p_1_p_2_hi = p_1_p_2 >> 64;
+    let mut carry = 0u128;
+    for (i, o) in out.iter_mut().enumerate() {
+        if i >= rs_1.len() {
+            *o = u64::wrapping_from(carry);
+            carry >>= 64;
+            continue;
+        }
+        let (r_1, r_2, r_3) = (rs_1[i], rs_2[i], rs_3[i]);
+        // x = r_1 + p_1 * t is the coefficient modulo p_1 * p_2
+        let t = NTT_PRIME_2.mul(NTT_PRIME_2.sub(r_2, r_1), NTT_P1_INV_MOD_P2);
+        let x = u128::from(r_1) + u128::from(p_1) * u128::from(t);
+        // the coefficient is x + p_1 * p_2 * u
+        let x_mod_p_3 = NTT_PRIME_3.add(r_1, NTT_PRIME_3.mul(t, NTT_P1_MOD_P3));
+        let u = u128::from(NTT_PRIME_3.mul(NTT_PRIME_3.sub(r_3, x_mod_p_3), NTT_P1_P2_INV_MOD_P3));
+        // x < 2^124 and p_1_p_2_lo * u < 2^126, so this doesn't overflow
+        let lo = x + p_1_p_2_lo * u + u128::from(u64::wrapping_from(carry));
+        *o = u64::wrapping_from(lo);
+        carry = (lo >> 64) + p_1_p_2_hi * u + (carry >> 64);
+    }
+    assert_eq!(carry, 0);
+}
+
+// Computes the convolution of `xs` and `ys` (or of `xs` with itself, if `ys` is `None`) modulo
+// each of the three primes, and combines the results into the product, writing its `out.len()`
+// lowest 64-bit words to `out`.
+fn limbs_ntt_mul_words_to_out(out: &mut [u64], xs: &[u64], ys: Option<&[u64]>) {
+    let ys_len = ys.map_or(xs.len(), <[u64]>::len);
+    let len = max((xs.len() + ys_len - 1).next_power_of_two(), 2);
+    assert!(len <= usize::power_of_2(NTT_MAX_LOG_LEN));
+    let ys_scratch_len = if ys.is_some() { len } else { 0 };
+    let mut rs = vec![0; 3 * len];
+    split_into_chunks_mut!(rs, len, [rs_1, rs_2], rs_3);
+    if cfg!(feature = "parallel") && out.len() >= MUL_FFT_PARALLEL_THRESHOLD {
+        let mut ys_scratch = vec![0; 3 * ys_scratch_len];
+        split_into_chunks_mut!(
+            ys_scratch,
+            ys_scratch_len,
+            [ys_scratch_1, ys_scratch_2],
+            ys_scratch_3
+        );
+        join(
+            || limbs_ntt_convolution_mod_prime(rs_1, xs, ys, ys_scratch_1, &NTT_PRIME_1),
+            || {
+                join(
+                    || limbs_ntt_convolution_mod_prime(rs_2, xs, ys, ys_scratch_2, &NTT_PRIME_2),
+                    || limbs_ntt_convolution_mod_prime(rs_3, xs, ys, ys_scratch_3, &NTT_PRIME_3),
+                )
+            },
+        );
+    } else {
+        let mut ys_scratch = vec![0; ys_scratch_len];
+        limbs_ntt_convolution_mod_prime(rs_1, xs, ys, &mut ys_scratch, &NTT_PRIME_1);
+        limbs_ntt_convolution_mod_prime(rs_2, xs, ys, &mut ys_scratch, &NTT_PRIME_2);
+        limbs_ntt_convolution_mod_prime(rs_3, xs, ys, &mut ys_scratch, &NTT_PRIME_3);
+    }
+    limbs_ntt_recombine(out, rs_1, rs_2, rs_3);
+}
+
+// Writes the product of `xs` and `ys` (or the square of `xs`, if `ys` is `None`) to
+// `out[..out_len]`, where `out_len` is the sum of the lengths of the factors.
+#[cfg(not(feature = "32_bit_limbs"))]
+#[inline]
+fn limbs_ntt_mul_to_out(out: &mut [Limb], xs: &[Limb], ys: Option<&[Limb]>) {
+    let out_len = xs.len() + ys.map_or(xs.len(), <[Limb]>::len);
+    limbs_ntt_mul_words_to_out(&mut out[..out_len], xs, ys);
+}
+
+// Writes the product of `xs` and `ys` (or the square of `xs`, if `ys` is `None`) to
+// `out[..out_len]`, where `out_len` is the sum of the lengths of the factors.
+#[cfg(feature = "32_bit_limbs")]
+fn limbs_ntt_mul_to_out(out: &mut [Limb], xs: &[Limb], ys: Option<&[Limb]>) {
+    let out_len = xs.len() + ys.map_or(xs.len(), <[Limb]>::len);
+    let xs_words = limbs_to_ntt_words(xs);
+    let ys_words = ys.map(limbs_to_ntt_words);
+    let mut words = vec![0; (out_len + 1) >> 1];
+    limbs_ntt_mul_words_to_out(&mut words, &xs_words, ys_words.as_deref());
+    for (chunk, word) in out[..out_len].chunks_mut(2).zip(words) {
+        let (hi, lo) = word.split_in_half();
+        chunk[0] = lo;
+        if let Some(x) = chunk.get_mut(1) {
+            *x = hi;
+        } else {
+            assert_eq!(hi, 0);
+        }
+    }
+}
+
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, writes
+// the `xs.len() + ys.len()` least-significant limbs of the product of the `Natural`s to an output
+// slice, using a number-theoretic transform modulo three primes. The output must be at least as
+// long as `xs.len() + ys.len()`, `xs` must be as least as long as `ys`, and `ys` cannot be empty.
+//
+// The operands are split into 64-bit coefficients, and their convolution is computed modulo three
+// primes just below $2^{62}$ and then recovered using the Chinese Remainder Theorem; since the
+// product of the primes exceeds $2^{185}$, this is exact for any product with fewer than $2^{57}$
+// coefficients. Unlike the Schönhage-Strassen algorithm in `fft`, no scratch space is passed in;
+// about $4n$ 64-bit words are allocated internally, where $n$ is the transform length.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is too short, `xs` is shorter than `ys`, or `ys` is empty.
+pub_crate_test! {limbs_mul_greater_to_out_ntt(out: &mut [Limb], xs: &[Limb], ys: &[Limb]) {
+    let xs_len = xs.len();
+    let ys_len = ys.len();
+    assert!(xs_len >= ys_len);
+    assert_ne!(ys_len, 0);
+    assert!(out.len() >= xs_len + ys_len);
+    limbs_ntt_mul_to_out(out, xs, Some(ys));
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// `2 * xs.len()` least-significant limbs of the square of the `Natural` to an output slice, using a
+// number-theoretic transform modulo three primes. The output must be at least twice as long as
+// `xs`, and `xs` cannot be empty.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is too short or `xs` is empty.
+pub_crate_test! {limbs_square_to_out_ntt(out: &mut [Limb], xs: &[Limb]) {
+    assert_ne!(xs.len(), 0);
+    assert!(out.len() >= xs.len() << 1);
+    limbs_ntt_mul_to_out(out, xs, None);
+}}
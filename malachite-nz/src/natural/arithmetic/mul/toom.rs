@@ -21,6 +21,7 @@ use crate::natural::arithmetic::mul::{
     limbs_mul_greater_to_out, limbs_mul_greater_to_out_basecase,
     limbs_mul_greater_to_out_scratch_len, limbs_mul_same_length_to_out,
     limbs_mul_same_length_to_out_scratch_len, limbs_mul_to_out, limbs_mul_to_out_scratch_len,
+    limbs_toom_sub_products,
 };
 use crate::natural::arithmetic::shl::{limbs_shl_to_out, limbs_slice_shl_in_place};
 use crate::natural::arithmetic::shr::limbs_slice_shr_in_place;
@@ -3210,9 +3211,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
     // X(-1/2) * Y(-1/2) * 2^
     // X(1/2) * Y(1/2) * 2^
     // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r5, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(r5, v2, v3, scratch),
+    );
     if half {
         limbs_toom_couple_handling(r5, &mut out_lo[..r], v_neg_half_neg, n, 2, 1);
     } else {
@@ -3232,9 +3236,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
     // X(-1) * Y(-1)
     // X(1) * Y(1)
     // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r3, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(r3, v2, v3, scratch),
+    );
     limbs_toom_couple_handling(r3, &mut out_lo[..r], v_neg_1_neg, n, 0, 0);
     // 4, -4
     let out_lo_lo = &mut out_lo[..m];
@@ -3243,9 +3250,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
             != limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v3, v1, q, ys, n, 2, out_lo_lo);
     // X(-4) * Y(-4)
     // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r1, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(r1, v2, v3, scratch),
+    );
     // X(4) * B(4)
     limbs_toom_couple_handling(r1, &mut out_lo[..r], v_neg_4_neg, n, 2, 4);
     // 1/4, -1/4
@@ -3258,9 +3268,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
     // X(-1/4) * Y(-1/4) * 4^
     // X(1/4) * Y(1/4) * 4^
     // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r4, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(r4, v2, v3, scratch),
+    );
     if half {
         limbs_toom_couple_handling(r4, &mut out_lo[..r], v_neg_quarter_neg, n, 4, 2);
     } else {
@@ -3697,9 +3710,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     // X(-1/8) * Y(-1/8) * 8^
     // X(1/8) * Y(1/8) * 8^
     // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r7, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r7, v2, v3, scratch),
+    );
     let limit = if BIT_CORRECTION { m << 1 } else { u };
     let pp_lo_lo = &mut pp_lo[..limit];
     if half {
@@ -3719,9 +3735,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     // X(-1/4) * Y(-1/4) * 4^
     // X(1/4) * Y(1/4) * 4^
     // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r5, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r5, v2, v3, scratch),
+    );
     let pp_lo_lo = &mut pp_lo[..u];
     if half {
         limbs_toom_couple_handling(r5, pp_lo_lo, v_neg_quarter_neg, n, 4, 2);
@@ -3735,9 +3754,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     // X(-2) * Y(-2)
     // X(2) * Y(2)
     // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r3, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r3, v2, v3, scratch),
+    );
     limbs_toom_couple_handling(r3, &mut pp_lo[..u], v_neg_2_neg, n, 1, 2);
     // 8, -8
     let pp_lo_lo = &mut pp_lo[..m];
@@ -3747,9 +3769,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     // X(-8) * Y(-8)
     // X(8) * Y(8)
     // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r1, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r1, v2, v3, scratch),
+    );
     limbs_toom_couple_handling(
         &mut scratch[3 * r..],
         &mut pp_lo[..limit],
@@ -3769,9 +3794,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     // X(-1/2) * Y(-1/2) * 2^
     // X(1/2) * Y(1/2) * 2^
     // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r6, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r6, v2, v3, scratch),
+    );
     let pp_lo_lo = &mut pp_lo[..u];
     if half {
         limbs_toom_couple_handling(r6, pp_lo_lo, v_neg_half_neg, n, 2, 1);
@@ -3791,9 +3819,12 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     // X(-1) * Y(-1)
     // X(1) * Y(1)
     // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r4, v2, v3, mul_scratch);
+    limbs_toom_sub_products(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r4, v2, v3, scratch),
+    );
     limbs_toom_couple_handling(r4, &mut pp_lo[..u], v_neg_1_neg, n, 0, 0);
     // 4, -4
     let pp_lo_lo = &mut pp_lo[..m];
@@ -0,0 +1,181 @@
+use crate::natural::arithmetic::carmichael_lambda::carmichael_lambda_factors;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CarmichaelLambda, CoprimeWith, DivExact, ModPow, MultiplicativeOrder, Pow,
+};
+use malachite_base::num::factorization::traits::Factor;
+
+// The order of every unit modulo $m$ divides $\lambda(m)$, so the order of `a` is found by removing
+// prime factors from $\lambda(m)$ for as long as `a` raised to the result is still 1.
+fn multiplicative_order_from_factors(a: &Natural, factors: &[(Natural, u64)]) -> Option<Natural> {
+    let m: Natural = factors.iter().map(|(p, e)| p.pow(*e)).product();
+    assert!(*a < m);
+    if !a.coprime_with(&m) {
+        return None;
+    }
+    let mut order = Natural::carmichael_lambda_from_factors(factors);
+    for (q, f) in carmichael_lambda_factors(factors) {
+        for _ in 0..f {
+            let reduced = (&order).div_exact(&q);
+            if a.mod_pow(&reduced, &m) != 1u32 {
+                break;
+            }
+            order = reduced;
+        }
+    }
+    Some(order)
+}
+
+impl MultiplicativeOrder<Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$: the
+    /// smallest positive $k$ such that $a^k \equiv 1 \mod m$. The input must be already reduced
+    /// modulo $m$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if the [`Natural`] is not coprime to $m$, since then no such $k$ exists.
+    ///
+    /// The modulus is factored first. If its factorization is already known, use
+    /// [`MultiplicativeOrder::multiplicative_order_from_factors`] instead.
+    ///
+    /// $f(a, m) = \min \\{k \in \N^+ : a^k \equiv 1 \mod m\\}$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of factoring $m$ and $p - 1$ for each prime factor $p$ of $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).multiplicative_order(Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Natural::from(6u32).multiplicative_order(Natural::from(9u32)), None);
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .multiplicative_order(Natural::from(170141183460469231731687303715884105727u128)),
+    ///     Some(Natural::from(56713727820156410577229101238628035242u128))
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order(self, m: Natural) -> Option<Natural> {
+        (&self).multiplicative_order(&m)
+    }
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$, given
+    /// the prime factorization of $m$. The input must be already reduced modulo $m$. The
+    /// [`Natural`] is taken by value.
+    ///
+    /// Returns `None` if the [`Natural`] is not coprime to $m$, since then no such $k$ exists.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// $f(a, \\{(p_i, e_i)\\}) = \min \\{k \in \N^+ : a^k \equiv 1 \mod \prod_i p_i^{e_i}\\}$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of factoring $p_i - 1$ for each $i$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to the product of the prime powers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(10u32).multiplicative_order_from_factors(&[
+    ///         (Natural::from(3u32), 1),
+    ///         (Natural::from(7u32), 1)
+    ///     ]),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order_from_factors(self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        multiplicative_order_from_factors(&self, factors)
+    }
+}
+
+impl<'a, 'b> MultiplicativeOrder<&'b Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$: the
+    /// smallest positive $k$ such that $a^k \equiv 1 \mod m$. The input must be already reduced
+    /// modulo $m$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns `None` if the [`Natural`] is not coprime to $m$, since then no such $k$ exists.
+    ///
+    /// The modulus is factored first. If its factorization is already known, use
+    /// [`MultiplicativeOrder::multiplicative_order_from_factors`] instead.
+    ///
+    /// $f(a, m) = \min \\{k \in \N^+ : a^k \equiv 1 \mod m\\}$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of factoring $m$ and $p - 1$ for each prime factor $p$ of $m$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).multiplicative_order(&Natural::from(7u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Natural::from(6u32)).multiplicative_order(&Natural::from(9u32)), None);
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .multiplicative_order(&Natural::from(170141183460469231731687303715884105727u128)),
+    ///     Some(Natural::from(56713727820156410577229101238628035242u128))
+    /// );
+    /// ```
+    fn multiplicative_order(self, m: &'b Natural) -> Option<Natural> {
+        assert!(self < m);
+        multiplicative_order_from_factors(self, &m.factor())
+    }
+
+    /// Computes the multiplicative order of a [`Natural`] modulo another [`Natural`] $m$, given
+    /// the prime factorization of $m$. The input must be already reduced modulo $m$. The
+    /// [`Natural`] is taken by reference.
+    ///
+    /// Returns `None` if the [`Natural`] is not coprime to $m$, since then no such $k$ exists.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// $f(a, \\{(p_i, e_i)\\}) = \min \\{k \in \N^+ : a^k \equiv 1 \mod \prod_i p_i^{e_i}\\}$.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of factoring $p_i - 1$ for each $i$.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to the product of the prime powers.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::MultiplicativeOrder;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(10u32)).multiplicative_order_from_factors(&[
+    ///         (Natural::from(3u32), 1),
+    ///         (Natural::from(7u32), 1)
+    ///     ]),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// ```
+    #[inline]
+    fn multiplicative_order_from_factors(self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        multiplicative_order_from_factors(self, factors)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Parity, PartitionCount};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+impl PartitionCount for Natural {
+    /// Computes the number of partitions of a number.
+    ///
+    /// $p(n)$ is the number of ways of writing $n$ as a sum of positive integers, where the order
+    /// of the summands does not matter.
+    ///
+    /// $$
+    /// f(n) = p(n).
+    /// $$
+    ///
+    /// $p(n)$ is computed using Euler's pentagonal number theorem, which gives the recurrence
+    /// $$
+    /// p(n) = \sum_{k \neq 0} (-1)^{k+1} p(n - k(3k-1)/2),
+    /// $$
+    /// where $p(0) = 1$, $p(n) = 0$ for $n < 0$, and $k$ ranges over the nonzero integers.
+    ///
+    /// $p(n) = O(e^{\pi\sqrt{2n/3}}/n)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n^{3/2})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PartitionCount;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::partition_count(0), 1);
+    /// assert_eq!(Natural::partition_count(1), 1);
+    /// assert_eq!(Natural::partition_count(4), 5);
+    /// assert_eq!(Natural::partition_count(10), 42);
+    /// assert_eq!(Natural::partition_count(100), 190569292);
+    /// assert_eq!(
+    ///     Natural::partition_count(1000).to_string(),
+    ///     "24061467864032622473692149727991"
+    /// );
+    /// ```
+    fn partition_count(n: u64) -> Natural {
+        let n = usize::exact_from(n);
+        let mut ps = Vec::with_capacity(n + 1);
+        ps.push(Natural::ONE);
+        for m in 1..=n {
+            // The terms with positive and negative signs are summed separately, so that all
+            // intermediate values are nonnegative.
+            let mut positive = Natural::ZERO;
+            let mut negative = Natural::ZERO;
+            let mut k = 1;
+            loop {
+                // The generalized pentagonal numbers k(3k - 1)/2 and k(3k + 1)/2
+                let g = (k * (3 * k - 1)) >> 1;
+                if g > m {
+                    break;
+                }
+                let sum = if k.odd() {
+                    &mut positive
+                } else {
+                    &mut negative
+                };
+                *sum += &ps[m - g];
+                let g = g + k;
+                if g <= m {
+                    *sum += &ps[m - g];
+                }
+                k += 1;
+            }
+            ps.push(positive - negative);
+        }
+        ps.pop().unwrap()
+    }
+}
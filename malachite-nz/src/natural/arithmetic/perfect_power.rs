@@ -0,0 +1,268 @@
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::perfect_power::POWER_RESIDUE_FILTER_COUNT;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, DivExactAssign, DivisibleBy, Gcd, IsPerfectPower, ModPow, PerfectPowerDecompose,
+};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::factor::{
+    max_perfect_power_exponent, TRIAL_DIVISION_BOUND,
+};
+use malachite_base::num::factorization::traits::{IsPrime, Primes, PrimesInRange};
+use malachite_base::num::logic::traits::SignificantBits;
+
+fn natural_mod_limb(n: &Natural, m: Limb) -> Limb {
+    match *n {
+        Natural(Small(small)) => small % m,
+        Natural(Large(ref xs)) => limbs_mod_limb(xs, m),
+    }
+}
+
+// Returns `false` if `n` is certainly not a perfect `p`th power. If $q \equiv 1 \pmod p$ is a prime
+// not dividing $n$, and $n$ is a $p$th power, then $n^{(q - 1)/p} \equiv 1 \pmod q$; only about
+// $1/p$ of all residues modulo $q$ pass this test.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn passes_power_residue_filter(n: &Natural, p: u64) -> bool {
+    let step = p << 1;
+    let mut q = step + 1;
+    let mut checked = 0;
+    while checked < POWER_RESIDUE_FILTER_COUNT {
+        let q_limb = match Limb::try_from(q) {
+            Ok(q_limb) => q_limb,
+            Err(_) => break,
+        };
+        if q_limb.is_prime() {
+            let r = natural_mod_limb(n, q_limb);
+            if r != 0 && r.mod_pow((q - 1) / p, q_limb) != 1 {
+                return false;
+            }
+            checked += 1;
+        }
+        q += step;
+    }
+    true
+}
+
+// Returns the gcd of the exponents of the primes less than `TRIAL_DIVISION_BOUND` in the prime
+// factorization of `n`, or 0 if `n` has no such prime factors. If the result is nonzero, then
+// every $k$ for which `n` is a perfect $k$th power divides it. Stops early if the result is 1.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn small_prime_exponent_gcd(n: &Natural) -> u64 {
+    let mut g = n.trailing_zeros().unwrap();
+    if g == 1 {
+        return 1;
+    }
+    let mut n = n >> g;
+    for p in Limb::primes_less_than(&Limb::wrapping_from(TRIAL_DIVISION_BOUND)).skip(1) {
+        if natural_mod_limb(&n, p) != 0 {
+            continue;
+        }
+        let p = Natural::from(p);
+        let mut exponent = 0;
+        while (&n).divisible_by(&p) {
+            n.div_exact_assign(&p);
+            exponent += 1;
+        }
+        g = g.gcd(exponent);
+        if g == 1 {
+            break;
+        }
+    }
+    g
+}
+
+// Returns the $p$th root of `n` and $p$, where $p$ is the smallest prime at least `p_min` for which
+// `n` is a perfect $p$th power, or `None` if there is no such prime. `g` must be the result of
+// `small_prime_exponent_gcd(n)`; if it is nonzero, only its prime divisors are candidates. Assumes
+// that `n` does not fit in a `u128`.
+fn smallest_prime_root(n: &Natural, g: u64, p_min: u64) -> Option<(Natural, u64)> {
+    // If `n` has no prime factors less than `TRIAL_DIVISION_BOUND`, then neither does any root of
+    // `n`, which bounds the exponent.
+    let p_max = if g == 0 {
+        max_perfect_power_exponent(n.significant_bits())
+    } else {
+        g
+    };
+    if p_min > p_max {
+        return None;
+    }
+    u64::primes_in_inclusive_range(&p_min, &p_max)
+        .filter(|&p| (g == 0 || g % p == 0) && passes_power_residue_filter(n, p))
+        .find_map(|p| n.checked_root(p).map(|root| (root, p)))
+}
+
+fn is_perfect_power_helper(n: &Natural) -> bool {
+    if let Ok(small) = u128::try_from(n) {
+        return small.is_perfect_power();
+    }
+    match small_prime_exponent_gcd(n) {
+        1 => false,
+        g => smallest_prime_root(n, g, 2).is_some(),
+    }
+}
+
+fn perfect_power_decompose_helper(mut n: Natural) -> (Natural, u64) {
+    if let Ok(small) = u128::try_from(&n) {
+        let (root, exp) = small.perfect_power_decompose();
+        return (Natural::from(root), exp);
+    }
+    let mut exp = 1;
+    let mut g = small_prime_exponent_gcd(&n);
+    if g == 1 {
+        return (n, exp);
+    }
+    // If $n = b^p$ and $p$ is the smallest prime for which $n$ is a $p$th power, then $b$ is not a
+    // $q$th power for any prime $q < p$, so the search for the next prime can resume at $p$.
+    let mut p_min = 2;
+    while let Some((root, p)) = smallest_prime_root(&n, g, p_min) {
+        exp *= p;
+        g /= p;
+        if let Ok(small) = u128::try_from(&root) {
+            let (root, small_exp) = small.perfect_power_decompose();
+            return (Natural::from(root), exp * small_exp);
+        }
+        n = root;
+        p_min = p;
+    }
+    (n, exp)
+}
+
+impl IsPerfectPower for Natural {
+    /// Determines whether a [`Natural`] is a perfect power: whether it is equal to $b^k$ for some
+    /// [`Natural`] $b$ and some integer $k \geq 2$.
+    ///
+    /// 0 and 1 are perfect powers.
+    ///
+    /// $f(n) = (\exists b \in \N, k \in \N : k \geq 2 \wedge b^k = n)$.
+    ///
+    /// The exponents of the primes less than $2^{10}$ are found by trial division; if they have a
+    /// gcd $g$, only prime divisors of $g$ need to be considered as exponents, and otherwise only
+    /// primes up to about a tenth of the bit length. Each candidate exponent $p$ is then checked
+    /// modulo a few small primes $q \equiv 1 \pmod p$ before any $p$th root is computed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsPerfectPower, Pow};
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.is_perfect_power(), true);
+    /// assert_eq!(Natural::ONE.is_perfect_power(), true);
+    /// assert_eq!(Natural::from(12u32).is_perfect_power(), false);
+    /// assert_eq!(Natural::from(1000u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(10u32).pow(100).is_perfect_power(), true);
+    /// assert_eq!((Natural::from(10u32).pow(100) + Natural::ONE).is_perfect_power(), false);
+    /// ```
+    #[inline]
+    fn is_perfect_power(&self) -> bool {
+        is_perfect_power_helper(self)
+    }
+}
+
+impl PerfectPowerDecompose for Natural {
+    type Output = Natural;
+
+    /// Writes a [`Natural`] as a perfect power $b^k$ with the largest possible $k$, returning $b$
+    /// and $k$. The [`Natural`] is taken by value.
+    ///
+    /// If the [`Natural`] is not a perfect power, the result is the [`Natural`] itself and 1. The
+    /// result for 0 and 1 is also the [`Natural`] itself and 1, since they are $k$th powers for
+    /// every $k$.
+    ///
+    /// $f(n) = (b, k)$, where $b^k = n$ and $k$ is as large as possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecompose, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(12u32).perfect_power_decompose().to_debug_string(), "(12, 1)");
+    /// assert_eq!(Natural::from(1000u32).perfect_power_decompose().to_debug_string(), "(10, 3)");
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(100).perfect_power_decompose().to_debug_string(),
+    ///     "(10, 100)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(u64::MAX).pow(6).perfect_power_decompose().to_debug_string(),
+    ///     "(18446744073709551615, 6)"
+    /// );
+    /// ```
+    #[inline]
+    fn perfect_power_decompose(self) -> (Natural, u64) {
+        perfect_power_decompose_helper(self)
+    }
+}
+
+impl<'a> PerfectPowerDecompose for &'a Natural {
+    type Output = Natural;
+
+    /// Writes a [`Natural`] as a perfect power $b^k$ with the largest possible $k$, returning $b$
+    /// and $k$. The [`Natural`] is taken by reference.
+    ///
+    /// If the [`Natural`] is not a perfect power, the result is the [`Natural`] itself and 1. The
+    /// result for 0 and 1 is also the [`Natural`] itself and 1, since they are $k$th powers for
+    /// every $k$.
+    ///
+    /// $f(n) = (b, k)$, where $b^k = n$ and $k$ is as large as possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{PerfectPowerDecompose, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!((&Natural::from(12u32)).perfect_power_decompose().to_debug_string(), "(12, 1)");
+    /// assert_eq!(
+    ///     (&Natural::from(1000u32)).perfect_power_decompose().to_debug_string(),
+    ///     "(10, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(10u32).pow(100)).perfect_power_decompose().to_debug_string(),
+    ///     "(10, 100)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(u64::MAX).pow(6)).perfect_power_decompose().to_debug_string(),
+    ///     "(18446744073709551615, 6)"
+    /// );
+    /// ```
+    #[inline]
+    fn perfect_power_decompose(self) -> (Natural, u64) {
+        perfect_power_decompose_helper(self.clone())
+    }
+}
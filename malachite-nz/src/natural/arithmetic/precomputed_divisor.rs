@@ -0,0 +1,390 @@
+use crate::natural::arithmetic::div_exact::limbs_div_exact_limb_in_place;
+use crate::natural::arithmetic::div_mod::{
+    limbs_div_limb_to_out_mod, limbs_div_mod_barrett_preinverse_scratch_len,
+    limbs_div_mod_barrett_preinverted, limbs_div_mod_to_out, limbs_invert_approx,
+    limbs_invert_approx_scratch_len,
+};
+use crate::natural::arithmetic::mod_op::{
+    limbs_mod_barrett_preinverted, limbs_mod_limb, limbs_mod_to_out,
+};
+use crate::natural::arithmetic::shl::limbs_shl_to_out;
+use crate::natural::arithmetic::shr::limbs_slice_shr_in_place;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::logic::traits::LeadingZeros;
+use malachite_base::slices::slice_move_left;
+use std::cmp::max;
+
+/// A precomputed divisor, for dividing many [`Natural`]s by the same [`Natural`].
+///
+/// Barrett division by a long [`Natural`] requires normalizing the divisor and computing an
+/// approximate inverse of it, which costs about as much as a multiplication. A
+/// [`PrecomputedDivisor`] does this work once, so that each subsequent division only needs to
+/// normalize the dividend. Since the inverse no longer needs to be paid for on each division,
+/// Barrett division is used for much shorter divisors than it is for one-off divisions. Shorter
+/// divisors are handled by the usual schoolbook and divide-and-conquer algorithms, whose setup
+/// costs are negligible. The divisor may be even or odd.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PrecomputedDivisor {
+    divisor: Natural,
+    // If Barrett division is used, the limbs of the divisor, shifted left so that the most
+    // significant bit is set. Otherwise, empty.
+    ds: Vec<Limb>,
+    // If Barrett division is used, the number of bits that the divisor was shifted left by.
+    // Otherwise, 0.
+    shift: u64,
+    // If Barrett division is used, an approximate inverse with as many limbs as the divisor.
+    // Otherwise, empty.
+    is: Vec<Limb>,
+}
+
+//TODO tune
+const PRECOMPUTED_DIV_MOD_BARRETT_THRESHOLD: usize = 256;
+
+impl PrecomputedDivisor {
+    /// Precomputes the data needed to divide by a [`Natural`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `d.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `d` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// assert_eq!(*d.divisor(), 10);
+    /// ```
+    pub fn new(d: &Natural) -> PrecomputedDivisor {
+        assert_ne!(*d, 0u32, "division by zero");
+        let (ds, shift, is) = match d {
+            Natural(Large(ref ds)) if ds.len() >= PRECOMPUTED_DIV_MOD_BARRETT_THRESHOLD => {
+                let d_len = ds.len();
+                let shift = LeadingZeros::leading_zeros(ds[d_len - 1]);
+                let mut ds = ds.clone();
+                if shift != 0 {
+                    let old_ds = ds.clone();
+                    limbs_shl_to_out(&mut ds, &old_ds, shift);
+                }
+                // Compute an approximate inverse on d_len + 1 limbs, then drop the lowest limb, as
+                // is done by `limbs_div_mod_barrett_helper`.
+                let d_len_plus_1 = d_len + 1;
+                let mut is = vec![0; d_len_plus_1];
+                let mut scratch =
+                    vec![0; d_len_plus_1 + limbs_invert_approx_scratch_len(d_len_plus_1)];
+                let (scratch_lo, scratch_hi) = scratch.split_at_mut(d_len_plus_1);
+                let (scratch_first, scratch_lo_tail) = scratch_lo.split_first_mut().unwrap();
+                scratch_lo_tail.copy_from_slice(&ds);
+                *scratch_first = 1;
+                limbs_invert_approx(&mut is, scratch_lo, scratch_hi);
+                slice_move_left(&mut is, 1);
+                is.truncate(d_len);
+                (ds, shift, is)
+            }
+            _ => (Vec::new(), 0, Vec::new()),
+        };
+        PrecomputedDivisor {
+            divisor: d.clone(),
+            ds,
+            shift,
+            is,
+        }
+    }
+
+    /// Returns the divisor that the data was precomputed from.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// assert_eq!(*d.divisor(), 10);
+    /// ```
+    pub const fn divisor(&self) -> &Natural {
+        &self.divisor
+    }
+
+    // Shifts the limbs of a numerator by the same amount as the divisor was shifted. The shifted
+    // numerator always gets an extra limb, which may be zero.
+    fn limbs_shift_numerator(&self, ns: &[Limb]) -> Vec<Limb> {
+        let mut ns_shifted = vec![0; ns.len() + 1];
+        let (ns_shifted_last, ns_shifted_init) = ns_shifted.split_last_mut().unwrap();
+        if self.shift == 0 {
+            ns_shifted_init.copy_from_slice(ns);
+        } else {
+            *ns_shifted_last = limbs_shl_to_out(ns_shifted_init, ns, self.shift);
+        }
+        ns_shifted
+    }
+
+    // Given the limbs of a numerator and of a divisor with more than one limb, where the numerator
+    // is at least as long as the divisor, returns the limbs of the quotient and remainder.
+    fn limbs_div_mod(&self, ns: &[Limb], ds: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+        let d_len = ds.len();
+        let n_len = ns.len();
+        if self.is.is_empty() {
+            let mut qs = vec![0; n_len - d_len + 1];
+            let mut rs = vec![0; d_len];
+            limbs_div_mod_to_out(&mut qs, &mut rs, ns, ds);
+            return (qs, rs);
+        }
+        let ns_shifted = self.limbs_shift_numerator(ns);
+        let q_len = n_len + 1 - d_len;
+        let mut qs = vec![0; q_len + 1];
+        let mut rs = vec![0; d_len];
+        let mut scratch = vec![
+            0;
+            max(
+                limbs_div_mod_barrett_preinverse_scratch_len(d_len, d_len),
+                (d_len << 1) + 1
+            )
+        ];
+        qs[q_len] = Limb::from(limbs_div_mod_barrett_preinverted(
+            &mut qs,
+            &mut rs,
+            &ns_shifted,
+            &self.ds,
+            &self.is,
+            &mut scratch,
+        ));
+        if self.shift != 0 {
+            limbs_slice_shr_in_place(&mut rs, self.shift);
+        }
+        (qs, rs)
+    }
+
+    // Given the limbs of a numerator and of a divisor with more than one limb, where the numerator
+    // is at least as long as the divisor, returns the limbs of the remainder.
+    fn limbs_mod(&self, ns: &[Limb], ds: &[Limb]) -> Vec<Limb> {
+        let d_len = ds.len();
+        let mut rs = vec![0; d_len];
+        if self.is.is_empty() {
+            limbs_mod_to_out(&mut rs, ns, ds);
+            return rs;
+        }
+        let ns_shifted = self.limbs_shift_numerator(ns);
+        let mut qs = vec![0; ns_shifted.len() - d_len];
+        let mut scratch = vec![
+            0;
+            max(
+                limbs_div_mod_barrett_preinverse_scratch_len(d_len, d_len),
+                (d_len << 1) + 1
+            )
+        ];
+        limbs_mod_barrett_preinverted(
+            &mut qs,
+            &mut rs,
+            &ns_shifted,
+            &self.ds,
+            &self.is,
+            &mut scratch,
+        );
+        if self.shift != 0 {
+            limbs_slice_shr_in_place(&mut rs, self.shift);
+        }
+        rs
+    }
+
+    /// Divides a [`Natural`] by the precomputed divisor, returning the quotient and remainder. The
+    /// quotient is rounded towards negative infinity.
+    ///
+    /// The quotient and remainder satisfy $x = qd + r$ and $0 \leq r < d$, where $d$ is the
+    /// divisor.
+    ///
+    /// $$
+    /// f(x, d) = \left ( \left \lfloor \frac{x}{d} \right \rfloor, \space
+    /// x - d\left \lfloor \frac{x}{d} \right \rfloor \right ).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // 2 * 10 + 3 = 23
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// assert_eq!(d.div_mod(&Natural::from(23u32)).to_debug_string(), "(2, 3)");
+    ///
+    /// // 810000006723 * 1234567890987 + 530068894399 = 1000000000000000000000000
+    /// let d = PrecomputedDivisor::new(&Natural::from_str("1234567890987").unwrap());
+    /// assert_eq!(
+    ///     d.div_mod(&Natural::from_str("1000000000000000000000000").unwrap())
+    ///         .to_debug_string(),
+    ///     "(810000006723, 530068894399)"
+    /// );
+    /// ```
+    pub fn div_mod(&self, x: &Natural) -> (Natural, Natural) {
+        match (x, &self.divisor) {
+            (x, d) if x < d => (Natural::ZERO, x.clone()),
+            (Natural(Small(x)), Natural(Small(d))) => (Natural::from(x / d), Natural::from(x % d)),
+            (Natural(Large(ref xs)), Natural(Small(d))) => {
+                let mut qs = vec![0; xs.len()];
+                let r = limbs_div_limb_to_out_mod(&mut qs, xs, *d);
+                (Natural::from_owned_limbs_asc(qs), Natural::from(r))
+            }
+            (Natural(Large(ref xs)), Natural(Large(ref ds))) => {
+                let (qs, rs) = self.limbs_div_mod(xs, ds);
+                (
+                    Natural::from_owned_limbs_asc(qs),
+                    Natural::from_owned_limbs_asc(rs),
+                )
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Divides a [`Natural`] by the precomputed divisor, returning just the remainder.
+    ///
+    /// If the quotient were computed, the quotient and remainder would satisfy $x = qd + r$ and
+    /// $0 \leq r < d$, where $d$ is the divisor.
+    ///
+    /// $$
+    /// f(x, d) = x - d\left \lfloor \frac{x}{d} \right \rfloor.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // 2 * 10 + 3 = 23
+    /// let d = PrecomputedDivisor::new(&Natural::from(10u32));
+    /// assert_eq!(d.mod_op(&Natural::from(23u32)), 3);
+    ///
+    /// // 810000006723 * 1234567890987 + 530068894399 = 1000000000000000000000000
+    /// let d = PrecomputedDivisor::new(&Natural::from_str("1234567890987").unwrap());
+    /// assert_eq!(
+    ///     d.mod_op(&Natural::from_str("1000000000000000000000000").unwrap()),
+    ///     530068894399u64
+    /// );
+    /// ```
+    pub fn mod_op(&self, x: &Natural) -> Natural {
+        match (x, &self.divisor) {
+            (x, d) if x < d => x.clone(),
+            (Natural(Small(x)), Natural(Small(d))) => Natural::from(x % d),
+            (Natural(Large(ref xs)), Natural(Small(d))) => Natural::from(limbs_mod_limb(xs, *d)),
+            (Natural(Large(ref xs)), Natural(Large(ref ds))) => {
+                Natural::from_owned_limbs_asc(self.limbs_mod(xs, ds))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Divides a [`Natural`] by the precomputed divisor. The [`Natural`] must be exactly divisible
+    /// by the divisor. If it isn't, the behavior of this function is undefined.
+    ///
+    /// If you are unsure whether the division will be exact, use
+    /// [`div_mod`](Self::div_mod) instead.
+    ///
+    /// $$
+    /// f(x, d) = \frac{x}{d}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // 123 * 456 = 56088
+    /// let d = PrecomputedDivisor::new(&Natural::from(456u32));
+    /// assert_eq!(d.div_exact(&Natural::from(56088u32)), 123);
+    ///
+    /// // 123456789000 * 987654321000 = 121932631112635269000000
+    /// let d = PrecomputedDivisor::new(&Natural::from_str("987654321000").unwrap());
+    /// assert_eq!(
+    ///     d.div_exact(&Natural::from_str("121932631112635269000000").unwrap()),
+    ///     123456789000u64
+    /// );
+    /// ```
+    pub fn div_exact(&self, x: &Natural) -> Natural {
+        match (x, &self.divisor) {
+            (x, d) if x < d => Natural::ZERO,
+            (Natural(Small(x)), Natural(Small(d))) => Natural::from(x / d),
+            (Natural(Large(ref xs)), Natural(Small(d))) => {
+                let mut qs = xs.clone();
+                limbs_div_exact_limb_in_place(&mut qs, *d);
+                Natural::from_owned_limbs_asc(qs)
+            }
+            (Natural(Large(ref xs)), Natural(Large(ref ds))) => {
+                Natural::from_owned_limbs_asc(self.limbs_div_mod(xs, ds).0)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns whether a [`Natural`] is divisible by the precomputed divisor; in other words,
+    /// whether the [`Natural`] is a multiple of the divisor.
+    ///
+    /// $f(x, d) = (\exists k \in \N : x = kd)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from(3u32));
+    /// assert_eq!(d.divisible_by(&Natural::ZERO), true);
+    /// assert_eq!(d.divisible_by(&Natural::from(100u32)), false);
+    /// assert_eq!(d.divisible_by(&Natural::from(102u32)), true);
+    ///
+    /// let d = PrecomputedDivisor::new(&Natural::from_str("1000000000000").unwrap());
+    /// assert_eq!(
+    ///     d.divisible_by(&Natural::from_str("1000000000000000000000000").unwrap()),
+    ///     true
+    /// );
+    /// assert_eq!(
+    ///     d.divisible_by(&Natural::from_str("1000000000001000000000001").unwrap()),
+    ///     false
+    /// );
+    /// ```
+    pub fn divisible_by(&self, x: &Natural) -> bool {
+        *x == 0u32 || self.mod_op(x) == 0u32
+    }
+}
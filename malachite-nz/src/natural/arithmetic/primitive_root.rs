@@ -0,0 +1,119 @@
+use crate::natural::arithmetic::carmichael_lambda::carmichael_lambda_factors;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CarmichaelLambda, CoprimeWith, DivExact, ModPow, Pow, PrimitiveRoot,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::Factor;
+
+// A primitive root modulo $n$ exists if and only if $n$ is 1, 2, 4, $p^k$, or $2p^k$, where $p$ is
+// an odd prime.
+fn has_primitive_root(factors: &[(Natural, u64)]) -> bool {
+    let mut odd_factor_count = 0;
+    let mut two_exponent = 0;
+    for (p, e) in factors {
+        if *p == Natural::TWO {
+            two_exponent = *e;
+        } else {
+            odd_factor_count += 1;
+        }
+    }
+    match odd_factor_count {
+        0 => two_exponent <= 2,
+        1 => two_exponent <= 1,
+        _ => false,
+    }
+}
+
+impl PrimitiveRoot for Natural {
+    /// Finds the smallest primitive root modulo a [`Natural`] $n$: the smallest generator of the
+    /// multiplicative group of integers modulo $n$.
+    ///
+    /// Returns `None` if there is no primitive root, which is the case unless $n$ is 1, 2, 4,
+    /// $p^k$, or $2p^k$ for an odd prime $p$. Modulo 1, the smallest primitive root is 0.
+    ///
+    /// The [`Natural`] is factored first. If its factorization is already known, use
+    /// [`primitive_root_from_factors`](PrimitiveRoot::primitive_root_from_factors) instead.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of factoring $n$ and $p - 1$ for each prime factor $p$ of $n$.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PrimitiveRoot;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ONE.primitive_root(), Some(Natural::from(0u32)));
+    /// assert_eq!(Natural::from(7u32).primitive_root(), Some(Natural::from(3u32)));
+    /// assert_eq!(Natural::from(15u32).primitive_root(), None);
+    /// assert_eq!(
+    ///     Natural::from(170141183460469231731687303715884105727u128).primitive_root(),
+    ///     Some(Natural::from(43u32))
+    /// );
+    /// ```
+    #[inline]
+    fn primitive_root(&self) -> Option<Natural> {
+        Natural::primitive_root_from_factors(&self.factor())
+    }
+
+    /// Finds the smallest primitive root modulo a [`Natural`] $n$, given the prime factorization
+    /// of $n$.
+    ///
+    /// Returns `None` if there is no primitive root, which is the case unless $n$ is 1, 2, 4,
+    /// $p^k$, or $2p^k$ for an odd prime $p$. Modulo 1, the smallest primitive root is 0.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of factoring $p - 1$ for the odd prime factor $p$ of $n$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PrimitiveRoot;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::primitive_root_from_factors(&[]), Some(Natural::from(0u32)));
+    /// assert_eq!(
+    ///     Natural::primitive_root_from_factors(&[
+    ///         (Natural::from(2u32), 1),
+    ///         (Natural::from(3u32), 2)
+    ///     ]),
+    ///     Some(Natural::from(5u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::primitive_root_from_factors(&[
+    ///         (Natural::from(3u32), 1),
+    ///         (Natural::from(5u32), 1)
+    ///     ]),
+    ///     None
+    /// );
+    /// ```
+    fn primitive_root_from_factors(factors: &[(Natural, u64)]) -> Option<Natural> {
+        // Candidates are tried in increasing order. A unit $g$ is a primitive root if and only if
+        // $g^{\lambda(n)/q} \not\equiv 1 \mod n$ for each prime factor $q$ of $\lambda(n)$.
+        if !has_primitive_root(factors) {
+            return None;
+        }
+        let n: Natural = factors.iter().map(|(p, e)| p.pow(*e)).product();
+        if n == 1u32 {
+            return Some(Natural::ZERO);
+        }
+        let lambda = Natural::carmichael_lambda_from_factors(factors);
+        let exps = carmichael_lambda_factors(factors)
+            .into_iter()
+            .map(|(q, _)| (&lambda).div_exact(q))
+            .collect::<Vec<_>>();
+        let mut g = Natural::ONE;
+        loop {
+            if (&g).coprime_with(&n) && exps.iter().all(|exp| (&g).mod_pow(exp, &n) != 1u32) {
+                return Some(g);
+            }
+            g += Natural::ONE;
+        }
+    }
+}
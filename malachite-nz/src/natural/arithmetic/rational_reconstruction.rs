@@ -0,0 +1,171 @@
+use crate::integer::Integer;
+use crate::natural::arithmetic::gcd::half_gcd::{
+    limbs_half_gcd, limbs_half_gcd_matrix_init_scratch_len, limbs_half_gcd_scratch_len,
+    HalfGcdMatrix,
+};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{DivMod, Gcd, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use std::mem::swap;
+
+//TODO tune
+pub(crate) const RATIONAL_RECONSTRUCTION_DC_THRESHOLD: usize = 40;
+
+// Given remainders $r_0$ and $r_1$ of the Euclidean algorithm, applies the half-GCD
+// algorithm to their high limbs and returns the entries of the resulting matrix $M$, in the order
+// $m_{00}$, $m_{01}$, $m_{10}$, $m_{11}$. $M$ has determinant 1, and the reduced remainders are
+// $M^{-1}(r_0, r_1)^T$. The number of ignored low limbs is chosen so that the reduced remainders
+// should have more than `bound_len` limbs; the caller must still check this.
+//
+// Returns `None` if $r_0 \leq r_1$ (the reduced remainders may come in either order, and a division
+// step then swaps them), if the remainders are too small for the half-GCD algorithm to be
+// worthwhile, or if no reduction is possible.
+//
+// # Worst-case complexity
+// $T(n) = O(n (\log n)^2 \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `r_0.significant_bits()`.
+fn half_gcd_matrix(r_0: &Natural, r_1: &Natural, bound_len: usize) -> Option<[Natural; 4]> {
+    if r_0 <= r_1 {
+        return None;
+    }
+    let mut xs = r_0.to_limbs_asc();
+    let n = xs.len();
+    // The half-GCD of the top n - p limbs leaves remainders with more than p + (n - p) / 2 + 1
+    // limbs, which is more than `bound_len` whenever p >= 2 * bound_len - n.
+    let p = (bound_len << 1).saturating_sub(n);
+    if p >= n || n - p < RATIONAL_RECONSTRUCTION_DC_THRESHOLD {
+        return None;
+    }
+    let mut ys = r_1.to_limbs_asc();
+    ys.resize(n, 0);
+    let len = n - p;
+    let matrix_scratch_len = limbs_half_gcd_matrix_init_scratch_len(len);
+    let mut scratch = vec![0; matrix_scratch_len + limbs_half_gcd_scratch_len(len)];
+    let (scratch_lo, scratch_hi) = scratch.split_at_mut(matrix_scratch_len);
+    let mut m = HalfGcdMatrix::init(len, scratch_lo);
+    if limbs_half_gcd(&mut xs[p..], &mut ys[p..], &mut m, scratch_hi) == 0 {
+        return None;
+    }
+    let m_n = m.n;
+    let (m00, m01, m10, m11) = m.get_four();
+    Some([
+        Natural::from_limbs_asc(&m00[..m_n]),
+        Natural::from_limbs_asc(&m01[..m_n]),
+        Natural::from_limbs_asc(&m10[..m_n]),
+        Natural::from_limbs_asc(&m11[..m_n]),
+    ])
+}
+
+impl Natural {
+    /// Given a residue $a$ modulo $m$, finds the fraction $p/q$ with $p \equiv qa \pmod m$,
+    /// $|p| \leq N$, and $0 < q \leq D$, where $N$ and $D$ are the specified numerator and
+    /// denominator bounds. The fraction is returned as a numerator and denominator in lowest terms,
+    /// or `None` if no such fraction exists.
+    ///
+    /// If $2ND < m$, as is the case when $N = D = \lfloor \sqrt{(m - 1)/2} \rfloor$, the fraction
+    /// is unique. The denominator $q$ is always coprime to $m$.
+    ///
+    /// This is the final step of multi-modular algorithms whose results are rational: once the
+    /// result is known modulo a sufficiently large $m$, for example by combining residues with
+    /// [`Crt`](malachite_base::num::arithmetic::traits::Crt), the fraction can be recovered. It is
+    /// computed using the extended Euclidean algorithm on $m$ and $a$, which stops once the
+    /// remainder is at most $N$; for large inputs, the half-GCD algorithm is used to skip most of
+    /// the intermediate remainders.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`, or if $2ND \geq m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // 3 * 23 = 69 = -2 (mod 71), so -2/3 = 23 (mod 71)
+    /// assert_eq!(
+    ///     Natural::from(23u32)
+    ///         .rational_reconstruction(
+    ///             &Natural::from(71u32),
+    ///             &Natural::from(5u32),
+    ///             &Natural::from(5u32)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some((-2, 3))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32)
+    ///         .rational_reconstruction(
+    ///             &Natural::from(71u32),
+    ///             &Natural::from(5u32),
+    ///             &Natural::from(5u32)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32)
+    ///         .rational_reconstruction(
+    ///             &Natural::from(71u32),
+    ///             &Natural::from(10u32),
+    ///             &Natural::from(3u32)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "Some((10, 1))"
+    /// );
+    /// ```
+    pub fn rational_reconstruction(
+        &self,
+        m: &Natural,
+        numerator_bound: &Natural,
+        denominator_bound: &Natural,
+    ) -> Option<(Integer, Natural)> {
+        assert!(self < m, "self must be less than m");
+        assert!(
+            (numerator_bound * denominator_bound) << 1u32 < *m,
+            "2 * numerator_bound * denominator_bound must be less than m"
+        );
+        // Invariants: r_0 = t_0 * a (mod m) and r_1 = t_1 * a (mod m).
+        let mut r_0 = m.clone();
+        let mut r_1 = self.clone();
+        let mut t_0 = Integer::ZERO;
+        let mut t_1 = Integer::ONE;
+        let bound_len = usize::exact_from(numerator_bound.limb_count());
+        while r_1 > *numerator_bound {
+            if let Some([m00, m01, m10, m11]) = half_gcd_matrix(&r_0, &r_1, bound_len) {
+                // (r_0, r_1) = M (r_0', r_1'), so (r_0', r_1') = M^-1 (r_0, r_1), and the same
+                // holds for the cofactors.
+                let new_r_0 = &m11 * &r_0 - &m01 * &r_1;
+                let new_r_1 = &m00 * &r_1 - &m10 * &r_0;
+                if new_r_0 > *numerator_bound && new_r_1 > *numerator_bound {
+                    let new_t_0 = Integer::from(m11) * &t_0 - Integer::from(m01) * &t_1;
+                    t_1 = Integer::from(m00) * t_1 - Integer::from(m10) * t_0;
+                    t_0 = new_t_0;
+                    r_0 = new_r_0;
+                    r_1 = new_r_1;
+                    continue;
+                }
+            }
+            let (q, r) = (&r_0).div_mod(&r_1);
+            r_0 = r;
+            swap(&mut r_0, &mut r_1);
+            t_0 -= Integer::from(q) * &t_1;
+            swap(&mut t_0, &mut t_1);
+        }
+        let denominator = (&t_1).unsigned_abs();
+        if denominator > *denominator_bound || (&r_1).gcd(&denominator) != 1u32 {
+            return None;
+        }
+        let numerator = Integer::from(r_1);
+        Some((if t_1 > 0u32 { numerator } else { -numerator }, denominator))
+    }
+}
@@ -8,7 +8,7 @@ use crate::natural::arithmetic::mul::fft::{
     limbs_square_to_out_fft, limbs_square_to_out_fft_scratch_len,
 };
 use crate::natural::arithmetic::mul::limb::limbs_mul_limb_to_out;
-use crate::natural::arithmetic::mul::limbs_mul_greater_to_out_basecase;
+use crate::natural::arithmetic::mul::ntt::limbs_square_to_out_ntt;
 use crate::natural::arithmetic::mul::poly_eval::{
     limbs_mul_toom_evaluate_deg_3_poly_in_1_and_neg_1,
     limbs_mul_toom_evaluate_deg_3_poly_in_2_and_neg_2, limbs_mul_toom_evaluate_poly_in_1_and_neg_1,
@@ -23,6 +23,7 @@ use crate::natural::arithmetic::mul::poly_interpolate::{
 use crate::natural::arithmetic::mul::toom::{
     limbs_toom_couple_handling, BIT_CORRECTION, TUNE_PROGRAM_BUILD, WANT_FAT_BINARY,
 };
+use crate::natural::arithmetic::mul::{limbs_mul_greater_to_out_basecase, limbs_toom_sub_products};
 use crate::natural::arithmetic::shl::{limbs_shl_to_out, limbs_slice_shl_in_place};
 use crate::natural::arithmetic::sub::{
     limbs_sub_limb_in_place, limbs_sub_same_length_in_place_left, limbs_sub_same_length_to_out,
@@ -31,8 +32,8 @@ use crate::natural::comparison::cmp::limbs_cmp_same_length;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
-    DoubleLimb, Limb, SQR_BASECASE_THRESHOLD, SQR_TOOM2_THRESHOLD, SQR_TOOM3_THRESHOLD,
-    SQR_TOOM4_THRESHOLD, SQR_TOOM6_THRESHOLD, SQR_TOOM8_THRESHOLD,
+    DoubleLimb, Limb, SQR_BASECASE_THRESHOLD, SQR_NTT_THRESHOLD, SQR_TOOM2_THRESHOLD,
+    SQR_TOOM3_THRESHOLD, SQR_TOOM4_THRESHOLD, SQR_TOOM6_THRESHOLD, SQR_TOOM8_THRESHOLD,
 };
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
@@ -683,18 +684,36 @@ pub_test! {limbs_square_to_out_toom_6(out: &mut [Limb], xs: &[Limb], scratch: &m
         &mut out_lo[..m],
     );
     split_into_chunks_mut!(scratch, 3 * n + 1, [r5, r3, r1], wse);
-    limbs_square_to_out_toom_6_recursive(out_lo, v0, wse); // X(-1/2) ^ 2 * 2 ^
-    limbs_square_to_out_toom_6_recursive(r5, v2, wse); // X(1/2) ^ 2 * 2 ^
+    limbs_toom_sub_products(
+        m,
+        wse,
+        // X(-1/2) ^ 2 * 2 ^
+        |scratch| limbs_square_to_out_toom_6_recursive(out_lo, v0, scratch),
+        // X(1/2) ^ 2 * 2 ^
+        |scratch| limbs_square_to_out_toom_6_recursive(r5, v2, scratch),
+    );
     limbs_toom_couple_handling(r5, &mut out_lo[..k], false, n, 1, 0);
     // +/- 1
     limbs_mul_toom_evaluate_poly_in_1_and_neg_1(v2, v0, 5, xs, n, &mut out_lo[..m]);
-    limbs_square_to_out_toom_6_recursive(out_lo, v0, wse); // X(-1) ^ 2
-    limbs_square_to_out_toom_6_recursive(r3, v2, wse); // X(1) ^ 2
+    limbs_toom_sub_products(
+        m,
+        wse,
+        // X(-1) ^ 2
+        |scratch| limbs_square_to_out_toom_6_recursive(out_lo, v0, scratch),
+        // X(1) ^ 2
+        |scratch| limbs_square_to_out_toom_6_recursive(r3, v2, scratch),
+    );
     limbs_toom_couple_handling(r3, &mut out_lo[..k], false, n, 0, 0);
     // +/- 4
     limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v2, v0, 5, xs, n, 2, &mut out_lo[..m]);
-    limbs_square_to_out_toom_6_recursive(out_lo, v0, wse); // X(-4) ^ 2
-    limbs_square_to_out_toom_6_recursive(r1, v2, wse); // X(4) ^ 2
+    limbs_toom_sub_products(
+        m,
+        wse,
+        // X(-4) ^ 2
+        |scratch| limbs_square_to_out_toom_6_recursive(out_lo, v0, scratch),
+        // X(4) ^ 2
+        |scratch| limbs_square_to_out_toom_6_recursive(r1, v2, scratch),
+    );
     limbs_toom_couple_handling(r1, &mut out_lo[..k], false, n, 2, 4);
     // +/- 1/4
     limbs_mul_toom_evaluate_poly_in_2_pow_neg_and_neg_2_pow_neg(
@@ -706,8 +725,14 @@ pub_test! {limbs_square_to_out_toom_6(out: &mut [Limb], xs: &[Limb], scratch: &m
         2,
         &mut out_lo[..m],
     );
-    limbs_square_to_out_toom_6_recursive(out_lo, v0, wse); // X(-1/4) ^ 2 * 4 ^
-    limbs_square_to_out_toom_6_recursive(r4, v2, wse); // X(1/4) ^ 2 * 4 ^
+    limbs_toom_sub_products(
+        m,
+        wse,
+        // X(-1/4) ^ 2 * 4 ^
+        |scratch| limbs_square_to_out_toom_6_recursive(out_lo, v0, scratch),
+        // X(1/4) ^ 2 * 4 ^
+        |scratch| limbs_square_to_out_toom_6_recursive(r4, v2, scratch),
+    );
     limbs_toom_couple_handling(r4, &mut out_lo[..k], false, n, 2, 0);
     // +/- 2
     limbs_mul_toom_evaluate_poly_in_2_and_neg_2(v2, v0, 5, xs, n, &mut out_lo[..m]);
@@ -872,8 +897,12 @@ pub_test! {limbs_square_to_out_toom_8(out: &mut [Limb], xs: &[Limb], scratch: &m
     let (r3, r1_wse) = remainder.split_at_mut(p);
     let (r1, wse) = r1_wse.split_at_mut(p);
     // A(-1/8) * B(-1/8) * 8 ^, A(1/8) * B(1/8) * 8 ^
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r7_r5, v2, wse);
+    limbs_toom_sub_products(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r7_r5, v2, scratch),
+    );
     let limit = if BIT_CORRECTION { m << 1 } else { k };
     limbs_toom_couple_handling(r7_r5, &mut pp_lo[..limit], false, n, 3, 0);
     // +/- 1/4
@@ -887,21 +916,33 @@ pub_test! {limbs_square_to_out_toom_8(out: &mut [Limb], xs: &[Limb], scratch: &m
         &mut pp_lo[..m],
     );
     // A(-1/4) * B(-1/4) * 4 ^, A(1/4) * B(1/4) * 4^
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
     let (r7, r5) = r7_r5.split_at_mut(p);
-    limbs_square_to_out_toom_8_recursive(r5, v2, wse);
+    limbs_toom_sub_products(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r5, v2, scratch),
+    );
     limbs_toom_couple_handling(r5, &mut pp_lo[..k], false, n, 2, 0);
     // +/- 2
     limbs_mul_toom_evaluate_poly_in_2_and_neg_2(v2, v0, 7, xs, n, &mut pp_lo[..m]);
     // A(-2)*B(-2), A(+2)*B(+2)
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r3, v2, wse);
+    limbs_toom_sub_products(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r3, v2, scratch),
+    );
     limbs_toom_couple_handling(r3, &mut pp_lo[..k], false, n, 1, 2);
     // +/- 8
     limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v2, v0, 7, xs, n, 3, &mut pp_lo[..m]);
     // A(-8) * B(-8), A(8) * B(8)
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r1, v2, wse);
+    limbs_toom_sub_products(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r1, v2, scratch),
+    );
     limbs_toom_couple_handling(r1_wse, &mut pp_lo[..limit], false, n, 3, 6);
     // +/- 1/2
     limbs_mul_toom_evaluate_poly_in_2_pow_neg_and_neg_2_pow_neg(
@@ -915,14 +956,22 @@ pub_test! {limbs_square_to_out_toom_8(out: &mut [Limb], xs: &[Limb], scratch: &m
     );
     // A(-1/2) * B(-1/2) * 2 ^, A(1/2) * B(1/2) * 2 ^
     let (r1, wse) = r1_wse.split_at_mut(p);
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r6, v2, wse);
+    limbs_toom_sub_products(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r6, v2, scratch),
+    );
     limbs_toom_couple_handling(r6, &mut pp_lo[..k], false, n, 1, 0);
     // +/- 1
     limbs_mul_toom_evaluate_poly_in_1_and_neg_1(v2, v0, 7, xs, n, &mut pp_lo[..m]);
     // A(-1) * B(-1), A(1) * B(1)
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r4, v2, wse);
+    limbs_toom_sub_products(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r4, v2, scratch),
+    );
     limbs_toom_couple_handling(r4, &mut pp_lo[..k], false, n, 0, 0);
     // +/- 4
     limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v2, v0, 7, xs, n, 2, &mut pp_lo[..m]);
@@ -949,10 +998,13 @@ limbs_square_to_out_scratch_len(n: usize) -> usize {
         limbs_square_to_out_toom_4_scratch_len(n)
     } else if n < SQR_TOOM8_THRESHOLD {
         limbs_square_to_out_toom_6_scratch_len(n)
-    } else if n < SQR_FFT_THRESHOLD {
+    } else if n < SQR_FFT_THRESHOLD && n < SQR_NTT_THRESHOLD {
         limbs_square_to_out_toom_8_scratch_len(n)
-    } else {
+    } else if n < SQR_NTT_THRESHOLD {
         limbs_square_to_out_fft_scratch_len(n)
+    } else {
+        // The NTT allocates its own memory.
+        0
     }
 }}
 
@@ -983,10 +1035,12 @@ limbs_square_to_out(out: &mut [Limb], xs: &[Limb], scratch: &mut [Limb]) {
         limbs_square_to_out_toom_4(out, xs, scratch);
     } else if n < SQR_TOOM8_THRESHOLD {
         limbs_square_to_out_toom_6(out, xs, scratch);
-    } else if n < SQR_FFT_THRESHOLD {
+    } else if n < SQR_FFT_THRESHOLD && n < SQR_NTT_THRESHOLD {
         limbs_square_to_out_toom_8(out, xs, scratch);
-    } else {
+    } else if n < SQR_NTT_THRESHOLD {
         limbs_square_to_out_fft(out, xs, scratch);
+    } else {
+        limbs_square_to_out_ntt(out, xs);
     }
 }}
 
@@ -0,0 +1,267 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    Bell, BinomialCoefficient, DivExact, DivExactAssign, Factorial, Parity, Pow, StirlingFirst,
+    StirlingSecond,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Computes the Stirling number with parameters $n$ and $k$, given the multiplier $m(j, t)$ of the
+// recurrence
+// $$
+// T(j, t) = m(j, t)T(j, t - 1) + T(j - 1, t),
+// $$
+// where $T(j, t)$ is the Stirling number with parameters $j + t$ and $j$, $T(j, 0) = 1$, and
+// $T(0, t) = 0$ for $t > 0$. The unsigned Stirling numbers of the first kind have $m(j, t) = j + t
+// - 1$, and the Stirling numbers of the second kind have $m(j, t) = j$.
+//
+// Only the band of the triangle between $(0, 0)$ and $(k, n - k)$ is computed, and only one row of
+// the band is stored; the rows are taken along whichever direction is shorter.
+//
+// Assumes $k \leq n$.
+//
+// # Worst-case complexity
+// $T(n, k) = O(k(n-k)n \log n)$
+//
+// $M(n, k) = O(\min(k, n-k)n \log n)$
+fn stirling_band(n: u64, k: u64, multiplier: impl Fn(u64, u64) -> u64) -> Natural {
+    let d = n - k;
+    if d <= k {
+        let mut row = vec![Natural::ZERO; usize::exact_from(d + 1)];
+        row[0] = Natural::ONE;
+        for j in 1..=k {
+            for t in 1..=d {
+                let i = usize::exact_from(t);
+                let (lo, hi) = row.split_at_mut(i);
+                hi[0] += &lo[i - 1] * Natural::from(multiplier(j, t));
+            }
+        }
+        row.pop().unwrap()
+    } else {
+        let mut row = vec![Natural::ONE; usize::exact_from(k + 1)];
+        for t in 1..=d {
+            row[0] = Natural::ZERO;
+            for j in 1..=k {
+                let i = usize::exact_from(j);
+                let (lo, hi) = row.split_at_mut(i);
+                hi[0] *= Natural::from(multiplier(j, t));
+                hi[0] += &lo[i - 1];
+            }
+        }
+        row.pop().unwrap()
+    }
+}
+
+// Computes the Stirling number of the second kind using the explicit formula
+// $$
+// \left\\{{n \atop k}\right\\} = \frac{1}{k!}\sum_{j=0}^k (-1)^{k-j}\binom{k}{j}j^n.
+// $$
+//
+// This is faster than the recurrence when $k$ is small compared to $n$.
+//
+// # Worst-case complexity
+// $T(n, k) = O(kn \log k \log (n \log k) \log\log (n \log k))$
+//
+// $M(n, k) = O(n \log k)$
+fn stirling_second_explicit(n: u64, k: u64) -> Natural {
+    let mut positive = Natural::ZERO;
+    let mut negative = Natural::ZERO;
+    let mut binomial = Natural::ONE;
+    for j in (1..=k).rev() {
+        let term = &binomial * Natural::from(j).pow(n);
+        if (k - j).even() {
+            positive += term;
+        } else {
+            negative += term;
+        }
+        // binomial(k, j - 1) = binomial(k, j) * j / (k - j + 1)
+        binomial *= Natural::from(j);
+        binomial.div_exact_assign(Natural::from(k - j + 1));
+    }
+    (positive - negative).div_exact(Natural::factorial(k))
+}
+
+impl StirlingFirst for Natural {
+    /// Computes an unsigned Stirling number of the first kind.
+    ///
+    /// The unsigned Stirling number of the first kind $\left[{n \atop k}\right]$ counts the
+    /// permutations of $n$ elements that have exactly $k$ cycles. Equivalently, it is the
+    /// coefficient of $x^k$ in $x(x+1)(x+2)\cdots(x+n-1)$.
+    ///
+    /// $$
+    /// f(n, k) = \left[{n \atop k}\right],
+    /// $$
+    /// where
+    /// $$
+    /// \left[{n+1 \atop k}\right] = n\left[{n \atop k}\right] + \left[{n \atop k-1}\right],
+    /// $$
+    /// $\left[{0 \atop 0}\right] = 1$, and $\left[{n \atop 0}\right] = \left[{0 \atop k}\right] =
+    /// 0$ for $n, k > 0$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(k(n-k)n \log n)$
+    ///
+    /// $M(n, k) = O(\min(k, n-k)n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::StirlingFirst;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling_first(0, 0), 1);
+    /// assert_eq!(Natural::stirling_first(3, 0), 0);
+    /// assert_eq!(Natural::stirling_first(4, 2), 11);
+    /// assert_eq!(Natural::stirling_first(5, 3), 35);
+    /// assert_eq!(Natural::stirling_first(3, 5), 0);
+    /// assert_eq!(
+    ///     Natural::stirling_first(50, 10).to_string(),
+    ///     "101623020926367490059043797119309944043405505380503665627365376"
+    /// );
+    /// ```
+    fn stirling_first(n: u64, k: u64) -> Natural {
+        if k > n {
+            Natural::ZERO
+        } else if k == n {
+            Natural::ONE
+        } else if k == 0 {
+            Natural::ZERO
+        } else if k == 1 {
+            Natural::factorial(n - 1)
+        } else if k == n - 1 {
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32))
+        } else {
+            stirling_band(n, k, |j, t| j + t - 1)
+        }
+    }
+}
+
+impl StirlingSecond for Natural {
+    /// Computes a Stirling number of the second kind.
+    ///
+    /// The Stirling number of the second kind $\left\\{{n \atop k}\right\\}$ counts the partitions
+    /// of a set of $n$ elements into exactly $k$ nonempty subsets.
+    ///
+    /// $$
+    /// f(n, k) = \left\\{{n \atop k}\right\\},
+    /// $$
+    /// where
+    /// $$
+    /// \left\\{{n+1 \atop k}\right\\} = k\left\\{{n \atop k}\right\\} + \left\\{{n \atop
+    /// k-1}\right\\},
+    /// $$
+    /// $\left\\{{0 \atop 0}\right\\} = 1$, and $\left\\{{n \atop 0}\right\\} = \left\\{{0 \atop
+    /// k}\right\\} = 0$ for $n, k > 0$.
+    ///
+    /// If $k \leq n - k$, the explicit formula
+    /// $\left\\{{n \atop k}\right\\} = (1/k!)\sum_{j=0}^k (-1)^{k-j}\binom{k}{j}j^n$ is used;
+    /// otherwise, the recurrence is used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(k(n-k)n \log n)$
+    ///
+    /// $M(n, k) = O(\min(k, n-k)n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $k$ is `k`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::StirlingSecond;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling_second(0, 0), 1);
+    /// assert_eq!(Natural::stirling_second(3, 0), 0);
+    /// assert_eq!(Natural::stirling_second(4, 2), 7);
+    /// assert_eq!(Natural::stirling_second(5, 3), 25);
+    /// assert_eq!(Natural::stirling_second(3, 5), 0);
+    /// assert_eq!(
+    ///     Natural::stirling_second(50, 10).to_string(),
+    ///     "26154716515862881292012777396577993781727011"
+    /// );
+    /// ```
+    fn stirling_second(n: u64, k: u64) -> Natural {
+        if k > n {
+            Natural::ZERO
+        } else if k == n {
+            Natural::ONE
+        } else if k == 0 {
+            Natural::ZERO
+        } else if k == 1 {
+            Natural::ONE
+        } else if k == n - 1 {
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32))
+        } else if k <= n - k {
+            stirling_second_explicit(n, k)
+        } else {
+            stirling_band(n, k, |j, _| j)
+        }
+    }
+}
+
+impl Bell for Natural {
+    /// Computes a Bell number.
+    ///
+    /// The Bell number $B_n$ counts the partitions of a set of $n$ elements.
+    ///
+    /// $$
+    /// f(n) = B_n = \sum_{k=0}^n \left\\{{n \atop k}\right\\}.
+    /// $$
+    ///
+    /// $B_n$ is computed using the formula
+    /// $$
+    /// B_n = \frac{1}{n!}\sum_{k=0}^n \binom{n}{k}k^n \cdot {!(n-k)},
+    /// $$
+    /// where ${!m}$ is the subfactorial of $m$. This follows from Dobinski's formula.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log^2 n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Bell;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::bell(0), 1);
+    /// assert_eq!(Natural::bell(1), 1);
+    /// assert_eq!(Natural::bell(2), 2);
+    /// assert_eq!(Natural::bell(3), 5);
+    /// assert_eq!(Natural::bell(10), 115975);
+    /// assert_eq!(
+    ///     Natural::bell(50).to_string(),
+    ///     "185724268771078270438257767181908917499221852770"
+    /// );
+    /// ```
+    fn bell(n: u64) -> Natural {
+        if n == 0 {
+            return Natural::ONE;
+        }
+        // The k = 0 term vanishes, so k runs from n down to 1, and m = n - k runs up from 0.
+        let mut sum = Natural::ZERO;
+        let mut binomial = Natural::ONE;
+        let mut subfactorial = Natural::ONE;
+        for k in (1..=n).rev() {
+            let m = n - k;
+            if m != 0 {
+                // !m = m * !(m - 1) + (-1)^m
+                subfactorial *= Natural::from(m);
+                if m.even() {
+                    subfactorial += Natural::ONE;
+                } else {
+                    subfactorial -= Natural::ONE;
+                }
+                // binomial(n, k) = binomial(n, k + 1) * (k + 1) / (n - k)
+                binomial *= Natural::from(k + 1);
+                binomial.div_exact_assign(Natural::from(m));
+            }
+            if subfactorial != 0u32 {
+                sum += &binomial * &subfactorial * Natural::from(k).pow(n);
+            }
+        }
+        sum.div_exact(Natural::factorial(n))
+    }
+}
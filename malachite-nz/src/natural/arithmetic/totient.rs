@@ -0,0 +1,76 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Pow, Totient};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::Factor;
+
+impl Totient for Natural {
+    /// Computes Euler's totient function $\varphi$ of a [`Natural`]: the number of positive
+    /// integers less than or equal to it that are coprime to it.
+    ///
+    /// The [`Natural`] is factored first. If its factorization is already known, use
+    /// [`totient_from_factors`](Totient::totient_from_factors) instead.
+    ///
+    /// $$
+    /// f(n) = \varphi(n) = n\prod_{p | n} \left ( 1 - \frac{1}{p} \right ).
+    /// $$
+    ///
+    /// # Expected complexity
+    /// Dominated by the complexity of [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Pow, Totient};
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ONE.totient(), 1);
+    /// assert_eq!(Natural::from(12u32).totient(), 4);
+    /// assert_eq!(Natural::from(97u32).totient(), 96);
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(20).totient(),
+    ///     40000000000000000000u128
+    /// );
+    /// ```
+    #[inline]
+    fn totient(&self) -> Natural {
+        Natural::totient_from_factors(&self.factor())
+    }
+
+    /// Computes Euler's totient function $\varphi$ of a [`Natural`], given its prime
+    /// factorization.
+    ///
+    /// `factors` must contain distinct primes and their positive exponents, as returned by
+    /// [`factor`](Factor::factor); this is not checked. The primes need not be sorted.
+    ///
+    /// $$
+    /// f(\\{(p_i, e_i)\\}) = \prod_i p_i^{e_i - 1}(p_i - 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of the number
+    /// whose factorization is `factors`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Totient;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::totient_from_factors(&[]), 1);
+    /// assert_eq!(
+    ///     Natural::totient_from_factors(&[(Natural::from(2u32), 3), (Natural::from(3u32), 2)]),
+    ///     24
+    /// );
+    /// ```
+    fn totient_from_factors(factors: &[(Natural, u64)]) -> Natural {
+        factors.iter().fold(Natural::ONE, |phi, (p, e)| {
+            phi * p.pow(e - 1) * (p - Natural::ONE)
+        })
+    }
+}
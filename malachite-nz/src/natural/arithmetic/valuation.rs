@@ -0,0 +1,247 @@
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    DivMod, IsPowerOf2, RemoveFactor, Square, Valuation,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Removes all factors of `p` from `n`, returning the cofactor and the number of factors removed.
+// The cofactor is `None` if it is equal to `n`, so that `n` need not be cloned.
+//
+// After dividing by $p$ once, $n$ is divided by $p^2, p^4, p^8, \ldots$ for as long as possible.
+// If $p^{2^k}$ was the last power to be divided out, the remaining valuation is less than
+// $2^{k + 1}$, and the powers $p^{2^k}, p^{2^{k - 1}}, \ldots, p$ are then tried in descending
+// order, which determines the remaining valuation bit by bit. A valuation of $v$ therefore takes
+// $O(\log v)$ divisions, rather than the $v$ divisions of the naive algorithm.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+pub(crate) fn remove_factor_natural(n: &Natural, p: &Natural) -> (Option<Natural>, u64) {
+    assert_ne!(*n, 0u32, "Cannot remove factors from zero");
+    assert!(*p > 1u32, "p must be at least 2");
+    if p.is_power_of_2() {
+        let log_p = p.significant_bits() - 1;
+        let v = n.trailing_zeros().unwrap() / log_p;
+        return (if v == 0 { None } else { Some(n >> (v * log_p)) }, v);
+    }
+    if let (Natural(Small(small_n)), Natural(Small(small_p))) = (n, p) {
+        let (cofactor, v) = small_n.remove_factor(*small_p);
+        return (
+            if v == 0 {
+                None
+            } else {
+                Some(Natural::from(cofactor))
+            },
+            v,
+        );
+    }
+    if p > n {
+        return (None, 0);
+    }
+    let (q, r) = n.div_mod(p);
+    if r != 0u32 {
+        return (None, 0);
+    }
+    let mut n = q;
+    let mut v = 1;
+    // powers[i] = p^(2^i)
+    let mut powers = vec![p.clone()];
+    loop {
+        let square = powers.last().unwrap().square();
+        if square > n {
+            break;
+        }
+        let (q, r) = (&n).div_mod(&square);
+        if r != 0u32 {
+            break;
+        }
+        n = q;
+        v += 1 << powers.len();
+        powers.push(square);
+    }
+    for (i, power) in powers.iter().enumerate().rev() {
+        if *power > n {
+            continue;
+        }
+        let (q, r) = (&n).div_mod(power);
+        if r == 0u32 {
+            n = q;
+            v += 1 << i;
+        }
+    }
+    (Some(n), v)
+}
+
+impl<'a, 'b> Valuation<&'b Natural> for &'a Natural {
+    type Output = u64;
+
+    /// Finds the $p$-adic valuation of a [`Natural`], taking both by reference: the largest $k$
+    /// such that $p^k$ divides it.
+    ///
+    /// $f(n, p) = \max\\{k \in \N : p^k \mid n\\}$.
+    ///
+    /// If $p$ is a power of 2, the valuation is computed from the number of trailing zeros. If
+    /// the valuation is large, it is found by dividing by repeated squares of $p$, so that only
+    /// $O(\log k)$ divisions are needed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Pow, Valuation};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(12u32).valuation(&Natural::from(2u32)), 2);
+    /// assert_eq!(Natural::from(12u32).valuation(&Natural::from(5u32)), 0);
+    /// assert_eq!(
+    ///     (Natural::from(3u32).pow(1000) * Natural::from(10u32)).valuation(&Natural::from(9u32)),
+    ///     500
+    /// );
+    /// assert_eq!(Natural::from(10u32).pow(100).valuation(&Natural::from(1000u32)), 33);
+    /// ```
+    #[inline]
+    fn valuation(self, p: &'b Natural) -> u64 {
+        remove_factor_natural(self, p).1
+    }
+}
+
+impl<'a> Valuation<&'a Natural> for Natural {
+    type Output = u64;
+
+    /// Finds the $p$-adic valuation of a [`Natural`], taking the [`Natural`] by value and $p$ by
+    /// reference: the largest $k$ such that $p^k$ divides it.
+    ///
+    /// $f(n, p) = \max\\{k \in \N : p^k \mid n\\}$.
+    ///
+    /// If $p$ is a power of 2, the valuation is computed from the number of trailing zeros. If
+    /// the valuation is large, it is found by dividing by repeated squares of $p$, so that only
+    /// $O(\log k)$ divisions are needed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Pow, Valuation};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(12u32).valuation(&Natural::from(2u32)), 2);
+    /// assert_eq!(Natural::from(12u32).valuation(&Natural::from(5u32)), 0);
+    /// assert_eq!(Natural::from(10u32).pow(100).valuation(&Natural::from(1000u32)), 33);
+    /// ```
+    #[inline]
+    fn valuation(self, p: &'a Natural) -> u64 {
+        remove_factor_natural(&self, p).1
+    }
+}
+
+impl<'a> RemoveFactor<&'a Natural> for Natural {
+    type CofactorOutput = Natural;
+    type ValuationOutput = u64;
+
+    /// Removes all factors of $p$ from a [`Natural`], taking the [`Natural`] by value and $p$ by
+    /// reference. Returns the cofactor and the $p$-adic valuation of the [`Natural`].
+    ///
+    /// $f(n, p) = (n/p^k, k)$, where $k = \max\\{k \in \N : p^k \mid n\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Pow, RemoveFactor};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(12u32).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(3, 2)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(12u32).remove_factor(&Natural::from(5u32)).to_debug_string(),
+    ///     "(12, 0)"
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(3u32).pow(1000) * Natural::from(10u32))
+    ///         .remove_factor(&Natural::from(9u32))
+    ///         .to_debug_string(),
+    ///     "(10, 500)"
+    /// );
+    /// ```
+    fn remove_factor(self, p: &'a Natural) -> (Natural, u64) {
+        let (cofactor, v) = remove_factor_natural(&self, p);
+        (cofactor.unwrap_or(self), v)
+    }
+}
+
+impl<'a, 'b> RemoveFactor<&'b Natural> for &'a Natural {
+    type CofactorOutput = Natural;
+    type ValuationOutput = u64;
+
+    /// Removes all factors of $p$ from a [`Natural`], taking both by reference. Returns the
+    /// cofactor and the $p$-adic valuation of the [`Natural`].
+    ///
+    /// $f(n, p) = (n/p^k, k)$, where $k = \max\\{k \in \N : p^k \mid n\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Pow, RemoveFactor};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(12u32)).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(3, 2)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(12u32)).remove_factor(&Natural::from(5u32)).to_debug_string(),
+    ///     "(12, 0)"
+    /// );
+    /// assert_eq!(
+    ///     (&(Natural::from(3u32).pow(1000) * Natural::from(10u32)))
+    ///         .remove_factor(&Natural::from(9u32))
+    ///         .to_debug_string(),
+    ///     "(10, 500)"
+    /// );
+    /// ```
+    fn remove_factor(self, p: &'b Natural) -> (Natural, u64) {
+        let (cofactor, v) = remove_factor_natural(self, p);
+        (cofactor.unwrap_or_else(|| self.clone()), v)
+    }
+}
@@ -0,0 +1,412 @@
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, DivExact, DivExactAssign, DivisibleBy, Gcd, ModAdd, ModInverse, ModMulPrecomputed,
+    ModSub,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::factor::{
+    max_perfect_power_exponent, merge_factors, ECM_PARAMETERS, ECM_STAGE_2_D, RHO_BATCH_SIZE,
+    RHO_ITERATIONS_BEFORE_ECM, TRIAL_DIVISION_BOUND,
+};
+use malachite_base::num::factorization::traits::{Factor, IsPrime, Primes};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use std::cmp::min;
+
+// Appends the factorization of `n`, with every exponent multiplied by `multiplicity`, to `factors`.
+fn push_primitive_factors<T: PrimitiveUnsigned>(
+    factors: &mut Vec<(Natural, u64)>,
+    n: T,
+    multiplicity: u64,
+) where
+    Natural: From<T>,
+{
+    factors.extend(
+        n.factor()
+            .into_iter()
+            .map(|(p, e)| (Natural::from(p), e * multiplicity)),
+    );
+}
+
+// Removes every prime factor less than `TRIAL_DIVISION_BOUND` from `n`, pushing the factors and
+// their exponents onto `factors`. If `n` becomes small enough to fit in a `u64` in the process, the
+// rest of its factorization is also pushed onto `factors`, and `n` is set to 1.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+fn factor_trial_division(n: &mut Natural, factors: &mut Vec<(Natural, u64)>) {
+    let zeros = n.trailing_zeros().unwrap();
+    if zeros != 0 {
+        factors.push((Natural::TWO, zeros));
+        *n >>= zeros;
+    }
+    for p in u64::primes_less_than(&TRIAL_DIVISION_BOUND).skip(1) {
+        if let Ok(small) = u64::try_from(&*n) {
+            push_primitive_factors(factors, small, 1);
+            *n = Natural::ONE;
+            return;
+        }
+        let p = Natural::from(p);
+        let mut exponent = 0;
+        while (&*n).divisible_by(&p) {
+            n.div_exact_assign(&p);
+            exponent += 1;
+        }
+        if exponent != 0 {
+            factors.push((p, exponent));
+        }
+    }
+    if let Ok(small) = u64::try_from(&*n) {
+        push_primitive_factors(factors, small, 1);
+        *n = Natural::ONE;
+    }
+}
+
+// Computes `|x - y|`.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is
+// `max(x.significant_bits(), y.significant_bits())`.
+fn abs_diff(x: &Natural, y: &Natural) -> Natural {
+    if x >= y {
+        x - y
+    } else {
+        y - x
+    }
+}
+
+// Attempts to find a nontrivial factor of `n` using Brent's variant of Pollard's rho algorithm,
+// iterating $x \mapsto x^2 + c$ starting from 2. Gives up and returns `None` if the algorithm fails
+// with this $c$ or no factor has been found after roughly `max_iterations` iterations. Assumes that
+// `n` is odd and composite, and that `0 < c < n - 2`.
+//
+// # Expected complexity
+// $T(n, p) = O(n^2 p^{1/2} \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `n.significant_bits()`, and $p$ is the
+// smallest prime factor of `n`.
+pub_crate_test! {natural_pollard_brent_rho(
+    n: &Natural,
+    c: &Natural,
+    max_iterations: u64
+) -> Option<Natural> {
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(n);
+    let f = |x: &Natural| x.mod_mul_precomputed(x, n, &data).mod_add(c, n);
+    let mut y = Natural::TWO;
+    let mut x = y.clone();
+    let mut ys = y.clone();
+    let mut q = Natural::ONE;
+    let mut g = Natural::ONE;
+    let mut r = 1;
+    let mut iterations = 0;
+    while g == 1u32 {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g == 1u32 {
+            ys = y.clone();
+            for _ in 0..min(RHO_BATCH_SIZE, r - k) {
+                y = f(&y);
+                q = q.mod_mul_precomputed(abs_diff(&x, &y), n, &data);
+            }
+            g = (&q).gcd(n);
+            k += RHO_BATCH_SIZE;
+        }
+        iterations += r;
+        if g == 1u32 && iterations >= max_iterations {
+            return None;
+        }
+        r <<= 1;
+    }
+    if g == *n {
+        // The batched product became divisible by `n`; step through the last batch one iteration
+        // at a time.
+        loop {
+            ys = f(&ys);
+            g = abs_diff(&x, &ys).gcd(n);
+            if g != 1u32 {
+                break;
+            }
+        }
+    }
+    if g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}}
+
+// A point on a Montgomery curve $By^2 = x^3 + Ax^2 + x$ modulo `n`, in projective $(X : Z)$
+// coordinates with the $y$-coordinate dropped.
+type EcmPoint = (Natural, Natural);
+
+// Arithmetic on the Montgomery curve with $(A + 2) / 4 = $`a_24`, modulo `n`.
+struct EcmCurve<'a> {
+    n: &'a Natural,
+    data: ModMulData,
+    a_24: Natural,
+}
+
+impl<'a> EcmCurve<'a> {
+    #[inline]
+    fn mul(&self, x: &Natural, y: &Natural) -> Natural {
+        x.mod_mul_precomputed(y, self.n, &self.data)
+    }
+
+    // Computes $2P$.
+    fn double(&self, (x, z): &EcmPoint) -> EcmPoint {
+        let n = self.n;
+        let s = x.mod_add(z, n);
+        let s = self.mul(&s, &s);
+        let d = x.mod_sub(z, n);
+        let d = self.mul(&d, &d);
+        let t = (&s).mod_sub(&d, n);
+        let z_2 = self.mul(&t, &(&d).mod_add(self.mul(&self.a_24, &t), n));
+        (self.mul(&s, &d), z_2)
+    }
+
+    // Computes $P + Q$, given $P - Q$.
+    fn add(&self, (xp, zp): &EcmPoint, (xq, zq): &EcmPoint, (xd, zd): &EcmPoint) -> EcmPoint {
+        let n = self.n;
+        let u = self.mul(&xp.mod_sub(zp, n), &xq.mod_add(zq, n));
+        let v = self.mul(&xp.mod_add(zp, n), &xq.mod_sub(zq, n));
+        let s = (&u).mod_add(&v, n);
+        let d = u.mod_sub(v, n);
+        (
+            self.mul(zd, &self.mul(&s, &s)),
+            self.mul(xd, &self.mul(&d, &d)),
+        )
+    }
+
+    // Computes $kP$ using the Montgomery ladder. Assumes that $k > 0$.
+    fn scale(&self, p: &EcmPoint, k: u64) -> EcmPoint {
+        let mut r0 = p.clone();
+        let mut r1 = self.double(p);
+        for i in (0..k.significant_bits() - 1).rev() {
+            if k.get_bit(i) {
+                r0 = self.add(&r1, &r0, p);
+                r1 = self.double(&r1);
+            } else {
+                r1 = self.add(&r1, &r0, p);
+                r0 = self.double(&r0);
+            }
+        }
+        r0
+    }
+}
+
+// Attempts to find a nontrivial factor of `n` using one curve of Lenstra's elliptic curve method,
+// with stage 1 bound `b_1` and stage 2 bound `b_2`. The curve is chosen using Suyama's
+// parametrization with parameter `sigma`. Assumes that `n` is odd, composite, has no prime factors
+// less than `TRIAL_DIVISION_BOUND`, and is greater than `sigma`; that `sigma > 5`; and that
+// `b_1 >= 2 * ECM_STAGE_2_D`.
+//
+// # Worst-case complexity
+// $T(n, B_2) = O(n B_2 \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `n.significant_bits()`, and $B_2$ is `b_2`.
+pub_crate_test! {natural_ecm_one_curve(
+    n: &Natural,
+    sigma: u64,
+    b_1: u64,
+    b_2: u64
+) -> Option<Natural> {
+    let mut curve = EcmCurve {
+        n,
+        data: ModMulPrecomputed::<Natural>::precompute_mod_mul_data(n),
+        a_24: Natural::ZERO,
+    };
+    // u = sigma^2 - 5, v = 4 * sigma, x_0 = u^3, z_0 = v^3
+    let sigma = Natural::from(sigma);
+    let u = curve.mul(&sigma, &sigma).mod_sub(Natural::from(5u32), n);
+    let v = sigma << 2;
+    let u_cubed = curve.mul(&curve.mul(&u, &u), &u);
+    let v_cubed = curve.mul(&curve.mul(&v, &v), &v);
+    // (A + 2) / 4 = (v - u)^3 * (3u + v) / (16 * u^3 * v)
+    let v_minus_u = (&v).mod_sub(&u, n);
+    let numerator = curve.mul(
+        &curve.mul(&curve.mul(&v_minus_u, &v_minus_u), &v_minus_u),
+        &curve.mul(&u, &Natural::from(3u32)).mod_add(&v, n),
+    );
+    let denominator = curve.mul(&curve.mul(&u_cubed, &v), &Natural::from(16u32));
+    let g = (&denominator).gcd(n);
+    if g != 1u32 {
+        return if g == *n { None } else { Some(g) };
+    }
+    curve.a_24 = curve.mul(&numerator, &denominator.mod_inverse(n).unwrap());
+    // Stage 1: multiply by every prime power no greater than b_1
+    let mut q = (u_cubed, v_cubed);
+    for p in u64::primes_less_than_or_equal_to(&b_1) {
+        let mut power = p;
+        while let Some(next_power) = power.checked_mul(p) {
+            if next_power > b_1 {
+                break;
+            }
+            power = next_power;
+        }
+        q = curve.scale(&q, power);
+    }
+    let g = (&q.1).gcd(n);
+    if g == *n {
+        return None;
+    } else if g != 1u32 {
+        return Some(g);
+    }
+    // Stage 2: every prime in (b_1, b_2] is m * D +/- j for some j coprime to D, 0 < j < D / 2. If
+    // the order of q modulo a prime factor of n is such a prime, then m * D * q = +/- j * q modulo
+    // that factor, so the cross product of their coordinates is divisible by it.
+    let half_d = ECM_STAGE_2_D >> 1;
+    let q_2 = curve.double(&q);
+    let mut baby_steps = Vec::new();
+    let mut previous = q.clone();
+    let mut current = q.clone();
+    for j in (1..half_d).step_by(2) {
+        if j > 1 {
+            // (j + 2) * q = j * q + 2 * q, and j * q - 2 * q = (j - 2) * q
+            let next = curve.add(&current, &q_2, &previous);
+            previous = current;
+            current = next;
+        }
+        if j.gcd(ECM_STAGE_2_D) == 1 {
+            baby_steps.push(current.clone());
+        }
+    }
+    let giant_step = curve.scale(&q, ECM_STAGE_2_D);
+    let mut m = b_1 / ECM_STAGE_2_D;
+    let mut previous = curve.scale(&q, (m - 1) * ECM_STAGE_2_D);
+    let mut current = curve.scale(&q, m * ECM_STAGE_2_D);
+    let mut product = Natural::ONE;
+    while m * ECM_STAGE_2_D <= b_2 + half_d {
+        for (x_j, z_j) in &baby_steps {
+            let cross = curve
+                .mul(&current.0, z_j)
+                .mod_sub(curve.mul(x_j, &current.1), n);
+            product = curve.mul(&product, &cross);
+        }
+        let next = curve.add(&current, &giant_step, &previous);
+        previous = current;
+        current = next;
+        m += 1;
+    }
+    let g = product.gcd(n);
+    if g == 1u32 || g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}}
+
+// Finds a nontrivial factor of `n`, first trying Pollard-Brent rho for a limited number of
+// iterations, and then ECM, trying curves with increasing bounds until one succeeds. Assumes that
+// `n` is odd, composite, not a perfect power, and has no prime factors less than
+// `TRIAL_DIVISION_BOUND`.
+//
+// # Expected complexity
+// $T(n, p) = O(n^2 e^{\sqrt{2 \log p \log\log p}} \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `n.significant_bits()`, and $p$ is the
+// smallest prime factor of `n`.
+fn find_factor(n: &Natural) -> Natural {
+    if let Some(g) = natural_pollard_brent_rho(n, &Natural::ONE, RHO_ITERATIONS_BEFORE_ECM) {
+        return g;
+    }
+    let mut sigma = 6;
+    for (i, &(b_1, curves)) in ECM_PARAMETERS.iter().enumerate() {
+        let last = i == ECM_PARAMETERS.len() - 1;
+        let mut curve = 0;
+        while last || curve < curves {
+            if let Some(g) = natural_ecm_one_curve(n, sigma, b_1, 100 * b_1) {
+                return g;
+            }
+            sigma += 1;
+            curve += 1;
+        }
+    }
+    unreachable!()
+}
+
+impl Factor for Natural {
+    type FACTORS = Vec<(Natural, u64)>;
+
+    /// Returns the prime factorization of a [`Natural`], as a [`Vec`] of primes and their
+    /// exponents, sorted in increasing order of the primes.
+    ///
+    /// If the [`Natural`] fits in a `u64`, the `u64` factorization is used. Otherwise, primes less
+    /// than $2^{10}$ are removed by trial division, medium-sized factors are found using Brent's
+    /// variant of Pollard's rho algorithm, and large factors are found using Lenstra's elliptic
+    /// curve method. Cofactors that fit in a `u128` are factored using the `u128` factorization.
+    /// Primality of the factors is determined using [`IsPrime`]; the result is exact unless a
+    /// composite number passes the Baillie-PSW test, and no such number is known.
+    ///
+    /// The running time depends mostly on the size of the second-largest prime factor, so
+    /// factoring a product of two large primes of similar size may take a very long time.
+    ///
+    /// The factorization of 1 is empty.
+    ///
+    /// # Expected complexity
+    /// $T(n, p) = O(n^2 e^{\sqrt{2 \log p \log\log p}} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and $p$ is
+    /// the second-largest prime factor of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(Natural, u64)> {
+        assert_ne!(*self, 0u32, "Cannot factor 0");
+        let mut factors = Vec::new();
+        if let Ok(n) = u64::try_from(self) {
+            push_primitive_factors(&mut factors, n, 1);
+            return factors;
+        }
+        let mut n = self.clone();
+        factor_trial_division(&mut n, &mut factors);
+        let mut composites = Vec::new();
+        if n != 1u32 {
+            composites.push((n, 1));
+        }
+        'outer: while let Some((m, multiplicity)) = composites.pop() {
+            if let Ok(small) = u128::try_from(&m) {
+                push_primitive_factors(&mut factors, small, multiplicity);
+                continue;
+            }
+            if m.is_prime() {
+                factors.push((m, multiplicity));
+                continue;
+            }
+            for k in
+                u64::primes_less_than_or_equal_to(&max_perfect_power_exponent(m.significant_bits()))
+            {
+                if let Some(root) = (&m).checked_root(k) {
+                    composites.push((root, multiplicity * k));
+                    continue 'outer;
+                }
+            }
+            let g = find_factor(&m);
+            composites.push(((&m).div_exact(&g), multiplicity));
+            composites.push((g, multiplicity));
+        }
+        merge_factors(factors)
+    }
+}
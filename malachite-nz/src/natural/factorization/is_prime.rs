@@ -0,0 +1,203 @@
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, DivisibleBy, JacobiSymbol, ModAdd, ModMulAssign, ModPow, ModSquareAssign,
+    ModSubAssign, Parity,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// The product of the odd primes less than 53.
+#[cfg(not(feature = "32_bit_limbs"))]
+const ODD_PRIMORIAL: Limb = 307444891294245705;
+// The product of the odd primes less than 29.
+#[cfg(feature = "32_bit_limbs")]
+const ODD_PRIMORIAL: Limb = 111546435;
+
+#[cfg(not(feature = "32_bit_limbs"))]
+const SMALL_ODD_PRIMES: [Limb; 14] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+#[cfg(feature = "32_bit_limbs")]
+const SMALL_ODD_PRIMES: [Limb; 8] = [3, 5, 7, 11, 13, 17, 19, 23];
+
+// Interpreting a slice of `Limb`s as the limbs of a `Natural` in ascending order, determines
+// whether that `Natural` is divisible by any of `SMALL_ODD_PRIMES`.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+fn limbs_has_small_odd_prime_factor(xs: &[Limb]) -> bool {
+    let r = limbs_mod_limb(xs, ODD_PRIMORIAL);
+    SMALL_ODD_PRIMES.iter().any(|&p| r.divisible_by(p))
+}
+
+// Determines whether `n` is a strong probable prime to base `a`. Assumes that `n` is odd and
+// greater than 2, and that `0 < a < n`.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2 \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+pub_crate_test! {natural_is_strong_probable_prime(n: &Natural, a: &Natural) -> bool {
+    let n_minus_1 = n - Natural::ONE;
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let d = &n_minus_1 >> s;
+    let mut x = a.mod_pow(&d, n);
+    if x == Natural::ONE || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_square_assign(n);
+        if x == n_minus_1 {
+            return true;
+        } else if x == Natural::ONE {
+            return false;
+        }
+    }
+    false
+}}
+
+// Computes `x / 2` mod `m`. Assumes that `x < m` and that `m` is odd.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+fn half_mod(x: Natural, m: &Natural) -> Natural {
+    if x.even() {
+        x >> 1
+    } else {
+        (x + m) >> 1
+    }
+}
+
+// Runs the strong Lucas probable-prime test on `n`, with parameters $P$, $Q$, and $D$ chosen using
+// Selfridge's Method A. Assumes that `n` is odd, is not a perfect square, and has no small prime
+// factors.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2 \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+pub_crate_test! {natural_is_strong_lucas_probable_prime(n: &Natural) -> bool {
+    // Find the first D in 5, -7, 9, -11, ... with (D/n) = -1. D and Q are stored reduced mod n.
+    let mut d_abs = 5u32;
+    let mut negative = false;
+    let (d, q) = loop {
+        let d = if negative {
+            n - Natural::from(d_abs)
+        } else {
+            Natural::from(d_abs)
+        };
+        match (&d).jacobi_symbol(n) {
+            -1 => {
+                // Q = (1 - D) / 4
+                let q = if negative {
+                    Natural::from((d_abs + 1) >> 2)
+                } else {
+                    n - Natural::from((d_abs - 1) >> 2)
+                };
+                break (d, q);
+            }
+            // n has no small factors, so this means that n and D share a large factor
+            0 => return false,
+            _ => {}
+        }
+        d_abs += 2;
+        negative = !negative;
+    };
+    let n_plus_1 = n + Natural::ONE;
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let k = n_plus_1 >> s;
+    // U_1 = 1, V_1 = P = 1, Q^1 = Q
+    let mut u = Natural::ONE;
+    let mut v = Natural::ONE;
+    let mut q_k = q.clone();
+    for i in (0..k.significant_bits() - 1).rev() {
+        // U_{2k} = U_k V_k, V_{2k} = V_k^2 - 2Q^k
+        u.mod_mul_assign(&v, n);
+        v.mod_square_assign(n);
+        v.mod_sub_assign((&q_k).mod_add(&q_k, n), n);
+        q_k.mod_square_assign(n);
+        if k.get_bit(i) {
+            // U_{k+1} = (P U_k + V_k) / 2, V_{k+1} = (D U_k + P V_k) / 2
+            let mut du = d.clone();
+            du.mod_mul_assign(&u, n);
+            u = half_mod((&u).mod_add(&v, n), n);
+            v = half_mod(du.mod_add(v, n), n);
+            q_k.mod_mul_assign(&q, n);
+        }
+    }
+    if u == 0u32 || v == 0u32 {
+        return true;
+    }
+    for _ in 1..s {
+        v.mod_square_assign(n);
+        v.mod_sub_assign((&q_k).mod_add(&q_k, n), n);
+        if v == 0u32 {
+            return true;
+        }
+        q_k.mod_square_assign(n);
+    }
+    false
+}}
+
+// Runs the Baillie-PSW test on `n`: a strong probable-prime test to base 2 followed by a strong
+// Lucas probable-prime test. Assumes that `n` is odd and has no small prime factors. No composite
+// number is known to pass this test.
+//
+// # Worst-case complexity
+// $T(n) = O(n^2 \log n \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `n.significant_bits()`.
+pub_crate_test! {natural_is_prime_baillie_psw(n: &Natural) -> bool {
+    natural_is_strong_probable_prime(n, &Natural::TWO)
+        && n.checked_sqrt().is_none()
+        && natural_is_strong_lucas_probable_prime(n)
+}}
+
+impl IsPrime for Natural {
+    /// Determines whether a [`Natural`] is prime.
+    ///
+    /// If the [`Natural`] is less than $2^{64}$, the result is computed exactly using the
+    /// deterministic Miller-Rabin test for `u64`s. Otherwise, small factors are removed by trial
+    /// division and the Baillie-PSW test is run: a strong probable-prime test to base 2, followed
+    /// by a strong Lucas probable-prime test. No composite number is known to pass the
+    /// Baillie-PSW test, but it has not been proven that none exists.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    fn is_prime(&self) -> bool {
+        if let Ok(n) = u64::try_from(self) {
+            return n.is_prime();
+        }
+        match *self {
+            Natural(Small(_)) => unreachable!(),
+            Natural(Large(ref xs)) => {
+                self.odd()
+                    && !limbs_has_small_odd_prime_factor(xs)
+                    && natural_is_prime_baillie_psw(self)
+            }
+        }
+    }
+}
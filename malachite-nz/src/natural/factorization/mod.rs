@@ -1,3 +1,121 @@
+/// An implementation of [`Factor`](malachite_base::num::factorization::traits::Factor), a trait
+/// for finding the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::Factor;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::ONE.factor().to_debug_string(), "[]");
+/// assert_eq!(Natural::from(360u32).factor().to_debug_string(), "[(2, 3), (3, 2), (5, 1)]");
+/// assert_eq!(
+///     (Natural::power_of_2(128) + Natural::ONE).factor().to_debug_string(),
+///     "[(59649589127497217, 1), (5704689200685129054721, 1)]"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(128) - Natural::ONE).factor().to_debug_string(),
+///     "[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (274177, 1), (6700417, 1), \
+///     (67280421310721, 1)]"
+/// );
+/// ```
+pub mod factor;
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for testing whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::from(0u32).is_prime(), false);
+/// assert_eq!(Natural::from(1u32).is_prime(), false);
+/// assert_eq!(Natural::from(2u32).is_prime(), true);
+/// assert_eq!(Natural::from(91u32).is_prime(), false);
+/// assert_eq!(Natural::from(97u32).is_prime(), true);
+/// assert_eq!((Natural::power_of_2(127) - Natural::ONE).is_prime(), true);
+/// assert_eq!((Natural::power_of_2(128) + Natural::ONE).is_prime(), false);
+/// assert_eq!(
+///     Natural::from_str("1000000000000000000000000000057").unwrap().is_prime(),
+///     true
+/// );
+/// ```
+pub mod is_prime;
+/// Implementations of [`NextPrime`](malachite_base::num::factorization::traits::NextPrime), a
+/// trait for finding the smallest prime greater than a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::Zero;
+/// use malachite_base::num::factorization::traits::NextPrime;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(Natural::ZERO.next_prime(), 2);
+/// assert_eq!(Natural::from(10u32).next_prime(), 11);
+/// assert_eq!(
+///     Natural::from(18446744073709551557u64).next_prime(),
+///     Natural::from_str("18446744073709551629").unwrap()
+/// );
+/// assert_eq!(
+///     (&Natural::power_of_2(100)).next_prime(),
+///     Natural::from_str("1267650600228229401496703205653").unwrap()
+/// );
+/// ```
+pub mod next_prime;
+/// Implementations of [`PreviousPrime`](malachite_base::num::factorization::traits::PreviousPrime),
+/// a trait for finding the largest prime less than a number.
+///
+/// # previous_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::factorization::traits::PreviousPrime;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(2u32).previous_prime(), None);
+/// assert_eq!(Natural::from(10u32).previous_prime().to_debug_string(), "Some(7)");
+/// assert_eq!(
+///     Natural::power_of_2(64).previous_prime().to_debug_string(),
+///     "Some(18446744073709551557)"
+/// );
+/// assert_eq!(
+///     (&Natural::power_of_2(100)).previous_prime().to_debug_string(),
+///     "Some(1267650600228229401496703205361)"
+/// );
+/// ```
+pub mod previous_prime;
+/// Implementations of [`PrimeCount`](malachite_base::num::factorization::traits::PrimeCount) and
+/// [`NthPrime`](malachite_base::num::factorization::traits::NthPrime), traits for counting the
+/// primes less than or equal to a number and for finding the $n$th prime.
+///
+/// # prime_count
+/// ```
+/// use malachite_base::num::basic::traits::Zero;
+/// use malachite_base::num::factorization::traits::PrimeCount;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::ZERO.prime_count(), 0);
+/// assert_eq!(Natural::from(100u32).prime_count(), 25);
+/// assert_eq!(Natural::from(10000000000u64).prime_count(), 455052511);
+/// ```
+///
+/// # nth_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NthPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::nth_prime(1), 2);
+/// assert_eq!(Natural::nth_prime(100), 541);
+/// assert_eq!(Natural::nth_prime(1000000000), 22801763489u64);
+/// ```
+pub mod prime_count;
 /// An implementation of [`Primes`](malachite_base::num::factorization::traits::Primes), a trait
 /// for generating prime numbers.
 ///
@@ -63,4 +181,77 @@
 ///     181, 191, 193, 197, 199, 211, 223, 227, 229, 233, 239, 241, 251]"
 /// );
 /// ```
+///
+/// # primes_greater_than_or_equal_to
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     Natural::primes_greater_than_or_equal_to(&Natural::from(10u32)).take(5).collect_vec()
+///         .to_debug_string(),
+///     "[11, 13, 17, 19, 23]"
+/// );
+/// assert_eq!(
+///     Natural::primes_greater_than_or_equal_to(&Natural::power_of_2(64)).take(3).collect_vec()
+///         .to_debug_string(),
+///     "[18446744073709551629, 18446744073709551653, 18446744073709551667]"
+/// );
+/// ```
 pub mod primes;
+/// An implementation of
+/// [`PrimesInRange`](malachite_base::num::factorization::traits::PrimesInRange), a trait for
+/// generating the primes in a range.
+///
+/// # primes_in_range
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::PrimesInRange;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     Natural::primes_in_range(&Natural::from(10u32), &Natural::from(30u32)).collect_vec()
+///         .to_debug_string(),
+///     "[11, 13, 17, 19, 23, 29]"
+/// );
+/// assert_eq!(
+///     Natural::primes_in_range(
+///         &Natural::from_str("10000000000000000000000000").unwrap(),
+///         &Natural::from_str("10000000000000000000000400").unwrap()
+///     )
+///     .collect_vec()
+///     .to_debug_string(),
+///     "[10000000000000000000000013, 10000000000000000000000223, 10000000000000000000000343, \
+///     10000000000000000000000349]"
+/// );
+/// ```
+///
+/// # primes_in_inclusive_range
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::PrimesInRange;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     Natural::primes_in_inclusive_range(&Natural::from(10u32), &Natural::from(29u32))
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[11, 13, 17, 19, 23, 29]"
+/// );
+/// assert_eq!(
+///     Natural::primes_in_inclusive_range(
+///         &Natural::from(18446744073709551557u64),
+///         &Natural::from_str("18446744073709551629").unwrap()
+///     )
+///     .collect_vec()
+///     .to_debug_string(),
+///     "[18446744073709551557, 18446744073709551629]"
+/// );
+/// ```
+pub mod primes_in_range;
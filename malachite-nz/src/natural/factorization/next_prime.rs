@@ -0,0 +1,127 @@
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::factorization::is_prime::natural_is_prime_baillie_psw;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{Parity, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{CheckedNextPrime, NextPrime, Primes};
+
+// The number of odd candidates in each window sieved by `next_prime` and `previous_prime`.
+pub(crate) const PRIME_SEARCH_WINDOW_SIZE: u64 = 1 << 10;
+
+// Odd primes less than this bound are used to sieve each window.
+const PRIME_SEARCH_SIEVE_BOUND: Limb = 1 << 12;
+
+// Marks the elements of `sieve` that correspond to numbers with an odd prime factor less than
+// `PRIME_SEARCH_SIEVE_BOUND`. Element $i$ of `sieve` corresponds to `start` + 2$i$ if `ascending`
+// is true, and to `start` - 2$i$ otherwise. Assumes that `start` is odd, and that every number
+// in the window is greater than `PRIME_SEARCH_SIEVE_BOUND`.
+//
+// # Worst-case complexity
+// $T(n, m) = O(n + m \log\log m)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, $n$ is `start.significant_bits()`, and $m$ is
+// `sieve.len()`.
+pub(crate) fn sieve_odd_window(start: &Natural, ascending: bool, sieve: &mut [bool]) {
+    sieve.fill(false);
+    let len = sieve.len();
+    for p in Limb::primes_less_than(&PRIME_SEARCH_SIEVE_BOUND).skip(1) {
+        let r = match start {
+            Natural(Small(small)) => small % p,
+            Natural(Large(ref xs)) => limbs_mod_limb(xs, p),
+        };
+        // start +/- 2i is divisible by p iff i = -/+ r / 2 mod p, and (p + 1) / 2 is the inverse
+        // of 2 mod p.
+        let r = if ascending && r != 0 { p - r } else { r };
+        let mut i = usize::exact_from(r * ((p + 1) >> 1) % p);
+        let p = usize::exact_from(p);
+        while i < len {
+            sieve[i] = true;
+            i += p;
+        }
+    }
+}
+
+fn natural_next_prime(n: &Natural) -> Natural {
+    if let Ok(small) = u64::try_from(n) {
+        if let Some(p) = small.checked_next_prime() {
+            return Natural::from(p);
+        }
+    }
+    // No prime fits in a u64 and is greater than n, so the search starts at 2^64 + 1 or at the
+    // smallest odd number greater than n, whichever is larger.
+    let mut start = if *n < Natural::power_of_2(u64::WIDTH) {
+        Natural::power_of_2(u64::WIDTH)
+    } else {
+        n.clone()
+    };
+    start += Natural::ONE;
+    if start.even() {
+        start += Natural::ONE;
+    }
+    let mut sieve = vec![false; usize::exact_from(PRIME_SEARCH_WINDOW_SIZE)];
+    loop {
+        sieve_odd_window(&start, true, &mut sieve);
+        for (i, &composite) in sieve.iter().enumerate() {
+            if !composite {
+                let candidate = &start + Natural::from(i << 1);
+                if natural_is_prime_baillie_psw(&candidate) {
+                    return candidate;
+                }
+            }
+        }
+        start += Natural::from(PRIME_SEARCH_WINDOW_SIZE << 1);
+    }
+}
+
+impl NextPrime for Natural {
+    type Output = Natural;
+
+    /// Finds the smallest prime greater than a [`Natural`], taking the [`Natural`] by value.
+    ///
+    /// See [`NextPrime`] for [`&Natural`](Natural) for details.
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#next_prime).
+    #[inline]
+    fn next_prime(self) -> Natural {
+        natural_next_prime(&self)
+    }
+}
+
+impl<'a> NextPrime for &'a Natural {
+    type Output = Natural;
+
+    /// Finds the smallest prime greater than a [`Natural`], taking the [`Natural`] by reference.
+    ///
+    /// If the result fits in a `u64`, it is found using the `u64` implementation. Otherwise,
+    /// windows of odd candidates are sieved by the odd primes less than $2^{12}$, and the
+    /// remaining candidates are tested in order using the Baillie-PSW test. No composite number
+    /// is known to pass this test.
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#next_prime).
+    #[inline]
+    fn next_prime(self) -> Natural {
+        natural_next_prime(self)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::natural::factorization::is_prime::natural_is_prime_baillie_psw;
+use crate::natural::factorization::next_prime::{sieve_odd_window, PRIME_SEARCH_WINDOW_SIZE};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Parity, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::PreviousPrime;
+use std::cmp::min;
+
+fn natural_previous_prime(n: &Natural) -> Option<Natural> {
+    if let Ok(small) = u64::try_from(n) {
+        return small.previous_prime().map(Natural::from);
+    }
+    let largest_u64_prime = Natural::from(u64::MAX.previous_prime().unwrap());
+    // The search starts at the largest odd number less than n, and continues down to 2^64 + 1; no
+    // prime lies between the largest u64 prime and 2^64.
+    let lower_bound = Natural::power_of_2(u64::WIDTH) + Natural::ONE;
+    let mut start = n - Natural::ONE;
+    if start.even() {
+        start -= Natural::ONE;
+    }
+    let mut sieve = vec![false; usize::exact_from(PRIME_SEARCH_WINDOW_SIZE)];
+    while start >= lower_bound {
+        let remaining = u64::try_from(&((&start - &lower_bound) >> 1u32))
+            .map_or(PRIME_SEARCH_WINDOW_SIZE, |r| {
+                min(r + 1, PRIME_SEARCH_WINDOW_SIZE)
+            });
+        let window = &mut sieve[..usize::exact_from(remaining)];
+        sieve_odd_window(&start, false, window);
+        for (i, &composite) in window.iter().enumerate() {
+            if !composite {
+                let candidate = &start - Natural::from(i << 1);
+                if natural_is_prime_baillie_psw(&candidate) {
+                    return Some(candidate);
+                }
+            }
+        }
+        if remaining < PRIME_SEARCH_WINDOW_SIZE {
+            break;
+        }
+        start -= Natural::from(PRIME_SEARCH_WINDOW_SIZE << 1);
+    }
+    Some(largest_u64_prime)
+}
+
+impl PreviousPrime for Natural {
+    type Output = Natural;
+
+    /// Finds the largest prime less than a [`Natural`], taking the [`Natural`] by value. Returns
+    /// `None` if the [`Natural`] is less than or equal to 2.
+    ///
+    /// See [`PreviousPrime`] for [`&Natural`](Natural) for details.
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::previous_prime#previous_prime).
+    #[inline]
+    fn previous_prime(self) -> Option<Natural> {
+        natural_previous_prime(&self)
+    }
+}
+
+impl<'a> PreviousPrime for &'a Natural {
+    type Output = Natural;
+
+    /// Finds the largest prime less than a [`Natural`], taking the [`Natural`] by reference.
+    /// Returns `None` if the [`Natural`] is less than or equal to 2.
+    ///
+    /// If the [`Natural`] fits in a `u64`, the result is found using the `u64` implementation.
+    /// Otherwise, windows of odd candidates are sieved by the odd primes less than $2^{12}$, and
+    /// the remaining candidates are tested in decreasing order using the Baillie-PSW test. No
+    /// composite number is known to pass this test.
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::previous_prime#previous_prime).
+    #[inline]
+    fn previous_prime(self) -> Option<Natural> {
+        natural_previous_prime(self)
+    }
+}
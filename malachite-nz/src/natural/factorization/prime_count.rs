@@ -0,0 +1,53 @@
+use crate::natural::Natural;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{NthPrime, PrimeCount};
+
+impl PrimeCount for Natural {
+    /// Counts the primes less than or equal to a [`Natural`]; that is, computes $\pi(x)$.
+    ///
+    /// The count is computed using the [`PrimeCount`] implementation for [`u64`], which uses the
+    /// Lagarias-Miller-Odlyzko algorithm; larger values are beyond the reach of any practical
+    /// computation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{2/3})$
+    ///
+    /// $M(n) = O(n^{1/3}\log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $2^{64}$.
+    ///
+    /// # Examples
+    /// See [here](super::prime_count#prime_count).
+    #[inline]
+    fn prime_count(&self) -> Natural {
+        Natural::from(u64::exact_from(self).prime_count())
+    }
+}
+
+impl NthPrime for Natural {
+    /// Returns the $n$th prime, counting from 1.
+    ///
+    /// The prime is computed using the [`NthPrime`] implementation for [`u64`].
+    ///
+    /// $f(n) = p_n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O((n \log n)^{2/3})$
+    ///
+    /// $M(n) = O((n \log n)^{1/3})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero or if the $n$th prime is greater than or equal to $2^{64}$.
+    ///
+    /// # Examples
+    /// See [here](super::prime_count#nth_prime).
+    #[inline]
+    fn nth_prime(n: u64) -> Natural {
+        Natural::from(u64::nth_prime(n))
+    }
+}
@@ -1,7 +1,8 @@
 use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::SaturatingFrom;
 use malachite_base::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::factorization::traits::{NextPrime, Primes};
 
 /// An iterator over that generates all prime [`Natural`]s less than a given value.
 ///
@@ -19,18 +20,61 @@ impl Iterator for NaturalPrimesLessThanIterator {
     }
 }
 
-/// An iterator over that generates all prime [`Natural`]s.
+/// An iterator over that generates all prime [`Natural`]s, or all prime [`Natural`]s greater than
+/// or equal to a given value.
 ///
-/// This `struct` is created by [`Natural::primes`]; see its documentation for more.
+/// This `struct` is created by [`Natural::primes`] and
+/// [`Natural::primes_greater_than_or_equal_to`]; see their documentation for more.
 #[derive(Clone, Debug)]
-pub struct NaturalPrimesIterator(PrimesIterator<u64>);
+pub struct NaturalPrimesIterator {
+    // Generates primes using a sieve, as long as they fit in a `u64`.
+    sieve: Option<PrimesIterator<u64>>,
+    // When `sieve` is `None`, the next prime generated is the smallest prime greater than this.
+    previous: Natural,
+}
 
 impl Iterator for NaturalPrimesIterator {
     type Item = Natural;
 
-    #[inline]
     fn next(&mut self) -> Option<Natural> {
-        self.0.next().map(Natural::from)
+        if let Some(sieve) = self.sieve.as_mut() {
+            if let Some(p) = sieve.next() {
+                return Some(Natural::from(p));
+            }
+            // There are no primes between the largest prime that fits in a `u64` and `u64::MAX`.
+            self.sieve = None;
+            self.previous = Natural::from(u64::MAX);
+        }
+        self.previous = (&self.previous).next_prime();
+        Some(self.previous.clone())
+    }
+}
+
+impl Natural {
+    /// Returns an iterator that generates all primes greater than or equal to a given value.
+    ///
+    /// Unlike [`Natural::primes`], this does not sieve from 0, so it can start at an arbitrarily
+    /// large [`Natural`]. Each prime is found using [`NextPrime`].
+    ///
+    /// # Expected complexity per iteration
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of
+    /// the prime generated.
+    ///
+    /// # Examples
+    /// See [here](super::primes#primes_greater_than_or_equal_to).
+    pub fn primes_greater_than_or_equal_to(n: &Natural) -> NaturalPrimesIterator {
+        NaturalPrimesIterator {
+            sieve: None,
+            previous: if *n == 0u32 {
+                Natural::ZERO
+            } else {
+                n - Natural::ONE
+            },
+        }
     }
 }
 
@@ -80,14 +124,10 @@ impl Primes for Natural {
         NaturalPrimesLessThanIterator(u64::primes_less_than_or_equal_to(&u64::saturating_from(n)))
     }
 
-    /// Returns all primes that fit into the specified type.
+    /// Returns an iterator that generates all primes.
     ///
-    /// The iterator produced by `primes(n)` generates the same primes as the iterator
-    /// produced by `primes_less_than_or_equal_to(T::MAX)`. If you really need to generate
-    /// _every_ prime, and `T` is `u32` or smaller, then you should use the latter, as it
-    /// will allocate all the needed memory at once. If `T` is `u64` or larger, or if you
-    /// probably don't need every prime, then `primes()` will be faster as it won't
-    /// allocate too much memory right away.
+    /// Primes that fit in a `u64` are generated using a prime sieve; larger primes are found
+    /// using [`NextPrime`].
     ///
     /// # Worst-case complexity (amortized)
     /// $T(i) = O(\log \log i)$
@@ -100,6 +140,9 @@ impl Primes for Natural {
     /// See [here](super::primes#primes).
     #[inline]
     fn primes() -> NaturalPrimesIterator {
-        NaturalPrimesIterator(u64::primes())
+        NaturalPrimesIterator {
+            sieve: Some(u64::primes()),
+            previous: Natural::ZERO,
+        }
     }
 }
@@ -0,0 +1,169 @@
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::factorization::is_prime::natural_is_prime_baillie_psw;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{FloorSqrt, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, SaturatingFrom, WrappingFrom};
+use malachite_base::num::factorization::primes_in_range::{
+    segmented_sieve_prime_bound, PrimesInRangeIterator, SegmentedPrimeSieve,
+    SEGMENTED_SIEVE_SEGMENT_LENGTH,
+};
+use malachite_base::num::factorization::traits::PrimesInRange;
+use std::cmp::{max, min};
+
+/// An iterator that generates all prime [`Natural`]s in a range.
+///
+/// This `struct` is created by [`PrimesInRange::primes_in_range`] and
+/// [`PrimesInRange::primes_in_inclusive_range`]; see their documentation for more.
+#[derive(Clone, Debug)]
+pub struct NaturalPrimesInRangeIterator {
+    // Generates the primes in the range that fit in a `u64`.
+    small: PrimesInRangeIterator<u64>,
+    // Sieves the odd candidates in the range that are greater than `u64::MAX`. `None` once every
+    // such candidate has been considered.
+    sieve: Option<SegmentedPrimeSieve>,
+    i: usize,
+    candidate: Natural,
+    last: Natural,
+}
+
+impl NaturalPrimesInRangeIterator {
+    // Generates the primes in [a, b]; assumes that a <= b.
+    fn new(a: &Natural, b: &Natural) -> NaturalPrimesInRangeIterator {
+        let small = if let Ok(a) = u64::try_from(a) {
+            u64::primes_in_inclusive_range(&a, &u64::saturating_from(b))
+        } else {
+            u64::primes_in_range(&0, &0)
+        };
+        // 2^64 + 1 is odd, and no prime greater than 2^64 is even.
+        let mut start = max(a.clone(), Natural::power_of_2(u64::WIDTH) + Natural::ONE);
+        start |= Natural::ONE;
+        if start > *b {
+            return NaturalPrimesInRangeIterator {
+                small,
+                sieve: None,
+                i: 0,
+                candidate: Natural::ZERO,
+                last: Natural::ZERO,
+            };
+        }
+        let count = u64::saturating_from(&((b - &start) >> 1)).saturating_add(1);
+        let len = usize::exact_from(min(count, SEGMENTED_SIEVE_SEGMENT_LENGTH as u64));
+        // The square root of b is at least 2^32, which is greater than any sieving bound, so the
+        // candidates that survive sieving must always be tested for primality.
+        let bound = segmented_sieve_prime_bound(u64::saturating_from(&b.floor_sqrt()), count);
+        let mut sieve = SegmentedPrimeSieve::new(
+            None,
+            |p| {
+                let p = Limb::wrapping_from(p);
+                u64::exact_from(match start {
+                    Natural(Small(small)) => small % p,
+                    Natural(Large(ref xs)) => limbs_mod_limb(xs, p),
+                })
+            },
+            bound,
+            len,
+        );
+        sieve.sieve_next_segment();
+        NaturalPrimesInRangeIterator {
+            small,
+            sieve: Some(sieve),
+            i: 0,
+            candidate: start,
+            last: b.clone(),
+        }
+    }
+}
+
+impl Iterator for NaturalPrimesInRangeIterator {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        if let Some(p) = self.small.next() {
+            return Some(Natural::from(p));
+        }
+        loop {
+            let sieve = self.sieve.as_mut()?;
+            if self.i == sieve.segment.len() {
+                sieve.sieve_next_segment();
+                self.i = 0;
+            }
+            let composite = sieve.segment[self.i];
+            self.i += 1;
+            let prime = !composite && natural_is_prime_baillie_psw(&self.candidate);
+            let result = if prime {
+                Some(self.candidate.clone())
+            } else {
+                None
+            };
+            self.candidate += Natural::TWO;
+            if self.candidate > self.last {
+                self.sieve = None;
+            }
+            if result.is_some() {
+                return result;
+            }
+        }
+    }
+}
+
+impl PrimesInRange for Natural {
+    type RI = NaturalPrimesInRangeIterator;
+
+    /// Returns an iterator that generates all primes in the range $[a, b)$.
+    ///
+    /// Primes that fit in a `u64` are generated using the `u64` implementation. Larger
+    /// candidates are handled by a segmented sieve: consecutive segments of odd candidates are
+    /// sieved by the odd primes up to a bound of at most $2^{20}$, which is smaller for short
+    /// ranges, and the candidates that survive are tested using the Baillie-PSW test. No
+    /// composite number is known to pass this test.
+    ///
+    /// # Expected complexity (amortized)
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `b.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if $a > b$.
+    ///
+    /// # Examples
+    /// See [here](super::primes_in_range#primes_in_range).
+    fn primes_in_range(a: &Natural, b: &Natural) -> NaturalPrimesInRangeIterator {
+        if a > b {
+            panic!("a must be less than or equal to b. a: {}, b: {}", a, b);
+        }
+        if a == b {
+            NaturalPrimesInRangeIterator::new(&Natural::ONE, &Natural::ONE)
+        } else {
+            NaturalPrimesInRangeIterator::new(a, &(b - Natural::ONE))
+        }
+    }
+
+    /// Returns an iterator that generates all primes in the range $[a, b]$.
+    ///
+    /// See [`PrimesInRange::primes_in_range`] for details.
+    ///
+    /// # Expected complexity (amortized)
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `b.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if $a > b$.
+    ///
+    /// # Examples
+    /// See [here](super::primes_in_range#primes_in_inclusive_range).
+    fn primes_in_inclusive_range(a: &Natural, b: &Natural) -> NaturalPrimesInRangeIterator {
+        if a > b {
+            panic!("a must be less than or equal to b. a: {}, b: {}", a, b);
+        }
+        NaturalPrimesInRangeIterator::new(a, b)
+    }
+}
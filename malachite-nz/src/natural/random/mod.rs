@@ -8,6 +8,7 @@ use malachite_base::num::arithmetic::traits::{
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, PrimesInRange};
 #[cfg(feature = "32_bit_limbs")]
 use malachite_base::num::iterators::iterator_to_bit_chunks;
 use malachite_base::num::logic::traits::{BitAccess, LowMask, SignificantBits};
@@ -1302,3 +1303,267 @@ pub fn striped_random_natural_range_to_infinity(
         ),
     }
 }
+
+/// Uniformly generates random prime [`Natural`]s with a given bit length.
+#[derive(Clone, Debug)]
+pub struct RandomPrimesWithBits {
+    bits: u64,
+    limbs: RandomPrimitiveInts<u64>,
+}
+
+impl Iterator for RandomPrimesWithBits {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let mut x = get_random_natural_with_bits(&mut self.limbs, self.bits);
+            // The only even prime, 2, has 2 bits.
+            if self.bits > 2 {
+                x.set_bit(0);
+            }
+            if x.is_prime() {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Uniformly generates random prime [`Natural`]s with a given bit length.
+///
+/// Candidates are generated uniformly and tested using [`IsPrime`]; the expected number of
+/// candidates tested per prime is $O(b)$.
+///
+/// $$
+/// P(p) = \\begin{cases}
+///     \frac{1}{\pi(2^b) - \pi(2^{b-1})} & \text{if} \\quad 2^{b-1} \leq p < 2^b
+///     \\ \text{and} \\ p \\ \text{is prime}, \\\\
+///     0 & \\text{otherwise},
+/// \\end{cases}
+/// $$
+/// where $b$ is `bits` and $\pi(x)$ is the number of primes less than or equal to $x$.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^4 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+///
+/// # Panics
+/// Panics if `bits` is less than 2.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_primes_with_bits;
+///
+/// assert_eq!(
+///     prefix_to_string(random_primes_with_bits(EXAMPLE_SEED, 10), 10),
+///     "[881, 599, 727, 641, 647, 797, 839, 647, 599, 593, ...]"
+/// )
+/// ```
+pub fn random_primes_with_bits(seed: Seed, bits: u64) -> RandomPrimesWithBits {
+    assert!(bits >= 2);
+    RandomPrimesWithBits {
+        bits,
+        limbs: random_primitive_ints(seed),
+    }
+}
+
+/// Uniformly generates random safe prime [`Natural`]s with a given bit length.
+#[derive(Clone, Debug)]
+pub struct RandomSafePrimesWithBits {
+    bits: u64,
+    limbs: RandomPrimitiveInts<u64>,
+}
+
+impl Iterator for RandomSafePrimesWithBits {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let mut x = get_random_natural_with_bits(&mut self.limbs, self.bits);
+            // Every safe prime greater than 7 is congruent to 3 mod 4.
+            x.set_bit(0);
+            if self.bits > 3 {
+                x.set_bit(1);
+            }
+            // Testing the smaller number first rejects most candidates more cheaply.
+            if (&x >> 1u32).is_prime() && x.is_prime() {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Uniformly generates random safe prime [`Natural`]s with a given bit length.
+///
+/// A safe prime is a prime $p$ such that $(p-1)/2$ is also prime. Candidates are generated
+/// uniformly and tested using [`IsPrime`]; the expected number of candidates tested per safe prime
+/// is $O(b^2)$.
+///
+/// $$
+/// P(p) = \\begin{cases}
+///     \frac{1}{S(b)} & \text{if} \\quad 2^{b-1} \leq p < 2^b
+///     \\ \text{and} \\ p \\ \text{is a safe prime}, \\\\
+///     0 & \\text{otherwise},
+/// \\end{cases}
+/// $$
+/// where $b$ is `bits` and $S(b)$ is the number of safe primes with $b$ bits.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^5 \log n \log\log n)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `bits`.
+///
+/// # Panics
+/// Panics if `bits` is less than 3.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_safe_primes_with_bits;
+///
+/// assert_eq!(
+///     prefix_to_string(random_safe_primes_with_bits(EXAMPLE_SEED, 10), 10),
+///     "[863, 839, 563, 563, 839, 1019, 887, 863, 1019, 839, ...]"
+/// )
+/// ```
+pub fn random_safe_primes_with_bits(seed: Seed, bits: u64) -> RandomSafePrimesWithBits {
+    assert!(bits >= 3);
+    RandomSafePrimesWithBits {
+        bits,
+        limbs: random_primitive_ints(seed),
+    }
+}
+
+/// Uniformly generates random prime [`Natural`]s in an interval.
+#[derive(Clone, Debug)]
+pub struct RandomPrimesInRange {
+    xs: UniformRandomNaturalRange,
+}
+
+impl Iterator for RandomPrimesInRange {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        loop {
+            let x = self.xs.next().unwrap();
+            if x.is_prime() {
+                return Some(x);
+            }
+        }
+    }
+}
+
+/// Uniformly generates random prime [`Natural`]s in the half-open interval $[a, b)$.
+///
+/// The interval must contain at least one prime. Candidates are generated uniformly from the
+/// interval and tested using [`IsPrime`].
+///
+/// $$
+/// P(p) = \\begin{cases}
+///     \frac{1}{k} & \text{if} \\quad a \leq p < b \\ \text{and} \\ p \\ \text{is prime}, \\\\
+///     0 & \\text{otherwise},
+/// \\end{cases}
+/// $$
+/// where $k$ is the number of primes in $[a, b)$.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^3 \log n \log\log n (b-a)/k)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, $n$ is `b.significant_bits()`, and $k$ is the
+/// number of primes in $[a, b)$.
+///
+/// # Panics
+/// Panics if $a \geq b$ or if there are no primes in $[a, b)$.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_primes_in_range;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     prefix_to_string(
+///         random_primes_in_range(EXAMPLE_SEED, Natural::from(10u32), Natural::from(100u32)),
+///         10
+///     ),
+///     "[97, 17, 37, 11, 17, 17, 97, 17, 17, 31, ...]"
+/// )
+/// ```
+pub fn random_primes_in_range(seed: Seed, a: Natural, b: Natural) -> RandomPrimesInRange {
+    assert!(a < b);
+    assert!(
+        Natural::primes_in_range(&a, &b).next().is_some(),
+        "There are no primes in [a, b). a: {}, b: {}",
+        a,
+        b
+    );
+    RandomPrimesInRange {
+        xs: uniform_random_natural_range(seed, a, b),
+    }
+}
+
+/// Uniformly generates random prime [`Natural`]s in the closed interval $[a, b]$.
+///
+/// The interval must contain at least one prime. Candidates are generated uniformly from the
+/// interval and tested using [`IsPrime`].
+///
+/// $$
+/// P(p) = \\begin{cases}
+///     \frac{1}{k} & \text{if} \\quad a \leq p \leq b \\ \text{and} \\ p \\ \text{is prime}, \\\\
+///     0 & \\text{otherwise},
+/// \\end{cases}
+/// $$
+/// where $k$ is the number of primes in $[a, b]$.
+///
+/// The output length is infinite.
+///
+/// # Expected complexity per iteration
+/// $T(n) = O(n^3 \log n \log\log n (b-a+1)/k)$
+///
+/// $M(n) = O(n \log n)$
+///
+/// where $T$ is time, $M$ is additional memory, $n$ is `b.significant_bits()`, and $k$ is the
+/// number of primes in $[a, b]$.
+///
+/// # Panics
+/// Panics if $a > b$ or if there are no primes in $[a, b]$.
+///
+/// # Examples
+/// ```
+/// use malachite_base::iterators::prefix_to_string;
+/// use malachite_base::random::EXAMPLE_SEED;
+/// use malachite_nz::natural::random::random_primes_in_inclusive_range;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     prefix_to_string(
+///         random_primes_in_inclusive_range(
+///             EXAMPLE_SEED,
+///             Natural::from(10u32),
+///             Natural::from(97u32)
+///         ),
+///         10
+///     ),
+///     "[97, 17, 37, 11, 17, 17, 97, 17, 17, 31, ...]"
+/// )
+/// ```
+pub fn random_primes_in_inclusive_range(seed: Seed, a: Natural, b: Natural) -> RandomPrimesInRange {
+    assert!(a <= b);
+    random_primes_in_range(seed, a, b + Natural::ONE)
+}
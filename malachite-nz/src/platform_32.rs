@@ -37,6 +37,13 @@ pub const MUL_TOOM42_TO_TOOM63_THRESHOLD: usize = 295;
 
 //TODO tune
 pub const MUL_FFT_THRESHOLD: usize = 5608;
+pub const MUL_NTT_THRESHOLD: usize = 1000;
+
+// These are only used when the `parallel` feature is enabled.
+//TODO tune
+pub const MUL_TOOM_PARALLEL_THRESHOLD: usize = 300;
+//TODO tune
+pub const MUL_FFT_PARALLEL_THRESHOLD: usize = 8000;
 
 //TODO tune
 pub const DC_DIV_QR_THRESHOLD: usize = 7;
@@ -106,6 +113,7 @@ pub const SQR_TOOM4_THRESHOLD: usize = 1170;
 pub const SQR_TOOM6_THRESHOLD: usize = 512;
 //TODO tune
 pub const SQR_TOOM8_THRESHOLD: usize = 644;
+pub const SQR_NTT_THRESHOLD: usize = 1100;
 
 //TODO tune
 pub const SQRLO_DC_THRESHOLD: usize = 460;
@@ -464,6 +472,22 @@ pub const TABLE_2N_MINUS_POPC_2N: [u8; 24] =
 
 pub const TABLE_LIMIT_2N_MINUS_POPC_2N: u64 = 49;
 
+// This section is created by fibonacci_data.rs.
+
+// This is equivalent to `__gmp_fib_table` in `mpn/fib_table.c`, GMP 6.2.1, without the leading
+// $F_{-1}$.
+pub const FIBONACCI_TABLE: [Limb; 48] = [
+    0x0, 0x1, 0x1, 0x2, 0x3, 0x5, 0x8, 0xd, 0x15, 0x22, 0x37, 0x59, 0x90, 0xe9, 0x179, 0x262,
+    0x3db, 0x63d, 0xa18, 0x1055, 0x1a6d, 0x2ac2, 0x452f, 0x6ff1, 0xb520, 0x12511, 0x1da31, 0x2ff42,
+    0x4d973, 0x7d8b5, 0xcb228, 0x148add, 0x213d05, 0x35c7e2, 0x5704e7, 0x8cccc9, 0xe3d1b0,
+    0x1709e79, 0x2547029, 0x3c50ea2, 0x6197ecb, 0x9de8d6d, 0xff80c38, 0x19d699a5, 0x29cea5dd,
+    0x43a53f82, 0x6d73e55f, 0xb11924e1,
+];
+// This is equivalent to `FIB_TABLE_LIMIT` in `fib_table.h`, GMP 6.2.1.
+pub const FIBONACCI_TABLE_LIMIT: u64 = 47;
+// This is equivalent to `FIB_TABLE_LUCNUM_LIMIT` in `fib_table.h`, GMP 6.2.1.
+pub const LUCAS_TABLE_LIMIT: u64 = 46;
+
 // end of auto-generated code
 
 pub const FFT_TAB: [[u8; 2]; 5] = [[3, 3], [3, 2], [2, 1], [2, 1], [0, 0]];
@@ -162,6 +162,13 @@ pub fn triple_3_natural_bit_bucketer<T, U>(var_name: &str) -> Bucketer<(T, U, Na
     }
 }
 
+pub fn quadruple_4_natural_bit_bucketer<T, U, V>(var_name: &str) -> Bucketer<(T, U, V, Natural)> {
+    Bucketer {
+        bucketing_function: &|(_, _, _, x)| usize::exact_from(x.significant_bits()),
+        bucketing_label: format!("{}.significant_bits()", var_name),
+    }
+}
+
 pub fn triple_3_pair_1_natural_bit_bucketer<T, U, V>(
     var_name: &str,
 ) -> Bucketer<(T, U, (Natural, V))> {
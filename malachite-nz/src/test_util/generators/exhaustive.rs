@@ -264,6 +264,13 @@ pub fn exhaustive_integer_pair_gen_var_7() -> It<(Integer, Integer)> {
     ))
 }
 
+pub fn exhaustive_integer_pair_gen_var_8() -> It<(Integer, Integer)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_integers(), exhaustive_natural_integers())
+            .map(|(a, n)| (a, n + Integer::ONE)),
+    )
+}
+
 // -- (Integer, Integer, Integer) --
 
 pub fn exhaustive_integer_triple_gen() -> It<(Integer, Integer, Integer)> {
@@ -288,6 +295,13 @@ pub fn exhaustive_integer_triple_gen_var_3() -> It<(Integer, Integer, Integer)>
     )
 }
 
+pub fn exhaustive_integer_triple_gen_var_4() -> It<(Integer, Integer, Integer)> {
+    Box::new(
+        exhaustive_triples_xxy(exhaustive_integers(), exhaustive_natural_integers())
+            .map(|(a, b, n)| (a, b, n + Integer::ONE)),
+    )
+}
+
 // -- (Integer, Integer, Integer, PrimitiveUnsigned) --
 
 pub fn exhaustive_integer_integer_integer_unsigned_quadruple_gen_var_1<T: PrimitiveUnsigned>(
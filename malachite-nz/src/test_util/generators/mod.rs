@@ -327,6 +327,15 @@ pub fn integer_pair_gen_var_7_rm() -> Generator<((rug::Integer, u32), (Integer,
     )
 }
 
+// All pairs of `Integer`s where the second `Integer` is positive.
+pub fn integer_pair_gen_var_8() -> Generator<(Integer, Integer)> {
+    Generator::new(
+        &exhaustive_integer_pair_gen_var_8,
+        &random_integer_pair_gen_var_8,
+        &special_random_integer_pair_gen_var_8,
+    )
+}
+
 // -- (Integer, Integer, Integer) --
 
 pub fn integer_triple_gen() -> Generator<(Integer, Integer, Integer)> {
@@ -364,6 +373,15 @@ pub fn integer_triple_gen_var_3() -> Generator<(Integer, Integer, Integer)> {
     )
 }
 
+// All triples of `Integer`s where the third `Integer` is positive.
+pub fn integer_triple_gen_var_4() -> Generator<(Integer, Integer, Integer)> {
+    Generator::new(
+        &exhaustive_integer_triple_gen_var_4,
+        &random_integer_triple_gen_var_4,
+        &special_random_integer_triple_gen_var_4,
+    )
+}
+
 // -- (Integer, Integer, Integer, PrimitiveUnsigned) --
 
 // All `(Integer, Integer, Integer, T)` where `T` is unsigned and small.
@@ -408,6 +408,29 @@ pub fn random_integer_pair_gen_var_7(config: &GenConfig) -> It<(Integer, Integer
     ))
 }
 
+pub fn random_integer_pair_gen_var_8(config: &GenConfig) -> It<(Integer, Integer)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_integers(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                random_natural_integers(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+        )
+        .map(|(a, n)| (a, n + Integer::ONE)),
+    )
+}
+
 // -- (Integer, Integer, Integer) --
 
 pub fn random_integer_triple_gen(config: &GenConfig) -> It<(Integer, Integer, Integer)> {
@@ -472,6 +495,29 @@ pub fn random_integer_triple_gen_var_3(config: &GenConfig) -> It<(Integer, Integ
     )
 }
 
+pub fn random_integer_triple_gen_var_4(config: &GenConfig) -> It<(Integer, Integer, Integer)> {
+    Box::new(
+        random_triples_xxy(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_integers(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| {
+                random_natural_integers(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+        )
+        .map(|(a, b, n)| (a, b, n + Integer::ONE)),
+    )
+}
+
 // -- (Integer, Integer, Integer, PrimitiveUnsigned) --
 
 pub fn random_integer_integer_integer_unsigned_quadruple_gen_var_1<T: PrimitiveUnsigned>(
@@ -0,0 +1,14 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Uses the recurrence $C_{n+1} = \sum_{i=0}^n C_iC_{n-i}$.
+pub fn catalan_naive(n: u64) -> Natural {
+    let n = usize::exact_from(n);
+    let mut cs = vec![Natural::ONE];
+    for m in 1..=n {
+        let c = (0..m).map(|i| &cs[i] * &cs[m - 1 - i]).sum();
+        cs.push(c);
+    }
+    cs.swap_remove(n)
+}
@@ -0,0 +1,42 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+
+fn fibonacci_like_pair_naive(n: u64, mut a: Natural, mut b: Natural) -> (Natural, Natural) {
+    for _ in 0..n {
+        let c = &a + &b;
+        a = b;
+        b = c;
+    }
+    (a, b)
+}
+
+pub fn fibonacci_pair_naive(n: u64) -> (Natural, Natural) {
+    fibonacci_like_pair_naive(n, Natural::ZERO, Natural::ONE)
+}
+
+pub fn lucas_pair_naive(n: u64) -> (Natural, Natural) {
+    fibonacci_like_pair_naive(n, Natural::TWO, Natural::ONE)
+}
+
+// Computes U_k(P, Q) and V_k(P, Q) mod m directly from the recurrence.
+pub fn lucas_sequence_mod_naive(
+    k: u64,
+    p: &Natural,
+    q: &Natural,
+    m: &Natural,
+) -> (Natural, Natural) {
+    let p = p % m;
+    let q = q % m;
+    let mut u = (Natural::ZERO, Natural::ONE % m);
+    let mut v = (Natural::TWO % m, p.clone());
+    // X_{k+2} = PX_{k+1} - QX_k; add a multiple of m to avoid going negative
+    let step = |(x_0, x_1): (Natural, Natural)| {
+        let x_2 = (&p * &x_1 + (m - &q) * x_0) % m;
+        (x_1, x_2)
+    };
+    for _ in 0..k {
+        u = step(u);
+        v = step(v);
+    }
+    (u.0, v.0)
+}
@@ -1,5 +1,6 @@
 pub mod add;
 pub mod binomial_coefficient;
+pub mod catalan;
 pub mod checked_sub;
 pub mod div;
 pub mod div_exact;
@@ -8,6 +9,7 @@ pub mod divisible_by;
 pub mod eq_mod;
 pub mod extended_gcd;
 pub mod factorial;
+pub mod fibonacci;
 pub mod gcd;
 pub mod kronecker_symbol;
 pub mod log_base;
@@ -20,9 +22,13 @@ pub mod mod_power_of_2_pow;
 pub mod mod_power_of_2_square;
 pub mod mul;
 pub mod neg;
+pub mod partition_count;
 pub mod pow;
 pub mod primorial;
+pub mod rational_reconstruction;
 pub mod root;
 pub mod sqrt;
 pub mod square;
+pub mod stirling;
 pub mod sub;
+pub mod valuation;
@@ -0,0 +1,17 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Counts the partitions of `n` by adding the parts one size at a time.
+pub fn partition_count_naive(n: u64) -> Natural {
+    let n = usize::exact_from(n);
+    let mut ps = vec![Natural::ZERO; n + 1];
+    ps[0] = Natural::ONE;
+    for part in 1..=n {
+        for m in part..=n {
+            let (lo, hi) = ps.split_at_mut(m);
+            hi[0] += &lo[m - part];
+        }
+    }
+    ps.swap_remove(n)
+}
@@ -0,0 +1,32 @@
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{DivMod, Gcd, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Zero};
+
+pub fn rational_reconstruction_simple(
+    a: &Natural,
+    m: &Natural,
+    numerator_bound: &Natural,
+    denominator_bound: &Natural,
+) -> Option<(Integer, Natural)> {
+    let mut r_0 = m.clone();
+    let mut r_1 = a.clone();
+    let mut t_0 = Integer::ZERO;
+    let mut t_1 = Integer::ONE;
+    while r_1 > *numerator_bound {
+        let (q, r) = (&r_0).div_mod(&r_1);
+        let t = t_0 - Integer::from(q) * &t_1;
+        r_0 = r_1;
+        r_1 = r;
+        t_0 = t_1;
+        t_1 = t;
+    }
+    let denominator = (&t_1).unsigned_abs();
+    if denominator > *denominator_bound || (&r_1).gcd(&denominator) != 1u32 {
+        None
+    } else if t_1 > 0u32 {
+        Some((Integer::from(r_1), denominator))
+    } else {
+        Some((-r_1, denominator))
+    }
+}
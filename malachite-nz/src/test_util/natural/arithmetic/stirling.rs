@@ -0,0 +1,47 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Computes the full triangle of Stirling numbers up to row `n`, given the multiplier of the
+// recurrence.
+fn stirling_triangle_naive(n: u64, k: u64, multiplier: impl Fn(u64, u64) -> u64) -> Natural {
+    if k > n {
+        return Natural::ZERO;
+    }
+    let n = usize::exact_from(n);
+    let k = usize::exact_from(k);
+    let mut row = vec![Natural::ONE];
+    for m in 1..=n {
+        let mut next = vec![Natural::ZERO; m + 1];
+        for (j, x) in next.iter_mut().enumerate().skip(1) {
+            if j < m {
+                *x += &row[j] * Natural::from(multiplier(u64::exact_from(m), u64::exact_from(j)));
+            }
+            *x += &row[j - 1];
+        }
+        row = next;
+    }
+    row.swap_remove(k)
+}
+
+pub fn stirling_first_naive(n: u64, k: u64) -> Natural {
+    stirling_triangle_naive(n, k, |m, _| m - 1)
+}
+
+pub fn stirling_second_naive(n: u64, k: u64) -> Natural {
+    stirling_triangle_naive(n, k, |_, j| j)
+}
+
+// Uses the Bell triangle.
+pub fn bell_naive(n: u64) -> Natural {
+    let mut row = vec![Natural::ONE];
+    for _ in 0..n {
+        let mut next = vec![row.last().unwrap().clone()];
+        for x in &row {
+            let y = next.last().unwrap() + x;
+            next.push(y);
+        }
+        row = next;
+    }
+    row.swap_remove(0)
+}
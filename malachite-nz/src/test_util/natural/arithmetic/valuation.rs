@@ -0,0 +1,16 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::DivMod;
+
+pub fn remove_factor_naive(mut n: Natural, p: &Natural) -> (Natural, u64) {
+    assert_ne!(n, 0u32);
+    assert!(*p > 1u32);
+    let mut v = 0;
+    loop {
+        let (q, r) = (&n).div_mod(p);
+        if r != 0u32 {
+            return (n, v);
+        }
+        n = q;
+        v += 1;
+    }
+}
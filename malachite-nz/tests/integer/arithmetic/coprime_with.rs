@@ -0,0 +1,63 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, Gcd, UnsignedAbs};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, integer_pair_gen, natural_pair_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_coprime_with() {
+    let test = |s, t, out| {
+        let u = Integer::from_str(s).unwrap();
+        let v = Integer::from_str(t).unwrap();
+
+        assert_eq!(u.clone().coprime_with(v.clone()), out);
+        assert_eq!((&u).coprime_with(v.clone()), out);
+        assert_eq!(u.clone().coprime_with(&v), out);
+        assert_eq!((&u).coprime_with(&v), out);
+    };
+    test("0", "0", false);
+    test("0", "-6", false);
+    test("-1", "0", true);
+    test("0", "-1", true);
+    test("1", "-6", true);
+    test("-6", "1", true);
+    test("-8", "12", false);
+    test("54", "-24", false);
+    test("-3", "5", true);
+    test("-3", "-5", true);
+    test("12", "-90", false);
+    test("-12345678987654321", "98765432123456789", true);
+    test("12345678987654321", "-98765432123456827", false);
+}
+
+#[test]
+fn coprime_with_properties() {
+    integer_pair_gen().test_properties(|(x, y)| {
+        let c = (&x).coprime_with(&y);
+        assert_eq!(x.clone().coprime_with(y.clone()), c);
+        assert_eq!(x.clone().coprime_with(&y), c);
+        assert_eq!((&x).coprime_with(y.clone()), c);
+
+        assert_eq!((&x).gcd(&y) == 1, c);
+        assert_eq!((-&x).coprime_with(&y), c);
+        assert_eq!((&x).unsigned_abs().coprime_with((&y).unsigned_abs()), c);
+        assert_eq!(y.coprime_with(x), c);
+    });
+
+    integer_gen().test_properties(|x| {
+        let abs_is_1 = (&x).unsigned_abs() == 1;
+        assert_eq!((&x).coprime_with(&x), abs_is_1);
+        assert!((&x).coprime_with(Integer::ONE));
+        assert!((&x).coprime_with(Integer::NEGATIVE_ONE));
+        assert_eq!((&x).coprime_with(Integer::ZERO), abs_is_1);
+        let y = &x + Integer::ONE;
+        assert!(x.coprime_with(y));
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(
+            Integer::from(&x).coprime_with(Integer::from(&y)),
+            x.coprime_with(y)
+        );
+    });
+}
@@ -0,0 +1,114 @@
+use malachite_base::num::arithmetic::traits::{Crt, CrtPrecomputed, Lcm, Mod, UnsignedAbs};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::signed_triple_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_triple_gen, integer_vec_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn parse(xs: &[&str]) -> Vec<Integer> {
+    xs.iter().map(|x| Integer::from_str(x).unwrap()).collect()
+}
+
+#[test]
+fn test_crt() {
+    let test = |congruences: &[(&str, &str)], out: &str| {
+        let congruences = congruences
+            .iter()
+            .map(|&(r, m)| (Integer::from_str(r).unwrap(), Integer::from_str(m).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(Integer::crt(&congruences).to_debug_string(), out);
+    };
+    test(&[], "Some((0, 1))");
+    test(&[("-1", "7")], "Some((6, 7))");
+    test(&[("-1", "3"), ("-1", "5")], "Some((14, 15))");
+    test(&[("-7", "4"), ("-1", "6")], "Some((5, 12))");
+    test(&[("-1", "4"), ("0", "6")], "None");
+    test(
+        &[("-1", "18446744073709551557"), ("-2", "18446744073709551533")],
+        "Some((70892159775195512675051583364639578451, \
+        340282366920938460843936948965011886881))",
+    );
+}
+
+#[test]
+fn crt_fail() {
+    assert_panic!(Integer::crt(&[(Integer::from(1), Integer::from(0))]));
+    assert_panic!(Integer::crt(&[(Integer::from(1), Integer::from(-3))]));
+}
+
+#[test]
+fn test_crt_precomputed() {
+    let test = |moduli: &[&str], residues: &[&str], out: &str| {
+        let basis = Integer::precompute_crt_data(&parse(moduli)).unwrap();
+        assert_eq!(
+            Integer::crt_precomputed(&parse(residues), &basis).to_string(),
+            out
+        );
+    };
+    test(&[], &[], "0");
+    test(&["3", "5", "7"], &["-1", "-1", "-1"], "104");
+    test(&["3", "5", "7"], &["2", "-2", "9"], "23");
+    test(&["4", "9", "25"], &["-1", "-10", "-1"], "899");
+    assert!(Integer::precompute_crt_data(&parse(&["4", "6"])).is_none());
+}
+
+#[test]
+fn crt_precomputed_fail() {
+    assert_panic!(Integer::precompute_crt_data(&[Integer::from(0)]));
+    assert_panic!(Integer::precompute_crt_data(&[Integer::from(-3)]));
+}
+
+#[test]
+fn crt_properties() {
+    integer_triple_gen().test_properties(|(x, a, b)| {
+        if a <= 0u32 || b <= 0u32 {
+            return;
+        }
+        let lcm = Integer::from(a.unsigned_abs_ref().lcm(b.unsigned_abs_ref()));
+        let result = Integer::crt(&[(x.clone(), a.clone()), (x.clone(), b.clone())]);
+        assert_eq!(result, Some(((&x).mod_op(&lcm), lcm)));
+        let natural_result = Natural::crt(&[
+            ((&x).mod_op(&a).unsigned_abs(), a.unsigned_abs()),
+            ((&x).mod_op(&b).unsigned_abs(), b.unsigned_abs()),
+        ]);
+        assert_eq!(
+            result,
+            natural_result.map(|(r, m)| (Integer::from(r), Integer::from(m)))
+        );
+    });
+
+    integer_vec_gen().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.is_empty() || xs[1..].iter().any(|m| *m <= 0u32) {
+            return;
+        }
+        let x = &xs[0];
+        let moduli = &xs[1..];
+        if let Some(basis) = Integer::precompute_crt_data(moduli) {
+            let modulus = Integer::from(basis.modulus());
+            let residues: Vec<Integer> = moduli.iter().map(|m| x - m).collect();
+            assert_eq!(
+                Integer::crt_precomputed(&residues, &basis),
+                x.mod_op(&modulus)
+            );
+        }
+    });
+
+    signed_triple_gen::<i32>().test_properties(|(x, a, b)| {
+        if a <= 0 || b <= 0 {
+            return;
+        }
+        let congruences = [(i64::from(x), i64::from(a)), (i64::from(x), i64::from(b))];
+        assert_eq!(
+            Integer::crt(
+                &congruences
+                    .iter()
+                    .map(|&(r, m)| (Integer::from(r), Integer::from(m)))
+                    .collect::<Vec<_>>()
+            ),
+            i64::crt(&congruences).map(|(r, m)| (Integer::from(r), Integer::from(m)))
+        );
+    });
+}
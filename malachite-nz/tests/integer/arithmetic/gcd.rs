@@ -0,0 +1,177 @@
+use malachite_base::num::arithmetic::traits::{
+    DivisibleBy, ExtendedGcd, Gcd, GcdAssign, GcdOf, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_gen, integer_pair_gen, integer_triple_gen, integer_vec_gen, natural_pair_gen,
+    natural_vec_gen,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_gcd() {
+    let test = |s, t, out| {
+        let u = Integer::from_str(s).unwrap();
+        let v = Integer::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.gcd_assign(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.gcd_assign(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().gcd(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).gcd(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().gcd(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).gcd(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = rug::Integer::from_str(s)
+            .unwrap()
+            .gcd(&rug::Integer::from_str(t).unwrap());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "6", "6");
+    test("0", "-6", "6");
+    test("-6", "0", "6");
+    test("1", "-6", "1");
+    test("-6", "1", "1");
+    test("-8", "12", "4");
+    test("54", "-24", "6");
+    test("-42", "-56", "14");
+    test("-3", "5", "1");
+    test("12", "-90", "6");
+    test("-12345678987654321", "98765432123456789", "1");
+    test("12345678987654321", "-98765432123456827", "37");
+}
+
+#[test]
+fn gcd_properties() {
+    integer_pair_gen().test_properties(|(x, y)| {
+        let gcd_val_val = x.clone().gcd(y.clone());
+        let gcd_val_ref = x.clone().gcd(&y);
+        let gcd_ref_val = (&x).gcd(y.clone());
+        let gcd = (&x).gcd(&y);
+        assert!(gcd_val_val.is_valid());
+        assert!(gcd_val_ref.is_valid());
+        assert!(gcd_ref_val.is_valid());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd_val_val, gcd);
+        assert_eq!(gcd_val_ref, gcd);
+        assert_eq!(gcd_ref_val, gcd);
+
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, gcd);
+
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(&y);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, gcd);
+
+        assert_eq!(
+            Natural::exact_from(&(rug::Integer::from(&x).gcd(&rug::Integer::from(&y)))),
+            gcd
+        );
+
+        assert_eq!((&y).gcd(&x), gcd);
+        assert_eq!((-&x).gcd(&y), gcd);
+        assert_eq!((&x).unsigned_abs().gcd((&y).unsigned_abs()), gcd);
+        assert_eq!((&x).extended_gcd(&y).0, gcd);
+        assert!(x.divisible_by(Integer::from(&gcd)));
+        assert!(y.divisible_by(Integer::from(&gcd)));
+    });
+
+    integer_gen().test_properties(|x| {
+        assert_eq!((&x).gcd(&x), (&x).unsigned_abs());
+        assert_eq!((&x).gcd(Integer::ONE), 1);
+        assert_eq!((&x).gcd(Integer::ZERO), (&x).unsigned_abs());
+    });
+
+    integer_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!(
+            Integer::from((&x).gcd(&y)).gcd(&z),
+            x.gcd(Integer::from(y.gcd(z)))
+        );
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(Integer::from(&x).gcd(Integer::from(&y)), x.gcd(y));
+    });
+}
+
+#[test]
+fn test_gcd_of() {
+    let test = |xs, out| {
+        let xs = vec_from_str::<Integer>(xs).unwrap();
+        let gcd = Natural::gcd_of(xs.iter());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd.to_string(), out);
+
+        let gcd = Natural::gcd_of(xs.into_iter());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd.to_string(), out);
+    };
+    test("[]", "0");
+    test("[0]", "0");
+    test("[-6]", "6");
+    test("[0, -6, 0]", "6");
+    test("[-12, 90, -42]", "6");
+    test("[6, -10, 15]", "1");
+    test("[-48, -18, 30, 0]", "6");
+}
+
+#[test]
+fn gcd_of_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let gcd = Natural::gcd_of(xs.iter());
+        assert!(gcd.is_valid());
+        let gcd_alt = Natural::gcd_of(xs.clone().into_iter());
+        assert!(gcd_alt.is_valid());
+        assert_eq!(gcd_alt, gcd);
+        assert_eq!(
+            xs.iter()
+                .fold(Integer::ZERO, |acc, x| Integer::from(acc.gcd(x))),
+            gcd
+        );
+        assert_eq!(
+            Natural::gcd_of(xs.iter().map(UnsignedAbs::unsigned_abs)),
+            gcd
+        );
+        assert_eq!(Natural::gcd_of(xs.iter().rev()), gcd);
+        for x in &xs {
+            assert!(x.divisible_by(Integer::from(&gcd)));
+        }
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(Natural::gcd_of([&x, &y].into_iter()), x.gcd(y));
+    });
+
+    natural_vec_gen().test_properties(|xs| {
+        assert_eq!(
+            Natural::gcd_of(xs.iter().map(Integer::from)),
+            Natural::gcd_of(xs.into_iter())
+        );
+    });
+}
@@ -0,0 +1,187 @@
+use malachite_base::num::arithmetic::traits::{
+    DivisibleBy, Gcd, Lcm, LcmAssign, LcmOf, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    integer_gen, integer_pair_gen, integer_triple_gen, integer_vec_gen, natural_pair_gen,
+    natural_vec_gen,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_lcm() {
+    let test = |s, t, out| {
+        let u = Integer::from_str(s).unwrap();
+        let v = Integer::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.lcm_assign(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.lcm_assign(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().lcm(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).lcm(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().lcm(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).lcm(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = rug::Integer::from_str(s)
+            .unwrap()
+            .lcm(&rug::Integer::from_str(t).unwrap());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "0", "0");
+    test("0", "-6", "0");
+    test("-6", "0", "0");
+    test("1", "-6", "6");
+    test("-6", "1", "6");
+    test("-8", "12", "24");
+    test("54", "-24", "216");
+    test("-42", "-56", "168");
+    test("-3", "5", "15");
+    test("12", "-90", "180");
+    test(
+        "-12345678987654321",
+        "98765432123456789",
+        "1219326320073159566072245112635269",
+    );
+    test(
+        "12345678987654321",
+        "-98765432123456827",
+        "32954765407382703654271530905391",
+    );
+}
+
+#[test]
+fn lcm_properties() {
+    integer_pair_gen().test_properties(|(x, y)| {
+        let lcm_val_val = x.clone().lcm(y.clone());
+        let lcm_val_ref = x.clone().lcm(&y);
+        let lcm_ref_val = (&x).lcm(y.clone());
+        let lcm = (&x).lcm(&y);
+        assert!(lcm_val_val.is_valid());
+        assert!(lcm_val_ref.is_valid());
+        assert!(lcm_ref_val.is_valid());
+        assert!(lcm.is_valid());
+        assert_eq!(lcm_val_val, lcm);
+        assert_eq!(lcm_val_ref, lcm);
+        assert_eq!(lcm_ref_val, lcm);
+
+        let mut mut_x = x.clone();
+        mut_x.lcm_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, lcm);
+
+        let mut mut_x = x.clone();
+        mut_x.lcm_assign(&y);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, lcm);
+
+        assert_eq!(
+            Natural::exact_from(&(rug::Integer::from(&x).lcm(&rug::Integer::from(&y)))),
+            lcm
+        );
+
+        assert_eq!((&y).lcm(&x), lcm);
+        assert_eq!((-&x).lcm(&y), lcm);
+        assert_eq!((&x).unsigned_abs().lcm((&y).unsigned_abs()), lcm);
+        assert!(Integer::from(&lcm).divisible_by(&x));
+        assert!(Integer::from(&lcm).divisible_by(&y));
+        assert_eq!(
+            &lcm * (&x).gcd(&y),
+            (&x).unsigned_abs() * (&y).unsigned_abs()
+        );
+    });
+
+    integer_gen().test_properties(|x| {
+        assert_eq!((&x).lcm(&x), (&x).unsigned_abs());
+        assert_eq!((&x).lcm(Integer::NEGATIVE_ONE), (&x).unsigned_abs());
+        assert_eq!(x.lcm(Integer::ZERO), 0);
+    });
+
+    integer_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!(
+            Integer::from((&x).lcm(&y)).lcm(&z),
+            x.lcm(Integer::from(y.lcm(z)))
+        );
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(Integer::from(&x).lcm(Integer::from(&y)), x.lcm(y));
+    });
+}
+
+#[test]
+fn test_lcm_of() {
+    let test = |xs, out| {
+        let xs = vec_from_str::<Integer>(xs).unwrap();
+        let lcm = Natural::lcm_of(xs.iter());
+        assert!(lcm.is_valid());
+        assert_eq!(lcm.to_string(), out);
+
+        let lcm = Natural::lcm_of(xs.into_iter());
+        assert!(lcm.is_valid());
+        assert_eq!(lcm.to_string(), out);
+    };
+    test("[]", "1");
+    test("[0]", "0");
+    test("[-6]", "6");
+    test("[6, 0, -15]", "0");
+    test("[-1, -1, 1]", "1");
+    test("[-12, 90, -42]", "1260");
+    test("[6, -10, 15]", "30");
+}
+
+#[test]
+fn lcm_of_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let lcm = Natural::lcm_of(xs.iter());
+        assert!(lcm.is_valid());
+        let lcm_alt = Natural::lcm_of(xs.clone().into_iter());
+        assert!(lcm_alt.is_valid());
+        assert_eq!(lcm_alt, lcm);
+        assert_eq!(
+            xs.iter()
+                .fold(Integer::ONE, |acc, x| Integer::from(acc.lcm(x))),
+            lcm
+        );
+        assert_eq!(
+            Natural::lcm_of(xs.iter().map(UnsignedAbs::unsigned_abs)),
+            lcm
+        );
+        assert_eq!(Natural::lcm_of(xs.iter().rev()), lcm);
+        for x in &xs {
+            assert!(Integer::from(&lcm).divisible_by(x));
+        }
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(Natural::lcm_of([&x, &y].into_iter()), x.lcm(y));
+    });
+
+    natural_vec_gen().test_properties(|xs| {
+        assert_eq!(
+            Natural::lcm_of(xs.iter().map(Integer::from)),
+            Natural::lcm_of(xs.into_iter())
+        );
+    });
+}
@@ -0,0 +1,136 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModAdd, ModAddAssign, ModNeg, ModSub};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_pair_gen_var_8, integer_triple_gen_var_4};
+use std::str::FromStr;
+
+#[test]
+fn test_mod_add() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let v = Integer::from_str(s).unwrap();
+        let m = Integer::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_add_assign(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_add_assign(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_add_assign(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_add_assign(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_add(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_add(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_add(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_add(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_add(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_add(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_add(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_add(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        assert_eq!((u + v).mod_op(m).to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("5", "-7", "1", "0");
+    test("-3", "4", "7", "1");
+    test("10", "-20", "7", "4");
+    test("-1", "-1", "2", "0");
+    test(
+        "123456789012345678901234567890",
+        "-987654321098765432109876543210",
+        "1000000007",
+        "617521330",
+    );
+    test("-18446744073709551616", "1", "18446744073709551617", "2");
+    test(
+        "-987654321098765432109876543210",
+        "-987654321098765432109876543210",
+        "170141183460469231731687303715884105727",
+        "170141181485160589534156439496131019307",
+    );
+    test(
+        "-123456789012345678901234567890",
+        "7",
+        "1267650600228229401496703205376",
+        "1144193811215883722595468637493",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_add_fail_1() {
+    Integer::from(3).mod_add(Integer::ONE, Integer::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mod_add_fail_2() {
+    Integer::from(3).mod_add(Integer::ONE, Integer::from(-7));
+}
+
+#[test]
+#[should_panic]
+fn mod_add_assign_fail() {
+    let mut x = Integer::from(3);
+    x.mod_add_assign(Integer::ONE, Integer::ZERO);
+}
+
+#[test]
+fn mod_add_properties() {
+    integer_triple_gen_var_4().test_properties(|(x, y, m)| {
+        let sum = (&x).mod_add(&y, &m);
+        assert!(sum.is_valid());
+        assert!(sum >= 0u32);
+        assert!(sum < m);
+
+        let mut x_alt = x.clone();
+        x_alt.mod_add_assign(&y, &m);
+        assert_eq!(x_alt, sum);
+
+        assert_eq!((&x + &y).mod_op(&m), sum);
+        assert_eq!((&y).mod_add(&x, &m), sum);
+        assert_eq!((&x).mod_sub(-&y, &m), sum);
+        assert_eq!((&x).mod_op(&m).mod_add((&y).mod_op(&m), &m), sum);
+        assert_eq!((-&x).mod_add(-&y, &m), sum.mod_neg(&m));
+    });
+
+    integer_pair_gen_var_8().test_properties(|(x, m)| {
+        assert_eq!((&x).mod_add(Integer::ZERO, &m), (&x).mod_op(&m));
+        assert_eq!((&x).mod_add(&m, &m), Integer::ZERO.mod_add(&x, &m));
+    });
+}
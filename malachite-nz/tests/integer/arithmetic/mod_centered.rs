@@ -0,0 +1,125 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModCentered, ModCenteredAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::signed_pair_gen_var_13;
+use malachite_nz::integer::Integer;
+use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::{integer_gen, integer_pair_gen_var_8};
+use std::str::FromStr;
+
+#[test]
+fn test_mod_centered() {
+    let test = |r, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let m = Integer::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_centered_assign(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_centered_assign(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_centered(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_centered(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_centered(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_centered(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "1", "0");
+    test("-5", "1", "0");
+    test("3", "7", "3");
+    test("-3", "7", "-3");
+    test("14", "7", "0");
+    test("-1", "2", "1");
+    test("-987654321098765432109876543210", "1000000007", "420086488");
+    test("-18446744073709551616", "18446744073709551617", "1");
+    test(
+        "123456789012345678901234567890",
+        "170141183460469231731687303715884105727",
+        "123456789012345678901234567890",
+    );
+    test(
+        "-123456789012345678901234567890",
+        "1267650600228229401496703205376",
+        "-123456789012345678901234567890",
+    );
+    test("7", "10", "-3");
+    test("5", "10", "5");
+    test("-5", "10", "5");
+    test("-6", "10", "4");
+    test("4", "9", "4");
+    test("5", "9", "-4");
+    test("-4", "9", "-4");
+    test(
+        "18446744073709551616",
+        "36893488147419103232",
+        "18446744073709551616",
+    );
+    test(
+        "18446744073709551617",
+        "36893488147419103232",
+        "-18446744073709551615",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_centered_fail_1() {
+    Integer::from(3).mod_centered(Integer::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mod_centered_fail_2() {
+    Integer::from(3).mod_centered(Integer::from(-7));
+}
+
+#[test]
+#[should_panic]
+fn mod_centered_assign_fail() {
+    let mut x = Integer::from(3);
+    x.mod_centered_assign(Integer::ZERO);
+}
+
+#[test]
+fn mod_centered_properties() {
+    integer_pair_gen_var_8().test_properties(|(x, m)| {
+        let centered = (&x).mod_centered(&m);
+        assert!(centered.is_valid());
+
+        let mut x_alt = x.clone();
+        x_alt.mod_centered_assign(&m);
+        assert_eq!(x_alt, centered);
+
+        let twice: Integer = &centered << 1u32;
+        assert!(twice > -&m);
+        assert!(twice <= m);
+        assert_eq!((&centered).mod_op(&m), (&x).mod_op(&m));
+        assert_eq!((&centered).mod_centered(&m), centered);
+        assert_eq!((&x + &m).mod_centered(&m), centered);
+    });
+
+    integer_gen().test_properties(|x| {
+        assert_eq!(x.mod_centered(Integer::ONE), 0u32);
+    });
+
+    signed_pair_gen_var_13::<SignedLimb>().test_properties(|(x, m)| {
+        assert_eq!(
+            Integer::from(x).mod_centered(Integer::from(m)),
+            x.mod_centered(m)
+        );
+    });
+}
@@ -0,0 +1,89 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, Mod, ModInverse, ModMul, ModNeg};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_pair_gen_var_8;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_inverse() {
+    let test = |r, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let m = Integer::from_str(t).unwrap();
+
+        let n = u.clone().mod_inverse(m.clone());
+        assert_eq!(n.to_debug_string(), out);
+
+        let n = u.clone().mod_inverse(&m);
+        assert_eq!(n.to_debug_string(), out);
+
+        let n = (&u).mod_inverse(m.clone());
+        assert_eq!(n.to_debug_string(), out);
+
+        let n = (&u).mod_inverse(&m);
+        assert_eq!(n.to_debug_string(), out);
+    };
+    test("0", "1", "Some(0)");
+    test("-5", "1", "Some(0)");
+    test("3", "10", "Some(7)");
+    test("-3", "10", "Some(3)");
+    test("13", "10", "Some(7)");
+    test("4", "10", "None");
+    test("0", "7", "None");
+    test(
+        "-987654321098765432109876543210",
+        "1000000007",
+        "Some(599624263)",
+    );
+    test("-18446744073709551616", "18446744073709551617", "Some(1)");
+    test(
+        "123456789012345678901234567890",
+        "170141183460469231731687303715884105727",
+        "Some(48464825753085841100438376607502766223)",
+    );
+    test(
+        "-123456789012345678901234567890",
+        "1267650600228229401496703205376",
+        "None",
+    );
+    test(
+        "-3",
+        "1267650600228229401496703205376",
+        "Some(422550200076076467165567735125)",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_inverse_fail_1() {
+    Integer::from(3).mod_inverse(Integer::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mod_inverse_fail_2() {
+    Integer::from(3).mod_inverse(Integer::from(-7));
+}
+
+#[test]
+fn mod_inverse_properties() {
+    integer_pair_gen_var_8().test_properties(|(x, m)| {
+        let inverse = (&x).mod_inverse(&m);
+        assert_eq!(
+            inverse.is_some(),
+            x.unsigned_abs_ref().coprime_with(m.unsigned_abs_ref())
+        );
+        assert_eq!(
+            (-&x).mod_inverse(&m),
+            inverse.as_ref().map(|y| y.mod_neg(&m))
+        );
+        if let Some(inverse) = inverse {
+            assert!(inverse.is_valid());
+            assert!(inverse >= 0u32);
+            assert!(inverse < m);
+            assert_eq!((&x).mod_mul(&inverse, &m), Integer::ONE.mod_op(&m));
+            assert_eq!((&inverse).mod_inverse(&m).unwrap(), (&x).mod_op(&m));
+            assert_eq!((&x + &m).mod_inverse(&m).unwrap(), inverse);
+        }
+    });
+}
@@ -0,0 +1,144 @@
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::arithmetic::traits::{Mod, ModMul, ModMulAssign, ModNeg};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_pair_gen_var_8, integer_triple_gen_var_4};
+use std::str::FromStr;
+
+#[test]
+fn test_mod_mul() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let v = Integer::from_str(s).unwrap();
+        let m = Integer::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_mul_assign(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_mul_assign(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_mul_assign(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_mul_assign(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_mul(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_mul(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_mul(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_mul(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_mul(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_mul(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_mul(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_mul(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        assert_eq!((u * v).mod_op(m).to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("5", "-7", "1", "0");
+    test("-3", "4", "7", "2");
+    test("10", "-20", "7", "3");
+    test("-1", "-1", "2", "1");
+    test(
+        "123456789012345678901234567890",
+        "-987654321098765432109876543210",
+        "1000000007",
+        "804036940",
+    );
+    test("-18446744073709551616", "1", "18446744073709551617", "1");
+    test(
+        "-987654321098765432109876543210",
+        "-987654321098765432109876543210",
+        "170141183460469231731687303715884105727",
+        "25164435113107579355918047505511713600",
+    );
+    test(
+        "-123456789012345678901234567890",
+        "7",
+        "1267650600228229401496703205376",
+        "403453077141809649188061230146",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_mul_fail_1() {
+    Integer::from(3).mod_mul(Integer::ONE, Integer::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mod_mul_fail_2() {
+    Integer::from(3).mod_mul(Integer::ONE, Integer::from(-7));
+}
+
+#[test]
+#[should_panic]
+fn mod_mul_assign_fail() {
+    let mut x = Integer::from(3);
+    x.mod_mul_assign(Integer::ONE, Integer::ZERO);
+}
+
+#[test]
+fn mod_mul_properties() {
+    integer_triple_gen_var_4().test_properties(|(x, y, m)| {
+        let product = (&x).mod_mul(&y, &m);
+        assert!(product.is_valid());
+        assert!(product >= 0u32);
+        assert!(product < m);
+
+        let mut x_alt = x.clone();
+        x_alt.mod_mul_assign(&y, &m);
+        assert_eq!(x_alt, product);
+
+        assert_eq!((&x * &y).mod_op(&m), product);
+        assert_eq!((&y).mod_mul(&x, &m), product);
+        assert_eq!(
+            Integer::from(
+                (&x).mod_op(&m)
+                    .unsigned_abs()
+                    .mod_mul((&y).mod_op(&m).unsigned_abs(), m.unsigned_abs_ref())
+            ),
+            product
+        );
+        assert_eq!((-&x).mod_mul(&y, &m), (&product).mod_neg(&m));
+    });
+
+    integer_pair_gen_var_8().test_properties(|(x, m)| {
+        assert_eq!((&x).mod_mul(Integer::ZERO, &m), 0u32);
+        assert_eq!((&x).mod_mul(Integer::ONE, &m), (&x).mod_op(&m));
+        assert_eq!(x.mod_mul(&m, &m), 0u32);
+    });
+}
@@ -0,0 +1,108 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModAdd, ModNeg, ModNegAssign, ModSub};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, integer_pair_gen_var_8};
+use std::str::FromStr;
+
+#[test]
+fn test_mod_neg() {
+    let test = |r, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let m = Integer::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_neg_assign(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_neg_assign(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_neg(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_neg(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_neg(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_neg(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        assert_eq!((-u).mod_op(m).to_string(), out);
+    };
+    test("0", "1", "0");
+    test("-5", "1", "0");
+    test("3", "7", "4");
+    test("-3", "7", "3");
+    test("14", "7", "0");
+    test("-1", "2", "1");
+    test("-987654321098765432109876543210", "1000000007", "579913519");
+    test(
+        "-18446744073709551616",
+        "18446744073709551617",
+        "18446744073709551616",
+    );
+    test(
+        "123456789012345678901234567890",
+        "170141183460469231731687303715884105727",
+        "170141183337012442719341624814649537837",
+    );
+    test(
+        "-123456789012345678901234567890",
+        "1267650600228229401496703205376",
+        "123456789012345678901234567890",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_neg_fail_1() {
+    Integer::from(3).mod_neg(Integer::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mod_neg_fail_2() {
+    Integer::from(3).mod_neg(Integer::from(-7));
+}
+
+#[test]
+#[should_panic]
+fn mod_neg_assign_fail() {
+    let mut x = Integer::from(3);
+    x.mod_neg_assign(Integer::ZERO);
+}
+
+#[test]
+fn mod_neg_properties() {
+    integer_pair_gen_var_8().test_properties(|(x, m)| {
+        let neg = (&x).mod_neg(&m);
+        assert!(neg.is_valid());
+        assert!(neg >= 0u32);
+        assert!(neg < m);
+
+        let mut x_alt = x.clone();
+        x_alt.mod_neg_assign(&m);
+        assert_eq!(x_alt, neg);
+
+        assert_eq!((-&x).mod_op(&m), neg);
+        assert_eq!((&neg).mod_neg(&m), (&x).mod_op(&m));
+        assert_eq!((&x).mod_add(&neg, &m), 0u32);
+        assert_eq!(Integer::ZERO.mod_sub(&x, &m), neg);
+        assert_eq!(Integer::ZERO.mod_neg(&m), 0u32);
+        assert_eq!((&m).mod_neg(&m), 0u32);
+        assert_eq!((-&m).mod_neg(&m), 0u32);
+    });
+
+    integer_gen().test_properties(|x| {
+        assert_eq!(x.mod_neg(Integer::ONE), 0u32);
+    });
+}
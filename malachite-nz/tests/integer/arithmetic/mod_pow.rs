@@ -0,0 +1,175 @@
+use malachite_base::num::arithmetic::traits::{
+    CoprimeWith, Mod, ModInverse, ModMul, ModPow, ModPowAssign, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_pair_gen_var_8, integer_triple_gen_var_4};
+use std::str::FromStr;
+
+#[test]
+fn test_mod_pow() {
+    let test = |r, s, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let v = Integer::from_str(s).unwrap();
+        let m = Integer::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_pow_assign(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_pow_assign(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_pow_assign(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_pow_assign(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_pow(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_pow(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_pow(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_pow(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_pow(v.clone(), m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_pow(v.clone(), &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_pow(&v, m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_pow(&v, &m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0", "1", "0");
+    test("-5", "-3", "1", "0");
+    test("0", "0", "10", "1");
+    test("0", "5", "10", "0");
+    test("-4", "13", "497", "52");
+    test("3", "-1", "10", "7");
+    test("-3", "-2", "10", "9");
+    test("-2", "10", "1000", "24");
+    test("-2", "11", "1000", "952");
+    test(
+        "-987654321098765432109876543210",
+        "123456789012345678901234567890",
+        "1000000007",
+        "597146046",
+    );
+    test(
+        "-18446744073709551616",
+        "-12345",
+        "18446744073709551617",
+        "1",
+    );
+    test(
+        "123456789012345678901234567890",
+        "-1180591620717411303424",
+        "170141183460469231731687303715884105727",
+        "24716643420246501211126262902578380715",
+    );
+    test(
+        "-3",
+        "1180591620717411303424",
+        "1267650600228229401496703205376",
+        "684805989991619068094069080065",
+    );
+    test(
+        "-3",
+        "-1180591620717411303424",
+        "1267650600228229401496703205376",
+        "582844610236610333402634125313",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_pow_fail_1() {
+    Integer::from(3).mod_pow(Integer::ONE, Integer::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mod_pow_fail_2() {
+    Integer::from(3).mod_pow(Integer::ONE, Integer::from(-7));
+}
+
+#[test]
+#[should_panic]
+fn mod_pow_fail_3() {
+    Integer::from(-4).mod_pow(Integer::from(-1), Integer::from(10));
+}
+
+#[test]
+#[should_panic]
+fn mod_pow_assign_fail() {
+    let mut x = Integer::ZERO;
+    x.mod_pow_assign(Integer::from(-1), Integer::from(10));
+}
+
+#[test]
+fn mod_pow_properties() {
+    integer_triple_gen_var_4().test_properties(|(x, exp, m)| {
+        let invertible = x.unsigned_abs_ref().coprime_with(m.unsigned_abs_ref());
+        if exp < 0u32 && !invertible {
+            return;
+        }
+        let power = (&x).mod_pow(&exp, &m);
+        assert!(power.is_valid());
+        assert!(power >= 0u32);
+        assert!(power < m);
+
+        let mut x_alt = x.clone();
+        x_alt.mod_pow_assign(&exp, &m);
+        assert_eq!(x_alt, power);
+
+        assert_eq!((&x + &m).mod_pow(&exp, &m), power);
+        let abs_power = Integer::from(
+            (&x).mod_op(&m)
+                .unsigned_abs()
+                .mod_pow(exp.unsigned_abs_ref(), m.unsigned_abs_ref()),
+        );
+        if exp >= 0u32 {
+            assert_eq!(power, abs_power);
+        } else {
+            assert_eq!(power, (&x).mod_inverse(&m).unwrap().mod_pow(-&exp, &m));
+            assert_eq!(power.mod_mul(abs_power, &m), Integer::ONE.mod_op(&m));
+        }
+        if invertible {
+            assert_eq!(
+                (&x).mod_pow(-&exp, &m).mod_mul((&x).mod_pow(&exp, &m), &m),
+                Integer::ONE.mod_op(&m)
+            );
+        }
+    });
+
+    integer_pair_gen_var_8().test_properties(|(x, m)| {
+        assert_eq!((&x).mod_pow(Integer::ZERO, &m), Integer::ONE.mod_op(&m));
+        assert_eq!((&x).mod_pow(Integer::ONE, &m), (&x).mod_op(&m));
+        assert_eq!((&x).mod_pow(Integer::from(2u32), &m), (&x).mod_mul(&x, &m));
+    });
+}
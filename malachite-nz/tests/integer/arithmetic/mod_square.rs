@@ -0,0 +1,106 @@
+use malachite_base::num::arithmetic::traits::{Mod, ModMul, ModSquare, ModSquareAssign};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::{integer_gen, integer_pair_gen_var_8};
+use std::str::FromStr;
+
+#[test]
+fn test_mod_square() {
+    let test = |r, t, out| {
+        let u = Integer::from_str(r).unwrap();
+        let m = Integer::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n.mod_square_assign(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.mod_square_assign(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_square(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mod_square(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_square(m.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mod_square(&m);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        assert_eq!((&u * &u).mod_op(m).to_string(), out);
+    };
+    test("0", "1", "0");
+    test("-5", "1", "0");
+    test("3", "7", "2");
+    test("-3", "7", "2");
+    test("14", "7", "0");
+    test("-1", "2", "1");
+    test("-987654321098765432109876543210", "1000000007", "164865552");
+    test("-18446744073709551616", "18446744073709551617", "1");
+    test(
+        "123456789012345678901234567890",
+        "170141183460469231731687303715884105727",
+        "76266579520188412428754099219286390388",
+    );
+    test(
+        "-123456789012345678901234567890",
+        "1267650600228229401496703205376",
+        "1214924082075298115044309537860",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_square_fail_1() {
+    Integer::from(3).mod_square(Integer::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn mod_square_fail_2() {
+    Integer::from(3).mod_square(Integer::from(-7));
+}
+
+#[test]
+#[should_panic]
+fn mod_square_assign_fail() {
+    let mut x = Integer::from(3);
+    x.mod_square_assign(Integer::ZERO);
+}
+
+#[test]
+fn mod_square_properties() {
+    integer_pair_gen_var_8().test_properties(|(x, m)| {
+        let square = (&x).mod_square(&m);
+        assert!(square.is_valid());
+        assert!(square >= 0u32);
+        assert!(square < m);
+
+        let mut x_alt = x.clone();
+        x_alt.mod_square_assign(&m);
+        assert_eq!(x_alt, square);
+
+        assert_eq!((&x * &x).mod_op(&m), square);
+        assert_eq!((&x).mod_mul(&x, &m), square);
+        assert_eq!((-&x).mod_square(&m), square);
+        assert_eq!(Integer::ZERO.mod_square(&m), 0u32);
+        assert_eq!((&m).mod_square(&m), 0u32);
+        assert_eq!(
+            Integer::NEGATIVE_ONE.mod_square(&m),
+            Integer::ONE.mod_op(&m)
+        );
+    });
+
+    integer_gen().test_properties(|x| {
+        assert_eq!(x.mod_square(Integer::ONE), 0u32);
+    });
+}
@@ -1,5 +1,6 @@
 use malachite_base::num::arithmetic::traits::{DivMod, Square};
 use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::strings::ToDebugString;
 use malachite_base::test_util::generators::signed_pair_gen;
 use malachite_base::vecs::vec_from_str;
 use malachite_nz::integer::Integer;
@@ -132,6 +133,27 @@ fn test_product() {
         "[123456, -789012, 345678, -9012345]",
         "303462729062737285547520",
     );
+    test(
+        "[-4294967295, 4294967295, -4294967295, 18446744073709551615, 3]",
+        "-4384504908930167452797977302174744082046750031875",
+    );
+}
+
+#[test]
+fn test_product_tree() {
+    let test = |xs, out| {
+        let xs = vec_from_str::<Integer>(xs).unwrap();
+        let tree = Integer::product_tree(&xs);
+        assert!(tree.iter().flatten().all(Integer::is_valid));
+        assert_eq!(tree.to_debug_string(), out);
+    };
+    test("[]", "[]");
+    test("[-10]", "[[-10]]");
+    test("[6, -2]", "[[6, -2], [-12]]");
+    test(
+        "[2, -3, 5, -7, 11]",
+        "[[2, -3, 5, -7, 11], [-6, -35, 11], [210, 11], [2310]]",
+    );
 }
 
 #[test]
@@ -247,3 +269,36 @@ fn product_properties() {
         );
     });
 }
+
+#[test]
+fn product_tree_properties() {
+    integer_vec_gen().test_properties(|xs| {
+        let tree = Integer::product_tree(&xs);
+        assert!(tree.iter().flatten().all(Integer::is_valid));
+        if xs.is_empty() {
+            assert!(tree.is_empty());
+            return;
+        }
+        assert_eq!(tree[0], xs);
+        for (level, next) in tree.iter().zip(tree.iter().skip(1)) {
+            assert_eq!(next.len(), (level.len() + 1) >> 1);
+            assert_eq!(
+                Integer::product(next.iter()),
+                Integer::product(level.iter())
+            );
+        }
+        assert_eq!(tree.last().unwrap().len(), 1);
+        assert_eq!(tree.last().unwrap()[0], Integer::product(xs.into_iter()));
+    });
+
+    natural_vec_gen().test_properties(|xs| {
+        let tree = Integer::product_tree(&xs.iter().map(Integer::from).collect::<Vec<_>>());
+        assert_eq!(
+            tree,
+            Natural::product_tree(&xs)
+                .into_iter()
+                .map(|level| level.into_iter().map(Integer::from).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+    });
+}
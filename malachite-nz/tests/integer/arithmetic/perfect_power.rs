@@ -0,0 +1,110 @@
+use malachite_base::num::arithmetic::traits::{
+    IsPerfectPower, Parity, PerfectPowerDecompose, Pow, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::{integer_gen, integer_unsigned_pair_gen_var_3};
+use std::str::FromStr;
+
+#[test]
+fn test_perfect_power_decompose() {
+    let test = |s, root, exp| {
+        let n = Integer::from_str(s).unwrap();
+        let (root_alt, exp_alt) = n.clone().perfect_power_decompose();
+        assert!(root_alt.is_valid());
+        assert_eq!(root_alt.to_string(), root);
+        assert_eq!(exp_alt, exp);
+
+        let (root_alt, exp_alt) = (&n).perfect_power_decompose();
+        assert!(root_alt.is_valid());
+        assert_eq!(root_alt.to_string(), root);
+        assert_eq!(exp_alt, exp);
+
+        assert_eq!(n.is_perfect_power(), exp > 1 || n == 0 || n == 1 || n == -1);
+        assert_eq!(
+            rug::Integer::from_str(s).unwrap().is_perfect_power(),
+            n.is_perfect_power()
+        );
+    };
+    test("0", "0", 1);
+    test("1", "1", 1);
+    test("-1", "-1", 1);
+    test("-8", "-2", 3);
+    test("-16", "-16", 1);
+    test("-64", "-4", 3);
+    test("1000", "10", 3);
+    test("-1000", "-10", 3);
+    test(
+        "-340282366920938463463374607431768211456",
+        "-340282366920938463463374607431768211456",
+        1,
+    );
+    test(
+        "-6277101735386680763835789423207666416102355444464034512896",
+        "-18446744073709551616",
+        3,
+    );
+    test(
+        "-1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000\
+        000000000000",
+        "-10",
+        99,
+    );
+    test(
+        "-3465837117651018563951546959352081782039555031571287326149653120011072614878757592034908\
+        29304450280262388005092129923564046385151",
+        "-2305843009213693951",
+        7,
+    );
+}
+
+#[test]
+fn perfect_power_decompose_properties() {
+    integer_gen().test_properties(|n| {
+        let (root, exp) = n.clone().perfect_power_decompose();
+        assert!(root.is_valid());
+        assert_eq!((&n).perfect_power_decompose(), (root.clone(), exp));
+        assert_ne!(exp, 0);
+        assert_eq!((&root).pow(exp), n);
+        assert_eq!(root >= 0, n >= 0);
+        if n < 0 {
+            assert!(exp.odd());
+        }
+        let is_perfect_power = n.is_perfect_power();
+        let trivial = n == Integer::ZERO || n == Integer::ONE || n == Integer::NEGATIVE_ONE;
+        assert_eq!(is_perfect_power, exp > 1 || trivial);
+        assert_eq!(rug::Integer::from(&n).is_perfect_power(), is_perfect_power);
+        if !trivial {
+            assert!(!root.is_perfect_power());
+        }
+        if n >= 0 {
+            let (root_alt, exp_alt) = (&n).unsigned_abs().perfect_power_decompose();
+            assert_eq!(root_alt, root.unsigned_abs());
+            assert_eq!(exp_alt, exp);
+        }
+    });
+
+    integer_unsigned_pair_gen_var_3().test_properties(|(b, exp)| {
+        if b == 0 || b == 1 || b == -1 {
+            return;
+        }
+        let n = (&b).pow(exp);
+        let (root, exp_alt) = (&n).perfect_power_decompose();
+        assert_eq!(exp_alt % exp, 0);
+        assert_eq!(root.pow(exp_alt / exp).unsigned_abs(), b.unsigned_abs());
+        if exp > 1 {
+            assert!(n.is_perfect_power());
+        }
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|i| {
+        let (root, exp) = i.perfect_power_decompose();
+        assert_eq!(
+            Integer::from(i).perfect_power_decompose(),
+            (Integer::from(root), exp)
+        );
+        assert_eq!(Integer::from(i).is_perfect_power(), i.is_perfect_power());
+    });
+}
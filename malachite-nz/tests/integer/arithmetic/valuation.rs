@@ -0,0 +1,80 @@
+use malachite_base::num::arithmetic::traits::{Pow, RemoveFactor, Valuation};
+use malachite_base::num::basic::traits::One;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen_var_3;
+use std::str::FromStr;
+
+#[test]
+fn test_valuation() {
+    let test = |n, p, out: &str| {
+        let n = Integer::from_str(n).unwrap();
+        let p = Natural::from_str(p).unwrap();
+        let (cofactor, v) = (&n).remove_factor(&p);
+        assert!(cofactor.is_valid());
+        assert_eq!((cofactor.clone(), v).to_debug_string(), out);
+        assert_eq!(n.clone().remove_factor(&p), (cofactor, v));
+        assert_eq!((&n).valuation(&p), v);
+        assert_eq!(n.valuation(&p), v);
+    };
+    test("1", "2", "(1, 0)");
+    test("-1", "2", "(-1, 0)");
+    test("12", "2", "(3, 2)");
+    test("-12", "2", "(-3, 2)");
+    test("-12", "3", "(-4, 1)");
+    test("-12", "5", "(-12, 0)");
+    test("-1000000000000", "100", "(-1, 6)");
+    test("-18446744073709551616", "4", "(-1, 32)");
+    test(
+        "-515377520732011331036461129765621272702107522001",
+        "9",
+        "(-1, 50)",
+    );
+    test(
+        "-515377520732011331036461129765621272702107522001",
+        "27",
+        "(-3, 33)",
+    );
+}
+
+#[test]
+#[should_panic]
+fn valuation_fail_1() {
+    Integer::from(0).valuation(&Natural::from(2u32));
+}
+
+#[test]
+#[should_panic]
+fn valuation_fail_2() {
+    Integer::from(-10).valuation(&Natural::ONE);
+}
+
+#[test]
+#[should_panic]
+fn remove_factor_fail_1() {
+    Integer::from(0).remove_factor(&Natural::from(2u32));
+}
+
+#[test]
+#[should_panic]
+fn remove_factor_fail_2() {
+    (&Integer::from(-10)).remove_factor(&Natural::ONE);
+}
+
+#[test]
+fn valuation_properties() {
+    natural_pair_gen_var_3().test_properties(|(n, p)| {
+        let (cofactor, v) = (&n).remove_factor(&p);
+        let x = Integer::from(n);
+        assert_eq!((&x).remove_factor(&p), (Integer::from(&cofactor), v));
+        assert_eq!((&x).valuation(&p), v);
+
+        let (neg_cofactor, neg_v) = (-&x).remove_factor(&p);
+        assert!(neg_cofactor.is_valid());
+        assert_eq!(neg_cofactor, -Integer::from(cofactor));
+        assert_eq!(neg_v, v);
+        assert_eq!((-&x).valuation(&p), v);
+        assert_eq!(neg_cofactor * Integer::from(p.pow(v)), -x);
+    });
+}
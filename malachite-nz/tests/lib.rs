@@ -50,6 +50,8 @@ pub mod integer {
         pub mod add;
         pub mod add_mul;
         pub mod binomial_coefficient;
+        pub mod coprime_with;
+        pub mod crt;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
@@ -59,12 +61,23 @@ pub mod integer {
         pub mod eq_mod;
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
+        pub mod gcd;
         pub mod kronecker_symbol;
+        pub mod lcm;
+        pub mod mod_add;
+        pub mod mod_centered;
+        pub mod mod_inverse;
+        pub mod mod_mul;
+        pub mod mod_neg;
         pub mod mod_op;
+        pub mod mod_pow;
         pub mod mod_power_of_2;
+        pub mod mod_square;
+        pub mod mod_sub;
         pub mod mul;
         pub mod neg;
         pub mod parity;
+        pub mod perfect_power;
         pub mod pow;
         pub mod power_of_2;
         pub mod root;
@@ -79,6 +92,7 @@ pub mod integer {
         pub mod square;
         pub mod sub;
         pub mod sub_mul;
+        pub mod valuation;
     }
     pub mod basic {
         pub mod constants;
@@ -190,19 +204,26 @@ pub mod natural {
         pub mod add;
         pub mod add_mul;
         pub mod binomial_coefficient;
+        pub mod carmichael_lambda;
+        pub mod catalan;
         pub mod checked_sub;
         pub mod checked_sub_mul;
         pub mod coprime_with;
+        pub mod crt;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
         pub mod div_round;
         pub mod divisible_by;
         pub mod divisible_by_power_of_2;
+        pub mod divisor_sigma;
+        pub mod divisors;
         pub mod eq_mod;
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
+        pub mod fixed_base_mod_pow;
         pub mod gcd;
         pub mod is_power_of_2;
         pub mod kronecker_symbol;
@@ -210,10 +231,13 @@ pub mod natural {
         pub mod log_base;
         pub mod log_base_2;
         pub mod log_base_power_of_2;
+        pub mod lucas;
         pub mod mod_add;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_log;
         pub mod mod_mul;
+        pub mod mod_multi_pow;
         pub mod mod_neg;
         pub mod mod_op;
         pub mod mod_pow;
@@ -230,15 +254,24 @@ pub mod natural {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
+        pub mod moebius_mu;
+        pub mod montgomery;
         pub mod mul;
+        pub mod multiplicative_order;
         pub mod neg;
         pub mod next_power_of_2;
         pub mod parity;
+        pub mod partition_count;
+        pub mod perfect_power;
         pub mod pow;
         pub mod power_of_2;
+        pub mod precomputed_divisor;
+        pub mod primitive_root;
         pub mod primorial;
+        pub mod rational_reconstruction;
         pub mod root;
         pub mod round_to_multiple;
         pub mod round_to_multiple_of_power_of_2;
@@ -251,8 +284,11 @@ pub mod natural {
         pub mod sign;
         pub mod sqrt;
         pub mod square;
+        pub mod stirling;
         pub mod sub;
         pub mod sub_mul;
+        pub mod totient;
+        pub mod valuation;
     }
     pub mod basic {
         pub mod constants;
@@ -309,7 +345,13 @@ pub mod natural {
         pub mod exhaustive_positive_naturals;
     }
     pub mod factorization {
+        pub mod factor;
+        pub mod is_prime;
+        pub mod next_prime;
+        pub mod previous_prime;
+        pub mod prime_count;
         pub mod primes;
+        pub mod primes_in_range;
     }
     pub mod logic {
         pub mod and;
@@ -346,6 +388,10 @@ pub mod natural {
         pub mod random_naturals;
         pub mod random_naturals_less_than;
         pub mod random_positive_naturals;
+        pub mod random_primes_in_inclusive_range;
+        pub mod random_primes_in_range;
+        pub mod random_primes_with_bits;
+        pub mod random_safe_primes_with_bits;
         pub mod striped_random_natural_inclusive_range;
         pub mod striped_random_natural_range;
         pub mod striped_random_natural_range_to_infinity;
@@ -1,8 +1,14 @@
-use malachite_base::num::arithmetic::traits::{BinomialCoefficient, DivExact, Gcd};
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, BinomialCoefficientMod, CheckedMultinomialCoefficient, DivExact, Gcd,
+    MultinomialCoefficient, MultinomialCoefficientMod, Pow,
+};
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::test_util::generators::common::GenConfig;
-use malachite_base::test_util::generators::{unsigned_pair_gen_var_28, unsigned_pair_gen_var_44};
+use malachite_base::test_util::generators::{
+    unsigned_pair_gen_var_28, unsigned_pair_gen_var_44, unsigned_triple_gen_var_19,
+    unsigned_vec_gen,
+};
 use malachite_nz::natural::arithmetic::binomial_coefficient::*;
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
@@ -631,6 +637,125 @@ fn test_binomial_coefficient() {
     }
 }
 
+#[test]
+fn test_binomial_coefficient_mod() {
+    fn test(n: &str, k: &str, m: &str, out: &str) {
+        let n = Natural::from_str(n).unwrap();
+        let k = Natural::from_str(k).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let b = Natural::binomial_coefficient_mod(n.clone(), k.clone(), m.clone());
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+
+        let b = Natural::binomial_coefficient_mod(&n, &k, &m);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+    }
+    test("0", "0", "1", "0");
+    test("0", "0", "10", "1");
+    test("4", "2", "10", "6");
+    test("1", "2", "10", "0");
+    test("10", "5", "100", "52");
+    test("1000000", "500000", "1000000007", "996692777");
+    test("100000", "50000", "1024", "576");
+    test("100", "50", "18446744073709551616", "1184508333840160104");
+    test("100", "50", "18446744073709551629", "1184508262738860393");
+    test(
+        "1000",
+        "300",
+        "36472996377170786403",
+        "30238233516340071219",
+    );
+    test(
+        "1000000000000000000000",
+        "1000",
+        "2305843009213693951",
+        "33507485050496592",
+    );
+    test(
+        "1000000000000000000000",
+        "1000",
+        "18446744073709551616",
+        "5579017440364068864",
+    );
+    test(
+        "1000000000000000000000",
+        "999999999999999999997",
+        "18446744073709551629",
+        "10876530469556503516",
+    );
+}
+
+#[test]
+#[should_panic]
+fn binomial_coefficient_mod_fail() {
+    Natural::binomial_coefficient_mod(Natural::from(2u32), Natural::ONE, Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn binomial_coefficient_mod_ref_fail() {
+    Natural::binomial_coefficient_mod(&Natural::from(2u32), &Natural::ONE, &Natural::ZERO);
+}
+
+#[test]
+fn test_multinomial_coefficient() {
+    fn test(ks: &[u32], out: &str) {
+        let ks = ks.iter().copied().map(Natural::from).collect::<Vec<_>>();
+        let x = Natural::multinomial_coefficient(&ks);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    }
+    test(&[], "1");
+    test(&[0], "1");
+    test(&[5], "1");
+    test(&[2, 3], "10");
+    test(&[1, 2, 3], "60");
+    test(&[20, 20, 20], "577831214478475823831865900");
+    test(&[10, 20, 30], "3553261127084984957001360");
+}
+
+#[test]
+fn test_multinomial_coefficient_mod() {
+    fn test(ks: &[u32], m: &str, out: &str) {
+        let ks = ks.iter().copied().map(Natural::from).collect::<Vec<_>>();
+        let m = Natural::from_str(m).unwrap();
+        let x = Natural::multinomial_coefficient_mod(&ks, m.clone());
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+
+        let x = Natural::multinomial_coefficient_mod(&ks, &m);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    }
+    test(&[], "1", "0");
+    test(&[], "10", "1");
+    test(&[1, 2, 3], "7", "4");
+    test(&[100, 200, 300], "1000000007", "756111920");
+    test(
+        &[10, 20, 30],
+        "18446744073709551629",
+        "12390118903703120122",
+    );
+    test(
+        &[100, 200, 300],
+        "36472996377170786403",
+        "21651510021559615890",
+    );
+}
+
+#[test]
+#[should_panic]
+fn multinomial_coefficient_mod_fail() {
+    Natural::multinomial_coefficient_mod(&[Natural::ONE, Natural::from(2u32)], Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn multinomial_coefficient_mod_ref_fail() {
+    Natural::multinomial_coefficient_mod(&[Natural::ONE, Natural::from(2u32)], &Natural::ZERO);
+}
+
 #[test]
 fn limbs_binomial_coefficient_limb_limb_bdiv_properties() {
     let mut config = GenConfig::new();
@@ -762,3 +887,66 @@ fn binomial_coefficient_properties() {
         );
     });
 }
+
+#[test]
+fn binomial_coefficient_mod_properties() {
+    // The moduli are larger than a limb, and include a prime, a prime power, and a composite.
+    let moduli = [
+        Natural::from_str("18446744073709551629").unwrap(),
+        Natural::from(3u32).pow(41),
+        Natural::from_str("36893488147419103232").unwrap() * Natural::from(15u32),
+    ];
+    natural_pair_gen_var_15().test_properties(|(n, k)| {
+        for m in &moduli {
+            let b = Natural::binomial_coefficient_mod(&n, &k, m);
+            assert!(b.is_valid());
+            assert_eq!(
+                Natural::binomial_coefficient_mod(n.clone(), k.clone(), m.clone()),
+                b
+            );
+            assert!(b < *m);
+            assert_eq!(Natural::binomial_coefficient(&n, &k) % m, b);
+        }
+    });
+
+    unsigned_triple_gen_var_19::<u8>().test_properties(|(n, k, m)| {
+        if m == 0 {
+            return;
+        }
+        assert_eq!(
+            Natural::binomial_coefficient_mod(Natural::from(n), Natural::from(k), Natural::from(m)),
+            Natural::binomial_coefficient(Natural::from(n), Natural::from(k)) % Natural::from(m)
+        );
+    });
+}
+
+#[test]
+fn multinomial_coefficient_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|ks| {
+        let ks_alt = ks.iter().copied().map(Limb::from).collect::<Vec<_>>();
+        let ks = ks.into_iter().map(Natural::from).collect::<Vec<_>>();
+        let x = Natural::multinomial_coefficient(&ks);
+        assert!(x.is_valid());
+        assert_ne!(x, 0u32);
+        if let Some(y) = Limb::checked_multinomial_coefficient(&ks_alt) {
+            assert_eq!(x, y);
+        }
+        let mut ks_rev = ks.clone();
+        ks_rev.reverse();
+        assert_eq!(Natural::multinomial_coefficient(&ks_rev), x);
+        let n = ks.iter().sum::<Natural>();
+        if let Some(k) = ks.first() {
+            assert!(k <= &n);
+            assert_eq!(
+                Natural::multinomial_coefficient(&ks[1..]) * Natural::binomial_coefficient(&n, k),
+                x
+            );
+        }
+
+        let m = Natural::from(3u32).pow(41);
+        let y = Natural::multinomial_coefficient_mod(&ks, &m);
+        assert!(y.is_valid());
+        assert_eq!(Natural::multinomial_coefficient_mod(&ks, m.clone()), y);
+        assert_eq!(x % m, y);
+    });
+}
@@ -0,0 +1,67 @@
+use malachite_base::num::arithmetic::traits::{CarmichaelLambda, DivisibleBy, ModPow, Totient};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, Primes};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_carmichael_lambda() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let out = Natural::from_str(out).unwrap();
+        assert_eq!(n.carmichael_lambda(), out);
+        assert_eq!(Natural::carmichael_lambda_from_factors(&n.factor()), out);
+    };
+    test("1", "1");
+    test("2", "1");
+    test("8", "2");
+    test("12", "2");
+    test("561", "80");
+    test("1000000007", "1000000006");
+    test("18446744073709551615", "17153064960");
+    test(
+        "1267650600228229401496703205376",
+        "316912650057057350374175801344",
+    );
+    // 2^128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        "664613997892457925309815931948264960",
+    );
+    // 10^30 - 1
+    test("999999999999999999999999999999", "18491896080");
+}
+
+#[test]
+#[should_panic]
+fn carmichael_lambda_fail() {
+    Natural::from(0u32).carmichael_lambda();
+}
+
+#[test]
+fn carmichael_lambda_properties() {
+    unsigned_vec_gen::<u16>().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        let factors = n.factor();
+        let lambda = n.carmichael_lambda();
+        assert_eq!(Natural::carmichael_lambda_from_factors(&factors), lambda);
+        assert!(Natural::totient_from_factors(&factors).divisible_by(&lambda));
+        if n > 2u32 {
+            let a = &n - Natural::ONE;
+            assert_eq!(a.mod_pow(&lambda, &n), 1u32);
+        }
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        assert_eq!(Natural::from(n).carmichael_lambda(), n.carmichael_lambda());
+    });
+
+    for p in Natural::primes().take(100) {
+        assert_eq!(p.carmichael_lambda(), &p - Natural::ONE);
+    }
+}
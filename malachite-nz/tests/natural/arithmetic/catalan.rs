@@ -0,0 +1,49 @@
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, Catalan};
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::catalan::catalan_naive;
+
+#[test]
+fn test_catalan() {
+    fn test(n: u64, out: &str) {
+        let c = Natural::catalan(n);
+        assert!(c.is_valid());
+        assert_eq!(c.to_string(), out);
+        assert_eq!(catalan_naive(n), c);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "5");
+    test(5, "42");
+    test(20, "6564120420");
+    test(35, "3116285494907301262");
+    test(36, "11959798385860453492");
+    test(
+        200,
+        "512201493211017079467541693136328292324432464582475861864920694407578768023144072628540276\
+        213813397768975366156750120",
+    );
+}
+
+#[test]
+fn catalan_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let c = Natural::catalan(n);
+        assert!(c.is_valid());
+        assert_eq!(catalan_naive(n), c);
+        assert_ne!(c, 0u32);
+        assert_eq!(
+            Natural::catalan(n + 1) * Natural::from(n + 2),
+            &c * Natural::from(4 * n + 2)
+        );
+        let n = Natural::from(n);
+        assert_eq!(
+            Natural::binomial_coefficient(&n << 1, n.clone())
+                - Natural::binomial_coefficient(&n << 1, n + Natural::ONE),
+            c
+        );
+    });
+}
@@ -0,0 +1,180 @@
+use malachite_base::num::arithmetic::traits::{Crt, CrtPrecomputed, Gcd, Lcm};
+use malachite_base::num::basic::traits::One;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_19;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_triple_gen, natural_vec_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn parse_pairs(congruences: &[(&str, &str)]) -> Vec<(Natural, Natural)> {
+    congruences
+        .iter()
+        .map(|&(r, m)| (Natural::from_str(r).unwrap(), Natural::from_str(m).unwrap()))
+        .collect()
+}
+
+fn parse(xs: &[&str]) -> Vec<Natural> {
+    xs.iter().map(|x| Natural::from_str(x).unwrap()).collect()
+}
+
+#[test]
+fn test_crt() {
+    let test = |congruences: &[(&str, &str)], out: &str| {
+        assert_eq!(
+            Natural::crt(&parse_pairs(congruences)).to_debug_string(),
+            out
+        );
+    };
+    test(&[], "Some((0, 1))");
+    test(&[("0", "1")], "Some((0, 1))");
+    test(&[("5", "1")], "Some((0, 1))");
+    test(&[("12", "7")], "Some((5, 7))");
+    test(&[("2", "3"), ("3", "5"), ("2", "7")], "Some((23, 105))");
+    test(&[("3", "4"), ("5", "6")], "Some((11, 12))");
+    test(&[("3", "4"), ("4", "6")], "None");
+    test(&[("1", "6"), ("1", "10"), ("1", "15")], "Some((1, 30))");
+    test(&[("2", "12"), ("5", "9"), ("14", "18")], "Some((14, 36))");
+    test(&[("6", "8"), ("2", "4")], "Some((6, 8))");
+    test(
+        &[("1", "18446744073709551557"), ("2", "18446744073709551533")],
+        "Some((269390207145742948168885365600372308430, \
+        340282366920938460843936948965011886881))",
+    );
+    test(
+        &[
+            ("1", "340282366920938463463374607431768211456"),
+            (
+                "340282366920938463463374607431768211455",
+                "18446744073709551616",
+            ),
+        ],
+        "None",
+    );
+    test(
+        &[
+            ("1", "340282366920938463463374607431768211456"),
+            ("1", "18446744073709551616"),
+            ("0", "3"),
+        ],
+        "Some((680564733841876926926749214863536422913, \
+        1020847100762815390390123822295304634368))",
+    );
+}
+
+#[test]
+fn crt_fail() {
+    assert_panic!(Natural::crt(&[(Natural::ONE, Natural::from(0u32))]));
+}
+
+#[test]
+fn test_crt_precomputed() {
+    let test = |moduli: &[&str], residues: &[&str], modulus: &str, out: &str| {
+        let moduli = parse(moduli);
+        let basis = Natural::precompute_crt_data(&moduli).unwrap();
+        assert_eq!(basis.moduli(), moduli);
+        assert_eq!(basis.modulus().to_string(), modulus);
+        assert_eq!(
+            Natural::crt_precomputed(&parse(residues), &basis).to_string(),
+            out
+        );
+    };
+    test(&[], &[], "1", "0");
+    test(&["1"], &["5"], "1", "0");
+    test(&["1", "7", "1"], &["3", "12", "5"], "7", "5");
+    test(&["3", "5", "7"], &["2", "3", "2"], "105", "23");
+    test(&["3", "5", "7"], &["5", "8", "9"], "105", "23");
+    test(&["4", "9", "25"], &["3", "8", "24"], "900", "899");
+    test(
+        &["18446744073709551557", "18446744073709551533"],
+        &["1", "2"],
+        "340282366920938460843936948965011886881",
+        "269390207145742948168885365600372308430",
+    );
+
+    let test_none = |moduli: &[&str]| {
+        assert!(Natural::precompute_crt_data(&parse(moduli)).is_none());
+    };
+    test_none(&["2", "2"]);
+    test_none(&["4", "6"]);
+    test_none(&["3", "5", "21"]);
+}
+
+#[test]
+fn crt_precomputed_fail() {
+    assert_panic!(Natural::precompute_crt_data(&[Natural::from(0u32)]));
+    let basis = Natural::precompute_crt_data(&[Natural::from(2u32), Natural::from(3u32)]).unwrap();
+    assert_panic!(Natural::crt_precomputed(&[Natural::ONE], &basis));
+}
+
+#[test]
+fn crt_properties() {
+    natural_triple_gen().test_properties(|(x, a, b)| {
+        if a == 0u32 || b == 0u32 {
+            return;
+        }
+        let lcm = (&a).lcm(&b);
+        let result = Natural::crt(&[(x.clone(), a.clone()), (x.clone(), b.clone())]);
+        assert_eq!(result, Some((&x % &lcm, lcm)));
+        let congruences = [(&x % &a, a.clone()), (&x % &b, b.clone())];
+        assert_eq!(Natural::crt(&congruences), result);
+        assert_eq!(
+            Natural::crt(&[congruences[1].clone(), congruences[0].clone()]),
+            result
+        );
+        let g = (&a).gcd(&b);
+        if g != 1u32 {
+            let y = &x % &a + Natural::ONE;
+            if &y % &g != &x % &g {
+                assert!(Natural::crt(&[(y, a), (x, b)]).is_none());
+            }
+        }
+    });
+
+    natural_vec_gen().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.is_empty() || xs[1..].contains(&Natural::from(0u32)) {
+            return;
+        }
+        let x = &xs[0];
+        let moduli = &xs[1..];
+        let congruences: Vec<(Natural, Natural)> =
+            moduli.iter().map(|m| (x % m, m.clone())).collect();
+        let lcm = moduli.iter().fold(Natural::ONE, Lcm::lcm);
+        let result = Natural::crt(&congruences);
+        assert_eq!(result, Some((x % &lcm, lcm.clone())));
+        if let Some(basis) = Natural::precompute_crt_data(moduli) {
+            assert_eq!(*basis.modulus(), lcm);
+            let residues: Vec<Natural> = moduli.iter().map(|m| x % m).collect();
+            assert_eq!(Natural::crt_precomputed(&residues, &basis), x % &lcm);
+            assert_eq!(
+                Natural::crt_precomputed(&xs[..moduli.len()], &basis),
+                Natural::crt(
+                    &xs[..moduli.len()]
+                        .iter()
+                        .cloned()
+                        .zip(moduli.iter().cloned())
+                        .collect::<Vec<_>>()
+                )
+                .unwrap()
+                .0
+            );
+        }
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties(|(x, a, b)| {
+        if a == 0 || b == 0 {
+            return;
+        }
+        let congruences = [(u64::from(x), u64::from(a)), (u64::from(x) + 1, u64::from(b))];
+        assert_eq!(
+            Natural::crt(
+                &congruences
+                    .iter()
+                    .map(|&(r, m)| (Natural::from(r), Natural::from(m)))
+                    .collect::<Vec<_>>()
+            ),
+            u64::crt(&congruences).map(|(r, m)| (Natural::from(r), Natural::from(m)))
+        );
+    });
+}
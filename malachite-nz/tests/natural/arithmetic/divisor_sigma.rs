@@ -0,0 +1,128 @@
+use malachite_base::num::arithmetic::traits::{DivisorCount, DivisorSigma, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, Primes};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_divisor_count() {
+    let test = |n: &str, out: u64| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.divisor_count(), out);
+        assert_eq!(Natural::divisor_count_from_factors(&n.factor()), out);
+    };
+    test("1", 1);
+    test("2", 2);
+    test("12", 6);
+    test("1000000007", 2);
+    test("18446744073709551615", 128);
+    test("18446744073709551616", 65);
+    test("1267650600228229401496703205376", 101);
+    // 2^128 + 1
+    test("340282366920938463463374607431768211457", 4);
+    // 10^30 - 1
+    test("999999999999999999999999999999", 16384);
+}
+
+#[test]
+fn test_divisor_sigma() {
+    let test = |n: &str, k: u64, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let out = Natural::from_str(out).unwrap();
+        assert_eq!(n.divisor_sigma(k), out);
+        assert_eq!(Natural::divisor_sigma_from_factors(&n.factor(), k), out);
+    };
+    test("1", 0, "1");
+    test("1", 5, "1");
+    test("12", 0, "6");
+    test("12", 1, "28");
+    test("12", 2, "210");
+    test("1000000007", 1, "1000000008");
+    test(
+        "1267650600228229401496703205376",
+        1,
+        "2535301200456458802993406410751",
+    );
+    test(
+        "1267650600228229401496703205376",
+        2,
+        "2142584059011987034055949456454883470029603991710390447068501",
+    );
+    // 2^128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        1,
+        "340282366920938469168123457706024763396",
+    );
+    // 10^30 - 1
+    test(
+        "999999999999999999999999999999",
+        1,
+        "2188755209566025722285554401280",
+    );
+    test(
+        "999999999999999999999999999999",
+        2,
+        "1166936571352743061224242040912222602517114525777286604800000",
+    );
+}
+
+#[test]
+#[should_panic]
+fn divisor_count_fail() {
+    Natural::from(0u32).divisor_count();
+}
+
+#[test]
+#[should_panic]
+fn divisor_sigma_fail() {
+    Natural::from(0u32).divisor_sigma(1);
+}
+
+#[test]
+fn divisor_count_properties() {
+    unsigned_vec_gen::<u16>().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        let tau = n.divisor_count();
+        assert_eq!(Natural::divisor_count_from_factors(&n.factor()), tau);
+        assert_eq!(n.divisor_sigma(0), tau);
+        assert!(tau <= n);
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        assert_eq!(Natural::from(n).divisor_count(), n.divisor_count());
+    });
+}
+
+#[test]
+fn divisor_sigma_properties() {
+    unsigned_vec_gen::<u16>().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        let factors = n.factor();
+        for k in 0..4 {
+            let sigma = n.divisor_sigma(k);
+            assert_eq!(Natural::divisor_sigma_from_factors(&factors, k), sigma);
+            assert!(sigma >= (&n).pow(k));
+        }
+    });
+
+    unsigned_gen_var_1::<u32>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        assert_eq!(
+            Natural::from(n).divisor_sigma(1),
+            u64::from(n).divisor_sigma(1)
+        );
+    });
+
+    for p in Natural::primes().take(100) {
+        assert_eq!(p.divisor_sigma(1), &p + Natural::ONE);
+        assert_eq!(p.divisor_sigma(2), p.pow(2) + Natural::ONE);
+    }
+}
@@ -0,0 +1,82 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::{DivisibleBy, DivisorCount, DivisorSigma, Divisors};
+use malachite_base::num::factorization::traits::{Factor, Primes};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_divisors() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.divisors().collect_vec().to_debug_string(), out);
+        assert_eq!(
+            Natural::divisors_from_factors(&n.factor())
+                .collect_vec()
+                .to_debug_string(),
+            out
+        );
+    };
+    test("1", "[1]");
+    test("2", "[1, 2]");
+    test("12", "[1, 2, 3, 4, 6, 12]");
+    test("1000000007", "[1, 1000000007]");
+    test(
+        "340282366920938463463374607431768211457",
+        "[1, 59649589127497217, 5704689200685129054721, 340282366920938463463374607431768211457]",
+    );
+    test(
+        "36893488147419103232",
+        "[1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536, \
+        131072, 262144, 524288, 1048576, 2097152, 4194304, 8388608, 16777216, 33554432, \
+        67108864, 134217728, 268435456, 536870912, 1073741824, 2147483648, 4294967296, \
+        8589934592, 17179869184, 34359738368, 68719476736, 137438953472, 274877906944, \
+        549755813888, 1099511627776, 2199023255552, 4398046511104, 8796093022208, \
+        17592186044416, 35184372088832, 70368744177664, 140737488355328, 281474976710656, \
+        562949953421312, 1125899906842624, 2251799813685248, 4503599627370496, \
+        9007199254740992, 18014398509481984, 36028797018963968, 72057594037927936, \
+        144115188075855872, 288230376151711744, 576460752303423488, 1152921504606846976, \
+        2305843009213693952, 4611686018427387904, 9223372036854775808, 18446744073709551616, \
+        36893488147419103232]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn divisors_fail() {
+    Natural::from(0u32).divisors();
+}
+
+#[test]
+fn divisors_properties() {
+    // Products of few u16s have few enough divisors to generate quickly.
+    unsigned_vec_gen::<u16>().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.len() > 3 || xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        let divisors = n.divisors().collect_vec();
+        assert_eq!(
+            Natural::divisors_from_factors(&n.factor()).collect_vec(),
+            divisors
+        );
+        assert_eq!(Natural::from(divisors.len()), n.divisor_count());
+        assert!(divisors.iter().tuple_windows().all(|(d, e)| d < e));
+        assert!(divisors.iter().all(|d| (&n).divisible_by(d)));
+        assert_eq!(divisors.iter().sum::<Natural>(), n.divisor_sigma(1));
+        assert_eq!(divisors.first().unwrap(), &1u32);
+        assert_eq!(divisors.last().unwrap(), &n);
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        assert!(Natural::from(n)
+            .divisors()
+            .eq(n.divisors().map(Natural::from)));
+    });
+
+    for p in Natural::primes().take(100) {
+        assert_eq!(p.divisors().collect_vec(), &[Natural::from(1u32), p]);
+    }
+}
@@ -1,22 +1,26 @@
 use malachite_base::num::arithmetic::traits::{
-    DivRound, DoubleFactorial, Factorial, Multifactorial, Parity, Subfactorial,
+    DivRound, DoubleFactorial, Factorial, FactorialMod, ModMul, Multifactorial, Parity,
+    Subfactorial,
 };
-use malachite_base::num::basic::traits::One;
+use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::{
     unsigned_gen_var_23, unsigned_gen_var_24, unsigned_gen_var_25, unsigned_gen_var_5,
-    unsigned_pair_gen_var_18, unsigned_pair_gen_var_43,
+    unsigned_pair_gen_var_12, unsigned_pair_gen_var_18, unsigned_pair_gen_var_43,
 };
 use malachite_nz::natural::arithmetic::factorial::{limbs_odd_factorial, subfactorial_naive};
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
-use malachite_nz::test_util::generators::unsigned_bool_pair_gen_var_1;
+use malachite_nz::test_util::generators::{
+    natural_unsigned_pair_gen_var_13, unsigned_bool_pair_gen_var_1,
+};
 use malachite_nz::test_util::natural::arithmetic::factorial::{
     double_factorial_naive, factorial_naive, multifactorial_naive,
 };
 use rug::Complete;
+use std::str::FromStr;
 
 #[cfg(not(feature = "32_bit_limbs"))]
 #[test]
@@ -2127,6 +2131,52 @@ fn test_subfactorial() {
     );
 }
 
+#[test]
+fn test_factorial_mod() {
+    fn test(n: u64, m: &str, out: &str) {
+        let m = Natural::from_str(m).unwrap();
+        let f = Natural::factorial_mod(n, m.clone());
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+
+        let f = Natural::factorial_mod(n, &m);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+    }
+    test(0, "1", "0");
+    test(0, "10", "1");
+    test(5, "7", "1");
+    test(10, "10", "0");
+    test(20, "100000000000000000000", "2432902008176640000");
+    test(100, "1000000000000000000000007", "803123216511661322057624");
+    test(1000, "18446744073709551616", "0");
+    test(1000, "18446744073709551629", "8075331241715570358");
+    test(
+        30,
+        "1267650600228229401496703205376",
+        "313884364491113723497510076416",
+    );
+    test(u64::MAX, "1000000007", "0");
+    // The running product becomes 0 long before $i$ reaches $n$.
+    test(60000, "1267650600228229401496703205376", "0");
+    test(1 << 61, "1361129467683753853853498429727072845824", "0");
+    // $m = 2^{70} \cdot 549755813881$ divides $n!$, although the running product would only become
+    // 0 at $i = 549755813881$.
+    test(1 << 40, "649037107308589312221290162028544", "0");
+}
+
+#[test]
+#[should_panic]
+fn factorial_mod_fail() {
+    Natural::factorial_mod(10, Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn factorial_mod_ref_fail() {
+    Natural::factorial_mod(10, &Natural::ZERO);
+}
+
 #[test]
 fn limbs_odd_factorial_properties() {
     let mut config = GenConfig::new();
@@ -2247,3 +2297,30 @@ fn subfactorial_properties() {
         assert_eq!(Natural::subfactorial(n), Limb::subfactorial(n));
     });
 }
+
+#[test]
+fn factorial_mod_properties() {
+    natural_unsigned_pair_gen_var_13::<u64>().test_properties(|(m, n)| {
+        let f = Natural::factorial_mod(n, &m);
+        assert!(f.is_valid());
+        assert_eq!(Natural::factorial_mod(n, m.clone()), f);
+        assert!(f < m);
+        assert_eq!(Natural::factorial(n) % &m, f);
+        if m <= n {
+            assert_eq!(f, 0u32);
+        } else if n != 0 {
+            assert_eq!(
+                Natural::factorial_mod(n - 1, &m).mod_mul(Natural::from(n), &m),
+                f
+            );
+        }
+    });
+
+    unsigned_pair_gen_var_12::<u8, Limb>().test_properties(|(n, m)| {
+        let n = u64::from(n);
+        assert_eq!(
+            Natural::factorial_mod(n, Natural::from(m)),
+            Limb::factorial_mod(n, m)
+        );
+    });
+}
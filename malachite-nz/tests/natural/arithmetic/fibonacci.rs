@@ -0,0 +1,162 @@
+use malachite_base::num::arithmetic::traits::{
+    CheckedFibonacci, Fibonacci, FibonacciMod, LucasSequenceMod, Parity, Square,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_unsigned_pair_gen_var_13;
+use malachite_nz::test_util::natural::arithmetic::fibonacci::fibonacci_pair_naive;
+use rug::Complete;
+use std::str::FromStr;
+
+#[test]
+fn test_fibonacci() {
+    fn test(n: u64, out: &str) {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(
+            rug::Integer::from(rug::Integer::fibonacci(u32::exact_from(n))).to_string(),
+            out
+        );
+    }
+    test(0, "0");
+    test(1, "1");
+    test(2, "1");
+    test(3, "2");
+    test(10, "55");
+    test(47, "2971215073");
+    test(48, "4807526976");
+    test(93, "12200160415121876738");
+    test(94, "19740274219868223167");
+    test(95, "31940434634990099905");
+    test(100, "354224848179261915075");
+    test(200, "280571172992510140037611932413038677189525");
+    test(
+        500,
+        "139423224561697880139724382870407283950070256587697307264108962948325571622863290691557658\
+        876222521294125",
+    );
+}
+
+#[test]
+fn test_fibonacci_pair() {
+    fn test(n: u64, out: &str) {
+        let (f, g) = Natural::fibonacci_pair(n);
+        assert!(f.is_valid());
+        assert!(g.is_valid());
+        assert_eq!((f, g).to_debug_string(), out);
+    }
+    test(0, "(0, 1)");
+    test(1, "(1, 1)");
+    test(10, "(55, 89)");
+    test(92, "(7540113804746346429, 12200160415121876738)");
+    test(93, "(12200160415121876738, 19740274219868223167)");
+    test(100, "(354224848179261915075, 573147844013817084101)");
+}
+
+#[test]
+fn test_fibonacci_mod() {
+    fn test(n: u64, m: &str, out: &str) {
+        let m = Natural::from_str(m).unwrap();
+        let f = Natural::fibonacci_mod(n, m.clone());
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+
+        let f = Natural::fibonacci_mod(n, &m);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+    }
+    test(0, "1", "0");
+    test(10, "1", "0");
+    test(0, "10", "0");
+    test(10, "10", "5");
+    test(100, "1000000007", "687995182");
+    test(100, "1000000000000000000", "224848179261915075");
+    test(
+        1000000000000000000,
+        "1000000000000000000000000000057",
+        "579082029062782147055949019488",
+    );
+    test(
+        u64::MAX,
+        "340282366920938463463374607431768211456",
+        "89931275452236769244234364387381151458",
+    );
+}
+
+#[test]
+#[should_panic]
+fn fibonacci_mod_fail() {
+    Natural::fibonacci_mod(10, Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn fibonacci_mod_ref_fail() {
+    Natural::fibonacci_mod(10, &Natural::ZERO);
+}
+
+#[test]
+fn fibonacci_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        let (f_alt, f_next) = Natural::fibonacci_pair(n);
+        assert_eq!(f_alt, f);
+        assert_eq!(fibonacci_pair_naive(n), (f.clone(), f_next.clone()));
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::fibonacci(u32::exact_from(n)).complete()),
+            f
+        );
+        assert_eq!(Natural::fibonacci(n + 1), f_next);
+        // Cassini's identity: F_{n+1}^2 - F_{n+2}F_n = (-1)^n
+        let f_next_next = &f + &f_next;
+        let lhs = (&f_next).square();
+        let rhs = f_next_next * &f;
+        if n.even() {
+            assert_eq!(lhs, rhs + Natural::ONE);
+        } else {
+            assert_eq!(lhs + Natural::ONE, rhs);
+        }
+    });
+
+    unsigned_gen().test_properties(|n| {
+        if let Some(f) = Limb::checked_fibonacci(n) {
+            assert_eq!(Natural::fibonacci(n), f);
+        }
+        if let Some((f, g)) = Limb::checked_fibonacci_pair(n) {
+            assert_eq!(
+                Natural::fibonacci_pair(n),
+                (Natural::from(f), Natural::from(g))
+            );
+        }
+    });
+}
+
+#[test]
+fn fibonacci_mod_properties() {
+    natural_unsigned_pair_gen_var_13::<u64>().test_properties(|(m, n)| {
+        let f = Natural::fibonacci_mod(n, &m);
+        assert!(f.is_valid());
+        assert_eq!(Natural::fibonacci_mod(n, m.clone()), f);
+        assert!(f < m);
+        assert_eq!(Natural::fibonacci(n) % &m, f);
+        let f_1 = Natural::fibonacci_mod(n + 1, &m);
+        let f_2 = Natural::fibonacci_mod(n + 2, &m);
+        assert_eq!((f + f_1) % &m, f_2);
+        assert_eq!(
+            Natural::from(n)
+                .lucas_sequence_mod(Natural::ONE, &m - Natural::ONE, m.clone())
+                .0,
+            Natural::fibonacci_mod(n, &m)
+        );
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        assert_eq!(Natural::fibonacci_mod(n, Natural::ONE), 0);
+    });
+}
@@ -0,0 +1,111 @@
+use malachite_base::num::arithmetic::traits::{ModIsReduced, ModMul, ModPow, PowerOf2};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::arithmetic::fixed_base_mod_pow::FixedBaseModPowTable;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_8, natural_triple_gen_var_5};
+use std::str::FromStr;
+
+#[test]
+fn test_fixed_base_mod_pow() {
+    let test = |x, m, max_exp_bits, exp, out| {
+        let x = Natural::from_str(x).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let table = FixedBaseModPowTable::new(&x, &m, max_exp_bits);
+        assert_eq!(*table.modulus(), m);
+        assert_eq!(table.max_exp_bits(), max_exp_bits);
+        let n = table.pow(&Natural::from_str(exp).unwrap());
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "1", 10, "0", "0");
+    test("5", "1", 10, "3", "0");
+    test("0", "10", 10, "0", "1");
+    test("0", "10", 10, "5", "0");
+    test("4", "497", 0, "0", "1");
+    test("4", "497", 10, "0", "1");
+    test("4", "497", 10, "1", "4");
+    test("4", "497", 10, "13", "445");
+    test("4", "497", 10, "100", "116");
+    test("501", "497", 10, "100", "116");
+    test(
+        "3",
+        "170141183460469231731687303715884105727",
+        127,
+        "85070591730234615865843651857942052864",
+        "170141183460469231731687303715884105724",
+    );
+    test(
+        "3",
+        "170141183460469231731687303715884105727",
+        100,
+        "12345678901234567890123",
+        "161355072618839174478868626410744543931",
+    );
+    test(
+        "7",
+        "100000000000000000000",
+        70,
+        "18446744073709551619",
+        "27188629326056653143",
+    );
+}
+
+#[test]
+fn test_fixed_base_mod_pow_large() {
+    for m in [
+        Natural::power_of_2(3000) - Natural::from(1095u32),
+        Natural::power_of_2(3000) + Natural::from(1096u32),
+    ] {
+        let x = Natural::from(3u32);
+        let table = FixedBaseModPowTable::new(&x, &m, 3000);
+        for exp in [
+            Natural::ZERO,
+            Natural::ONE,
+            Natural::power_of_2(2999),
+            Natural::power_of_2(3000) - Natural::ONE,
+            (Natural::power_of_2(3000) - Natural::ONE) / Natural::from(7u32),
+        ] {
+            assert_eq!(table.pow(&exp), (&x).mod_pow(&exp, &m));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn fixed_base_mod_pow_fail_1() {
+    FixedBaseModPowTable::new(&Natural::ONE, &Natural::ZERO, 10);
+}
+
+#[test]
+#[should_panic]
+fn fixed_base_mod_pow_fail_2() {
+    FixedBaseModPowTable::new(&Natural::from(3u32), &Natural::from(10u32), 3)
+        .pow(&Natural::from(8u32));
+}
+
+#[test]
+fn fixed_base_mod_pow_properties() {
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        let exp_bits = exp.significant_bits();
+        let table = FixedBaseModPowTable::new(&x, &m, exp_bits);
+        let power = table.pow(&exp);
+        assert!(power.is_valid());
+        assert!(power.mod_is_reduced(&m));
+        assert_eq!((&x).mod_pow(&exp, &m), power);
+
+        let larger_table = FixedBaseModPowTable::new(&(&x + &m), &m, exp_bits + 10);
+        assert_eq!(larger_table.pow(&exp), power);
+    });
+
+    natural_pair_gen_var_8().test_properties(|(x, m)| {
+        let table = FixedBaseModPowTable::new(&x, &m, 10);
+        assert_eq!(table.pow(&Natural::ZERO), Natural::ONE % &m);
+        assert_eq!(table.pow(&Natural::ONE), x);
+        assert_eq!(table.pow(&Natural::from(2u32)), (&x).mod_mul(&x, &m));
+        assert_eq!(
+            table.pow(&Natural::from(1000u32)),
+            x.mod_pow(Natural::from(1000u32), &m)
+        );
+    });
+}
@@ -1,14 +1,15 @@
 use malachite_base::num::arithmetic::traits::{
-    CoprimeWith, DivExact, DivisibleBy, Gcd, GcdAssign, Lcm, ModPowerOf2,
+    CoprimeWith, DivExact, DivisibleBy, Gcd, GcdAssign, GcdOf, Lcm, ModPowerOf2,
 };
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::test_util::generators::common::{GenConfig, TINY_LIMIT};
 use malachite_base::test_util::generators::{
-    unsigned_gen_var_11, unsigned_pair_gen_var_27, unsigned_quadruple_gen_var_11,
+    unsigned_gen_var_11, unsigned_pair_gen_var_27, unsigned_quadruple_gen_var_11, unsigned_vec_gen,
     unsigned_vec_unsigned_pair_gen_var_23,
 };
+use malachite_base::vecs::vec_from_str;
 use malachite_nz::natural::arithmetic::gcd::half_gcd::{
     limbs_gcd_div, limbs_gcd_reduced, limbs_half_gcd_matrix_1_mul_vector, HalfGcdMatrix,
 };
@@ -20,7 +21,7 @@ use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
 use malachite_nz::test_util::generators::{
     large_type_gen_var_5, large_type_gen_var_6, large_type_gen_var_7, large_type_gen_var_8,
-    natural_gen, natural_pair_gen, natural_pair_gen_var_4, natural_triple_gen,
+    natural_gen, natural_pair_gen, natural_pair_gen_var_4, natural_triple_gen, natural_vec_gen,
     unsigned_vec_pair_gen_var_10,
 };
 #[cfg(feature = "32_bit_limbs")]
@@ -13130,3 +13131,57 @@ fn gcd_properties() {
         assert_eq!(Natural::from(x).gcd(Natural::from(y)), x.gcd(y));
     });
 }
+
+#[test]
+fn test_gcd_of() {
+    let test = |xs, out| {
+        let xs = vec_from_str::<Natural>(xs).unwrap();
+        let gcd = Natural::gcd_of(xs.iter());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd.to_string(), out);
+
+        let gcd = Natural::gcd_of(xs.into_iter());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd.to_string(), out);
+    };
+    test("[]", "0");
+    test("[0]", "0");
+    test("[0, 0, 0]", "0");
+    test("[6]", "6");
+    test("[0, 6, 0]", "6");
+    test("[12, 90, 42]", "6");
+    test("[6, 10, 15]", "1");
+    test("[48, 18, 30, 0]", "6");
+    test(
+        "[1219326320073159566072245112635269, 12345678987654321000, 37037036962962963]",
+        "12345678987654321",
+    );
+}
+
+#[test]
+fn gcd_of_properties() {
+    natural_vec_gen().test_properties(|xs| {
+        let gcd = Natural::gcd_of(xs.iter());
+        assert!(gcd.is_valid());
+        let gcd_alt = Natural::gcd_of(xs.clone().into_iter());
+        assert!(gcd_alt.is_valid());
+        assert_eq!(gcd_alt, gcd);
+        assert_eq!(xs.iter().fold(Natural::ZERO, Gcd::gcd), gcd);
+        assert_eq!(Natural::gcd_of(xs.iter().rev()), gcd);
+        for x in &xs {
+            assert!(x.divisible_by(&gcd));
+        }
+        assert_eq!(gcd == 0, xs.iter().all(|x| *x == 0));
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(Natural::gcd_of([&x, &y].into_iter()), x.gcd(y));
+    });
+
+    unsigned_vec_gen::<Limb>().test_properties(|xs| {
+        assert_eq!(
+            Natural::gcd_of(xs.iter().copied().map(Natural::from)),
+            Limb::gcd_of(xs.into_iter())
+        );
+    });
+}
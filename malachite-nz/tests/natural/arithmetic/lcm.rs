@@ -1,10 +1,15 @@
-use malachite_base::num::arithmetic::traits::{DivExact, DivisibleBy, Gcd, Lcm, LcmAssign};
+use malachite_base::num::arithmetic::traits::{
+    CheckedLcm, DivExact, DivisibleBy, Gcd, Lcm, LcmAssign, LcmOf,
+};
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
-use malachite_base::test_util::generators::unsigned_pair_gen_var_34;
+use malachite_base::test_util::generators::{unsigned_pair_gen_var_34, unsigned_vec_gen};
+use malachite_base::vecs::vec_from_str;
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
-use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen, natural_triple_gen};
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_pair_gen, natural_triple_gen, natural_vec_gen,
+};
 use num::BigUint;
 use num::Integer as rug_integer;
 use std::str::FromStr;
@@ -138,3 +143,60 @@ fn lcm_properties() {
         assert_eq!(Natural::from(x).lcm(Natural::from(y)), x.lcm(y));
     });
 }
+
+#[test]
+fn test_lcm_of() {
+    let test = |xs, out| {
+        let xs = vec_from_str::<Natural>(xs).unwrap();
+        let lcm = Natural::lcm_of(xs.iter());
+        assert!(lcm.is_valid());
+        assert_eq!(lcm.to_string(), out);
+
+        let lcm = Natural::lcm_of(xs.into_iter());
+        assert!(lcm.is_valid());
+        assert_eq!(lcm.to_string(), out);
+    };
+    test("[]", "1");
+    test("[0]", "0");
+    test("[6]", "6");
+    test("[6, 0, 15]", "0");
+    test("[1, 1, 1]", "1");
+    test("[12, 90, 42]", "1260");
+    test("[6, 10, 15]", "30");
+    test("[4, 6, 8, 9, 10]", "360");
+    test(
+        "[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]",
+        "232792560",
+    );
+    test(
+        "[12345678987654321, 98765432123456789, 98765432123456827]",
+        "3254791645987298235185851882467222583605835649499",
+    );
+}
+
+#[test]
+fn lcm_of_properties() {
+    natural_vec_gen().test_properties(|xs| {
+        let lcm = Natural::lcm_of(xs.iter());
+        assert!(lcm.is_valid());
+        let lcm_alt = Natural::lcm_of(xs.clone().into_iter());
+        assert!(lcm_alt.is_valid());
+        assert_eq!(lcm_alt, lcm);
+        assert_eq!(xs.iter().fold(Natural::ONE, Lcm::lcm), lcm);
+        assert_eq!(Natural::lcm_of(xs.iter().rev()), lcm);
+        for x in &xs {
+            assert!((&lcm).divisible_by(x));
+        }
+        assert_eq!(lcm == 0, xs.iter().any(|x| *x == 0));
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(Natural::lcm_of([&x, &y].into_iter()), x.lcm(y));
+    });
+
+    unsigned_vec_gen::<Limb>().test_properties(|xs| {
+        if let Some(lcm) = xs.iter().try_fold(1, |acc: Limb, &x| acc.checked_lcm(x)) {
+            assert_eq!(Natural::lcm_of(xs.into_iter().map(Natural::from)), lcm);
+        }
+    });
+}
@@ -0,0 +1,251 @@
+use malachite_base::num::arithmetic::traits::{
+    CheckedLucas, Fibonacci, Lucas, LucasMod, LucasSequenceMod, Parity, Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{
+    natural_quadruple_gen_var_1, natural_unsigned_pair_gen_var_13,
+};
+use malachite_nz::test_util::natural::arithmetic::fibonacci::{
+    lucas_pair_naive, lucas_sequence_mod_naive,
+};
+use rug::Complete;
+use std::str::FromStr;
+
+#[test]
+fn test_lucas() {
+    fn test(n: u64, out: &str) {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), out);
+        assert_eq!(
+            rug::Integer::from(rug::Integer::lucas(u32::exact_from(n))).to_string(),
+            out
+        );
+    }
+    test(0, "2");
+    test(1, "1");
+    test(2, "3");
+    test(10, "123");
+    test(46, "4106118243");
+    test(47, "6643838879");
+    test(92, "16860207025497407047");
+    test(93, "27280388024614569596");
+    test(94, "44140595050111976643");
+    test(100, "792070839848372253127");
+    test(
+        300,
+        "496926405783746676393791436882468230898067489522034699520200002",
+    );
+}
+
+#[test]
+fn test_lucas_pair() {
+    fn test(n: u64, out: &str) {
+        let (l, l_next) = Natural::lucas_pair(n);
+        assert!(l.is_valid());
+        assert!(l_next.is_valid());
+        assert_eq!((l, l_next).to_debug_string(), out);
+    }
+    test(0, "(2, 1)");
+    test(1, "(1, 3)");
+    test(10, "(123, 199)");
+    test(92, "(16860207025497407047, 27280388024614569596)");
+    test(100, "(792070839848372253127, 1281597540372340914251)");
+}
+
+#[test]
+fn test_lucas_mod() {
+    fn test(n: u64, m: &str, out: &str) {
+        let m = Natural::from_str(m).unwrap();
+        let l = Natural::lucas_mod(n, m.clone());
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), out);
+
+        let l = Natural::lucas_mod(n, &m);
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), out);
+    }
+    test(0, "1", "0");
+    test(0, "2", "0");
+    test(0, "3", "2");
+    test(10, "10", "3");
+    test(
+        1000000000000000000,
+        "1000000000000000000000000000057",
+        "273674495706502311781176843414",
+    );
+    test(100, "1000000000000000000000", "792070839848372253127");
+}
+
+#[test]
+#[should_panic]
+fn lucas_mod_fail() {
+    Natural::lucas_mod(10, Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn lucas_mod_ref_fail() {
+    Natural::lucas_mod(10, &Natural::ZERO);
+}
+
+#[test]
+fn test_lucas_sequence_mod() {
+    fn test(k: &str, p: &str, q: &str, m: &str, out: &str) {
+        let k = Natural::from_str(k).unwrap();
+        let p = Natural::from_str(p).unwrap();
+        let q = Natural::from_str(q).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let (u, v) = k
+            .clone()
+            .lucas_sequence_mod(p.clone(), q.clone(), m.clone());
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!((u, v).to_debug_string(), out);
+
+        let (u, v) = (&k).lucas_sequence_mod(&p, &q, &m);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!((u, v).to_debug_string(), out);
+    }
+    test("0", "1", "1", "1", "(0, 0)");
+    test("0", "5", "7", "10", "(0, 2)");
+    test("1", "5", "7", "10", "(1, 5)");
+    test("10", "1", "255", "256", "(55, 123)");
+    test(
+        "1000",
+        "3",
+        "2",
+        "1000000000000000000000000000057",
+        "(141502251827270929530186206575, 141502251827270929530186206577)",
+    );
+    test(
+        "100",
+        "7",
+        "5",
+        "10000000000000000000000013",
+        "(4743909517733817167957436, 488353866950817715073267)",
+    );
+    // Even modulus, and Q larger than the modulus
+    test(
+        "64",
+        "3",
+        "100000000000000000000",
+        "1180591620717411303424",
+        "(1078770017657853110699, 853893669170079251713)",
+    );
+}
+
+#[test]
+#[should_panic]
+fn lucas_sequence_mod_fail() {
+    Natural::TWO.lucas_sequence_mod(Natural::ONE, Natural::ONE, Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn lucas_sequence_mod_ref_fail() {
+    (&Natural::TWO).lucas_sequence_mod(&Natural::ONE, &Natural::ONE, &Natural::ZERO);
+}
+
+#[test]
+fn lucas_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        let (l_alt, l_next) = Natural::lucas_pair(n);
+        assert_eq!(l_alt, l);
+        assert_eq!(lucas_pair_naive(n), (l.clone(), l_next.clone()));
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::lucas(u32::exact_from(n)).complete()),
+            l
+        );
+        assert_eq!(Natural::lucas(n + 1), l_next);
+        // F_{2n} = F_n L_n
+        assert_eq!(Natural::fibonacci(n) * &l, Natural::fibonacci(n << 1));
+        // L_{2n} = L_n^2 - 2(-1)^n
+        let l_sq = l.square();
+        if n.even() {
+            assert_eq!(l_sq - Natural::TWO, Natural::lucas(n << 1));
+        } else {
+            assert_eq!(l_sq + Natural::TWO, Natural::lucas(n << 1));
+        }
+    });
+
+    unsigned_gen().test_properties(|n| {
+        if let Some(l) = Limb::checked_lucas(n) {
+            assert_eq!(Natural::lucas(n), l);
+        }
+        if let Some((l, l_next)) = Limb::checked_lucas_pair(n) {
+            assert_eq!(
+                Natural::lucas_pair(n),
+                (Natural::from(l), Natural::from(l_next))
+            );
+        }
+    });
+}
+
+#[test]
+fn lucas_mod_properties() {
+    natural_unsigned_pair_gen_var_13::<u64>().test_properties(|(m, n)| {
+        let l = Natural::lucas_mod(n, &m);
+        assert!(l.is_valid());
+        assert_eq!(Natural::lucas_mod(n, m.clone()), l);
+        assert!(l < m);
+        assert_eq!(Natural::lucas(n) % &m, l);
+        assert_eq!(
+            Natural::from(n)
+                .lucas_sequence_mod(Natural::ONE, &m - Natural::ONE, m.clone())
+                .1,
+            l
+        );
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        assert_eq!(Natural::lucas_mod(n, Natural::ONE), 0);
+    });
+}
+
+#[test]
+fn lucas_sequence_mod_properties() {
+    natural_quadruple_gen_var_1().test_properties(|(k, p, q, m)| {
+        let (u, v) = (&k).lucas_sequence_mod(&p, &q, &m);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!(
+            k.clone()
+                .lucas_sequence_mod(p.clone(), q.clone(), m.clone()),
+            (u.clone(), v.clone())
+        );
+        assert!(u < m);
+        assert!(v < m);
+        assert_eq!(
+            (&k).lucas_sequence_mod(&(&p + &m), &(&q + &m), &m),
+            (u.clone(), v.clone())
+        );
+        if let Ok(small_k) = u64::try_from(&k) {
+            if small_k < 1000 {
+                assert_eq!(
+                    lucas_sequence_mod_naive(small_k, &p, &q, &m),
+                    (u.clone(), v.clone())
+                );
+            }
+        }
+        // U_{2k} = U_k V_k
+        let u_2 = (&(&k << 1u32)).lucas_sequence_mod(&p, &q, &m).0;
+        assert_eq!(u_2, u * v % &m);
+    });
+
+    natural_unsigned_pair_gen_var_13::<u64>().test_properties(|(m, k)| {
+        let k = Natural::from(k) + Natural::ONE;
+        assert_eq!(
+            (&k).lucas_sequence_mod(&Natural::ONE, &Natural::ZERO, &m).0,
+            Natural::ONE % &m
+        );
+    });
+}
@@ -0,0 +1,118 @@
+use malachite_base::num::arithmetic::traits::{ModLog, ModPow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_nz::natural::arithmetic::mod_log::{
+    mod_log_baby_step_giant_step, mod_log_pollard_rho,
+};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_log() {
+    let test = |x, base, m, out| {
+        let x = Natural::from_str(x).unwrap();
+        let base = Natural::from_str(base).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        assert_eq!((&x).mod_log(&base, &m).to_debug_string(), out);
+        assert_eq!(x.mod_log(base, m).to_debug_string(), out);
+    };
+    test("0", "0", "1", "Some(0)");
+    test("0", "0", "2", "Some(1)");
+    test("1", "1", "2", "Some(0)");
+    test("0", "1", "2", "None");
+    test("2", "3", "7", "Some(2)");
+    test("3", "2", "7", "None");
+    test("4", "2", "12", "Some(2)");
+    test("3", "2", "12", "None");
+    test("0", "6", "36", "Some(2)");
+    test("11", "3", "256", "Some(39)");
+    test("12345", "5", "1000000007", "Some(775972150)");
+    test(
+        "377154892137755323",
+        "5",
+        "3000000042000000147",
+        "Some(12345678901234)",
+    );
+    test(
+        "809860754640765673",
+        "37",
+        "2305843009213693951",
+        "Some(987654321987654321)",
+    );
+    // The order of 3 has a prime factor with more than 32 bits, so Pollard's rho algorithm is used
+    test(
+        "50413764737726541207935044233129750476",
+        "3",
+        "170141183460469231731687303715884105727",
+        "Some(1234567890123456789)",
+    );
+}
+
+#[test]
+fn mod_log_fail() {
+    assert_panic!(Natural::ZERO.mod_log(Natural::ZERO, Natural::ZERO));
+    assert_panic!(Natural::ONE.mod_log(Natural::ZERO, Natural::ONE));
+    assert_panic!((&Natural::from(7u32)).mod_log(&Natural::from(2u32), &Natural::from(7u32)));
+}
+
+#[test]
+fn test_mod_log_baby_step_giant_step_and_pollard_rho() {
+    let test = |gamma, beta, q, m, out| {
+        let gamma = Natural::from_str(gamma).unwrap();
+        let beta = Natural::from_str(beta).unwrap();
+        let q = Natural::from_str(q).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let out = Natural::from_str(out).unwrap();
+        assert_eq!(
+            mod_log_baby_step_giant_step(&gamma, &beta, &q, &m),
+            Some(out.clone())
+        );
+        assert_eq!(mod_log_pollard_rho(&gamma, &beta, &q, &m), out);
+    };
+    // 2 has order 11 modulo 23
+    test("2", "13", "11", "23", "7");
+    // 4 has order 500000003 modulo 1000000007
+    test("4", "554030327", "500000003", "1000000007", "123456789");
+
+    // 2 is not a power of 4 modulo 11
+    assert_eq!(
+        mod_log_baby_step_giant_step(
+            &Natural::from(4u32),
+            &Natural::from(2u32),
+            &Natural::from(5u32),
+            &Natural::from(11u32)
+        ),
+        None
+    );
+}
+
+#[test]
+fn mod_log_properties() {
+    // Discrete logarithms modulo large random numbers are infeasible, so the inputs are taken from
+    // triples of `u32`s.
+    unsigned_triple_gen_var_12::<u32>().test_properties_with_limit(SMALL_LIMIT, |(base, x, m)| {
+        let power = base.mod_pow(u64::from(x), m);
+        let base_alt = Natural::from(base);
+        let m_alt = Natural::from(m);
+        let power_alt = Natural::from(power);
+        let log = (&power_alt).mod_log(&base_alt, &m_alt).unwrap();
+        assert!(log.is_valid());
+        assert!(log <= x);
+        assert_eq!((&base_alt).mod_pow(&log, &m_alt), power_alt);
+        assert_eq!(Some(log), power.mod_log(base, m).map(Natural::from));
+        assert_eq!(
+            power_alt.mod_log(base_alt, m_alt),
+            power.mod_log(base, m).map(Natural::from)
+        );
+    });
+
+    unsigned_triple_gen_var_12::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, base, m)| {
+        assert_eq!(
+            Natural::from(x).mod_log(Natural::from(base), Natural::from(m)),
+            x.mod_log(base, m).map(Natural::from)
+        );
+    });
+}
@@ -0,0 +1,131 @@
+use malachite_base::num::arithmetic::traits::{ModIsReduced, ModMul, ModPow, Pow, PowerOf2};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::arithmetic::mod_multi_pow::{
+    mod_multi_pow_naive, mod_multi_pow_pippenger, mod_multi_pow_straus,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    natural_pair_gen_var_5, natural_triple_gen_var_5, natural_vec_natural_pair_gen_var_4,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_mod_multi_pow() {
+    let test = |powers: &[(&str, &str)], m, out| {
+        let powers: Vec<(Natural, Natural)> = powers
+            .iter()
+            .map(|&(x, e)| (Natural::from_str(x).unwrap(), Natural::from_str(e).unwrap()))
+            .collect();
+        let m = Natural::from_str(m).unwrap();
+        let n = Natural::mod_multi_pow(&powers, &m);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+        if m != 1u32 {
+            assert_eq!(mod_multi_pow_straus(&powers, &m), n);
+            assert_eq!(mod_multi_pow_pippenger(&powers, &m), n);
+        }
+        assert_eq!(mod_multi_pow_naive(&powers, &m), n);
+    };
+    test(&[], "1", "0");
+    test(&[], "10", "1");
+    test(&[("5", "3")], "1", "0");
+    test(&[("4", "13")], "497", "445");
+    test(&[("4", "13"), ("5", "7")], "497", "475");
+    test(&[("4", "0"), ("5", "0")], "497", "1");
+    test(&[("3", "0"), ("0", "5")], "7", "0");
+    test(&[("1000", "13"), ("5", "7")], "497", "9");
+    test(
+        &[("3", "1000"), ("5", "77")],
+        "1267650600228229401496703205376",
+        "1133858671211624706765311592501",
+    );
+    test(
+        &[
+            ("123456789012345678901234567890", "100000000000000000001"),
+            ("987654321098765432109876543210", "12345"),
+            ("3", "1180591620717411303429"),
+        ],
+        "1000000000000000000000000000057",
+        "319188180247740328111155233091",
+    );
+}
+
+#[test]
+fn test_mod_multi_pow_many_bases() {
+    // Enough bases that Pippenger's algorithm is used
+    for m in [
+        Natural::power_of_2(200) - Natural::from(75u32),
+        Natural::power_of_2(200) + Natural::from(76u32),
+    ] {
+        let powers: Vec<(Natural, Natural)> = (1..=150u32)
+            .map(|i| {
+                (
+                    Natural::from(i + 1).pow(40) % &m,
+                    Natural::from(i).pow(30) + Natural::from(i),
+                )
+            })
+            .collect();
+        let n = Natural::mod_multi_pow(&powers, &m);
+        assert!(n.is_valid());
+        assert_eq!(mod_multi_pow_naive(&powers, &m), n);
+        assert_eq!(mod_multi_pow_straus(&powers, &m), n);
+    }
+}
+
+#[test]
+#[should_panic]
+fn mod_multi_pow_fail() {
+    Natural::mod_multi_pow(&[(Natural::ONE, Natural::ONE)], &Natural::ZERO);
+}
+
+#[test]
+fn mod_multi_pow_properties() {
+    natural_vec_natural_pair_gen_var_4().test_properties(|(xs, m)| {
+        let powers: Vec<(Natural, Natural)> = xs
+            .chunks_exact(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect();
+        let n = Natural::mod_multi_pow(&powers, &m);
+        assert!(n.is_valid());
+        assert!(n.mod_is_reduced(&m));
+        assert_eq!(mod_multi_pow_naive(&powers, &m), n);
+        assert_eq!(mod_multi_pow_straus(&powers, &m), n);
+        assert_eq!(mod_multi_pow_pippenger(&powers, &m), n);
+
+        let mut reversed = powers.clone();
+        reversed.reverse();
+        assert_eq!(Natural::mod_multi_pow(&reversed, &m), n);
+        let mut extended = powers;
+        extended.push((Natural::ONE, m.clone()));
+        extended.push((m.clone(), Natural::ZERO));
+        assert_eq!(Natural::mod_multi_pow(&extended, &m), n);
+    });
+
+    natural_triple_gen_var_5().test_properties(|(x, e, m)| {
+        let power = (&x).mod_pow(&e, &m);
+        assert_eq!(Natural::mod_multi_pow(&[(x.clone(), e.clone())], &m), power);
+        if m != 1u32 {
+            assert_eq!(mod_multi_pow_straus(&[(x.clone(), e.clone())], &m), power);
+            assert_eq!(
+                mod_multi_pow_pippenger(&[(x.clone(), e.clone())], &m),
+                power
+            );
+            let e_2 = &e + Natural::ONE;
+            assert_eq!(
+                Natural::mod_multi_pow(&[(x.clone(), e), (x.clone(), Natural::ONE)], &m),
+                x.mod_pow(e_2, &m)
+            );
+        }
+    });
+
+    natural_pair_gen_var_5().test_properties(|(x, m)| {
+        assert_eq!(
+            Natural::mod_multi_pow(&[(x.clone(), Natural::ONE)], &m),
+            &x % &m
+        );
+        assert_eq!(
+            Natural::mod_multi_pow(&[(x.clone(), Natural::ONE), (x.clone(), Natural::ONE)], &m),
+            (&x % &m).mod_mul(&x % &m, &m)
+        );
+    });
+}
@@ -0,0 +1,163 @@
+use malachite_base::num::arithmetic::traits::{ModSqrt, ModSquare, Pow, PowerOf2};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::{Factor, NextPrime};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_nz::natural::arithmetic::mod_sqrt::{mod_sqrt_cipolla, mod_sqrt_tonelli_shanks};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sqrt() {
+    let test = |x, m, out, outs| {
+        let x = Natural::from_str(x).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let factors = m.factor();
+        assert_eq!((&x).mod_sqrt(&m).to_debug_string(), out);
+        assert_eq!(x.clone().mod_sqrt(m.clone()).to_debug_string(), out);
+        assert_eq!((&x).mod_sqrt_from_factors(&factors).to_debug_string(), out);
+        assert_eq!(
+            x.clone().mod_sqrt_from_factors(&factors).to_debug_string(),
+            out
+        );
+        assert_eq!((&x).mod_sqrts(&m).to_debug_string(), outs);
+        assert_eq!(x.clone().mod_sqrts(m).to_debug_string(), outs);
+        assert_eq!(
+            (&x).mod_sqrts_from_factors(&factors).to_debug_string(),
+            outs
+        );
+        assert_eq!(x.mod_sqrts_from_factors(&factors).to_debug_string(), outs);
+    };
+    test("0", "1", "Some(0)", "[0]");
+    test("1", "2", "Some(1)", "[1]");
+    test("2", "7", "Some(3)", "[3, 4]");
+    test("3", "7", "None", "[]");
+    test("10", "13", "Some(6)", "[6, 7]");
+    test("4", "8", "Some(2)", "[2, 6]");
+    test("0", "16", "Some(0)", "[0, 4, 8, 12]");
+    test("9", "27", "Some(3)", "[3, 6, 12, 15, 21, 24]");
+    test("4", "15", "Some(7)", "[2, 7, 8, 13]");
+    test(
+        "17",
+        "1048576",
+        "Some(206569)",
+        "[206569, 317719, 730857, 842007]",
+    );
+    test(
+        "3",
+        "18446744069414584321",
+        "Some(281474976579584)",
+        "[281474976579584, 18446462594438004737]",
+    );
+    test(
+        "2",
+        "170141183460469231731687303715884105727",
+        "Some(18446744073709551616)",
+        "[18446744073709551616, 170141183460469231713240559642174554111]",
+    );
+    test("5", "170141183460469231731687303715884105727", "None", "[]");
+    test(
+        "3",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        "Some(15029839470433391022265175636939773287626296101036845499088079275986334742835)",
+        "[15029839470433391022265175636939773287626296101036845499088079275986334742835, \
+        42866205148224706689520316867404180639008696231783436520640712727970230077114]",
+    );
+}
+
+#[test]
+fn test_mod_sqrt_prime_power() {
+    // 4 has exactly two square roots modulo 3^50
+    let m = Natural::from(3u32).pow(50);
+    let roots = Natural::from(4u32).mod_sqrts_from_factors(&[(Natural::from(3u32), 50)]);
+    assert_eq!(roots.to_debug_string(), "[2, 717897987691852588770247]");
+    assert_eq!(&roots[1] + Natural::from(2u32), m);
+
+    // 1 has four square roots modulo 2^100
+    let m = Natural::power_of_2(100);
+    assert_eq!(
+        (&Natural::from(1u32)).mod_sqrts(&m).to_debug_string(),
+        "[1, 633825300114114700748351602687, 633825300114114700748351602689, \
+        1267650600228229401496703205375]"
+    );
+}
+
+#[test]
+fn test_mod_sqrt_tonelli_shanks_and_cipolla() {
+    let test = |x, p, out| {
+        let x = Natural::from_str(x).unwrap();
+        let p = Natural::from_str(p).unwrap();
+        let out = Natural::from_str(out).unwrap();
+        let other = &p - &out;
+        let root = mod_sqrt_tonelli_shanks(&x, &p);
+        assert!(root == out || root == other);
+        let root = mod_sqrt_cipolla(&x, &p);
+        assert!(root == out || root == other);
+    };
+    test("2", "7", "3");
+    test("10", "13", "6");
+    test("3", "193", "14");
+    test("3", "18446744069414584321", "281474976579584");
+    test(
+        "3",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        "15029839470433391022265175636939773287626296101036845499088079275986334742835",
+    );
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    assert_panic!(Natural::ZERO.mod_sqrt(Natural::ZERO));
+    assert_panic!(Natural::from(7u32).mod_sqrt(Natural::from(7u32)));
+    assert_panic!((&Natural::from(7u32)).mod_sqrts(&Natural::from(7u32)));
+    assert_panic!(Natural::from(1u32).mod_sqrt_from_factors(&[]));
+    assert_panic!(Natural::from(9u32).mod_sqrts_from_factors(&[(Natural::from(3u32), 2)]));
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    natural_pair_gen_var_8().test_properties_with_limit(SMALL_LIMIT, |(x, m)| {
+        let p = (&m).next_prime();
+        let x = x % &p;
+        if x == 0u32 || p == 2u32 {
+            return;
+        }
+        let square = (&x).mod_square(&p);
+        let other = &p - &x;
+        let root = mod_sqrt_tonelli_shanks(&square, &p);
+        assert!(root == x || root == other);
+        let root = mod_sqrt_cipolla(&square, &p);
+        assert!(root == x || root == other);
+        let root = (&square).mod_sqrt_from_factors(&[(p.clone(), 1)]).unwrap();
+        assert_eq!(root, if x < other { x } else { other });
+        assert!(root.is_valid());
+    });
+
+    unsigned_pair_gen_var_16::<u64>().test_properties(|(x, m)| {
+        let x_alt = Natural::from(x);
+        let m_alt = Natural::from(m);
+        let root = (&x_alt).mod_sqrt(&m_alt);
+        assert_eq!(root, x.mod_sqrt(m).map(Natural::from));
+        if let Some(root) = root {
+            assert!(root.is_valid());
+            assert_eq!(root.mod_square(&m_alt), x_alt);
+        }
+        let factors = m.factor();
+        let factors_alt = factors
+            .iter()
+            .map(|&(p, e)| (Natural::from(p), e))
+            .collect::<Vec<_>>();
+        if factors.iter().all(|&(_, e)| e == 1) {
+            assert_eq!(
+                (&x_alt).mod_sqrts_from_factors(&factors_alt),
+                x.mod_sqrts_from_factors(&factors)
+                    .into_iter()
+                    .map(Natural::from)
+                    .collect::<Vec<_>>()
+            );
+        }
+    });
+}
@@ -0,0 +1,56 @@
+use malachite_base::num::arithmetic::traits::{MoebiusMu, Square};
+use malachite_base::num::factorization::traits::{Factor, Primes};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_moebius_mu() {
+    let test = |n: &str, out: i8| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.moebius_mu(), out);
+        assert_eq!(Natural::moebius_mu_from_factors(&n.factor()), out);
+    };
+    test("1", 1);
+    test("2", -1);
+    test("4", 0);
+    test("30", -1);
+    test("1000000007", -1);
+    test("18446744073709551615", -1);
+    test("18446744073709551616", 0);
+    // 2^128 + 1
+    test("340282366920938463463374607431768211457", 1);
+    // 10^30 - 1
+    test("999999999999999999999999999999", 0);
+}
+
+#[test]
+#[should_panic]
+fn moebius_mu_fail() {
+    Natural::from(0u32).moebius_mu();
+}
+
+#[test]
+fn moebius_mu_properties() {
+    unsigned_vec_gen::<u16>().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        let factors = n.factor();
+        let mu = n.moebius_mu();
+        assert_eq!(Natural::moebius_mu_from_factors(&factors), mu);
+        assert!(mu == -1 || mu == 0 || mu == 1);
+        assert_eq!(mu == 0, factors.iter().any(|&(_, e)| e > 1));
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        assert_eq!(Natural::from(n).moebius_mu(), n.moebius_mu());
+    });
+
+    for p in Natural::primes().take(100) {
+        assert_eq!(p.moebius_mu(), -1);
+        assert_eq!(p.square().moebius_mu(), 0);
+    }
+}
@@ -0,0 +1,166 @@
+use malachite_base::num::arithmetic::traits::{
+    ModIsReduced, ModMul, ModPow, ModSquare, Pow, PowerOf2,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    natural_pair_gen_var_8, natural_triple_gen_var_3, natural_triple_gen_var_5,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_montgomery() {
+    let test = |x, y, m, product, square| {
+        let x = Natural::from_str(x).unwrap();
+        let y = Natural::from_str(y).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let context = MontgomeryContext::new(&m);
+        assert_eq!(*context.modulus(), m);
+        let x_m = context.to_montgomery(&x);
+        let y_m = context.to_montgomery(&y);
+        assert!(x_m.is_valid());
+        assert!(x_m.mod_is_reduced(&m));
+        assert_eq!(context.from_montgomery(&x_m), x);
+
+        let n = context.from_montgomery(&context.mul(&x_m, &y_m));
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), product);
+
+        let n = context.from_montgomery(&context.square(&x_m));
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), square);
+    };
+    test("0", "0", "1", "0", "0");
+    test("0", "5", "7", "0", "0");
+    test("1", "1", "3", "1", "1");
+    test("20", "30", "101", "95", "97");
+    test(
+        "123456789012345678901234567890",
+        "987654321098765432109876543210",
+        "1000000000000000000000000000057",
+        "672763357427221473218716661518",
+        "667428798567291505096784013123",
+    );
+    test(
+        "5",
+        "7",
+        "170141183460469231731687303715884105727",
+        "35",
+        "25",
+    );
+}
+
+#[test]
+fn test_montgomery_pow() {
+    let test = |x, exp, m, out| {
+        let x = Natural::from_str(x).unwrap();
+        let exp = Natural::from_str(exp).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let context = MontgomeryContext::new(&m);
+        let n = context.from_montgomery(&context.pow(&context.to_montgomery(&x), &exp));
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("0", "0", "9", "1");
+    test("0", "1", "9", "0");
+    test("4", "13", "497", "445");
+    test("3", "100", "101", "1");
+    test(
+        "123456789012345678901234567890",
+        "100000000000000000001",
+        "1000000000000000000000000000057",
+        "711569146593847384882551865826",
+    );
+    test(
+        "3",
+        "85070591730234615865843651857942052864",
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105724",
+    );
+}
+
+#[test]
+fn test_montgomery_large_modulus() {
+    // A modulus with enough limbs that `limbs_redc` is used instead of `limbs_redc_limb`
+    let m = Natural::power_of_2(12000) - Natural::from(3u32);
+    let context = MontgomeryContext::new(&m);
+    let x = Natural::from(3u32).pow(5000) % &m;
+    let y = Natural::from(7u32).pow(4000) % &m;
+    let x_m = context.to_montgomery(&x);
+    let y_m = context.to_montgomery(&y);
+    assert_eq!(context.from_montgomery(&x_m), x);
+    assert_eq!(
+        context.from_montgomery(&context.mul(&x_m, &y_m)),
+        (&x).mod_mul(&y, &m)
+    );
+    assert_eq!(
+        context.from_montgomery(&context.square(&y_m)),
+        (&y).mod_square(&m)
+    );
+    let exp = Natural::from(12345u32);
+    assert_eq!(
+        context.from_montgomery(&context.pow(&x_m, &exp)),
+        x.mod_pow(&exp, &m)
+    );
+}
+
+#[test]
+#[should_panic]
+fn montgomery_fail_1() {
+    MontgomeryContext::new(&Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn montgomery_fail_2() {
+    MontgomeryContext::new(&Natural::from(10u32));
+}
+
+#[test]
+fn montgomery_properties() {
+    natural_triple_gen_var_3().test_properties(|(x, y, m)| {
+        // The generated moduli may be even, so make them odd without making `x` or `y` unreduced.
+        let m = m | Natural::ONE;
+        let context = MontgomeryContext::new(&m);
+        let x_m = context.to_montgomery(&x);
+        let y_m = context.to_montgomery(&y);
+        assert!(x_m.mod_is_reduced(&m));
+        assert!(y_m.mod_is_reduced(&m));
+
+        let product = context.mul(&x_m, &y_m);
+        assert!(product.is_valid());
+        assert!(product.mod_is_reduced(&m));
+        assert_eq!(context.mul(&y_m, &x_m), product);
+        assert_eq!(context.from_montgomery(&product), (&x).mod_mul(&y, &m));
+        assert_eq!(context.to_montgomery(&(&x).mod_mul(&y, &m)), product);
+    });
+
+    natural_pair_gen_var_8().test_properties(|(x, m)| {
+        let m = m | Natural::ONE;
+        let context = MontgomeryContext::new(&m);
+        let x_m = context.to_montgomery(&x);
+        assert_eq!(context.from_montgomery(&x_m), x);
+        assert_eq!(context.to_montgomery(&(&x + &m)), x_m);
+
+        let square = context.square(&x_m);
+        assert!(square.is_valid());
+        assert!(square.mod_is_reduced(&m));
+        assert_eq!(context.mul(&x_m, &x_m), square);
+        assert_eq!(context.from_montgomery(&square), (&x).mod_square(&m));
+
+        assert_eq!(context.mul(&x_m, context.one()), x_m);
+        assert_eq!(context.pow(&x_m, &Natural::ONE), x_m);
+        assert_eq!(context.pow(&x_m, &Natural::from(2u32)), square);
+    });
+
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        let m = m | Natural::ONE;
+        let context = MontgomeryContext::new(&m);
+        let power = context.pow(&context.to_montgomery(&x), &exp);
+        assert!(power.is_valid());
+        assert!(power.mod_is_reduced(&m));
+        assert_eq!(context.from_montgomery(&power), (&x).mod_pow(&exp, &m));
+    });
+}
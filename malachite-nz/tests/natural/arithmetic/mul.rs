@@ -2,6 +2,7 @@ use malachite_base::num::arithmetic::traits::{DivMod, Square};
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
 use malachite_base::test_util::common::rle_decode;
 use malachite_base::test_util::generators::common::GenConfig;
 use malachite_base::test_util::generators::{
@@ -30,6 +31,9 @@ use malachite_nz::natural::arithmetic::mul::mul_low::{
 };
 #[cfg(not(feature = "32_bit_limbs"))]
 use malachite_nz::natural::arithmetic::mul::mul_mod::limbs_mul_mod_base_pow_n_minus_1;
+use malachite_nz::natural::arithmetic::mul::ntt::{
+    limbs_mul_greater_to_out_ntt, limbs_square_to_out_ntt,
+};
 use malachite_nz::natural::arithmetic::mul::product_of_limbs::limbs_product;
 use malachite_nz::natural::arithmetic::mul::toom::{
     limbs_mul_greater_to_out_toom_22, limbs_mul_greater_to_out_toom_22_scratch_len,
@@ -119,6 +123,31 @@ fn test_product() {
         "[123456, 789012, 345678, 9012345]",
         "303462729062737285547520",
     );
+    test(
+        "[4294967295, 4294967295, 4294967295, 18446744073709551615, 3]",
+        "4384504908930167452797977302174744082046750031875",
+    );
+}
+
+#[test]
+fn test_product_tree() {
+    let test = |xs, out| {
+        let xs = vec_from_str::<Natural>(xs).unwrap();
+        let tree = Natural::product_tree(&xs);
+        assert!(tree.iter().flatten().all(Natural::is_valid));
+        assert_eq!(tree.to_debug_string(), out);
+    };
+    test("[]", "[]");
+    test("[10]", "[[10]]");
+    test("[6, 2]", "[[6, 2], [12]]");
+    test(
+        "[2, 3, 5, 7, 11]",
+        "[[2, 3, 5, 7, 11], [6, 35, 11], [210, 11], [2310]]",
+    );
+    test(
+        "[1, 2, 3, 0, 5, 6]",
+        "[[1, 2, 3, 0, 5, 6], [2, 0, 30], [0, 30], [0]]",
+    );
 }
 
 #[test]
@@ -12122,6 +12151,101 @@ fn limbs_square_to_out_fft_properties() {
     });
 }
 
+#[test]
+fn test_limbs_mul_greater_to_out_ntt() {
+    let test = |xs: &[Limb], ys: &[Limb], out_before: &[Limb], out_after: &[Limb]| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_ntt(&mut out, xs, ys);
+        assert_eq!(out, out_after);
+        verify_limbs_mul_greater_to_out_fft(out_before, xs, ys, out_after);
+    };
+    test(&[2], &[3], &[10, 10, 10], &[6, 0, 10]);
+    test(&[1, 2, 3], &[6, 7], &[10; 6], &[6, 19, 32, 21, 0, 10]);
+    test(
+        &[Limb::MAX; 3],
+        &[Limb::MAX; 2],
+        &[10; 6],
+        &[1, 0, Limb::MAX, Limb::MAX - 1, Limb::MAX, 10],
+    );
+    let test_big = |xs: &[Limb], ys: &[Limb]| {
+        let mut out = vec![10; xs.len() + ys.len() + 1];
+        let out_before = out.clone();
+        limbs_mul_greater_to_out_ntt(&mut out, xs, ys);
+        verify_limbs_mul_greater_to_out_fft(&out_before, xs, ys, &out);
+    };
+    // the transform length is an exact power of 2
+    test_big(&[Limb::MAX; 1024], &[Limb::MAX; 1024]);
+    // the transform is split recursively
+    test_big(&[Limb::MAX; 5000], &[Limb::MAX; 3001]);
+    test_big(&[10; 100000], &[Limb::MAX; 7]);
+}
+
+#[test]
+fn test_limbs_square_to_out_ntt() {
+    let test = |xs: &[Limb], out_before: &[Limb], out_after: &[Limb]| {
+        let mut out = out_before.to_vec();
+        limbs_square_to_out_ntt(&mut out, xs);
+        assert_eq!(out, out_after);
+        verify_limbs_square_to_out_fft(out_before, xs, out_after);
+    };
+    test(&[3], &[10, 10, 10], &[9, 0, 10]);
+    test(&[1, 2, 3], &[10; 7], &[1, 4, 10, 12, 9, 0, 10]);
+    test(&[Limb::MAX; 2], &[10; 4], &[1, 0, Limb::MAX - 1, Limb::MAX]);
+    let test_big = |xs: &[Limb]| {
+        let mut out = vec![10; (xs.len() << 1) + 1];
+        let out_before = out.clone();
+        limbs_square_to_out_ntt(&mut out, xs);
+        verify_limbs_square_to_out_fft(&out_before, xs, &out);
+    };
+    test_big(&[Limb::MAX; 1024]);
+    test_big(&[Limb::MAX; 5001]);
+}
+
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_ntt_fail_1() {
+    let mut out = vec![10; 4];
+    limbs_mul_greater_to_out_ntt(&mut out, &[6, 7], &[1, 2, 3]);
+}
+
+#[test]
+#[should_panic]
+fn limbs_mul_greater_to_out_ntt_fail_2() {
+    let mut out = vec![10; 4];
+    limbs_mul_greater_to_out_ntt(&mut out, &[6, 7, 8], &[1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn limbs_square_to_out_ntt_fail() {
+    let mut out = vec![10; 3];
+    limbs_square_to_out_ntt(&mut out, &[6, 7]);
+}
+
+#[test]
+fn limbs_mul_greater_to_out_ntt_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 2048);
+    config.insert("mean_stripe_n", 256 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_2().test_properties_with_config(&config, |(out_before, xs, ys)| {
+        let mut out = out_before.to_vec();
+        limbs_mul_greater_to_out_ntt(&mut out, &xs, &ys);
+        verify_limbs_mul_greater_to_out_fft(&out_before, &xs, &ys, &out);
+    });
+}
+
+#[test]
+fn limbs_square_to_out_ntt_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 2048);
+    config.insert("mean_stripe_n", 256 << Limb::LOG_WIDTH);
+    unsigned_vec_pair_gen_var_33().test_properties_with_config(&config, |(out_before, xs)| {
+        let mut out = out_before.to_vec();
+        limbs_square_to_out_ntt(&mut out, &xs);
+        verify_limbs_square_to_out_fft(&out_before, &xs, &out);
+    });
+}
+
 #[test]
 fn limbs_mul_low_same_length_properties() {
     let mut config = GenConfig::new();
@@ -12344,3 +12468,25 @@ fn product_properties() {
         assert_eq!(Natural::product([x, y].into_iter()), product);
     });
 }
+
+#[test]
+fn product_tree_properties() {
+    natural_vec_gen().test_properties(|xs| {
+        let tree = Natural::product_tree(&xs);
+        assert!(tree.iter().flatten().all(Natural::is_valid));
+        if xs.is_empty() {
+            assert!(tree.is_empty());
+            return;
+        }
+        assert_eq!(tree[0], xs);
+        for (level, next) in tree.iter().zip(tree.iter().skip(1)) {
+            assert_eq!(next.len(), (level.len() + 1) >> 1);
+            assert_eq!(
+                Natural::product(next.iter()),
+                Natural::product(level.iter())
+            );
+        }
+        assert_eq!(tree.last().unwrap().len(), 1);
+        assert_eq!(tree.last().unwrap()[0], Natural::product(xs.into_iter()));
+    });
+}
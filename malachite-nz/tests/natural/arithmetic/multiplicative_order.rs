@@ -0,0 +1,76 @@
+use malachite_base::num::arithmetic::traits::{
+    CarmichaelLambda, DivisibleBy, ModPow, MultiplicativeOrder,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_multiplicative_order() {
+    let test = |x, m, out| {
+        let x = Natural::from_str(x).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let factors = m.factor();
+        assert_eq!((&x).multiplicative_order(&m).to_debug_string(), out);
+        assert_eq!(
+            (&x).multiplicative_order_from_factors(&factors)
+                .to_debug_string(),
+            out
+        );
+        assert_eq!(
+            x.clone()
+                .multiplicative_order_from_factors(&factors)
+                .to_debug_string(),
+            out
+        );
+        assert_eq!(x.multiplicative_order(m).to_debug_string(), out);
+    };
+    test("0", "1", "Some(1)");
+    test("0", "2", "None");
+    test("2", "7", "Some(3)");
+    test("3", "7", "Some(6)");
+    test("6", "9", "None");
+    test("10", "21", "Some(6)");
+    test("5", "128", "Some(32)");
+    test("2", "1000000007", "Some(500000003)");
+    test("2", "18446744073709551557", "Some(18446744073709551556)");
+    test(
+        "3",
+        "170141183460469231731687303715884105727",
+        "Some(56713727820156410577229101238628035242)",
+    );
+    test(
+        "3",
+        "1267650600228229401496703205376",
+        "Some(316912650057057350374175801344)",
+    );
+}
+
+#[test]
+fn multiplicative_order_fail() {
+    assert_panic!(Natural::ZERO.multiplicative_order(Natural::ZERO));
+    assert_panic!(Natural::ONE.multiplicative_order(Natural::ONE));
+    assert_panic!((&Natural::from(7u32)).multiplicative_order(&Natural::from(7u32)));
+    assert_panic!(Natural::ONE.multiplicative_order_from_factors(&[]));
+}
+
+#[test]
+fn multiplicative_order_properties() {
+    unsigned_pair_gen_var_16::<u64>().test_properties_with_limit(SMALL_LIMIT, |(x, m)| {
+        let x_alt = Natural::from(x);
+        let m_alt = Natural::from(m);
+        let order = (&x_alt).multiplicative_order(&m_alt);
+        assert_eq!(order, x.multiplicative_order(m).map(Natural::from));
+        assert_eq!(x_alt.clone().multiplicative_order(m_alt.clone()), order);
+        if let Some(order) = order {
+            assert!(order.is_valid());
+            assert!(m_alt.carmichael_lambda().divisible_by(&order));
+            assert_eq!(x_alt.mod_pow(&order, &m_alt), Natural::ONE % &m_alt);
+        }
+    });
+}
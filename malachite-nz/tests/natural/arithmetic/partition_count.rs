@@ -0,0 +1,36 @@
+use malachite_base::num::arithmetic::traits::PartitionCount;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::partition_count::partition_count_naive;
+
+#[test]
+fn test_partition_count() {
+    fn test(n: u64, out: &str) {
+        let p = Natural::partition_count(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(partition_count_naive(n), p);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "3");
+    test(4, "5");
+    test(5, "7");
+    test(10, "42");
+    test(100, "190569292");
+    test(200, "3972999029388");
+    test(1000, "24061467864032622473692149727991");
+}
+
+#[test]
+fn partition_count_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let p = Natural::partition_count(n);
+        assert!(p.is_valid());
+        assert_eq!(partition_count_naive(n), p);
+        assert_ne!(p, 0u32);
+        assert!(Natural::partition_count(n + 1) >= p);
+    });
+}
@@ -0,0 +1,132 @@
+use malachite_base::num::arithmetic::traits::{IsPerfectPower, PerfectPowerDecompose, Pow};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_unsigned_pair_gen_var_7};
+use std::str::FromStr;
+
+#[test]
+fn test_perfect_power_decompose() {
+    let test = |s, root, exp| {
+        let n = Natural::from_str(s).unwrap();
+        let (root_alt, exp_alt) = n.clone().perfect_power_decompose();
+        assert!(root_alt.is_valid());
+        assert_eq!(root_alt.to_string(), root);
+        assert_eq!(exp_alt, exp);
+
+        let (root_alt, exp_alt) = (&n).perfect_power_decompose();
+        assert!(root_alt.is_valid());
+        assert_eq!(root_alt.to_string(), root);
+        assert_eq!(exp_alt, exp);
+
+        assert_eq!(n.is_perfect_power(), exp > 1 || n <= 1u32);
+        assert_eq!(
+            rug::Integer::from_str(s).unwrap().is_perfect_power(),
+            n.is_perfect_power()
+        );
+    };
+    test("0", "0", 1);
+    test("1", "1", 1);
+    test("2", "2", 1);
+    test("12", "12", 1);
+    test("1000", "10", 3);
+    test("18446744073709551615", "18446744073709551615", 1);
+    test("18446744073709551616", "2", 64);
+    test("340282366920938463463374607431768211456", "2", 128);
+    test(
+        "340282366920938463463374607431768211457",
+        "340282366920938463463374607431768211457",
+        1,
+    );
+    test(
+        "1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000\
+        0000000000000",
+        "10",
+        100,
+    );
+    test(
+        "910043815000214977332758527534256632492715260325658624",
+        "12",
+        50,
+    );
+    test(
+        "2095324917039863304313251555826661356467173660585553096278749223514308239748113453774362\
+        345525549271530430449123445790860832162183502447305727400816977024078369140625",
+        "45",
+        100,
+    );
+    test(
+        "3940200619639447919946311788461815331244649037200787691156008901052839015434239918150521\
+        7109422728930545305988890625",
+        "18446744073709551615",
+        6,
+    );
+    test(
+        "1503067252975253261938158507382962416125454065023441036581768042339598440262102647588295\
+        59272645143729222451201",
+        "2305843009213693951",
+        6,
+    );
+    test(
+        "3465837117651018563951546959352081782039555031571287326149653120011072614878757592034908\
+        29304450280262388005092129923564046385151",
+        "2305843009213693951",
+        7,
+    );
+    test(
+        "3465837117651018563951546959352081782039555031571287326149653120011072614878757592034908\
+        29304450280262388005092129923564046385153",
+        "3465837117651018563951546959352081782039555031571287326149653120011072614878757592034908\
+        29304450280262388005092129923564046385153",
+        1,
+    );
+    test(
+        "2037035976334486084501598617048975602566470615228603995309592795470263500528238325368094\
+        721",
+        "1427247692705959880439315947500961989719490561",
+        2,
+    );
+    test(
+        "98079714615416886807328322142267864008174058253966114808",
+        "4611686018427387902",
+        3,
+    );
+}
+
+#[test]
+fn perfect_power_decompose_properties() {
+    natural_gen().test_properties(|n| {
+        let (root, exp) = n.clone().perfect_power_decompose();
+        assert!(root.is_valid());
+        assert_eq!((&n).perfect_power_decompose(), (root.clone(), exp));
+        assert_ne!(exp, 0);
+        assert_eq!((&root).pow(exp), n);
+        let is_perfect_power = n.is_perfect_power();
+        assert_eq!(is_perfect_power, exp > 1 || n <= 1u32);
+        assert_eq!(rug::Integer::from(&n).is_perfect_power(), is_perfect_power);
+        if n > 1u32 {
+            assert!(!root.is_perfect_power());
+        }
+    });
+
+    natural_unsigned_pair_gen_var_7().test_properties(|(b, exp)| {
+        if b <= 1u32 {
+            return;
+        }
+        let n = (&b).pow(exp);
+        let (root, exp_alt) = (&n).perfect_power_decompose();
+        assert_eq!(exp_alt % exp, 0);
+        assert_eq!(root.pow(exp_alt / exp), b);
+        if exp > 1 {
+            assert!(n.is_perfect_power());
+        }
+    });
+
+    unsigned_gen::<u128>().test_properties(|n| {
+        let (root, exp) = n.perfect_power_decompose();
+        assert_eq!(
+            Natural::from(n).perfect_power_decompose(),
+            (Natural::from(root), exp)
+        );
+        assert_eq!(Natural::from(n).is_perfect_power(), n.is_perfect_power());
+    });
+}
@@ -0,0 +1,173 @@
+use malachite_base::num::arithmetic::traits::{DivMod, DivisibleBy, Mod, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_nz::natural::arithmetic::precomputed_divisor::PrecomputedDivisor;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{
+    natural_gen_var_2, natural_pair_gen_var_5, natural_pair_gen_var_6,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_precomputed_divisor() {
+    let test = |x, d, out| {
+        let x = Natural::from_str(x).unwrap();
+        let d = PrecomputedDivisor::new(&Natural::from_str(d).unwrap());
+        let (q, r) = d.div_mod(&x);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+        assert_eq!(d.mod_op(&x), r);
+        assert_eq!(d.divisible_by(&x), r == 0);
+        if r == 0 {
+            assert_eq!(d.div_exact(&x), q);
+        }
+    };
+    test("0", "1", "(0, 0)");
+    test("0", "123", "(0, 0)");
+    test("1", "1", "(1, 0)");
+    test("123", "1", "(123, 0)");
+    test("123", "123", "(1, 0)");
+    test("123", "456", "(0, 123)");
+    test("456", "123", "(3, 87)");
+    test("4294967295", "1", "(4294967295, 0)");
+    test("4294967295", "4294967295", "(1, 0)");
+    test("1000000000000", "1", "(1000000000000, 0)");
+    test("1000000000000", "3", "(333333333333, 1)");
+    test("1000000000000", "123", "(8130081300, 100)");
+    test("1000000000000", "4294967295", "(232, 3567587560)");
+    test(
+        "1000000000000000000000000",
+        "1234567890987",
+        "(810000006723, 530068894399)",
+    );
+    test(
+        "253640751230376270397812803167",
+        "2669936877441",
+        "(94998781946290113, 1520301762334)",
+    );
+    test(
+        "3768477692975601",
+        "11447376614057827956",
+        "(0, 3768477692975601)",
+    );
+    test(
+        "3356605361737854",
+        "3081095617839357",
+        "(1, 275509743898497)",
+    );
+    test(
+        "1098730198198174614195",
+        "953382298040157850476",
+        "(1, 145347900158016763719)",
+    );
+    test(
+        "69738658860594537152875081748",
+        "69738658860594537152875081748",
+        "(1, 0)",
+    );
+    test(
+        "1000000000000000000000000",
+        "1000000000000000000000000",
+        "(1, 0)",
+    );
+    test("0", "1000000000000000000000000", "(0, 0)");
+    test("123", "1000000000000000000000000", "(0, 123)");
+    test(
+        "340282366920938463463374607431768211455",
+        "18446744073709551616",
+        "(18446744073709551615, 18446744073709551615)",
+    );
+    test(
+        "340282366920938463463374607431768211455",
+        "340282366920938463463374607431768211455",
+        "(1, 0)",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "340282366920938463463374607431768211455",
+        "(340282366920938463463374607431768211457, 0)",
+    );
+    test(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+        "18446744073709551617",
+        "(6277101735386680763495507056286727952657427581105975853055, 0)",
+    );
+}
+
+#[test]
+fn test_precomputed_divisor_large() {
+    // Divisors on either side of the length at which Barrett division with a precomputed inverse
+    // starts being used
+    for &d_bits in &[6000, 16320, 16321, 20000] {
+        let d = Natural::power_of_2(d_bits) - Natural::from(12345u32);
+        let precomputed = PrecomputedDivisor::new(&d);
+        for &x_bits in &[d_bits, d_bits + 1, d_bits + 100, 2 * d_bits, 2 * d_bits + 1, 5 * d_bits] {
+            for x in [
+                Natural::power_of_2(x_bits) - Natural::ONE,
+                Natural::power_of_2(x_bits) - Natural::from(0x123456789abcdefu64),
+                Natural::power_of_2(x_bits - 1) + Natural::from(0x123456789abcdefu64),
+                &d * Natural::power_of_2(x_bits - d_bits),
+            ] {
+                let (q, r) = precomputed.div_mod(&x);
+                assert!(q.is_valid());
+                assert!(r.is_valid());
+                assert_eq!((&x).div_mod(&d), (q, r.clone()));
+                assert_eq!(precomputed.mod_op(&x), r);
+                assert_eq!(precomputed.divisible_by(&x), r == 0);
+            }
+            let x = Natural::power_of_2(x_bits) - Natural::from(3u32);
+            let product = &x * &d;
+            assert_eq!(precomputed.div_exact(&product), x);
+            assert!(precomputed.divisible_by(&product));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn precomputed_divisor_fail() {
+    PrecomputedDivisor::new(&Natural::ZERO);
+}
+
+#[test]
+fn precomputed_divisor_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 2048);
+    config.insert("mean_stripe_n", 256 << Limb::LOG_WIDTH);
+    natural_pair_gen_var_5().test_properties_with_config(&config, |(x, y)| {
+        let d = PrecomputedDivisor::new(&y);
+        assert_eq!(*d.divisor(), y);
+        let (q, r) = d.div_mod(&x);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&x).div_mod(&y), (q.clone(), r.clone()));
+        assert_eq!(d.mod_op(&x), r);
+        assert_eq!((&x).mod_op(&y), r);
+        assert_eq!(d.divisible_by(&x), r == 0);
+        assert_eq!((&x).divisible_by(&y), r == 0);
+        assert!(r < y);
+        assert_eq!(q * &y + r, x);
+    });
+
+    natural_pair_gen_var_6().test_properties_with_config(&config, |(x, y)| {
+        let d = PrecomputedDivisor::new(&y);
+        let q = d.div_exact(&x);
+        assert!(q.is_valid());
+        assert_eq!((&x).div_mod(&y).0, q);
+        assert!(d.divisible_by(&x));
+        assert_eq!(q * y, x);
+    });
+
+    natural_gen_var_2().test_properties(|x| {
+        let d = PrecomputedDivisor::new(&x);
+        assert_eq!(d.div_mod(&x), (Natural::ONE, Natural::ZERO));
+        assert_eq!(d.div_mod(&Natural::ZERO), (Natural::ZERO, Natural::ZERO));
+        assert!(d.divisible_by(&Natural::ZERO));
+        let one = PrecomputedDivisor::new(&Natural::ONE);
+        assert_eq!(one.div_mod(&x), (x.clone(), Natural::ZERO));
+    });
+}
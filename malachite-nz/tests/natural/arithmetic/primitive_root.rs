@@ -0,0 +1,49 @@
+use malachite_base::num::arithmetic::traits::{MultiplicativeOrder, PrimitiveRoot, Totient};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_primitive_root() {
+    let test = |n, out| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.primitive_root().to_debug_string(), out);
+        assert_eq!(
+            Natural::primitive_root_from_factors(&n.factor()).to_debug_string(),
+            out
+        );
+    };
+    test("1", "Some(0)");
+    test("2", "Some(1)");
+    test("4", "Some(3)");
+    test("7", "Some(3)");
+    test("8", "None");
+    test("15", "None");
+    test("18", "Some(5)");
+    test("998244353", "Some(3)");
+    test("2305843009213693951", "Some(37)");
+    test("170141183460469231731687303715884105727", "Some(43)");
+}
+
+#[test]
+#[should_panic]
+fn primitive_root_fail() {
+    Natural::ZERO.primitive_root();
+}
+
+#[test]
+fn primitive_root_properties() {
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let n_alt = Natural::from(n);
+        let root = n_alt.primitive_root();
+        assert_eq!(root, n.primitive_root().map(Natural::from));
+        if let Some(root) = root {
+            assert!(root.is_valid());
+            assert_eq!((&root).multiplicative_order(&n_alt), Some(n_alt.totient()));
+        }
+    });
+}
@@ -0,0 +1,185 @@
+use malachite_base::num::arithmetic::traits::{
+    DivExact, EqMod, FloorSqrt, Gcd, ModInverse, ModMul, ModNeg, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_8, natural_triple_gen};
+use malachite_nz::test_util::natural::arithmetic::rational_reconstruction::*;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn default_bound(m: &Natural) -> Natural {
+    ((m - Natural::ONE) >> 1u32).floor_sqrt()
+}
+
+#[test]
+fn test_rational_reconstruction() {
+    let test = |a, m, n, d, out| {
+        let a = Natural::from_str(a).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let n = Natural::from_str(n).unwrap();
+        let d = Natural::from_str(d).unwrap();
+        let result = a.rational_reconstruction(&m, &n, &d);
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(rational_reconstruction_simple(&a, &m, &n, &d), result);
+    };
+    test("0", "1", "0", "0", "None");
+    test("0", "2", "0", "0", "None");
+    test("0", "3", "1", "1", "Some((0, 1))");
+    test("1", "3", "1", "1", "Some((1, 1))");
+    test("2", "3", "1", "1", "Some((-1, 1))");
+    test("23", "71", "5", "5", "Some((-2, 3))");
+    test("10", "71", "5", "5", "None");
+    test("10", "71", "10", "3", "Some((10, 1))");
+    test("10", "71", "3", "10", "Some((-1, 7))");
+    test("50", "100", "3", "10", "None");
+    test(
+        "7905747460161236410",
+        "18446744073709551616",
+        "3037000499",
+        "3037000499",
+        "Some((22, 7))",
+    );
+    test(
+        "778761061946902654867256637165",
+        "1000000000000000000000000000000",
+        "1000",
+        "1000",
+        "Some((-355, 113))",
+    );
+    test(
+        "778761061946902654867256637165",
+        "1000000000000000000000000000000",
+        "100",
+        "1000",
+        "None",
+    );
+}
+
+#[test]
+fn rational_reconstruction_fail() {
+    assert_panic!(Natural::from(5u32).rational_reconstruction(
+        &Natural::from(5u32),
+        &Natural::ONE,
+        &Natural::ONE
+    ));
+    assert_panic!(Natural::ZERO.rational_reconstruction(
+        &Natural::from(8u32),
+        &Natural::from(2u32),
+        &Natural::from(2u32)
+    ));
+}
+
+// Reconstructs `p / q` from its residue modulo `m`, where the inputs are large enough for the
+// half-GCD algorithm to be used.
+fn large_test_helper(p: &Integer, q: &Natural, m: &Natural, n: &Natural, d: &Natural) {
+    let inverse = (q % m).mod_inverse(m).unwrap();
+    let a = (p.unsigned_abs_ref() % m).mod_mul(inverse, m);
+    let a = if *p >= 0u32 { a } else { a.mod_neg(m) };
+    let result = a.rational_reconstruction(m, n, d);
+    let g = p.unsigned_abs_ref().gcd(q);
+    assert_eq!(
+        result,
+        Some((p.div_exact(Integer::from(&g)), q.div_exact(&g)))
+    );
+    assert_eq!(rational_reconstruction_simple(&a, m, n, d), result);
+}
+
+#[test]
+fn test_rational_reconstruction_large() {
+    let m = Natural::ONE << 20000u32;
+    let bound = default_bound(&m);
+    for i in 0..5u64 {
+        let p = Integer::from(-3i32).pow(6000 + i);
+        let q = Natural::from(5u32).pow(4000 + 3 * i) + Natural::from(2 * i);
+        large_test_helper(&p, &q, &m, &bound, &bound);
+        large_test_helper(&-p, &q, &m, &bound, &bound);
+    }
+    // Small numerator and denominator, large modulus
+    let m = Natural::from(3u32).pow(20000) + Natural::from(2u32);
+    let bound = Natural::ONE << 500u32;
+    large_test_helper(
+        &Integer::from(12345678),
+        &Natural::from(7u32).pow(170),
+        &m,
+        &bound,
+        &bound,
+    );
+    // Unbalanced bounds
+    let m = Natural::ONE << 30000u32;
+    let n = Natural::ONE << 25000u32;
+    let d = Natural::ONE << 4000u32;
+    large_test_helper(
+        &-Integer::from(Natural::from(11u32).pow(7000)),
+        &Natural::from(13u32).pow(1000),
+        &m,
+        &n,
+        &d,
+    );
+    let n = Natural::ONE << 1000u32;
+    let d = Natural::ONE << 28000u32;
+    large_test_helper(
+        &Integer::from(Natural::from(11u32).pow(250)),
+        &(Natural::from(3u32).pow(17000) + Natural::from(2u32)),
+        &m,
+        &n,
+        &d,
+    );
+    // Residues that are not small fractions, so that the result is usually `None`, but the full
+    // remainder sequence must still be followed
+    let m = Natural::ONE << 20000u32;
+    let bounds = [
+        (default_bound(&m), default_bound(&m)),
+        (Natural::ONE << 2000u32, Natural::ONE << 2000u32),
+        (Natural::ONE << 15000u32, Natural::ONE << 4000u32),
+        (Natural::ONE << 100u32, Natural::ONE << 19000u32),
+    ];
+    for i in 0..4u64 {
+        let a = Natural::from(3u32).pow(12345 + 1000 * i) % &m;
+        for (n, d) in &bounds {
+            assert_eq!(
+                a.rational_reconstruction(&m, n, d),
+                rational_reconstruction_simple(&a, &m, n, d)
+            );
+        }
+    }
+}
+
+#[test]
+fn rational_reconstruction_properties() {
+    natural_pair_gen_var_8().test_properties(|(a, m)| {
+        let bound = default_bound(&m);
+        let result = a.rational_reconstruction(&m, &bound, &bound);
+        assert_eq!(
+            rational_reconstruction_simple(&a, &m, &bound, &bound),
+            result
+        );
+        if let Some((p, q)) = result {
+            assert!(p.unsigned_abs_ref() <= &bound);
+            assert_ne!(q, 0u32);
+            assert!(q <= bound);
+            assert_eq!(p.unsigned_abs_ref().gcd(&q), 1u32);
+            assert_eq!((&q).gcd(&m), 1u32);
+            assert!((Integer::from(&q) * Integer::from(&a)).eq_mod(p, m));
+        }
+    });
+
+    natural_triple_gen().test_properties(|(p, q, k)| {
+        let q = q + Natural::ONE;
+        let m = ((&p * &q) << 1u32) + k + Natural::ONE;
+        if m == 1u32 || (&q).gcd(&m) != 1u32 {
+            return;
+        }
+        let a = (&p % &m).mod_mul((&q % &m).mod_inverse(&m).unwrap(), &m);
+        let g = (&p).gcd(&q);
+        let expected = Some((Integer::from((&p).div_exact(&g)), (&q).div_exact(&g)));
+        assert_eq!(a.rational_reconstruction(&m, &p, &q), expected);
+        let a = a.mod_neg(&m);
+        assert_eq!(
+            a.rational_reconstruction(&m, &p, &q),
+            expected.map(|(p, q)| (-p, q))
+        );
+    });
+}
@@ -0,0 +1,174 @@
+use malachite_base::num::arithmetic::traits::{
+    Bell, BinomialCoefficient, Factorial, StirlingFirst, StirlingSecond,
+};
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_gen_var_28};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::stirling::{
+    bell_naive, stirling_first_naive, stirling_second_naive,
+};
+
+#[test]
+fn test_stirling_first() {
+    fn test(n: u64, k: u64, out: &str) {
+        let s = Natural::stirling_first(n, k);
+        assert!(s.is_valid());
+        assert_eq!(s.to_string(), out);
+        assert_eq!(stirling_first_naive(n, k), s);
+    }
+    test(0, 0, "1");
+    test(0, 1, "0");
+    test(1, 0, "0");
+    test(1, 1, "1");
+    test(5, 1, "24");
+    test(5, 2, "50");
+    test(5, 4, "10");
+    test(5, 5, "1");
+    test(5, 6, "0");
+    test(10, 5, "269325");
+    test(20, 3, "668609730341153280");
+    test(20, 17, "920550");
+    test(
+        100,
+        50,
+        "318322278235296438474435412072968606417560943939705506371757866876922711307183638219873969\
+        7421125692626030268475",
+    );
+}
+
+#[test]
+fn test_stirling_second() {
+    fn test(n: u64, k: u64, out: &str) {
+        let s = Natural::stirling_second(n, k);
+        assert!(s.is_valid());
+        assert_eq!(s.to_string(), out);
+        assert_eq!(stirling_second_naive(n, k), s);
+    }
+    test(0, 0, "1");
+    test(0, 1, "0");
+    test(1, 0, "0");
+    test(1, 1, "1");
+    test(5, 1, "1");
+    test(5, 2, "15");
+    test(5, 4, "10");
+    test(5, 5, "1");
+    test(5, 6, "0");
+    test(10, 5, "42525");
+    test(20, 3, "580606446");
+    test(20, 17, "741285");
+    test(
+        100,
+        50,
+        "430983237009366340421514301547258695943520289614340613912441741131280319058853783145598261\
+        659992013900",
+    );
+}
+
+#[test]
+fn test_bell() {
+    fn test(n: u64, out: &str) {
+        let b = Natural::bell(n);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+        assert_eq!(bell_naive(n), b);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "5");
+    test(5, "52");
+    test(20, "51724158235372");
+    test(26, "49631246523618756274");
+    test(27, "545717047936059989389");
+    test(
+        100,
+        "475853912767648336587907688413872078263636696868256114666163346375591144978924426226727240\
+        44217756306953557882560751",
+    );
+}
+
+#[test]
+fn stirling_first_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        let s = Natural::stirling_first(n, k);
+        assert!(s.is_valid());
+        assert_eq!(stirling_first_naive(n, k), s);
+        assert!(s >= Natural::stirling_second(n, k));
+        assert_eq!(s == 0u32, k > n || (k == 0 && n != 0));
+        if k != 0 {
+            assert_eq!(
+                Natural::stirling_first(n + 1, k),
+                Natural::from(n) * &s + Natural::stirling_first(n, k - 1)
+            );
+        }
+    });
+
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        assert_eq!(Natural::stirling_first(n, n), 1u32);
+        if n != 0 {
+            assert_eq!(Natural::stirling_first(n, 1), Natural::factorial(n - 1));
+            assert_eq!(
+                Natural::stirling_first(n, n - 1),
+                Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32))
+            );
+        }
+    });
+}
+
+#[test]
+fn stirling_second_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        let s = Natural::stirling_second(n, k);
+        assert!(s.is_valid());
+        assert_eq!(stirling_second_naive(n, k), s);
+        assert_eq!(s == 0u32, k > n || (k == 0 && n != 0));
+        if k != 0 {
+            assert_eq!(
+                Natural::stirling_second(n + 1, k),
+                Natural::from(k) * &s + Natural::stirling_second(n, k - 1)
+            );
+        }
+    });
+
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        assert_eq!(Natural::stirling_second(n, n), 1u32);
+        if n != 0 {
+            assert_eq!(Natural::stirling_second(n, 1), 1u32);
+            assert_eq!(
+                Natural::stirling_second(n, n - 1),
+                Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32))
+            );
+        }
+    });
+}
+
+#[test]
+fn bell_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let b = Natural::bell(n);
+        assert!(b.is_valid());
+        assert_eq!(bell_naive(n), b);
+        assert_ne!(b, 0u32);
+        assert!(Natural::bell(n + 1) >= b);
+        // Summing whole rows takes cubic time, so only do it for small n.
+        if n >= 64 {
+            return;
+        }
+        // B_n is the sum of the nth row of Stirling numbers of the second kind.
+        assert_eq!(
+            (0..=n)
+                .map(|k| Natural::stirling_second(n, k))
+                .sum::<Natural>(),
+            Natural::bell(n)
+        );
+        // n! is the sum of the nth row of Stirling numbers of the first kind.
+        assert_eq!(
+            (0..=n)
+                .map(|k| Natural::stirling_first(n, k))
+                .sum::<Natural>(),
+            Natural::factorial(n)
+        );
+    });
+}
@@ -0,0 +1,69 @@
+use malachite_base::num::arithmetic::traits::Totient;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, Primes};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_totient() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let out = Natural::from_str(out).unwrap();
+        assert_eq!(n.totient(), out);
+        assert_eq!(Natural::totient_from_factors(&n.factor()), out);
+    };
+    test("1", "1");
+    test("2", "1");
+    test("12", "4");
+    test("1000000007", "1000000006");
+    test("18446744073709551615", "9208981628670443520");
+    test("18446744073709551616", "9223372036854775808");
+    test(
+        "1267650600228229401496703205376",
+        "633825300114114700748351602688",
+    );
+    // 2^128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        "340282366920938457758625757157511659520",
+    );
+    // 10^30 - 1
+    test(
+        "999999999999999999999999999999",
+        "434730593657789743104000000000",
+    );
+}
+
+#[test]
+#[should_panic]
+fn totient_fail() {
+    Natural::from(0u32).totient();
+}
+
+#[test]
+fn totient_properties() {
+    unsigned_vec_gen::<u16>().test_properties_with_limit(SMALL_LIMIT, |xs| {
+        if xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        let factors = n.factor();
+        let phi = n.totient();
+        assert_eq!(Natural::totient_from_factors(&factors), phi);
+        assert!(phi <= n);
+        // phi(n) * prod(p) == n * prod(p - 1)
+        let rad: Natural = factors.iter().map(|(p, _)| p).product();
+        let rad_minus: Natural = factors.iter().map(|(p, _)| p - Natural::ONE).product();
+        assert_eq!(phi * rad, n * rad_minus);
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        assert_eq!(Natural::from(n).totient(), n.totient());
+    });
+
+    for p in Natural::primes().take(100) {
+        assert_eq!(p.totient(), &p - Natural::ONE);
+    }
+}
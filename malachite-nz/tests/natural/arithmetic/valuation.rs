@@ -0,0 +1,190 @@
+use malachite_base::num::arithmetic::traits::{
+    DivisibleBy, IsPowerOf2, Pow, RemoveFactor, Valuation,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_24;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_pair_gen_var_3;
+use malachite_nz::test_util::natural::arithmetic::valuation::remove_factor_naive;
+use std::str::FromStr;
+
+#[test]
+fn test_valuation() {
+    let test = |n, p, cofactor, v| {
+        let n = Natural::from_str(n).unwrap();
+        let p = Natural::from_str(p).unwrap();
+        assert_eq!((&n).valuation(&p), v);
+        assert_eq!(n.clone().valuation(&p), v);
+
+        let (c, v_alt) = (&n).remove_factor(&p);
+        assert!(c.is_valid());
+        assert_eq!(c.to_string(), cofactor);
+        assert_eq!(v_alt, v);
+
+        let (c, v_alt) = n.clone().remove_factor(&p);
+        assert!(c.is_valid());
+        assert_eq!(c.to_string(), cofactor);
+        assert_eq!(v_alt, v);
+
+        assert_eq!(
+            remove_factor_naive(n, &p).to_debug_string(),
+            format!("({}, {})", cofactor, v)
+        );
+    };
+    test("1", "2", "1", 0);
+    test("1", "3", "1", 0);
+    test("12", "2", "3", 2);
+    test("12", "3", "4", 1);
+    test("12", "4", "3", 1);
+    test("12", "5", "12", 0);
+    test("12", "8", "12", 0);
+    test("12", "12", "1", 1);
+    test("12", "13", "12", 0);
+    test("1000000000000", "10", "1", 12);
+    test("1000000000000", "100", "1", 6);
+    test("1000000000000", "1000000", "1", 2);
+    test("1000000000000", "10000000", "100000", 1);
+    test("1000000000000", "7", "1000000000000", 0);
+    test("18446744073709551616", "2", "1", 64);
+    test("18446744073709551616", "16", "1", 16);
+    test("18446744073709551616", "32", "16", 12);
+    test("18446744073709551616", "18446744073709551616", "1", 1);
+    test(
+        "18446744073709551616",
+        "18446744073709551617",
+        "18446744073709551616",
+        0,
+    );
+    // 3^100
+    test(
+        "515377520732011331036461129765621272702107522001",
+        "3",
+        "1",
+        100,
+    );
+    test(
+        "515377520732011331036461129765621272702107522001",
+        "27",
+        "3",
+        33,
+    );
+    test(
+        "515377520732011331036461129765621272702107522001",
+        "515377520732011331036461129765621272702107522001",
+        "1",
+        1,
+    );
+    // 10^30 - 1 = 3^3 * 7 * 11 * 13 * 31 * 37 * 41 * 211 * 241 * 271 * 2161 * 9091 * 2906161
+    test(
+        "999999999999999999999999999999",
+        "3",
+        "37037037037037037037037037037",
+        3,
+    );
+    test(
+        "999999999999999999999999999999",
+        "9",
+        "111111111111111111111111111111",
+        1,
+    );
+}
+
+#[test]
+#[should_panic]
+fn valuation_fail_1() {
+    Natural::from(0u32).valuation(&Natural::from(2u32));
+}
+
+#[test]
+#[should_panic]
+fn valuation_fail_2() {
+    Natural::from(10u32).valuation(&Natural::ONE);
+}
+
+#[test]
+#[should_panic]
+fn valuation_fail_3() {
+    Natural::from(10u32).valuation(&Natural::from(0u32));
+}
+
+#[test]
+#[should_panic]
+fn remove_factor_fail_1() {
+    Natural::from(0u32).remove_factor(&Natural::from(2u32));
+}
+
+#[test]
+#[should_panic]
+fn remove_factor_fail_2() {
+    (&Natural::from(10u32)).remove_factor(&Natural::ONE);
+}
+
+#[test]
+fn test_valuation_large() {
+    let test = |c: Natural, p: Natural, k: u64| {
+        let n = &c * (&p).pow(k);
+        let (cofactor, v) = (&n).remove_factor(&p);
+        assert!(cofactor.is_valid());
+        assert_eq!(v, k);
+        assert_eq!(cofactor, c);
+        assert_eq!(n.valuation(&p), k);
+    };
+    for k in [0, 1, 2, 3, 7, 8, 9, 100, 255, 256, 257, 1000, 4095, 4096, 4097] {
+        test(Natural::from(7u32), Natural::from(3u32), k);
+        test(Natural::from(10u32), Natural::from(9u32), k);
+        test(Natural::from(11u32), Natural::from(6u32), k);
+        test(Natural::from(1u32), Natural::from(1000000007u32), k);
+        // p does not fit in a limb
+        test(
+            Natural::from(5u32),
+            Natural::from_str("340282366920938463463374607431768211459").unwrap(),
+            k,
+        );
+        test(
+            Natural::from(5u32).pow(100),
+            Natural::from_str("340282366920938463463374607431768211459").unwrap(),
+            k >> 2,
+        );
+    }
+}
+
+#[test]
+fn valuation_properties() {
+    natural_pair_gen_var_3().test_properties(|(n, p)| {
+        let v = (&n).valuation(&p);
+        assert_eq!(n.clone().valuation(&p), v);
+
+        let (cofactor, v_alt) = (&n).remove_factor(&p);
+        assert!(cofactor.is_valid());
+        assert_eq!(v_alt, v);
+        assert_eq!(n.clone().remove_factor(&p), (cofactor.clone(), v));
+        assert_eq!(remove_factor_naive(n.clone(), &p), (cofactor.clone(), v));
+
+        assert_ne!(cofactor, 0u32);
+        assert!(!(&cofactor).divisible_by(&p));
+        assert_eq!(&cofactor * (&p).pow(v), n);
+        assert_eq!(v != 0, (&n).divisible_by(&p));
+        if p.is_power_of_2() {
+            assert_eq!(v, n.trailing_zeros().unwrap() / (p.significant_bits() - 1));
+        }
+        for k in 0..4 {
+            assert_eq!((&n * (&p).pow(k)).valuation(&p), v + k);
+        }
+        assert_eq!(cofactor.valuation(&p), 0);
+    });
+
+    unsigned_pair_gen_var_24::<Limb, Limb>().test_properties(|(n, p)| {
+        let (cofactor, v) = n.remove_factor(p);
+        assert_eq!(
+            Natural::from(n).remove_factor(&Natural::from(p)),
+            (Natural::from(cofactor), v)
+        );
+        assert_eq!(
+            Natural::from(n).valuation(&Natural::from(p)),
+            n.valuation(p)
+        );
+    });
+}
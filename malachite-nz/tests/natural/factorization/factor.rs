@@ -0,0 +1,138 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, IsPrime, Primes};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_vec_gen};
+use malachite_nz::natural::factorization::factor::{
+    natural_ecm_one_curve, natural_pollard_brent_rho,
+};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+fn merge(factors: impl Iterator<Item = (Natural, u64)>) -> Vec<(Natural, u64)> {
+    let mut merged: Vec<(Natural, u64)> = Vec::new();
+    for (p, e) in factors.sorted() {
+        match merged.last_mut() {
+            Some((q, f)) if *q == p => *f += e,
+            _ => merged.push((p, e)),
+        }
+    }
+    merged
+}
+
+#[test]
+fn test_factor() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let factors = n.factor();
+        assert_eq!(factors.to_debug_string(), out);
+        assert_eq!(
+            factors.iter().map(|(p, e)| p.pow(*e)).product::<Natural>(),
+            n
+        );
+    };
+    test("1", "[]");
+    test("2", "[(2, 1)]");
+    test("360", "[(2, 3), (3, 2), (5, 1)]");
+    test("1000000007", "[(1000000007, 1)]");
+    test(
+        "18446744073709551615",
+        "[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]",
+    );
+    test("18446744073709551616", "[(2, 64)]");
+    test("18446744073709551629", "[(18446744073709551629, 1)]");
+    // 2^64 + 1
+    test("18446744073709551617", "[(274177, 1), (67280421310721, 1)]");
+    // 2^128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        "[(59649589127497217, 1), (5704689200685129054721, 1)]",
+    );
+    // 10^30 - 1
+    test(
+        "999999999999999999999999999999",
+        "[(3, 3), (7, 1), (11, 1), (13, 1), (31, 1), (37, 1), (41, 1), (211, 1), (241, 1), \
+        (271, 1), (2161, 1), (9091, 1), (2906161, 1)]",
+    );
+    // (2^31 - 1)^5
+    test(
+        "45671926060252476630107084286792841360213803007",
+        "[(2147483647, 5)]",
+    );
+    // A power of a product of two 40-bit primes
+    test(
+        "1461501637139494086813768722078029478696349990001",
+        "[(1099511627689, 2), (1099511627791, 2)]",
+    );
+    // A product of a 56-bit prime and a 71-bit prime
+    test(
+        "70421805103159615449676617095090001433",
+        "[(59649589127497217, 1), (1180591620717411303449, 1)]",
+    );
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "[(170141183460469231731687303715884105727, 1)]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn factor_fail() {
+    Natural::from(0u32).factor();
+}
+
+#[test]
+fn test_natural_pollard_brent_rho() {
+    let n = Natural::from_str("18446743979220271189").unwrap();
+    let g = natural_pollard_brent_rho(&n, &Natural::ONE, u64::MAX).unwrap();
+    assert!(g == 4294967279u32 || g == 4294967291u32);
+    assert_eq!(natural_pollard_brent_rho(&n, &Natural::ONE, 1), None);
+}
+
+#[test]
+fn test_natural_ecm_one_curve() {
+    let n = Natural::from_str("70421805103159615449676617095090001433").unwrap();
+    let g = (6..)
+        .find_map(|sigma| natural_ecm_one_curve(&n, sigma, 2000, 200000))
+        .unwrap();
+    assert_eq!(g, 59649589127497217u64);
+}
+
+#[test]
+fn factor_properties() {
+    // Each u16 factors quickly, and the product's factorization must merge theirs.
+    unsigned_vec_gen::<u16>().test_properties(|xs| {
+        if xs.contains(&0) {
+            return;
+        }
+        let n: Natural = xs.iter().map(|&x| Natural::from(x)).product();
+        let factors = n.factor();
+        assert!(factors.iter().all(|(p, e)| p.is_prime() && *e != 0));
+        assert!(factors.iter().tuple_windows().all(|((p, _), (q, _))| p < q));
+        assert_eq!(
+            factors,
+            merge(
+                xs.iter()
+                    .flat_map(Factor::factor)
+                    .map(|(p, e)| (Natural::from(p), e))
+            )
+        );
+    });
+
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        assert_eq!(
+            Natural::from(n).factor(),
+            n.factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), e))
+                .collect_vec()
+        );
+    });
+
+    for p in Natural::primes().take(100) {
+        assert_eq!(p.factor(), &[(p.clone(), 1)]);
+        assert_eq!((&p).pow(10).factor(), &[(p, 10)]);
+    }
+}
@@ -0,0 +1,91 @@
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Parity};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::factorization::is_prime::{
+    natural_is_prime_baillie_psw, natural_is_strong_lucas_probable_prime,
+    natural_is_strong_probable_prime,
+};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen_var_2};
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |n: &str, out: bool| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.is_prime(), out);
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("3", true);
+    test("4", false);
+    test("91", false);
+    test("97", true);
+    test("3215031751", false);
+    test("3825123056546413051", false);
+    test("18446744073709551557", true);
+    test("18446744073709551615", false);
+    test("18446744073709551629", true);
+    // The smallest strong pseudoprime to the first 12 prime bases
+    test("318665857834031151167461", false);
+    // A strong pseudoprime to base 2, equal to 8589937621 * 17179875241
+    test("147574056656752341661", false);
+    test("1000000000000000000000000000057", true);
+    test("340282366920938463463374607431768211297", true);
+    // 2^127 - 1 and 2^521 - 1 are Mersenne primes
+    test("170141183460469231731687303715884105727", true);
+    test(
+        "686479766013060971498190079908139321726943530014330540939446345918554318339765605212255\
+        9640661454554977296311391480858037121987999716643812574028291115057151",
+        true,
+    );
+    // 2^128 + 1 = 59649589127497217 * 5704689200685129054721
+    test("340282366920938463463374607431768211457", false);
+    // The product of two 64-bit primes
+    test("340282366920938462614824380041128836353", false);
+    // The square of a 64-bit prime
+    test("340282366920938461286658806734041124249", false);
+
+    let n = Natural::from_str("147574056656752341661").unwrap();
+    assert!(natural_is_strong_probable_prime(&n, &Natural::from(2u32)));
+    assert!(!natural_is_strong_lucas_probable_prime(&n));
+}
+
+#[test]
+fn is_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let p = n.is_prime();
+        if p && n != 2u32 {
+            assert!(n.odd());
+        }
+        if let Ok(n_64) = u64::try_from(&n) {
+            assert_eq!(n_64.is_prime(), p);
+        }
+        if n > 2u32 && n.odd() && !(3u32..53).any(|q| (&n).divisible_by(Natural::from(q))) {
+            assert_eq!(natural_is_prime_baillie_psw(&n), p);
+        }
+        if p && n > 2u32 {
+            assert!(natural_is_strong_probable_prime(&n, &Natural::from(2u32)));
+            if n > 53u32 {
+                assert!(natural_is_strong_lucas_probable_prime(&n));
+            }
+        }
+    });
+
+    natural_pair_gen_var_2().test_properties(|(a, b)| {
+        if a > 1u32 {
+            assert!(!(a * b).is_prime());
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        assert_eq!(Natural::from(n).is_prime(), n.is_prime());
+    });
+
+    for p in Natural::primes().take(1000) {
+        assert!(p.is_prime());
+        assert!(!(&p + Natural::ONE).is_prime() || p == 2u32);
+    }
+}
@@ -0,0 +1,75 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::factorization::traits::{
+    CheckedNextPrime, IsPrime, NextPrime, PreviousPrime,
+};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_next_prime() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let p = n.clone().next_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+
+        let p_alt = (&n).next_prime();
+        assert!(p_alt.is_valid());
+        assert_eq!(p_alt, p);
+    };
+    test("0", "2");
+    test("1", "2");
+    test("2", "3");
+    test("3", "5");
+    test("10", "11");
+    test("1000000000", "1000000007");
+    test("18446744073709551556", "18446744073709551557");
+    test("18446744073709551557", "18446744073709551629");
+    test("18446744073709551615", "18446744073709551629");
+    test("18446744073709551616", "18446744073709551629");
+    test("18446744073709551629", "18446744073709551653");
+    test(
+        "1267650600228229401496703205376",
+        "1267650600228229401496703205653",
+    );
+}
+
+#[test]
+fn next_prime_properties() {
+    // Prime tests on large Naturals are slow, so smaller Naturals are generated.
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 32);
+    config.insert("mean_stripe_n", 16);
+    natural_gen().test_properties_with_config(&config, |n| {
+        let p = (&n).next_prime();
+        assert!(p.is_valid());
+        assert_eq!(n.clone().next_prime(), p);
+        assert!(p > n);
+        assert!(p.is_prime());
+        assert!((&p).previous_prime().map_or(true, |q| q <= n));
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        if let Some(p) = n.checked_next_prime() {
+            assert_eq!(Natural::from(n).next_prime(), p);
+        }
+    });
+
+    let mut n = Natural::power_of_2(64);
+    let ps = (0..10)
+        .map(|_| {
+            n = (&n).next_prime();
+            n.clone()
+        })
+        .collect_vec();
+    assert_eq!(
+        Natural::primes_greater_than_or_equal_to(&Natural::power_of_2(64))
+            .take(10)
+            .collect_vec(),
+        ps
+    );
+}
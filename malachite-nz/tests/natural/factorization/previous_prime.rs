@@ -0,0 +1,63 @@
+use malachite_base::num::basic::traits::Two;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PreviousPrime};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_previous_prime() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let p = n.clone().previous_prime();
+        assert!(p.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(p.to_debug_string(), out);
+
+        let p_alt = (&n).previous_prime();
+        assert!(p_alt.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(p_alt, p);
+    };
+    test("0", "None");
+    test("1", "None");
+    test("2", "None");
+    test("3", "Some(2)");
+    test("10", "Some(7)");
+    test("1000000000", "Some(999999937)");
+    test("18446744073709551615", "Some(18446744073709551557)");
+    test("18446744073709551616", "Some(18446744073709551557)");
+    test("18446744073709551629", "Some(18446744073709551557)");
+    test("18446744073709551630", "Some(18446744073709551629)");
+    test(
+        "1267650600228229401496703205376",
+        "Some(1267650600228229401496703205361)",
+    );
+}
+
+#[test]
+fn previous_prime_properties() {
+    // Prime tests on large Naturals are slow, so smaller Naturals are generated.
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 32);
+    config.insert("mean_stripe_n", 16);
+    natural_gen().test_properties_with_config(&config, |n| {
+        let p = (&n).previous_prime();
+        assert!(p.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(n.clone().previous_prime(), p);
+        assert_eq!(p.is_some(), n > Natural::TWO);
+        if let Some(p) = p {
+            assert!(p < n);
+            assert!(p.is_prime());
+            // There are no primes strictly between p and n
+            assert!(p.next_prime() >= n);
+        }
+    });
+
+    unsigned_gen::<u64>().test_properties(|n| {
+        assert_eq!(
+            Natural::from(n).previous_prime(),
+            n.previous_prime().map(Natural::from)
+        );
+    });
+}
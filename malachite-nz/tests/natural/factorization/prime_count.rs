@@ -0,0 +1,85 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{IsPrime, NthPrime, PrimeCount};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_11};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_prime_count() {
+    let test = |n: &str, out: &str| {
+        let count = Natural::from_str(n).unwrap().prime_count();
+        assert!(count.is_valid());
+        assert_eq!(count.to_string(), out);
+    };
+    test("0", "0");
+    test("1", "0");
+    test("2", "1");
+    test("10", "4");
+    test("65536", "6542");
+    test("1000000000", "50847534");
+    test("4294967296", "203280221");
+    test("10000000000", "455052511");
+}
+
+#[test]
+#[should_panic]
+fn prime_count_fail() {
+    Natural::power_of_2(64).prime_count();
+}
+
+#[test]
+fn test_nth_prime() {
+    let test = |n: u64, out: &str| {
+        let p = Natural::nth_prime(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test(1, "2");
+    test(2, "3");
+    test(10, "29");
+    test(6543, "65537");
+    test(1000000, "15485863");
+    test(1000000000, "22801763489");
+}
+
+#[test]
+#[should_panic]
+fn nth_prime_fail_1() {
+    Natural::nth_prime(0);
+}
+
+#[test]
+#[should_panic]
+fn nth_prime_fail_2() {
+    Natural::nth_prime(425656284035217744);
+}
+
+#[test]
+fn prime_count_properties() {
+    unsigned_gen::<u32>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let n = n >> 12;
+        let x = Natural::from(n);
+        let count = x.prime_count();
+        assert!(count.is_valid());
+        assert_eq!(count, n.prime_count());
+        if n != 0 {
+            assert_eq!(
+                &count - (&x - Natural::ONE).prime_count(),
+                u32::from(x.is_prime())
+            );
+        }
+    });
+}
+
+#[test]
+fn nth_prime_properties() {
+    unsigned_gen_var_11::<u64>().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let p = Natural::nth_prime(n);
+        assert!(p.is_valid());
+        assert!(p.is_prime());
+        assert_eq!(p, u64::nth_prime(n));
+        assert_eq!(p.prime_count(), n);
+    });
+}
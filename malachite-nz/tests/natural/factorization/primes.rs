@@ -180,3 +180,42 @@ fn primes_less_than_or_equal_to_properties() {
         assert_eq!(Natural::primes().take_while(|p| *p <= n).collect_vec(), ps);
     });
 }
+
+#[test]
+fn test_primes_greater_than_or_equal_to() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let ps = Natural::primes_greater_than_or_equal_to(&n)
+            .take(5)
+            .collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+    };
+    test("0", "[2, 3, 5, 7, 11]");
+    test("2", "[2, 3, 5, 7, 11]");
+    test("10", "[11, 13, 17, 19, 23]");
+    test("11", "[11, 13, 17, 19, 23]");
+    test(
+        "18446744073709551557",
+        "[18446744073709551557, 18446744073709551629, 18446744073709551653, \
+        18446744073709551667, 18446744073709551697]",
+    );
+}
+
+#[test]
+fn primes_greater_than_or_equal_to_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        let ps = Natural::primes_greater_than_or_equal_to(&n)
+            .take(10)
+            .collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert!(is_strictly_ascending(ps.iter()));
+        assert_eq!(
+            Natural::primes()
+                .skip_while(|p| *p < n)
+                .take(10)
+                .collect_vec(),
+            ps
+        );
+    });
+}
@@ -0,0 +1,125 @@
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrimesInRange};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::unsigned_pair_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_unsigned_pair_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_primes_in_range() {
+    let test = |a: &str, b: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let ps = Natural::primes_in_range(&a, &b).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+    };
+    test("0", "0", "[]");
+    test("0", "2", "[]");
+    test("0", "3", "[2]");
+    test("0", "30", "[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]");
+    test("24", "29", "[]");
+    test(
+        "18446744073709551516",
+        "18446744073709551716",
+        "[18446744073709551521, 18446744073709551533, 18446744073709551557, \
+        18446744073709551629, 18446744073709551653, 18446744073709551667, 18446744073709551697, \
+        18446744073709551709]",
+    );
+    test(
+        "18446744073709551616",
+        "18446744073709551653",
+        "[18446744073709551629]",
+    );
+    test(
+        "10000000000000000000000000",
+        "10000000000000000000001000",
+        "[10000000000000000000000013, 10000000000000000000000223, 10000000000000000000000343, \
+        10000000000000000000000349, 10000000000000000000000451, 10000000000000000000000513, \
+        10000000000000000000000559, 10000000000000000000000561, 10000000000000000000000583, \
+        10000000000000000000000607, 10000000000000000000000609, 10000000000000000000000657, \
+        10000000000000000000000667, 10000000000000000000000747, 10000000000000000000000799, \
+        10000000000000000000000871, 10000000000000000000000937]",
+    );
+
+    let a = Natural::power_of_2(100);
+    let b = &a + Natural::from(10000u32);
+    assert_eq!(Natural::primes_in_range(&a, &b).count(), 124);
+}
+
+#[test]
+fn test_primes_in_inclusive_range() {
+    let test = |a: &str, b: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let ps = Natural::primes_in_inclusive_range(&a, &b).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+    };
+    test("0", "0", "[]");
+    test("0", "2", "[2]");
+    test("11", "11", "[11]");
+    test("0", "29", "[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]");
+    test(
+        "18446744073709551557",
+        "18446744073709551629",
+        "[18446744073709551557, 18446744073709551629]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn primes_in_range_fail() {
+    Natural::primes_in_range(&Natural::ONE, &Natural::ZERO);
+}
+
+#[test]
+#[should_panic]
+fn primes_in_inclusive_range_fail() {
+    Natural::primes_in_inclusive_range(&Natural::ONE, &Natural::ZERO);
+}
+
+#[test]
+fn primes_in_range_properties() {
+    // Prime tests on large Naturals are slow, so smaller Naturals are generated.
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 32);
+    config.insert("mean_stripe_n", 16);
+    natural_unsigned_pair_gen::<u8>().test_properties_with_config(&config, |(a, d)| {
+        let b = &a + Natural::from(d);
+        let ps = Natural::primes_in_range(&a, &b).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert!(is_strictly_ascending(ps.iter()));
+        assert!(ps.iter().all(|p| a <= *p && *p < b));
+        let first = if a <= 2u32 {
+            Natural::TWO
+        } else {
+            (&a - Natural::ONE).next_prime()
+        };
+        let expected = itertools::iterate(first, |p| p.next_prime())
+            .take_while(|p| *p < b)
+            .collect_vec();
+        assert_eq!(ps, expected);
+
+        let mut ps = ps;
+        if b.is_prime() {
+            ps.push(b.clone());
+        }
+        assert_eq!(Natural::primes_in_inclusive_range(&a, &b).collect_vec(), ps);
+    });
+
+    unsigned_pair_gen::<u64, u8>().test_properties(|(a, d)| {
+        let b = a.saturating_add(u64::from(d));
+        assert_eq!(
+            Natural::primes_in_range(&Natural::from(a), &Natural::from(b)).collect_vec(),
+            u64::primes_in_range(&a, &b)
+                .map(Natural::from)
+                .collect_vec()
+        );
+    });
+}
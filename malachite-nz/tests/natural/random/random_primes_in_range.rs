@@ -0,0 +1,136 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::test_util::stats::moments::MomentStats;
+use malachite_nz::natural::random::random_primes_in_range;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::random::random_naturals_helper_helper;
+use std::str::FromStr;
+
+fn random_primes_in_range_helper(
+    a: &str,
+    b: &str,
+    expected_values: &[&str],
+    expected_common_values: &[(&str, usize)],
+    expected_sample_median: (&str, Option<&str>),
+    expected_sample_moment_stats: MomentStats,
+) {
+    random_naturals_helper_helper(
+        random_primes_in_range(
+            EXAMPLE_SEED,
+            Natural::from_str(a).unwrap(),
+            Natural::from_str(b).unwrap(),
+        ),
+        expected_values,
+        expected_common_values,
+        expected_sample_median,
+        expected_sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_primes_in_range() {
+    let values = &["2"; 20];
+    let common_values = &[("2", 1000000)];
+    let sample_median = ("2", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(2.0),
+        standard_deviation: NiceFloat(0.0),
+        skewness: NiceFloat(f64::NAN),
+        excess_kurtosis: NiceFloat(f64::NAN),
+    };
+    random_primes_in_range_helper(
+        "1",
+        "3",
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+    let values = &[
+        "97", "17", "37", "11", "17", "17", "97", "17", "17", "31", "53", "59", "43", "37", "11",
+        "13", "31", "73", "53", "29",
+    ];
+    let common_values = &[
+        ("97", 48112),
+        ("61", 47876),
+        ("19", 47825),
+        ("73", 47820),
+        ("47", 47814),
+        ("83", 47812),
+        ("23", 47781),
+        ("11", 47756),
+        ("37", 47755),
+        ("13", 47731),
+    ];
+    let sample_median = ("47", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(49.657154000000865),
+        standard_deviation: NiceFloat(25.754505468263808),
+        skewness: NiceFloat(0.14400736279359497),
+        excess_kurtosis: NiceFloat(-1.1630389867277877),
+    };
+    random_primes_in_range_helper(
+        "10",
+        "100",
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_primes_in_range_large() {
+    let test = |a: Natural, b: Natural, out: &[&str]| {
+        let xs = random_primes_in_range(EXAMPLE_SEED, a.clone(), b.clone())
+            .take(out.len())
+            .collect_vec();
+        for x in &xs {
+            assert!(a <= *x && *x < b);
+            assert!(x.is_prime());
+        }
+        assert_eq!(xs.iter().map(ToString::to_string).collect_vec(), out);
+    };
+    test(
+        Natural::from(1000000u32),
+        Natural::from(1000100u32),
+        &[
+            "1000081", "1000033", "1000099", "1000003", "1000099", "1000033", "1000039", "1000081",
+            "1000037", "1000033",
+        ],
+    );
+    let a = Natural::from(10u32).pow(30);
+    let b = &a + Natural::from(10000u32);
+    test(
+        a,
+        b,
+        &[
+            "1000000000000000000000000000751",
+            "1000000000000000000000000006207",
+            "1000000000000000000000000005359",
+            "1000000000000000000000000001783",
+            "1000000000000000000000000001081",
+        ],
+    );
+}
+
+#[test]
+#[should_panic]
+fn random_primes_in_range_fail_1() {
+    random_primes_in_range(EXAMPLE_SEED, Natural::from(10u32), Natural::from(10u32));
+}
+
+#[test]
+#[should_panic]
+fn random_primes_in_range_fail_2() {
+    random_primes_in_range(EXAMPLE_SEED, Natural::from(10u32), Natural::from(9u32));
+}
+
+#[test]
+#[should_panic]
+fn random_primes_in_range_fail_3() {
+    random_primes_in_range(EXAMPLE_SEED, Natural::from(24u32), Natural::from(29u32));
+}
@@ -0,0 +1,143 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::test_util::stats::moments::MomentStats;
+use malachite_nz::natural::random::random_primes_with_bits;
+use malachite_nz::test_util::natural::random::random_naturals_helper_helper;
+
+fn random_primes_with_bits_helper(
+    bits: u64,
+    expected_values: &[&str],
+    expected_common_values: &[(&str, usize)],
+    expected_sample_median: (&str, Option<&str>),
+    expected_sample_moment_stats: MomentStats,
+) {
+    random_naturals_helper_helper(
+        random_primes_with_bits(EXAMPLE_SEED, bits),
+        expected_values,
+        expected_common_values,
+        expected_sample_median,
+        expected_sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_primes_with_bits() {
+    let values = &[
+        "3", "3", "3", "3", "3", "3", "2", "2", "2", "3", "2", "3", "2", "3", "2", "2", "3", "3",
+        "3", "3",
+    ];
+    let common_values = &[("2", 500399), ("3", 499601)];
+    let sample_median = ("2", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(2.4996009999998368),
+        standard_deviation: NiceFloat(0.5000000907990857),
+        skewness: NiceFloat(0.0015960005081696673),
+        excess_kurtosis: NiceFloat(-1.9999974527823994),
+    };
+    random_primes_with_bits_helper(2, values, common_values, sample_median, sample_moment_stats);
+    let values = &[
+        "5", "7", "5", "5", "7", "5", "7", "5", "7", "7", "5", "7", "7", "5", "7", "7", "7", "5",
+        "7", "7",
+    ];
+    let common_values = &[("7", 501268), ("5", 498732)];
+    let sample_median = ("7", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(6.002536000000046),
+        standard_deviation: NiceFloat(0.9999972843456104),
+        skewness: NiceFloat(-0.0050720163098450805),
+        excess_kurtosis: NiceFloat(-1.9999742746505949),
+    };
+    random_primes_with_bits_helper(3, values, common_values, sample_median, sample_moment_stats);
+    let values = &[
+        "881", "599", "727", "641", "647", "797", "839", "647", "599", "593", "857", "829", "691",
+        "613", "683", "929", "733", "683", "751", "593",
+    ];
+    let common_values = &[
+        ("727", 13711),
+        ("547", 13544),
+        ("643", 13539),
+        ("607", 13536),
+        ("661", 13473),
+        ("691", 13469),
+        ("1013", 13468),
+        ("911", 13455),
+        ("653", 13452),
+        ("733", 13445),
+    ];
+    let sample_median = ("761", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(768.4881879999868),
+        standard_deviation: NiceFloat(147.09336870483202),
+        skewness: NiceFloat(0.07555985068738975),
+        excess_kurtosis: NiceFloat(-1.2178202402134548),
+    };
+    random_primes_with_bits_helper(
+        10,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_primes_with_bits_large() {
+    let test = |bits, out: &[&str]| {
+        let xs = random_primes_with_bits(EXAMPLE_SEED, bits)
+            .take(out.len())
+            .collect_vec();
+        for x in &xs {
+            assert_eq!(x.significant_bits(), bits);
+            assert!(x.is_prime());
+        }
+        assert_eq!(xs.iter().map(ToString::to_string).collect_vec(), out);
+    };
+    test(
+        32,
+        &[
+            "2381873899",
+            "3441745031",
+            "3156494423",
+            "3008075069",
+            "2243260091",
+            "3173155549",
+            "3262431643",
+            "3664058753",
+            "3607270493",
+            "3327300287",
+        ],
+    );
+    test(
+        100,
+        &[
+            "834112884250571316017711689193",
+            "1096064561690550858427390195543",
+            "1247194096045807604465004471959",
+            "704568598090055117872122270247",
+            "1015301622876522270733791140083",
+        ],
+    );
+    test(
+        256,
+        &[
+            "90940578488510162533659490724030586846246437681175877305173649005623339367449",
+            "67990936465117988210392646564976218942490357059543934313137551374763765079983",
+            "87540175601457768086766371658159478292670441305532749023405437691639766510303",
+        ],
+    );
+}
+
+#[test]
+#[should_panic]
+fn random_primes_with_bits_fail_1() {
+    random_primes_with_bits(EXAMPLE_SEED, 0);
+}
+
+#[test]
+#[should_panic]
+fn random_primes_with_bits_fail_2() {
+    random_primes_with_bits(EXAMPLE_SEED, 1);
+}
@@ -0,0 +1,140 @@
+use itertools::Itertools;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::float::NiceFloat;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_base::test_util::stats::moments::MomentStats;
+use malachite_nz::natural::random::random_safe_primes_with_bits;
+use malachite_nz::test_util::natural::random::random_naturals_helper_helper;
+
+fn random_safe_primes_with_bits_helper(
+    bits: u64,
+    expected_values: &[&str],
+    expected_common_values: &[(&str, usize)],
+    expected_sample_median: (&str, Option<&str>),
+    expected_sample_moment_stats: MomentStats,
+) {
+    random_naturals_helper_helper(
+        random_safe_primes_with_bits(EXAMPLE_SEED, bits),
+        expected_values,
+        expected_common_values,
+        expected_sample_median,
+        expected_sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_safe_primes_with_bits() {
+    let values = &[
+        "5", "7", "5", "5", "7", "5", "7", "5", "7", "7", "5", "7", "7", "5", "7", "7", "7", "5",
+        "7", "7",
+    ];
+    let common_values = &[("7", 501268), ("5", 498732)];
+    let sample_median = ("7", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(6.002536000000046),
+        standard_deviation: NiceFloat(0.9999972843456104),
+        skewness: NiceFloat(-0.0050720163098450805),
+        excess_kurtosis: NiceFloat(-1.9999742746505949),
+    };
+    random_safe_primes_with_bits_helper(
+        3,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+    let values = &["11"; 20];
+    let common_values = &[("11", 1000000)];
+    let sample_median = ("11", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(11.0),
+        standard_deviation: NiceFloat(0.0),
+        skewness: NiceFloat(f64::NAN),
+        excess_kurtosis: NiceFloat(f64::NAN),
+    };
+    random_safe_primes_with_bits_helper(
+        4,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+    let values = &[
+        "863", "839", "563", "563", "839", "1019", "887", "863", "1019", "839", "1019", "587",
+        "1019", "1019", "887", "1019", "563", "983", "983", "839",
+    ];
+    let common_values = &[
+        ("839", 125464),
+        ("983", 125300),
+        ("719", 125192),
+        ("563", 125021),
+        ("887", 124975),
+        ("587", 124789),
+        ("1019", 124652),
+        ("863", 124607),
+    ];
+    let sample_median = ("839", None);
+    let sample_moment_stats = MomentStats {
+        mean: NiceFloat(807.4942640000129),
+        standard_deviation: NiceFloat(158.9183458199188),
+        skewness: NiceFloat(-0.32562241222992255),
+        excess_kurtosis: NiceFloat(-1.2377092592504657),
+    };
+    random_safe_primes_with_bits_helper(
+        10,
+        values,
+        common_values,
+        sample_median,
+        sample_moment_stats,
+    );
+}
+
+#[test]
+fn test_random_safe_primes_with_bits_large() {
+    let test = |bits, out: &[&str]| {
+        let xs = random_safe_primes_with_bits(EXAMPLE_SEED, bits)
+            .take(out.len())
+            .collect_vec();
+        for x in &xs {
+            assert_eq!(x.significant_bits(), bits);
+            assert!(x.is_prime());
+            assert!((x >> 1u32).is_prime());
+        }
+        assert_eq!(xs.iter().map(ToString::to_string).collect_vec(), out);
+    };
+    test(
+        16,
+        &["63719", "34703", "51287", "41507", "61703", "53783", "36083", "43427", "59063", "39983"],
+    );
+    test(
+        64,
+        &[
+            "15160681727928509519",
+            "9638454647140770167",
+            "16934440110117877979",
+            "12117049948564736783",
+            "16455790133461806503",
+        ],
+    );
+    test(
+        128,
+        &[
+            "327046585580438051957751563860503001283",
+            "254784675657323854056903435199811849927",
+            "188218996120914246459822068173070612243",
+        ],
+    );
+}
+
+#[test]
+#[should_panic]
+fn random_safe_primes_with_bits_fail_1() {
+    random_safe_primes_with_bits(EXAMPLE_SEED, 0);
+}
+
+#[test]
+#[should_panic]
+fn random_safe_primes_with_bits_fail_2() {
+    random_safe_primes_with_bits(EXAMPLE_SEED, 2);
+}
@@ -2,8 +2,9 @@ use crate::Rational;
 use malachite_base::num::arithmetic::traits::{
     DivExact, DivExactAssign, Gcd, GcdAssign, UnsignedAbs,
 };
-use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::basic::traits::{One, Zero};
 use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
 use std::iter::Sum;
 use std::ops::{Add, AddAssign};
 
@@ -372,9 +373,41 @@ impl<'a> AddAssign<&'a Rational> for Rational {
     }
 }
 
+// Adds two fractions, represented as numerator-denominator pairs, without reducing the result. The
+// fractions need not be reduced either. The denominator of the result is the least common multiple
+// of the input denominators.
+fn add_unreduced(x: (Integer, Natural), y: (Integer, Natural)) -> (Integer, Natural) {
+    let (x_n, x_d) = x;
+    let (y_n, y_d) = y;
+    if x_d == y_d {
+        return (x_n + y_n, x_d);
+    }
+    let gcd = (&x_d).gcd(&y_d);
+    if gcd == 1u32 {
+        (
+            x_n * Integer::from(&y_d) + y_n * Integer::from(&x_d),
+            x_d * y_d,
+        )
+    } else {
+        let x_d = x_d.div_exact(&gcd);
+        let y_d_over_gcd = (&y_d).div_exact(gcd);
+        (
+            x_n * Integer::from(y_d_over_gcd) + y_n * Integer::from(&x_d),
+            x_d * y_d,
+        )
+    }
+}
+
 impl Sum for Rational {
     /// Adds up all the [`Rational`]s in an iterator.
     ///
+    /// The summands are added together in a balanced binary tree. The partial sums are not reduced;
+    /// instead, each pair of denominators is combined into their least common multiple, and the
+    /// result is reduced only once, at the end. This is fastest when the sum does not simplify much.
+    /// If it does, as with a telescoping sum like $\sum_{k=1}^n 1/(k(k+1))$, the unreduced partial
+    /// sums grow much larger than the reduced ones would, and adding the summands one at a time may
+    /// be an order of magnitude faster.
+    ///
     /// $$
     /// f((x_i)_ {i=0}^{n-1}) = \sum_ {i=0}^{n-1} x_i.
     /// $$
@@ -404,23 +437,34 @@ impl Sum for Rational {
     {
         let mut stack = Vec::new();
         for (i, x) in xs.enumerate().map(|(i, x)| (i + 1, x)) {
-            let mut s = x;
+            let mut s = (
+                Integer::from_sign_and_abs(x.sign, x.numerator),
+                x.denominator,
+            );
             for _ in 0..i.trailing_zeros() {
-                s += stack.pop().unwrap();
+                s = add_unreduced(stack.pop().unwrap(), s);
             }
             stack.push(s);
         }
-        let mut s = Rational::ZERO;
-        for x in stack {
-            s += x;
+        let mut s = (Integer::ZERO, Natural::ONE);
+        for x in stack.into_iter().rev() {
+            s = add_unreduced(x, s);
         }
-        s
+        let (numerator, denominator) = s;
+        Rational::from_sign_and_naturals(numerator >= 0, numerator.unsigned_abs(), denominator)
     }
 }
 
 impl<'a> Sum<&'a Rational> for Rational {
     /// Adds up all the [`Rational`]s in an iterator of [`Rational`] references.
     ///
+    /// The summands are added together in a balanced binary tree. The partial sums are not reduced;
+    /// instead, each pair of denominators is combined into their least common multiple, and the
+    /// result is reduced only once, at the end. This is fastest when the sum does not simplify much.
+    /// If it does, as with a telescoping sum like $\sum_{k=1}^n 1/(k(k+1))$, the unreduced partial
+    /// sums grow much larger than the reduced ones would, and adding the summands one at a time may
+    /// be an order of magnitude faster.
+    ///
     /// $$
     /// f((x_i)_ {i=0}^{n-1}) = \sum_ {i=0}^{n-1} x_i.
     /// $$
@@ -453,16 +497,20 @@ impl<'a> Sum<&'a Rational> for Rational {
     {
         let mut stack = Vec::new();
         for (i, x) in xs.enumerate().map(|(i, x)| (i + 1, x)) {
-            let mut s = x.clone();
+            let mut s = (
+                Integer::from_sign_and_abs_ref(x.sign, &x.numerator),
+                x.denominator.clone(),
+            );
             for _ in 0..i.trailing_zeros() {
-                s += stack.pop().unwrap();
+                s = add_unreduced(stack.pop().unwrap(), s);
             }
             stack.push(s);
         }
-        let mut s = Rational::ZERO;
-        for x in stack {
-            s += x;
+        let mut s = (Integer::ZERO, Natural::ONE);
+        for x in stack.into_iter().rev() {
+            s = add_unreduced(x, s);
         }
-        s
+        let (numerator, denominator) = s;
+        Rational::from_sign_and_naturals(numerator >= 0, numerator.unsigned_abs(), denominator)
     }
 }
@@ -0,0 +1,94 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Bernoulli, Parity, PowerOf2};
+use malachite_base::num::basic::traits::{One, OneHalf, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+// Computes the tangent number $T_m$, the coefficient of $x^{2m-1}/(2m-1)!$ in the Taylor series of
+// $\tan x$, for $m > 0$.
+//
+// This is Algorithm TangentNumbers from Richard P. Brent and David Harvey, *Fast computation of
+// Bernoulli, tangent and secant numbers*, 2011. Only additions and multiplications by small
+// numbers are needed.
+//
+// # Worst-case complexity
+// $T(m) = O(m^3 \log m)$
+//
+// $M(m) = O(m^2 \log m)$
+fn tangent_number(m: u64) -> Natural {
+    let len = usize::exact_from(m);
+    let mut ts = Vec::with_capacity(len);
+    ts.push(Natural::ONE);
+    for k in 1..len {
+        let t = &ts[k - 1] * Natural::from(k);
+        ts.push(t);
+    }
+    for k in 1..len {
+        for j in k..len {
+            let (lo, hi) = ts.split_at_mut(j);
+            hi[0] *= Natural::from(j - k + 2);
+            hi[0] += &lo[j - 1] * Natural::from(j - k);
+        }
+    }
+    ts.pop().unwrap()
+}
+
+impl Bernoulli for Rational {
+    /// Computes a Bernoulli number.
+    ///
+    /// The Bernoulli numbers are defined by the generating function
+    /// $$
+    /// \frac{x}{e^x - 1} = \sum_{n=0}^\infty B_n\frac{x^n}{n!}.
+    /// $$
+    /// In particular, $B_1 = -1/2$, and $B_n = 0$ for all odd $n > 1$.
+    ///
+    /// $$
+    /// f(n) = B_n.
+    /// $$
+    ///
+    /// For even $n = 2m > 0$, $B_n$ is computed from the tangent number $T_m$ using
+    /// $$
+    /// B_{2m} = (-1)^{m-1}\frac{2mT_m}{4^m(4^m-1)}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Bernoulli;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::bernoulli(0).to_string(), "1");
+    /// assert_eq!(Rational::bernoulli(1).to_string(), "-1/2");
+    /// assert_eq!(Rational::bernoulli(2).to_string(), "1/6");
+    /// assert_eq!(Rational::bernoulli(3).to_string(), "0");
+    /// assert_eq!(Rational::bernoulli(4).to_string(), "-1/30");
+    /// assert_eq!(Rational::bernoulli(12).to_string(), "-691/2730");
+    /// assert_eq!(
+    ///     Rational::bernoulli(50).to_string(),
+    ///     "495057205241079648212477525/66"
+    /// );
+    /// ```
+    fn bernoulli(n: u64) -> Rational {
+        match n {
+            0 => Rational::ONE,
+            1 => -Rational::ONE_HALF,
+            n if n.odd() => Rational::ZERO,
+            n => {
+                let m = n >> 1;
+                let power = Natural::power_of_2(n);
+                let denominator = &power * (&power - Natural::ONE);
+                Rational::from_sign_and_naturals(
+                    m.odd(),
+                    tangent_number(m) * Natural::from(n),
+                    denominator,
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{GcdAssign, GcdOf, LcmOf};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+
+impl GcdOf for Rational {
+    /// Computes the GCD (greatest common divisor), or content, of all the [`Rational`]s in an
+    /// iterator.
+    ///
+    /// The result is the largest nonnegative [`Rational`] $g$ such that every $x_i/g$ is an
+    /// integer: the GCD of the numerators divided by the LCM of the denominators. The GCD of an
+    /// empty iterator, or of an iterator containing only zeros, is 0.
+    ///
+    /// $$
+    /// f((p_i/q_i)_ {i=0}^{n-1}) = \frac{\gcd(p_0, p_1, \ldots, p_{n-1})}
+    ///     {\operatorname{lcm}(q_0, q_1, \ldots, q_{n-1})},
+    /// $$
+    /// where each $p_i/q_i$ is in lowest terms.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Rational::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::gcd_of(Vec::<Rational>::new().into_iter()), 0);
+    /// assert_eq!(
+    ///     Rational::gcd_of(vec_from_str::<Rational>("[6, 4]").unwrap().into_iter()).to_string(),
+    ///     "2"
+    /// );
+    /// assert_eq!(
+    ///     Rational::gcd_of(vec_from_str::<Rational>("[2/3, -4/5, 0]").unwrap().into_iter())
+    ///         .to_string(),
+    ///     "2/15"
+    /// );
+    /// ```
+    fn gcd_of<I: Iterator<Item = Rational>>(xs: I) -> Rational {
+        let mut numerator = Natural::ZERO;
+        let mut denominators = Vec::new();
+        for x in xs {
+            numerator.gcd_assign(x.numerator);
+            denominators.push(x.denominator);
+        }
+        // Any prime dividing the GCD of the numerators divides every numerator, so it divides none
+        // of the denominators; hence the result is already in lowest terms.
+        Rational {
+            sign: true,
+            numerator,
+            denominator: Natural::lcm_of(denominators.into_iter()),
+        }
+    }
+}
+
+impl<'a> GcdOf<&'a Rational> for Rational {
+    /// Computes the GCD (greatest common divisor), or content, of all the [`Rational`]s in an
+    /// iterator of [`Rational`] references.
+    ///
+    /// The result is the largest nonnegative [`Rational`] $g$ such that every $x_i/g$ is an
+    /// integer: the GCD of the numerators divided by the LCM of the denominators. The GCD of an
+    /// empty iterator, or of an iterator containing only zeros, is 0.
+    ///
+    /// $$
+    /// f((p_i/q_i)_ {i=0}^{n-1}) = \frac{\gcd(p_0, p_1, \ldots, p_{n-1})}
+    ///     {\operatorname{lcm}(q_0, q_1, \ldots, q_{n-1})},
+    /// $$
+    /// where each $p_i/q_i$ is in lowest terms.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `Natural::sum(xs.map(Rational::significant_bits))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::GcdOf;
+    /// use malachite_base::vecs::vec_from_str;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::gcd_of(Vec::<Rational>::new().iter()), 0);
+    /// assert_eq!(
+    ///     Rational::gcd_of(vec_from_str::<Rational>("[6, 4]").unwrap().iter()).to_string(),
+    ///     "2"
+    /// );
+    /// assert_eq!(
+    ///     Rational::gcd_of(vec_from_str::<Rational>("[2/3, -4/5, 0]").unwrap().iter())
+    ///         .to_string(),
+    ///     "2/15"
+    /// );
+    /// ```
+    fn gcd_of<I: Iterator<Item = &'a Rational>>(xs: I) -> Rational {
+        let mut numerator = Natural::ZERO;
+        let mut denominators = Vec::new();
+        for x in xs {
+            numerator.gcd_assign(&x.numerator);
+            denominators.push(&x.denominator);
+        }
+        Rational {
+            sign: true,
+            numerator,
+            denominator: Natural::lcm_of(denominators.into_iter()),
+        }
+    }
+}
@@ -6,6 +6,9 @@ pub mod add;
 /// [`ApproximateAssign`](traits::ApproximateAssign), traits for approximating a
 /// [`Rational`](super::Rational) by a [`Rational`](super::Rational) with a bounded denominator.
 pub mod approximate;
+/// An implementation of [`Bernoulli`](malachite_base::num::arithmetic::traits::Bernoulli), a trait
+/// for computing Bernoulli numbers.
+pub mod bernoulli;
 /// Implementations of [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling) and
 /// [`CeilingAssign`](malachite_base::num::arithmetic::traits::CeilingAssign), traits for taking
 /// the ceiling of a number.
@@ -18,6 +21,9 @@ pub mod div;
 /// [`FloorAssign`](malachite_base::num::arithmetic::traits::FloorAssign), traits for taking the
 /// floor of a number.
 pub mod floor;
+/// Implementations of [`GcdOf`](malachite_base::num::arithmetic::traits::GcdOf), a trait for
+/// computing the GCD (greatest common divisor), or content, of a collection of numbers.
+pub mod gcd;
 /// Implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a trait
 /// for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
@@ -49,6 +55,11 @@ pub mod neg;
 /// [`NextPowerOf2Assign`](malachite_base::num::arithmetic::traits::NextPowerOf2Assign), traits for
 /// getting the next-highest power of 2.
 pub mod next_power_of_2;
+/// Implementations of [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower)
+/// and [`PerfectPowerDecompose`](malachite_base::num::arithmetic::traits::PerfectPowerDecompose),
+/// traits for determining whether a number is a perfect power and for writing it as a perfect power
+/// with the largest possible exponent.
+pub mod perfect_power;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
@@ -223,3 +234,7 @@ pub mod square;
 pub mod sub;
 /// Various traits for performing arithmetic operations on numbers.
 pub mod traits;
+/// Implementations of [`Valuation`](malachite_base::num::arithmetic::traits::Valuation) and
+/// [`RemoveFactor`](malachite_base::num::arithmetic::traits::RemoveFactor), traits for finding the
+/// $p$-adic valuation of a number and for removing all factors of $p$ from it.
+pub mod valuation;
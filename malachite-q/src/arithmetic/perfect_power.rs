@@ -0,0 +1,207 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Gcd, IsPerfectPower, PerfectPowerDecompose, Pow};
+use malachite_nz::natural::Natural;
+
+// Returns the largest exponent of a perfect power that is equal to the numerator or denominator of
+// a `Rational`, or 0 if it is 1 (since 1 is a $k$th power for every $k$).
+fn perfect_power_exponent(x: &Natural) -> u64 {
+    if *x == 1u32 {
+        0
+    } else {
+        x.perfect_power_decompose().1
+    }
+}
+
+// Combines the perfect power decompositions of the numerator and denominator of a `Rational`.
+// Since the numerator and denominator are coprime, a `Rational` is a $k$th power if and only if
+// both of them are, so the exponent is the gcd of their exponents; a negative `Rational` can only
+// be an odd power, so the factors of 2 in that gcd are moved into the base.
+fn perfect_power_decompose_helper(
+    sign: bool,
+    (n_root, n_exp): (Natural, u64),
+    (d_root, d_exp): (Natural, u64),
+) -> (Rational, u64) {
+    let n_exp = if n_root == 1u32 { 0 } else { n_exp };
+    let d_exp = if d_root == 1u32 { 0 } else { d_exp };
+    let mut exp = n_exp.gcd(d_exp);
+    if exp == 0 {
+        return (
+            Rational {
+                sign,
+                numerator: n_root,
+                denominator: d_root,
+            },
+            1,
+        );
+    }
+    if !sign {
+        exp >>= exp.trailing_zeros();
+    }
+    (
+        Rational {
+            sign,
+            numerator: n_root.pow(n_exp / exp),
+            denominator: d_root.pow(d_exp / exp),
+        },
+        exp,
+    )
+}
+
+impl IsPerfectPower for Rational {
+    /// Determines whether a [`Rational`] is a perfect power: whether it is equal to $b^k$ for some
+    /// [`Rational`] $b$ and some integer $k \geq 2$.
+    ///
+    /// 0, 1, and $-1$ are perfect powers. A negative [`Rational`] is a perfect power if and only if
+    /// it is a perfect $k$th power for some odd $k \geq 3$. Since the numerator and denominator are
+    /// coprime, a [`Rational`] is a perfect $k$th power if and only if its numerator and
+    /// denominator both are.
+    ///
+    /// $f(x) = (\exists b \in \Q, k \in \N : k \geq 2 \wedge b^k = x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::ZERO.is_perfect_power(), true);
+    /// assert_eq!(Rational::NEGATIVE_ONE.is_perfect_power(), true);
+    /// assert_eq!(Rational::from_signeds(1, 4).is_perfect_power(), true);
+    /// assert_eq!(Rational::from_signeds(-1, 4).is_perfect_power(), false);
+    /// assert_eq!(Rational::from_signeds(8, 27).is_perfect_power(), true);
+    /// assert_eq!(Rational::from_signeds(-8, 27).is_perfect_power(), true);
+    /// assert_eq!(Rational::from_signeds(4, 27).is_perfect_power(), false);
+    /// assert_eq!(Rational::from_signeds(22, 7).is_perfect_power(), false);
+    /// ```
+    fn is_perfect_power(&self) -> bool {
+        if self.numerator == 0u32 {
+            return true;
+        }
+        let mut exp =
+            perfect_power_exponent(&self.numerator).gcd(perfect_power_exponent(&self.denominator));
+        if exp == 0 {
+            return true;
+        }
+        if !self.sign {
+            exp >>= exp.trailing_zeros();
+        }
+        exp > 1
+    }
+}
+
+impl PerfectPowerDecompose for Rational {
+    type Output = Rational;
+
+    /// Writes a [`Rational`] as a perfect power $b^k$ with the largest possible $k$, returning $b$
+    /// and $k$. The [`Rational`] is taken by value.
+    ///
+    /// If the [`Rational`] is not a perfect power, the result is the [`Rational`] itself and 1. The
+    /// result for 0, 1, and $-1$ is also the [`Rational`] itself and 1, since they are $k$th powers
+    /// for every (odd) $k$. If the [`Rational`] is negative, $k$ is odd.
+    ///
+    /// $f(x) = (b, k)$, where $b^k = x$ and $k$ is as large as possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PerfectPowerDecompose;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(22, 7).perfect_power_decompose().to_debug_string(),
+    ///     "(22/7, 1)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 64).perfect_power_decompose().to_debug_string(),
+    ///     "(1/2, 6)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1, 64).perfect_power_decompose().to_debug_string(),
+    ///     "(-1/4, 3)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(16, 81).perfect_power_decompose().to_debug_string(),
+    ///     "(2/3, 4)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(8, 9).perfect_power_decompose().to_debug_string(),
+    ///     "(8/9, 1)"
+    /// );
+    /// ```
+    fn perfect_power_decompose(self) -> (Rational, u64) {
+        perfect_power_decompose_helper(
+            self.sign,
+            self.numerator.perfect_power_decompose(),
+            self.denominator.perfect_power_decompose(),
+        )
+    }
+}
+
+impl<'a> PerfectPowerDecompose for &'a Rational {
+    type Output = Rational;
+
+    /// Writes a [`Rational`] as a perfect power $b^k$ with the largest possible $k$, returning $b$
+    /// and $k$. The [`Rational`] is taken by reference.
+    ///
+    /// If the [`Rational`] is not a perfect power, the result is the [`Rational`] itself and 1. The
+    /// result for 0, 1, and $-1$ is also the [`Rational`] itself and 1, since they are $k$th powers
+    /// for every (odd) $k$. If the [`Rational`] is negative, $k$ is odd.
+    ///
+    /// $f(x) = (b, k)$, where $b^k = x$ and $k$ is as large as possible.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PerfectPowerDecompose;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(22, 7)).perfect_power_decompose().to_debug_string(),
+    ///     "(22/7, 1)"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 64)).perfect_power_decompose().to_debug_string(),
+    ///     "(1/2, 6)"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-1, 64)).perfect_power_decompose().to_debug_string(),
+    ///     "(-1/4, 3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(16, 81)).perfect_power_decompose().to_debug_string(),
+    ///     "(2/3, 4)"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(8, 9)).perfect_power_decompose().to_debug_string(),
+    ///     "(8/9, 1)"
+    /// );
+    /// ```
+    fn perfect_power_decompose(self) -> (Rational, u64) {
+        perfect_power_decompose_helper(
+            self.sign,
+            (&self.numerator).perfect_power_decompose(),
+            (&self.denominator).perfect_power_decompose(),
+        )
+    }
+}
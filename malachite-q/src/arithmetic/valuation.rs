@@ -0,0 +1,225 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{RemoveFactor, Valuation};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+impl<'a, 'b> Valuation<&'b Natural> for &'a Rational {
+    type Output = i64;
+
+    /// Finds the $p$-adic valuation of a [`Rational`], taking both the [`Rational`] and $p$ by
+    /// reference.
+    ///
+    /// The valuation of $x = a/b$, where $a$ and $b$ are coprime, is the valuation of $a$ minus
+    /// the valuation of $b$. Since $a$ and $b$ are coprime, at most one of these is nonzero. The
+    /// valuation is negative if $p$ divides the denominator.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!((&Rational::from(12)).valuation(&Natural::from(2u32)), 2);
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-5, 12)).valuation(&Natural::from(2u32)),
+    ///     -2
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-5, 12)).valuation(&Natural::from(5u32)),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-5, 12)).valuation(&Natural::from(7u32)),
+    ///     0
+    /// );
+    /// ```
+    fn valuation(self, p: &'b Natural) -> i64 {
+        let v = (&self.numerator).valuation(p);
+        if v != 0 {
+            i64::exact_from(v)
+        } else {
+            -i64::exact_from((&self.denominator).valuation(p))
+        }
+    }
+}
+
+impl<'a> Valuation<&'a Natural> for Rational {
+    type Output = i64;
+
+    /// Finds the $p$-adic valuation of a [`Rational`], taking the [`Rational`] by value and $p$ by
+    /// reference.
+    ///
+    /// The valuation of $x = a/b$, where $a$ and $b$ are coprime, is the valuation of $a$ minus
+    /// the valuation of $b$. Since $a$ and $b$ are coprime, at most one of these is nonzero. The
+    /// valuation is negative if $p$ divides the denominator.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Valuation;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(12).valuation(&Natural::from(2u32)), 2);
+    /// assert_eq!(Rational::from_signeds(-5, 12).valuation(&Natural::from(2u32)), -2);
+    /// assert_eq!(Rational::from_signeds(-5, 12).valuation(&Natural::from(5u32)), 1);
+    /// assert_eq!(Rational::from_signeds(-5, 12).valuation(&Natural::from(7u32)), 0);
+    /// ```
+    #[inline]
+    fn valuation(self, p: &'a Natural) -> i64 {
+        (&self).valuation(p)
+    }
+}
+
+impl<'a> RemoveFactor<&'a Natural> for Rational {
+    type CofactorOutput = Rational;
+    type ValuationOutput = i64;
+
+    /// Removes all factors of $p$ from a [`Rational`], taking the [`Rational`] by value and $p$
+    /// by reference. Returns the cofactor and the $p$-adic valuation of the [`Rational`].
+    ///
+    /// If $x = p^k c$, where neither the numerator nor the denominator of $c$ is divisible by
+    /// $p$, then $f(x, p) = (c, k)$. The valuation $k$ is negative if $p$ divides the
+    /// denominator of $x$. The cofactor has the same sign as $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from(12).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(3, 2)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-5, 12).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(-5/3, -2)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-5, 12).remove_factor(&Natural::from(5u32)).to_debug_string(),
+    ///     "(-1/12, 1)"
+    /// );
+    /// ```
+    fn remove_factor(self, p: &'a Natural) -> (Rational, i64) {
+        let (numerator, v) = self.numerator.remove_factor(p);
+        if v != 0 {
+            return (
+                Rational {
+                    sign: self.sign,
+                    numerator,
+                    denominator: self.denominator,
+                },
+                i64::exact_from(v),
+            );
+        }
+        let (denominator, v) = self.denominator.remove_factor(p);
+        (
+            Rational {
+                sign: self.sign,
+                numerator,
+                denominator,
+            },
+            -i64::exact_from(v),
+        )
+    }
+}
+
+impl<'a, 'b> RemoveFactor<&'b Natural> for &'a Rational {
+    type CofactorOutput = Rational;
+    type ValuationOutput = i64;
+
+    /// Removes all factors of $p$ from a [`Rational`], taking both the [`Rational`] and $p$ by
+    /// reference. Returns the cofactor and the $p$-adic valuation of the [`Rational`].
+    ///
+    /// If $x = p^k c$, where neither the numerator nor the denominator of $c$ is divisible by
+    /// $p$, then $f(x, p) = (c, k)$. The valuation $k$ is negative if $p$ divides the
+    /// denominator of $x$. The cofactor has the same sign as $x$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0 or `p` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RemoveFactor;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from(12)).remove_factor(&Natural::from(2u32)).to_debug_string(),
+    ///     "(3, 2)"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-5, 12))
+    ///         .remove_factor(&Natural::from(2u32))
+    ///         .to_debug_string(),
+    ///     "(-5/3, -2)"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-5, 12))
+    ///         .remove_factor(&Natural::from(5u32))
+    ///         .to_debug_string(),
+    ///     "(-1/12, 1)"
+    /// );
+    /// ```
+    fn remove_factor(self, p: &'b Natural) -> (Rational, i64) {
+        let (numerator, v) = (&self.numerator).remove_factor(p);
+        if v != 0 {
+            return (
+                Rational {
+                    sign: self.sign,
+                    numerator,
+                    denominator: self.denominator.clone(),
+                },
+                i64::exact_from(v),
+            );
+        }
+        let (denominator, v) = (&self.denominator).remove_factor(p);
+        (
+            Rational {
+                sign: self.sign,
+                numerator,
+                denominator,
+            },
+            -i64::exact_from(v),
+        )
+    }
+}
@@ -0,0 +1,35 @@
+use malachite_base::num::arithmetic::traits::Bernoulli;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_q::test_util::arithmetic::bernoulli::bernoulli_naive;
+use malachite_q::Rational;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_bernoulli);
+    register_bench!(runner, benchmark_bernoulli_algorithms);
+}
+
+fn demo_bernoulli(gm: GenMode, config: GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, &config).take(limit) {
+        println!("bernoulli({}) = {}", n, Rational::bernoulli(n));
+    }
+}
+
+fn benchmark_bernoulli_algorithms(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Rational::bernoulli(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Rational::bernoulli(n))),
+            ("naive", &mut |n| no_out!(bernoulli_naive(n))),
+        ],
+    );
+}
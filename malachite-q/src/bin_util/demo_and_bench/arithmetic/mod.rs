@@ -4,6 +4,7 @@ pub(crate) fn register(runner: &mut Runner) {
     abs::register(runner);
     add::register(runner);
     approximate::register(runner);
+    bernoulli::register(runner);
     ceiling::register(runner);
     denominators_in_closed_interval::register(runner);
     div::register(runner);
@@ -15,6 +16,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mul::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
+    perfect_power::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
     reciprocal::register(runner);
@@ -28,11 +30,13 @@ pub(crate) fn register(runner: &mut Runner) {
     sqrt::register(runner);
     square::register(runner);
     sub::register(runner);
+    valuation::register(runner);
 }
 
 mod abs;
 mod add;
 mod approximate;
+mod bernoulli;
 mod ceiling;
 mod denominators_in_closed_interval;
 mod div;
@@ -44,6 +48,7 @@ mod log_base_power_of_2;
 mod mul;
 mod neg;
 mod next_power_of_2;
+mod perfect_power;
 mod pow;
 mod power_of_2;
 mod reciprocal;
@@ -57,3 +62,4 @@ mod simplest_rational_in_interval;
 mod sqrt;
 mod square;
 mod sub;
+mod valuation;
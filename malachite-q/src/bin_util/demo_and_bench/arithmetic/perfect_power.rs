@@ -0,0 +1,91 @@
+use malachite_base::num::arithmetic::traits::{IsPerfectPower, PerfectPowerDecompose};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_q::test_util::bench::bucketers::rational_bit_bucketer;
+use malachite_q::test_util::generators::rational_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_rational_is_perfect_power);
+    register_demo!(runner, demo_rational_perfect_power_decompose);
+    register_demo!(runner, demo_rational_perfect_power_decompose_ref);
+
+    register_bench!(runner, benchmark_rational_is_perfect_power);
+    register_bench!(
+        runner,
+        benchmark_rational_perfect_power_decompose_evaluation_strategy
+    );
+}
+
+fn demo_rational_is_perfect_power(gm: GenMode, config: GenConfig, limit: usize) {
+    for x in rational_gen().get(gm, &config).take(limit) {
+        if x.is_perfect_power() {
+            println!("{} is a perfect power", x);
+        } else {
+            println!("{} is not a perfect power", x);
+        }
+    }
+}
+
+fn demo_rational_perfect_power_decompose(gm: GenMode, config: GenConfig, limit: usize) {
+    for x in rational_gen().get(gm, &config).take(limit) {
+        println!(
+            "{}.perfect_power_decompose() = {:?}",
+            x.clone(),
+            x.perfect_power_decompose()
+        );
+    }
+}
+
+fn demo_rational_perfect_power_decompose_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for x in rational_gen().get(gm, &config).take(limit) {
+        println!(
+            "(&{}).perfect_power_decompose() = {:?}",
+            x,
+            (&x).perfect_power_decompose()
+        );
+    }
+}
+
+fn benchmark_rational_is_perfect_power(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Rational.is_perfect_power()",
+        BenchmarkType::Single,
+        rational_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &rational_bit_bucketer("x"),
+        &mut [("Malachite", &mut |x| no_out!(x.is_perfect_power()))],
+    );
+}
+
+fn benchmark_rational_perfect_power_decompose_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Rational.perfect_power_decompose()",
+        BenchmarkType::EvaluationStrategy,
+        rational_gen().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &rational_bit_bucketer("x"),
+        &mut [
+            ("Rational.perfect_power_decompose()", &mut |x| {
+                no_out!(x.perfect_power_decompose())
+            }),
+            ("(&Rational).perfect_power_decompose()", &mut |x| {
+                no_out!((&x).perfect_power_decompose())
+            }),
+        ],
+    );
+}
@@ -0,0 +1,122 @@
+use malachite_base::num::arithmetic::traits::{RemoveFactor, Valuation};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_q::test_util::bench::bucketers::pair_1_rational_bit_bucketer;
+use malachite_q::test_util::generators::rational_natural_pair_gen_var_1;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_rational_valuation);
+    register_demo!(runner, demo_rational_valuation_ref);
+    register_demo!(runner, demo_rational_remove_factor);
+    register_demo!(runner, demo_rational_remove_factor_ref);
+
+    register_bench!(runner, benchmark_rational_valuation_evaluation_strategy);
+    register_bench!(runner, benchmark_rational_remove_factor_evaluation_strategy);
+}
+
+fn demo_rational_valuation(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, p) in rational_natural_pair_gen_var_1()
+        .get(gm, &config)
+        .filter(|(x, _)| *x != 0u32)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!("({}).valuation(&{}) = {}", x_old, p, x.valuation(&p));
+    }
+}
+
+fn demo_rational_valuation_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, p) in rational_natural_pair_gen_var_1()
+        .get(gm, &config)
+        .filter(|(x, _)| *x != 0u32)
+        .take(limit)
+    {
+        println!("(&{}).valuation(&{}) = {}", x, p, (&x).valuation(&p));
+    }
+}
+
+fn demo_rational_remove_factor(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, p) in rational_natural_pair_gen_var_1()
+        .get(gm, &config)
+        .filter(|(x, _)| *x != 0u32)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        println!(
+            "({}).remove_factor(&{}) = {:?}",
+            x_old,
+            p,
+            x.remove_factor(&p)
+        );
+    }
+}
+
+fn demo_rational_remove_factor_ref(gm: GenMode, config: GenConfig, limit: usize) {
+    for (x, p) in rational_natural_pair_gen_var_1()
+        .get(gm, &config)
+        .filter(|(x, _)| *x != 0u32)
+        .take(limit)
+    {
+        println!(
+            "(&{}).remove_factor(&{}) = {:?}",
+            x,
+            p,
+            (&x).remove_factor(&p)
+        );
+    }
+}
+
+fn benchmark_rational_valuation_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Rational.valuation(&Natural)",
+        BenchmarkType::EvaluationStrategy,
+        rational_natural_pair_gen_var_1()
+            .get(gm, &config)
+            .filter(|(x, _)| *x != 0u32),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_rational_bit_bucketer("x"),
+        &mut [
+            ("Rational.valuation(&Natural)", &mut |(x, p)| {
+                no_out!(x.valuation(&p))
+            }),
+            ("(&Rational).valuation(&Natural)", &mut |(x, p)| {
+                no_out!((&x).valuation(&p))
+            }),
+        ],
+    );
+}
+
+fn benchmark_rational_remove_factor_evaluation_strategy(
+    gm: GenMode,
+    config: GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Rational.remove_factor(&Natural)",
+        BenchmarkType::EvaluationStrategy,
+        rational_natural_pair_gen_var_1()
+            .get(gm, &config)
+            .filter(|(x, _)| *x != 0u32),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_rational_bit_bucketer("x"),
+        &mut [
+            ("Rational.remove_factor(&Natural)", &mut |(x, p)| {
+                no_out!(x.remove_factor(&p))
+            }),
+            ("(&Rational).remove_factor(&Natural)", &mut |(x, p)| {
+                no_out!((&x).remove_factor(&p))
+            }),
+        ],
+    );
+}
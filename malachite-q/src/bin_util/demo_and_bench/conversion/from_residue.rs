@@ -0,0 +1,38 @@
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+use malachite_q::Rational;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_rational_from_residue);
+    register_bench!(runner, benchmark_rational_from_residue);
+}
+
+fn demo_rational_from_residue(gm: GenMode, config: GenConfig, limit: usize) {
+    for (a, m) in natural_pair_gen_var_8().get(gm, &config).take(limit) {
+        println!(
+            "Rational::from_residue({}, {}) = {}",
+            a,
+            m,
+            Rational::from_residue(&a, &m).to_debug_string()
+        );
+    }
+}
+
+fn benchmark_rational_from_residue(gm: GenMode, config: GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Rational::from_residue(&Natural, &Natural)",
+        BenchmarkType::Single,
+        natural_pair_gen_var_8().get(gm, &config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(a, m)| {
+            no_out!(Rational::from_residue(&a, &m))
+        })],
+    );
+}
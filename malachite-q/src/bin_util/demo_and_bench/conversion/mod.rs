@@ -11,6 +11,7 @@ pub(crate) fn register(runner: &mut Runner) {
     from_numerator_and_denominator::register(runner);
     from_primitive_float::register(runner);
     from_primitive_int::register(runner);
+    from_residue::register(runner);
     integer_from_rational::register(runner);
     is_integer::register(runner);
     mutate_numerator_or_denominator::register(runner);
@@ -33,6 +34,7 @@ mod from_natural;
 mod from_numerator_and_denominator;
 mod from_primitive_float;
 mod from_primitive_int;
+mod from_residue;
 mod integer_from_rational;
 mod is_integer;
 mod mutate_numerator_or_denominator;
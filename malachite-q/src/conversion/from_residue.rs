@@ -0,0 +1,117 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{FloorSqrt, UnsignedAbs};
+use malachite_base::num::basic::traits::One;
+use malachite_nz::natural::Natural;
+
+impl Rational {
+    /// Recovers a [`Rational`] from its residue modulo a [`Natural`], using the default bounds on
+    /// its numerator and denominator.
+    ///
+    /// Given $a$ and $m$, finds the [`Rational`] $p/q$ with $p \equiv qa \pmod m$ and $|p|, q \leq
+    /// \lfloor \sqrt{(m - 1)/2} \rfloor$. There is at most one such [`Rational`]; if there is none,
+    /// `None` is returned. See
+    /// [`from_residue_with_bounds`](Rational::from_residue_with_bounds) for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero or if `a` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_residue(&Natural::from(23u32), &Natural::from(71u32)).to_debug_string(),
+    ///     "Some(-2/3)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_residue(&Natural::from(10u32), &Natural::from(71u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_residue(
+    ///         &Natural::from(7905747460161236410u64),
+    ///         &Natural::from(1u128 << 64)
+    ///     )
+    ///     .to_debug_string(),
+    ///     "Some(22/7)"
+    /// );
+    /// ```
+    pub fn from_residue(a: &Natural, m: &Natural) -> Option<Rational> {
+        assert_ne!(*m, 0u32);
+        let bound = ((m - Natural::ONE) >> 1u32).floor_sqrt();
+        Rational::from_residue_with_bounds(a, m, &bound, &bound)
+    }
+
+    /// Recovers a [`Rational`] from its residue modulo a [`Natural`], given bounds on its
+    /// numerator and denominator.
+    ///
+    /// Given $a$, $m$, $N$, and $D$, finds the [`Rational`] $p/q$ with $p \equiv qa \pmod m$,
+    /// $|p| \leq N$, and $0 < q \leq D$, or returns `None` if there is no such [`Rational`]. Since
+    /// $2ND < m$, there is at most one. The denominator $q$ is always coprime to $m$.
+    ///
+    /// This is the final step of multi-modular algorithms whose results are rational, like the
+    /// solution of a linear system with rational coefficients: once the result is known modulo a
+    /// sufficiently large $m$ (for example, by combining residues modulo several primes using
+    /// [`Crt`](malachite_base::num::arithmetic::traits::Crt)), it can be recovered with this
+    /// function. For large $m$, the half-GCD algorithm is used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `a` is greater than or equal to `m`, or if $2ND \geq m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_residue_with_bounds(
+    ///         &Natural::from(10u32),
+    ///         &Natural::from(71u32),
+    ///         &Natural::from(10u32),
+    ///         &Natural::from(3u32)
+    ///     )
+    ///     .to_debug_string(),
+    ///     "Some(10)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_residue_with_bounds(
+    ///         &Natural::from(778761061946902654867256637165u128),
+    ///         &Natural::from(10u128.pow(30)),
+    ///         &Natural::from(1000u32),
+    ///         &Natural::from(1000u32)
+    ///     )
+    ///     .to_debug_string(),
+    ///     "Some(-355/113)"
+    /// );
+    /// ```
+    pub fn from_residue_with_bounds(
+        a: &Natural,
+        m: &Natural,
+        numerator_bound: &Natural,
+        denominator_bound: &Natural,
+    ) -> Option<Rational> {
+        a.rational_reconstruction(m, numerator_bound, denominator_bound)
+            .map(|(numerator, denominator)| Rational {
+                sign: numerator >= 0u32,
+                numerator: numerator.unsigned_abs(),
+                denominator,
+            })
+    }
+}
@@ -45,6 +45,9 @@ pub mod from_primitive_float;
 /// assert_eq!(Rational::from(-123i32), -123);
 /// ```
 pub mod from_primitive_int;
+/// Functions for recovering a [`Rational`](crate::Rational) from its residue modulo a
+/// [`Natural`](malachite_nz::natural::Natural), a process known as rational reconstruction.
+pub mod from_residue;
 /// Implementations of traits for converting a [`Rational`](crate::Rational) to an
 /// [`Integer`](malachite_nz::integer::Integer).
 ///
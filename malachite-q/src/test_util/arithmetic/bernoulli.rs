@@ -0,0 +1,32 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{Factorial, Parity};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+// Uses the formula $B_n = \sum_{k=0}^n (-1)^k k! \left\{{n \atop k}\right\} / (k + 1)$, computing
+// the Stirling numbers of the second kind from their recurrence.
+pub fn bernoulli_naive(n: u64) -> Rational {
+    let len = usize::exact_from(n) + 1;
+    let mut row = vec![Natural::ZERO; len];
+    row[0] = Natural::ONE;
+    for m in 1..len {
+        for j in (1..=m).rev() {
+            let (lo, hi) = row.split_at_mut(j);
+            hi[0] *= Natural::from(j);
+            hi[0] += &lo[j - 1];
+        }
+        row[0] = Natural::ZERO;
+    }
+    let mut sum = Rational::ZERO;
+    for (k, s) in row.into_iter().enumerate() {
+        let k = u64::exact_from(k);
+        let term = Rational::from_naturals(Natural::factorial(k) * s, Natural::from(k + 1));
+        if k.even() {
+            sum += term;
+        } else {
+            sum -= term;
+        }
+    }
+    sum
+}
@@ -1,5 +1,6 @@
 pub mod add;
 pub mod approximate;
+pub mod bernoulli;
 pub mod div;
 pub mod mul;
 pub mod sign;
@@ -96,6 +96,8 @@ fn test_sum() {
         "[0, 1, 2/3, 3/4, 4/5, 5/6, 6/7, 7/8, 8/9, 9/10]",
         "19079/2520",
     );
+    test("[1/6, 1/10, 1/15]", "1/3");
+    test("[1/2, -1/3, 1/2, -2/3]", "0");
     test(
         "[123456/78901, 34567/890123, 45678/90123]",
         "342501191973781/162294410775211",
@@ -0,0 +1,62 @@
+use malachite_base::num::arithmetic::traits::{Bernoulli, Parity};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_q::test_util::arithmetic::bernoulli::bernoulli_naive;
+use malachite_q::Rational;
+
+#[test]
+fn test_bernoulli() {
+    fn test(n: u64, out: &str) {
+        let b = Rational::bernoulli(n);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+        assert_eq!(bernoulli_naive(n), b);
+    }
+    test(0, "1");
+    test(1, "-1/2");
+    test(2, "1/6");
+    test(3, "0");
+    test(4, "-1/30");
+    test(6, "1/42");
+    test(8, "-1/30");
+    test(10, "5/66");
+    test(12, "-691/2730");
+    test(14, "7/6");
+    test(101, "0");
+    test(
+        100,
+        "-94598037819122125295227433069493721872702841533066936133385696204311395415197247711/\
+        33330",
+    );
+}
+
+#[test]
+fn bernoulli_properties() {
+    unsigned_gen_var_5::<u8>().test_properties(|n| {
+        let n = u64::from(n);
+        let b = Rational::bernoulli(n);
+        assert!(b.is_valid());
+        assert_eq!(bernoulli_naive(n), b);
+        if n > 1 && n.odd() {
+            assert_eq!(b, 0u32);
+        }
+        if n != 0 && n.even() {
+            // The sign of B_n alternates.
+            assert_eq!(b > 0u32, (n >> 1).odd());
+            // von Staudt–Clausen: the denominator of B_n is the product of the primes p such that
+            // p - 1 divides n.
+            assert_eq!(
+                *b.denominator_ref(),
+                u64::primes_less_than_or_equal_to(&(n + 1))
+                    .filter(|&p| n % (p - 1) == 0)
+                    .map(Natural::from)
+                    .product::<Natural>()
+            );
+        }
+        if n == 1 || n.even() {
+            assert_ne!(b, Rational::ZERO);
+        }
+    });
+}
@@ -0,0 +1,86 @@
+use malachite_base::num::arithmetic::traits::{Abs, GcdOf, LcmOf};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_vec_gen;
+use malachite_q::test_util::generators::{rational_triple_gen, rational_vec_gen};
+use malachite_q::Rational;
+
+#[test]
+fn test_gcd_of() {
+    let test = |xs, out| {
+        let xs = vec_from_str::<Rational>(xs).unwrap();
+        let gcd = Rational::gcd_of(xs.iter());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd.to_string(), out);
+
+        let gcd = Rational::gcd_of(xs.into_iter());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd.to_string(), out);
+    };
+    test("[]", "0");
+    test("[0]", "0");
+    test("[0, 0]", "0");
+    test("[-1/2]", "1/2");
+    test("[6, 4]", "2");
+    test("[-6, 0, 4]", "2");
+    test("[2/3, -4/5, 0]", "2/15");
+    test("[1/2, 1/3, 1/4]", "1/12");
+    test("[3/4, 9/10, -15/8]", "3/40");
+}
+
+#[test]
+fn gcd_of_properties() {
+    rational_vec_gen().test_properties(|xs| {
+        let gcd = Rational::gcd_of(xs.iter());
+        assert!(gcd.is_valid());
+        let gcd_alt = Rational::gcd_of(xs.clone().into_iter());
+        assert!(gcd_alt.is_valid());
+        assert_eq!(gcd_alt, gcd);
+
+        assert!(gcd >= 0u32);
+        assert_eq!(gcd == 0u32, xs.iter().all(|x| *x == 0u32));
+        assert_eq!(Rational::gcd_of(xs.iter().rev()), gcd);
+        assert_eq!(Rational::gcd_of(xs.iter().map(Abs::abs)), gcd);
+        assert_eq!(
+            *gcd.numerator_ref(),
+            Natural::gcd_of(xs.iter().map(Rational::numerator_ref))
+        );
+        assert_eq!(
+            *gcd.denominator_ref(),
+            Natural::lcm_of(xs.iter().map(Rational::denominator_ref))
+        );
+        if gcd != 0u32 {
+            // Dividing by the content leaves integers with no common factor
+            let quotients: Vec<Integer> = xs
+                .iter()
+                .map(|x| Integer::try_from(x / &gcd).unwrap())
+                .collect();
+            assert_eq!(Natural::gcd_of(quotients.iter()), 1);
+        }
+    });
+
+    rational_triple_gen().test_properties(|(c, x, y)| {
+        assert_eq!(
+            Rational::gcd_of([&x * &c, &y * &c].into_iter()),
+            Rational::gcd_of([x, y].into_iter()) * c.abs()
+        );
+    });
+
+    integer_vec_gen().test_properties(|xs| {
+        assert_eq!(
+            Rational::gcd_of(xs.iter().map(Rational::from)),
+            Natural::gcd_of(xs.iter())
+        );
+    });
+
+    rational_vec_gen().test_properties(|xs| {
+        let mut ys = xs.clone();
+        ys.push(Rational::ZERO);
+        assert_eq!(
+            Rational::gcd_of(ys.into_iter()),
+            Rational::gcd_of(xs.into_iter())
+        );
+    });
+}
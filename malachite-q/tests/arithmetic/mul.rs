@@ -100,6 +100,7 @@ fn test_product() {
     test("[22/7, 1/3]", "22/21");
     test("[0, 1, 2/3, 3/4, 4/5, 5/6, 6/7, 7/8, 8/9, 9/10]", "0");
     test("[1, 2/3, 3/4, 4/5, 5/6, 6/7, 7/8, 8/9, 9/10]", "1/5");
+    test("[-1/2, 2/3, -3/4]", "1/4");
     test(
         "[123456/78901, 34567/890123, 45678/90123]",
         "217314411648/7056278729357",
@@ -0,0 +1,97 @@
+use malachite_base::num::arithmetic::traits::{
+    IsPerfectPower, Parity, PerfectPowerDecompose, Pow, Reciprocal,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_nz::test_util::generators::integer_gen;
+use malachite_q::test_util::generators::{rational_gen, rational_unsigned_pair_gen_var_4};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_perfect_power_decompose() {
+    let test = |s, root, exp| {
+        let n = Rational::from_str(s).unwrap();
+        let (root_alt, exp_alt) = n.clone().perfect_power_decompose();
+        assert!(root_alt.is_valid());
+        assert_eq!(root_alt.to_string(), root);
+        assert_eq!(exp_alt, exp);
+
+        let (root_alt, exp_alt) = (&n).perfect_power_decompose();
+        assert!(root_alt.is_valid());
+        assert_eq!(root_alt.to_string(), root);
+        assert_eq!(exp_alt, exp);
+
+        assert_eq!(
+            n.is_perfect_power(),
+            exp > 1 || n == 0u32 || n == 1u32 || n == -1i32
+        );
+    };
+    test("0", "0", 1);
+    test("1", "1", 1);
+    test("-1", "-1", 1);
+    test("1000", "10", 3);
+    test("-1000", "-10", 3);
+    test("22/7", "22/7", 1);
+    test("1/4", "1/2", 2);
+    test("-1/4", "-1/4", 1);
+    test("1/64", "1/2", 6);
+    test("-1/64", "-1/4", 3);
+    test("8/27", "2/3", 3);
+    test("-8/27", "-2/3", 3);
+    test("16/81", "2/3", 4);
+    test("8/9", "8/9", 1);
+    test("64/729", "2/3", 6);
+    test("-64/729", "-4/9", 3);
+    test("4/27", "4/27", 1);
+    test("1/18446744073709551616", "1/2", 64);
+    test("931322574615478515625/1152921504606846976", "5/4", 30);
+    test("-931322574615478515625/1152921504606846976", "-25/16", 15);
+}
+
+#[test]
+fn perfect_power_decompose_properties() {
+    rational_gen().test_properties(|x| {
+        let (root, exp) = x.clone().perfect_power_decompose();
+        assert!(root.is_valid());
+        assert_eq!((&x).perfect_power_decompose(), (root.clone(), exp));
+        assert_ne!(exp, 0);
+        assert_eq!((&root).pow(exp), x);
+        assert_eq!(root >= 0, x >= 0);
+        if x < 0 {
+            assert!(exp.odd());
+        }
+        let is_perfect_power = x.is_perfect_power();
+        let trivial = x == Rational::ZERO || x == Rational::ONE || x == Rational::NEGATIVE_ONE;
+        assert_eq!(is_perfect_power, exp > 1 || trivial);
+        if !trivial {
+            assert!(!root.is_perfect_power());
+        }
+        if x != 0 {
+            let (root_alt, exp_alt) = (&x).reciprocal().perfect_power_decompose();
+            assert_eq!(root_alt, root.reciprocal());
+            assert_eq!(exp_alt, exp);
+        }
+    });
+
+    rational_unsigned_pair_gen_var_4::<u64>().test_properties(|(b, exp)| {
+        if b == 0u32 || b == 1u32 || b == -1i32 {
+            return;
+        }
+        let x = (&b).pow(exp);
+        let (root, exp_alt) = (&x).perfect_power_decompose();
+        assert_eq!(exp_alt % exp, 0);
+        assert_eq!(root.pow(exp_alt / exp), b);
+        if exp > 1 {
+            assert!(x.is_perfect_power());
+        }
+    });
+
+    integer_gen().test_properties(|n| {
+        let (root, exp) = (&n).perfect_power_decompose();
+        assert_eq!(
+            Rational::from(&n).perfect_power_decompose(),
+            (Rational::from(root), exp)
+        );
+        assert_eq!(Rational::from(&n).is_perfect_power(), n.is_perfect_power());
+    });
+}
@@ -0,0 +1,121 @@
+use malachite_base::num::arithmetic::traits::{Pow, Reciprocal, RemoveFactor, Valuation};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+use malachite_q::test_util::generators::rational_natural_pair_gen_var_1;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_valuation() {
+    let test = |x, p, out: &str| {
+        let x = Rational::from_str(x).unwrap();
+        let p = Natural::from_str(p).unwrap();
+        let (cofactor, v) = x.clone().remove_factor(&p);
+        assert!(cofactor.is_valid());
+        assert_eq!((cofactor.clone(), v).to_debug_string(), out);
+
+        let (cofactor_alt, v_alt) = (&x).remove_factor(&p);
+        assert!(cofactor_alt.is_valid());
+        assert_eq!(cofactor_alt, cofactor);
+        assert_eq!(v_alt, v);
+
+        assert_eq!((&x).valuation(&p), v);
+        assert_eq!(x.valuation(&p), v);
+    };
+    test("1", "2", "(1, 0)");
+    test("-1", "2", "(-1, 0)");
+    test("12", "2", "(3, 2)");
+    test("-12", "3", "(-4, 1)");
+    test("1/12", "2", "(1/3, -2)");
+    test("-5/12", "2", "(-5/3, -2)");
+    test("-5/12", "3", "(-5/4, -1)");
+    test("-5/12", "5", "(-1/12, 1)");
+    test("-5/12", "6", "(-5/2, -1)");
+    test("-5/12", "7", "(-5/12, 0)");
+    test("22/7", "7", "(22, -1)");
+    test("1/1000000000000", "100", "(1, -6)");
+    test("1/18446744073709551616", "4", "(1, -32)");
+    test(
+        "124/515377520732011331036461129765621272702107522001",
+        "2",
+        "(31/515377520732011331036461129765621272702107522001, 2)",
+    );
+    test(
+        "124/515377520732011331036461129765621272702107522001",
+        "3",
+        "(124, -100)",
+    );
+    test(
+        "124/515377520732011331036461129765621272702107522001",
+        "9",
+        "(124, -50)",
+    );
+}
+
+#[test]
+#[should_panic]
+fn valuation_fail_1() {
+    Rational::from(0).valuation(&Natural::from(2u32));
+}
+
+#[test]
+#[should_panic]
+fn valuation_fail_2() {
+    Rational::from_signeds(1, 10).valuation(&Natural::ONE);
+}
+
+#[test]
+#[should_panic]
+fn remove_factor_fail_1() {
+    Rational::from(0).remove_factor(&Natural::from(2u32));
+}
+
+#[test]
+#[should_panic]
+fn remove_factor_fail_2() {
+    (&Rational::from_signeds(1, 10)).remove_factor(&Natural::ONE);
+}
+
+#[test]
+fn valuation_properties() {
+    rational_natural_pair_gen_var_1().test_properties(|(x, p)| {
+        if x == 0u32 {
+            return;
+        }
+        let (cofactor, v) = (&x).remove_factor(&p);
+        assert!(cofactor.is_valid());
+        assert_eq!(x.clone().remove_factor(&p), (cofactor.clone(), v));
+        assert_eq!((&x).valuation(&p), v);
+        assert_eq!(x.clone().valuation(&p), v);
+
+        assert_eq!((&cofactor).valuation(&p), 0);
+        assert_eq!((&cofactor).reciprocal().valuation(&p), 0);
+        let p_rational = Rational::from(&p);
+        let power = if v >= 0 {
+            (&p_rational).pow(u64::exact_from(v))
+        } else {
+            (&p_rational).pow(v.unsigned_abs()).reciprocal()
+        };
+        assert_eq!(&cofactor * power, x);
+        assert_eq!((&x).reciprocal().valuation(&p), -v);
+        assert_eq!((-&x).remove_factor(&p), (-cofactor, v));
+    });
+
+    integer_natural_pair_gen().test_properties(|(n, p)| {
+        if n == 0u32 || p < 2u32 {
+            return;
+        }
+        let (cofactor, v) = (&n).remove_factor(&p);
+        assert_eq!(
+            Rational::from(&n).remove_factor(&p),
+            (Rational::from(cofactor), i64::exact_from(v))
+        );
+        assert_eq!(
+            Rational::from(n).reciprocal().valuation(&p),
+            -i64::exact_from(v)
+        );
+    });
+}
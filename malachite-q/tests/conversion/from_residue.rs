@@ -0,0 +1,152 @@
+use malachite_base::num::arithmetic::traits::{FloorSqrt, ModInverse, ModMul, ModNeg};
+use malachite_base::num::basic::traits::One;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen_var_8};
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_from_residue() {
+    let test = |a, m, out| {
+        let a = Natural::from_str(a).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let x = Rational::from_residue(&a, &m);
+        assert!(x.as_ref().map_or(true, Rational::is_valid));
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test("0", "1", "None");
+    test("0", "5", "Some(0)");
+    test("1", "5", "Some(1)");
+    test("4", "5", "Some(-1)");
+    test("2", "5", "None");
+    test("23", "71", "Some(-2/3)");
+    test("10", "71", "None");
+    test("7905747460161236410", "18446744073709551616", "Some(22/7)");
+    test(
+        "778761061946902654867256637165",
+        "1000000000000000000000000000000",
+        "Some(-355/113)",
+    );
+}
+
+#[test]
+fn from_residue_fail() {
+    assert_panic!(Rational::from_residue(
+        &Natural::from(0u32),
+        &Natural::from(0u32)
+    ));
+    assert_panic!(Rational::from_residue(
+        &Natural::from(5u32),
+        &Natural::from(5u32)
+    ));
+}
+
+#[test]
+fn test_from_residue_with_bounds() {
+    let test = |a, m, n, d, out| {
+        let a = Natural::from_str(a).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let n = Natural::from_str(n).unwrap();
+        let d = Natural::from_str(d).unwrap();
+        let x = Rational::from_residue_with_bounds(&a, &m, &n, &d);
+        assert!(x.as_ref().map_or(true, Rational::is_valid));
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test("0", "1", "0", "0", "None");
+    test("0", "3", "1", "1", "Some(0)");
+    test("10", "71", "10", "3", "Some(10)");
+    test("10", "71", "3", "10", "Some(-1/7)");
+    test("50", "100", "3", "10", "None");
+    test(
+        "778761061946902654867256637165",
+        "1000000000000000000000000000000",
+        "1000",
+        "1000",
+        "Some(-355/113)",
+    );
+    test(
+        "778761061946902654867256637165",
+        "1000000000000000000000000000000",
+        "100",
+        "1000",
+        "None",
+    );
+}
+
+#[test]
+fn from_residue_with_bounds_fail() {
+    assert_panic!(Rational::from_residue_with_bounds(
+        &Natural::from(5u32),
+        &Natural::from(5u32),
+        &Natural::ONE,
+        &Natural::ONE
+    ));
+    assert_panic!(Rational::from_residue_with_bounds(
+        &Natural::from(0u32),
+        &Natural::from(8u32),
+        &Natural::from(2u32),
+        &Natural::from(2u32)
+    ));
+}
+
+#[test]
+fn from_residue_properties() {
+    natural_pair_gen_var_8().test_properties(|(a, m)| {
+        let x = Rational::from_residue(&a, &m);
+        let bound = ((&m - Natural::ONE) >> 1u32).floor_sqrt();
+        assert_eq!(
+            Rational::from_residue_with_bounds(&a, &m, &bound, &bound),
+            x
+        );
+        if let Some(x) = x {
+            assert!(x.is_valid());
+            assert!(*x.numerator_ref() <= bound);
+            assert!(*x.denominator_ref() <= bound);
+            let residue = x
+                .numerator_ref()
+                .mod_mul(x.denominator_ref().mod_inverse(&m).unwrap(), &m);
+            assert_eq!(
+                if x >= 0u32 {
+                    residue
+                } else {
+                    residue.mod_neg(&m)
+                },
+                a
+            );
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        let n = x.numerator_ref();
+        let d = x.denominator_ref();
+        if *n == 0u32 {
+            return;
+        }
+        // Any modulus greater than 2nd that is coprime to d will do
+        let m = ((n * d) << 1u32) + Natural::ONE;
+        let a = (n % &m).mod_mul(d.mod_inverse(&m).unwrap(), &m);
+        let a = if x >= 0u32 { a } else { a.mod_neg(&m) };
+        assert_eq!(
+            Rational::from_residue_with_bounds(&a, &m, n, d),
+            Some(x.clone())
+        );
+        let bound = n.max(d);
+        let m = ((bound * bound) << 1u32) + Natural::ONE;
+        if d.mod_inverse(&m).is_some() {
+            let a = (n % &m).mod_mul(d.mod_inverse(&m).unwrap(), &m);
+            let a = if x >= 0u32 { a } else { a.mod_neg(&m) };
+            assert_eq!(Rational::from_residue(&a, &m), Some(x));
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        let m = (&n << 1u32) + Natural::ONE;
+        assert_eq!(
+            Rational::from_residue_with_bounds(&n, &m, &n, &Natural::ONE),
+            Some(Rational::from(n))
+        );
+    });
+}
@@ -49,10 +49,12 @@ pub mod arithmetic {
     pub mod abs;
     pub mod add;
     pub mod approximate;
+    pub mod bernoulli;
     pub mod ceiling;
     pub mod denominators_in_closed_interval;
     pub mod div;
     pub mod floor;
+    pub mod gcd;
     pub mod is_power_of_2;
     pub mod log_base;
     pub mod log_base_2;
@@ -60,6 +62,7 @@ pub mod arithmetic {
     pub mod mul;
     pub mod neg;
     pub mod next_power_of_2;
+    pub mod perfect_power;
     pub mod pow;
     pub mod power_of_2;
     pub mod reciprocal;
@@ -73,6 +76,7 @@ pub mod arithmetic {
     pub mod sqrt;
     pub mod square;
     pub mod sub;
+    pub mod valuation;
 }
 pub mod basic {
     pub mod constants;
@@ -124,6 +128,7 @@ pub mod conversion {
     pub mod from_natural;
     pub mod from_primitive_float;
     pub mod from_primitive_int;
+    pub mod from_residue;
     pub mod integer_from_rational;
     pub mod is_integer;
     pub mod natural_from_rational;